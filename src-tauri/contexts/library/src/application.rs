@@ -2,4 +2,6 @@
 
 pub mod dto;
 pub mod errors;
+pub mod exporters;
+pub mod importers;
 pub mod services;
@@ -1,3 +1,19 @@
 // Library Application Layer - DTOs
 
+pub mod author;
 pub mod book;
+pub mod citation;
+pub mod classification;
+pub mod copy;
+pub mod cover;
+pub mod duplicate;
+pub mod ebook;
+pub mod highlight;
+pub mod history;
+pub mod import;
+pub mod loan;
+pub mod reading;
+pub mod review;
+pub mod series;
+pub mod shelf;
+pub mod tag;
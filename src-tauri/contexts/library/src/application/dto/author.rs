@@ -0,0 +1,92 @@
+// Library Application Layer - Author データ転送オブジェクト
+
+use crate::application::dto::book::BookDto;
+use crate::domain::entities::author::{Author, BookAuthor};
+use crate::domain::value_objects::author_role::AuthorRole;
+use async_graphql::{Enum, InputObject, SimpleObject};
+
+/// 本に対する人物の役割
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Enum)]
+pub enum BookAuthorRole {
+    #[default]
+    Author,
+    Translator,
+    Editor,
+    Illustrator,
+}
+
+impl From<AuthorRole> for BookAuthorRole {
+    fn from(role: AuthorRole) -> Self {
+        match role {
+            AuthorRole::Author => Self::Author,
+            AuthorRole::Translator => Self::Translator,
+            AuthorRole::Editor => Self::Editor,
+            AuthorRole::Illustrator => Self::Illustrator,
+        }
+    }
+}
+
+impl From<BookAuthorRole> for AuthorRole {
+    fn from(role: BookAuthorRole) -> Self {
+        match role {
+            BookAuthorRole::Author => Self::Author,
+            BookAuthorRole::Translator => Self::Translator,
+            BookAuthorRole::Editor => Self::Editor,
+            BookAuthorRole::Illustrator => Self::Illustrator,
+        }
+    }
+}
+
+/// 本の著者（役割付き） DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct BookAuthorDto {
+    /// 著者ID
+    pub id: i32,
+    pub name: String,
+    pub role: BookAuthorRole,
+}
+
+impl From<&BookAuthor> for BookAuthorDto {
+    fn from(book_author: &BookAuthor) -> Self {
+        Self {
+            id: book_author
+                .author()
+                .id()
+                .expect("Author must have an ID when converting to DTO"),
+            name: book_author.author().name().to_string(),
+            role: book_author.role().into(),
+        }
+    }
+}
+
+/// 著者 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct AuthorDto {
+    pub id: i32,
+    pub name: String,
+    /// この人物が関わっている本（出版年順）
+    pub books: Vec<BookDto>,
+}
+
+impl AuthorDto {
+    pub fn new(author: Author, books: Vec<BookDto>) -> Self {
+        Self {
+            id: author
+                .id()
+                .expect("Author must have an ID when converting to DTO"),
+            name: author.name().to_string(),
+            books,
+        }
+    }
+}
+
+/// 本の著者の指定 - GraphQL入力用
+///
+/// 既存の著者は `authorId`、新しい著者は `name` で指定する（同名の著者がいれば再利用する）。
+#[derive(Debug, Clone, InputObject)]
+pub struct BookAuthorInput {
+    pub author_id: Option<i32>,
+    pub name: Option<String>,
+    #[graphql(default)]
+    pub role: BookAuthorRole,
+}
@@ -1,16 +1,60 @@
 // Library Application Layer - Book データ転送オブジェクト
 
+use crate::application::dto::author::BookAuthorDto;
+use crate::application::dto::cover::cover_url;
 use crate::domain::entities::book::Book;
-use async_graphql::SimpleObject;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::book::{BookFilter, BookSearchHit, BookSortKey, SortOrder};
+use crate::domain::repositories::cover::CoverSize;
+use crate::domain::value_objects::class_number::{ClassPrefix, ClassificationScheme};
+use async_graphql::{Enum, InputObject, SimpleObject};
+use chrono::{DateTime, Utc};
 
 /// Book DTO - GraphQLレスポンス用
+///
+/// 読書状態などの関連データは presentation 層の `ComplexObject` で解決する。
 #[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
 pub struct BookDto {
     pub id: i32,
     pub title: String,
     pub author: Option<String>,
+    /// タイトルの読み（かな）
+    pub title_reading: Option<String>,
+    /// 著者の読み（かな）
+    pub author_reading: Option<String>,
+    /// タイトルの並び替え用のキー（読み、なければタイトルを正規化したもの。英語の先頭の冠詞は除く）
+    pub title_sort_key: String,
+    /// 著者の並び替え用のキー（読み、なければ著者名を正規化したもの）
+    pub author_sort_key: Option<String>,
     pub description: Option<String>,
     pub published_year: Option<i32>,
+    /// 出版社
+    pub publisher: Option<String>,
+    /// ISBN-13（ハイフンなし）
+    pub isbn: Option<String>,
+    /// ISBN-10（ハイフンなし、979で始まるISBNの場合はなし）
+    pub isbn10: Option<String>,
+    /// 日本十進分類法（NDC）の分類記号
+    pub ndc: Option<String>,
+    /// NDCの分類表での名称（目・綱・類のうち最も詳しいもの）
+    pub ndc_name: Option<String>,
+    /// デューイ十進分類法（DDC）の分類記号
+    pub ddc: Option<String>,
+    /// DDCの分類表での名称（目・綱・類のうち最も詳しいもの）
+    pub ddc_name: Option<String>,
+    /// 著者・翻訳者などの一覧（表示順）
+    pub authors: Vec<BookAuthorDto>,
+    /// 表紙画像のURL（カスタムプロトコル経由、表紙がない場合はなし）
+    pub cover_url: Option<String>,
+    /// 表紙画像のサムネイル（固定サイズ）のURL
+    pub thumbnail_url: Option<String>,
+    /// ゴミ箱に移した日時（ゴミ箱にない本はなし）
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// 版番号（保存するたびに1増える。本を更新するミューテーションの `expectedVersion` に渡す。他で変更されていた場合は CONFLICT エラーになる）
+    pub version: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl From<Book> for BookDto {
@@ -21,8 +65,185 @@ impl From<Book> for BookDto {
                 .expect("Book must have an ID when converting to DTO"),
             title: book.title().to_string(),
             author: book.author().map(String::from),
+            title_reading: book.title_reading().map(String::from),
+            author_reading: book.author_reading().map(String::from),
+            title_sort_key: book.title_sort_key().as_str().to_string(),
+            author_sort_key: book.author_sort_key().map(|key| key.as_str().to_string()),
             description: book.description().map(String::from),
             published_year: book.published_year(),
+            publisher: book.publisher().map(String::from),
+            isbn: book.isbn().map(|isbn| isbn.as_isbn13().to_string()),
+            isbn10: book.isbn().and_then(|isbn| isbn.to_isbn10()),
+            ndc: book.ndc().map(|ndc| ndc.as_str().to_string()),
+            ndc_name: book
+                .ndc()
+                .and_then(|ndc| ndc.class_name())
+                .map(String::from),
+            ddc: book.ddc().map(|ddc| ddc.as_str().to_string()),
+            ddc_name: book
+                .ddc()
+                .and_then(|ddc| ddc.class_name())
+                .map(String::from),
+            authors: book.authors().iter().map(BookAuthorDto::from).collect(),
+            cover_url: book.cover().map(|hash| cover_url(hash, CoverSize::Full)),
+            thumbnail_url: book
+                .cover()
+                .map(|hash| cover_url(hash, CoverSize::Thumbnail)),
+            deleted_at: book.deleted_at(),
+            version: book.version(),
+            created_at: book.created_at(),
+            updated_at: book.updated_at(),
         }
     }
 }
+
+/// 全文検索結果 DTO - GraphQLレスポンス用
+///
+/// 強調箇所は `<mark>` で囲まれる（本文はHTMLエスケープされない）
+#[derive(Debug, Clone, SimpleObject)]
+pub struct BookSearchResultDto {
+    pub book: BookDto,
+    /// 一致箇所を強調したタイトル
+    pub title_highlight: String,
+    /// 一致箇所を強調した著者名
+    pub author_highlight: Option<String>,
+    /// 一致箇所周辺の説明文の抜粋
+    pub description_snippet: Option<String>,
+    /// 関連度（大きいほど関連が高い。3文字未満の語だけで検索した場合は0）
+    pub score: f64,
+}
+
+impl From<BookSearchHit> for BookSearchResultDto {
+    fn from(hit: BookSearchHit) -> Self {
+        Self {
+            book: BookDto::from(hit.book),
+            title_highlight: hit.title_highlight,
+            author_highlight: hit.author_highlight,
+            description_snippet: hit.description_snippet,
+            score: hit.score,
+        }
+    }
+}
+
+/// 本一覧の絞り込み条件 - GraphQL入力用
+#[derive(Debug, Clone, Default, InputObject)]
+pub struct BookFilterInput {
+    /// 著者名（部分一致）
+    pub author: Option<String>,
+    /// タイトル（部分一致）
+    pub title_contains: Option<String>,
+    /// 出版年の下限（この年を含む）
+    pub published_year_from: Option<i32>,
+    /// 出版年の上限（この年を含む）
+    pub published_year_to: Option<i32>,
+    /// 作成日時の下限（この日時を含む）
+    pub created_at_from: Option<DateTime<Utc>>,
+    /// 作成日時の上限（この日時を含む）
+    pub created_at_to: Option<DateTime<Utc>>,
+    /// 更新日時の下限（この日時を含む）
+    pub updated_at_from: Option<DateTime<Utc>>,
+    /// 更新日時の上限（この日時を含む）
+    pub updated_at_to: Option<DateTime<Utc>>,
+    /// 日本十進分類法（NDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む）
+    pub ndc: Option<String>,
+    /// デューイ十進分類法（DDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む）
+    pub ddc: Option<String>,
+}
+
+impl TryFrom<BookFilterInput> for BookFilter {
+    type Error = DomainError;
+
+    fn try_from(input: BookFilterInput) -> Result<Self, Self::Error> {
+        // 空文字列は未指定扱い
+        let class_prefix = |scheme, input: Option<String>| {
+            input
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| ClassPrefix::parse(scheme, &value))
+                .transpose()
+        };
+
+        Ok(Self {
+            author: input
+                .author
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty()),
+            title_contains: input
+                .title_contains
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty()),
+            published_year_from: input.published_year_from,
+            published_year_to: input.published_year_to,
+            created_at_from: input.created_at_from,
+            created_at_to: input.created_at_to,
+            updated_at_from: input.updated_at_from,
+            updated_at_to: input.updated_at_to,
+            ndc: class_prefix(ClassificationScheme::Ndc, input.ndc)?,
+            ddc: class_prefix(ClassificationScheme::Ddc, input.ddc)?,
+            tag: None,
+            shelf: None,
+        })
+    }
+}
+
+/// 本一覧の並び替え項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Enum)]
+pub enum BookSortField {
+    /// タイトルの読み順（並び替え用のキー）
+    Title,
+    /// 著者の読み順（並び替え用のキー）
+    Author,
+    PublishedYear,
+    /// 作成日時（最近追加した本）
+    CreatedAt,
+    /// 更新日時（最近編集した本）
+    UpdatedAt,
+    #[default]
+    Id,
+}
+
+impl From<BookSortField> for BookSortKey {
+    fn from(field: BookSortField) -> Self {
+        match field {
+            BookSortField::Title => Self::Title,
+            BookSortField::Author => Self::Author,
+            BookSortField::PublishedYear => Self::PublishedYear,
+            BookSortField::CreatedAt => Self::CreatedAt,
+            BookSortField::UpdatedAt => Self::UpdatedAt,
+            BookSortField::Id => Self::Id,
+        }
+    }
+}
+
+/// 並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Enum)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl From<SortDirection> for SortOrder {
+    fn from(direction: SortDirection) -> Self {
+        match direction {
+            SortDirection::Asc => Self::Asc,
+            SortDirection::Desc => Self::Desc,
+        }
+    }
+}
+
+/// 本一覧の並び替え条件 - GraphQL入力用
+#[derive(Debug, Clone, Copy, Default, InputObject)]
+pub struct BookSortInput {
+    #[graphql(default)]
+    pub field: BookSortField,
+    #[graphql(default)]
+    pub direction: SortDirection,
+}
+
+/// 本一覧の1ページ分の取得結果
+#[derive(Debug, Clone)]
+pub struct BookPageDto {
+    pub books: Vec<BookDto>,
+    pub total_count: u64,
+    pub has_next_page: bool,
+}
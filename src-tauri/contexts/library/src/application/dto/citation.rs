@@ -0,0 +1,14 @@
+// Library Application Layer - 引用文献の書き出し データ転送オブジェクト
+
+use async_graphql::Enum;
+
+/// 引用文献の書き出し形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum CitationFormat {
+    /// BibTeX（`@book` エントリ、LaTeX の特殊文字はエスケープ済み）
+    Bibtex,
+    /// RIS（EndNote・Zotero などで読み込める形式）
+    Ris,
+    /// CSL-JSON（Pandoc・Zotero などの引用処理系向け）
+    CslJson,
+}
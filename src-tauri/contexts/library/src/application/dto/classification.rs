@@ -0,0 +1,33 @@
+// Library Application Layer - 本の分類 データ転送オブジェクト
+
+use crate::domain::value_objects::class_number::ClassificationScheme;
+use async_graphql::{Enum, SimpleObject};
+
+/// 分類法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum ClassificationSystem {
+    /// 日本十進分類法
+    Ndc,
+    /// デューイ十進分類法
+    Ddc,
+}
+
+impl From<ClassificationSystem> for ClassificationScheme {
+    fn from(system: ClassificationSystem) -> Self {
+        match system {
+            ClassificationSystem::Ndc => Self::Ndc,
+            ClassificationSystem::Ddc => Self::Ddc,
+        }
+    }
+}
+
+/// 分類ごとの本の数 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct BookClassDto {
+    /// 分類記号（類は1桁、綱は2桁、目以下は分類記号）
+    pub code: String,
+    /// 分類表での名称（分類表にない分類記号の場合はなし）
+    pub name: Option<String>,
+    /// この分類に含まれる本の数（下位の分類を含む）
+    pub book_count: u64,
+}
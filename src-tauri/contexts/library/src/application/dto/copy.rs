@@ -0,0 +1,227 @@
+// Library Application Layer - 所有している本の1冊・場所 データ転送オブジェクト
+
+use crate::domain::entities::{copy::Copy, location::Location};
+use crate::domain::value_objects::{
+    copy_condition::CopyCondition, copy_format::CopyFormat, location_kind::LocationKind,
+    price::Price,
+};
+use async_graphql::{Enum, InputObject, MaybeUndefined, SimpleObject};
+use chrono::NaiveDate;
+
+/// 所有している本の版型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookCopyFormat {
+    Hardcover,
+    Paperback,
+    Ebook,
+    Audiobook,
+}
+
+impl From<CopyFormat> for BookCopyFormat {
+    fn from(format: CopyFormat) -> Self {
+        match format {
+            CopyFormat::Hardcover => Self::Hardcover,
+            CopyFormat::Paperback => Self::Paperback,
+            CopyFormat::Ebook => Self::Ebook,
+            CopyFormat::Audiobook => Self::Audiobook,
+        }
+    }
+}
+
+impl From<BookCopyFormat> for CopyFormat {
+    fn from(format: BookCopyFormat) -> Self {
+        match format {
+            BookCopyFormat::Hardcover => Self::Hardcover,
+            BookCopyFormat::Paperback => Self::Paperback,
+            BookCopyFormat::Ebook => Self::Ebook,
+            BookCopyFormat::Audiobook => Self::Audiobook,
+        }
+    }
+}
+
+/// 所有している本の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookCopyCondition {
+    /// 新品
+    New,
+    /// 新品同様
+    LikeNew,
+    /// 非常に良い
+    VeryGood,
+    /// 良い
+    Good,
+    /// 可
+    Fair,
+    /// 悪い
+    Poor,
+}
+
+impl From<CopyCondition> for BookCopyCondition {
+    fn from(condition: CopyCondition) -> Self {
+        match condition {
+            CopyCondition::New => Self::New,
+            CopyCondition::LikeNew => Self::LikeNew,
+            CopyCondition::VeryGood => Self::VeryGood,
+            CopyCondition::Good => Self::Good,
+            CopyCondition::Fair => Self::Fair,
+            CopyCondition::Poor => Self::Poor,
+        }
+    }
+}
+
+impl From<BookCopyCondition> for CopyCondition {
+    fn from(condition: BookCopyCondition) -> Self {
+        match condition {
+            BookCopyCondition::New => Self::New,
+            BookCopyCondition::LikeNew => Self::LikeNew,
+            BookCopyCondition::VeryGood => Self::VeryGood,
+            BookCopyCondition::Good => Self::Good,
+            BookCopyCondition::Fair => Self::Fair,
+            BookCopyCondition::Poor => Self::Poor,
+        }
+    }
+}
+
+/// 場所の階層
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookLocationKind {
+    /// 部屋（最上位）
+    Room,
+    /// 本棚（部屋に置く）
+    Bookcase,
+    /// 棚（本棚に置く）
+    Shelf,
+}
+
+impl From<LocationKind> for BookLocationKind {
+    fn from(kind: LocationKind) -> Self {
+        match kind {
+            LocationKind::Room => Self::Room,
+            LocationKind::Bookcase => Self::Bookcase,
+            LocationKind::Shelf => Self::Shelf,
+        }
+    }
+}
+
+impl From<BookLocationKind> for LocationKind {
+    fn from(kind: BookLocationKind) -> Self {
+        match kind {
+            BookLocationKind::Room => Self::Room,
+            BookLocationKind::Bookcase => Self::Bookcase,
+            BookLocationKind::Shelf => Self::Shelf,
+        }
+    }
+}
+
+/// 場所 DTO - GraphQLレスポンス用
+///
+/// 下位の場所・部屋からの経路・冊数は presentation 層の `ComplexObject` で解決する。
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct LocationDto {
+    pub id: i32,
+    pub name: String,
+    pub kind: BookLocationKind,
+    /// 1つ上の階層の場所（部屋はなし）
+    pub parent_id: Option<i32>,
+}
+
+impl From<Location> for LocationDto {
+    fn from(location: Location) -> Self {
+        Self {
+            id: location
+                .id()
+                .expect("Location must have an ID when converting to DTO"),
+            name: location.name().to_string(),
+            kind: location.kind().into(),
+            parent_id: location.parent_id(),
+        }
+    }
+}
+
+/// 価格 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct PriceDto {
+    /// 金額の10進表記（通貨の小数点以下の桁数にそろえる。例: `12.90`・`1980`）
+    pub amount: String,
+    /// 通貨コード（ISO 4217）
+    pub currency: String,
+}
+
+impl From<&Price> for PriceDto {
+    fn from(price: &Price) -> Self {
+        Self {
+            amount: price.amount(),
+            currency: price.currency().to_string(),
+        }
+    }
+}
+
+/// 所有している本の1冊 DTO - GraphQLレスポンス用
+///
+/// 本・置いてある場所の経路は presentation 層の `ComplexObject` で解決する。
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct CopyDto {
+    pub id: i32,
+    pub book_id: i32,
+    pub format: BookCopyFormat,
+    pub condition: Option<BookCopyCondition>,
+    pub acquired_on: Option<NaiveDate>,
+    /// 入手時の価格
+    pub price: Option<PriceDto>,
+    /// 置いてある場所（部屋・本棚・棚のいずれか）
+    pub location_id: Option<i32>,
+}
+
+impl From<Copy> for CopyDto {
+    fn from(copy: Copy) -> Self {
+        Self {
+            id: copy
+                .id()
+                .expect("Copy must have an ID when converting to DTO"),
+            book_id: copy.book_id(),
+            format: copy.format().into(),
+            condition: copy.condition().map(Into::into),
+            acquired_on: copy.acquired_on(),
+            price: copy.price().map(PriceDto::from),
+            location_id: copy.location_id(),
+        }
+    }
+}
+
+/// 価格 - GraphQL入力用
+#[derive(Debug, Clone, InputObject)]
+pub struct PriceInput {
+    /// 金額の10進表記（例: `1980`・`12.99`。`,` の桁区切りは無視する）
+    pub amount: String,
+    /// 通貨コード（ISO 4217。例: `JPY`・`USD`）
+    pub currency: String,
+}
+
+/// 所有している本の1冊の追加 - GraphQL入力用
+#[derive(Debug, Clone, InputObject)]
+pub struct AddCopyInput {
+    pub book_id: i32,
+    pub format: BookCopyFormat,
+    pub condition: Option<BookCopyCondition>,
+    pub acquired_on: Option<NaiveDate>,
+    /// 入手時の価格
+    pub price: Option<PriceInput>,
+    /// 置いてある場所（部屋・本棚・棚のいずれか）
+    pub location_id: Option<i32>,
+}
+
+/// 所有している本の1冊の更新 - GraphQL入力用
+///
+/// 省略した項目は変更せず、`null` を指定した項目は未設定に戻す。
+#[derive(Debug, Clone, Default, InputObject)]
+pub struct UpdateCopyInput {
+    pub format: Option<BookCopyFormat>,
+    pub condition: MaybeUndefined<BookCopyCondition>,
+    pub acquired_on: MaybeUndefined<NaiveDate>,
+    /// 入手時の価格
+    pub price: MaybeUndefined<PriceInput>,
+    /// 置いてある場所（部屋・本棚・棚のいずれか）
+    pub location_id: MaybeUndefined<i32>,
+}
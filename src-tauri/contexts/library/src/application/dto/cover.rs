@@ -0,0 +1,61 @@
+// Library Application Layer - 表紙画像の URL
+
+use crate::domain::repositories::cover::CoverSize;
+use crate::domain::value_objects::cover_hash::CoverHash;
+
+/// 表紙画像を webview に配信するカスタムプロトコルのスキーム名
+pub const COVER_PROTOCOL: &str = "lifebook-cover";
+
+/// 画像・サムネイルのURLのパスの接頭辞
+const FULL_PATH_PREFIX: &str = "/covers/";
+const THUMBNAIL_PATH_PREFIX: &str = "/thumbnails/";
+/// URLのファイル名の拡張子
+const FILE_EXTENSION: &str = ".jpg";
+
+/// 表紙画像のURL（webview からカスタムプロトコル経由で読み込む）
+///
+/// Windows・Android の webview はカスタムスキームを直接扱えないため、
+/// Tauri の規約に従って `http://<スキーム>.localhost` の形式にする。
+pub fn cover_url(hash: &CoverHash, size: CoverSize) -> String {
+    let prefix = match size {
+        CoverSize::Full => FULL_PATH_PREFIX,
+        CoverSize::Thumbnail => THUMBNAIL_PATH_PREFIX,
+    };
+    let origin = if cfg!(any(windows, target_os = "android")) {
+        format!("http://{}.localhost", COVER_PROTOCOL)
+    } else {
+        format!("{}://localhost", COVER_PROTOCOL)
+    };
+    format!("{}{}{}{}", origin, prefix, hash, FILE_EXTENSION)
+}
+
+/// カスタムプロトコルで要求されたURLのパスを画像のハッシュと大きさに変換する
+pub fn parse_cover_path(path: &str) -> Option<(CoverHash, CoverSize)> {
+    let (size, file_name) = if let Some(name) = path.strip_prefix(FULL_PATH_PREFIX) {
+        (CoverSize::Full, name)
+    } else {
+        (
+            CoverSize::Thumbnail,
+            path.strip_prefix(THUMBNAIL_PATH_PREFIX)?,
+        )
+    };
+    let hash = CoverHash::parse(file_name.strip_suffix(FILE_EXTENSION)?).ok()?;
+    Some((hash, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cover_url_round_trip() {
+        let hash = CoverHash::of(b"cover");
+        for size in [CoverSize::Full, CoverSize::Thumbnail] {
+            let url = cover_url(&hash, size);
+            let path = &url[url.find("localhost").unwrap() + "localhost".len()..];
+            assert_eq!(parse_cover_path(path), Some((hash.clone(), size)));
+        }
+        assert_eq!(parse_cover_path("/covers/../secret.jpg"), None);
+        assert_eq!(parse_cover_path("/other/abc.jpg"), None);
+    }
+}
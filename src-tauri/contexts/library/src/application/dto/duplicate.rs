@@ -0,0 +1,52 @@
+// Library Application Layer - 重複した本 データ転送オブジェクト
+
+use crate::application::dto::book::BookDto;
+use async_graphql::{Enum, InputObject, SimpleObject};
+
+/// 重複とみなした根拠
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Enum)]
+pub enum DuplicateReason {
+    /// ISBNが一致する
+    SameIsbn,
+    /// タイトルが一致する（大文字・小文字、全角・半角、カタカナ・ひらがな、記号・空白の違いは無視する）
+    SameTitle,
+    /// 著者が一致する（表記ゆれを吸収して比較する）
+    SimilarAuthors,
+    /// 出版年が一致する
+    SamePublishedYear,
+}
+
+/// 重複している可能性がある本のグループ DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct DuplicateGroupDto {
+    /// グループ内の本（ID順）
+    pub books: Vec<BookDto>,
+    /// 重複である確からしさ（0.0〜1.0。グループ内で最も弱い結び付きの値）
+    pub confidence: f64,
+    pub reasons: Vec<DuplicateReason>,
+}
+
+/// 本をまとめる際に値を選べる項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Enum)]
+pub enum BookMergeField {
+    Title,
+    /// タイトル・著者の読み（並び替えの位置も変わる）
+    Readings,
+    /// 著者（表示用の著者名と、翻訳者などを含む著者一覧）
+    Authors,
+    Description,
+    PublishedYear,
+    Publisher,
+    Isbn,
+    Cover,
+    /// 分類記号（NDC・DDC）
+    Classification,
+}
+
+/// 項目の値をどの本から採るか - GraphQL入力用
+#[derive(Debug, Clone, InputObject)]
+pub struct MergeFieldChoiceInput {
+    pub field: BookMergeField,
+    /// 値を採る本のID（まとめ先またはまとめる本のいずれか）
+    pub book_id: i32,
+}
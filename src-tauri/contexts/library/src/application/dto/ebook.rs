@@ -0,0 +1,92 @@
+// Library Application Layer - 電子書籍ファイル データ転送オブジェクト
+
+use crate::application::dto::{author::BookAuthorRole, book::BookDto};
+use crate::domain::entities::book_file::BookFile;
+use crate::domain::value_objects::ebook_format::EbookFormat;
+use async_graphql::{Enum, SimpleObject};
+
+/// 電子書籍ファイルの形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum EbookFileFormat {
+    Epub,
+    Pdf,
+}
+
+impl From<EbookFormat> for EbookFileFormat {
+    fn from(format: EbookFormat) -> Self {
+        match format {
+            EbookFormat::Epub => Self::Epub,
+            EbookFormat::Pdf => Self::Pdf,
+        }
+    }
+}
+
+/// 本に紐付けた電子書籍ファイル DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct BookFileDto {
+    pub id: i32,
+    pub book_id: i32,
+    /// ファイルの絶対パス（このパスでファイルを開く）
+    pub path: String,
+    pub format: EbookFileFormat,
+    /// アプリが管理するコピーか（紐付けを外すとファイルも削除する）
+    pub managed: bool,
+}
+
+impl From<BookFile> for BookFileDto {
+    fn from(file: BookFile) -> Self {
+        Self {
+            id: file
+                .id()
+                .expect("BookFile must have an ID when converting to DTO"),
+            book_id: file.book_id(),
+            path: file.path().to_string_lossy().into_owned(),
+            format: file.format().into(),
+            managed: file.managed(),
+        }
+    }
+}
+
+/// 電子書籍ファイルの著者（役割付き） DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct EbookCreatorDto {
+    pub name: String,
+    pub role: BookAuthorRole,
+}
+
+/// 電子書籍ファイルの書誌情報 DTO - GraphQLレスポンス用（本の登録フォームの初期値）
+#[derive(Debug, Clone, SimpleObject)]
+pub struct EbookMetadataDto {
+    pub format: EbookFileFormat,
+    /// タイトル（ファイルにない場合はファイル名）
+    pub title: String,
+    /// 表示用の著者名（役割が著者の人物をカンマ区切りで連結）
+    pub author: Option<String>,
+    /// 著者・翻訳者などの一覧（ファイルでの記載順）
+    pub creators: Vec<EbookCreatorDto>,
+    /// 言語（LifeBookには言語がないため保存しない）
+    pub language: Option<String>,
+    /// ファイルに記載された識別子
+    pub identifiers: Vec<String>,
+    /// 識別子のうち最初の正しいISBN（ISBN-13、ハイフンなし）
+    pub isbn: Option<String>,
+    /// 出版社（新しく登録する本の出版社として保存する）
+    pub publisher: Option<String>,
+    /// 紹介文（HTMLはテキストに変換）
+    pub description: Option<String>,
+    pub published_year: Option<i32>,
+    /// 表紙画像が含まれているか
+    pub has_cover: bool,
+}
+
+/// 電子書籍ファイルの取り込み結果 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct EbookImportResultDto {
+    /// 登録した本、またはISBNが一致した登録済みの本
+    pub book: BookDto,
+    pub file: BookFileDto,
+    /// 本を新規登録したか
+    pub created: bool,
+    /// 無視した値の説明
+    pub messages: Vec<String>,
+}
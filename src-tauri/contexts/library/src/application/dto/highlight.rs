@@ -0,0 +1,110 @@
+// Library Application Layer - ハイライト・取り込み結果 データ転送オブジェクト
+
+use crate::domain::entities::highlight::Highlight;
+use crate::domain::value_objects::highlight_kind::HighlightKind;
+use async_graphql::{Enum, SimpleObject};
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// 取り込んだ抜き書きの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookHighlightKind {
+    Highlight,
+    Note,
+}
+
+impl From<HighlightKind> for BookHighlightKind {
+    fn from(kind: HighlightKind) -> Self {
+        match kind {
+            HighlightKind::Highlight => Self::Highlight,
+            HighlightKind::Note => Self::Note,
+        }
+    }
+}
+
+/// ハイライト DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct HighlightDto {
+    pub id: i32,
+    pub book_id: i32,
+    pub kind: BookHighlightKind,
+    pub content: String,
+    pub page: Option<i32>,
+    pub location_start: Option<i32>,
+    pub location_end: Option<i32>,
+    /// 端末上で作成された日時（端末のローカル時刻）
+    pub clipped_at: Option<NaiveDateTime>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Highlight> for HighlightDto {
+    fn from(highlight: Highlight) -> Self {
+        let location = highlight.location();
+        Self {
+            id: highlight
+                .id()
+                .expect("Highlight must have an ID when converting to DTO"),
+            book_id: highlight.book_id(),
+            kind: highlight.kind().into(),
+            content: highlight.content().to_string(),
+            page: location.page(),
+            location_start: location.start(),
+            location_end: location.end(),
+            clipped_at: highlight.clipped_at(),
+            created_at: highlight.created_at(),
+        }
+    }
+}
+
+/// 取り込み結果（項目ごと）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum ClippingImportStatus {
+    /// 新しく取り込んだ
+    Imported,
+    /// 取り込み済みのため読み飛ばした
+    Duplicate,
+    /// 取り込み対象外（ブックマーク・本文のない項目）
+    Skipped,
+    /// 解析・保存に失敗した
+    Failed,
+}
+
+/// 取り込み結果の1項目 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ClippingImportEntryDto {
+    /// ファイル内での順番（1始まり）
+    pub index: usize,
+    pub status: ClippingImportStatus,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// 対応付けた本のID
+    pub book_id: Option<i32>,
+    /// この項目のために本を新しく作成したか
+    pub book_created: bool,
+    pub highlight_id: Option<i32>,
+    /// 読み飛ばし・失敗の理由
+    pub message: Option<String>,
+}
+
+/// Kindle「My Clippings.txt」の取り込み結果 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct KindleImportReportDto {
+    pub imported_count: usize,
+    pub duplicate_count: usize,
+    pub skipped_count: usize,
+    pub failed_count: usize,
+    pub entries: Vec<ClippingImportEntryDto>,
+}
+
+impl KindleImportReportDto {
+    /// 項目ごとの結果から件数を集計して作成
+    pub fn new(entries: Vec<ClippingImportEntryDto>) -> Self {
+        let count = |status| entries.iter().filter(|e| e.status == status).count();
+        Self {
+            imported_count: count(ClippingImportStatus::Imported),
+            duplicate_count: count(ClippingImportStatus::Duplicate),
+            skipped_count: count(ClippingImportStatus::Skipped),
+            failed_count: count(ClippingImportStatus::Failed),
+            entries,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Library Application Layer - 本の変更履歴 データ転送オブジェクト
+
+use crate::domain::entities::book_change::{BookChange, BookField, FieldChange};
+use crate::domain::value_objects::{
+    book_change_action::BookChangeAction, book_change_source::BookChangeSource,
+};
+use async_graphql::{Enum, SimpleObject};
+use chrono::{DateTime, Utc};
+
+/// 変更履歴に記録する操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookChangeKind {
+    Create,
+    Update,
+    /// ゴミ箱に移す
+    Delete,
+    /// ゴミ箱から元に戻す
+    Restore,
+    /// 指定した履歴番号の状態への復元
+    Revert,
+    /// 直前の変更の取り消し
+    Undo,
+    /// 他の本をまとめた、または他の本にまとめてゴミ箱に移した
+    Merge,
+}
+
+impl From<BookChangeAction> for BookChangeKind {
+    fn from(action: BookChangeAction) -> Self {
+        match action {
+            BookChangeAction::Create => Self::Create,
+            BookChangeAction::Update => Self::Update,
+            BookChangeAction::Delete => Self::Delete,
+            BookChangeAction::Restore => Self::Restore,
+            BookChangeAction::Revert => Self::Revert,
+            BookChangeAction::Undo => Self::Undo,
+            BookChangeAction::Merge => Self::Merge,
+        }
+    }
+}
+
+/// 変更のきっかけ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookChangeOrigin {
+    /// ユーザーによる作成・編集・ゴミ箱への移動・復元
+    User,
+    /// CSV・Calibre・電子書籍ファイル・Kindle のハイライトなどの取り込み
+    Import,
+    /// 重複した本のまとめ
+    Merge,
+    /// 変更履歴からの取り消し・以前の版への復元
+    History,
+}
+
+impl From<BookChangeSource> for BookChangeOrigin {
+    fn from(source: BookChangeSource) -> Self {
+        match source {
+            BookChangeSource::User => Self::User,
+            BookChangeSource::Import => Self::Import,
+            BookChangeSource::Merge => Self::Merge,
+            BookChangeSource::History => Self::History,
+        }
+    }
+}
+
+/// 変更履歴で差分を表示する本の項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookHistoryField {
+    Title,
+    /// 著者（翻訳者などは役割を添える）
+    Authors,
+    TitleReading,
+    AuthorReading,
+    Description,
+    PublishedYear,
+    Publisher,
+    Isbn,
+    /// 日本十進分類法（NDC）の分類記号
+    Ndc,
+    /// デューイ十進分類法（DDC）の分類記号
+    Ddc,
+}
+
+impl From<BookField> for BookHistoryField {
+    fn from(field: BookField) -> Self {
+        match field {
+            BookField::Title => Self::Title,
+            BookField::Authors => Self::Authors,
+            BookField::TitleReading => Self::TitleReading,
+            BookField::AuthorReading => Self::AuthorReading,
+            BookField::Description => Self::Description,
+            BookField::PublishedYear => Self::PublishedYear,
+            BookField::Publisher => Self::Publisher,
+            BookField::Isbn => Self::Isbn,
+            BookField::Ndc => Self::Ndc,
+            BookField::Ddc => Self::Ddc,
+        }
+    }
+}
+
+/// 1項目の変更前後の値 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct FieldChangeDto {
+    pub field: BookHistoryField,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl From<FieldChange> for FieldChangeDto {
+    fn from(change: FieldChange) -> Self {
+        Self {
+            field: change.field.into(),
+            before: change.before,
+            after: change.after,
+        }
+    }
+}
+
+/// 本の変更履歴 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct BookChangeDto {
+    pub book_id: i32,
+    /// 本ごとの版番号（1から連番。`revertToVersion` で指定する。同時編集検出用の `Book.version` とは別の番号）
+    pub version: i32,
+    pub action: BookChangeKind,
+    /// 変更のきっかけ（ユーザーの操作・取り込み・まとめ・変更履歴からの復元）
+    pub source: BookChangeOrigin,
+    /// 変更した項目（ゴミ箱への移動・復元では空）
+    pub changes: Vec<FieldChangeDto>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<BookChange> for BookChangeDto {
+    fn from(change: BookChange) -> Self {
+        Self {
+            book_id: change.book_id(),
+            version: change.revision(),
+            action: change.action().into(),
+            source: change.source().into(),
+            changes: change
+                .changes()
+                .into_iter()
+                .map(FieldChangeDto::from)
+                .collect(),
+            changed_at: change.changed_at(),
+        }
+    }
+}
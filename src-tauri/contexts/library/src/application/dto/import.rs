@@ -0,0 +1,191 @@
+// Library Application Layer - 一括取り込み データ転送オブジェクト
+
+use crate::application::dto::{book::BookDto, reading::BookReadingStatus};
+use crate::application::importers::book_csv::BookCsvColumn;
+use async_graphql::{Enum, InputObject, SimpleObject};
+
+/// CSVの列（取り込み時の列の対応付け用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookCsvField {
+    /// 取り込み時は無視する
+    Id,
+    Title,
+    Author,
+    Description,
+    PublishedYear,
+    Isbn,
+}
+
+impl From<BookCsvField> for BookCsvColumn {
+    fn from(field: BookCsvField) -> Self {
+        match field {
+            BookCsvField::Id => Self::Id,
+            BookCsvField::Title => Self::Title,
+            BookCsvField::Author => Self::Author,
+            BookCsvField::Description => Self::Description,
+            BookCsvField::PublishedYear => Self::PublishedYear,
+            BookCsvField::Isbn => Self::Isbn,
+        }
+    }
+}
+
+/// ヘッダー名と列の対応 - GraphQL入力用（自動判定より優先する）
+#[derive(Debug, Clone, InputObject)]
+pub struct CsvColumnMappingInput {
+    /// CSVのヘッダー名（大文字・小文字は区別しない）
+    pub header: String,
+    pub field: BookCsvField,
+}
+
+/// 取り込めなかった行 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ImportRowErrorDto {
+    /// ファイル内の行番号（1始まり、ヘッダーを含む）
+    pub line: u64,
+    pub message: String,
+}
+
+/// 一括取り込みの結果 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct BookImportReportDto {
+    /// 登録した本の件数
+    pub imported_count: usize,
+    /// 登録を確定したか（エラーのある行があり、部分的な取り込みを許可していない場合は false）
+    pub committed: bool,
+    pub errors: Vec<ImportRowErrorDto>,
+    /// 登録した本
+    pub books: Vec<BookDto>,
+}
+
+/// 取り込み元のサービス
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum ExternalLibrarySource {
+    /// Goodreads の goodreads_library_export.csv
+    Goodreads,
+    /// StoryGraph の書き出しCSV
+    #[graphql(name = "STORYGRAPH")]
+    StoryGraph,
+}
+
+/// 取り込み時の1冊ごとの処理
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum ExternalImportAction {
+    /// 本を新しく登録する
+    Create,
+    /// 登録済みの本に不足している情報を追加する
+    Update,
+    /// 登録済みで追加する情報がない・ファイル内で重複している
+    Skip,
+    /// 解析・検証・保存に失敗した
+    Fail,
+}
+
+/// 取り込み結果の1冊分 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ExternalImportItemDto {
+    /// ファイル内の行番号（1始まり、ヘッダーを含む）
+    pub line: u64,
+    pub action: ExternalImportAction,
+    /// 対応付けた本のID（新規登録の場合は登録後のID。試行時はなし）
+    pub book_id: Option<i32>,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// ISBN-13（ハイフンなし）
+    pub isbn: Option<String>,
+    pub reading_status: Option<BookReadingStatus>,
+    /// 星の数（0.5刻みに丸めたもの）
+    pub rating: Option<f64>,
+    /// 取り込み元の棚・タグ（同じ名前のタグとして本に付ける）
+    pub shelves: Vec<String>,
+    /// 行う変更の説明
+    pub changes: Vec<String>,
+    /// 読み飛ばし・失敗の理由
+    pub message: Option<String>,
+}
+
+/// 他サービスからの取り込み結果 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ExternalImportReportDto {
+    /// 試行のみで何も保存していないか
+    pub dry_run: bool,
+    pub created_count: usize,
+    pub updated_count: usize,
+    pub skipped_count: usize,
+    pub failed_count: usize,
+    pub items: Vec<ExternalImportItemDto>,
+}
+
+impl ExternalImportReportDto {
+    /// 1冊ごとの結果から件数を集計して作成
+    pub fn new(dry_run: bool, items: Vec<ExternalImportItemDto>) -> Self {
+        let count = |action| items.iter().filter(|i| i.action == action).count();
+        Self {
+            dry_run,
+            created_count: count(ExternalImportAction::Create),
+            updated_count: count(ExternalImportAction::Update),
+            skipped_count: count(ExternalImportAction::Skip),
+            failed_count: count(ExternalImportAction::Fail),
+            items,
+        }
+    }
+}
+
+/// Calibre からの取り込み結果の1冊分 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CalibreImportItemDto {
+    /// Calibre での本のID
+    pub calibre_id: i32,
+    /// 新規登録・更新・変更なし・失敗（変更なしは SKIP）
+    pub action: ExternalImportAction,
+    /// 対応付けた本のID
+    pub book_id: Option<i32>,
+    pub title: String,
+    pub author: Option<String>,
+    /// 行った変更の説明
+    pub changes: Vec<String>,
+    /// シリーズ名（同じ名前のシリーズに巻として登録する）
+    pub series: Option<String>,
+    /// シリーズでの巻数
+    pub series_index: Option<f64>,
+    /// タグ（同じ名前のタグとして本に付ける）
+    pub tags: Vec<String>,
+    /// 出版社（本の出版社として保存する）
+    pub publisher: Option<String>,
+    /// 失敗の理由・無視した値の説明
+    pub message: Option<String>,
+}
+
+impl CalibreImportItemDto {
+    /// 無視した値の説明を追加する（既にある場合は `; ` で連結）
+    pub fn add_message(&mut self, message: String) {
+        self.message = Some(match self.message.take() {
+            Some(existing) => format!("{}; {}", existing, message),
+            None => message,
+        });
+    }
+}
+
+/// Calibre からの取り込み結果 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CalibreImportReportDto {
+    pub created_count: usize,
+    pub updated_count: usize,
+    /// 登録済みで変更がなかった本の数
+    pub unchanged_count: usize,
+    pub failed_count: usize,
+    pub items: Vec<CalibreImportItemDto>,
+}
+
+impl CalibreImportReportDto {
+    /// 1冊ごとの結果から件数を集計して作成
+    pub fn new(items: Vec<CalibreImportItemDto>) -> Self {
+        let count = |action| items.iter().filter(|i| i.action == action).count();
+        Self {
+            created_count: count(ExternalImportAction::Create),
+            updated_count: count(ExternalImportAction::Update),
+            unchanged_count: count(ExternalImportAction::Skip),
+            failed_count: count(ExternalImportAction::Fail),
+            items,
+        }
+    }
+}
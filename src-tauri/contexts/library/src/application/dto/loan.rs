@@ -0,0 +1,79 @@
+// Library Application Layer - 貸出・貸出相手 データ転送オブジェクト
+
+use crate::domain::entities::{borrower::Borrower, loan::Loan};
+use async_graphql::{Enum, SimpleObject};
+use chrono::NaiveDate;
+
+/// 貸出相手 DTO - GraphQLレスポンス用
+///
+/// 貸出の履歴は presentation 層の `ComplexObject` で解決する。
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct BorrowerDto {
+    pub id: i32,
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+impl From<Borrower> for BorrowerDto {
+    fn from(borrower: Borrower) -> Self {
+        Self {
+            id: borrower
+                .id()
+                .expect("Borrower must have an ID when converting to DTO"),
+            name: borrower.name().to_string(),
+            email: borrower.email().map(str::to_string),
+            phone: borrower.phone().map(str::to_string),
+        }
+    }
+}
+
+/// 貸出 DTO - GraphQLレスポンス用
+///
+/// 貸した冊・貸出相手は presentation 層の `ComplexObject` で解決する。
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct LoanDto {
+    pub id: i32,
+    pub copy_id: i32,
+    pub borrower_id: i32,
+    pub lent_on: NaiveDate,
+    /// 返却期限
+    pub due_on: Option<NaiveDate>,
+    /// 返却日（貸出中はなし）
+    pub returned_on: Option<NaiveDate>,
+}
+
+impl From<Loan> for LoanDto {
+    fn from(loan: Loan) -> Self {
+        Self {
+            id: loan
+                .id()
+                .expect("Loan must have an ID when converting to DTO"),
+            copy_id: loan.copy_id(),
+            borrower_id: loan.borrower_id(),
+            lent_on: loan.lent_on(),
+            due_on: loan.due_on(),
+            returned_on: loan.returned_on(),
+        }
+    }
+}
+
+/// 返却のリマインダーの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum LoanReminderKind {
+    /// 返却期限を過ぎている
+    Overdue,
+    /// 返却期限が近い（当日を含む）
+    DueSoon,
+}
+
+/// 返却のリマインダー DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct LoanReminderDto {
+    pub loan: LoanDto,
+    pub kind: LoanReminderKind,
+    /// 返却期限までの日数（当日は0、期限を過ぎている場合は負）
+    pub days_until_due: i64,
+}
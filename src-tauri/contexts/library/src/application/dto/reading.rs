@@ -0,0 +1,118 @@
+// Library Application Layer - 読書記録 データ転送オブジェクト
+
+use crate::domain::entities::read_through::{ReadThrough, ReadingSession};
+use crate::domain::value_objects::{rating::Rating, reading_status::ReadingStatus};
+use async_graphql::{Enum, InputObject, SimpleObject};
+use chrono::NaiveDate;
+
+/// 読書の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BookReadingStatus {
+    WantToRead,
+    Reading,
+    Finished,
+    Abandoned,
+}
+
+impl From<ReadingStatus> for BookReadingStatus {
+    fn from(status: ReadingStatus) -> Self {
+        match status {
+            ReadingStatus::WantToRead => Self::WantToRead,
+            ReadingStatus::Reading => Self::Reading,
+            ReadingStatus::Finished => Self::Finished,
+            ReadingStatus::Abandoned => Self::Abandoned,
+        }
+    }
+}
+
+impl From<BookReadingStatus> for ReadingStatus {
+    fn from(status: BookReadingStatus) -> Self {
+        match status {
+            BookReadingStatus::WantToRead => Self::WantToRead,
+            BookReadingStatus::Reading => Self::Reading,
+            BookReadingStatus::Finished => Self::Finished,
+            BookReadingStatus::Abandoned => Self::Abandoned,
+        }
+    }
+}
+
+/// 読書記録 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ReadingSessionDto {
+    pub id: i32,
+    pub read_on: NaiveDate,
+    /// 読み終えたページ
+    pub page: Option<i32>,
+    /// 読み終えた割合（0〜100）
+    pub percent: Option<f64>,
+    pub duration_minutes: Option<i32>,
+}
+
+impl From<&ReadingSession> for ReadingSessionDto {
+    fn from(session: &ReadingSession) -> Self {
+        Self {
+            id: session
+                .id()
+                .expect("ReadingSession must have an ID when converting to DTO"),
+            read_on: session.read_on(),
+            page: session.progress().as_page(),
+            percent: session.progress().as_percent(),
+            duration_minutes: session.duration_minutes(),
+        }
+    }
+}
+
+/// 通読 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ReadThroughDto {
+    pub id: i32,
+    pub book_id: i32,
+    pub status: BookReadingStatus,
+    pub started_on: Option<NaiveDate>,
+    pub finished_on: Option<NaiveDate>,
+    /// この通読での評価（0.5〜5.0、0.5刻み）
+    pub rating: Option<f64>,
+    /// 最新の進捗（ページ）
+    pub current_page: Option<i32>,
+    /// 最新の進捗（割合）
+    pub current_percent: Option<f64>,
+    /// 読書記録（日付順）
+    pub sessions: Vec<ReadingSessionDto>,
+}
+
+impl From<ReadThrough> for ReadThroughDto {
+    fn from(read_through: ReadThrough) -> Self {
+        Self {
+            id: read_through
+                .id()
+                .expect("ReadThrough must have an ID when converting to DTO"),
+            book_id: read_through.book_id(),
+            status: read_through.status().into(),
+            started_on: read_through.started_on(),
+            finished_on: read_through.finished_on(),
+            rating: read_through.rating().map(Rating::stars),
+            current_page: read_through.progress().and_then(|p| p.as_page()),
+            current_percent: read_through.progress().and_then(|p| p.as_percent()),
+            sessions: read_through
+                .sessions()
+                .iter()
+                .map(ReadingSessionDto::from)
+                .collect(),
+        }
+    }
+}
+
+/// 読書記録の追加 - GraphQL入力用
+///
+/// 進捗は `page` と `percent` のどちらか一方を指定する。
+#[derive(Debug, Clone, InputObject)]
+pub struct LogReadingSessionInput {
+    pub book_id: i32,
+    /// 読んだ日（省略時は今日）
+    pub read_on: Option<NaiveDate>,
+    /// 読み終えたページ
+    pub page: Option<i32>,
+    /// 読み終えた割合（0〜100）
+    pub percent: Option<f64>,
+    pub duration_minutes: Option<i32>,
+}
@@ -0,0 +1,57 @@
+// Library Application Layer - 評価・感想・メモ データ転送オブジェクト
+
+use crate::domain::entities::{note::Note, review::Review};
+use crate::domain::value_objects::rating::Rating;
+use async_graphql::SimpleObject;
+use chrono::{DateTime, Utc};
+
+/// 評価・感想 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ReviewDto {
+    pub id: i32,
+    pub book_id: i32,
+    /// 星の数（0.5〜5.0、0.5刻み）
+    pub rating: Option<f64>,
+    pub body: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Review> for ReviewDto {
+    fn from(review: Review) -> Self {
+        Self {
+            id: review
+                .id()
+                .expect("Review must have an ID when converting to DTO"),
+            book_id: review.book_id(),
+            rating: review.rating().map(Rating::stars),
+            body: review.body().map(String::from),
+            created_at: review.created_at(),
+            updated_at: review.updated_at(),
+        }
+    }
+}
+
+/// メモ DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct NoteDto {
+    pub id: i32,
+    pub book_id: i32,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Note> for NoteDto {
+    fn from(note: Note) -> Self {
+        Self {
+            id: note
+                .id()
+                .expect("Note must have an ID when converting to DTO"),
+            book_id: note.book_id(),
+            body: note.body().to_string(),
+            created_at: note.created_at(),
+            updated_at: note.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// Library Application Layer - シリーズ データ転送オブジェクト
+
+use crate::application::dto::book::BookDto;
+use crate::domain::entities::series::Series;
+use crate::domain::value_objects::series_status::SeriesStatus;
+use async_graphql::{Enum, SimpleObject};
+
+/// シリーズの刊行状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum SeriesPublicationStatus {
+    /// 刊行中
+    Ongoing,
+    /// 完結
+    Completed,
+}
+
+impl From<SeriesStatus> for SeriesPublicationStatus {
+    fn from(status: SeriesStatus) -> Self {
+        match status {
+            SeriesStatus::Ongoing => Self::Ongoing,
+            SeriesStatus::Completed => Self::Completed,
+        }
+    }
+}
+
+impl From<SeriesPublicationStatus> for SeriesStatus {
+    fn from(status: SeriesPublicationStatus) -> Self {
+        match status {
+            SeriesPublicationStatus::Ongoing => Self::Ongoing,
+            SeriesPublicationStatus::Completed => Self::Completed,
+        }
+    }
+}
+
+/// シリーズ DTO - GraphQLレスポンス用
+///
+/// 巻の一覧・欠けている巻は presentation 層の `ComplexObject` で解決する。
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct SeriesDto {
+    pub id: i32,
+    pub name: String,
+    /// 読み（かな）
+    pub reading: Option<String>,
+    /// 並び替え用のキー（読み、なければ名前を正規化したもの）
+    pub sort_key: String,
+    pub status: SeriesPublicationStatus,
+    /// 刊行済みの巻数
+    pub volume_count: Option<i32>,
+}
+
+impl From<Series> for SeriesDto {
+    fn from(series: Series) -> Self {
+        Self {
+            id: series
+                .id()
+                .expect("Series must have an ID when converting to DTO"),
+            name: series.name().to_string(),
+            reading: series.reading().map(String::from),
+            sort_key: series.sort_key().as_str().to_string(),
+            status: series.status().into(),
+            volume_count: series.volume_count(),
+        }
+    }
+}
+
+/// シリーズの1巻分 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct SeriesVolumeDto {
+    /// 巻数（1.5 などの小数を含む）
+    pub volume: f64,
+    pub book: BookDto,
+}
+
+/// 本が属するシリーズと巻数 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct BookSeriesDto {
+    pub series: SeriesDto,
+    /// 巻数（1.5 などの小数を含む）
+    pub volume: f64,
+}
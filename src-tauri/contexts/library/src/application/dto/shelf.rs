@@ -0,0 +1,128 @@
+// Library Application Layer - 本棚 データ転送オブジェクト
+
+use crate::domain::entities::shelf::Shelf;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::book::BookFilter;
+use crate::domain::value_objects::{
+    class_number::{ClassPrefix, ClassificationScheme},
+    tag_name::TagName,
+};
+use async_graphql::{InputObject, SimpleObject};
+
+/// 本棚 DTO - GraphQLレスポンス用
+///
+/// 並べた本は presentation 層の `ComplexObject` で解決する。
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct ShelfDto {
+    pub id: i32,
+    pub name: String,
+    /// 本棚一覧での表示順（0始まり）
+    pub position: i32,
+    /// スマート本棚か（条件に一致する本を並べ、本を個別に追加できない）
+    pub smart: bool,
+    /// スマート本棚の絞り込み条件
+    pub smart_filter: Option<SmartShelfFilterDto>,
+}
+
+impl From<Shelf> for ShelfDto {
+    fn from(shelf: Shelf) -> Self {
+        Self {
+            id: shelf
+                .id()
+                .expect("Shelf must have an ID when converting to DTO"),
+            name: shelf.name().to_string(),
+            position: shelf.position(),
+            smart: shelf.is_smart(),
+            smart_filter: shelf.smart_filter().map(SmartShelfFilterDto::from),
+        }
+    }
+}
+
+/// スマート本棚の絞り込み条件 DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct SmartShelfFilterDto {
+    /// 著者名（部分一致）
+    pub author: Option<String>,
+    /// タイトル（部分一致）
+    pub title_contains: Option<String>,
+    /// 出版年の下限（この年を含む）
+    pub published_year_from: Option<i32>,
+    /// 出版年の上限（この年を含む）
+    pub published_year_to: Option<i32>,
+    /// タグ（子孫のタグが付いた本も含む）
+    pub tag: Option<String>,
+    /// 日本十進分類法（NDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む）
+    pub ndc: Option<String>,
+    /// デューイ十進分類法（DDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む）
+    pub ddc: Option<String>,
+}
+
+impl From<&BookFilter> for SmartShelfFilterDto {
+    fn from(filter: &BookFilter) -> Self {
+        Self {
+            author: filter.author.clone(),
+            title_contains: filter.title_contains.clone(),
+            published_year_from: filter.published_year_from,
+            published_year_to: filter.published_year_to,
+            tag: filter.tag.as_ref().map(|tag| tag.as_str().to_string()),
+            ndc: filter.ndc.as_ref().map(|ndc| ndc.as_str().to_string()),
+            ddc: filter.ddc.as_ref().map(|ddc| ddc.as_str().to_string()),
+        }
+    }
+}
+
+/// スマート本棚の絞り込み条件 - GraphQL入力用
+#[derive(Debug, Clone, Default, InputObject)]
+pub struct SmartShelfFilterInput {
+    /// 著者名（部分一致）
+    pub author: Option<String>,
+    /// タイトル（部分一致）
+    pub title_contains: Option<String>,
+    /// 出版年の下限（この年を含む）
+    pub published_year_from: Option<i32>,
+    /// 出版年の上限（この年を含む）
+    pub published_year_to: Option<i32>,
+    /// タグ（子孫のタグが付いた本も含む）
+    pub tag: Option<String>,
+    /// 日本十進分類法（NDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む）
+    pub ndc: Option<String>,
+    /// デューイ十進分類法（DDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む）
+    pub ddc: Option<String>,
+}
+
+impl TryFrom<SmartShelfFilterInput> for BookFilter {
+    type Error = DomainError;
+
+    fn try_from(input: SmartShelfFilterInput) -> Result<Self, Self::Error> {
+        // 空文字列は未指定扱い
+        let class_prefix = |scheme, input: Option<String>| {
+            input
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| ClassPrefix::parse(scheme, &value))
+                .transpose()
+        };
+
+        Ok(Self {
+            author: input
+                .author
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty()),
+            title_contains: input
+                .title_contains
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty()),
+            published_year_from: input.published_year_from,
+            published_year_to: input.published_year_to,
+            tag: input
+                .tag
+                .filter(|t| !t.trim().is_empty())
+                .map(|t| TagName::new(&t))
+                .transpose()?,
+            ndc: class_prefix(ClassificationScheme::Ndc, input.ndc)?,
+            ddc: class_prefix(ClassificationScheme::Ddc, input.ddc)?,
+            // スマート本棚は作成日時・更新日時の条件を持たない
+            ..Default::default()
+        })
+    }
+}
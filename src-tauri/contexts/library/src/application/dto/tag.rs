@@ -0,0 +1,33 @@
+// Library Application Layer - タグ データ転送オブジェクト
+
+use crate::domain::entities::tag::Tag;
+use async_graphql::SimpleObject;
+
+/// タグ DTO - GraphQLレスポンス用
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TagDto {
+    pub id: i32,
+    /// 最上位からのパス（`親/子` 形式）
+    pub name: String,
+    /// 最後の階層の名前
+    pub label: String,
+    /// 親のタグ名（最上位の場合はなし）
+    pub parent: Option<String>,
+    /// 階層の深さ（最上位は0）
+    pub depth: usize,
+}
+
+impl From<Tag> for TagDto {
+    fn from(tag: Tag) -> Self {
+        let name = tag.name();
+        Self {
+            id: tag
+                .id()
+                .expect("Tag must have an ID when converting to DTO"),
+            name: name.as_str().to_string(),
+            label: name.label().to_string(),
+            parent: name.parent().map(|parent| parent.as_str().to_string()),
+            depth: name.depth(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+// Library Application Layer - Exporters（外部ツール向けのデータ形式への書き出し）
+
+pub mod citation;
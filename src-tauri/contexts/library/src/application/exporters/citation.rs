@@ -0,0 +1,470 @@
+// Library Application Layer - 引用文献の形式（BibTeX・RIS・CSL-JSON）への書き出し
+//
+// 引用キーは本の内容から `著者の姓 + 出版年 + タイトルの最初の語`（英数字のみ、小文字）で作る:
+//
+//     herbert1965dune
+//
+// キーは初めて書き出すときに割り当てて保存し、以後は本や他の本を変更・削除しても変えない。
+// 割り当て済みのキーと重なる場合は a, b, … を付ける。英数字が残らない場合は `book<ID>` とする。
+
+use crate::domain::entities::book::Book;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::author_role::AuthorRole;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// キーのタイトル部分に使わない語
+const TITLE_STOP_WORDS: [&str; 12] = [
+    "a", "an", "the", "of", "on", "in", "and", "to", "for", "at", "by", "with",
+];
+
+/// 本ごとの引用キー（本のID → キー）
+#[derive(Debug, Clone, Default)]
+pub struct CitationKeys {
+    keys: HashMap<i32, String>,
+}
+
+impl CitationKeys {
+    /// 割り当て済みのキーから作成
+    pub fn new(keys: HashMap<i32, String>) -> Self {
+        Self { keys }
+    }
+
+    /// キーのない本にIDの順にキーを割り当て、新しく割り当てたキー（本のID, キー）を返す
+    ///
+    /// 割り当て済みのキーは変えないため、他の本の変更や削除でキーがずれることはない。
+    pub fn assign_missing(&mut self, books: &[Book]) -> Vec<(i32, String)> {
+        let mut sorted: Vec<i32> = books
+            .iter()
+            .filter_map(Book::id)
+            .filter(|id| !self.keys.contains_key(id))
+            .collect();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        let mut used: HashSet<String> = self.keys.values().cloned().collect();
+        let mut assigned = Vec::with_capacity(sorted.len());
+        for id in sorted {
+            let book = books
+                .iter()
+                .find(|book| book.id() == Some(id))
+                .expect("Book must exist");
+            let base = Some(base_key(book))
+                .filter(|base| !base.is_empty())
+                .unwrap_or_else(|| format!("book{}", id));
+            let key = (0usize..)
+                .map(|n| match n {
+                    0 => base.clone(),
+                    n @ 1..=26 => format!(
+                        "{}{}",
+                        base,
+                        char::from(b'a' + u8::try_from(n - 1).unwrap_or_default())
+                    ),
+                    n => format!("{}{}", base, n),
+                })
+                .find(|key| !used.contains(key))
+                .expect("Unused key must exist");
+            used.insert(key.clone());
+            self.keys.insert(id, key.clone());
+            assigned.push((id, key));
+        }
+
+        assigned
+    }
+
+    /// 本の引用キー（キーを割り当てていない本は `book<ID>`）
+    pub fn key_for(&self, book: &Book) -> String {
+        let id = book.id().unwrap_or_default();
+        self.keys
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| format!("book{}", id))
+    }
+}
+
+/// BibTeX（`@book` エントリ）で書き出す
+pub fn write_bibtex(books: &[Book], keys: &CitationKeys) -> String {
+    let mut output = String::new();
+    for book in books {
+        let mut fields = Vec::new();
+        for (field, role) in [
+            ("author", AuthorRole::Author),
+            ("editor", AuthorRole::Editor),
+            ("translator", AuthorRole::Translator),
+            ("illustrator", AuthorRole::Illustrator),
+        ] {
+            let names = names(book, role);
+            if !names.is_empty() {
+                let names: Vec<String> = names.iter().map(|name| bibtex_name(name)).collect();
+                fields.push((field, format!("{{{}}}", names.join(" and "))));
+            }
+        }
+        // 二重の波括弧で大文字・小文字を保つ
+        fields.push(("title", format!("{{{{{}}}}}", escape_latex(book.title()))));
+        if let Some(year) = book.published_year() {
+            fields.push(("year", format!("{{{}}}", year)));
+        }
+        if let Some(isbn) = book.isbn() {
+            fields.push(("isbn", format!("{{{}}}", isbn.as_isbn13())));
+        }
+        if let Some(description) = book.description() {
+            fields.push(("abstract", format!("{{{}}}", escape_latex(description))));
+        }
+
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&format!("@book{{{},\n", keys.key_for(book)));
+        for (field, value) in fields {
+            output.push_str(&format!("  {} = {},\n", field, value));
+        }
+        output.push_str("}\n");
+    }
+    output
+}
+
+/// RIS（`TY  - BOOK` のレコード）で書き出す
+pub fn write_ris(books: &[Book], keys: &CitationKeys) -> String {
+    let mut output = String::new();
+    for book in books {
+        let mut lines = vec![("TY", "BOOK".to_string()), ("ID", keys.key_for(book))];
+        for (tag, role) in [
+            ("AU", AuthorRole::Author),
+            ("ED", AuthorRole::Editor),
+            ("A4", AuthorRole::Translator),
+        ] {
+            lines.extend(names(book, role).into_iter().map(|name| (tag, name)));
+        }
+        lines.push(("TI", book.title().to_string()));
+        if let Some(year) = book.published_year() {
+            lines.push(("PY", year.to_string()));
+        }
+        if let Some(isbn) = book.isbn() {
+            lines.push(("SN", isbn.as_isbn13().to_string()));
+        }
+        if let Some(description) = book.description() {
+            lines.push(("AB", description.to_string()));
+        }
+
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        for (tag, value) in lines {
+            // RIS の値は1行に収める
+            let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            output.push_str(&format!("{}  - {}\n", tag, value));
+        }
+        output.push_str("ER  - \n");
+    }
+    output
+}
+
+/// CSL-JSON の人物名
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum CslName {
+    Structured { family: String, given: String },
+    Literal { literal: String },
+}
+
+/// CSL-JSON の日付
+#[derive(Debug, Serialize)]
+struct CslDate {
+    #[serde(rename = "date-parts")]
+    date_parts: Vec<Vec<i32>>,
+}
+
+/// CSL-JSON の1件分
+#[derive(Debug, Serialize)]
+struct CslItem {
+    id: String,
+    #[serde(rename = "type")]
+    item_type: &'static str,
+    title: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    author: Vec<CslName>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    editor: Vec<CslName>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    translator: Vec<CslName>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    illustrator: Vec<CslName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issued: Option<CslDate>,
+    #[serde(rename = "ISBN", skip_serializing_if = "Option::is_none")]
+    isbn: Option<String>,
+    #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+/// CSL-JSON（項目の配列）で書き出す
+pub fn write_csl_json(books: &[Book], keys: &CitationKeys) -> Result<String, DomainError> {
+    let csl_names = |book: &Book, role| -> Vec<CslName> {
+        names(book, role)
+            .iter()
+            .map(|name| csl_name(name))
+            .collect()
+    };
+    let items: Vec<CslItem> = books
+        .iter()
+        .map(|book| CslItem {
+            id: keys.key_for(book),
+            item_type: "book",
+            title: book.title().to_string(),
+            author: csl_names(book, AuthorRole::Author),
+            editor: csl_names(book, AuthorRole::Editor),
+            translator: csl_names(book, AuthorRole::Translator),
+            illustrator: csl_names(book, AuthorRole::Illustrator),
+            issued: book.published_year().map(|year| CslDate {
+                date_parts: vec![vec![year]],
+            }),
+            isbn: book.isbn().map(|isbn| isbn.as_isbn13().to_string()),
+            description: book.description().map(String::from),
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&items)
+        .map_err(|e| DomainError::IoError(format!("CSL-JSON write error: {}", e)))
+}
+
+/// 指定した役割の人物名（表示順）
+///
+/// 著者一覧が未設定の本は、表示用の著者名を1人の著者として扱う。
+fn names(book: &Book, role: AuthorRole) -> Vec<String> {
+    if book.authors().is_empty() {
+        return match (role, book.author()) {
+            (AuthorRole::Author, Some(author)) => vec![author.to_string()],
+            _ => Vec::new(),
+        };
+    }
+    book.authors()
+        .iter()
+        .filter(|a| a.role() == role)
+        .map(|a| a.author().name().to_string())
+        .collect()
+}
+
+/// 姓（`姓, 名` 形式はカンマの前、それ以外は最後の語）
+fn family_name(name: &str) -> &str {
+    match name.split_once(',') {
+        Some((family, _)) => family.trim(),
+        None => name.split_whitespace().last().unwrap_or_default(),
+    }
+}
+
+/// キーの元になる文字列（著者・編者の姓 + 出版年 + タイトルの最初の語）
+fn base_key(book: &Book) -> String {
+    let person = names(book, AuthorRole::Author)
+        .into_iter()
+        .next()
+        .or_else(|| names(book, AuthorRole::Editor).into_iter().next());
+    let person = person.map(|name| key_part(family_name(&name)));
+    let title = book
+        .title()
+        .split_whitespace()
+        .map(key_part)
+        .find(|word| !word.is_empty() && !TITLE_STOP_WORDS.contains(&word.as_str()));
+
+    let mut key = person.unwrap_or_default();
+    if let Some(year) = book.published_year() {
+        // 著者もタイトルの英数字もない場合は年だけのキーにしない
+        if !key.is_empty() || title.is_some() {
+            key.push_str(&year.to_string());
+        }
+    }
+    key.push_str(&title.unwrap_or_default());
+    key
+}
+
+/// キーに使える文字（英数字を小文字化し、アクセント記号付きのラテン文字は基本の文字にする）
+fn key_part(value: &str) -> String {
+    let mut part = String::new();
+    for c in value.chars() {
+        if c.is_ascii_alphanumeric() {
+            part.push(c.to_ascii_lowercase());
+        } else if let Some(folded) = fold_latin(c.to_lowercase().next().unwrap_or(c)) {
+            part.push_str(folded);
+        }
+    }
+    part
+}
+
+/// アクセント記号付きのラテン文字を基本の文字にする
+fn fold_latin(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => "a",
+        'æ' => "ae",
+        'ç' | 'č' | 'ć' => "c",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ě' => "e",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' => "i",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'š' | 'ś' => "s",
+        'ß' => "ss",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' => "u",
+        'ý' | 'ÿ' => "y",
+        'ž' | 'ź' | 'ż' => "z",
+        'ł' => "l",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+/// LaTeX の特殊文字をエスケープする
+fn escape_latex(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '&' | '%' | '$' | '#' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// BibTeX の人物名（`and` を含む団体名などは波括弧で1つの名前にする）
+fn bibtex_name(name: &str) -> String {
+    let escaped = escape_latex(name);
+    if name.split_whitespace().any(|word| word == "and") {
+        format!("{{{}}}", escaped)
+    } else {
+        escaped
+    }
+}
+
+/// CSL-JSON の人物名（`姓, 名` または `名 姓` のラテン文字の名前のみ分割する）
+fn csl_name(name: &str) -> CslName {
+    if let Some((family, given)) = name.split_once(',') {
+        return CslName::Structured {
+            family: family.trim().to_string(),
+            given: given.trim().to_string(),
+        };
+    }
+    match name.rsplit_once(char::is_whitespace) {
+        Some((given, family)) if name.is_ascii() => CslName::Structured {
+            family: family.to_string(),
+            given: given.trim().to_string(),
+        },
+        _ => CslName::Literal {
+            literal: name.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(id: i32, title: &str, author: Option<&str>, year: Option<i32>) -> Book {
+        Book::reconstruct(
+            id,
+            title.to_string(),
+            author.map(String::from),
+            None,
+            year,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_keys_are_stable_and_unique() {
+        let books = vec![
+            book(3, "Dune Messiah", Some("Frank Herbert"), Some(1965)),
+            book(1, "Dune", Some("Herbert, Frank"), Some(1965)),
+            book(
+                2,
+                "The Dune Encyclopedia",
+                Some("Frank Herbert"),
+                Some(1965),
+            ),
+            book(4, "吾輩は猫である", Some("夏目漱石"), Some(1905)),
+            book(5, "Ærø: A Story", Some("Søren Kierkegaard"), None),
+        ];
+        let mut keys = CitationKeys::default();
+        let assigned = keys.assign_missing(&books);
+
+        assert_eq!(assigned.len(), 5);
+        assert_eq!(keys.key_for(&books[1]), "herbert1965dune");
+        assert_eq!(keys.key_for(&books[2]), "herbert1965dunea");
+        assert_eq!(keys.key_for(&books[0]), "herbert1965duneb");
+        assert_eq!(keys.key_for(&books[3]), "book4");
+        assert_eq!(keys.key_for(&books[4]), "kierkegaardaero");
+
+        // 割り当て済みのキーは、同じキーになる本がなくなっても変えない
+        let mut keys = CitationKeys::new(HashMap::from([(2, "herbert1965dunea".to_string())]));
+        let assigned = keys.assign_missing(&books[2..=2]);
+        assert!(assigned.is_empty());
+        assert_eq!(keys.key_for(&books[2]), "herbert1965dunea");
+
+        // 新しい本には使われていないキーを割り当てる
+        let assigned = keys.assign_missing(&[book(6, "Dune", Some("Frank Herbert"), Some(1965))]);
+        assert_eq!(assigned, [(6, "herbert1965dune".to_string())]);
+    }
+
+    #[test]
+    fn test_write_bibtex_escapes_latex() {
+        let mut dune = book(1, "R&D: 100% {Dune}_1", Some("Frank Herbert"), Some(1965));
+        dune.update_details(None, None, Some("~$5 \\ #1^2".to_string()), None, None)
+            .unwrap();
+        let books = vec![dune, book(2, "Untitled", None, None)];
+        let mut keys = CitationKeys::default();
+        keys.assign_missing(&books);
+
+        assert_eq!(
+            write_bibtex(&books, &keys),
+            "@book{herbert1965rd,\n\
+             \x20 author = {Frank Herbert},\n\
+             \x20 title = {{R\\&D: 100\\% \\{Dune\\}\\_1}},\n\
+             \x20 year = {1965},\n\
+             \x20 abstract = {\\textasciitilde{}\\$5 \\textbackslash{} \\#1\\textasciicircum{}2},\n\
+             }\n\
+             \n\
+             @book{untitled,\n\
+             \x20 title = {{Untitled}},\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_write_ris_and_csl_json() {
+        let mut dune = book(1, "Dune", Some("Herbert, Frank"), Some(1965));
+        dune.update_details(
+            None,
+            None,
+            Some("Line one\nline two".to_string()),
+            None,
+            None,
+        )
+        .unwrap();
+        let books = vec![dune];
+        let mut keys = CitationKeys::default();
+        keys.assign_missing(&books);
+
+        assert_eq!(
+            write_ris(&books, &keys),
+            "TY  - BOOK\nID  - herbert1965dune\nAU  - Herbert, Frank\nTI  - Dune\nPY  - 1965\nAB  - Line one line two\nER  - \n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&write_csl_json(&books, &keys).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "id": "herbert1965dune",
+                "type": "book",
+                "title": "Dune",
+                "author": [{ "family": "Herbert", "given": "Frank" }],
+                "issued": { "date-parts": [[1965]] },
+                "abstract": "Line one\nline two"
+            }])
+        );
+    }
+}
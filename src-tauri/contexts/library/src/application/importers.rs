@@ -0,0 +1,7 @@
+// Library Application Layer - Importers（外部サービス・端末のデータ形式の解析）
+
+pub mod book_csv;
+pub mod goodreads;
+pub mod kindle;
+pub mod library_export;
+pub mod storygraph;
@@ -0,0 +1,263 @@
+// Library Application Layer - 本の一覧のCSV形式の読み書き
+//
+// 書き出しの列は `BookCsvColumn::ALL` の順（1行目はヘッダー）:
+//
+//     id,title,author,description,published_year,isbn
+//
+// 取り込み時はヘッダー名で列を判定するため、列の順序は問わない。
+
+use crate::domain::entities::book::Book;
+use crate::domain::errors::DomainError;
+use std::collections::HashMap;
+
+/// CSVの列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BookCsvColumn {
+    /// 本のID（書き出しのみ。取り込み時は無視して新しい本として登録する）
+    Id,
+    Title,
+    Author,
+    Description,
+    PublishedYear,
+    /// ISBN-10 / ISBN-13（ハイフン有無は問わない）
+    Isbn,
+}
+
+impl BookCsvColumn {
+    /// 書き出し時の列（この順で出力する）
+    pub const ALL: [Self; 6] = [
+        Self::Id,
+        Self::Title,
+        Self::Author,
+        Self::Description,
+        Self::PublishedYear,
+        Self::Isbn,
+    ];
+
+    /// 書き出し時のヘッダー名
+    pub fn header(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Author => "author",
+            Self::Description => "description",
+            Self::PublishedYear => "published_year",
+            Self::Isbn => "isbn",
+        }
+    }
+
+    /// ヘッダー名から列を判定（大文字・小文字や空白・ハイフンの違い、日本語の列名も受け付ける）
+    pub fn from_header(header: &str) -> Option<Self> {
+        match normalize_header(header).as_str() {
+            "id" => Some(Self::Id),
+            "title" | "タイトル" | "書名" => Some(Self::Title),
+            "author" | "authors" | "著者" | "著者名" => Some(Self::Author),
+            "description" | "説明" | "内容" => Some(Self::Description),
+            "published_year" | "publishedyear" | "year" | "出版年" | "発行年" => {
+                Some(Self::PublishedYear)
+            }
+            "isbn" | "isbn13" | "isbn_13" | "isbn10" | "isbn_10" => Some(Self::Isbn),
+            _ => None,
+        }
+    }
+}
+
+/// CSVの1行（取り込み時。空欄は None）
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BookCsvRow {
+    /// ファイル内の行番号（1始まり、ヘッダーを含む）
+    pub line: u64,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    pub published_year: Option<String>,
+    pub isbn: Option<String>,
+}
+
+/// 読み込めなかった行
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookCsvRowError {
+    pub line: u64,
+    pub message: String,
+}
+
+/// ヘッダー行で列の対応を決めて各行を読み込む
+///
+/// `overrides` に指定したヘッダー名の対応は自動判定より優先する。
+/// タイトルの列が見つからない場合はファイル全体をエラーとする。
+pub fn read_rows(
+    content: &str,
+    overrides: &[(String, BookCsvColumn)],
+) -> Result<Vec<Result<BookCsvRow, BookCsvRowError>>, DomainError> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.trim_start_matches('\u{feff}').as_bytes());
+
+    let headers = reader
+        .headers()
+        .map_err(|e| DomainError::ValidationError(format!("Invalid CSV header: {}", e)))?
+        .clone();
+    let overrides: HashMap<String, BookCsvColumn> = overrides
+        .iter()
+        .map(|(header, column)| (normalize_header(header), *column))
+        .collect();
+    let columns: Vec<Option<BookCsvColumn>> = headers
+        .iter()
+        .map(|header| {
+            overrides
+                .get(&normalize_header(header))
+                .copied()
+                .or_else(|| BookCsvColumn::from_header(header))
+        })
+        .collect();
+    if !columns.contains(&Some(BookCsvColumn::Title)) {
+        return Err(DomainError::ValidationError(
+            "CSV must have a title column".to_string(),
+        ));
+    }
+
+    let rows = reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(|e| BookCsvRowError {
+                line: e.position().map_or(0, ::csv::Position::line),
+                message: format!("Invalid CSV row: {}", e),
+            })?;
+
+            let mut row = BookCsvRow {
+                line: record.position().map_or(0, ::csv::Position::line),
+                ..BookCsvRow::default()
+            };
+            for (column, value) in columns.iter().zip(record.iter()) {
+                let value = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+                match column {
+                    Some(BookCsvColumn::Title) => row.title = value,
+                    Some(BookCsvColumn::Author) => row.author = value,
+                    Some(BookCsvColumn::Description) => row.description = value,
+                    Some(BookCsvColumn::PublishedYear) => row.published_year = value,
+                    Some(BookCsvColumn::Isbn) => row.isbn = value,
+                    Some(BookCsvColumn::Id) | None => {}
+                }
+            }
+            Ok(row)
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+/// 本の一覧をCSVに書き出す（列は `BookCsvColumn::ALL` の順）
+pub fn write_books(books: &[Book]) -> Result<String, DomainError> {
+    let mut writer = ::csv::Writer::from_writer(Vec::new());
+    let csv_error = |e: ::csv::Error| DomainError::IoError(format!("CSV write error: {}", e));
+
+    writer
+        .write_record(BookCsvColumn::ALL.map(BookCsvColumn::header))
+        .map_err(csv_error)?;
+    for book in books {
+        writer
+            .write_record(BookCsvColumn::ALL.map(|column| {
+                match column {
+                    BookCsvColumn::Id => book.id().map(|id| id.to_string()).unwrap_or_default(),
+                    BookCsvColumn::Title => book.title().to_string(),
+                    BookCsvColumn::Author => book.author().unwrap_or_default().to_string(),
+                    BookCsvColumn::Description => {
+                        book.description().unwrap_or_default().to_string()
+                    }
+                    BookCsvColumn::PublishedYear => book
+                        .published_year()
+                        .map(|year| year.to_string())
+                        .unwrap_or_default(),
+                    BookCsvColumn::Isbn => book
+                        .isbn()
+                        .map(|isbn| isbn.as_isbn13().to_string())
+                        .unwrap_or_default(),
+                }
+            }))
+            .map_err(csv_error)?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| DomainError::IoError(format!("CSV write error: {}", e)))?;
+    String::from_utf8(bytes).map_err(|e| DomainError::IoError(format!("CSV write error: {}", e)))
+}
+
+/// ヘッダー名の比較用（前後の空白を除き小文字化、空白・ハイフンは `_` にそろえる）
+fn normalize_header(header: &str) -> String {
+    header
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_whitespace() || c == '-' {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_rows_by_header() {
+        let content = "\u{feff}ISBN,Published Year,Title,Notes\n\
+            978-4-06-519981-7,2020,\"Dune, Part One\",ignored\n\
+            ,,,\n";
+
+        let rows = read_rows(content, &[]).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            Ok(BookCsvRow {
+                line: 2,
+                title: Some("Dune, Part One".to_string()),
+                published_year: Some("2020".to_string()),
+                isbn: Some("978-4-06-519981-7".to_string()),
+                ..BookCsvRow::default()
+            })
+        );
+        assert_eq!(rows[1].as_ref().unwrap().title, None);
+    }
+
+    #[test]
+    fn test_read_rows_with_overrides() {
+        let content = "書籍,Writer\n吾輩は猫である,夏目漱石\n";
+
+        assert!(read_rows(content, &[]).is_err());
+
+        let overrides = [
+            ("書籍".to_string(), BookCsvColumn::Title),
+            ("writer".to_string(), BookCsvColumn::Author),
+        ];
+        let rows = read_rows(content, &overrides).unwrap();
+        let row = rows[0].as_ref().unwrap();
+        assert_eq!(row.title.as_deref(), Some("吾輩は猫である"));
+        assert_eq!(row.author.as_deref(), Some("夏目漱石"));
+    }
+
+    #[test]
+    fn test_write_books_round_trip() {
+        let book = Book::reconstruct(
+            7,
+            "Dune, \"Part One\"".to_string(),
+            Some("Frank Herbert".to_string()),
+            Some("line 1\nline 2".to_string()),
+            Some(1965),
+            None,
+        );
+
+        let csv = write_books(&[book]).unwrap();
+        assert!(csv.starts_with("id,title,author,description,published_year,isbn\n"));
+
+        let rows = read_rows(&csv, &[]).unwrap();
+        let row = rows[0].as_ref().unwrap();
+        assert_eq!(row.title.as_deref(), Some("Dune, \"Part One\""));
+        assert_eq!(row.description.as_deref(), Some("line 1\nline 2"));
+        assert_eq!(row.published_year.as_deref(), Some("1965"));
+    }
+}
@@ -0,0 +1,161 @@
+// Library Application Layer - Goodreads の書き出しCSV（goodreads_library_export.csv）の解析
+
+use crate::application::importers::book_csv::BookCsvRowError;
+use crate::application::importers::library_export::{
+    self, ExportRow, ExternalBookRecord, ExternalRead,
+};
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{author_role::AuthorRole, reading_status::ReadingStatus};
+
+/// 読書状態を表す既定の棚（Exclusive Shelf）
+const STATUS_SHELVES: [(&str, ReadingStatus); 3] = [
+    ("to-read", ReadingStatus::WantToRead),
+    ("currently-reading", ReadingStatus::Reading),
+    ("read", ReadingStatus::Finished),
+];
+
+/// ファイル全体を解析し、行ごとの結果を返す
+pub fn parse(
+    content: &str,
+) -> Result<Vec<Result<ExternalBookRecord, BookCsvRowError>>, DomainError> {
+    library_export::read_records(content, &["Title", "Author", "Exclusive Shelf"], parse_row)
+}
+
+fn parse_row(row: &ExportRow<'_>) -> Result<ExternalBookRecord, String> {
+    let title = row
+        .get("Title")
+        .ok_or_else(|| "Title is empty".to_string())?;
+
+    let authors = row
+        .get("Author")
+        .map(String::from)
+        .into_iter()
+        .chain(library_export::split_list(row.get("Additional Authors")))
+        .map(|name| (name, AuthorRole::Author))
+        .collect();
+
+    // ISBN は `="..."` で囲まれている（ISBN-13 を優先）
+    let isbn = ["ISBN13", "ISBN"]
+        .iter()
+        .filter_map(|header| row.get(header))
+        .map(library_export::strip_formula)
+        .find(|isbn| !isbn.is_empty())
+        .map(String::from);
+
+    let published_year = ["Original Publication Year", "Year Published"]
+        .iter()
+        .find_map(|header| row.get(header))
+        .map(|year| {
+            year.parse::<i32>()
+                .map_err(|_| format!("Invalid publication year: {}", year))
+        })
+        .transpose()?;
+
+    let exclusive_shelf = row.get("Exclusive Shelf");
+    let status = exclusive_shelf.and_then(|shelf| {
+        STATUS_SHELVES
+            .iter()
+            .find(|(name, _)| *name == shelf)
+            .map(|(_, status)| *status)
+    });
+    // 読書状態の棚以外（独自の Exclusive Shelf を含む）を棚として扱う
+    let mut shelves: Vec<String> = library_export::split_list(row.get("Bookshelves"))
+        .into_iter()
+        .filter(|shelf| !STATUS_SHELVES.iter().any(|(name, _)| name == shelf))
+        .collect();
+    if let Some(shelf) = exclusive_shelf
+        && status.is_none()
+        && !shelves.iter().any(|s| s == shelf)
+    {
+        shelves.insert(0, shelf.to_string());
+    }
+
+    let reads = row
+        .get("Date Read")
+        .map(library_export::parse_date)
+        .transpose()?
+        .map(|finished_on| ExternalRead {
+            started_on: None,
+            finished_on: Some(finished_on),
+        })
+        .into_iter()
+        .collect();
+
+    // 未評価は 0
+    let rating = row
+        .get("My Rating")
+        .map(|rating| {
+            rating
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid rating: {}", rating))
+        })
+        .transpose()?
+        .filter(|rating| *rating > 0.0);
+
+    Ok(ExternalBookRecord {
+        line: row.line,
+        title: title.to_string(),
+        authors,
+        isbn,
+        published_year,
+        status,
+        shelves,
+        reads,
+        added_on: row
+            .get("Date Added")
+            .map(library_export::parse_date)
+            .transpose()?,
+        rating,
+        review: row.get("My Review").map(library_export::html_to_text),
+        private_notes: row.get("Private Notes").map(String::from),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_goodreads_export() {
+        let content = "Book Id,Title,Author,Author l-f,Additional Authors,ISBN,ISBN13,My Rating,Average Rating,Publisher,Binding,Number of Pages,Year Published,Original Publication Year,Date Read,Date Added,Bookshelves,Bookshelves with positions,Exclusive Shelf,My Review,Spoiler,Private Notes,Read Count,Owned Copies\n\
+            234225,Dune (Dune #1),Frank Herbert,\"Herbert, Frank\",,\"=\"\"0441172717\"\"\",\"=\"\"9780441172719\"\"\",5,4.27,Ace,Paperback,688,1990,1965,2024/03/04,2024/01/02,\"sci-fi, favorites\",\"sci-fi (#1), favorites (#3)\",read,Loved it.<br/>Again.,,p. 42,1,0\n\
+            1,Someday,Someone,\"One, Some\",,\"=\"\"\"\"\",\"=\"\"\"\"\",0,3.9,,,,,,,2024/02/01,,,to-read,,,,0,0\n";
+
+        let records = parse(content).unwrap();
+        assert_eq!(records.len(), 2);
+
+        let dune = records[0].as_ref().unwrap();
+        assert_eq!(dune.line, 2);
+        assert_eq!(dune.title, "Dune (Dune #1)");
+        assert_eq!(
+            dune.authors,
+            vec![("Frank Herbert".to_string(), AuthorRole::Author)]
+        );
+        assert_eq!(dune.isbn.as_deref(), Some("9780441172719"));
+        assert_eq!(dune.published_year, Some(1965));
+        assert_eq!(dune.status, Some(ReadingStatus::Finished));
+        assert_eq!(dune.shelves, vec!["sci-fi", "favorites"]);
+        assert_eq!(
+            dune.reads,
+            vec![ExternalRead {
+                started_on: None,
+                finished_on: NaiveDate::from_ymd_opt(2024, 3, 4),
+            }]
+        );
+        assert_eq!(dune.rating, Some(5.0));
+        assert_eq!(dune.review.as_deref(), Some("Loved it.\nAgain."));
+        assert_eq!(dune.private_notes.as_deref(), Some("p. 42"));
+
+        let someday = records[1].as_ref().unwrap();
+        assert_eq!(someday.isbn, None);
+        assert_eq!(someday.rating, None);
+        assert_eq!(someday.status, Some(ReadingStatus::WantToRead));
+        assert!(someday.reads.is_empty());
+    }
+
+    #[test]
+    fn test_other_format_is_rejected() {
+        assert!(parse("title,author\nDune,Frank Herbert\n").is_err());
+    }
+}
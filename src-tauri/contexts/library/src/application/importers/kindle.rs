@@ -0,0 +1,385 @@
+// Library Application Layer - Kindle「My Clippings.txt」の解析
+//
+// 1件は次の形式で、`==========` の行で区切られる（英語・日本語の端末に対応）。
+//
+//     タイトル (著者)
+//     - Your Highlight on page 12 | Location 180-181 | Added on Sunday, October 18, 2026 10:12:33 PM
+//     （空行）
+//     本文
+//
+//     - 12ページ|位置No. 180-181のハイライト |作成日: 2026年10月18日日曜日 22:12:33
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// 各項目の区切り行
+const SEPARATOR: &str = "==========";
+
+/// 抜き書きの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClippingKind {
+    Highlight,
+    Note,
+    Bookmark,
+}
+
+/// 解析済みの抜き書き1件
+#[derive(Debug, Clone, PartialEq)]
+pub struct KindleClipping {
+    pub title: String,
+    pub author: Option<String>,
+    pub kind: ClippingKind,
+    pub page: Option<i32>,
+    pub location_start: Option<i32>,
+    pub location_end: Option<i32>,
+    pub added_at: Option<NaiveDateTime>,
+    pub content: String,
+}
+
+/// 解析できなかった項目（分かる範囲でタイトルを含める）
+#[derive(Debug, Clone, PartialEq)]
+pub struct KindleClippingError {
+    pub title: Option<String>,
+    pub message: String,
+}
+
+/// ファイル全体を解析し、項目ごとの結果をファイル内の順に返す（空の項目は含めない）
+pub fn parse_clippings(content: &str) -> Vec<Result<KindleClipping, KindleClippingError>> {
+    let mut entries = Vec::new();
+    let mut block: Vec<&str> = Vec::new();
+
+    for line in content.lines() {
+        if line.trim_start_matches('\u{feff}').trim() == SEPARATOR {
+            if block.iter().any(|l| !is_blank(l)) {
+                entries.push(parse_entry(&block));
+            }
+            block.clear();
+        } else {
+            block.push(line);
+        }
+    }
+    // 最後の区切り行がないファイル
+    if block.iter().any(|l| !is_blank(l)) {
+        entries.push(parse_entry(&block));
+    }
+
+    entries
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim_start_matches('\u{feff}').trim().is_empty()
+}
+
+fn parse_entry(lines: &[&str]) -> Result<KindleClipping, KindleClippingError> {
+    let mut lines = lines.iter().skip_while(|l| is_blank(l));
+
+    // 各項目の先頭にBOMが付くことがある
+    let header = lines
+        .next()
+        .map(|l| l.trim_start_matches('\u{feff}').trim())
+        .unwrap_or_default();
+    let (title, author) = parse_title_line(header);
+    if title.is_empty() {
+        return Err(KindleClippingError {
+            title: None,
+            message: "Missing title line".to_string(),
+        });
+    }
+
+    let error = |message: &str| KindleClippingError {
+        title: Some(title.clone()),
+        message: message.to_string(),
+    };
+
+    let metadata = lines
+        .next()
+        .map(|l| l.trim())
+        .and_then(|l| l.strip_prefix('-'))
+        .ok_or_else(|| error("Missing metadata line"))?;
+    let kind = parse_kind(metadata).ok_or_else(|| error("Unknown clipping type"))?;
+
+    let mut page = None;
+    let mut location = None;
+    let mut added_at = None;
+    for segment in metadata.split('|').map(str::trim) {
+        let lower = segment.to_lowercase();
+        if let Some(rest) = after_keyword(segment, &lower, &["added on", "作成日"]) {
+            added_at = Some(parse_added_at(rest).ok_or_else(|| error("Invalid date"))?);
+        } else if let Some(rest) = after_keyword(segment, &lower, &["location", "loc.", "位置"]) {
+            location = parse_range(rest);
+        } else if lower.contains("page") || segment.contains("ページ") {
+            // ローマ数字などのページ番号は記録しない
+            page = first_number(segment);
+        }
+    }
+
+    // メタデータ行の後の空行を読み飛ばし、残りを本文とする
+    let content = lines
+        .skip_while(|l| is_blank(l))
+        .copied()
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+
+    Ok(KindleClipping {
+        title,
+        author,
+        kind,
+        page,
+        location_start: location.map(|(start, _)| start),
+        location_end: location.map(|(_, end)| end),
+        added_at,
+        content,
+    })
+}
+
+/// 「タイトル (著者)」を分割する。著者は末尾の括弧（入れ子・全角にも対応）
+fn parse_title_line(line: &str) -> (String, Option<String>) {
+    let Some(close) = line.chars().last().filter(|c| *c == ')' || *c == '）') else {
+        return (line.to_string(), None);
+    };
+    let open = if close == ')' { '(' } else { '（' };
+
+    let mut depth = 0;
+    for (index, c) in line.char_indices().rev() {
+        if c == close {
+            depth += 1;
+        } else if c == open {
+            depth -= 1;
+            if depth == 0 {
+                let title = line[..index].trim();
+                let author = line[index + c.len_utf8()..line.len() - close.len_utf8()].trim();
+                // 全体が括弧で囲まれている場合はタイトルとみなす
+                if title.is_empty() {
+                    break;
+                }
+                return (
+                    title.to_string(),
+                    Some(author.to_string()).filter(|a| !a.is_empty()),
+                );
+            }
+        }
+    }
+
+    (line.to_string(), None)
+}
+
+fn parse_kind(metadata: &str) -> Option<ClippingKind> {
+    let lower = metadata.to_lowercase();
+    if lower.contains("bookmark") || metadata.contains("ブックマーク") {
+        Some(ClippingKind::Bookmark)
+    } else if lower.contains("note") || metadata.contains("メモ") {
+        Some(ClippingKind::Note)
+    } else if lower.contains("highlight")
+        || lower.contains("clip")
+        || metadata.contains("ハイライト")
+    {
+        Some(ClippingKind::Highlight)
+    } else {
+        None
+    }
+}
+
+/// キーワードの後ろの部分を返す（英字は大文字・小文字を区別しない）
+fn after_keyword<'a>(segment: &'a str, lower: &str, keywords: &[&str]) -> Option<&'a str> {
+    keywords.iter().find_map(|keyword| {
+        // 小文字化で長さが変わる文字が前にあると位置がずれるため、見つかった位置が境界か確認する
+        let index = lower.find(keyword)?;
+        segment.get(index + keyword.len()..)
+    })
+}
+
+/// 文字列中の数字の並びを順に取り出す
+fn numbers(text: &str) -> Vec<u32> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter_map(|digits| digits.parse().ok())
+        .collect()
+}
+
+fn first_number(text: &str) -> Option<i32> {
+    numbers(text).first().and_then(|n| i32::try_from(*n).ok())
+}
+
+/// 「180-181」「180」を解析する。古い端末の「1420-21」のような省略形にも対応
+fn parse_range(text: &str) -> Option<(i32, i32)> {
+    let text = text.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let start_digits: String = text.chars().take_while(char::is_ascii_digit).collect();
+    let start: i32 = start_digits.parse().ok()?;
+
+    let rest = text[start_digits.len()..].trim_start();
+    let Some(end_digits) = rest
+        .strip_prefix('-')
+        .map(|r| {
+            r.trim_start()
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>()
+        })
+        .filter(|d| !d.is_empty())
+    else {
+        return Some((start, start));
+    };
+
+    let mut end: i32 = end_digits.parse().ok()?;
+    if end < start && end_digits.len() < start_digits.len() {
+        let prefix = &start_digits[..start_digits.len() - end_digits.len()];
+        end = format!("{}{}", prefix, end_digits).parse().ok()?;
+    }
+    Some((start, end))
+}
+
+/// 作成日時を解析する（英語は曜日付きの米国式・英国式、日本語は「年月日」表記）
+fn parse_added_at(text: &str) -> Option<NaiveDateTime> {
+    let text = text.trim().trim_start_matches([':', '：']).trim();
+
+    if text.contains('年') {
+        let values = numbers(text);
+        let [year, month, day, hour, minute, rest @ ..] = values.as_slice() else {
+            return None;
+        };
+        let hour = match (text.contains("午後"), text.contains("午前")) {
+            (true, _) if *hour < 12 => hour + 12,
+            (_, true) if *hour == 12 => 0,
+            _ => *hour,
+        };
+        let date = NaiveDate::from_ymd_opt(i32::try_from(*year).ok()?, *month, *day)?;
+        let time = NaiveTime::from_hms_opt(hour, *minute, rest.first().copied().unwrap_or(0))?;
+        return Some(date.and_time(time));
+    }
+
+    // 曜日は読み飛ばす
+    let text = text
+        .split_once(',')
+        .filter(|(weekday, _)| weekday.chars().all(char::is_alphabetic))
+        .map_or(text, |(_, rest)| rest.trim());
+    [
+        "%B %d, %Y %I:%M:%S %p",
+        "%B %d, %Y %I:%M %p",
+        "%d %B %Y %H:%M:%S",
+        "%d %B %Y %H:%M",
+        "%B %d, %Y %H:%M:%S",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(s: &str) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok()
+    }
+
+    #[test]
+    fn test_parse_english_entries() {
+        let content = "\u{feff}Dune (Herbert, Frank)\r\n\
+            - Your Highlight on page 12 | Location 180-181 | Added on Sunday, October 18, 2026 10:12:33 PM\r\n\
+            \r\n\
+            I must not fear.\r\n\
+            Fear is the mind-killer.\r\n\
+            ==========\r\n\
+            \u{feff}Dune (Herbert, Frank)\r\n\
+            - Your Note on Location 181 | Added on Sunday, 18 October 2026 22:13:00\r\n\
+            \r\n\
+            Litany\r\n\
+            ==========\r\n";
+
+        let entries = parse_clippings(content);
+        assert_eq!(entries.len(), 2);
+
+        let highlight = entries[0].as_ref().unwrap();
+        assert_eq!(highlight.title, "Dune");
+        assert_eq!(highlight.author.as_deref(), Some("Herbert, Frank"));
+        assert_eq!(highlight.kind, ClippingKind::Highlight);
+        assert_eq!(highlight.page, Some(12));
+        assert_eq!(highlight.location_start, Some(180));
+        assert_eq!(highlight.location_end, Some(181));
+        assert_eq!(highlight.added_at, datetime("2026-10-18 22:12:33"));
+        assert_eq!(
+            highlight.content,
+            "I must not fear.\nFear is the mind-killer."
+        );
+
+        let note = entries[1].as_ref().unwrap();
+        assert_eq!(note.kind, ClippingKind::Note);
+        assert_eq!(note.page, None);
+        assert_eq!(note.location_start, Some(181));
+        assert_eq!(note.location_end, Some(181));
+        assert_eq!(note.added_at, datetime("2026-10-18 22:13:00"));
+    }
+
+    #[test]
+    fn test_parse_japanese_entries() {
+        let content = "吾輩は猫である (夏目 漱石)\n\
+            - 12ページ|位置No. 180-181のハイライト |作成日: 2026年10月18日日曜日 22:12:33\n\
+            \n\
+            吾輩は猫である。名前はまだ無い。\n\
+            ==========\n\
+            吾輩は猫である (夏目 漱石)\n\
+            - 位置No. 200のブックマーク |作成日: 2026年10月18日 日曜日 午後1:05:00\n\
+            \n\
+            \n\
+            ==========\n";
+
+        let entries = parse_clippings(content);
+        assert_eq!(entries.len(), 2);
+
+        let highlight = entries[0].as_ref().unwrap();
+        assert_eq!(highlight.title, "吾輩は猫である");
+        assert_eq!(highlight.author.as_deref(), Some("夏目 漱石"));
+        assert_eq!(highlight.kind, ClippingKind::Highlight);
+        assert_eq!(highlight.page, Some(12));
+        assert_eq!(highlight.location_start, Some(180));
+        assert_eq!(highlight.location_end, Some(181));
+        assert_eq!(highlight.added_at, datetime("2026-10-18 22:12:33"));
+
+        let bookmark = entries[1].as_ref().unwrap();
+        assert_eq!(bookmark.kind, ClippingKind::Bookmark);
+        assert_eq!(bookmark.location_start, Some(200));
+        assert_eq!(bookmark.added_at, datetime("2026-10-18 13:05:00"));
+        assert!(bookmark.content.is_empty());
+    }
+
+    #[test]
+    fn test_parse_title_line() {
+        assert_eq!(
+            parse_title_line("The C Programming Language (2nd Edition) (Kernighan, Brian)"),
+            (
+                "The C Programming Language (2nd Edition)".to_string(),
+                Some("Kernighan, Brian".to_string())
+            )
+        );
+        assert_eq!(
+            parse_title_line("Notes (draft (v2))"),
+            ("Notes".to_string(), Some("draft (v2)".to_string()))
+        );
+        assert_eq!(
+            parse_title_line("坊っちゃん（夏目漱石）"),
+            ("坊っちゃん".to_string(), Some("夏目漱石".to_string()))
+        );
+        assert_eq!(
+            parse_title_line("Personal Document"),
+            ("Personal Document".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_abbreviated_location_range() {
+        assert_eq!(parse_range(" 1420-21"), Some((1420, 1421)));
+        assert_eq!(parse_range("No. 98-102のハイライト"), Some((98, 102)));
+        assert_eq!(parse_range("abc"), None);
+    }
+
+    #[test]
+    fn test_malformed_entry_is_reported() {
+        let entries = parse_clippings("Dune (Frank Herbert)\nno metadata\n==========\n");
+        assert_eq!(
+            entries,
+            vec![Err(KindleClippingError {
+                title: Some("Dune".to_string()),
+                message: "Missing metadata line".to_string(),
+            })]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+// Library Application Layer - 他の書籍管理サービスから書き出したCSVの共通処理
+//
+// Goodreads・StoryGraph の各形式を `ExternalBookRecord` にそろえてから取り込む。
+
+use crate::application::importers::book_csv::BookCsvRowError;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{author_role::AuthorRole, reading_status::ReadingStatus};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+/// 1回分の通読（開始日・終了日のどちらかが分かっている）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalRead {
+    pub started_on: Option<NaiveDate>,
+    pub finished_on: Option<NaiveDate>,
+}
+
+/// 他サービスから書き出した1冊分の記録
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExternalBookRecord {
+    /// ファイル内の行番号（1始まり、ヘッダーを含む）
+    pub line: u64,
+    pub title: String,
+    /// 著者・翻訳者など（表示順）
+    pub authors: Vec<(String, AuthorRole)>,
+    pub isbn: Option<String>,
+    pub published_year: Option<i32>,
+    /// 現在の読書状態
+    pub status: Option<ReadingStatus>,
+    /// 読書状態以外の棚・タグ
+    pub shelves: Vec<String>,
+    /// 通読の履歴（古い順）
+    pub reads: Vec<ExternalRead>,
+    pub added_on: Option<NaiveDate>,
+    /// 星の数（0.5刻みとは限らない）
+    pub rating: Option<f64>,
+    pub review: Option<String>,
+    pub private_notes: Option<String>,
+}
+
+/// ヘッダー名で値を取り出せるCSVの1行
+pub struct ExportRow<'a> {
+    pub line: u64,
+    columns: &'a HashMap<String, usize>,
+    record: &'a ::csv::StringRecord,
+}
+
+impl ExportRow<'_> {
+    /// 列の値（前後の空白を除く。列がない・空欄の場合は None）
+    pub fn get(&self, header: &str) -> Option<&str> {
+        let index = self.columns.get(&header.to_lowercase())?;
+        self.record
+            .get(*index)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    }
+}
+
+/// ヘッダー行を読み、各行を `parse_row` で変換する
+///
+/// `required` の列がない場合は取り込み元の形式が違うとみなし、ファイル全体をエラーとする。
+pub fn read_records<F>(
+    content: &str,
+    required: &[&str],
+    parse_row: F,
+) -> Result<Vec<Result<ExternalBookRecord, BookCsvRowError>>, DomainError>
+where
+    F: Fn(&ExportRow<'_>) -> Result<ExternalBookRecord, String>,
+{
+    let mut reader = ::csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.trim_start_matches('\u{feff}').as_bytes());
+
+    let columns: HashMap<String, usize> = reader
+        .headers()
+        .map_err(|e| DomainError::ValidationError(format!("Invalid CSV header: {}", e)))?
+        .iter()
+        .enumerate()
+        .map(|(index, header)| (header.trim().to_lowercase(), index))
+        .collect();
+    if let Some(missing) = required
+        .iter()
+        .find(|header| !columns.contains_key(&header.to_lowercase()))
+    {
+        return Err(DomainError::ValidationError(format!(
+            "CSV is missing the \"{}\" column",
+            missing
+        )));
+    }
+
+    let rows = reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(|e| BookCsvRowError {
+                line: e.position().map_or(0, ::csv::Position::line),
+                message: format!("Invalid CSV row: {}", e),
+            })?;
+            let row = ExportRow {
+                line: record.position().map_or(0, ::csv::Position::line),
+                columns: &columns,
+                record: &record,
+            };
+            parse_row(&row).map_err(|message| BookCsvRowError {
+                line: row.line,
+                message,
+            })
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+/// 表計算ソフトで数値扱いされないように付けられた `="..."` を外す
+pub fn strip_formula(value: &str) -> &str {
+    value
+        .strip_prefix("=\"")
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+        .trim()
+}
+
+/// 日付（`2024/03/04` または `2024-03-04`）を解析する
+pub fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    let value = value.trim();
+    ["%Y/%m/%d", "%Y-%m-%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        .ok_or_else(|| format!("Invalid date: {}", value))
+}
+
+/// カンマ区切りの一覧を分割する
+pub fn split_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// 感想のHTML（改行タグ・強調タグなど）をテキストにする
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            // 閉じていない `<` はそのまま残す
+            text.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim().to_lowercase();
+        if tag.starts_with("br") || tag == "/p" {
+            text.push('\n');
+        }
+        rest = &rest[start + end + 1..];
+    }
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_formula() {
+        assert_eq!(strip_formula("=\"9780441172719\""), "9780441172719");
+        assert_eq!(strip_formula("=\"\""), "");
+        assert_eq!(strip_formula("0441172717"), "0441172717");
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text("Great book.<br/><br />Loved <b>Paul</b> &amp; Jessica."),
+            "Great book.\n\nLoved Paul & Jessica."
+        );
+        assert_eq!(html_to_text("1 < 2"), "1 < 2");
+    }
+}
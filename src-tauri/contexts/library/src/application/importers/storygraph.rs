@@ -0,0 +1,157 @@
+// Library Application Layer - StoryGraph の書き出しCSVの解析
+
+use crate::application::importers::book_csv::BookCsvRowError;
+use crate::application::importers::library_export::{
+    self, ExportRow, ExternalBookRecord, ExternalRead,
+};
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{
+    author_role::AuthorRole, isbn::Isbn, reading_status::ReadingStatus,
+};
+
+/// ファイル全体を解析し、行ごとの結果を返す
+pub fn parse(
+    content: &str,
+) -> Result<Vec<Result<ExternalBookRecord, BookCsvRowError>>, DomainError> {
+    library_export::read_records(content, &["Title", "Authors", "Read Status"], parse_row)
+}
+
+fn parse_row(row: &ExportRow<'_>) -> Result<ExternalBookRecord, String> {
+    let title = row
+        .get("Title")
+        .ok_or_else(|| "Title is empty".to_string())?;
+
+    let mut authors: Vec<(String, AuthorRole)> = library_export::split_list(row.get("Authors"))
+        .into_iter()
+        .map(|name| (name, AuthorRole::Author))
+        .collect();
+    // 「名前 (Translator)」の形式。対応する役割がない人物（朗読者など）は取り込まない
+    authors.extend(
+        library_export::split_list(row.get("Contributors"))
+            .iter()
+            .filter_map(|contributor| parse_contributor(contributor)),
+    );
+
+    // ISBN がない本は StoryGraph 独自のIDが入っている
+    let isbn = row
+        .get("ISBN/UID")
+        .map(library_export::strip_formula)
+        .filter(|value| Isbn::parse(value).is_ok())
+        .map(String::from);
+
+    let status = match row.get("Read Status") {
+        Some("to-read") => Some(ReadingStatus::WantToRead),
+        Some("currently-reading" | "paused") => Some(ReadingStatus::Reading),
+        Some("read") => Some(ReadingStatus::Finished),
+        Some("did-not-finish") => Some(ReadingStatus::Abandoned),
+        _ => None,
+    };
+
+    // 「開始日-終了日」をカンマ区切りで並べたもの（どちらかが空のこともある）
+    let mut reads = library_export::split_list(row.get("Dates Read"))
+        .iter()
+        .map(|range| parse_read(range))
+        .collect::<Result<Vec<_>, _>>()?;
+    if reads.is_empty()
+        && let Some(date) = row.get("Last Date Read")
+    {
+        reads.push(ExternalRead {
+            started_on: None,
+            finished_on: Some(library_export::parse_date(date)?),
+        });
+    }
+    reads.sort_by_key(|read| read.finished_on.or(read.started_on));
+
+    let rating = row
+        .get("Star Rating")
+        .map(|rating| {
+            rating
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid rating: {}", rating))
+        })
+        .transpose()?
+        .filter(|rating| *rating > 0.0);
+
+    Ok(ExternalBookRecord {
+        line: row.line,
+        title: title.to_string(),
+        authors,
+        isbn,
+        published_year: None,
+        status,
+        shelves: library_export::split_list(row.get("Tags")),
+        reads,
+        added_on: row
+            .get("Date Added")
+            .map(library_export::parse_date)
+            .transpose()?,
+        rating,
+        review: row.get("Review").map(library_export::html_to_text),
+        private_notes: None,
+    })
+}
+
+fn parse_contributor(contributor: &str) -> Option<(String, AuthorRole)> {
+    let (name, role) = contributor.strip_suffix(')')?.rsplit_once('(')?;
+    let role = match role.trim().to_lowercase().as_str() {
+        "translator" => AuthorRole::Translator,
+        "editor" => AuthorRole::Editor,
+        "illustrator" | "cover artist" => AuthorRole::Illustrator,
+        "author" => AuthorRole::Author,
+        _ => return None,
+    };
+    Some((name.trim().to_string(), role))
+}
+
+fn parse_read(range: &str) -> Result<ExternalRead, String> {
+    let (start, end) = range.split_once('-').unwrap_or(("", range));
+    let date = |value: &str| {
+        Some(value.trim())
+            .filter(|v| !v.is_empty())
+            .map(library_export::parse_date)
+            .transpose()
+    };
+    Ok(ExternalRead {
+        started_on: date(start)?,
+        finished_on: date(end)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_storygraph_export() {
+        let content = "Title,Authors,Contributors,ISBN/UID,Format,Read Status,Date Added,Last Date Read,Dates Read,Read Count,Moods,Pace,Star Rating,Review,Content Warnings,Tags,Owned?\n\
+            The Three-Body Problem,Cixin Liu,\"Ken Liu (Translator), Luke Daniels (Narrator)\",9780765382030,paperback,read,2024/01/02,2024/05/14,\"2024/05/01-2024/05/14, 2023/01/01-2023/01/20\",2,,,4.25,So good,,\"sci-fi, china\",No\n\
+            Unknown,Someone,,a1b2c3,digital,did-not-finish,2024/01/02,,,0,,,,,,,No\n";
+
+        let records = parse(content).unwrap();
+        let three_body = records[0].as_ref().unwrap();
+        assert_eq!(
+            three_body.authors,
+            vec![
+                ("Cixin Liu".to_string(), AuthorRole::Author),
+                ("Ken Liu".to_string(), AuthorRole::Translator),
+            ]
+        );
+        assert_eq!(three_body.isbn.as_deref(), Some("9780765382030"));
+        assert_eq!(three_body.status, Some(ReadingStatus::Finished));
+        assert_eq!(three_body.reads.len(), 2);
+        assert_eq!(
+            three_body.reads[0],
+            ExternalRead {
+                started_on: NaiveDate::from_ymd_opt(2023, 1, 1),
+                finished_on: NaiveDate::from_ymd_opt(2023, 1, 20),
+            }
+        );
+        assert_eq!(three_body.rating, Some(4.25));
+        assert_eq!(three_body.shelves, vec!["sci-fi", "china"]);
+
+        let unknown = records[1].as_ref().unwrap();
+        assert_eq!(unknown.isbn, None);
+        assert_eq!(unknown.status, Some(ReadingStatus::Abandoned));
+    }
+}
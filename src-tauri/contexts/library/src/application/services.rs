@@ -1,3 +1,18 @@
 // Library Application Layer - Services
 
+mod authorship;
 pub mod book;
+mod book_matching;
+pub mod catalog;
+pub mod copy;
+pub mod cover;
+pub mod duplicate;
+pub mod ebook;
+pub mod highlight;
+pub mod loan;
+pub mod reading;
+pub mod review;
+pub mod series;
+pub mod shelf;
+pub mod tag;
+mod tagging;
@@ -0,0 +1,67 @@
+// Library Application Layer - 著者の関連付け（本を作成するサービスで共有）
+
+use crate::application::errors::ApplicationError;
+use crate::domain::{
+    entities::{
+        author::{Author, BookAuthor},
+        book::Book,
+    },
+    repositories::author::AuthorRepository,
+    value_objects::author_role::AuthorRole,
+};
+
+/// 表示用の著者名に合わせて、役割が著者の関連を置き換える（翻訳者などは維持する）
+pub(crate) async fn sync_primary_author(
+    author_repository: &dyn AuthorRepository,
+    book: &mut Book,
+) -> Result<(), ApplicationError> {
+    let mut authors = Vec::new();
+    if let Some(name) = book.author() {
+        let author = find_or_create_author(author_repository, name.to_string()).await?;
+        authors.push(BookAuthor::new(author, AuthorRole::Author));
+    }
+    authors.extend(
+        book.authors()
+            .iter()
+            .filter(|a| a.role() != AuthorRole::Author)
+            .cloned(),
+    );
+
+    book.set_authors(authors)?;
+    Ok(())
+}
+
+/// 名前で著者を取得し、いなければ作成する
+pub(crate) async fn find_or_create_author(
+    author_repository: &dyn AuthorRepository,
+    name: String,
+) -> Result<Author, ApplicationError> {
+    let author = Author::new(name)?;
+    if let Some(existing) = author_repository.find_by_name(author.name()).await? {
+        return Ok(existing);
+    }
+
+    Ok(author_repository.save(author).await?)
+}
+
+/// 名前と役割の一覧（表示順）で著者を取得または作成し、本の著者一覧を置き換える
+pub(crate) async fn link_authors(
+    author_repository: &dyn AuthorRepository,
+    book: &mut Book,
+    authors: &[(String, AuthorRole)],
+) -> Result<(), ApplicationError> {
+    let mut book_authors: Vec<BookAuthor> = Vec::with_capacity(authors.len());
+    for (name, role) in authors {
+        let author = find_or_create_author(author_repository, name.clone()).await?;
+        // 同じ人物・役割の重複は読み飛ばす
+        if !book_authors
+            .iter()
+            .any(|a| a.author().id() == author.id() && a.role() == *role)
+        {
+            book_authors.push(BookAuthor::new(author, *role));
+        }
+    }
+
+    book.set_authors(book_authors)?;
+    Ok(())
+}
@@ -1,18 +1,65 @@
 // Library Application Layer - Book アプリケーションサービス
 
-use crate::application::dto::book::BookDto;
+use crate::application::dto::{
+    author::{AuthorDto, BookAuthorInput},
+    book::{BookDto, BookFilterInput, BookPageDto, BookSearchResultDto, BookSortInput},
+    classification::{BookClassDto, ClassificationSystem},
+    history::BookChangeDto,
+};
 use crate::application::errors::ApplicationError;
-use crate::domain::{entities::book::Book, repositories::book::BookRepository};
+use crate::application::services::{authorship, book_matching};
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{
+        author::BookAuthor,
+        book::Book,
+        book_change::{BookChange, BookSnapshot, ChangeRecord},
+    },
+    repositories::{
+        author::AuthorRepository,
+        book::{BookFilter, BookQuerySpec, BookRepository, ShelfFilter},
+        book_change::BookChangeRepository,
+        shelf::ShelfRepository,
+    },
+    value_objects::{
+        book_change_action::BookChangeAction,
+        book_change_source::BookChangeSource,
+        class_number::{CLASS_TABLE_DEPTH, ClassNumber, ClassPrefix, ClassificationScheme},
+        isbn::Isbn,
+        search_query::SearchQuery,
+        tag_name::TagName,
+    },
+};
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// 1ページあたりの取得件数の上限
+pub const MAX_PAGE_SIZE: usize = 100;
+
 /// Book管理のユースケースを実装するサービス
+///
+/// 本の作成・編集・ゴミ箱への移動・復元・取り消しは、本の保存と同じトランザクションで変更履歴に記録する。
 pub struct BookService {
     repository: Arc<dyn BookRepository>,
+    author_repository: Arc<dyn AuthorRepository>,
+    shelf_repository: Arc<dyn ShelfRepository>,
+    history_repository: Arc<dyn BookChangeRepository>,
 }
 
 impl BookService {
-    pub fn new(repository: Arc<dyn BookRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn BookRepository>,
+        author_repository: Arc<dyn AuthorRepository>,
+        shelf_repository: Arc<dyn ShelfRepository>,
+        history_repository: Arc<dyn BookChangeRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            author_repository,
+            shelf_repository,
+            history_repository,
+        }
     }
 
     /// 新しい本を作成
@@ -22,12 +69,18 @@ impl BookService {
         author: Option<String>,
         description: Option<String>,
         published_year: Option<i32>,
+        isbn: Option<String>,
     ) -> Result<BookDto, ApplicationError> {
         // 1. ドメインエンティティ作成（バリデーション実行）
-        let book = Book::new(title, author, description, published_year)?;
+        let mut book = Book::new(title, author, description, published_year, isbn)?;
+        self.ensure_isbn_is_unique(&book).await?;
+        authorship::sync_primary_author(self.author_repository.as_ref(), &mut book).await?;
 
-        // 2. リポジトリで永続化
-        let saved_book = self.repository.save(book).await?;
+        // 2. リポジトリで永続化し、履歴に記録
+        let saved_book = self
+            .repository
+            .save_with_history(book, ChangeRecord::created(BookChangeSource::User))
+            .await?;
 
         // 3. DTOに変換して返す
         Ok(BookDto::from(saved_book))
@@ -39,13 +92,110 @@ impl BookService {
         Ok(books.into_iter().map(BookDto::from).collect())
     }
 
+    /// 条件に一致する本を1ページ分取得（`tag` は子孫のタグを含み、`shelf` はスマート本棚の条件も使える）
+    pub async fn get_books_page(
+        &self,
+        filter: BookFilterInput,
+        tag: Option<String>,
+        shelf: Option<i32>,
+        sort: BookSortInput,
+        offset: usize,
+        limit: usize,
+    ) -> Result<BookPageDto, ApplicationError> {
+        if limit > MAX_PAGE_SIZE {
+            return Err(DomainError::ValidationError(format!(
+                "Page size must be {} or less",
+                MAX_PAGE_SIZE
+            ))
+            .into());
+        }
+
+        let mut filter = BookFilter::try_from(filter)?;
+        filter.tag = tag.map(|tag| TagName::new(&tag)).transpose()?;
+        if let Some(shelf_id) = shelf {
+            filter.shelf = Some(self.shelf_filter(shelf_id).await?);
+        }
+        if let (Some(from), Some(to)) = (filter.published_year_from, filter.published_year_to)
+            && from > to
+        {
+            return Err(DomainError::ValidationError(
+                "Published year range start must not be after its end".to_string(),
+            )
+            .into());
+        }
+        if let (Some(from), Some(to)) = (filter.created_at_from, filter.created_at_to)
+            && from > to
+        {
+            return Err(DomainError::ValidationError(
+                "Created date range start must not be after its end".to_string(),
+            )
+            .into());
+        }
+        if let (Some(from), Some(to)) = (filter.updated_at_from, filter.updated_at_to)
+            && from > to
+        {
+            return Err(DomainError::ValidationError(
+                "Updated date range start must not be after its end".to_string(),
+            )
+            .into());
+        }
+
+        let spec = BookQuerySpec {
+            filter,
+            sort_key: sort.field.into(),
+            sort_order: sort.direction.into(),
+            offset: offset as u64,
+            limit: limit as u64,
+        };
+        let page = self.repository.find_page(&spec).await?;
+
+        let has_next_page = spec.offset + (page.books.len() as u64) < page.total_count;
+        Ok(BookPageDto {
+            books: page.books.into_iter().map(BookDto::from).collect(),
+            total_count: page.total_count,
+            has_next_page,
+        })
+    }
+
+    /// 本を全文検索（関連度の高い順）
+    pub async fn search_books(
+        &self,
+        query: String,
+        limit: usize,
+    ) -> Result<Vec<BookSearchResultDto>, ApplicationError> {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(DomainError::ValidationError(format!(
+                "Search limit must be between 1 and {}",
+                MAX_PAGE_SIZE
+            ))
+            .into());
+        }
+
+        let query = SearchQuery::parse(&query)?;
+        let hits = self.repository.search(&query, limit as u64).await?;
+        Ok(hits.into_iter().map(BookSearchResultDto::from).collect())
+    }
+
     /// IDで本を取得
     pub async fn get_book(&self, id: i32) -> Result<Option<BookDto>, ApplicationError> {
         let book = self.repository.find_by_id(id).await?;
         Ok(book.map(BookDto::from))
     }
 
-    /// 本を更新
+    /// ISBN（ISBN-10 / ISBN-13、ハイフン有無は問わない）で本を取得
+    pub async fn get_book_by_isbn(
+        &self,
+        isbn: String,
+    ) -> Result<Option<BookDto>, ApplicationError> {
+        let isbn = Isbn::parse(&isbn)?;
+        let book = self.repository.find_by_isbn(&isbn).await?;
+        Ok(book.map(BookDto::from))
+    }
+
+    /// 本を更新（出版社は `None` で変更なし、空文字列で削除）
+    ///
+    /// 編集を始めた時点の版 `expected_version` から変更されていれば、更新せずに競合エラーを返す。
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_book(
         &self,
         id: i32,
@@ -53,33 +203,516 @@ impl BookService {
         author: Option<String>,
         description: Option<String>,
         published_year: Option<i32>,
+        isbn: Option<String>,
+        publisher: Option<String>,
+        expected_version: i32,
     ) -> Result<BookDto, ApplicationError> {
-        // 1. 既存の本を取得
+        // 1. 既存の本を取得（編集を始めた時点から変更されていないことを確認）
         let mut book =
             self.repository.find_by_id(id).await?.ok_or_else(|| {
                 ApplicationError::NotFound(format!("Book with id {} not found", id))
             })?;
+        book.ensure_version(expected_version)?;
 
         // 2. ドメインロジックで更新（バリデーション実行）
-        book.update_details(title, author, description, published_year)?;
+        let before = BookSnapshot::of(&book);
+        // 表示用の著者名が変わった場合のみ著者の関連を更新する（共著の表示名をそのまま送り返された場合など）
+        let author_changed = author
+            .as_deref()
+            .is_some_and(|a| Some(a.trim()).filter(|a| !a.is_empty()) != book.author());
+        book.update_details(title, author, description, published_year, isbn)?;
+        book.update_publisher(publisher)?;
+        self.ensure_isbn_is_unique(&book).await?;
+        if author_changed {
+            authorship::sync_primary_author(self.author_repository.as_ref(), &mut book).await?;
+        }
 
-        // 3. リポジトリで保存
-        let updated_book = self.repository.save(book).await?;
+        // 3. リポジトリで保存し、履歴に記録
+        let updated_book = self
+            .repository
+            .save_with_history(
+                book,
+                ChangeRecord::new(
+                    BookChangeAction::Update,
+                    BookChangeSource::User,
+                    Some(before),
+                ),
+            )
+            .await?;
 
         // 4. DTOに変換して返す
         Ok(BookDto::from(updated_book))
     }
 
-    /// 本を削除
+    /// 本のタイトル・著者の読みを更新（`None` は変更なし、空文字列で削除。並び替え用のキーも作り直す）
+    ///
+    /// `expected_version` の版から変更されていれば競合エラーを返す。
+    pub async fn update_book_readings(
+        &self,
+        id: i32,
+        title_reading: Option<String>,
+        author_reading: Option<String>,
+        expected_version: i32,
+    ) -> Result<BookDto, ApplicationError> {
+        let mut book =
+            self.repository.find_by_id(id).await?.ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", id))
+            })?;
+        book.ensure_version(expected_version)?;
+
+        let before = BookSnapshot::of(&book);
+        book.update_readings(title_reading, author_reading)?;
+        let saved = self
+            .repository
+            .save_with_history(
+                book,
+                ChangeRecord::new(
+                    BookChangeAction::Update,
+                    BookChangeSource::User,
+                    Some(before),
+                ),
+            )
+            .await?;
+
+        Ok(BookDto::from(saved))
+    }
+
+    /// 本の分類記号を更新（省略した分類法は変更なし、空文字列で削除）
+    ///
+    /// `expected_version` の版から変更されていれば競合エラーを返す。
+    pub async fn update_book_classification(
+        &self,
+        id: i32,
+        ndc: Option<String>,
+        ddc: Option<String>,
+        expected_version: i32,
+    ) -> Result<BookDto, ApplicationError> {
+        let mut book =
+            self.repository.find_by_id(id).await?.ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", id))
+            })?;
+        book.ensure_version(expected_version)?;
+
+        let before = BookSnapshot::of(&book);
+        book.update_classification(ndc, ddc)?;
+        let saved = self
+            .repository
+            .save_with_history(
+                book,
+                ChangeRecord::new(
+                    BookChangeAction::Update,
+                    BookChangeSource::User,
+                    Some(before),
+                ),
+            )
+            .await?;
+
+        Ok(BookDto::from(saved))
+    }
+
+    /// 分類ごとの本の数を分類記号の順に取得（`parent` を指定しない場合は類ごと）
+    ///
+    /// 類・綱を指定した場合は分類表の1つ下の階層を本のない分類も含めて返し、
+    /// 目以下を指定した場合は本に付けた分類記号ごとに返す。
+    pub async fn get_book_classes(
+        &self,
+        system: ClassificationSystem,
+        parent: Option<String>,
+    ) -> Result<Vec<BookClassDto>, ApplicationError> {
+        let scheme = ClassificationScheme::from(system);
+        let parent = parent
+            .filter(|parent| !parent.trim().is_empty())
+            .map(|parent| ClassPrefix::parse(scheme, &parent))
+            .transpose()?;
+        let depth = parent.as_ref().map_or(0, ClassPrefix::depth);
+
+        if depth >= CLASS_TABLE_DEPTH {
+            let counts = self
+                .repository
+                .count_by_class(scheme, parent.as_ref(), None)
+                .await?;
+            return Ok(counts
+                .into_iter()
+                .map(|count| BookClassDto {
+                    name: ClassNumber::parse(scheme, &count.code)
+                        .ok()
+                        .and_then(|number| number.class_name())
+                        .map(String::from),
+                    code: count.code,
+                    book_count: count.book_count,
+                })
+                .collect());
+        }
+
+        let mut counts: HashMap<String, u64> = self
+            .repository
+            .count_by_class(scheme, parent.as_ref(), Some(depth + 1))
+            .await?
+            .into_iter()
+            .map(|count| (count.code, count.book_count))
+            .collect();
+        let mut classes: Vec<BookClassDto> = scheme
+            .subclasses(parent.as_ref().map(ClassPrefix::as_str))
+            .into_iter()
+            .map(|(code, name)| BookClassDto {
+                code: code.to_string(),
+                name: Some(name.to_string()),
+                book_count: counts.remove(code).unwrap_or(0),
+            })
+            .collect();
+        // 分類表で使われていない分類記号を付けた本も数える
+        classes.extend(counts.into_iter().map(|(code, book_count)| BookClassDto {
+            code,
+            name: None,
+            book_count,
+        }));
+        classes.sort_by(|a, b| a.code.cmp(&b.code));
+
+        Ok(classes)
+    }
+
+    /// 本の著者一覧（役割・表示順）を置き換える
+    pub async fn set_book_authors(
+        &self,
+        book_id: i32,
+        authors: Vec<BookAuthorInput>,
+    ) -> Result<BookDto, ApplicationError> {
+        let mut book = self.repository.find_by_id(book_id).await?.ok_or_else(|| {
+            ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+        })?;
+
+        let mut book_authors = Vec::with_capacity(authors.len());
+        for input in authors {
+            let author = match (input.author_id, input.name) {
+                (Some(author_id), _) => self
+                    .author_repository
+                    .find_by_id(author_id)
+                    .await?
+                    .ok_or_else(|| {
+                        ApplicationError::NotFound(format!(
+                            "Author with id {} not found",
+                            author_id
+                        ))
+                    })?,
+                (None, Some(name)) => {
+                    authorship::find_or_create_author(self.author_repository.as_ref(), name).await?
+                }
+                (None, None) => {
+                    return Err(DomainError::ValidationError(
+                        "Either authorId or name is required".to_string(),
+                    )
+                    .into());
+                }
+            };
+            book_authors.push(BookAuthor::new(author, input.role.into()));
+        }
+
+        let before = BookSnapshot::of(&book);
+        book.set_authors(book_authors)?;
+        let saved_book = self
+            .repository
+            .save_with_history(
+                book,
+                ChangeRecord::new(
+                    BookChangeAction::Update,
+                    BookChangeSource::User,
+                    Some(before),
+                ),
+            )
+            .await?;
+
+        Ok(BookDto::from(saved_book))
+    }
+
+    /// IDで著者と、その人物が関わっている本を取得
+    pub async fn get_author(&self, id: i32) -> Result<Option<AuthorDto>, ApplicationError> {
+        let Some(author) = self.author_repository.find_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        let books = self.repository.find_by_author(id).await?;
+        Ok(Some(AuthorDto::new(
+            author,
+            books.into_iter().map(BookDto::from).collect(),
+        )))
+    }
+
+    /// 本をゴミ箱に移す（`restore_book` で元に戻せる。読書記録などの関連データはそのまま残す）
     pub async fn delete_book(&self, id: i32) -> Result<(), ApplicationError> {
-        // 存在確認
-        self.repository
-            .find_by_id(id)
+        let book =
+            self.repository.find_by_id(id).await?.ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", id))
+            })?;
+
+        self.move_to_trash(book, BookChangeAction::Delete, BookChangeSource::User)
+            .await?;
+        Ok(())
+    }
+
+    /// ゴミ箱にある本をゴミ箱に移した日時の新しい順に取得
+    pub async fn get_trashed_books(&self) -> Result<Vec<BookDto>, ApplicationError> {
+        let books = self.repository.find_trashed().await?;
+        Ok(books.into_iter().map(BookDto::from).collect())
+    }
+
+    /// ゴミ箱にある本を元に戻す
+    pub async fn restore_book(&self, id: i32) -> Result<BookDto, ApplicationError> {
+        let book = self
+            .repository
+            .find_trashed_by_id(id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found in the trash", id))
+            })?;
+
+        let saved = self
+            .restore_from_trash(book, BookChangeAction::Restore, BookChangeSource::User)
+            .await?;
+        Ok(BookDto::from(saved))
+    }
+
+    /// 本をゴミ箱に移し、指定した操作として履歴に記録する
+    async fn move_to_trash(
+        &self,
+        mut book: Book,
+        action: BookChangeAction,
+        source: BookChangeSource,
+    ) -> Result<Book, ApplicationError> {
+        let snapshot = BookSnapshot::of(&book);
+        book.move_to_trash(Utc::now())?;
+        Ok(self
+            .repository
+            .save_with_history(book, ChangeRecord::new(action, source, Some(snapshot)))
+            .await?)
+    }
+
+    /// ゴミ箱にある本を元に戻し、指定した操作として履歴に記録する
+    async fn restore_from_trash(
+        &self,
+        mut book: Book,
+        action: BookChangeAction,
+        source: BookChangeSource,
+    ) -> Result<Book, ApplicationError> {
+        let snapshot = BookSnapshot::of(&book);
+        book.restore()?;
+        Ok(self
+            .repository
+            .save_with_history(book, ChangeRecord::new(action, source, Some(snapshot)))
+            .await?)
+    }
+
+    /// ゴミ箱を空にする（ゴミ箱にある本を完全に削除し、削除した件数を返す）
+    pub async fn empty_trash(&self) -> Result<u64, ApplicationError> {
+        Ok(self.repository.delete_trashed(None).await?)
+    }
+
+    /// ゴミ箱に移してから保存日数を過ぎた本を完全に削除し、削除した件数を返す（0日の場合は削除しない）
+    pub async fn purge_expired_trash(&self, retention_days: u32) -> Result<u64, ApplicationError> {
+        if retention_days == 0 {
+            return Ok(0);
+        }
+
+        let cutoff = Utc::now() - Duration::days(i64::from(retention_days));
+        Ok(self.repository.delete_trashed(Some(cutoff)).await?)
+    }
+
+    /// 本の変更履歴を新しい順に取得（ゴミ箱にある本を含む）
+    pub async fn get_book_history(
+        &self,
+        book_id: i32,
+    ) -> Result<Vec<BookChangeDto>, ApplicationError> {
+        if self.repository.find_by_id(book_id).await?.is_none()
+            && self.repository.find_trashed_by_id(book_id).await?.is_none()
+        {
+            return Err(ApplicationError::NotFound(format!(
+                "Book with id {} not found",
+                book_id
+            )));
+        }
+
+        let changes = self.history_repository.find_by_book(book_id).await?;
+        Ok(changes.into_iter().rev().map(BookChangeDto::from).collect())
+    }
+
+    /// 本の最後の変更を取り消す（取り消した内容は取り消しとして履歴に記録する）
+    ///
+    /// 取り消し済みの変更は対象にしないため、繰り返し呼ぶと古い変更へ順に取り消していく。
+    /// ゴミ箱への移動（まとめてゴミ箱に移した本を含む）は元に戻し、復元はゴミ箱に移し直す。編集は直前の書誌情報に戻す
+    /// （作成とまとめ先の本のまとめは取り消せない）。
+    pub async fn undo_last_change(&self, book_id: i32) -> Result<BookDto, ApplicationError> {
+        let history = self.history_repository.find_by_book(book_id).await?;
+        let target = BookChange::last_undoable(&history).ok_or_else(|| {
+            ApplicationError::NotFound(format!("No changes recorded for book {}", book_id))
+        })?;
+
+        match target.action() {
+            BookChangeAction::Create => Err(DomainError::InvalidState(
+                "The creation of a book cannot be undone; delete it instead".to_string(),
+            )
+            .into()),
+            BookChangeAction::Delete | BookChangeAction::Merge => {
+                // まとめ先の本のまとめは取り消せない（まとめた本はゴミ箱から個別に元に戻す）
+                if target.action() == BookChangeAction::Merge
+                    && self.repository.find_by_id(book_id).await?.is_some()
+                {
+                    return Err(DomainError::InvalidState(
+                        "Merging books cannot be undone; restore the merged books from the trash instead"
+                            .to_string(),
+                    )
+                    .into());
+                }
+                let book = self
+                    .repository
+                    .find_trashed_by_id(book_id)
+                    .await?
+                    .ok_or_else(|| {
+                        ApplicationError::NotFound(format!(
+                            "Book with id {} not found in the trash",
+                            book_id
+                        ))
+                    })?;
+                let saved = self
+                    .restore_from_trash(book, BookChangeAction::Undo, BookChangeSource::History)
+                    .await?;
+                Ok(BookDto::from(saved))
+            }
+            BookChangeAction::Restore => {
+                let book = self.repository.find_by_id(book_id).await?.ok_or_else(|| {
+                    ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+                })?;
+                let saved = self
+                    .move_to_trash(book, BookChangeAction::Undo, BookChangeSource::History)
+                    .await?;
+                Ok(BookDto::from(saved))
+            }
+            BookChangeAction::Update | BookChangeAction::Revert | BookChangeAction::Undo => {
+                let before = target.before().cloned().ok_or_else(|| {
+                    DomainError::InvalidState(format!(
+                        "Version {} of book {} has no previous state",
+                        target.revision(),
+                        book_id
+                    ))
+                })?;
+                self.apply_snapshot(
+                    book_id,
+                    before,
+                    BookChangeAction::Undo,
+                    BookChangeSource::History,
+                )
+                .await
+            }
+        }
+    }
+
+    /// 本の書誌情報を指定した履歴番号の変更後の状態に戻す（戻した内容は新しい履歴として記録する）
+    pub async fn revert_to_revision(
+        &self,
+        book_id: i32,
+        revision: i32,
+    ) -> Result<BookDto, ApplicationError> {
+        let change = self
+            .history_repository
+            .find_revision(book_id, revision)
             .await?
-            .ok_or_else(|| ApplicationError::NotFound(format!("Book with id {} not found", id)))?;
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Version {} of book {} not found",
+                    revision, book_id
+                ))
+            })?;
+
+        self.apply_snapshot(
+            book_id,
+            change.after().clone(),
+            BookChangeAction::Revert,
+            BookChangeSource::History,
+        )
+        .await
+    }
+
+    /// 書誌情報を記録した状態に戻す（通常の編集と同じバリデーションを行い、指定した操作として履歴に記録する）
+    async fn apply_snapshot(
+        &self,
+        book_id: i32,
+        snapshot: BookSnapshot,
+        action: BookChangeAction,
+        source: BookChangeSource,
+    ) -> Result<BookDto, ApplicationError> {
+        let mut book = self.repository.find_by_id(book_id).await?.ok_or_else(|| {
+            ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+        })?;
+        let before = BookSnapshot::of(&book);
+
+        // 著者一覧は表示用の著者名を上書きするため、書誌情報より先に戻す
+        authorship::link_authors(
+            self.author_repository.as_ref(),
+            &mut book,
+            &snapshot.authors,
+        )
+        .await?;
+        book.replace_details(
+            snapshot.title,
+            snapshot.author,
+            snapshot.description,
+            snapshot.published_year,
+            snapshot.isbn,
+        )?;
+        book.update_readings(
+            Some(snapshot.title_reading.unwrap_or_default()),
+            Some(snapshot.author_reading.unwrap_or_default()),
+        )?;
+        book.update_publisher(Some(snapshot.publisher.unwrap_or_default()))?;
+        book.update_classification(
+            Some(snapshot.ndc.unwrap_or_default()),
+            Some(snapshot.ddc.unwrap_or_default()),
+        )?;
+        self.ensure_isbn_is_unique(&book).await?;
+
+        let saved = self
+            .repository
+            .save_with_history(book, ChangeRecord::new(action, source, Some(before)))
+            .await?;
+
+        Ok(BookDto::from(saved))
+    }
+
+    /// 保存済みの並び替え用のキーを現在の正規化の規則で作り直し、更新した件数を返す
+    pub async fn refresh_sort_keys(&self) -> Result<u64, ApplicationError> {
+        Ok(self.repository.refresh_sort_keys().await?)
+    }
+
+    /// 本棚を絞り込み条件に変換（スマート本棚は保存した条件を使う）
+    async fn shelf_filter(&self, shelf_id: i32) -> Result<ShelfFilter, ApplicationError> {
+        let shelf = self
+            .shelf_repository
+            .find_by_id(shelf_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Shelf with id {} not found", shelf_id))
+            })?;
+
+        Ok(match shelf.smart_filter() {
+            Some(filter) => ShelfFilter::Matching(Box::new(filter.clone())),
+            None => ShelfFilter::Books(shelf_id),
+        })
+    }
+
+    /// 同じISBNの本が他に登録されていないことを確認（ゴミ箱にある本を含む）
+    async fn ensure_isbn_is_unique(&self, book: &Book) -> Result<(), ApplicationError> {
+        if let Some(isbn) = book.isbn()
+            && let Some(existing) = self.repository.find_by_isbn(isbn).await?
+            && existing.id() != book.id()
+        {
+            return Err(DomainError::ValidationError(format!(
+                "A book with ISBN {} already exists",
+                isbn
+            ))
+            .into());
+        }
 
-        // 削除実行
-        self.repository.delete(id).await?;
+        if let Some(isbn) = book.isbn() {
+            book_matching::ensure_isbn_not_trashed(self.repository.as_ref(), isbn).await?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,97 @@
+// Library Application Layer - 取り込んだ記録と登録済みの本の対応付け（取り込み処理で共有）
+
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::book::Book;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::book::BookRepository;
+use crate::domain::value_objects::{author_role::AuthorRole, isbn::Isbn};
+
+/// ゴミ箱にある本がISBNを使っていないことを確認する
+///
+/// ゴミ箱にある本もISBNの一意制約の対象のため、同じISBNの本は登録・変更できない。
+pub(crate) async fn ensure_isbn_not_trashed(
+    book_repository: &dyn BookRepository,
+    isbn: &Isbn,
+) -> Result<(), ApplicationError> {
+    if book_repository.find_trashed_by_isbn(isbn).await?.is_some() {
+        return Err(DomainError::ValidationError(format!(
+            "A book with ISBN {} is in the trash; restore it or empty the trash",
+            isbn
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// タイトルが一致する本の中から、著者が一致する本を選ぶ
+///
+/// 著者が分からない場合は先頭の本、一致する著者がいない場合は著者が未登録の本を同じ本とみなす。
+pub(crate) fn find_by_author<'a>(candidates: &'a [Book], author: Option<&str>) -> Option<&'a Book> {
+    match author {
+        None => candidates.first(),
+        Some(author) => candidates
+            .iter()
+            .find(|book| is_written_by(book, author))
+            .or_else(|| candidates.iter().find(|book| book.author().is_none())),
+    }
+}
+
+/// 表示用の著者名、または関連付けられた人物のいずれかが一致するか
+fn is_written_by(book: &Book, author: &str) -> bool {
+    book.author()
+        .into_iter()
+        .chain(book.authors().iter().map(|a| a.author().name()))
+        .any(|name| is_same_name(name, author))
+}
+
+/// 2冊の本の著者がどの程度一致するか（0.0〜1.0。どちらかの著者が分からない場合は None）
+///
+/// 役割が著者の人物（未登録の場合は表示用の著者名）のうち、表記ゆれを吸収して一致する人数の割合。
+pub(crate) fn author_similarity(a: &Book, b: &Book) -> Option<f64> {
+    fn names(book: &Book) -> Vec<&str> {
+        let mut names: Vec<&str> = book
+            .authors()
+            .iter()
+            .filter(|a| a.role() == AuthorRole::Author)
+            .map(|a| a.author().name())
+            .collect();
+        if names.is_empty() {
+            names.extend(book.author());
+        }
+        names
+    }
+    let (a, b) = (names(a), names(b));
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+
+    let matched = a
+        .iter()
+        .filter(|name| b.iter().any(|other| is_same_name(name, other)))
+        .count();
+    // 1冊の著者は多くても数十人のため精度は落ちない
+    #[allow(clippy::cast_precision_loss)]
+    let similarity = matched as f64 / a.len().max(b.len()) as f64;
+    Some(similarity)
+}
+
+/// 人名の表記ゆれを吸収して比較する（「Herbert, Frank」と「Frank Herbert」、「夏目 漱石」と「夏目漱石」）
+fn is_same_name(a: &str, b: &str) -> bool {
+    let words = |name: &str| {
+        let mut words: Vec<String> = name
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '、')
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        words.sort();
+        words
+    };
+    let compact = |name: &str| {
+        name.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+
+    words(a) == words(b) || compact(a) == compact(b)
+}
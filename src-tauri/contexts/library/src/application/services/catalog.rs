@@ -0,0 +1,1002 @@
+// Library Application Layer - 本の一覧の取り込み・書き出し アプリケーションサービス
+
+use crate::application::dto::{
+    book::BookDto,
+    citation::CitationFormat,
+    import::{
+        BookImportReportDto, CalibreImportItemDto, CalibreImportReportDto, CsvColumnMappingInput,
+        ExternalImportAction, ExternalImportItemDto, ExternalImportReportDto,
+        ExternalLibrarySource, ImportRowErrorDto,
+    },
+};
+use crate::application::errors::ApplicationError;
+use crate::application::exporters::citation::{self, CitationKeys};
+use crate::application::importers::{
+    book_csv::{self, BookCsvRow},
+    goodreads,
+    library_export::{self, ExternalBookRecord},
+    storygraph,
+};
+use crate::application::services::{authorship, book_matching, tagging};
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{
+        author::Author,
+        book::Book,
+        book_change::{BookSnapshot, ChangeRecord},
+        note::Note,
+        read_through::ReadThrough,
+        review::Review,
+        series::{Series, SeriesVolume},
+    },
+    repositories::{
+        author::AuthorRepository,
+        book::{BookFilter, BookRepository},
+        calibre::{CalibreBook, CalibreLibraryRepository},
+        note::NoteRepository,
+        read_through::ReadThroughRepository,
+        review::ReviewRepository,
+        series::SeriesRepository,
+        tag::TagRepository,
+    },
+    value_objects::{
+        author_role::AuthorRole, book_change_action::BookChangeAction,
+        book_change_source::BookChangeSource, external_source::ExternalSource, isbn::Isbn,
+        rating::Rating, reading_status::ReadingStatus, series_status::SeriesStatus,
+        tag_name::TagName, volume_number::VolumeNumber,
+    },
+};
+use chrono::NaiveDate;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 他サービスから取り込む1冊分の処理内容
+///
+/// 試行時はこの内容を報告し、実行時はこの内容に従って保存する。
+struct ExternalImportPlan {
+    action: ExternalImportAction,
+    /// 新規登録する本、または情報を追加した登録済みの本
+    book: Book,
+    /// 本の基本情報を保存するか
+    save_book: bool,
+    /// 本を保存する際に変更履歴に記録する内容
+    change: ChangeRecord,
+    /// 著者一覧を取り込み元の内容で置き換えるか
+    link_authors: bool,
+    add_read_throughs: bool,
+    add_review: bool,
+    add_note: bool,
+    /// 本に付ける（まだ付いていない）タグ
+    add_tags: Vec<TagName>,
+    changes: Vec<String>,
+    message: Option<String>,
+}
+
+/// 本の一覧の一括取り込み・書き出しのユースケースを実装するサービス
+pub struct CatalogService {
+    book_repository: Arc<dyn BookRepository>,
+    author_repository: Arc<dyn AuthorRepository>,
+    read_through_repository: Arc<dyn ReadThroughRepository>,
+    review_repository: Arc<dyn ReviewRepository>,
+    note_repository: Arc<dyn NoteRepository>,
+    series_repository: Arc<dyn SeriesRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    calibre_library_repository: Arc<dyn CalibreLibraryRepository>,
+}
+
+impl CatalogService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: Arc<dyn BookRepository>,
+        author_repository: Arc<dyn AuthorRepository>,
+        read_through_repository: Arc<dyn ReadThroughRepository>,
+        review_repository: Arc<dyn ReviewRepository>,
+        note_repository: Arc<dyn NoteRepository>,
+        series_repository: Arc<dyn SeriesRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        calibre_library_repository: Arc<dyn CalibreLibraryRepository>,
+    ) -> Self {
+        Self {
+            book_repository,
+            author_repository,
+            read_through_repository,
+            review_repository,
+            note_repository,
+            series_repository,
+            tag_repository,
+            calibre_library_repository,
+        }
+    }
+
+    /// すべての本をタイトルの読み順にCSVに書き出す
+    pub async fn export_books_csv(&self) -> Result<String, ApplicationError> {
+        let books = self.book_repository.find_all().await?;
+        Ok(book_csv::write_books(&books)?)
+    }
+
+    /// 本を引用文献の形式で書き出す
+    ///
+    /// `ids` を指定した場合はその本を指定順に、指定しない場合は `filter` に一致する本をタイトルの読み順に書き出す。
+    /// 引用キーのない本にはライブラリのすべての本にまとめて割り当てて保存するため、キーは書き出す範囲や
+    /// その後の本の変更・削除によらず同じになる。
+    pub async fn export_citations(
+        &self,
+        format: CitationFormat,
+        ids: Option<Vec<i32>>,
+        filter: BookFilter,
+    ) -> Result<String, ApplicationError> {
+        let all_books = self.book_repository.find_all().await?;
+        let mut keys = CitationKeys::new(self.book_repository.find_citation_keys().await?);
+        let assigned = keys.assign_missing(&all_books);
+        if !assigned.is_empty() {
+            self.book_repository.save_citation_keys(&assigned).await?;
+        }
+
+        let books = match ids {
+            Some(ids) => {
+                let mut books = Vec::with_capacity(ids.len());
+                let mut seen = HashSet::new();
+                for id in ids.into_iter().filter(|id| seen.insert(*id)) {
+                    let book = all_books
+                        .iter()
+                        .find(|book| book.id() == Some(id))
+                        .ok_or_else(|| {
+                            ApplicationError::NotFound(format!("Book with id {} not found", id))
+                        })?;
+                    books.push(book.clone());
+                }
+                books
+            }
+            None => self.book_repository.find_matching(&filter).await?,
+        };
+
+        Ok(match format {
+            CitationFormat::Bibtex => citation::write_bibtex(&books, &keys),
+            CitationFormat::Ris => citation::write_ris(&books, &keys),
+            CitationFormat::CslJson => citation::write_csl_json(&books, &keys)?,
+        })
+    }
+
+    /// CSVから本を一括登録する
+    ///
+    /// 各行を検証し、エラーのある行は行番号付きで報告する。エラーが1件でもあれば何も登録しない。
+    /// `allow_partial` を指定した場合は、エラーのない行だけを登録する。
+    pub async fn import_books_csv(
+        &self,
+        content: String,
+        column_mapping: Vec<CsvColumnMappingInput>,
+        allow_partial: bool,
+    ) -> Result<BookImportReportDto, ApplicationError> {
+        let overrides: Vec<_> = column_mapping
+            .into_iter()
+            .map(|mapping| (mapping.header, mapping.field.into()))
+            .collect();
+        let rows = book_csv::read_rows(&content, &overrides)?;
+
+        let mut books = Vec::new();
+        let mut errors = Vec::new();
+        // ファイル内で同じISBNが重複していないか確認する
+        let mut seen_isbns = HashSet::new();
+        for row in rows {
+            let result = match row {
+                Ok(row) => {
+                    let line = row.line;
+                    self.validate_row(row, &mut seen_isbns)
+                        .await
+                        .map_err(|e| ImportRowErrorDto {
+                            line,
+                            message: e.to_string(),
+                        })
+                }
+                Err(e) => Err(ImportRowErrorDto {
+                    line: e.line,
+                    message: e.message,
+                }),
+            };
+
+            match result {
+                Ok(book) => books.push(book),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if !errors.is_empty() && !allow_partial {
+            return Ok(BookImportReportDto {
+                imported_count: 0,
+                committed: false,
+                errors,
+                books: Vec::new(),
+            });
+        }
+
+        let saved = self
+            .book_repository
+            .save_all(books, BookChangeSource::Import)
+            .await?;
+        Ok(BookImportReportDto {
+            imported_count: saved.len(),
+            committed: true,
+            errors,
+            books: saved.into_iter().map(BookDto::from).collect(),
+        })
+    }
+
+    /// Goodreads・StoryGraph の書き出しCSVから本と読書状態・評価・感想を取り込む
+    ///
+    /// 本はISBN、なければタイトルと著者で登録済みの本に対応付け、登録済みの本には不足している情報だけを追加する。
+    /// `dry_run` の場合は何も保存せず、行う予定の登録・更新・読み飛ばしを返す。
+    pub async fn import_external_library(
+        &self,
+        source: ExternalLibrarySource,
+        content: String,
+        dry_run: bool,
+    ) -> Result<ExternalImportReportDto, ApplicationError> {
+        let records = match source {
+            ExternalLibrarySource::Goodreads => goodreads::parse(&content)?,
+            ExternalLibrarySource::StoryGraph => storygraph::parse(&content)?,
+        };
+
+        // ファイル内の重複（ISBN、またはタイトルと著者 → 最初の行番号）
+        let mut seen = HashMap::new();
+        let mut items = Vec::with_capacity(records.len());
+        for record in records {
+            let item = match record {
+                Ok(record) => self.import_record(&record, &mut seen, dry_run).await,
+                Err(e) => ExternalImportItemDto {
+                    line: e.line,
+                    action: ExternalImportAction::Fail,
+                    book_id: None,
+                    title: None,
+                    author: None,
+                    isbn: None,
+                    reading_status: None,
+                    rating: None,
+                    shelves: Vec::new(),
+                    changes: Vec::new(),
+                    message: Some(e.message),
+                },
+            };
+            items.push(item);
+        }
+
+        Ok(ExternalImportReportDto::new(dry_run, items))
+    }
+
+    /// Calibre のライブラリ（metadata.db）から本を取り込む
+    ///
+    /// Calibre での本のIDを記録し、再取り込み時は記録した本を Calibre の内容で更新する。
+    /// 初回はISBN、なければタイトルと著者で登録済みの本に対応付ける。ライブラリには書き込まない。
+    pub async fn import_calibre_library(
+        &self,
+        path: String,
+    ) -> Result<CalibreImportReportDto, ApplicationError> {
+        let calibre_books = self
+            .calibre_library_repository
+            .read_books(Path::new(&path))
+            .await?;
+
+        // 今回の取り込みで対応付けた本（本のID → Calibre での本のID）
+        let mut linked = HashMap::new();
+        let mut items = Vec::with_capacity(calibre_books.len());
+        for calibre_book in calibre_books {
+            let (series, series_index) = calibre_book
+                .series
+                .clone()
+                .map_or((None, None), |(name, index)| (Some(name), Some(index)));
+            let mut item = CalibreImportItemDto {
+                calibre_id: calibre_book.calibre_id,
+                action: ExternalImportAction::Fail,
+                book_id: None,
+                title: calibre_book.title.clone(),
+                author: (!calibre_book.authors.is_empty()).then(|| calibre_book.authors.join(", ")),
+                changes: Vec::new(),
+                series,
+                series_index,
+                tags: calibre_book.tags.clone(),
+                publisher: calibre_book.publisher.clone(),
+                message: None,
+            };
+            if let Err(e) = self
+                .import_calibre_book(&calibre_book, &mut linked, &mut item)
+                .await
+            {
+                item.action = ExternalImportAction::Fail;
+                item.message = Some(e.to_string());
+            }
+            items.push(item);
+        }
+
+        Ok(CalibreImportReportDto::new(items))
+    }
+
+    /// Calibre の1冊分を登録・更新し、結果を `item` に記録する
+    async fn import_calibre_book(
+        &self,
+        calibre_book: &CalibreBook,
+        linked: &mut HashMap<i32, i32>,
+        item: &mut CalibreImportItemDto,
+    ) -> Result<(), ApplicationError> {
+        let external_id = calibre_book.calibre_id.to_string();
+        for name in &calibre_book.authors {
+            Author::new(name.clone())?;
+        }
+        // 不正なISBNは無視して取り込む
+        let isbn = match calibre_book.isbn.as_deref().map(Isbn::parse).transpose() {
+            Ok(isbn) => isbn,
+            Err(_) => {
+                item.add_message(format!(
+                    "Ignored invalid ISBN {}",
+                    calibre_book.isbn.as_deref().unwrap_or_default()
+                ));
+                None
+            }
+        };
+        let mut candidate = Book::new(
+            calibre_book.title.clone(),
+            item.author.clone(),
+            calibre_book
+                .comments
+                .as_deref()
+                .map(library_export::html_to_text),
+            calibre_book.published_year,
+            isbn.map(|isbn| isbn.to_string()),
+        )?;
+        // 長すぎる出版社名は無視して取り込む
+        if let Err(e) = candidate.update_publisher(calibre_book.publisher.clone()) {
+            item.add_message(format!("Ignored publisher: {}", e));
+        }
+        let authors: Vec<(String, AuthorRole)> = calibre_book
+            .authors
+            .iter()
+            .map(|name| (name.clone(), AuthorRole::Author))
+            .collect();
+
+        let mut changes = Vec::new();
+        let mut book = match self
+            .book_repository
+            .find_by_external_id(ExternalSource::Calibre, &external_id)
+            .await?
+        {
+            Some(book) => book,
+            None => match self.find_existing(&candidate).await? {
+                Some(book) => {
+                    let book_id = book.id().expect("Existing book must have an ID");
+                    if let Some(other) = linked.get(&book_id) {
+                        return Err(DomainError::ValidationError(format!(
+                            "Matches the same book as Calibre book {}",
+                            other
+                        ))
+                        .into());
+                    }
+                    changes.push(format!("Link Calibre book {}", calibre_book.calibre_id));
+                    book
+                }
+                None => {
+                    // 新しい本
+                    let mut book = candidate;
+                    if !authors.is_empty() {
+                        authorship::link_authors(
+                            self.author_repository.as_ref(),
+                            &mut book,
+                            &authors,
+                        )
+                        .await?;
+                    }
+                    let book = self
+                        .book_repository
+                        .save_with_history(book, ChangeRecord::created(BookChangeSource::Import))
+                        .await?;
+                    let book_id = book.id().expect("Saved book must have an ID");
+                    self.book_repository
+                        .link_external_id(book_id, ExternalSource::Calibre, &external_id)
+                        .await?;
+                    linked.insert(book_id, calibre_book.calibre_id);
+
+                    item.action = ExternalImportAction::Create;
+                    item.book_id = Some(book_id);
+                    item.changes.push("Create book".to_string());
+                    self.link_calibre_series(book_id, calibre_book, &mut item.changes)
+                        .await?;
+                    self.link_calibre_tags(book_id, calibre_book, &mut item.changes)
+                        .await?;
+                    return Ok(());
+                }
+            },
+        };
+        let book_id = book.id().expect("Existing book must have an ID");
+        let newly_linked = !changes.is_empty();
+        let before = BookSnapshot::of(&book);
+
+        // 登録済みの本は Calibre に値がある項目を Calibre の内容で更新する
+        if book.title() != candidate.title() {
+            book.update_details(Some(candidate.title().to_string()), None, None, None, None)?;
+            changes.push(format!("Set title {}", candidate.title()));
+        }
+        if let Some(description) = candidate.description()
+            && book.description() != Some(description)
+        {
+            book.update_details(None, None, Some(description.to_string()), None, None)?;
+            changes.push("Set description".to_string());
+        }
+        if let Some(year) = candidate.published_year()
+            && book.published_year() != Some(year)
+        {
+            book.update_details(None, None, None, Some(year), None)?;
+            changes.push(format!("Set published year {}", year));
+        }
+        if let Some(publisher) = candidate.publisher()
+            && book.publisher() != Some(publisher)
+        {
+            book.update_publisher(Some(publisher.to_string()))?;
+            changes.push(format!("Set publisher {}", publisher));
+        }
+        if let Some(isbn) = candidate.isbn()
+            && book.isbn() != Some(isbn)
+        {
+            if let Some(other) = self.book_repository.find_by_isbn(isbn).await?
+                && other.id() != Some(book_id)
+            {
+                return Err(DomainError::ValidationError(format!(
+                    "ISBN {} already belongs to another book",
+                    isbn
+                ))
+                .into());
+            }
+            book_matching::ensure_isbn_not_trashed(self.book_repository.as_ref(), isbn).await?;
+            book.update_details(None, None, None, None, Some(isbn.to_string()))?;
+            changes.push(format!("Set ISBN {}", isbn));
+        }
+        if !authors.is_empty() && book.author() != candidate.author() {
+            // 翻訳者など著者以外の役割は維持する
+            let mut all_authors = authors.clone();
+            all_authors.extend(
+                book.authors()
+                    .iter()
+                    .filter(|a| a.role() != AuthorRole::Author)
+                    .map(|a| (a.author().name().to_string(), a.role())),
+            );
+            authorship::link_authors(self.author_repository.as_ref(), &mut book, &all_authors)
+                .await?;
+            changes.push(format!("Set authors {}", calibre_book.authors.join(", ")));
+        }
+
+        if changes.len() > usize::from(newly_linked) {
+            self.book_repository
+                .save_with_history(
+                    book,
+                    ChangeRecord::new(
+                        BookChangeAction::Update,
+                        BookChangeSource::Import,
+                        Some(before),
+                    ),
+                )
+                .await?;
+        }
+        if newly_linked {
+            self.book_repository
+                .link_external_id(book_id, ExternalSource::Calibre, &external_id)
+                .await?;
+        }
+        linked.insert(book_id, calibre_book.calibre_id);
+        self.link_calibre_series(book_id, calibre_book, &mut changes)
+            .await?;
+        self.link_calibre_tags(book_id, calibre_book, &mut changes)
+            .await?;
+
+        item.action = if changes.is_empty() {
+            ExternalImportAction::Skip
+        } else {
+            ExternalImportAction::Update
+        };
+        item.book_id = Some(book_id);
+        item.changes = changes;
+        Ok(())
+    }
+
+    /// Calibre のシリーズと巻数を本に設定する（同じ名前のシリーズがなければ刊行中として作成する）
+    async fn link_calibre_series(
+        &self,
+        book_id: i32,
+        calibre_book: &CalibreBook,
+        changes: &mut Vec<String>,
+    ) -> Result<(), ApplicationError> {
+        let Some((name, index)) = &calibre_book.series else {
+            return Ok(());
+        };
+        let volume = VolumeNumber::new(*index)?;
+        let series = match self.series_repository.find_by_name(name.trim()).await? {
+            Some(series) => series,
+            None => {
+                let series = Series::new(name.clone(), None, SeriesStatus::Ongoing, None)?;
+                self.series_repository.save(series).await?
+            }
+        };
+        let series_id = series.id().expect("Saved series must have an ID");
+
+        let current = self.series_repository.find_volume_by_book(book_id).await?;
+        if current.is_some_and(|c| c.series_id() == series_id && c.volume() == volume) {
+            return Ok(());
+        }
+        self.series_repository
+            .save_volume(SeriesVolume::new(book_id, series_id, volume))
+            .await?;
+        changes.push(format!("Set series {} #{}", series.name(), volume.value()));
+        Ok(())
+    }
+
+    /// Calibre のタグを本に付ける（本に付いている他のタグは外さない）
+    async fn link_calibre_tags(
+        &self,
+        book_id: i32,
+        calibre_book: &CalibreBook,
+        changes: &mut Vec<String>,
+    ) -> Result<(), ApplicationError> {
+        let tags = tagging::missing_tags(
+            self.tag_repository.as_ref(),
+            book_id,
+            tagging::tag_names(&calibre_book.tags),
+        )
+        .await?;
+        if tags.is_empty() {
+            return Ok(());
+        }
+
+        tagging::add_tags(self.tag_repository.as_ref(), book_id, &tags).await?;
+        changes.push(tagging::describe(&tags));
+        Ok(())
+    }
+
+    /// 1冊分の処理内容を決め、試行でなければ保存する（失敗は項目の結果として返す）
+    async fn import_record(
+        &self,
+        record: &ExternalBookRecord,
+        seen: &mut HashMap<String, u64>,
+        dry_run: bool,
+    ) -> ExternalImportItemDto {
+        let mut item = ExternalImportItemDto {
+            line: record.line,
+            action: ExternalImportAction::Fail,
+            book_id: None,
+            title: Some(record.title.clone()),
+            author: None,
+            isbn: None,
+            reading_status: record.status.map(Into::into),
+            rating: Self::round_rating(record.rating)
+                .ok()
+                .flatten()
+                .map(Rating::stars),
+            shelves: record.shelves.clone(),
+            changes: Vec::new(),
+            message: None,
+        };
+
+        let plan = match self.plan_record(record, seen).await {
+            Ok(plan) => plan,
+            Err(e) => {
+                item.message = Some(e.to_string());
+                return item;
+            }
+        };
+        item.action = plan.action;
+        item.book_id = plan.book.id();
+        item.author = plan.book.author().map(String::from);
+        item.isbn = plan.book.isbn().map(|isbn| isbn.as_isbn13().to_string());
+        item.message = plan.message.clone();
+        item.changes = plan.changes.clone();
+
+        if dry_run || plan.action == ExternalImportAction::Skip {
+            return item;
+        }
+        match self.apply_plan(plan, record).await {
+            Ok(book_id) => item.book_id = Some(book_id),
+            Err(e) => {
+                item.action = ExternalImportAction::Fail;
+                item.message = Some(e.to_string());
+            }
+        }
+        item
+    }
+
+    /// 1冊分の処理内容を決める（保存はしない）
+    async fn plan_record(
+        &self,
+        record: &ExternalBookRecord,
+        seen: &mut HashMap<String, u64>,
+    ) -> Result<ExternalImportPlan, ApplicationError> {
+        for (name, _) in &record.authors {
+            Author::new(name.clone())?;
+        }
+        let author_names: Vec<&str> = record
+            .authors
+            .iter()
+            .filter(|(_, role)| *role == AuthorRole::Author)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let candidate = Book::new(
+            record.title.clone(),
+            Some(author_names.join(", ")),
+            None,
+            record.published_year,
+            record.isbn.clone(),
+        )?;
+        let rating = Self::round_rating(record.rating)?;
+
+        let key = candidate.isbn().map_or_else(
+            || {
+                format!(
+                    "{}\u{0}{}",
+                    candidate.title().to_lowercase(),
+                    candidate.author().unwrap_or_default().to_lowercase()
+                )
+            },
+            |isbn| isbn.as_isbn13().to_string(),
+        );
+        if let Some(first_line) = seen.get(&key) {
+            return Ok(ExternalImportPlan {
+                action: ExternalImportAction::Skip,
+                message: Some(format!("Duplicate of line {}", first_line)),
+                ..Self::empty_plan(candidate)
+            });
+        }
+        seen.insert(key, record.line);
+
+        let Some(mut book) = self.find_existing(&candidate).await? else {
+            // 新しい本（本のIDは保存時に決まるため、ここでは検証のみ行う）
+            let read_throughs = Self::read_throughs_for(0, record)?;
+            let review = Self::review_for(0, record, rating)?;
+            let note = Self::note_for(0, record)?;
+
+            let tags = tagging::tag_names(&record.shelves);
+
+            let mut changes = vec!["Create book".to_string()];
+            Self::describe_additions(
+                &read_throughs,
+                review.as_ref(),
+                note.is_some(),
+                &mut changes,
+            );
+            if !tags.is_empty() {
+                changes.push(tagging::describe(&tags));
+            }
+            return Ok(ExternalImportPlan {
+                action: ExternalImportAction::Create,
+                save_book: true,
+                link_authors: !record.authors.is_empty(),
+                add_read_throughs: !read_throughs.is_empty(),
+                add_review: review.is_some(),
+                add_note: note.is_some(),
+                add_tags: tags,
+                changes,
+                ..Self::empty_plan(candidate)
+            });
+        };
+
+        // 登録済みの本には不足している情報だけを追加する
+        let book_id = book.id().expect("Existing book must have an ID");
+        let before = BookSnapshot::of(&book);
+        let mut changes = Vec::new();
+        if book.isbn().is_none()
+            && let Some(isbn) = candidate.isbn()
+        {
+            book.update_details(None, None, None, None, Some(isbn.to_string()))?;
+            changes.push(format!("Set ISBN {}", isbn));
+        }
+        if book.published_year().is_none()
+            && let Some(year) = candidate.published_year()
+        {
+            book.update_details(None, None, None, Some(year), None)?;
+            changes.push(format!("Set published year {}", year));
+        }
+        let link_authors = book.authors().is_empty() && !record.authors.is_empty();
+        if link_authors {
+            let names: Vec<&str> = record
+                .authors
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect();
+            changes.push(format!("Set authors {}", names.join(", ")));
+        }
+        let save_book = !changes.is_empty();
+
+        let read_throughs = if self
+            .read_through_repository
+            .find_by_book(book_id)
+            .await?
+            .is_empty()
+        {
+            Self::read_throughs_for(book_id, record)?
+        } else {
+            Vec::new()
+        };
+        let review = if self
+            .review_repository
+            .find_by_book(book_id)
+            .await?
+            .is_none()
+        {
+            Self::review_for(book_id, record, rating)?
+        } else {
+            None
+        };
+        let note = match Self::note_for(book_id, record)? {
+            Some(note) => {
+                let notes = self.note_repository.find_by_book(book_id).await?;
+                (!notes.iter().any(|n| n.body() == note.body())).then_some(note)
+            }
+            None => None,
+        };
+        Self::describe_additions(
+            &read_throughs,
+            review.as_ref(),
+            note.is_some(),
+            &mut changes,
+        );
+        let tags = tagging::missing_tags(
+            self.tag_repository.as_ref(),
+            book_id,
+            tagging::tag_names(&record.shelves),
+        )
+        .await?;
+        if !tags.is_empty() {
+            changes.push(tagging::describe(&tags));
+        }
+
+        let action = if changes.is_empty() {
+            ExternalImportAction::Skip
+        } else {
+            ExternalImportAction::Update
+        };
+        Ok(ExternalImportPlan {
+            action,
+            save_book,
+            change: ChangeRecord::new(
+                BookChangeAction::Update,
+                BookChangeSource::Import,
+                Some(before),
+            ),
+            link_authors,
+            add_read_throughs: !read_throughs.is_empty(),
+            add_review: review.is_some(),
+            add_note: note.is_some(),
+            add_tags: tags,
+            message: (action == ExternalImportAction::Skip)
+                .then(|| "Already in the library with nothing to add".to_string()),
+            changes,
+            ..Self::empty_plan(book)
+        })
+    }
+
+    /// 処理内容に従って保存し、本のIDを返す
+    async fn apply_plan(
+        &self,
+        plan: ExternalImportPlan,
+        record: &ExternalBookRecord,
+    ) -> Result<i32, ApplicationError> {
+        let mut book = plan.book;
+        if plan.link_authors {
+            authorship::link_authors(self.author_repository.as_ref(), &mut book, &record.authors)
+                .await?;
+        }
+        let book_id = if plan.save_book {
+            self.book_repository
+                .save_with_history(book, plan.change)
+                .await?
+                .id()
+        } else {
+            book.id()
+        }
+        .expect("Saved book must have an ID");
+
+        if plan.add_read_throughs {
+            for read_through in Self::read_throughs_for(book_id, record)? {
+                self.read_through_repository.save(read_through).await?;
+            }
+        }
+        if plan.add_review
+            && let Some(review) =
+                Self::review_for(book_id, record, Self::round_rating(record.rating)?)?
+        {
+            self.review_repository.save(review).await?;
+        }
+        if plan.add_note
+            && let Some(note) = Self::note_for(book_id, record)?
+        {
+            self.note_repository.save(note).await?;
+        }
+        tagging::add_tags(self.tag_repository.as_ref(), book_id, &plan.add_tags).await?;
+
+        Ok(book_id)
+    }
+
+    /// ISBN、なければタイトルと著者で登録済みの本を探す（ISBNがゴミ箱にある本のものの場合はエラー）
+    async fn find_existing(&self, candidate: &Book) -> Result<Option<Book>, ApplicationError> {
+        if let Some(isbn) = candidate.isbn() {
+            if let Some(book) = self.book_repository.find_by_isbn(isbn).await? {
+                return Ok(Some(book));
+            }
+            book_matching::ensure_isbn_not_trashed(self.book_repository.as_ref(), isbn).await?;
+        }
+
+        // ISBNが異なる本は別の版とみなす
+        let candidates: Vec<Book> = self
+            .book_repository
+            .find_by_title(candidate.title())
+            .await?
+            .into_iter()
+            .filter(|book| candidate.isbn().is_none() || book.isbn().is_none())
+            .collect();
+        Ok(book_matching::find_by_author(&candidates, candidate.author()).cloned())
+    }
+
+    fn empty_plan(book: Book) -> ExternalImportPlan {
+        ExternalImportPlan {
+            action: ExternalImportAction::Skip,
+            book,
+            save_book: false,
+            change: ChangeRecord::created(BookChangeSource::Import),
+            link_authors: false,
+            add_read_throughs: false,
+            add_review: false,
+            add_note: false,
+            add_tags: Vec::new(),
+            changes: Vec::new(),
+            message: None,
+        }
+    }
+
+    /// 通読の履歴を作成する
+    ///
+    /// 履歴に現在の読書状態が含まれていない場合は追加する（日付が分からない場合は登録日、なければ今日）。
+    fn read_throughs_for(
+        book_id: i32,
+        record: &ExternalBookRecord,
+    ) -> Result<Vec<ReadThrough>, DomainError> {
+        let mut read_throughs = Vec::new();
+        for (index, read) in record.reads.iter().enumerate() {
+            // 中断した場合は最後の通読のみ中断とする
+            let closing = if index + 1 == record.reads.len()
+                && record.status == Some(ReadingStatus::Abandoned)
+            {
+                ReadingStatus::Abandoned
+            } else {
+                ReadingStatus::Finished
+            };
+            let read_through = match (read.started_on, read.finished_on) {
+                (Some(started_on), Some(finished_on)) => {
+                    let mut read_through =
+                        ReadThrough::new(book_id, ReadingStatus::Reading, started_on);
+                    read_through.change_status(closing, finished_on)?;
+                    read_through
+                }
+                (None, Some(finished_on)) => ReadThrough::new(book_id, closing, finished_on),
+                (Some(started_on), None) => {
+                    ReadThrough::new(book_id, ReadingStatus::Reading, started_on)
+                }
+                (None, None) => continue,
+            };
+            read_throughs.push(read_through);
+        }
+
+        if read_throughs.is_empty()
+            && let Some(status) = record.status
+        {
+            let on = record.added_on.unwrap_or_else(today);
+            read_throughs.push(ReadThrough::new(book_id, status, on));
+        }
+
+        Ok(read_throughs)
+    }
+
+    fn review_for(
+        book_id: i32,
+        record: &ExternalBookRecord,
+        rating: Option<Rating>,
+    ) -> Result<Option<Review>, DomainError> {
+        if rating.is_none() && record.review.is_none() {
+            return Ok(None);
+        }
+        Review::new(book_id, rating, record.review.clone()).map(Some)
+    }
+
+    fn note_for(book_id: i32, record: &ExternalBookRecord) -> Result<Option<Note>, DomainError> {
+        record
+            .private_notes
+            .clone()
+            .map(|body| Note::new(book_id, body))
+            .transpose()
+    }
+
+    /// 星の数を0.5刻みに丸める（StoryGraph は0.25刻み）
+    fn round_rating(stars: Option<f64>) -> Result<Option<Rating>, DomainError> {
+        stars
+            .map(|stars| Rating::new(((stars * 2.0).round() / 2.0).max(0.5)))
+            .transpose()
+    }
+
+    /// 追加する読書状態・感想・メモの説明
+    fn describe_additions(
+        read_throughs: &[ReadThrough],
+        review: Option<&Review>,
+        add_note: bool,
+        changes: &mut Vec<String>,
+    ) {
+        for read_through in read_throughs {
+            let dates = match (read_through.started_on(), read_through.finished_on()) {
+                (Some(started_on), Some(finished_on)) => {
+                    format!(" ({} to {})", started_on, finished_on)
+                }
+                (Some(started_on), None) => format!(" (since {})", started_on),
+                (None, Some(finished_on)) => format!(" (on {})", finished_on),
+                (None, None) => String::new(),
+            };
+            let description = format!(
+                "Add read-through: {}{}",
+                read_through.status().as_str(),
+                dates
+            );
+            changes.push(description);
+        }
+        if let Some(review) = review {
+            changes.push(match review.rating() {
+                Some(rating) => format!("Add review ({} stars)", rating.stars()),
+                None => "Add review".to_string(),
+            });
+        }
+        if add_note {
+            changes.push("Add private note".to_string());
+        }
+    }
+
+    /// 1行分の値から本を作成して検証する（登録済み・ファイル内のISBNの重複も確認する）
+    async fn validate_row(
+        &self,
+        row: BookCsvRow,
+        seen_isbns: &mut HashSet<String>,
+    ) -> Result<Book, ApplicationError> {
+        let published_year = row
+            .published_year
+            .map(|year| {
+                year.parse::<i32>().map_err(|_| {
+                    DomainError::ValidationError(format!("Invalid published year: {}", year))
+                })
+            })
+            .transpose()?;
+
+        let book = Book::new(
+            row.title.unwrap_or_default(),
+            row.author,
+            row.description,
+            published_year,
+            row.isbn,
+        )?;
+        if let Some(name) = book.author() {
+            Author::new(name.to_string())?;
+        }
+
+        if let Some(isbn) = book.isbn() {
+            if !seen_isbns.insert(isbn.as_isbn13().to_string()) {
+                return Err(DomainError::ValidationError(format!(
+                    "ISBN {} appears more than once in the file",
+                    isbn
+                ))
+                .into());
+            }
+            if self.book_repository.find_by_isbn(isbn).await?.is_some() {
+                return Err(DomainError::ValidationError(format!(
+                    "A book with ISBN {} already exists",
+                    isbn
+                ))
+                .into());
+            }
+            book_matching::ensure_isbn_not_trashed(self.book_repository.as_ref(), isbn).await?;
+        }
+
+        Ok(book)
+    }
+}
+
+/// 日付が分からない場合に使う今日の日付（ローカル時刻）
+fn today() -> NaiveDate {
+    chrono::Local::now().date_naive()
+}
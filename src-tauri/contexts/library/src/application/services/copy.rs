@@ -0,0 +1,325 @@
+// Library Application Layer - 所有している本の1冊・場所 アプリケーションサービス
+
+use crate::application::dto::copy::{
+    AddCopyInput, BookLocationKind, CopyDto, LocationDto, PriceInput, UpdateCopyInput,
+};
+use crate::application::errors::ApplicationError;
+use crate::application::services::book::MAX_PAGE_SIZE;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{copy::Copy, location::Location},
+    repositories::{book::BookRepository, copy::CopyRepository, location::LocationRepository},
+    value_objects::{price::Price, search_query::SearchQuery},
+};
+use std::sync::Arc;
+
+/// 所有している本の1冊ごとの記録と、本を置く場所（部屋・本棚・棚）のユースケースを実装するサービス
+pub struct CopyService {
+    copy_repository: Arc<dyn CopyRepository>,
+    location_repository: Arc<dyn LocationRepository>,
+    book_repository: Arc<dyn BookRepository>,
+}
+
+impl CopyService {
+    pub fn new(
+        copy_repository: Arc<dyn CopyRepository>,
+        location_repository: Arc<dyn LocationRepository>,
+        book_repository: Arc<dyn BookRepository>,
+    ) -> Self {
+        Self {
+            copy_repository,
+            location_repository,
+            book_repository,
+        }
+    }
+
+    /// 1つ下の階層の場所を名前の順に取得（`parent_id` が None の場合は部屋）
+    pub async fn list_locations(
+        &self,
+        parent_id: Option<i32>,
+    ) -> Result<Vec<LocationDto>, ApplicationError> {
+        let locations = self.location_repository.find_children(parent_id).await?;
+        Ok(locations.into_iter().map(LocationDto::from).collect())
+    }
+
+    /// IDで場所を取得
+    pub async fn get_location(&self, id: i32) -> Result<Option<LocationDto>, ApplicationError> {
+        let location = self.location_repository.find_by_id(id).await?;
+        Ok(location.map(LocationDto::from))
+    }
+
+    /// 部屋から場所までの経路（部屋が先頭で、場所自身を含む）
+    pub async fn get_location_path(&self, id: i32) -> Result<Vec<LocationDto>, ApplicationError> {
+        let locations = self.location_repository.find_all().await?;
+        Ok(path_of(&locations, id)
+            .into_iter()
+            .map(LocationDto::from)
+            .collect())
+    }
+
+    /// 場所（下位の場所を含む）に置いてある冊数
+    pub async fn count_copies_in(&self, location_id: i32) -> Result<u64, ApplicationError> {
+        let locations = self.location_repository.find_all().await?;
+        let counts = self.copy_repository.count_by_location().await?;
+        Ok(subtree_ids(&locations, location_id)
+            .iter()
+            .filter_map(|id| counts.get(id))
+            .sum())
+    }
+
+    /// 場所を作成（部屋は最上位に、本棚は部屋に、棚は本棚に作る）
+    pub async fn create_location(
+        &self,
+        name: String,
+        kind: BookLocationKind,
+        parent_id: Option<i32>,
+    ) -> Result<LocationDto, ApplicationError> {
+        let parent = self.find_parent(parent_id).await?;
+        let location = Location::new(name, kind.into(), parent.as_ref())?;
+        self.ensure_name_available(&location).await?;
+
+        let saved = self.location_repository.save(location).await?;
+        Ok(LocationDto::from(saved))
+    }
+
+    /// 場所の名前を変更・別の場所に移す（`parent_id` は `Some(None)` で最上位に移す）
+    pub async fn update_location(
+        &self,
+        id: i32,
+        name: Option<String>,
+        parent_id: Option<Option<i32>>,
+    ) -> Result<LocationDto, ApplicationError> {
+        let mut location = self.find_location(id).await?;
+        if let Some(name) = name {
+            location.rename(name)?;
+        }
+        if let Some(parent_id) = parent_id {
+            let parent = self.find_parent(parent_id).await?;
+            location.move_to(parent.as_ref())?;
+        }
+        self.ensure_name_available(&location).await?;
+
+        let saved = self.location_repository.save(location).await?;
+        Ok(LocationDto::from(saved))
+    }
+
+    /// 場所を削除（下位の場所や置いてある本がある場合は削除できない）
+    pub async fn delete_location(&self, id: i32) -> Result<(), ApplicationError> {
+        let location = self.find_location(id).await?;
+        let has_children = !self
+            .location_repository
+            .find_children(Some(id))
+            .await?
+            .is_empty();
+        let has_copies = !self
+            .copy_repository
+            .find_in_locations(&[id])
+            .await?
+            .is_empty();
+        if has_children || has_copies {
+            return Err(DomainError::InvalidState(format!(
+                "Location {} is not empty",
+                location.name()
+            ))
+            .into());
+        }
+
+        self.location_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// IDで1冊を取得
+    pub async fn get_copy(&self, id: i32) -> Result<Option<CopyDto>, ApplicationError> {
+        let copy = self.copy_repository.find_by_id(id).await?;
+        Ok(copy.map(CopyDto::from))
+    }
+
+    /// 本の所有しているすべての冊を登録順に取得
+    pub async fn get_book_copies(&self, book_id: i32) -> Result<Vec<CopyDto>, ApplicationError> {
+        let copies = self.copy_repository.find_by_book(book_id).await?;
+        Ok(copies.into_iter().map(CopyDto::from).collect())
+    }
+
+    /// 所有している本の1冊を追加
+    pub async fn add_copy(&self, input: AddCopyInput) -> Result<CopyDto, ApplicationError> {
+        self.book_repository
+            .find_by_id(input.book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", input.book_id))
+            })?;
+        if let Some(location_id) = input.location_id {
+            self.find_location(location_id).await?;
+        }
+
+        let mut copy = Copy::new(input.book_id, input.format.into());
+        copy.change_condition(input.condition.map(Into::into));
+        copy.change_acquired_on(input.acquired_on);
+        copy.change_price(input.price.map(parse_price).transpose()?);
+        copy.move_to(input.location_id);
+
+        let saved = self.copy_repository.save(copy).await?;
+        Ok(CopyDto::from(saved))
+    }
+
+    /// 1冊の情報を更新（省略した項目は変更せず、`null` を指定した項目は未設定に戻す）
+    pub async fn update_copy(
+        &self,
+        id: i32,
+        input: UpdateCopyInput,
+    ) -> Result<CopyDto, ApplicationError> {
+        let mut copy = self.find_copy(id).await?;
+
+        if let Some(format) = input.format {
+            copy.change_format(format.into());
+        }
+        if !input.condition.is_undefined() {
+            copy.change_condition(input.condition.take().map(Into::into));
+        }
+        if !input.acquired_on.is_undefined() {
+            copy.change_acquired_on(input.acquired_on.take());
+        }
+        if !input.price.is_undefined() {
+            copy.change_price(input.price.take().map(parse_price).transpose()?);
+        }
+        if !input.location_id.is_undefined() {
+            let location_id = input.location_id.take();
+            if let Some(location_id) = location_id {
+                self.find_location(location_id).await?;
+            }
+            copy.move_to(location_id);
+        }
+
+        let saved = self.copy_repository.save(copy).await?;
+        Ok(CopyDto::from(saved))
+    }
+
+    /// 1冊を削除（本そのものは削除しない）
+    pub async fn delete_copy(&self, id: i32) -> Result<(), ApplicationError> {
+        self.copy_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// 本を全文検索し、一致した本の冊を関連度の高い本の順に取得（置いてある場所を探す用）
+    pub async fn where_is(
+        &self,
+        query: String,
+        limit: usize,
+    ) -> Result<Vec<CopyDto>, ApplicationError> {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(DomainError::ValidationError(format!(
+                "Search limit must be between 1 and {}",
+                MAX_PAGE_SIZE
+            ))
+            .into());
+        }
+
+        let query = SearchQuery::parse(&query)?;
+        let hits = self.book_repository.search(&query, limit as u64).await?;
+
+        let mut dtos = Vec::new();
+        for hit in hits {
+            if let Some(book_id) = hit.book.id() {
+                let copies = self.copy_repository.find_by_book(book_id).await?;
+                dtos.extend(copies.into_iter().map(CopyDto::from));
+            }
+        }
+        Ok(dtos)
+    }
+
+    /// 場所（下位の場所を含む）に置いてある冊を本のタイトルの読み順に取得
+    ///
+    /// `location_id` が None の場合は場所が未設定の冊を取得する。
+    pub async fn get_location_inventory(
+        &self,
+        location_id: Option<i32>,
+    ) -> Result<Vec<CopyDto>, ApplicationError> {
+        let copies = match location_id {
+            Some(location_id) => {
+                self.find_location(location_id).await?;
+                let locations = self.location_repository.find_all().await?;
+                self.copy_repository
+                    .find_in_locations(&subtree_ids(&locations, location_id))
+                    .await?
+            }
+            None => self.copy_repository.find_unplaced().await?,
+        };
+        Ok(copies.into_iter().map(CopyDto::from).collect())
+    }
+
+    async fn find_location(&self, id: i32) -> Result<Location, ApplicationError> {
+        self.location_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Location with id {} not found", id)))
+    }
+
+    async fn find_parent(&self, id: Option<i32>) -> Result<Option<Location>, ApplicationError> {
+        match id {
+            Some(id) => Ok(Some(self.find_location(id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_copy(&self, id: i32) -> Result<Copy, ApplicationError> {
+        self.copy_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Copy with id {} not found", id)))
+    }
+
+    /// ビジネスルール: 同じ場所の中で名前は重複できない
+    async fn ensure_name_available(&self, location: &Location) -> Result<(), ApplicationError> {
+        let siblings = self
+            .location_repository
+            .find_children(location.parent_id())
+            .await?;
+        if siblings
+            .iter()
+            .any(|sibling| sibling.name() == location.name() && sibling.id() != location.id())
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Location {} already exists",
+                location.name()
+            ))
+            .into());
+        }
+        Ok(())
+    }
+}
+
+fn parse_price(input: PriceInput) -> Result<Price, DomainError> {
+    Price::parse(&input.amount, &input.currency)
+}
+
+/// 場所とその下位の場所のID
+fn subtree_ids(locations: &[Location], root: i32) -> Vec<i32> {
+    let mut ids = vec![root];
+    let mut index = 0;
+    while index < ids.len() {
+        let parent = ids[index];
+        ids.extend(
+            locations
+                .iter()
+                .filter(|location| location.parent_id() == Some(parent))
+                .filter_map(Location::id),
+        );
+        index += 1;
+    }
+    ids
+}
+
+/// 部屋から場所までの経路（部屋が先頭）
+fn path_of(locations: &[Location], id: i32) -> Vec<Location> {
+    let mut path = Vec::new();
+    let mut current = Some(id);
+    while let Some(id) = current {
+        let Some(location) = locations.iter().find(|location| location.id() == Some(id)) else {
+            break;
+        };
+        path.push(location.clone());
+        current = location.parent_id();
+    }
+    path.reverse();
+    path
+}
@@ -0,0 +1,105 @@
+// Library Application Layer - 表紙画像 アプリケーションサービス
+
+use crate::application::dto::{book::BookDto, cover::parse_cover_path};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::book::Book,
+    repositories::{book::BookRepository, cover::CoverImageRepository},
+    value_objects::cover_hash::CoverHash,
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 本の表紙画像のユースケースを実装するサービス
+pub struct CoverService {
+    cover_repository: Arc<dyn CoverImageRepository>,
+    book_repository: Arc<dyn BookRepository>,
+}
+
+impl CoverService {
+    pub fn new(
+        cover_repository: Arc<dyn CoverImageRepository>,
+        book_repository: Arc<dyn BookRepository>,
+    ) -> Self {
+        Self {
+            cover_repository,
+            book_repository,
+        }
+    }
+
+    /// Base64 でエンコードされた画像を本の表紙にする（`data:` URL 形式も受け付ける）
+    pub async fn set_book_cover(
+        &self,
+        book_id: i32,
+        image_base64: &str,
+    ) -> Result<BookDto, ApplicationError> {
+        let book = self.find_book(book_id).await?;
+
+        let encoded = image_base64
+            .split_once(";base64,")
+            .map_or(image_base64, |(_, data)| data);
+        let bytes = STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| DomainError::ValidationError(format!("Invalid base64 image: {}", e)))?;
+        let hash = self.cover_repository.save(bytes).await?;
+
+        self.replace_cover(book, Some(hash)).await
+    }
+
+    /// ローカルのファイルの画像を本の表紙にする（ファイルは管理ディレクトリにコピーする）
+    pub async fn set_book_cover_from_file(
+        &self,
+        book_id: i32,
+        path: &Path,
+    ) -> Result<BookDto, ApplicationError> {
+        let book = self.find_book(book_id).await?;
+        let hash = self.cover_repository.save_file(path).await?;
+
+        self.replace_cover(book, Some(hash)).await
+    }
+
+    /// 本の表紙画像を外す
+    pub async fn remove_book_cover(&self, book_id: i32) -> Result<BookDto, ApplicationError> {
+        let book = self.find_book(book_id).await?;
+        self.replace_cover(book, None).await
+    }
+
+    /// カスタムプロトコルで要求されたパスの画像を読み込む（該当する画像がない場合は None）
+    pub async fn read_cover_resource(
+        &self,
+        path: &str,
+    ) -> Result<Option<Vec<u8>>, ApplicationError> {
+        let Some((hash, size)) = parse_cover_path(path) else {
+            return Ok(None);
+        };
+        Ok(self.cover_repository.read(&hash, size).await?)
+    }
+
+    /// 表紙を置き換え、どの本からも使われなくなった画像を削除する
+    async fn replace_cover(
+        &self,
+        mut book: Book,
+        cover: Option<CoverHash>,
+    ) -> Result<BookDto, ApplicationError> {
+        let previous = book.cover().cloned();
+        book.set_cover(cover);
+        let saved = self.book_repository.save(book).await?;
+
+        if let Some(previous) = previous.filter(|hash| Some(hash) != saved.cover())
+            && self.book_repository.count_by_cover(&previous).await? == 0
+        {
+            self.cover_repository.delete(&previous).await?;
+        }
+
+        Ok(BookDto::from(saved))
+    }
+
+    async fn find_book(&self, id: i32) -> Result<Book, ApplicationError> {
+        self.book_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Book with id {} not found", id)))
+    }
+}
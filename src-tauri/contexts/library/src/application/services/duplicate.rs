@@ -0,0 +1,397 @@
+// Library Application Layer - 重複した本の検出・統合 アプリケーションサービス
+
+use crate::application::dto::{
+    book::BookDto,
+    duplicate::{BookMergeField, DuplicateGroupDto, DuplicateReason, MergeFieldChoiceInput},
+};
+use crate::application::errors::ApplicationError;
+use crate::application::services::book_matching::author_similarity;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{
+        book::Book,
+        book_change::{BookSnapshot, ChangeRecord},
+    },
+    repositories::{book::BookRepository, cover::CoverImageRepository},
+    value_objects::{
+        book_change_action::BookChangeAction, book_change_source::BookChangeSource,
+        sort_key::SortKey,
+    },
+};
+use chrono::Utc;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+/// 重複として報告する確からしさの既定の下限
+const DEFAULT_MIN_CONFIDENCE: f64 = 0.5;
+
+/// 2冊の本の結び付き（重複である確からしさと根拠）
+struct DuplicateLink {
+    a: usize,
+    b: usize,
+    confidence: f64,
+    reasons: Vec<DuplicateReason>,
+}
+
+/// 重複した本の検出と統合のユースケースを実装するサービス
+pub struct DuplicateService {
+    book_repository: Arc<dyn BookRepository>,
+    cover_repository: Arc<dyn CoverImageRepository>,
+}
+
+impl DuplicateService {
+    pub fn new(
+        book_repository: Arc<dyn BookRepository>,
+        cover_repository: Arc<dyn CoverImageRepository>,
+    ) -> Self {
+        Self {
+            book_repository,
+            cover_repository,
+        }
+    }
+
+    /// 重複している可能性がある本をグループにまとめて取得（確からしさの高い順）
+    ///
+    /// ISBNまたは正規化したタイトルが一致する本を候補とし、著者・出版年で確からしさを調整する。
+    pub async fn find_duplicates(
+        &self,
+        min_confidence: Option<f64>,
+    ) -> Result<Vec<DuplicateGroupDto>, ApplicationError> {
+        let min_confidence = min_confidence.unwrap_or(DEFAULT_MIN_CONFIDENCE);
+        if !(0.0..=1.0).contains(&min_confidence) {
+            return Err(DomainError::ValidationError(
+                "Minimum confidence must be between 0 and 1".to_string(),
+            )
+            .into());
+        }
+
+        let books = self.book_repository.find_all().await?;
+
+        // ISBN・タイトルが一致する本の組だけを比較する
+        let mut buckets: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, book) in books.iter().enumerate() {
+            if let Some(isbn) = book.isbn() {
+                buckets
+                    .entry(format!("isbn:{}", isbn.as_isbn13()))
+                    .or_default()
+                    .push(index);
+            }
+            let title = title_key(book.title());
+            if !title.is_empty() {
+                buckets
+                    .entry(format!("title:{}", title))
+                    .or_default()
+                    .push(index);
+            }
+        }
+        let mut pairs = BTreeSet::new();
+        for indexes in buckets.values() {
+            for (i, &a) in indexes.iter().enumerate() {
+                for &b in &indexes[i + 1..] {
+                    pairs.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+
+        let mut links: Vec<DuplicateLink> = pairs
+            .into_iter()
+            .map(|(a, b)| score(&books, a, b))
+            .filter(|link| link.confidence >= min_confidence)
+            .collect();
+
+        // 確からしさの高い結び付きから順にグループにまとめる（グループの確からしさは最も弱い結び付きの値）
+        links.sort_by(|x, y| y.confidence.total_cmp(&x.confidence));
+        let mut groups = DisjointSet::new(books.len());
+        let used: Vec<&DuplicateLink> = links
+            .iter()
+            .filter(|link| groups.union(link.a, link.b))
+            .collect();
+
+        let mut grouped: HashMap<usize, (f64, BTreeSet<DuplicateReason>)> = HashMap::new();
+        for link in used {
+            let entry = grouped
+                .entry(groups.find(link.a))
+                .or_insert((1.0, BTreeSet::new()));
+            entry.0 = entry.0.min(link.confidence);
+            entry.1.extend(link.reasons.iter().copied());
+        }
+
+        let mut members: HashMap<usize, Vec<BookDto>> = HashMap::new();
+        for (index, book) in books.into_iter().enumerate() {
+            let root = groups.find(index);
+            if grouped.contains_key(&root) {
+                members.entry(root).or_default().push(BookDto::from(book));
+            }
+        }
+
+        let mut result: Vec<DuplicateGroupDto> = grouped
+            .into_iter()
+            .map(|(root, (confidence, reasons))| {
+                let mut books = members.remove(&root).unwrap_or_default();
+                books.sort_by_key(|book| book.id);
+                DuplicateGroupDto {
+                    books,
+                    confidence,
+                    reasons: reasons.into_iter().collect(),
+                }
+            })
+            .collect();
+        result.sort_by(|x, y| {
+            y.confidence
+                .total_cmp(&x.confidence)
+                .then_with(|| x.books[0].id.cmp(&y.books[0].id))
+        });
+
+        Ok(result)
+    }
+
+    /// 複数の本を1冊にまとめる
+    ///
+    /// 各項目は指定された本の値を採り、指定がない項目はまとめ先の値（空の場合はまとめる本の値を順に）を採る。
+    /// まとめる本の読書記録・メモ・ハイライト・タグ・本棚・ファイルなどはまとめ先に付け替え、本はゴミ箱に移す
+    /// （まとめ先に引き継いだISBNは外す）。まとめ先・まとめる本の変更履歴にはまとめとして記録する。
+    pub async fn merge_books(
+        &self,
+        primary_id: i32,
+        other_ids: Vec<i32>,
+        field_choices: Vec<MergeFieldChoiceInput>,
+    ) -> Result<BookDto, ApplicationError> {
+        // ビジネスルール: まとめる本は1冊以上で、まとめ先・重複を含まないこと
+        if other_ids.is_empty() {
+            return Err(DomainError::ValidationError(
+                "At least one book to merge is required".to_string(),
+            )
+            .into());
+        }
+        let mut seen = HashSet::new();
+        if other_ids
+            .iter()
+            .any(|&id| id == primary_id || !seen.insert(id))
+        {
+            return Err(DomainError::ValidationError(
+                "Books to merge must be distinct and must not include the primary book".to_string(),
+            )
+            .into());
+        }
+
+        let primary = self.find_book(primary_id).await?;
+        let mut others = Vec::with_capacity(other_ids.len());
+        for &id in &other_ids {
+            others.push(self.find_book(id).await?);
+        }
+
+        let mut choices: HashMap<BookMergeField, &Book> = HashMap::new();
+        for choice in &field_choices {
+            let source = std::iter::once(&primary)
+                .chain(&others)
+                .find(|book| book.id() == Some(choice.book_id))
+                .ok_or_else(|| {
+                    DomainError::ValidationError(format!(
+                        "Book {} is not one of the books being merged",
+                        choice.book_id
+                    ))
+                })?;
+            choices.insert(choice.field, source);
+        }
+
+        // 指定された本の値、またはまとめ先から順に最初に見つかった値を採る
+        let pick = |field: BookMergeField, has_value: fn(&Book) -> bool| -> &Book {
+            choices.get(&field).copied().unwrap_or_else(|| {
+                std::iter::once(&primary)
+                    .chain(&others)
+                    .find(|book| has_value(book))
+                    .unwrap_or(&primary)
+            })
+        };
+        let authors = pick(BookMergeField::Authors, |b| {
+            b.author().is_some() || !b.authors().is_empty()
+        });
+        let description = pick(BookMergeField::Description, |b| b.description().is_some());
+        let published_year = pick(BookMergeField::PublishedYear, |b| {
+            b.published_year().is_some()
+        });
+        let publisher = pick(BookMergeField::Publisher, |b| b.publisher().is_some());
+        let isbn = pick(BookMergeField::Isbn, |b| b.isbn().is_some());
+        let cover = pick(BookMergeField::Cover, |b| b.cover().is_some());
+        let readings = pick(BookMergeField::Readings, |b| {
+            b.title_reading().is_some() || b.author_reading().is_some()
+        });
+        let classification = pick(BookMergeField::Classification, |b| {
+            b.ndc().is_some() || b.ddc().is_some()
+        });
+
+        let mut merged = Book::reconstruct(
+            primary_id,
+            pick(BookMergeField::Title, |_| true).title().to_string(),
+            authors.author().map(String::from),
+            description.description().map(String::from),
+            published_year.published_year(),
+            isbn.isbn().cloned(),
+        );
+        if !authors.authors().is_empty() {
+            merged.set_authors(authors.authors().to_vec())?;
+        }
+        merged.update_publisher(Some(publisher.publisher().unwrap_or_default().to_string()))?;
+        merged.set_cover(cover.cover().cloned());
+        // 読みを引き継ぎ、並び替え用のキーも読みから作り直す
+        merged.update_readings(
+            Some(readings.title_reading().unwrap_or_default().to_string()),
+            Some(readings.author_reading().unwrap_or_default().to_string()),
+        )?;
+        merged.update_classification(
+            Some(
+                classification
+                    .ndc()
+                    .map_or("", |ndc| ndc.as_str())
+                    .to_string(),
+            ),
+            Some(
+                classification
+                    .ddc()
+                    .map_or("", |ddc| ddc.as_str())
+                    .to_string(),
+            ),
+        )?;
+        merged.load_version(primary.version());
+
+        let previous_covers: HashSet<_> = std::iter::once(&primary)
+            .chain(&others)
+            .filter_map(|book| book.cover().cloned())
+            .collect();
+
+        // まとめる本はゴミ箱に移し、元に戻せるようにする
+        let now = Utc::now();
+        let mut trashed = Vec::with_capacity(others.len());
+        for mut other in others {
+            let before = BookSnapshot::of(&other);
+            if other.isbn().is_some() && other.isbn() == merged.isbn() {
+                other.clear_isbn();
+            }
+            other.move_to_trash(now)?;
+            trashed.push((
+                other,
+                ChangeRecord::new(
+                    BookChangeAction::Merge,
+                    BookChangeSource::Merge,
+                    Some(before),
+                ),
+            ));
+        }
+
+        let change = ChangeRecord::new(
+            BookChangeAction::Merge,
+            BookChangeSource::Merge,
+            Some(BookSnapshot::of(&primary)),
+        );
+        let saved = self.book_repository.merge(merged, trashed, change).await?;
+
+        // どの本からも使われなくなった表紙画像を削除する
+        for hash in previous_covers {
+            if Some(&hash) != saved.cover()
+                && self.book_repository.count_by_cover(&hash).await? == 0
+            {
+                self.cover_repository.delete(&hash).await?;
+            }
+        }
+
+        Ok(BookDto::from(saved))
+    }
+
+    async fn find_book(&self, id: i32) -> Result<Book, ApplicationError> {
+        self.book_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Book with id {} not found", id)))
+    }
+}
+
+/// 重複の判定に使うタイトル（並び替えキーと同じ正規化をし、文字・数字以外を除いたもの）
+fn title_key(title: &str) -> String {
+    SortKey::new(title)
+        .as_str()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// 2冊の本が重複である確からしさを求める
+///
+/// ISBNの一致を最も強い根拠とし、タイトルのみ一致する場合は著者・出版年で上下させる。
+/// ISBNが異なる本は別の版とみなして確からしさを下げる。
+fn score(books: &[Book], a: usize, b: usize) -> DuplicateLink {
+    let (x, y) = (&books[a], &books[b]);
+    let mut reasons = Vec::new();
+
+    let same_title = title_key(x.title()) == title_key(y.title());
+    if same_title {
+        reasons.push(DuplicateReason::SameTitle);
+    }
+
+    let mut confidence = match (x.isbn(), y.isbn()) {
+        (Some(i), Some(j)) if i == j => {
+            reasons.push(DuplicateReason::SameIsbn);
+            if same_title { 1.0 } else { 0.9 }
+        }
+        (Some(_), Some(_)) => 0.3,
+        _ => 0.5,
+    };
+
+    match author_similarity(x, y) {
+        // 著者が分からない本は判断材料が少ないため少しだけ上げる
+        None => confidence += 0.1,
+        Some(similarity) if similarity > 0.0 => {
+            reasons.push(DuplicateReason::SimilarAuthors);
+            confidence += 0.35 * similarity;
+        }
+        Some(_) => confidence -= 0.3,
+    }
+
+    match (x.published_year(), y.published_year()) {
+        (Some(i), Some(j)) if i == j => {
+            reasons.push(DuplicateReason::SamePublishedYear);
+            confidence += 0.05;
+        }
+        (Some(_), Some(_)) => confidence -= 0.1,
+        _ => {}
+    }
+
+    DuplicateLink {
+        a,
+        b,
+        confidence: confidence.clamp(0.0, 1.0),
+        reasons,
+    }
+}
+
+/// 本のグループ分け用の素集合（Union-Find）
+struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let parent = self.parents[index];
+        if parent == index {
+            return index;
+        }
+        let root = self.find(parent);
+        self.parents[index] = root;
+        root
+    }
+
+    /// 2つのグループをまとめる（すでに同じグループの場合は false）
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        self.parents[b.max(a)] = a.min(b);
+        true
+    }
+}
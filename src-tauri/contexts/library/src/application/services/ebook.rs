@@ -0,0 +1,358 @@
+// Library Application Layer - 電子書籍ファイル アプリケーションサービス
+
+use crate::application::dto::{
+    book::BookDto,
+    ebook::{BookFileDto, EbookCreatorDto, EbookImportResultDto, EbookMetadataDto},
+};
+use crate::application::errors::ApplicationError;
+use crate::application::importers::library_export;
+use crate::application::services::{authorship, book_matching};
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{book::Book, book_change::ChangeRecord, book_file::BookFile},
+    repositories::{
+        author::AuthorRepository,
+        book::BookRepository,
+        book_file::BookFileRepository,
+        cover::CoverImageRepository,
+        ebook::{EbookFileStore, EbookMetadata, EbookMetadataReader},
+    },
+    value_objects::{
+        author_role::AuthorRole, book_change_source::BookChangeSource, ebook_format::EbookFormat,
+        isbn::Isbn,
+    },
+};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// 電子書籍ファイルから読み込んだ、本の登録に使う値
+struct EbookDetails {
+    title: String,
+    author: Option<String>,
+    description: Option<String>,
+    isbn: Option<Isbn>,
+}
+
+/// 電子書籍ファイル（EPUB・PDF）の取り込みと本への紐付けのユースケースを実装するサービス
+pub struct EbookService {
+    book_repository: Arc<dyn BookRepository>,
+    author_repository: Arc<dyn AuthorRepository>,
+    book_file_repository: Arc<dyn BookFileRepository>,
+    cover_repository: Arc<dyn CoverImageRepository>,
+    metadata_reader: Arc<dyn EbookMetadataReader>,
+    file_store: Arc<dyn EbookFileStore>,
+}
+
+impl EbookService {
+    pub fn new(
+        book_repository: Arc<dyn BookRepository>,
+        author_repository: Arc<dyn AuthorRepository>,
+        book_file_repository: Arc<dyn BookFileRepository>,
+        cover_repository: Arc<dyn CoverImageRepository>,
+        metadata_reader: Arc<dyn EbookMetadataReader>,
+        file_store: Arc<dyn EbookFileStore>,
+    ) -> Self {
+        Self {
+            book_repository,
+            author_repository,
+            book_file_repository,
+            cover_repository,
+            metadata_reader,
+            file_store,
+        }
+    }
+
+    /// ファイルの書誌情報を読み込む（本は登録しない）
+    pub async fn read_ebook_metadata(
+        &self,
+        path: String,
+    ) -> Result<EbookMetadataDto, ApplicationError> {
+        let path = PathBuf::from(path);
+        let format = EbookFormat::from_path(&path)?;
+        let metadata = self.metadata_reader.read_metadata(&path, format).await?;
+        let details = Self::details(&path, &metadata);
+
+        Ok(EbookMetadataDto {
+            format: format.into(),
+            title: details.title,
+            author: details.author,
+            creators: metadata
+                .creators
+                .iter()
+                .map(|(name, role)| EbookCreatorDto {
+                    name: name.clone(),
+                    role: (*role).into(),
+                })
+                .collect(),
+            language: metadata.language,
+            identifiers: metadata.identifiers,
+            isbn: details.isbn.map(|isbn| isbn.as_isbn13().to_string()),
+            publisher: metadata.publisher,
+            description: details.description,
+            published_year: metadata.published_year,
+            has_cover: metadata.cover.is_some(),
+        })
+    }
+
+    /// ファイルの書誌情報から本を登録し、ファイルを紐付ける
+    ///
+    /// ISBNが一致する本が登録済みの場合は、その本にファイルを紐付ける。
+    /// `copy_file` の場合はファイルをアプリが管理するディレクトリにコピーし、コピーを紐付ける。
+    pub async fn import_ebook(
+        &self,
+        path: String,
+        copy_file: bool,
+    ) -> Result<EbookImportResultDto, ApplicationError> {
+        let path = PathBuf::from(path);
+        let format = EbookFormat::from_path(&path)?;
+        self.ensure_not_linked(&path).await?;
+        let metadata = self.metadata_reader.read_metadata(&path, format).await?;
+        let file_path = self.file_path(&path, format, copy_file).await?;
+
+        let result = self
+            .import_with_file(&path, format, metadata, file_path.clone(), copy_file)
+            .await;
+        self.discard_copy_on_error(result, &file_path, copy_file)
+            .await
+    }
+
+    /// 書誌情報から本を登録（またはISBNが一致する本を取得）し、ファイルを紐付ける
+    async fn import_with_file(
+        &self,
+        path: &Path,
+        format: EbookFormat,
+        metadata: EbookMetadata,
+        file_path: PathBuf,
+        copy_file: bool,
+    ) -> Result<EbookImportResultDto, ApplicationError> {
+        let details = Self::details(path, &metadata);
+        let mut messages = Vec::new();
+        let existing = match &details.isbn {
+            Some(isbn) => match self.book_repository.find_by_isbn(isbn).await? {
+                Some(book) => Some(book),
+                None => {
+                    book_matching::ensure_isbn_not_trashed(self.book_repository.as_ref(), isbn)
+                        .await?;
+                    None
+                }
+            },
+            None => None,
+        };
+        let created = existing.is_none();
+        let mut book = match existing {
+            Some(book) => book,
+            None => {
+                let isbn = details.isbn.as_ref().map(Isbn::to_string);
+                let candidate = Book::new(
+                    details.title.clone(),
+                    details.author.clone(),
+                    details.description.clone(),
+                    metadata.published_year,
+                    isbn.clone(),
+                );
+                // 出版年が範囲外の場合は出版年なしで登録する
+                let mut book = match (candidate, metadata.published_year) {
+                    (Ok(book), _) => book,
+                    (Err(_), Some(year)) => {
+                        messages.push(format!("Ignored invalid published year {}", year));
+                        Book::new(
+                            details.title,
+                            details.author,
+                            details.description,
+                            None,
+                            isbn,
+                        )?
+                    }
+                    (Err(e), None) => return Err(e.into()),
+                };
+                if let Err(e) = book.update_publisher(metadata.publisher.clone()) {
+                    messages.push(format!("Ignored publisher: {}", e));
+                }
+                if !metadata.creators.is_empty() {
+                    authorship::link_authors(
+                        self.author_repository.as_ref(),
+                        &mut book,
+                        &metadata.creators,
+                    )
+                    .await?;
+                }
+                book
+            }
+        };
+
+        // 表紙が未設定の場合はファイルの表紙画像を使う
+        let mut changed = created;
+        if book.cover().is_none()
+            && let Some(cover) = metadata.cover
+        {
+            match self.cover_repository.save(cover).await {
+                Ok(hash) => {
+                    book.set_cover(Some(hash));
+                    changed = true;
+                }
+                Err(e) => messages.push(format!("Ignored cover image: {}", e)),
+            }
+        }
+        let book = if created {
+            self.book_repository
+                .save_with_history(book, ChangeRecord::created(BookChangeSource::Import))
+                .await?
+        } else if changed {
+            // 表紙だけの変更は変更履歴に記録しない
+            self.book_repository.save(book).await?
+        } else {
+            book
+        };
+        let book_id = book.id().expect("Saved book must have an ID");
+
+        let file = self
+            .save_book_file(book_id, file_path, format, copy_file)
+            .await?;
+
+        Ok(EbookImportResultDto {
+            book: BookDto::from(book),
+            file: BookFileDto::from(file),
+            created,
+            messages,
+        })
+    }
+
+    /// 登録済みの本にファイルを紐付ける（`copy_file` の場合はコピーを紐付ける）
+    pub async fn attach_book_file(
+        &self,
+        book_id: i32,
+        path: String,
+        copy_file: bool,
+    ) -> Result<BookFileDto, ApplicationError> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+            })?;
+        let path = PathBuf::from(path);
+        let format = EbookFormat::from_path(&path)?;
+        self.ensure_not_linked(&path).await?;
+        let file_path = self.file_path(&path, format, copy_file).await?;
+
+        let result = self
+            .save_book_file(book_id, file_path.clone(), format, copy_file)
+            .await;
+        self.discard_copy_on_error(result, &file_path, copy_file)
+            .await
+            .map(BookFileDto::from)
+    }
+
+    /// 本に紐付けたファイルを紐付けた順に取得
+    pub async fn get_book_files(&self, book_id: i32) -> Result<Vec<BookFileDto>, ApplicationError> {
+        let files = self.book_file_repository.find_by_book(book_id).await?;
+        Ok(files.into_iter().map(BookFileDto::from).collect())
+    }
+
+    /// ファイルの紐付けを外す（アプリが管理するコピーはファイルも削除する）
+    pub async fn remove_book_file(&self, id: i32) -> Result<(), ApplicationError> {
+        let file = self
+            .book_file_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book file with id {} not found", id))
+            })?;
+
+        self.book_file_repository.delete(id).await?;
+        if file.managed() {
+            self.file_store.delete_copy(file.path()).await?;
+        }
+        Ok(())
+    }
+
+    /// 紐付けるファイルのパス（`copy_file` の場合は管理ディレクトリへのコピー）
+    async fn file_path(
+        &self,
+        path: &Path,
+        format: EbookFormat,
+        copy_file: bool,
+    ) -> Result<PathBuf, ApplicationError> {
+        if !copy_file {
+            return Ok(path.to_path_buf());
+        }
+        let copy = self.file_store.store_copy(path, format).await?;
+        self.ensure_not_linked(&copy).await?;
+        Ok(copy)
+    }
+
+    /// 本にファイルを紐付けて保存する
+    async fn save_book_file(
+        &self,
+        book_id: i32,
+        path: PathBuf,
+        format: EbookFormat,
+        managed: bool,
+    ) -> Result<BookFile, ApplicationError> {
+        let file = BookFile::new(book_id, path, format, managed)?;
+        Ok(self.book_file_repository.save(file).await?)
+    }
+
+    /// 紐付けに失敗した場合は `copy_file` で作ったコピーを削除する
+    ///
+    /// コピーはどの本にも紐付いていないことを確認済みのため、削除しても他の本には影響しない。
+    /// 削除に失敗しても元のエラーを返す。
+    async fn discard_copy_on_error<T>(
+        &self,
+        result: Result<T, ApplicationError>,
+        copy: &Path,
+        copy_file: bool,
+    ) -> Result<T, ApplicationError> {
+        if result.is_err() && copy_file {
+            let _ = self.file_store.delete_copy(copy).await;
+        }
+        result
+    }
+
+    /// ビジネスルール: 同じファイルは1冊の本にだけ紐付ける
+    async fn ensure_not_linked(&self, path: &Path) -> Result<(), ApplicationError> {
+        if let Some(file) = self.book_file_repository.find_by_path(path).await? {
+            return Err(DomainError::ValidationError(format!(
+                "{} is already linked to book {}",
+                path.display(),
+                file.book_id()
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// 書誌情報から本の登録に使う値を作る
+    fn details(path: &Path, metadata: &EbookMetadata) -> EbookDetails {
+        let names: Vec<&str> = metadata
+            .creators
+            .iter()
+            .filter(|(_, role)| *role == AuthorRole::Author)
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        EbookDetails {
+            // タイトルがない場合はファイル名（拡張子なし）
+            title: metadata.title.clone().unwrap_or_else(|| {
+                path.file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            }),
+            author: (!names.is_empty()).then(|| names.join(", ")),
+            description: metadata
+                .description
+                .as_deref()
+                .map(library_export::html_to_text)
+                .filter(|description| !description.is_empty()),
+            // `urn:isbn:` などの接頭辞を除き、最初に解析できたISBN
+            isbn: metadata.identifiers.iter().find_map(|identifier| {
+                let value = identifier.trim();
+                let value = value
+                    .get(..9)
+                    .filter(|prefix| prefix.eq_ignore_ascii_case("urn:isbn:"))
+                    .map_or(value, |_| &value[9..]);
+                Isbn::parse(value).ok()
+            }),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+// Library Application Layer - ハイライト アプリケーションサービス
+
+use crate::application::dto::highlight::{
+    ClippingImportEntryDto, ClippingImportStatus, HighlightDto, KindleImportReportDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::application::importers::kindle::{self, ClippingKind, KindleClipping};
+use crate::application::services::{authorship, book_matching};
+use crate::domain::{
+    entities::{
+        book::Book,
+        book_change::ChangeRecord,
+        highlight::{Highlight, HighlightLocation},
+    },
+    repositories::{
+        author::AuthorRepository, book::BookRepository, highlight::HighlightRepository,
+    },
+    value_objects::{book_change_source::BookChangeSource, highlight_kind::HighlightKind},
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 取り込み中に対応付けた本（タイトル・著者の小文字 → 本のID）
+type BookCache = HashMap<(String, Option<String>), i32>;
+
+/// 電子書籍のハイライト取り込み・参照のユースケースを実装するサービス
+pub struct HighlightService {
+    highlight_repository: Arc<dyn HighlightRepository>,
+    book_repository: Arc<dyn BookRepository>,
+    author_repository: Arc<dyn AuthorRepository>,
+}
+
+impl HighlightService {
+    pub fn new(
+        highlight_repository: Arc<dyn HighlightRepository>,
+        book_repository: Arc<dyn BookRepository>,
+        author_repository: Arc<dyn AuthorRepository>,
+    ) -> Self {
+        Self {
+            highlight_repository,
+            book_repository,
+            author_repository,
+        }
+    }
+
+    /// 本のハイライトを位置順に取得
+    pub async fn get_highlights(
+        &self,
+        book_id: i32,
+    ) -> Result<Vec<HighlightDto>, ApplicationError> {
+        let highlights = self.highlight_repository.find_by_book(book_id).await?;
+        Ok(highlights.into_iter().map(HighlightDto::from).collect())
+    }
+
+    /// Kindle の「My Clippings.txt」を取り込む
+    ///
+    /// 本はタイトルと著者で既存の本に対応付け、見つからなければ作成する。
+    /// 取り込み済みの項目は重複として読み飛ばすため、同じファイルを何度取り込んでもよい。
+    pub async fn import_kindle_clippings(
+        &self,
+        content: String,
+    ) -> Result<KindleImportReportDto, ApplicationError> {
+        let mut books = BookCache::new();
+        let mut entries = Vec::new();
+
+        for (index, parsed) in kindle::parse_clippings(&content).into_iter().enumerate() {
+            let mut entry = ClippingImportEntryDto {
+                index: index + 1,
+                status: ClippingImportStatus::Failed,
+                title: None,
+                author: None,
+                book_id: None,
+                book_created: false,
+                highlight_id: None,
+                message: None,
+            };
+
+            match parsed {
+                Ok(clipping) => {
+                    entry.title = Some(clipping.title.clone());
+                    entry.author = clipping.author.clone();
+                    // 1件の失敗で取り込み全体を止めず、項目ごとに結果を返す
+                    if let Err(e) = self.import_clipping(clipping, &mut books, &mut entry).await {
+                        entry.status = ClippingImportStatus::Failed;
+                        entry.message = Some(e.to_string());
+                    }
+                }
+                Err(e) => {
+                    entry.title = e.title;
+                    entry.message = Some(e.message);
+                }
+            }
+
+            entries.push(entry);
+        }
+
+        Ok(KindleImportReportDto::new(entries))
+    }
+
+    /// ハイライトを削除
+    pub async fn delete_highlight(&self, id: i32) -> Result<(), ApplicationError> {
+        self.highlight_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Highlight with id {} not found", id))
+            })?;
+
+        self.highlight_repository.delete(id).await?;
+
+        Ok(())
+    }
+
+    async fn import_clipping(
+        &self,
+        clipping: KindleClipping,
+        books: &mut BookCache,
+        entry: &mut ClippingImportEntryDto,
+    ) -> Result<(), ApplicationError> {
+        let kind = match clipping.kind {
+            ClippingKind::Highlight => HighlightKind::Highlight,
+            ClippingKind::Note => HighlightKind::Note,
+            ClippingKind::Bookmark => {
+                entry.status = ClippingImportStatus::Skipped;
+                entry.message = Some("Bookmarks are not imported".to_string());
+                return Ok(());
+            }
+        };
+        if clipping.content.is_empty() {
+            entry.status = ClippingImportStatus::Skipped;
+            entry.message = Some("Clipping has no text".to_string());
+            return Ok(());
+        }
+        let location = HighlightLocation::new(
+            clipping.page,
+            clipping.location_start,
+            clipping.location_end,
+        )?;
+
+        let (book_id, book_created) = self.resolve_book(&clipping, books).await?;
+        entry.book_id = Some(book_id);
+        entry.book_created = book_created;
+
+        let highlight =
+            Highlight::new(book_id, kind, clipping.content, location, clipping.added_at)?;
+        if let Some(existing) = self
+            .highlight_repository
+            .find_same_clipping(&highlight)
+            .await?
+        {
+            entry.status = ClippingImportStatus::Duplicate;
+            entry.highlight_id = existing.id();
+            return Ok(());
+        }
+
+        let saved = self.highlight_repository.save(highlight).await?;
+        entry.status = ClippingImportStatus::Imported;
+        entry.highlight_id = saved.id();
+        Ok(())
+    }
+
+    /// タイトル・著者が一致する本のIDを返す。見つからなければ本を作成する（作成したかどうかも返す）
+    async fn resolve_book(
+        &self,
+        clipping: &KindleClipping,
+        books: &mut BookCache,
+    ) -> Result<(i32, bool), ApplicationError> {
+        let key = (
+            clipping.title.to_lowercase(),
+            clipping.author.as_deref().map(str::to_lowercase),
+        );
+        if let Some(book_id) = books.get(&key) {
+            return Ok((*book_id, false));
+        }
+
+        let candidates = self.book_repository.find_by_title(&clipping.title).await?;
+        let matched = book_matching::find_by_author(&candidates, clipping.author.as_deref());
+
+        let (book_id, created) = match matched.and_then(Book::id) {
+            Some(book_id) => (book_id, false),
+            None => {
+                let mut book = Book::new(
+                    clipping.title.clone(),
+                    clipping.author.clone(),
+                    None,
+                    None,
+                    None,
+                )?;
+                authorship::sync_primary_author(self.author_repository.as_ref(), &mut book).await?;
+                let saved = self
+                    .book_repository
+                    .save_with_history(book, ChangeRecord::created(BookChangeSource::Import))
+                    .await?;
+                let book_id = saved.id().expect("Saved book must have an ID");
+                (book_id, true)
+            }
+        };
+
+        books.insert(key, book_id);
+        Ok((book_id, created))
+    }
+}
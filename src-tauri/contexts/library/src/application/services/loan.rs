@@ -0,0 +1,244 @@
+// Library Application Layer - 貸出 アプリケーションサービス
+
+use crate::application::dto::loan::{BorrowerDto, LoanDto, LoanReminderDto, LoanReminderKind};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{borrower::Borrower, loan::Loan},
+    repositories::{borrower::BorrowerRepository, copy::CopyRepository, loan::LoanRepository},
+};
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+/// 所有している本の1冊を友人などに貸した記録と、貸出相手の連絡先のユースケースを実装するサービス
+pub struct LoanService {
+    loan_repository: Arc<dyn LoanRepository>,
+    borrower_repository: Arc<dyn BorrowerRepository>,
+    copy_repository: Arc<dyn CopyRepository>,
+}
+
+impl LoanService {
+    pub fn new(
+        loan_repository: Arc<dyn LoanRepository>,
+        borrower_repository: Arc<dyn BorrowerRepository>,
+        copy_repository: Arc<dyn CopyRepository>,
+    ) -> Self {
+        Self {
+            loan_repository,
+            borrower_repository,
+            copy_repository,
+        }
+    }
+
+    /// すべての貸出相手を名前順に取得
+    pub async fn list_borrowers(&self) -> Result<Vec<BorrowerDto>, ApplicationError> {
+        let borrowers = self.borrower_repository.find_all().await?;
+        Ok(borrowers.into_iter().map(BorrowerDto::from).collect())
+    }
+
+    /// IDで貸出相手を取得
+    pub async fn get_borrower(&self, id: i32) -> Result<Option<BorrowerDto>, ApplicationError> {
+        let borrower = self.borrower_repository.find_by_id(id).await?;
+        Ok(borrower.map(BorrowerDto::from))
+    }
+
+    /// 貸出相手を登録
+    pub async fn create_borrower(
+        &self,
+        name: String,
+        email: Option<String>,
+        phone: Option<String>,
+    ) -> Result<BorrowerDto, ApplicationError> {
+        let borrower = Borrower::new(name, email, phone)?;
+        let saved = self.borrower_repository.save(borrower).await?;
+        Ok(BorrowerDto::from(saved))
+    }
+
+    /// 貸出相手の連絡先を更新（`email`・`phone` は `Some(None)` で削除）
+    pub async fn update_borrower(
+        &self,
+        id: i32,
+        name: Option<String>,
+        email: Option<Option<String>>,
+        phone: Option<Option<String>>,
+    ) -> Result<BorrowerDto, ApplicationError> {
+        let mut borrower = self.find_borrower(id).await?;
+        if let Some(name) = name {
+            borrower.rename(name)?;
+        }
+        if let Some(email) = email {
+            borrower.change_email(email)?;
+        }
+        if let Some(phone) = phone {
+            borrower.change_phone(phone)?;
+        }
+
+        let saved = self.borrower_repository.save(borrower).await?;
+        Ok(BorrowerDto::from(saved))
+    }
+
+    /// 貸出相手を削除（貸出の履歴も削除する。貸出中の本がある場合は削除できない）
+    pub async fn delete_borrower(&self, id: i32) -> Result<(), ApplicationError> {
+        let borrower = self.find_borrower(id).await?;
+        let loans = self.loan_repository.find_by_borrower(id).await?;
+        if loans.iter().any(Loan::is_active) {
+            return Err(DomainError::InvalidState(format!(
+                "Borrower {} still has books out",
+                borrower.name()
+            ))
+            .into());
+        }
+
+        self.borrower_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// 1冊を貸し出す（`lent_on` 省略時は今日）
+    pub async fn lend_copy(
+        &self,
+        copy_id: i32,
+        borrower_id: i32,
+        due_on: Option<NaiveDate>,
+        lent_on: Option<NaiveDate>,
+    ) -> Result<LoanDto, ApplicationError> {
+        self.copy_repository
+            .find_by_id(copy_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Copy with id {} not found", copy_id))
+            })?;
+        self.find_borrower(borrower_id).await?;
+
+        let current = self.loan_repository.find_active_by_copy(copy_id).await?;
+        let loan = Loan::lend(
+            copy_id,
+            borrower_id,
+            lent_on.unwrap_or_else(today),
+            due_on,
+            current.as_ref(),
+        )?;
+
+        let saved = self.loan_repository.save(loan).await?;
+        Ok(LoanDto::from(saved))
+    }
+
+    /// 貸出中の1冊の返却を記録（`returned_on` 省略時は今日）
+    pub async fn return_copy(
+        &self,
+        copy_id: i32,
+        returned_on: Option<NaiveDate>,
+    ) -> Result<LoanDto, ApplicationError> {
+        let mut loan = self
+            .loan_repository
+            .find_active_by_copy(copy_id)
+            .await?
+            .ok_or_else(|| {
+                DomainError::InvalidState(format!("Copy {} is not lent out", copy_id))
+            })?;
+        loan.mark_returned(returned_on.unwrap_or_else(today))?;
+
+        let saved = self.loan_repository.save(loan).await?;
+        Ok(LoanDto::from(saved))
+    }
+
+    /// 貸出中の返却期限を変更（None で期限なしにする）
+    pub async fn set_loan_due_date(
+        &self,
+        loan_id: i32,
+        due_on: Option<NaiveDate>,
+    ) -> Result<LoanDto, ApplicationError> {
+        let mut loan = self
+            .loan_repository
+            .find_by_id(loan_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Loan with id {} not found", loan_id))
+            })?;
+        loan.change_due_on(due_on)?;
+
+        let saved = self.loan_repository.save(loan).await?;
+        Ok(LoanDto::from(saved))
+    }
+
+    /// 1冊の貸出中の貸出を取得
+    pub async fn get_current_loan(
+        &self,
+        copy_id: i32,
+    ) -> Result<Option<LoanDto>, ApplicationError> {
+        let loan = self.loan_repository.find_active_by_copy(copy_id).await?;
+        Ok(loan.map(LoanDto::from))
+    }
+
+    /// 1冊の貸出の履歴を新しい順に取得
+    pub async fn get_copy_loans(&self, copy_id: i32) -> Result<Vec<LoanDto>, ApplicationError> {
+        let loans = self.loan_repository.find_by_copy(copy_id).await?;
+        Ok(loans.into_iter().map(LoanDto::from).collect())
+    }
+
+    /// 貸出相手への貸出の履歴を新しい順に取得
+    pub async fn get_borrower_loans(
+        &self,
+        borrower_id: i32,
+    ) -> Result<Vec<LoanDto>, ApplicationError> {
+        let loans = self.loan_repository.find_by_borrower(borrower_id).await?;
+        Ok(loans.into_iter().map(LoanDto::from).collect())
+    }
+
+    /// 貸出中のすべての貸出を返却期限の近い順に取得（期限なしは最後）
+    pub async fn active_loans(&self) -> Result<Vec<LoanDto>, ApplicationError> {
+        let loans = self.loan_repository.find_active().await?;
+        Ok(loans.into_iter().map(LoanDto::from).collect())
+    }
+
+    /// 返却期限を過ぎている貸出を期限の古い順に取得
+    pub async fn overdue_loans(&self) -> Result<Vec<LoanDto>, ApplicationError> {
+        let today = today();
+        let loans = self.loan_repository.find_active().await?;
+        Ok(loans
+            .into_iter()
+            .filter(|loan| loan.is_overdue(today))
+            .map(LoanDto::from)
+            .collect())
+    }
+
+    /// 返却を促すリマインダーを返却期限の近い順に取得
+    ///
+    /// 期限を過ぎている貸出と、今日から `within_days` 日以内に期限が来る貸出を含む。
+    pub async fn loan_reminders(
+        &self,
+        within_days: u32,
+    ) -> Result<Vec<LoanReminderDto>, ApplicationError> {
+        let today = today();
+        let loans = self.loan_repository.find_active().await?;
+        Ok(loans
+            .into_iter()
+            .filter_map(|loan| {
+                let days_until_due = loan.days_until_due(today)?;
+                let kind = if days_until_due < 0 {
+                    LoanReminderKind::Overdue
+                } else if days_until_due <= i64::from(within_days) {
+                    LoanReminderKind::DueSoon
+                } else {
+                    return None;
+                };
+                Some(LoanReminderDto {
+                    loan: LoanDto::from(loan),
+                    kind,
+                    days_until_due,
+                })
+            })
+            .collect())
+    }
+
+    async fn find_borrower(&self, id: i32) -> Result<Borrower, ApplicationError> {
+        self.borrower_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Borrower with id {} not found", id)))
+    }
+}
+
+/// 日付省略時・延滞の判定に使う今日の日付（ローカル時刻）
+fn today() -> NaiveDate {
+    chrono::Local::now().date_naive()
+}
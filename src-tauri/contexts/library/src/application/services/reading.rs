@@ -0,0 +1,155 @@
+// Library Application Layer - 読書記録 アプリケーションサービス
+
+use crate::application::dto::reading::{BookReadingStatus, LogReadingSessionInput, ReadThroughDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::{
+    entities::read_through::{ReadThrough, ReadingSession},
+    repositories::{book::BookRepository, read_through::ReadThroughRepository},
+    value_objects::{
+        rating::Rating, reading_progress::ReadingProgress, reading_status::ReadingStatus,
+    },
+};
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+/// 読書状態・読書記録のユースケースを実装するサービス
+pub struct ReadingService {
+    repository: Arc<dyn ReadThroughRepository>,
+    book_repository: Arc<dyn BookRepository>,
+}
+
+impl ReadingService {
+    pub fn new(
+        repository: Arc<dyn ReadThroughRepository>,
+        book_repository: Arc<dyn BookRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            book_repository,
+        }
+    }
+
+    /// 本の通読を古い順にすべて取得
+    pub async fn get_read_throughs(
+        &self,
+        book_id: i32,
+    ) -> Result<Vec<ReadThroughDto>, ApplicationError> {
+        let read_throughs = self.repository.find_by_book(book_id).await?;
+        Ok(read_throughs
+            .into_iter()
+            .map(ReadThroughDto::from)
+            .collect())
+    }
+
+    /// 本の現在の読書状態（最新の通読の状態）を取得
+    pub async fn get_reading_status(
+        &self,
+        book_id: i32,
+    ) -> Result<Option<BookReadingStatus>, ApplicationError> {
+        let latest = self.repository.find_latest_by_book(book_id).await?;
+        Ok(latest.map(|read_through| read_through.status().into()))
+    }
+
+    /// 読書状態を変更（前回の通読が終わっている場合は再読として新しい通読を始める）
+    pub async fn change_reading_status(
+        &self,
+        book_id: i32,
+        status: BookReadingStatus,
+        on: Option<NaiveDate>,
+    ) -> Result<ReadThroughDto, ApplicationError> {
+        self.ensure_book_exists(book_id).await?;
+
+        let status = ReadingStatus::from(status);
+        let on = on.unwrap_or_else(today);
+
+        let read_through = match self.repository.find_latest_by_book(book_id).await? {
+            Some(mut current) if !current.status().is_closed() => {
+                current.change_status(status, on)?;
+                current
+            }
+            _ => ReadThrough::new(book_id, status, on),
+        };
+
+        let saved = self.repository.save(read_through).await?;
+        Ok(ReadThroughDto::from(saved))
+    }
+
+    /// 読書記録を追加（前回の通読が終わっている場合は再読として新しい通読を始める）
+    pub async fn log_reading_session(
+        &self,
+        input: LogReadingSessionInput,
+    ) -> Result<ReadThroughDto, ApplicationError> {
+        self.ensure_book_exists(input.book_id).await?;
+
+        let read_on = input.read_on.unwrap_or_else(today);
+        let progress = ReadingProgress::new(input.page, input.percent)?;
+        let session = ReadingSession::new(read_on, progress, input.duration_minutes)?;
+
+        let mut read_through = match self.repository.find_latest_by_book(input.book_id).await? {
+            Some(current) if !current.status().is_closed() => current,
+            _ => ReadThrough::new(input.book_id, ReadingStatus::Reading, read_on),
+        };
+        read_through.log_session(session)?;
+
+        let saved = self.repository.save(read_through).await?;
+        Ok(ReadThroughDto::from(saved))
+    }
+
+    /// 読書記録を削除
+    pub async fn delete_reading_session(&self, session_id: i32) -> Result<(), ApplicationError> {
+        let mut read_through = self
+            .repository
+            .find_by_session_id(session_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Reading session with id {} not found",
+                    session_id
+                ))
+            })?;
+
+        read_through.remove_session(session_id)?;
+        self.repository.save(read_through).await?;
+
+        Ok(())
+    }
+
+    /// 通読の評価を設定（`None` で削除）
+    pub async fn rate_read_through(
+        &self,
+        read_through_id: i32,
+        rating: Option<f64>,
+    ) -> Result<ReadThroughDto, ApplicationError> {
+        let mut read_through = self
+            .repository
+            .find_by_id(read_through_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Read-through with id {} not found",
+                    read_through_id
+                ))
+            })?;
+
+        read_through.rate(rating.map(Rating::new).transpose()?)?;
+        let saved = self.repository.save(read_through).await?;
+
+        Ok(ReadThroughDto::from(saved))
+    }
+
+    /// 本が存在することを確認
+    async fn ensure_book_exists(&self, book_id: i32) -> Result<(), ApplicationError> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+            })?;
+        Ok(())
+    }
+}
+
+/// 日付省略時に使う今日の日付（ローカル時刻）
+fn today() -> NaiveDate {
+    chrono::Local::now().date_naive()
+}
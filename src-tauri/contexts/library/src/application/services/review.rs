@@ -0,0 +1,147 @@
+// Library Application Layer - 評価・感想・メモ アプリケーションサービス
+
+use crate::application::dto::review::{NoteDto, ReviewDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{note::Note, review::Review},
+    repositories::{book::BookRepository, note::NoteRepository, review::ReviewRepository},
+    value_objects::rating::Rating,
+};
+use std::sync::Arc;
+
+/// 評価・感想・メモのユースケースを実装するサービス
+pub struct ReviewService {
+    review_repository: Arc<dyn ReviewRepository>,
+    note_repository: Arc<dyn NoteRepository>,
+    book_repository: Arc<dyn BookRepository>,
+}
+
+impl ReviewService {
+    pub fn new(
+        review_repository: Arc<dyn ReviewRepository>,
+        note_repository: Arc<dyn NoteRepository>,
+        book_repository: Arc<dyn BookRepository>,
+    ) -> Self {
+        Self {
+            review_repository,
+            note_repository,
+            book_repository,
+        }
+    }
+
+    /// 本の評価・感想を取得
+    pub async fn get_review(&self, book_id: i32) -> Result<Option<ReviewDto>, ApplicationError> {
+        let review = self.review_repository.find_by_book(book_id).await?;
+        Ok(review.map(ReviewDto::from))
+    }
+
+    /// 本の評価・感想を作成（1冊につき1件）
+    pub async fn create_review(
+        &self,
+        book_id: i32,
+        rating: Option<f64>,
+        body: Option<String>,
+    ) -> Result<ReviewDto, ApplicationError> {
+        self.ensure_book_exists(book_id).await?;
+
+        if self
+            .review_repository
+            .find_by_book(book_id)
+            .await?
+            .is_some()
+        {
+            return Err(DomainError::InvalidState(format!(
+                "Book with id {} already has a review",
+                book_id
+            ))
+            .into());
+        }
+
+        let rating = rating.map(Rating::new).transpose()?;
+        let review = Review::new(book_id, rating, body)?;
+        let saved = self.review_repository.save(review).await?;
+
+        Ok(ReviewDto::from(saved))
+    }
+
+    /// 評価・感想を更新（`rating` は `Some(None)` で評価を削除、`body` は空文字列で感想を削除）
+    pub async fn update_review(
+        &self,
+        id: i32,
+        rating: Option<Option<f64>>,
+        body: Option<String>,
+    ) -> Result<ReviewDto, ApplicationError> {
+        let mut review = self
+            .review_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Review with id {} not found", id))
+            })?;
+
+        let rating = rating
+            .map(|rating| rating.map(Rating::new).transpose())
+            .transpose()?;
+        review.update(rating, body)?;
+        let saved = self.review_repository.save(review).await?;
+
+        Ok(ReviewDto::from(saved))
+    }
+
+    /// 評価・感想を削除
+    pub async fn delete_review(&self, id: i32) -> Result<(), ApplicationError> {
+        self.review_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// 本のメモを古い順にすべて取得
+    pub async fn get_notes(&self, book_id: i32) -> Result<Vec<NoteDto>, ApplicationError> {
+        let notes = self.note_repository.find_by_book(book_id).await?;
+        Ok(notes.into_iter().map(NoteDto::from).collect())
+    }
+
+    /// メモを追加
+    pub async fn create_note(
+        &self,
+        book_id: i32,
+        body: String,
+    ) -> Result<NoteDto, ApplicationError> {
+        self.ensure_book_exists(book_id).await?;
+
+        let note = Note::new(book_id, body)?;
+        let saved = self.note_repository.save(note).await?;
+
+        Ok(NoteDto::from(saved))
+    }
+
+    /// メモを編集
+    pub async fn update_note(&self, id: i32, body: String) -> Result<NoteDto, ApplicationError> {
+        let mut note =
+            self.note_repository.find_by_id(id).await?.ok_or_else(|| {
+                ApplicationError::NotFound(format!("Note with id {} not found", id))
+            })?;
+
+        note.edit(body)?;
+        let saved = self.note_repository.save(note).await?;
+
+        Ok(NoteDto::from(saved))
+    }
+
+    /// メモを削除
+    pub async fn delete_note(&self, id: i32) -> Result<(), ApplicationError> {
+        self.note_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// 本が存在することを確認
+    async fn ensure_book_exists(&self, book_id: i32) -> Result<(), ApplicationError> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+            })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+// Library Application Layer - シリーズ アプリケーションサービス
+
+use crate::application::dto::{
+    book::BookDto,
+    series::{BookSeriesDto, SeriesDto, SeriesPublicationStatus, SeriesVolumeDto},
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::series::{Series, SeriesVolume},
+    repositories::{book::BookRepository, series::SeriesRepository},
+    value_objects::volume_number::VolumeNumber,
+};
+use std::sync::Arc;
+
+/// シリーズと巻の順序のユースケースを実装するサービス
+pub struct SeriesService {
+    series_repository: Arc<dyn SeriesRepository>,
+    book_repository: Arc<dyn BookRepository>,
+}
+
+impl SeriesService {
+    pub fn new(
+        series_repository: Arc<dyn SeriesRepository>,
+        book_repository: Arc<dyn BookRepository>,
+    ) -> Self {
+        Self {
+            series_repository,
+            book_repository,
+        }
+    }
+
+    /// すべてのシリーズを読みの順に取得
+    pub async fn list_series(&self) -> Result<Vec<SeriesDto>, ApplicationError> {
+        let series = self.series_repository.find_all().await?;
+        Ok(series.into_iter().map(SeriesDto::from).collect())
+    }
+
+    /// IDでシリーズを取得
+    pub async fn get_series(&self, id: i32) -> Result<Option<SeriesDto>, ApplicationError> {
+        let series = self.series_repository.find_by_id(id).await?;
+        Ok(series.map(SeriesDto::from))
+    }
+
+    /// シリーズを作成（同じ名前のシリーズは作成できない）
+    pub async fn create_series(
+        &self,
+        name: String,
+        reading: Option<String>,
+        status: SeriesPublicationStatus,
+        volume_count: Option<i32>,
+    ) -> Result<SeriesDto, ApplicationError> {
+        let series = Series::new(name, reading, status.into(), volume_count)?;
+        self.ensure_name_available(series.name(), None).await?;
+
+        let saved = self.series_repository.save(series).await?;
+        Ok(SeriesDto::from(saved))
+    }
+
+    /// シリーズを更新（`reading` は空文字列で削除、`volume_count` は `Some(None)` で削除）
+    pub async fn update_series(
+        &self,
+        id: i32,
+        name: Option<String>,
+        reading: Option<String>,
+        status: Option<SeriesPublicationStatus>,
+        volume_count: Option<Option<i32>>,
+    ) -> Result<SeriesDto, ApplicationError> {
+        let mut series = self.find_series(id).await?;
+        series.update(name, reading, status.map(Into::into), volume_count)?;
+        self.ensure_name_available(series.name(), Some(id)).await?;
+
+        let saved = self.series_repository.save(series).await?;
+        Ok(SeriesDto::from(saved))
+    }
+
+    /// シリーズを削除（本は削除せず、シリーズから外す）
+    pub async fn delete_series(&self, id: i32) -> Result<(), ApplicationError> {
+        self.series_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// シリーズの本を巻数の順に取得
+    pub async fn get_volumes(
+        &self,
+        series_id: i32,
+    ) -> Result<Vec<SeriesVolumeDto>, ApplicationError> {
+        let volumes = self.series_repository.find_volumes(series_id).await?;
+
+        let mut dtos = Vec::with_capacity(volumes.len());
+        for volume in volumes {
+            if let Some(book) = self.book_repository.find_by_id(volume.book_id()).await? {
+                dtos.push(SeriesVolumeDto {
+                    volume: volume.volume().value(),
+                    book: BookDto::from(book),
+                });
+            }
+        }
+        Ok(dtos)
+    }
+
+    /// 所有していない巻（整数の巻のみ）を取得
+    pub async fn get_missing_volumes(&self, series_id: i32) -> Result<Vec<i32>, ApplicationError> {
+        let series = self.find_series(series_id).await?;
+        let owned: Vec<VolumeNumber> = self
+            .series_repository
+            .find_volumes(series_id)
+            .await?
+            .iter()
+            .map(SeriesVolume::volume)
+            .collect();
+
+        Ok(series.missing_volumes(&owned))
+    }
+
+    /// 本が属するシリーズと巻数を取得
+    pub async fn get_book_series(
+        &self,
+        book_id: i32,
+    ) -> Result<Option<BookSeriesDto>, ApplicationError> {
+        let Some(volume) = self.series_repository.find_volume_by_book(book_id).await? else {
+            return Ok(None);
+        };
+        let series = self.find_series(volume.series_id()).await?;
+
+        Ok(Some(BookSeriesDto {
+            series: SeriesDto::from(series),
+            volume: volume.volume().value(),
+        }))
+    }
+
+    /// 本をシリーズの巻として登録（別のシリーズに属していた場合は移す）
+    pub async fn set_book_series(
+        &self,
+        book_id: i32,
+        series_id: i32,
+        volume: f64,
+    ) -> Result<BookSeriesDto, ApplicationError> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+            })?;
+        let series = self.find_series(series_id).await?;
+        let volume = VolumeNumber::new(volume)?;
+
+        self.series_repository
+            .save_volume(SeriesVolume::new(book_id, series_id, volume))
+            .await?;
+
+        Ok(BookSeriesDto {
+            series: SeriesDto::from(series),
+            volume: volume.value(),
+        })
+    }
+
+    /// 本をシリーズから外す
+    pub async fn remove_book_from_series(&self, book_id: i32) -> Result<(), ApplicationError> {
+        self.series_repository.remove_volume(book_id).await?;
+        Ok(())
+    }
+
+    async fn find_series(&self, id: i32) -> Result<Series, ApplicationError> {
+        self.series_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Series with id {} not found", id)))
+    }
+
+    /// ビジネスルール: シリーズ名は重複できない
+    async fn ensure_name_available(
+        &self,
+        name: &str,
+        current_id: Option<i32>,
+    ) -> Result<(), ApplicationError> {
+        if let Some(existing) = self.series_repository.find_by_name(name).await?
+            && existing.id() != current_id
+        {
+            return Err(
+                DomainError::ValidationError(format!("Series {} already exists", name)).into(),
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+// Library Application Layer - 本棚 アプリケーションサービス
+
+use crate::application::dto::{
+    book::BookDto,
+    shelf::{ShelfDto, SmartShelfFilterInput},
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{book::Book, shelf::Shelf},
+    repositories::{
+        book::{BookFilter, BookRepository},
+        shelf::ShelfRepository,
+    },
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// 本棚と本の並び順のユースケースを実装するサービス
+pub struct ShelfService {
+    shelf_repository: Arc<dyn ShelfRepository>,
+    book_repository: Arc<dyn BookRepository>,
+}
+
+impl ShelfService {
+    pub fn new(
+        shelf_repository: Arc<dyn ShelfRepository>,
+        book_repository: Arc<dyn BookRepository>,
+    ) -> Self {
+        Self {
+            shelf_repository,
+            book_repository,
+        }
+    }
+
+    /// すべての本棚を表示順に取得
+    pub async fn list_shelves(&self) -> Result<Vec<ShelfDto>, ApplicationError> {
+        let shelves = self.shelf_repository.find_all().await?;
+        Ok(shelves.into_iter().map(ShelfDto::from).collect())
+    }
+
+    /// IDで本棚を取得
+    pub async fn get_shelf(&self, id: i32) -> Result<Option<ShelfDto>, ApplicationError> {
+        let shelf = self.shelf_repository.find_by_id(id).await?;
+        Ok(shelf.map(ShelfDto::from))
+    }
+
+    /// 本を並べている手動の本棚を取得（スマート本棚は含まない）
+    pub async fn get_book_shelves(&self, book_id: i32) -> Result<Vec<ShelfDto>, ApplicationError> {
+        let shelves = self.shelf_repository.find_by_book(book_id).await?;
+        Ok(shelves.into_iter().map(ShelfDto::from).collect())
+    }
+
+    /// 本棚を作成（`smart_filter` を指定するとスマート本棚になる。同じ名前の本棚は作成できない）
+    pub async fn create_shelf(
+        &self,
+        name: String,
+        smart_filter: Option<SmartShelfFilterInput>,
+    ) -> Result<ShelfDto, ApplicationError> {
+        let smart_filter = smart_filter.map(BookFilter::try_from).transpose()?;
+        let shelf = Shelf::new(name, smart_filter)?;
+        self.ensure_name_available(shelf.name(), None).await?;
+
+        let saved = self.shelf_repository.save(shelf).await?;
+        Ok(ShelfDto::from(saved))
+    }
+
+    /// 本棚を更新（絞り込み条件はスマート本棚のみ変更できる）
+    pub async fn update_shelf(
+        &self,
+        id: i32,
+        name: Option<String>,
+        smart_filter: Option<SmartShelfFilterInput>,
+    ) -> Result<ShelfDto, ApplicationError> {
+        let mut shelf = self.find_shelf(id).await?;
+        if let Some(name) = name {
+            shelf.rename(name)?;
+        }
+        if let Some(filter) = smart_filter {
+            shelf.change_smart_filter(BookFilter::try_from(filter)?)?;
+        }
+        self.ensure_name_available(shelf.name(), Some(id)).await?;
+
+        let saved = self.shelf_repository.save(shelf).await?;
+        Ok(ShelfDto::from(saved))
+    }
+
+    /// 本棚を削除（本は削除しない）
+    pub async fn delete_shelf(&self, id: i32) -> Result<(), ApplicationError> {
+        self.shelf_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// 本棚の表示順を並べ替える（すべての本棚のIDを新しい順に指定する）
+    pub async fn reorder_shelves(
+        &self,
+        shelf_ids: Vec<i32>,
+    ) -> Result<Vec<ShelfDto>, ApplicationError> {
+        let current: Vec<i32> = self
+            .shelf_repository
+            .find_all()
+            .await?
+            .iter()
+            .filter_map(Shelf::id)
+            .collect();
+        ensure_same_ids(&current, &shelf_ids, "shelves")?;
+
+        self.shelf_repository.reorder(&shelf_ids).await?;
+        self.list_shelves().await
+    }
+
+    /// 本棚の本を取得（手動の本棚は並べた順、スマート本棚は条件に一致する本をタイトルの読み順）
+    pub async fn get_shelf_books(&self, shelf_id: i32) -> Result<Vec<BookDto>, ApplicationError> {
+        let shelf = self.find_shelf(shelf_id).await?;
+        if let Some(filter) = shelf.smart_filter() {
+            let books = self.book_repository.find_matching(filter).await?;
+            return Ok(books.into_iter().map(BookDto::from).collect());
+        }
+
+        let book_ids = self.shelf_repository.find_book_ids(shelf_id).await?;
+        let mut books = Vec::with_capacity(book_ids.len());
+        for book_id in book_ids {
+            if let Some(book) = self.book_repository.find_by_id(book_id).await? {
+                books.push(BookDto::from(book));
+            }
+        }
+        Ok(books)
+    }
+
+    /// 手動の本棚の末尾に本を追加する
+    pub async fn add_book_to_shelf(
+        &self,
+        shelf_id: i32,
+        book_id: i32,
+    ) -> Result<ShelfDto, ApplicationError> {
+        let shelf = self.find_manual_shelf(shelf_id).await?;
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+            })?;
+
+        self.shelf_repository.add_book(shelf_id, book_id).await?;
+        Ok(ShelfDto::from(shelf))
+    }
+
+    /// 手動の本棚から本を外す
+    pub async fn remove_book_from_shelf(
+        &self,
+        shelf_id: i32,
+        book_id: i32,
+    ) -> Result<ShelfDto, ApplicationError> {
+        let shelf = self.find_manual_shelf(shelf_id).await?;
+        self.shelf_repository.remove_book(shelf_id, book_id).await?;
+        Ok(ShelfDto::from(shelf))
+    }
+
+    /// 手動の本棚の本を並べ替える（並べているすべての本のIDを新しい順に指定する）
+    ///
+    /// ゴミ箱にある本は本棚の本として表示しないため指定せず、指定した本の後ろに元の順で並べる。
+    pub async fn reorder_shelf_books(
+        &self,
+        shelf_id: i32,
+        book_ids: Vec<i32>,
+    ) -> Result<ShelfDto, ApplicationError> {
+        let shelf = self.find_manual_shelf(shelf_id).await?;
+        let trashed: HashSet<i32> = self
+            .book_repository
+            .find_trashed()
+            .await?
+            .iter()
+            .filter_map(Book::id)
+            .collect();
+        let (current, trashed): (Vec<i32>, Vec<i32>) = self
+            .shelf_repository
+            .find_book_ids(shelf_id)
+            .await?
+            .into_iter()
+            .partition(|id| !trashed.contains(id));
+        ensure_same_ids(&current, &book_ids, "books on the shelf")?;
+
+        let order: Vec<i32> = book_ids.into_iter().chain(trashed).collect();
+        self.shelf_repository
+            .reorder_books(shelf_id, &order)
+            .await?;
+        Ok(ShelfDto::from(shelf))
+    }
+
+    async fn find_shelf(&self, id: i32) -> Result<Shelf, ApplicationError> {
+        self.shelf_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Shelf with id {} not found", id)))
+    }
+
+    /// ビジネスルール: スマート本棚には本を個別に並べられない
+    async fn find_manual_shelf(&self, id: i32) -> Result<Shelf, ApplicationError> {
+        let shelf = self.find_shelf(id).await?;
+        if shelf.is_smart() {
+            return Err(DomainError::InvalidState(format!(
+                "Books cannot be arranged on smart shelf '{}'",
+                shelf.name()
+            ))
+            .into());
+        }
+        Ok(shelf)
+    }
+
+    /// ビジネスルール: 本棚の名前は重複できない
+    async fn ensure_name_available(
+        &self,
+        name: &str,
+        current_id: Option<i32>,
+    ) -> Result<(), ApplicationError> {
+        if let Some(existing) = self.shelf_repository.find_by_name(name).await?
+            && existing.id() != current_id
+        {
+            return Err(
+                DomainError::ValidationError(format!("Shelf {} already exists", name)).into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// 並べ替えの指定が現在のIDをちょうど1回ずつ含むか
+fn ensure_same_ids(current: &[i32], given: &[i32], what: &str) -> Result<(), DomainError> {
+    let unique: HashSet<i32> = given.iter().copied().collect();
+    if unique.len() != given.len()
+        || given.len() != current.len()
+        || !current.iter().all(|id| unique.contains(id))
+    {
+        return Err(DomainError::ValidationError(format!(
+            "New order must list each of the {} exactly once",
+            what
+        )));
+    }
+    Ok(())
+}
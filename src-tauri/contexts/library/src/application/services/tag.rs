@@ -0,0 +1,167 @@
+// Library Application Layer - タグ アプリケーションサービス
+
+use crate::application::dto::tag::TagDto;
+use crate::application::errors::ApplicationError;
+use crate::application::services::tagging;
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::tag::Tag,
+    repositories::{book::BookRepository, tag::TagRepository},
+    value_objects::tag_name::TagName,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// タグとタグ付けのユースケースを実装するサービス
+pub struct TagService {
+    tag_repository: Arc<dyn TagRepository>,
+    book_repository: Arc<dyn BookRepository>,
+}
+
+impl TagService {
+    pub fn new(
+        tag_repository: Arc<dyn TagRepository>,
+        book_repository: Arc<dyn BookRepository>,
+    ) -> Self {
+        Self {
+            tag_repository,
+            book_repository,
+        }
+    }
+
+    /// すべてのタグを名前の順に取得（親のタグの直後に子のタグが並ぶ）
+    pub async fn list_tags(&self) -> Result<Vec<TagDto>, ApplicationError> {
+        let mut tags = self.tag_repository.find_all().await?;
+        // 「a/b」と「a b」のように区切りより小さい文字が混ざっても階層の順になるよう、階層ごとに比較する
+        tags.sort_by(|a, b| {
+            a.name()
+                .as_str()
+                .split('/')
+                .cmp(b.name().as_str().split('/'))
+        });
+        Ok(tags.into_iter().map(TagDto::from).collect())
+    }
+
+    /// 本に付いているタグを名前の順に取得
+    pub async fn get_book_tags(&self, book_id: i32) -> Result<Vec<TagDto>, ApplicationError> {
+        let tags = self.tag_repository.find_by_book(book_id).await?;
+        Ok(tags.into_iter().map(TagDto::from).collect())
+    }
+
+    /// タグを作成（祖先のタグがなければ作成する。同じ名前のタグは作成できない）
+    pub async fn create_tag(&self, name: String) -> Result<TagDto, ApplicationError> {
+        let name = TagName::new(&name)?;
+        if self.tag_repository.find_by_name(&name).await?.is_some() {
+            return Err(DomainError::ValidationError(format!(
+                "Tag {} already exists",
+                name.as_str()
+            ))
+            .into());
+        }
+
+        let tag = tagging::find_or_create_tag(self.tag_repository.as_ref(), &name).await?;
+        Ok(TagDto::from(tag))
+    }
+
+    /// タグの名前を変更（子孫のタグも新しい名前の下に移す）
+    pub async fn rename_tag(&self, id: i32, name: String) -> Result<TagDto, ApplicationError> {
+        let tag = self.find_tag(id).await?;
+        let from = tag.name().clone();
+        let to = TagName::new(&name)?;
+        if to == from {
+            return Ok(TagDto::from(tag));
+        }
+        if to.is_within(&from) {
+            return Err(DomainError::ValidationError(format!(
+                "Tag {} cannot be moved under itself",
+                from.as_str()
+            ))
+            .into());
+        }
+
+        let (subtree, others): (Vec<Tag>, Vec<Tag>) = self
+            .tag_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .partition(|t| t.name().is_within(&from));
+        let existing: HashSet<&TagName> = others.iter().map(Tag::name).collect();
+
+        let mut renamed = Vec::with_capacity(subtree.len());
+        for mut tag in subtree {
+            let Some(moved) = tag.name().moved(&from, &to) else {
+                continue;
+            };
+            if existing.contains(&moved) {
+                return Err(DomainError::ValidationError(format!(
+                    "Tag {} already exists",
+                    moved.as_str()
+                ))
+                .into());
+            }
+            tag.rename(moved);
+            renamed.push(tag);
+        }
+
+        tagging::create_ancestors(self.tag_repository.as_ref(), &to).await?;
+        let renamed = self.tag_repository.save_all(renamed).await?;
+        renamed
+            .into_iter()
+            .find(|t| t.id() == Some(id))
+            .map(TagDto::from)
+            .ok_or_else(|| ApplicationError::NotFound(format!("Tag with id {} not found", id)))
+    }
+
+    /// タグを削除（子孫のタグも削除し、本からも外す。本は削除しない）
+    pub async fn delete_tag(&self, id: i32) -> Result<(), ApplicationError> {
+        self.tag_repository.delete(id).await?;
+        Ok(())
+    }
+
+    /// 本にタグを付ける（タグがなければ作成する）。付けた後の本のタグを返す
+    pub async fn tag_book(
+        &self,
+        book_id: i32,
+        name: String,
+    ) -> Result<Vec<TagDto>, ApplicationError> {
+        self.ensure_book_exists(book_id).await?;
+        let name = TagName::new(&name)?;
+        let tag = tagging::find_or_create_tag(self.tag_repository.as_ref(), &name).await?;
+        let tag_id = tag.id().expect("Saved tag must have an ID");
+
+        self.tag_repository.add_to_book(book_id, tag_id).await?;
+        self.get_book_tags(book_id).await
+    }
+
+    /// 本からタグを外す。外した後の本のタグを返す
+    pub async fn untag_book(
+        &self,
+        book_id: i32,
+        tag_id: i32,
+    ) -> Result<Vec<TagDto>, ApplicationError> {
+        self.ensure_book_exists(book_id).await?;
+        self.find_tag(tag_id).await?;
+
+        self.tag_repository
+            .remove_from_book(book_id, tag_id)
+            .await?;
+        self.get_book_tags(book_id).await
+    }
+
+    async fn find_tag(&self, id: i32) -> Result<Tag, ApplicationError> {
+        self.tag_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Tag with id {} not found", id)))
+    }
+
+    async fn ensure_book_exists(&self, book_id: i32) -> Result<(), ApplicationError> {
+        self.book_repository
+            .find_by_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Book with id {} not found", book_id))
+            })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+// Library Application Layer - タグ付け（タグを作成するサービスで共有）
+
+use crate::application::errors::ApplicationError;
+use crate::domain::{
+    entities::tag::Tag, repositories::tag::TagRepository, value_objects::tag_name::TagName,
+};
+use std::collections::HashSet;
+
+/// 取り込み元のタグ・棚の名前をタグ名に変換（タグ名にできない名前と重複は除く）
+pub(crate) fn tag_names(names: &[String]) -> Vec<TagName> {
+    let mut seen = HashSet::new();
+    names
+        .iter()
+        .filter_map(|name| TagName::new(name).ok())
+        .filter(|name| seen.insert(name.clone()))
+        .collect()
+}
+
+/// 本に付いていないタグだけを残す
+pub(crate) async fn missing_tags(
+    tag_repository: &dyn TagRepository,
+    book_id: i32,
+    names: Vec<TagName>,
+) -> Result<Vec<TagName>, ApplicationError> {
+    let current: HashSet<TagName> = tag_repository
+        .find_by_book(book_id)
+        .await?
+        .into_iter()
+        .map(|tag| tag.name().clone())
+        .collect();
+    Ok(names
+        .into_iter()
+        .filter(|name| !current.contains(name))
+        .collect())
+}
+
+/// 本にタグを付ける（タグがなければ作成する）
+pub(crate) async fn add_tags(
+    tag_repository: &dyn TagRepository,
+    book_id: i32,
+    names: &[TagName],
+) -> Result<(), ApplicationError> {
+    for name in names {
+        let tag = find_or_create_tag(tag_repository, name).await?;
+        let tag_id = tag.id().expect("Saved tag must have an ID");
+        tag_repository.add_to_book(book_id, tag_id).await?;
+    }
+    Ok(())
+}
+
+/// 追加するタグの変更内容の説明
+pub(crate) fn describe(names: &[TagName]) -> String {
+    let names: Vec<&str> = names.iter().map(TagName::as_str).collect();
+    format!("Add tags {}", names.join(", "))
+}
+
+/// 名前でタグを取得し、なければ祖先のタグとともに作成する
+pub(crate) async fn find_or_create_tag(
+    tag_repository: &dyn TagRepository,
+    name: &TagName,
+) -> Result<Tag, ApplicationError> {
+    if let Some(existing) = tag_repository.find_by_name(name).await? {
+        return Ok(existing);
+    }
+
+    create_ancestors(tag_repository, name).await?;
+    Ok(tag_repository.save(Tag::new(name.clone())).await?)
+}
+
+/// 階層をたどれるよう、なければ祖先のタグを作成する
+pub(crate) async fn create_ancestors(
+    tag_repository: &dyn TagRepository,
+    name: &TagName,
+) -> Result<(), ApplicationError> {
+    for ancestor in name.ancestors() {
+        if tag_repository.find_by_name(&ancestor).await?.is_none() {
+            tag_repository.save(Tag::new(ancestor)).await?;
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,17 @@
 // Library Domain - Entities
 
+pub mod author;
 pub mod book;
+pub mod book_change;
+pub mod book_file;
+pub mod borrower;
+pub mod copy;
+pub mod highlight;
+pub mod loan;
+pub mod location;
+pub mod note;
+pub mod read_through;
+pub mod review;
+pub mod series;
+pub mod shelf;
+pub mod tag;
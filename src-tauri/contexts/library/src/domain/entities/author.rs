@@ -0,0 +1,100 @@
+// Library Domain Layer - Author エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::author_role::AuthorRole;
+
+/// 著者名の最大文字数
+const MAX_NAME_LENGTH: usize = 200;
+
+/// Author エンティティ（著者・翻訳者・編者などの人物）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Author {
+    id: Option<i32>,
+    name: String,
+}
+
+impl Author {
+    /// 新しい著者を作成（バリデーション付き）
+    pub fn new(name: String) -> Result<Self, DomainError> {
+        // ビジネスルール: 名前は必須で、空文字列は不可
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Author name cannot be empty".to_string(),
+            ));
+        }
+
+        // ビジネスルール: 名前は200文字以内
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Author name must be {} characters or less",
+                MAX_NAME_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            id: None,
+            name: name.to_string(),
+        })
+    }
+
+    /// 既存の著者を再構築（DB から取得した場合など）
+    pub fn reconstruct(id: i32, name: String) -> Self {
+        Self { id: Some(id), name }
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // IDをセット（リポジトリで保存後に使用）
+    pub(crate) fn set_id(&mut self, id: i32) {
+        self.id = Some(id);
+    }
+}
+
+/// 本と著者の関わり（役割付き）
+///
+/// 本の著者一覧での並び順は、`Book::authors` 内の位置で表す。
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookAuthor {
+    author: Author,
+    role: AuthorRole,
+}
+
+impl BookAuthor {
+    pub fn new(author: Author, role: AuthorRole) -> Self {
+        Self { author, role }
+    }
+
+    pub fn author(&self) -> &Author {
+        &self.author
+    }
+
+    pub fn role(&self) -> AuthorRole {
+        self.role
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_author() {
+        let author = Author::new("  村上 春樹 ".to_string()).unwrap();
+        assert_eq!(author.name(), "村上 春樹");
+        assert_eq!(author.id(), None);
+    }
+
+    #[test]
+    fn test_empty_name_fails() {
+        assert!(Author::new("   ".to_string()).is_err());
+        assert!(Author::new("a".repeat(201)).is_err());
+    }
+}
@@ -1,7 +1,22 @@
 // Library Domain Layer - Book エンティティ
 
+use crate::domain::entities::author::BookAuthor;
 use crate::domain::errors::DomainError;
-use chrono::Datelike;
+use crate::domain::value_objects::{
+    author_role::AuthorRole,
+    class_number::{ClassNumber, ClassificationScheme},
+    cover_hash::CoverHash,
+    isbn::Isbn,
+    sort_key::SortKey,
+};
+use chrono::{DateTime, Datelike, Utc};
+use std::collections::HashSet;
+
+/// タイトル・著者の読みの最大文字数
+const MAX_READING_LENGTH: usize = 200;
+
+/// 出版社名の最大文字数
+const MAX_PUBLISHER_LENGTH: usize = 200;
 
 /// Book エンティティ（ビジネスルールを持つドメインモデル）
 #[derive(Debug, Clone, PartialEq)]
@@ -9,8 +24,26 @@ pub struct Book {
     id: Option<i32>,
     title: String,
     author: Option<String>,
+    /// タイトルの読み（かな）
+    title_reading: Option<String>,
+    /// 著者の読み（かな）
+    author_reading: Option<String>,
+    title_sort_key: SortKey,
+    author_sort_key: Option<SortKey>,
+    /// 日本十進分類法（NDC）の分類記号
+    ndc: Option<ClassNumber>,
+    /// デューイ十進分類法（DDC）の分類記号
+    ddc: Option<ClassNumber>,
     description: Option<String>,
     published_year: Option<i32>,
+    publisher: Option<String>,
+    isbn: Option<Isbn>,
+    authors: Vec<BookAuthor>,
+    cover: Option<CoverHash>,
+    deleted_at: Option<DateTime<Utc>>,
+    version: i32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
 }
 
 impl Book {
@@ -20,6 +53,7 @@ impl Book {
         author: Option<String>,
         description: Option<String>,
         published_year: Option<i32>,
+        isbn: Option<String>,
     ) -> Result<Self, DomainError> {
         // ビジネスルール: タイトルは必須で、空文字列は不可
         if title.trim().is_empty() {
@@ -46,17 +80,40 @@ impl Book {
             }
         }
 
-        Ok(Self {
+        // ビジネスルール: ISBNはチェックディジットが正しいこと（空文字列は未設定扱い）
+        let isbn = isbn
+            .filter(|i| !i.trim().is_empty())
+            .map(|i| Isbn::parse(&i))
+            .transpose()?;
+
+        let now = Utc::now();
+        let mut book = Self {
             id: None,
             title: title.trim().to_string(),
             author: author
                 .map(|a| a.trim().to_string())
                 .filter(|a| !a.is_empty()),
+            title_reading: None,
+            author_reading: None,
+            title_sort_key: SortKey::new(""),
+            author_sort_key: None,
+            ndc: None,
+            ddc: None,
             description: description
                 .map(|d| d.trim().to_string())
                 .filter(|d| !d.is_empty()),
             published_year,
-        })
+            publisher: None,
+            isbn,
+            authors: Vec::new(),
+            cover: None,
+            deleted_at: None,
+            version: 0,
+            created_at: now,
+            updated_at: now,
+        };
+        book.refresh_sort_keys();
+        Ok(book)
     }
 
     /// 既存の本を再構築（DB から取得した場合など）
@@ -66,14 +123,32 @@ impl Book {
         author: Option<String>,
         description: Option<String>,
         published_year: Option<i32>,
+        isbn: Option<Isbn>,
     ) -> Self {
-        Self {
+        let now = Utc::now();
+        let mut book = Self {
             id: Some(id),
             title,
             author,
+            title_reading: None,
+            author_reading: None,
+            title_sort_key: SortKey::new(""),
+            author_sort_key: None,
+            ndc: None,
+            ddc: None,
             description,
             published_year,
-        }
+            publisher: None,
+            isbn,
+            authors: Vec::new(),
+            cover: None,
+            deleted_at: None,
+            version: 1,
+            created_at: now,
+            updated_at: now,
+        };
+        book.refresh_sort_keys();
+        book
     }
 
     /// 本の詳細を更新（バリデーション付き）
@@ -83,6 +158,7 @@ impl Book {
         author: Option<String>,
         description: Option<String>,
         published_year: Option<i32>,
+        isbn: Option<String>,
     ) -> Result<(), DomainError> {
         // タイトル更新
         if let Some(new_title) = title {
@@ -129,6 +205,210 @@ impl Book {
             self.published_year = Some(year);
         }
 
+        // ISBN更新（空文字列で削除）
+        if let Some(new_isbn) = isbn {
+            self.isbn = if new_isbn.trim().is_empty() {
+                None
+            } else {
+                Some(Isbn::parse(&new_isbn)?)
+            };
+        }
+
+        self.refresh_sort_keys();
+        Ok(())
+    }
+
+    /// 書誌情報をまとめて置き換える（`None` の項目は空にする。バリデーションは新規作成時と同じ）
+    pub fn replace_details(
+        &mut self,
+        title: String,
+        author: Option<String>,
+        description: Option<String>,
+        published_year: Option<i32>,
+        isbn: Option<String>,
+    ) -> Result<(), DomainError> {
+        let validated = Self::new(title, author, description, published_year, isbn)?;
+        self.title = validated.title;
+        self.author = validated.author;
+        self.description = validated.description;
+        self.published_year = validated.published_year;
+        self.isbn = validated.isbn;
+        self.refresh_sort_keys();
+        Ok(())
+    }
+
+    /// タイトル・著者の読みを更新（`None` は変更なし、空文字列で削除）
+    pub fn update_readings(
+        &mut self,
+        title_reading: Option<String>,
+        author_reading: Option<String>,
+    ) -> Result<(), DomainError> {
+        let title_reading = title_reading.map(Self::validate_reading).transpose()?;
+        let author_reading = author_reading.map(Self::validate_reading).transpose()?;
+
+        if let Some(reading) = title_reading {
+            self.title_reading = reading;
+        }
+        if let Some(reading) = author_reading {
+            self.author_reading = reading;
+        }
+        self.refresh_sort_keys();
+        Ok(())
+    }
+
+    /// 読みの検証（前後の空白を除き、空文字列は未設定扱い）
+    fn validate_reading(reading: String) -> Result<Option<String>, DomainError> {
+        // ビジネスルール: 読みは200文字以内
+        let reading = reading.trim();
+        if reading.chars().count() > MAX_READING_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Reading must be {} characters or less",
+                MAX_READING_LENGTH
+            )));
+        }
+        Ok((!reading.is_empty()).then(|| reading.to_string()))
+    }
+
+    /// 出版社を更新（`None` は変更なし、空文字列で削除）
+    pub fn update_publisher(&mut self, publisher: Option<String>) -> Result<(), DomainError> {
+        let Some(publisher) = publisher else {
+            return Ok(());
+        };
+
+        // ビジネスルール: 出版社名は200文字以内
+        let publisher = publisher.trim();
+        if publisher.chars().count() > MAX_PUBLISHER_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Publisher must be {} characters or less",
+                MAX_PUBLISHER_LENGTH
+            )));
+        }
+        self.publisher = (!publisher.is_empty()).then(|| publisher.to_string());
+        Ok(())
+    }
+
+    /// 分類記号を更新（`None` は変更なし、空文字列で削除）
+    pub fn update_classification(
+        &mut self,
+        ndc: Option<String>,
+        ddc: Option<String>,
+    ) -> Result<(), DomainError> {
+        let ndc = ndc
+            .map(|input| Self::parse_class_number(ClassificationScheme::Ndc, &input))
+            .transpose()?;
+        let ddc = ddc
+            .map(|input| Self::parse_class_number(ClassificationScheme::Ddc, &input))
+            .transpose()?;
+
+        if let Some(number) = ndc {
+            self.ndc = number;
+        }
+        if let Some(number) = ddc {
+            self.ddc = number;
+        }
+        Ok(())
+    }
+
+    /// 分類記号の解析（空文字列は未設定扱い）
+    fn parse_class_number(
+        scheme: ClassificationScheme,
+        input: &str,
+    ) -> Result<Option<ClassNumber>, DomainError> {
+        if input.trim().is_empty() {
+            return Ok(None);
+        }
+        ClassNumber::parse(scheme, input).map(Some)
+    }
+
+    /// 並び替え用のキーを作り直す（読み、なければタイトル・表示用の著者名から作る）
+    fn refresh_sort_keys(&mut self) {
+        self.title_sort_key =
+            SortKey::for_title(self.title_reading.as_deref().unwrap_or(&self.title));
+        self.author_sort_key = self
+            .author_reading
+            .as_deref()
+            .or(self.author.as_deref())
+            .map(SortKey::new);
+    }
+
+    /// 編集を始めた時点の版番号と一致することを確認する（楽観的排他制御）
+    pub fn ensure_version(&self, expected: i32) -> Result<(), DomainError> {
+        // ビジネスルール: 他で変更された本は、最新の内容を確認してから編集する
+        if self.version != expected {
+            return Err(DomainError::Conflict(format!(
+                "Book has been modified since version {} (current version is {})",
+                expected, self.version
+            )));
+        }
+        Ok(())
+    }
+
+    /// 著者一覧（役割・表示順）を置き換える
+    ///
+    /// 表示用の著者名（`author`）は役割が著者の人物名から導出する。
+    pub fn set_authors(&mut self, authors: Vec<BookAuthor>) -> Result<(), DomainError> {
+        // ビジネスルール: 著者は保存済みであること
+        if authors.iter().any(|a| a.author().id().is_none()) {
+            return Err(DomainError::InvalidState(
+                "Authors must be saved before being linked to a book".to_string(),
+            ));
+        }
+
+        // ビジネスルール: 同じ人物を同じ役割で重複して登録できない
+        let mut seen = HashSet::new();
+        for book_author in &authors {
+            if !seen.insert((book_author.author().id(), book_author.role())) {
+                return Err(DomainError::ValidationError(format!(
+                    "{} is listed more than once as {}",
+                    book_author.author().name(),
+                    book_author.role().as_str()
+                )));
+            }
+        }
+
+        let names: Vec<&str> = authors
+            .iter()
+            .filter(|a| a.role() == AuthorRole::Author)
+            .map(|a| a.author().name())
+            .collect();
+        self.author = (!names.is_empty()).then(|| names.join(", "));
+        self.authors = authors;
+        self.refresh_sort_keys();
+
+        Ok(())
+    }
+
+    /// 表紙画像を設定する（`None` で削除）
+    pub fn set_cover(&mut self, cover: Option<CoverHash>) {
+        self.cover = cover;
+    }
+
+    /// ISBNを外す（まとめた本のISBNをまとめ先の本に引き継ぐときに使う）
+    pub fn clear_isbn(&mut self) {
+        self.isbn = None;
+    }
+
+    /// ゴミ箱に移す
+    pub fn move_to_trash(&mut self, at: DateTime<Utc>) -> Result<(), DomainError> {
+        // ビジネスルール: ゴミ箱にある本は再度移せない
+        if self.deleted_at.is_some() {
+            return Err(DomainError::InvalidState(
+                "Book is already in the trash".to_string(),
+            ));
+        }
+        self.deleted_at = Some(at);
+        Ok(())
+    }
+
+    /// ゴミ箱から元に戻す
+    pub fn restore(&mut self) -> Result<(), DomainError> {
+        // ビジネスルール: ゴミ箱にない本は戻せない
+        if self.deleted_at.is_none() {
+            return Err(DomainError::InvalidState(
+                "Book is not in the trash".to_string(),
+            ));
+        }
+        self.deleted_at = None;
         Ok(())
     }
 
@@ -145,6 +425,40 @@ impl Book {
         self.author.as_deref()
     }
 
+    pub fn title_reading(&self) -> Option<&str> {
+        self.title_reading.as_deref()
+    }
+
+    pub fn author_reading(&self) -> Option<&str> {
+        self.author_reading.as_deref()
+    }
+
+    /// タイトルの並び替え用のキー（タイトルの読み、なければタイトルから作る）
+    pub fn title_sort_key(&self) -> &SortKey {
+        &self.title_sort_key
+    }
+
+    /// 著者の並び替え用のキー（著者の読み、なければ表示用の著者名から作る。著者がない本は None）
+    pub fn author_sort_key(&self) -> Option<&SortKey> {
+        self.author_sort_key.as_ref()
+    }
+
+    pub fn ndc(&self) -> Option<&ClassNumber> {
+        self.ndc.as_ref()
+    }
+
+    pub fn ddc(&self) -> Option<&ClassNumber> {
+        self.ddc.as_ref()
+    }
+
+    /// 指定した分類法での分類記号
+    pub fn class_number(&self, scheme: ClassificationScheme) -> Option<&ClassNumber> {
+        match scheme {
+            ClassificationScheme::Ndc => self.ndc(),
+            ClassificationScheme::Ddc => self.ddc(),
+        }
+    }
+
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
@@ -153,10 +467,93 @@ impl Book {
         self.published_year
     }
 
+    pub fn publisher(&self) -> Option<&str> {
+        self.publisher.as_deref()
+    }
+
+    pub fn isbn(&self) -> Option<&Isbn> {
+        self.isbn.as_ref()
+    }
+
+    pub fn authors(&self) -> &[BookAuthor] {
+        &self.authors
+    }
+
+    pub fn cover(&self) -> Option<&CoverHash> {
+        self.cover.as_ref()
+    }
+
+    /// ゴミ箱に移した日時（ゴミ箱にない本は None）
+    pub fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+
+    /// 保存済みの版番号（保存するたびに1増える。未保存の本は 0）
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    /// 作成日時（保存時にリポジトリが設定する）
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// 更新日時（保存するたびにリポジトリが設定する）
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
     // IDをセット（リポジトリで保存後に使用）
     pub(crate) fn set_id(&mut self, id: i32) {
         self.id = Some(id);
     }
+
+    // 保存済みの著者一覧をセット（リポジトリでの読み込み時に使用）
+    pub(crate) fn load_authors(&mut self, authors: Vec<BookAuthor>) {
+        self.authors = authors;
+    }
+
+    // ゴミ箱に移した日時をセット（リポジトリでの読み込み時に使用）
+    pub(crate) fn load_deleted_at(&mut self, deleted_at: Option<DateTime<Utc>>) {
+        self.deleted_at = deleted_at;
+    }
+
+    // 保存済みの版番号をセット（リポジトリでの読み込み時に使用）
+    pub(crate) fn load_version(&mut self, version: i32) {
+        self.version = version;
+    }
+
+    // 保存済みの読みをセット（リポジトリでの読み込み時に使用）
+    pub(crate) fn load_readings(
+        &mut self,
+        title_reading: Option<String>,
+        author_reading: Option<String>,
+    ) {
+        self.title_reading = title_reading;
+        self.author_reading = author_reading;
+        self.refresh_sort_keys();
+    }
+
+    // 保存済みの出版社をセット（リポジトリでの読み込み時に使用）
+    pub(crate) fn load_publisher(&mut self, publisher: Option<String>) {
+        self.publisher = publisher;
+    }
+
+    // 保存済みの分類記号をセット（リポジトリでの読み込み時に使用）
+    pub(crate) fn load_classification(
+        &mut self,
+        ndc: Option<ClassNumber>,
+        ddc: Option<ClassNumber>,
+    ) {
+        self.ndc = ndc;
+        self.ddc = ddc;
+    }
+
+    // 作成日時・更新日時をセット（リポジトリでの読み込み時に使用）
+    pub(crate) fn load_timestamps(&mut self, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) {
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+    }
 }
 
 #[cfg(test)]
@@ -170,26 +567,208 @@ mod tests {
             Some("Steve Klabnik".to_string()),
             None,
             Some(2018),
+            Some("978-1-59327-828-1".to_string()),
         );
         assert!(book.is_ok());
+        assert_eq!(
+            book.unwrap().isbn().map(Isbn::as_isbn13),
+            Some("9781593278281")
+        );
     }
 
     #[test]
     fn test_empty_title_fails() {
-        let book = Book::new("".to_string(), None, None, None);
+        let book = Book::new("".to_string(), None, None, None, None);
         assert!(book.is_err());
     }
 
     #[test]
     fn test_long_title_fails() {
         let long_title = "a".repeat(201);
-        let book = Book::new(long_title, None, None, None);
+        let book = Book::new(long_title, None, None, None, None);
         assert!(book.is_err());
     }
 
     #[test]
     fn test_invalid_year_fails() {
-        let book = Book::new("Test".to_string(), None, None, Some(500));
+        let book = Book::new("Test".to_string(), None, None, Some(500), None);
         assert!(book.is_err());
     }
+
+    #[test]
+    fn test_invalid_isbn_fails() {
+        let book = Book::new(
+            "Test".to_string(),
+            None,
+            None,
+            None,
+            Some("978-1-59327-828-2".to_string()),
+        );
+        assert!(matches!(book, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_update_clears_isbn() {
+        let mut book = Book::new(
+            "Test".to_string(),
+            None,
+            None,
+            None,
+            Some("9781593278281".to_string()),
+        )
+        .unwrap();
+        book.update_details(None, None, None, None, Some("".to_string()))
+            .unwrap();
+        assert!(book.isbn().is_none());
+    }
+
+    #[test]
+    fn test_replace_details_clears_missing_fields() {
+        let mut book = Book::new(
+            "Title".to_string(),
+            Some("Author".to_string()),
+            Some("Description".to_string()),
+            Some(2020),
+            None,
+        )
+        .unwrap();
+        book.replace_details("New Title".to_string(), None, None, None, None)
+            .unwrap();
+        assert_eq!(book.title(), "New Title");
+        assert_eq!(book.author(), None);
+        assert_eq!(book.description(), None);
+        assert_eq!(book.published_year(), None);
+
+        assert!(
+            book.replace_details(" ".to_string(), None, None, None, None)
+                .is_err()
+        );
+        assert_eq!(book.title(), "New Title");
+    }
+
+    #[test]
+    fn test_set_authors_derives_display_name() {
+        use crate::domain::entities::author::Author;
+
+        let mut book = Book::new("Test".to_string(), None, None, None, None).unwrap();
+        let first = Author::reconstruct(1, "Terry Pratchett".to_string());
+        let second = Author::reconstruct(2, "Neil Gaiman".to_string());
+        let translator = Author::reconstruct(3, "金原 瑞人".to_string());
+
+        book.set_authors(vec![
+            BookAuthor::new(first.clone(), AuthorRole::Author),
+            BookAuthor::new(second, AuthorRole::Author),
+            BookAuthor::new(translator, AuthorRole::Translator),
+        ])
+        .unwrap();
+        assert_eq!(book.author(), Some("Terry Pratchett, Neil Gaiman"));
+        assert_eq!(book.authors().len(), 3);
+
+        // 同じ人物・同じ役割の重複は不可
+        let result = book.set_authors(vec![
+            BookAuthor::new(first.clone(), AuthorRole::Author),
+            BookAuthor::new(first, AuthorRole::Author),
+        ]);
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_sort_keys_follow_readings() {
+        let mut book = Book::new(
+            "吾輩は猫である".to_string(),
+            Some("夏目 漱石".to_string()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(book.title_sort_key().as_str(), "吾輩は猫である");
+
+        book.update_readings(
+            Some(" ワガハイハネコデアル ".to_string()),
+            Some("なつめ そうせき".to_string()),
+        )
+        .unwrap();
+        assert_eq!(book.title_reading(), Some("ワガハイハネコデアル"));
+        assert_eq!(book.title_sort_key().as_str(), "わがはいはねこである");
+        assert_eq!(
+            book.author_sort_key().map(SortKey::as_str),
+            Some("なつめ そうせき")
+        );
+
+        // 空文字列で読みを削除すると、タイトルから作り直す
+        book.update_readings(Some(String::new()), None).unwrap();
+        book.update_details(Some("The Cat".to_string()), None, None, None, None)
+            .unwrap();
+        assert_eq!(book.title_sort_key().as_str(), "cat");
+        assert_eq!(book.author_reading(), Some("なつめ そうせき"));
+        assert!(book.update_readings(Some("あ".repeat(201)), None).is_err());
+    }
+
+    #[test]
+    fn test_update_classification() {
+        let mut book = Book::new("こころ".to_string(), None, None, None, None).unwrap();
+        book.update_classification(Some("913.6".to_string()), Some("895.634".to_string()))
+            .unwrap();
+        assert_eq!(book.ndc().map(ClassNumber::as_str), Some("913.6"));
+        assert_eq!(
+            book.class_number(ClassificationScheme::Ddc)
+                .map(ClassNumber::as_str),
+            Some("895.634")
+        );
+
+        // 不正な分類記号は変更しない。空文字列で削除する
+        assert!(
+            book.update_classification(None, Some("89".to_string()))
+                .is_err()
+        );
+        book.update_classification(Some(String::new()), None)
+            .unwrap();
+        assert!(book.ndc().is_none());
+        assert_eq!(book.ddc().map(ClassNumber::as_str), Some("895.634"));
+    }
+
+    #[test]
+    fn test_update_publisher() {
+        let mut book = Book::new("こころ".to_string(), None, None, None, None).unwrap();
+        book.update_publisher(Some("  岩波書店 ".to_string()))
+            .unwrap();
+        assert_eq!(book.publisher(), Some("岩波書店"));
+
+        // `None` は変更なし。長すぎる出版社名は変更しない。空文字列で削除する
+        book.update_publisher(None).unwrap();
+        assert_eq!(book.publisher(), Some("岩波書店"));
+        assert!(book.update_publisher(Some("あ".repeat(201))).is_err());
+        assert_eq!(book.publisher(), Some("岩波書店"));
+        book.update_publisher(Some(String::new())).unwrap();
+        assert!(book.publisher().is_none());
+    }
+
+    #[test]
+    fn test_ensure_version() {
+        let mut book = Book::reconstruct(1, "Title".to_string(), None, None, None, None);
+        book.load_version(3);
+        assert!(book.ensure_version(3).is_ok());
+        assert!(matches!(
+            book.ensure_version(2),
+            Err(DomainError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn test_move_to_trash_and_restore() {
+        let mut book = Book::new("Test".to_string(), None, None, None, None).unwrap();
+        assert!(matches!(book.restore(), Err(DomainError::InvalidState(_))));
+
+        let now = Utc::now();
+        book.move_to_trash(now).unwrap();
+        assert_eq!(book.deleted_at(), Some(now));
+        assert!(matches!(
+            book.move_to_trash(now),
+            Err(DomainError::InvalidState(_))
+        ));
+
+        book.restore().unwrap();
+        assert!(book.deleted_at().is_none());
+    }
 }
@@ -0,0 +1,449 @@
+// Library Domain Layer - BookChange エンティティ（本の変更履歴）
+
+use crate::domain::entities::book::Book;
+use crate::domain::value_objects::{
+    author_role::AuthorRole, book_change_action::BookChangeAction,
+    book_change_source::BookChangeSource,
+};
+use chrono::{DateTime, Utc};
+
+/// 変更履歴で差分を表示する本の項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BookField {
+    Title,
+    /// 著者（翻訳者などを含む著者一覧。一覧がない本は表示用の著者名）
+    Authors,
+    TitleReading,
+    AuthorReading,
+    Description,
+    PublishedYear,
+    Publisher,
+    Isbn,
+    Ndc,
+    Ddc,
+}
+
+/// 1項目の変更前後の値（表示用の文字列。値がない場合は None）
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: BookField,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// ある時点での本の書誌情報（以前の版に戻す際にこの内容を適用する）
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookSnapshot {
+    pub title: String,
+    /// 表示用の著者名
+    pub author: Option<String>,
+    pub title_reading: Option<String>,
+    pub author_reading: Option<String>,
+    pub description: Option<String>,
+    pub published_year: Option<i32>,
+    pub publisher: Option<String>,
+    /// ISBN-13（ハイフンなし）
+    pub isbn: Option<String>,
+    /// 日本十進分類法（NDC）の分類記号
+    pub ndc: Option<String>,
+    /// デューイ十進分類法（DDC）の分類記号
+    pub ddc: Option<String>,
+    /// 著者一覧（名前と役割、表示順）
+    pub authors: Vec<(String, AuthorRole)>,
+}
+
+impl BookSnapshot {
+    /// 本の現在の書誌情報を記録する
+    pub fn of(book: &Book) -> Self {
+        Self {
+            title: book.title().to_string(),
+            author: book.author().map(String::from),
+            title_reading: book.title_reading().map(String::from),
+            author_reading: book.author_reading().map(String::from),
+            description: book.description().map(String::from),
+            published_year: book.published_year(),
+            publisher: book.publisher().map(String::from),
+            isbn: book.isbn().map(|isbn| isbn.as_isbn13().to_string()),
+            ndc: book.ndc().map(|ndc| ndc.as_str().to_string()),
+            ddc: book.ddc().map(|ddc| ddc.as_str().to_string()),
+            authors: book
+                .authors()
+                .iter()
+                .map(|a| (a.author().name().to_string(), a.role()))
+                .collect(),
+        }
+    }
+
+    /// 表示用の著者（役割が著者以外の人物は役割を添える）
+    fn authors_label(&self) -> Option<String> {
+        if self.authors.is_empty() {
+            return self.author.clone();
+        }
+        let names: Vec<String> = self
+            .authors
+            .iter()
+            .map(|(name, role)| match role {
+                AuthorRole::Author => name.clone(),
+                other => format!("{} ({})", name, other.as_str()),
+            })
+            .collect();
+        Some(names.join(", "))
+    }
+}
+
+/// BookChange エンティティ（本の作成・編集・削除などの1回分の記録）
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookChange {
+    id: Option<i32>,
+    book_id: i32,
+    revision: i32,
+    action: BookChangeAction,
+    source: BookChangeSource,
+    before: Option<BookSnapshot>,
+    after: BookSnapshot,
+    changed_at: DateTime<Utc>,
+}
+
+impl BookChange {
+    /// 新しい変更を記録（履歴番号は本ごとに1から連番）
+    pub fn new(
+        book_id: i32,
+        revision: i32,
+        action: BookChangeAction,
+        source: BookChangeSource,
+        before: Option<BookSnapshot>,
+        after: BookSnapshot,
+    ) -> Self {
+        Self {
+            id: None,
+            book_id,
+            revision,
+            action,
+            source,
+            before,
+            after,
+            changed_at: Utc::now(),
+        }
+    }
+
+    /// 既存の変更履歴を再構築（DB から取得した場合など）
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstruct(
+        id: i32,
+        book_id: i32,
+        revision: i32,
+        action: BookChangeAction,
+        source: BookChangeSource,
+        before: Option<BookSnapshot>,
+        after: BookSnapshot,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            book_id,
+            revision,
+            action,
+            source,
+            before,
+            after,
+            changed_at,
+        }
+    }
+
+    /// 項目ごとの変更前後の値（変更のない項目は含まない。作成時は全項目を空からの変更とする）
+    pub fn changes(&self) -> Vec<FieldChange> {
+        let before = self.before.as_ref();
+        let fields = [
+            (
+                BookField::Title,
+                before.map(|b| b.title.clone()),
+                Some(self.after.title.clone()),
+            ),
+            (
+                BookField::Authors,
+                before.and_then(BookSnapshot::authors_label),
+                self.after.authors_label(),
+            ),
+            (
+                BookField::TitleReading,
+                before.and_then(|b| b.title_reading.clone()),
+                self.after.title_reading.clone(),
+            ),
+            (
+                BookField::AuthorReading,
+                before.and_then(|b| b.author_reading.clone()),
+                self.after.author_reading.clone(),
+            ),
+            (
+                BookField::Description,
+                before.and_then(|b| b.description.clone()),
+                self.after.description.clone(),
+            ),
+            (
+                BookField::PublishedYear,
+                before.and_then(|b| b.published_year).map(|y| y.to_string()),
+                self.after.published_year.map(|y| y.to_string()),
+            ),
+            (
+                BookField::Publisher,
+                before.and_then(|b| b.publisher.clone()),
+                self.after.publisher.clone(),
+            ),
+            (
+                BookField::Isbn,
+                before.and_then(|b| b.isbn.clone()),
+                self.after.isbn.clone(),
+            ),
+            (
+                BookField::Ndc,
+                before.and_then(|b| b.ndc.clone()),
+                self.after.ndc.clone(),
+            ),
+            (
+                BookField::Ddc,
+                before.and_then(|b| b.ddc.clone()),
+                self.after.ddc.clone(),
+            ),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|(_, before, after)| before != after)
+            .map(|(field, before, after)| FieldChange {
+                field,
+                before,
+                after,
+            })
+            .collect()
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    /// 本ごとの履歴番号（本の同時編集検出用の版数 `Book::version` とは別の番号）
+    pub fn revision(&self) -> i32 {
+        self.revision
+    }
+
+    pub fn action(&self) -> BookChangeAction {
+        self.action
+    }
+
+    pub fn source(&self) -> BookChangeSource {
+        self.source
+    }
+
+    /// 変更前の書誌情報（作成時は None）
+    pub fn before(&self) -> Option<&BookSnapshot> {
+        self.before.as_ref()
+    }
+
+    /// 変更後の書誌情報
+    pub fn after(&self) -> &BookSnapshot {
+        &self.after
+    }
+
+    pub fn changed_at(&self) -> DateTime<Utc> {
+        self.changed_at
+    }
+
+    /// 取り消しの対象になる変更（履歴番号の古い順の履歴から、取り消し済みの変更と取り消し自体を除いた最新の変更）
+    pub fn last_undoable(history: &[BookChange]) -> Option<&BookChange> {
+        let mut undone = 0;
+        for change in history.iter().rev() {
+            if change.action == BookChangeAction::Undo {
+                undone += 1;
+            } else if undone > 0 {
+                undone -= 1;
+            } else {
+                return Some(change);
+            }
+        }
+        None
+    }
+}
+
+/// 本の保存と同じトランザクションで変更履歴に記録する内容（変更後の書誌情報は保存した本から記録する）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeRecord {
+    pub action: BookChangeAction,
+    pub source: BookChangeSource,
+    /// 変更前の書誌情報（作成時は None）
+    pub before: Option<BookSnapshot>,
+}
+
+impl ChangeRecord {
+    pub fn new(
+        action: BookChangeAction,
+        source: BookChangeSource,
+        before: Option<BookSnapshot>,
+    ) -> Self {
+        Self {
+            action,
+            source,
+            before,
+        }
+    }
+
+    /// 本の作成
+    pub fn created(source: BookChangeSource) -> Self {
+        Self::new(BookChangeAction::Create, source, None)
+    }
+
+    /// 保存した本の変更を履歴として記録するか（書誌情報が変わらない編集・復元は記録しない）
+    pub fn is_recorded(&self, after: &BookSnapshot) -> bool {
+        !matches!(
+            self.action,
+            BookChangeAction::Update | BookChangeAction::Revert
+        ) || self.before.as_ref() != Some(after)
+    }
+
+    /// 保存した本の変更履歴を作る
+    pub fn into_change(self, book_id: i32, revision: i32, after: BookSnapshot) -> BookChange {
+        BookChange::new(
+            book_id,
+            revision,
+            self.action,
+            self.source,
+            self.before,
+            after,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(title: &str, year: Option<i32>) -> BookSnapshot {
+        BookSnapshot {
+            title: title.to_string(),
+            author: Some("夏目 漱石".to_string()),
+            title_reading: None,
+            author_reading: None,
+            description: None,
+            published_year: year,
+            publisher: None,
+            isbn: None,
+            ndc: None,
+            ddc: None,
+            authors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_changes_lists_only_modified_fields() {
+        let change = BookChange::new(
+            1,
+            2,
+            BookChangeAction::Update,
+            BookChangeSource::User,
+            Some(snapshot("こころ", Some(1914))),
+            snapshot("坊っちゃん", None),
+        );
+        assert_eq!(
+            change.changes(),
+            vec![
+                FieldChange {
+                    field: BookField::Title,
+                    before: Some("こころ".to_string()),
+                    after: Some("坊っちゃん".to_string()),
+                },
+                FieldChange {
+                    field: BookField::PublishedYear,
+                    before: Some("1914".to_string()),
+                    after: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_creation_changes_from_empty() {
+        let change = BookChange::new(
+            1,
+            1,
+            BookChangeAction::Create,
+            BookChangeSource::User,
+            None,
+            snapshot("こころ", None),
+        );
+        let fields: Vec<BookField> = change.changes().iter().map(|c| c.field).collect();
+        assert_eq!(fields, vec![BookField::Title, BookField::Authors]);
+    }
+
+    #[test]
+    fn test_last_undoable_skips_undone_changes() {
+        let change = |revision, action| {
+            BookChange::new(
+                1,
+                revision,
+                action,
+                BookChangeSource::User,
+                None,
+                snapshot("こころ", None),
+            )
+        };
+        let mut history = vec![
+            change(1, BookChangeAction::Create),
+            change(2, BookChangeAction::Update),
+            change(3, BookChangeAction::Update),
+        ];
+        assert_eq!(BookChange::last_undoable(&history).unwrap().revision(), 3);
+
+        history.push(change(4, BookChangeAction::Undo));
+        assert_eq!(BookChange::last_undoable(&history).unwrap().revision(), 2);
+
+        history.push(change(5, BookChangeAction::Undo));
+        assert_eq!(BookChange::last_undoable(&history).unwrap().revision(), 1);
+
+        history.push(change(6, BookChangeAction::Revert));
+        assert_eq!(BookChange::last_undoable(&history).unwrap().revision(), 6);
+    }
+
+    #[test]
+    fn test_unchanged_update_is_not_recorded() {
+        let before = snapshot("こころ", None);
+        let update = ChangeRecord::new(
+            BookChangeAction::Update,
+            BookChangeSource::User,
+            Some(before.clone()),
+        );
+        assert!(!update.is_recorded(&before));
+        assert!(update.is_recorded(&snapshot("坊っちゃん", None)));
+
+        let undo = ChangeRecord::new(
+            BookChangeAction::Undo,
+            BookChangeSource::History,
+            Some(before.clone()),
+        );
+        assert!(undo.is_recorded(&before));
+    }
+
+    #[test]
+    fn test_authors_label_includes_roles() {
+        let mut after = snapshot("変身", None);
+        after.authors = vec![
+            ("カフカ".to_string(), AuthorRole::Author),
+            ("高橋 義孝".to_string(), AuthorRole::Translator),
+        ];
+        let change = BookChange::new(
+            1,
+            2,
+            BookChangeAction::Update,
+            BookChangeSource::User,
+            Some(snapshot("変身", None)),
+            after,
+        );
+        assert_eq!(
+            change.changes()[0].after.as_deref(),
+            Some("カフカ, 高橋 義孝 (translator)")
+        );
+    }
+}
@@ -0,0 +1,82 @@
+// Library Domain Layer - BookFile エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::ebook_format::EbookFormat;
+use std::path::{Path, PathBuf};
+
+/// BookFile エンティティ（本に紐付けた電子書籍ファイル）
+///
+/// 元の場所のファイルを参照するか、アプリが管理するディレクトリへのコピーを参照する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookFile {
+    id: Option<i32>,
+    book_id: i32,
+    path: PathBuf,
+    format: EbookFormat,
+    managed: bool,
+}
+
+impl BookFile {
+    /// 新しいファイルの紐付けを作成（バリデーション付き）
+    pub fn new(
+        book_id: i32,
+        path: PathBuf,
+        format: EbookFormat,
+        managed: bool,
+    ) -> Result<Self, DomainError> {
+        // ビジネスルール: 後から開けるように絶対パスで記録する
+        if !path.is_absolute() {
+            return Err(DomainError::ValidationError(format!(
+                "E-book file path must be absolute: {}",
+                path.display()
+            )));
+        }
+
+        Ok(Self {
+            id: None,
+            book_id,
+            path,
+            format,
+            managed,
+        })
+    }
+
+    /// 既存の紐付けを再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        book_id: i32,
+        path: PathBuf,
+        format: EbookFormat,
+        managed: bool,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            book_id,
+            path,
+            format,
+            managed,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn format(&self) -> EbookFormat {
+        self.format
+    }
+
+    /// アプリが管理するコピーか
+    pub fn managed(&self) -> bool {
+        self.managed
+    }
+}
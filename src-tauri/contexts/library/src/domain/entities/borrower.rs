@@ -0,0 +1,195 @@
+// Library Domain Layer - Borrower エンティティ
+
+use crate::domain::errors::DomainError;
+
+/// 名前の最大文字数
+const MAX_NAME_LENGTH: usize = 100;
+/// メールアドレスの最大文字数
+const MAX_EMAIL_LENGTH: usize = 254;
+/// 電話番号の最大文字数
+const MAX_PHONE_LENGTH: usize = 30;
+
+/// Borrower エンティティ（本を貸す相手の連絡先）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Borrower {
+    id: Option<i32>,
+    name: String,
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+impl Borrower {
+    /// 新しい連絡先を作成（バリデーション付き）
+    pub fn new(
+        name: String,
+        email: Option<String>,
+        phone: Option<String>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: None,
+            name: Self::validate_name(name)?,
+            email: Self::validate_email(email)?,
+            phone: Self::validate_phone(phone)?,
+        })
+    }
+
+    /// 既存の連絡先を再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        name: String,
+        email: Option<String>,
+        phone: Option<String>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            name,
+            email,
+            phone,
+        }
+    }
+
+    /// 名前を変更
+    pub fn rename(&mut self, name: String) -> Result<(), DomainError> {
+        self.name = Self::validate_name(name)?;
+        Ok(())
+    }
+
+    /// メールアドレスを変更（None で削除）
+    pub fn change_email(&mut self, email: Option<String>) -> Result<(), DomainError> {
+        self.email = Self::validate_email(email)?;
+        Ok(())
+    }
+
+    /// 電話番号を変更（None で削除）
+    pub fn change_phone(&mut self, phone: Option<String>) -> Result<(), DomainError> {
+        self.phone = Self::validate_phone(phone)?;
+        Ok(())
+    }
+
+    /// ビジネスルール: 名前は必須で100文字以内
+    fn validate_name(name: String) -> Result<String, DomainError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Borrower name cannot be empty".to_string(),
+            ));
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Borrower name must be {} characters or less",
+                MAX_NAME_LENGTH
+            )));
+        }
+        Ok(name.to_string())
+    }
+
+    /// メールアドレスの検証（空文字列は未設定扱い。`@` の前後が空でなく、空白を含まない）
+    fn validate_email(email: Option<String>) -> Result<Option<String>, DomainError> {
+        let Some(email) = email
+            .map(|e| e.trim().to_string())
+            .filter(|e| !e.is_empty())
+        else {
+            return Ok(None);
+        };
+        let valid = email.chars().count() <= MAX_EMAIL_LENGTH
+            && !email.chars().any(char::is_whitespace)
+            && email.split_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty() && !domain.is_empty() && !domain.contains('@')
+            });
+        if !valid {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid email address: {}",
+                email
+            )));
+        }
+        Ok(Some(email))
+    }
+
+    /// 電話番号の検証（空文字列は未設定扱い。数字と `+` `-` `(` `)` 空白のみ）
+    fn validate_phone(phone: Option<String>) -> Result<Option<String>, DomainError> {
+        let Some(phone) = phone
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+        else {
+            return Ok(None);
+        };
+        let valid = phone.chars().count() <= MAX_PHONE_LENGTH
+            && phone.chars().any(|c| c.is_ascii_digit())
+            && phone
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '(' | ')' | ' '));
+        if !valid {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid phone number: {}",
+                phone
+            )));
+        }
+        Ok(Some(phone))
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_borrower_contacts() {
+        let mut borrower = Borrower::new(
+            " 山田太郎 ".to_string(),
+            Some(" taro@example.com ".to_string()),
+            Some("090-1234-5678".to_string()),
+        )
+        .unwrap();
+        assert_eq!(borrower.name(), "山田太郎");
+        assert_eq!(borrower.email(), Some("taro@example.com"));
+
+        borrower.change_email(Some(String::new())).unwrap();
+        borrower
+            .change_phone(Some("+81 (90) 1234 5678".to_string()))
+            .unwrap();
+        assert_eq!(borrower.email(), None);
+        assert_eq!(borrower.phone(), Some("+81 (90) 1234 5678"));
+    }
+
+    #[test]
+    fn test_invalid_borrower_fails() {
+        assert!(Borrower::new("  ".to_string(), None, None).is_err());
+        for email in [
+            "taro",
+            "@example.com",
+            "taro@",
+            "taro @example.com",
+            "a@b@c",
+        ] {
+            assert!(
+                Borrower::new("Taro".to_string(), Some(email.to_string()), None).is_err(),
+                "{}",
+                email
+            );
+        }
+        for phone in ["---", "090-1234-ABCD"] {
+            assert!(
+                Borrower::new("Taro".to_string(), None, Some(phone.to_string())).is_err(),
+                "{}",
+                phone
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// Library Domain Layer - Copy エンティティ
+
+use crate::domain::value_objects::{
+    copy_condition::CopyCondition, copy_format::CopyFormat, price::Price,
+};
+use chrono::NaiveDate;
+
+/// Copy エンティティ（所有している本の1冊。同じ本を複数冊持つ場合は冊ごとに作る）
+///
+/// 本（`Book`）は作品・版を表し、1冊ごとの版型・状態・入手時の情報と置いてある場所を持つ。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Copy {
+    id: Option<i32>,
+    book_id: i32,
+    format: CopyFormat,
+    condition: Option<CopyCondition>,
+    acquired_on: Option<NaiveDate>,
+    /// 入手時の価格
+    price: Option<Price>,
+    /// 置いてある場所（部屋・本棚・棚のいずれか）
+    location_id: Option<i32>,
+}
+
+impl Copy {
+    /// 新しい1冊を作成
+    pub fn new(book_id: i32, format: CopyFormat) -> Self {
+        Self {
+            id: None,
+            book_id,
+            format,
+            condition: None,
+            acquired_on: None,
+            price: None,
+            location_id: None,
+        }
+    }
+
+    /// 既存の1冊を再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        book_id: i32,
+        format: CopyFormat,
+        condition: Option<CopyCondition>,
+        acquired_on: Option<NaiveDate>,
+        price: Option<Price>,
+        location_id: Option<i32>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            book_id,
+            format,
+            condition,
+            acquired_on,
+            price,
+            location_id,
+        }
+    }
+
+    /// 版型を変更
+    pub fn change_format(&mut self, format: CopyFormat) {
+        self.format = format;
+    }
+
+    /// 状態を変更（None で未設定に戻す）
+    pub fn change_condition(&mut self, condition: Option<CopyCondition>) {
+        self.condition = condition;
+    }
+
+    /// 入手日を変更（None で未設定に戻す）
+    pub fn change_acquired_on(&mut self, acquired_on: Option<NaiveDate>) {
+        self.acquired_on = acquired_on;
+    }
+
+    /// 入手時の価格を変更（None で未設定に戻す）
+    pub fn change_price(&mut self, price: Option<Price>) {
+        self.price = price;
+    }
+
+    /// 別の場所に移す（None で場所を未設定に戻す）
+    pub fn move_to(&mut self, location_id: Option<i32>) {
+        self.location_id = location_id;
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    pub fn format(&self) -> CopyFormat {
+        self.format
+    }
+
+    pub fn condition(&self) -> Option<CopyCondition> {
+        self.condition
+    }
+
+    pub fn acquired_on(&self) -> Option<NaiveDate> {
+        self.acquired_on
+    }
+
+    pub fn price(&self) -> Option<&Price> {
+        self.price.as_ref()
+    }
+
+    pub fn location_id(&self) -> Option<i32> {
+        self.location_id
+    }
+}
@@ -0,0 +1,190 @@
+// Library Domain Layer - Highlight エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::highlight_kind::HighlightKind;
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// ハイライトの最大文字数
+const MAX_CONTENT_LENGTH: usize = 20_000;
+
+/// 電子書籍内の位置（ページ・位置番号。どちらも端末が記録した場合のみ）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HighlightLocation {
+    page: Option<i32>,
+    start: Option<i32>,
+    end: Option<i32>,
+}
+
+impl HighlightLocation {
+    /// 位置を作成（バリデーション付き）。終了位置を省略すると開始位置と同じとみなす
+    pub fn new(
+        page: Option<i32>,
+        start: Option<i32>,
+        end: Option<i32>,
+    ) -> Result<Self, DomainError> {
+        if page.is_some_and(|p| p < 1) {
+            return Err(DomainError::ValidationError(
+                "Page must be 1 or greater".to_string(),
+            ));
+        }
+
+        let end = match (start, end) {
+            (None, Some(_)) => {
+                return Err(DomainError::ValidationError(
+                    "Location end requires a location start".to_string(),
+                ));
+            }
+            (Some(start), _) if start < 0 => {
+                return Err(DomainError::ValidationError(
+                    "Location must not be negative".to_string(),
+                ));
+            }
+            (Some(start), Some(end)) if end < start => {
+                return Err(DomainError::ValidationError(
+                    "Location end must not be before its start".to_string(),
+                ));
+            }
+            (Some(start), None) => Some(start),
+            (_, end) => end,
+        };
+
+        Ok(Self { page, start, end })
+    }
+
+    pub fn page(self) -> Option<i32> {
+        self.page
+    }
+
+    pub fn start(self) -> Option<i32> {
+        self.start
+    }
+
+    pub fn end(self) -> Option<i32> {
+        self.end
+    }
+}
+
+/// Highlight エンティティ（電子書籍のハイライト・メモを取り込んだもの）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Highlight {
+    id: Option<i32>,
+    book_id: i32,
+    kind: HighlightKind,
+    content: String,
+    location: HighlightLocation,
+    clipped_at: Option<NaiveDateTime>,
+    created_at: DateTime<Utc>,
+}
+
+impl Highlight {
+    /// 新しいハイライトを作成（バリデーション付き）
+    pub fn new(
+        book_id: i32,
+        kind: HighlightKind,
+        content: String,
+        location: HighlightLocation,
+        clipped_at: Option<NaiveDateTime>,
+    ) -> Result<Self, DomainError> {
+        // ビジネスルール: 本文は必須
+        let content = content.trim();
+        if content.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Highlight cannot be empty".to_string(),
+            ));
+        }
+
+        // ビジネスルール: 本文は20000文字以内
+        if content.chars().count() > MAX_CONTENT_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Highlight must be {} characters or less",
+                MAX_CONTENT_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            id: None,
+            book_id,
+            kind,
+            content: content.to_string(),
+            location,
+            clipped_at,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// 既存のハイライトを再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        book_id: i32,
+        kind: HighlightKind,
+        content: String,
+        location: HighlightLocation,
+        clipped_at: Option<NaiveDateTime>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            book_id,
+            kind,
+            content,
+            location,
+            clipped_at,
+            created_at,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    pub fn kind(&self) -> HighlightKind {
+        self.kind
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn location(&self) -> HighlightLocation {
+        self.location
+    }
+
+    pub fn clipped_at(&self) -> Option<NaiveDateTime> {
+        self.clipped_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_validation() {
+        let location = HighlightLocation::new(Some(12), Some(180), None).unwrap();
+        assert_eq!(location.end(), Some(180));
+        assert!(HighlightLocation::new(Some(0), None, None).is_err());
+        assert!(HighlightLocation::new(None, Some(181), Some(180)).is_err());
+        assert!(HighlightLocation::new(None, None, Some(180)).is_err());
+    }
+
+    #[test]
+    fn test_empty_highlight_fails() {
+        let result = Highlight::new(
+            1,
+            HighlightKind::Highlight,
+            "  \n".to_string(),
+            HighlightLocation::default(),
+            None,
+        );
+        assert!(result.is_err());
+    }
+}
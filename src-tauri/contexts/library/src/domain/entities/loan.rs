@@ -0,0 +1,196 @@
+// Library Domain Layer - Loan エンティティ
+
+use crate::domain::errors::DomainError;
+use chrono::NaiveDate;
+
+/// Loan エンティティ（所有している本の1冊を貸した記録。返却後も履歴として残す）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loan {
+    id: Option<i32>,
+    copy_id: i32,
+    borrower_id: i32,
+    lent_on: NaiveDate,
+    /// 返却期限
+    due_on: Option<NaiveDate>,
+    /// 返却日（貸出中はなし）
+    returned_on: Option<NaiveDate>,
+}
+
+impl Loan {
+    /// 1冊を貸し出す（`current` はその冊の最新の貸出）
+    ///
+    /// ビジネスルール: 貸出中の冊は返却されるまで貸し出せない
+    pub fn lend(
+        copy_id: i32,
+        borrower_id: i32,
+        lent_on: NaiveDate,
+        due_on: Option<NaiveDate>,
+        current: Option<&Loan>,
+    ) -> Result<Self, DomainError> {
+        if current.is_some_and(Loan::is_active) {
+            return Err(DomainError::InvalidState(format!(
+                "Copy {} is already lent out",
+                copy_id
+            )));
+        }
+        Self::validate_due_on(lent_on, due_on)?;
+
+        Ok(Self {
+            id: None,
+            copy_id,
+            borrower_id,
+            lent_on,
+            due_on,
+            returned_on: None,
+        })
+    }
+
+    /// 既存の貸出を再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        copy_id: i32,
+        borrower_id: i32,
+        lent_on: NaiveDate,
+        due_on: Option<NaiveDate>,
+        returned_on: Option<NaiveDate>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            copy_id,
+            borrower_id,
+            lent_on,
+            due_on,
+            returned_on,
+        }
+    }
+
+    /// 返却を記録
+    pub fn mark_returned(&mut self, returned_on: NaiveDate) -> Result<(), DomainError> {
+        if !self.is_active() {
+            return Err(DomainError::InvalidState(format!(
+                "Copy {} has already been returned",
+                self.copy_id
+            )));
+        }
+        if returned_on < self.lent_on {
+            return Err(DomainError::ValidationError(
+                "Return date cannot be before the lending date".to_string(),
+            ));
+        }
+        self.returned_on = Some(returned_on);
+        Ok(())
+    }
+
+    /// 返却期限を変更（貸出中のみ。None で期限なしにする）
+    pub fn change_due_on(&mut self, due_on: Option<NaiveDate>) -> Result<(), DomainError> {
+        if !self.is_active() {
+            return Err(DomainError::InvalidState(format!(
+                "Copy {} has already been returned",
+                self.copy_id
+            )));
+        }
+        Self::validate_due_on(self.lent_on, due_on)?;
+        self.due_on = due_on;
+        Ok(())
+    }
+
+    /// 貸出中か
+    pub fn is_active(&self) -> bool {
+        self.returned_on.is_none()
+    }
+
+    /// 返却期限までの日数（貸出中で期限がある場合のみ。当日は0、期限を過ぎている場合は負）
+    pub fn days_until_due(&self, today: NaiveDate) -> Option<i64> {
+        self.due_on
+            .filter(|_| self.is_active())
+            .map(|due_on| (due_on - today).num_days())
+    }
+
+    /// 返却期限を過ぎているか（貸出中のみ）
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.days_until_due(today).is_some_and(|days| days < 0)
+    }
+
+    /// ビジネスルール: 返却期限は貸出日以降
+    fn validate_due_on(lent_on: NaiveDate, due_on: Option<NaiveDate>) -> Result<(), DomainError> {
+        if due_on.is_some_and(|due_on| due_on < lent_on) {
+            return Err(DomainError::ValidationError(
+                "Due date cannot be before the lending date".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn copy_id(&self) -> i32 {
+        self.copy_id
+    }
+
+    pub fn borrower_id(&self) -> i32 {
+        self.borrower_id
+    }
+
+    pub fn lent_on(&self) -> NaiveDate {
+        self.lent_on
+    }
+
+    pub fn due_on(&self) -> Option<NaiveDate> {
+        self.due_on
+    }
+
+    pub fn returned_on(&self) -> Option<NaiveDate> {
+        self.returned_on
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_cannot_lend_a_copy_that_is_out() {
+        let mut current = Loan::reconstruct(1, 10, 1, date("2026-09-01"), None, None);
+        assert!(Loan::lend(10, 2, date("2026-09-10"), None, Some(&current)).is_err());
+
+        current.mark_returned(date("2026-09-20")).unwrap();
+        assert!(current.mark_returned(date("2026-09-21")).is_err());
+        let next = Loan::lend(
+            10,
+            2,
+            date("2026-09-21"),
+            Some(date("2026-10-05")),
+            Some(&current),
+        )
+        .unwrap();
+        assert!(next.is_active());
+        assert!(Loan::lend(10, 2, date("2026-09-21"), Some(date("2026-09-20")), None).is_err());
+    }
+
+    #[test]
+    fn test_due_dates() {
+        let mut loan =
+            Loan::lend(10, 1, date("2026-10-01"), Some(date("2026-10-15")), None).unwrap();
+        assert_eq!(loan.days_until_due(date("2026-10-12")), Some(3));
+        assert!(!loan.is_overdue(date("2026-10-15")));
+        assert!(loan.is_overdue(date("2026-10-16")));
+
+        loan.change_due_on(None).unwrap();
+        assert_eq!(loan.days_until_due(date("2026-10-16")), None);
+        assert!(loan.mark_returned(date("2026-09-30")).is_err());
+
+        // 返却後は期限を過ぎても延滞扱いにしない
+        let mut returned =
+            Loan::lend(10, 1, date("2026-10-01"), Some(date("2026-10-02")), None).unwrap();
+        returned.mark_returned(date("2026-10-05")).unwrap();
+        assert!(!returned.is_overdue(date("2026-10-18")));
+        assert!(returned.change_due_on(None).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+// Library Domain Layer - Location エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::location_kind::LocationKind;
+
+/// 場所の名前の最大文字数
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Location エンティティ（本を置く場所。部屋 > 本棚 > 棚の階層）
+///
+/// 部屋は最上位に置き、本棚は部屋に、棚は本棚に置く。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    id: Option<i32>,
+    name: String,
+    kind: LocationKind,
+    /// 1つ上の階層の場所（部屋はなし）
+    parent_id: Option<i32>,
+}
+
+impl Location {
+    /// 新しい場所を作成（バリデーション付き）
+    pub fn new(
+        name: String,
+        kind: LocationKind,
+        parent: Option<&Location>,
+    ) -> Result<Self, DomainError> {
+        let name = Self::validate_name(name)?;
+        let parent_id = Self::validate_parent(kind, parent)?;
+
+        Ok(Self {
+            id: None,
+            name,
+            kind,
+            parent_id,
+        })
+    }
+
+    /// 既存の場所を再構築（DB から取得した場合など）
+    pub fn reconstruct(id: i32, name: String, kind: LocationKind, parent_id: Option<i32>) -> Self {
+        Self {
+            id: Some(id),
+            name,
+            kind,
+            parent_id,
+        }
+    }
+
+    /// 名前を変更
+    pub fn rename(&mut self, name: String) -> Result<(), DomainError> {
+        self.name = Self::validate_name(name)?;
+        Ok(())
+    }
+
+    /// 別の場所に移す（本棚を別の部屋に、棚を別の本棚に移す場合など）
+    pub fn move_to(&mut self, parent: Option<&Location>) -> Result<(), DomainError> {
+        self.parent_id = Self::validate_parent(self.kind, parent)?;
+        Ok(())
+    }
+
+    /// ビジネスルール: 名前は必須で100文字以内
+    fn validate_name(name: String) -> Result<String, DomainError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Location name cannot be empty".to_string(),
+            ));
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Location name must be {} characters or less",
+                MAX_NAME_LENGTH
+            )));
+        }
+        Ok(name.to_string())
+    }
+
+    /// ビジネスルール: 部屋は最上位に置き、本棚は部屋に、棚は本棚に置く
+    fn validate_parent(
+        kind: LocationKind,
+        parent: Option<&Location>,
+    ) -> Result<Option<i32>, DomainError> {
+        let Some(expected) = kind.parent_kind() else {
+            return match parent {
+                None => Ok(None),
+                Some(_) => Err(DomainError::ValidationError(format!(
+                    "A {} cannot be placed in another location",
+                    kind.as_str()
+                ))),
+            };
+        };
+
+        match parent {
+            Some(parent) if parent.kind == expected => parent.id.map(Some).ok_or_else(|| {
+                DomainError::InvalidState("Parent location must be saved".to_string())
+            }),
+            _ => Err(DomainError::ValidationError(format!(
+                "A {} must be placed in a {}",
+                kind.as_str(),
+                expected.as_str()
+            ))),
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> LocationKind {
+        self.kind
+    }
+
+    pub fn parent_id(&self) -> Option<i32> {
+        self.parent_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_hierarchy_rules() {
+        let study = Location::reconstruct(1, "書斎".to_string(), LocationKind::Room, None);
+        let bookcase =
+            Location::reconstruct(2, "壁の本棚".to_string(), LocationKind::Bookcase, Some(1));
+
+        let shelf =
+            Location::new(" 上段 ".to_string(), LocationKind::Shelf, Some(&bookcase)).unwrap();
+        assert_eq!(shelf.name(), "上段");
+        assert_eq!(shelf.parent_id(), Some(2));
+        assert!(Location::new("居間".to_string(), LocationKind::Room, None).is_ok());
+
+        // 階層を飛ばす・逆にすることはできない
+        assert!(Location::new("上段".to_string(), LocationKind::Shelf, Some(&study)).is_err());
+        assert!(Location::new("本棚".to_string(), LocationKind::Bookcase, None).is_err());
+        assert!(Location::new("寝室".to_string(), LocationKind::Room, Some(&study)).is_err());
+        assert!(Location::new("  ".to_string(), LocationKind::Room, None).is_err());
+    }
+
+    #[test]
+    fn test_move_location() {
+        let mut bookcase =
+            Location::reconstruct(2, "壁の本棚".to_string(), LocationKind::Bookcase, Some(1));
+        let bedroom = Location::reconstruct(3, "寝室".to_string(), LocationKind::Room, None);
+        bookcase.move_to(Some(&bedroom)).unwrap();
+        assert_eq!(bookcase.parent_id(), Some(3));
+
+        assert!(bookcase.move_to(None).is_err());
+        assert_eq!(bookcase.parent_id(), Some(3));
+    }
+}
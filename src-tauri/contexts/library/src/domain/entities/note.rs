@@ -0,0 +1,119 @@
+// Library Domain Layer - Note エンティティ
+
+use crate::domain::errors::DomainError;
+use chrono::{DateTime, Utc};
+
+/// メモの最大文字数
+const MAX_BODY_LENGTH: usize = 10_000;
+
+/// Note エンティティ（本に付ける非公開のメモ）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    id: Option<i32>,
+    book_id: i32,
+    body: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Note {
+    /// 新しいメモを作成（バリデーション付き）
+    pub fn new(book_id: i32, body: String) -> Result<Self, DomainError> {
+        let body = Self::validate_body(body)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: None,
+            book_id,
+            body,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 既存のメモを再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        book_id: i32,
+        body: String,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            book_id,
+            body,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// 本文を編集
+    pub fn edit(&mut self, body: String) -> Result<(), DomainError> {
+        self.body = Self::validate_body(body)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn validate_body(body: String) -> Result<String, DomainError> {
+        // ビジネスルール: 本文は必須で、空文字列は不可
+        let body = body.trim();
+        if body.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Note cannot be empty".to_string(),
+            ));
+        }
+
+        // ビジネスルール: 本文は10000文字以内
+        if body.chars().count() > MAX_BODY_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Note must be {} characters or less",
+                MAX_BODY_LENGTH
+            )));
+        }
+
+        Ok(body.to_string())
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_note_fails() {
+        assert!(Note::new(1, " \n ".to_string()).is_err());
+        assert!(Note::new(1, "a".repeat(10_001)).is_err());
+    }
+
+    #[test]
+    fn test_edit_updates_timestamp() {
+        let mut note = Note::new(1, "p.42 が良い".to_string()).unwrap();
+        let created_at = note.created_at();
+        note.edit("p.43 が良い".to_string()).unwrap();
+        assert_eq!(note.body(), "p.43 が良い");
+        assert!(note.updated_at() >= created_at);
+        assert_eq!(note.created_at(), created_at);
+    }
+}
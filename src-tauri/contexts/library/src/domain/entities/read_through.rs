@@ -0,0 +1,372 @@
+// Library Domain Layer - ReadThrough エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{
+    rating::Rating, reading_progress::ReadingProgress, reading_status::ReadingStatus,
+};
+use chrono::NaiveDate;
+use std::cmp::Ordering;
+
+/// 1日の読書時間の上限（分）
+const MAX_SESSION_MINUTES: i32 = 24 * 60;
+
+/// ReadingSession エンティティ（1回分の読書記録）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadingSession {
+    id: Option<i32>,
+    read_on: NaiveDate,
+    progress: ReadingProgress,
+    duration_minutes: Option<i32>,
+}
+
+impl ReadingSession {
+    /// 新しい読書記録を作成（バリデーション付き）
+    pub fn new(
+        read_on: NaiveDate,
+        progress: ReadingProgress,
+        duration_minutes: Option<i32>,
+    ) -> Result<Self, DomainError> {
+        // ビジネスルール: 読書時間は1分以上、1日以内
+        if let Some(minutes) = duration_minutes
+            && !(1..=MAX_SESSION_MINUTES).contains(&minutes)
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Duration must be between 1 and {} minutes",
+                MAX_SESSION_MINUTES
+            )));
+        }
+
+        Ok(Self {
+            id: None,
+            read_on,
+            progress,
+            duration_minutes,
+        })
+    }
+
+    /// 既存の読書記録を再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        read_on: NaiveDate,
+        progress: ReadingProgress,
+        duration_minutes: Option<i32>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            read_on,
+            progress,
+            duration_minutes,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn read_on(&self) -> NaiveDate {
+        self.read_on
+    }
+
+    pub fn progress(&self) -> &ReadingProgress {
+        &self.progress
+    }
+
+    pub fn duration_minutes(&self) -> Option<i32> {
+        self.duration_minutes
+    }
+}
+
+/// ReadThrough エンティティ（1回分の通読。再読のたびに新しく作られる）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadThrough {
+    id: Option<i32>,
+    book_id: i32,
+    status: ReadingStatus,
+    started_on: Option<NaiveDate>,
+    finished_on: Option<NaiveDate>,
+    /// この通読での評価
+    rating: Option<Rating>,
+    /// 読書記録（日付順）
+    sessions: Vec<ReadingSession>,
+}
+
+impl ReadThrough {
+    /// 指定した状態で新しい通読を開始
+    ///
+    /// 読書中なら `on` を開始日、読了・中断なら `on` を終了日とする。
+    pub fn new(book_id: i32, status: ReadingStatus, on: NaiveDate) -> Self {
+        Self {
+            id: None,
+            book_id,
+            status,
+            started_on: (status == ReadingStatus::Reading).then_some(on),
+            finished_on: status.is_closed().then_some(on),
+            rating: None,
+            sessions: Vec::new(),
+        }
+    }
+
+    /// 既存の通読を再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        book_id: i32,
+        status: ReadingStatus,
+        started_on: Option<NaiveDate>,
+        finished_on: Option<NaiveDate>,
+        rating: Option<Rating>,
+        sessions: Vec<ReadingSession>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            book_id,
+            status,
+            started_on,
+            finished_on,
+            rating,
+            sessions,
+        }
+    }
+
+    /// 状態を変更（読みたい → 読書中 → 読了・中断 の順にのみ進む）
+    pub fn change_status(
+        &mut self,
+        status: ReadingStatus,
+        on: NaiveDate,
+    ) -> Result<(), DomainError> {
+        if self.status == status {
+            return Err(DomainError::InvalidState(format!(
+                "Read-through is already {}",
+                status.as_str()
+            )));
+        }
+
+        // ビジネスルール: 終わった通読の状態は変えられない（再読は新しい通読として記録する）
+        if self.status.is_closed() {
+            return Err(DomainError::InvalidState(
+                "Read-through is already closed; start a re-read instead".to_string(),
+            ));
+        }
+
+        match status {
+            ReadingStatus::WantToRead => Err(DomainError::InvalidState(
+                "Cannot move a started read-through back to want-to-read".to_string(),
+            )),
+            ReadingStatus::Reading => {
+                self.status = status;
+                self.started_on = Some(on);
+                Ok(())
+            }
+            ReadingStatus::Finished | ReadingStatus::Abandoned => {
+                // ビジネスルール: 開始日より前に終えることはできない
+                if self.started_on.is_some_and(|started| on < started) {
+                    return Err(DomainError::ValidationError(
+                        "Finish date cannot be before the start date".to_string(),
+                    ));
+                }
+                // ビジネスルール: 最後の読書記録より前に終えることはできない
+                if self.sessions.last().is_some_and(|s| on < s.read_on()) {
+                    return Err(DomainError::ValidationError(
+                        "Finish date cannot be before the last reading session".to_string(),
+                    ));
+                }
+                self.status = status;
+                self.finished_on = Some(on);
+                Ok(())
+            }
+        }
+    }
+
+    /// 読書記録を追加（読みたい状態なら記録日から読書中にする）
+    pub fn log_session(&mut self, session: ReadingSession) -> Result<(), DomainError> {
+        if self.status.is_closed() {
+            return Err(DomainError::InvalidState(
+                "Cannot log a session on a closed read-through; start a re-read instead"
+                    .to_string(),
+            ));
+        }
+
+        // ビジネスルール: 開始日より前の記録は不可
+        if let Some(started) = self.started_on
+            && session.read_on < started
+        {
+            return Err(DomainError::ValidationError(
+                "Session date cannot be before the start date".to_string(),
+            ));
+        }
+
+        // ビジネスルール: 通読の中で進捗は増える一方（日付順で前の記録より進み、後の記録より手前）
+        let position = self
+            .sessions
+            .partition_point(|s| s.read_on <= session.read_on);
+        if let Some(previous) = position.checked_sub(1).map(|i| &self.sessions[i])
+            && session.progress.compare(&previous.progress)? != Ordering::Greater
+        {
+            return Err(DomainError::ValidationError(
+                "Reading progress must increase within a read-through".to_string(),
+            ));
+        }
+        if let Some(next) = self.sessions.get(position)
+            && session.progress.compare(&next.progress)? != Ordering::Less
+        {
+            return Err(DomainError::ValidationError(
+                "Reading progress must increase within a read-through".to_string(),
+            ));
+        }
+
+        if self.status == ReadingStatus::WantToRead {
+            self.status = ReadingStatus::Reading;
+            self.started_on = Some(session.read_on);
+        }
+        self.sessions.insert(position, session);
+
+        Ok(())
+    }
+
+    /// 読書記録を削除
+    pub fn remove_session(&mut self, session_id: i32) -> Result<(), DomainError> {
+        let index = self
+            .sessions
+            .iter()
+            .position(|s| s.id == Some(session_id))
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Reading session with id {} not found", session_id))
+            })?;
+        self.sessions.remove(index);
+        Ok(())
+    }
+
+    /// この通読の評価を設定（`None` で削除）
+    pub fn rate(&mut self, rating: Option<Rating>) -> Result<(), DomainError> {
+        // ビジネスルール: 読み始める前の通読には評価を付けられない
+        if self.status == ReadingStatus::WantToRead {
+            return Err(DomainError::InvalidState(
+                "Cannot rate a read-through that has not been started".to_string(),
+            ));
+        }
+        self.rating = rating;
+        Ok(())
+    }
+
+    /// 最新の進捗
+    pub fn progress(&self) -> Option<&ReadingProgress> {
+        self.sessions.last().map(ReadingSession::progress)
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    pub fn status(&self) -> ReadingStatus {
+        self.status
+    }
+
+    pub fn started_on(&self) -> Option<NaiveDate> {
+        self.started_on
+    }
+
+    pub fn finished_on(&self) -> Option<NaiveDate> {
+        self.finished_on
+    }
+
+    pub fn rating(&self) -> Option<Rating> {
+        self.rating
+    }
+
+    pub fn sessions(&self) -> &[ReadingSession] {
+        &self.sessions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    fn session(day: u32, page: i32) -> ReadingSession {
+        ReadingSession::new(date(day), ReadingProgress::Page(page), Some(30)).unwrap()
+    }
+
+    #[test]
+    fn test_lifecycle() {
+        let mut read_through = ReadThrough::new(1, ReadingStatus::WantToRead, date(1));
+        assert_eq!(read_through.started_on(), None);
+
+        // 記録すると読書中になる
+        read_through.log_session(session(3, 40)).unwrap();
+        assert_eq!(read_through.status(), ReadingStatus::Reading);
+        assert_eq!(read_through.started_on(), Some(date(3)));
+
+        read_through
+            .change_status(ReadingStatus::Finished, date(5))
+            .unwrap();
+        assert_eq!(read_through.finished_on(), Some(date(5)));
+
+        // 終わった通読は変更できない
+        assert!(read_through.log_session(session(6, 80)).is_err());
+        assert!(
+            read_through
+                .change_status(ReadingStatus::Reading, date(6))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_finish_before_start_fails() {
+        let mut read_through = ReadThrough::new(1, ReadingStatus::Reading, date(10));
+        let result = read_through.change_status(ReadingStatus::Finished, date(9));
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_progress_must_increase() {
+        let mut read_through = ReadThrough::new(1, ReadingStatus::Reading, date(1));
+        read_through.log_session(session(2, 50)).unwrap();
+        read_through.log_session(session(4, 120)).unwrap();
+
+        // 前の記録以下
+        assert!(read_through.log_session(session(5, 120)).is_err());
+        // 間の日付には間の進捗のみ
+        assert!(read_through.log_session(session(3, 150)).is_err());
+        read_through.log_session(session(3, 80)).unwrap();
+        assert_eq!(read_through.sessions()[1].progress().as_page(), Some(80));
+
+        // 単位の混在は不可
+        let percent = ReadingSession::new(date(6), ReadingProgress::Percent(90.0), None).unwrap();
+        assert!(read_through.log_session(percent).is_err());
+
+        // 開始日より前は不可
+        assert!(
+            ReadThrough::new(1, ReadingStatus::Reading, date(5))
+                .log_session(session(4, 10))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_rate_requires_started_read_through() {
+        let rating = Rating::new(4.5).unwrap();
+        let mut read_through = ReadThrough::new(1, ReadingStatus::WantToRead, date(1));
+        assert!(read_through.rate(Some(rating)).is_err());
+
+        read_through
+            .change_status(ReadingStatus::Reading, date(2))
+            .unwrap();
+        read_through.rate(Some(rating)).unwrap();
+        assert_eq!(read_through.rating(), Some(rating));
+    }
+
+    #[test]
+    fn test_invalid_duration_fails() {
+        assert!(ReadingSession::new(date(1), ReadingProgress::Page(1), Some(0)).is_err());
+        assert!(ReadingSession::new(date(1), ReadingProgress::Page(1), Some(24 * 60 + 1)).is_err());
+    }
+}
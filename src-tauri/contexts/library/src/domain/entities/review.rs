@@ -0,0 +1,160 @@
+// Library Domain Layer - Review エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::rating::Rating;
+use chrono::{DateTime, Utc};
+
+/// 感想の最大文字数
+const MAX_BODY_LENGTH: usize = 20_000;
+
+/// Review エンティティ（本ごとに1件の評価と感想）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Review {
+    id: Option<i32>,
+    book_id: i32,
+    rating: Option<Rating>,
+    body: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Review {
+    /// 新しいレビューを作成（バリデーション付き）
+    pub fn new(
+        book_id: i32,
+        rating: Option<Rating>,
+        body: Option<String>,
+    ) -> Result<Self, DomainError> {
+        let body = Self::validate_body(body)?;
+        Self::ensure_not_empty(rating.as_ref(), body.as_deref())?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: None,
+            book_id,
+            rating,
+            body,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 既存のレビューを再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        book_id: i32,
+        rating: Option<Rating>,
+        body: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            book_id,
+            rating,
+            body,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// 評価・感想を更新（`rating` は `Some(None)` で評価を削除、`body` は空文字列で感想を削除）
+    pub fn update(
+        &mut self,
+        rating: Option<Option<Rating>>,
+        body: Option<String>,
+    ) -> Result<(), DomainError> {
+        let rating = rating.unwrap_or(self.rating);
+        let body = match body {
+            Some(body) => Self::validate_body(Some(body))?,
+            None => self.body.clone(),
+        };
+        Self::ensure_not_empty(rating.as_ref(), body.as_deref())?;
+
+        self.rating = rating;
+        self.body = body;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 感想の検証（前後の空白を除き、空文字列は未設定扱い）
+    fn validate_body(body: Option<String>) -> Result<Option<String>, DomainError> {
+        let body = body.map(|b| b.trim().to_string()).filter(|b| !b.is_empty());
+
+        // ビジネスルール: 感想は20000文字以内
+        if body
+            .as_ref()
+            .is_some_and(|b| b.chars().count() > MAX_BODY_LENGTH)
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Review must be {} characters or less",
+                MAX_BODY_LENGTH
+            )));
+        }
+
+        Ok(body)
+    }
+
+    /// ビジネスルール: 評価と感想の少なくとも一方は必要
+    fn ensure_not_empty(rating: Option<&Rating>, body: Option<&str>) -> Result<(), DomainError> {
+        if rating.is_none() && body.is_none() {
+            return Err(DomainError::ValidationError(
+                "Review needs a rating or a body".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    pub fn rating(&self) -> Option<Rating> {
+        self.rating
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_needs_rating_or_body() {
+        assert!(Review::new(1, None, Some("  ".to_string())).is_err());
+        assert!(Review::new(1, Some(Rating::new(4.5).unwrap()), None).is_ok());
+    }
+
+    #[test]
+    fn test_update_cannot_clear_everything() {
+        let mut review = Review::new(
+            1,
+            Some(Rating::new(4.0).unwrap()),
+            Some("Great".to_string()),
+        )
+        .unwrap();
+
+        review.update(Some(None), None).unwrap();
+        assert!(review.rating().is_none());
+        assert_eq!(review.body(), Some("Great"));
+
+        let result = review.update(None, Some(String::new()));
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+}
@@ -0,0 +1,279 @@
+// Library Domain Layer - Series エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{
+    series_status::SeriesStatus, sort_key::SortKey, volume_number::VolumeNumber,
+};
+use std::collections::BTreeSet;
+
+/// シリーズ名・読みの最大文字数
+const MAX_NAME_LENGTH: usize = 200;
+/// 刊行済みの巻数の最大値
+const MAX_VOLUME_COUNT: i32 = 9999;
+
+/// Series エンティティ（漫画・ライトノベルなど、巻の順に読む本のまとまり）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    id: Option<i32>,
+    name: String,
+    /// 読み（かな）
+    reading: Option<String>,
+    sort_key: SortKey,
+    status: SeriesStatus,
+    /// 刊行済みの巻数（分かる場合のみ。欠けている巻の判定に使う）
+    volume_count: Option<i32>,
+}
+
+impl Series {
+    /// 新しいシリーズを作成（バリデーション付き）
+    pub fn new(
+        name: String,
+        reading: Option<String>,
+        status: SeriesStatus,
+        volume_count: Option<i32>,
+    ) -> Result<Self, DomainError> {
+        let name = Self::validate_name(name)?;
+        let reading = Self::validate_reading(reading)?;
+        Self::validate_volume_count(volume_count)?;
+
+        Ok(Self {
+            id: None,
+            sort_key: Self::sort_key_for(&name, reading.as_deref()),
+            name,
+            reading,
+            status,
+            volume_count,
+        })
+    }
+
+    /// 既存のシリーズを再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        name: String,
+        reading: Option<String>,
+        sort_key: SortKey,
+        status: SeriesStatus,
+        volume_count: Option<i32>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            name,
+            reading,
+            sort_key,
+            status,
+            volume_count,
+        }
+    }
+
+    /// シリーズの情報を更新（`reading` は空文字列で削除、`volume_count` は `Some(None)` で削除）
+    pub fn update(
+        &mut self,
+        name: Option<String>,
+        reading: Option<String>,
+        status: Option<SeriesStatus>,
+        volume_count: Option<Option<i32>>,
+    ) -> Result<(), DomainError> {
+        let name = name.map(Self::validate_name).transpose()?;
+        let reading = reading
+            .map(|reading| Self::validate_reading(Some(reading)))
+            .transpose()?;
+        if let Some(volume_count) = volume_count {
+            Self::validate_volume_count(volume_count)?;
+            self.volume_count = volume_count;
+        }
+
+        if let Some(name) = name {
+            self.name = name;
+        }
+        if let Some(reading) = reading {
+            self.reading = reading;
+        }
+        if let Some(status) = status {
+            self.status = status;
+        }
+        self.sort_key = Self::sort_key_for(&self.name, self.reading.as_deref());
+
+        Ok(())
+    }
+
+    /// 所有している巻から欠けている巻（整数の巻のみ）を求める
+    ///
+    /// 1巻から、刊行済みの巻数と所有している最後の巻の大きい方までを対象とする。
+    pub fn missing_volumes(&self, owned: &[VolumeNumber]) -> Vec<i32> {
+        let owned_whole: BTreeSet<i32> = owned.iter().filter_map(|v| v.whole()).collect();
+        let last_owned = owned.iter().map(|v| v.value().floor()).fold(0.0, f64::max);
+        // 範囲は検証済みのため i32 に収まる
+        #[allow(clippy::cast_possible_truncation)]
+        let last = self.volume_count.unwrap_or(0).max(last_owned as i32);
+
+        (1..=last).filter(|v| !owned_whole.contains(v)).collect()
+    }
+
+    /// ビジネスルール: 名前は必須で200文字以内
+    fn validate_name(name: String) -> Result<String, DomainError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Series name cannot be empty".to_string(),
+            ));
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Series name must be {} characters or less",
+                MAX_NAME_LENGTH
+            )));
+        }
+        Ok(name.to_string())
+    }
+
+    /// 読みの検証（前後の空白を除き、空文字列は未設定扱い）
+    fn validate_reading(reading: Option<String>) -> Result<Option<String>, DomainError> {
+        let reading = reading
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty());
+        if reading
+            .as_ref()
+            .is_some_and(|r| r.chars().count() > MAX_NAME_LENGTH)
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Series reading must be {} characters or less",
+                MAX_NAME_LENGTH
+            )));
+        }
+        Ok(reading)
+    }
+
+    /// ビジネスルール: 刊行済みの巻数は1〜9999
+    fn validate_volume_count(volume_count: Option<i32>) -> Result<(), DomainError> {
+        if volume_count.is_some_and(|count| !(1..=MAX_VOLUME_COUNT).contains(&count)) {
+            return Err(DomainError::ValidationError(format!(
+                "Volume count must be between 1 and {}",
+                MAX_VOLUME_COUNT
+            )));
+        }
+        Ok(())
+    }
+
+    /// 並び替え用のキー（読み、なければ名前から作る）
+    fn sort_key_for(name: &str, reading: Option<&str>) -> SortKey {
+        SortKey::new(reading.unwrap_or(name))
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn reading(&self) -> Option<&str> {
+        self.reading.as_deref()
+    }
+
+    pub fn sort_key(&self) -> &SortKey {
+        &self.sort_key
+    }
+
+    pub fn status(&self) -> SeriesStatus {
+        self.status
+    }
+
+    pub fn volume_count(&self) -> Option<i32> {
+        self.volume_count
+    }
+}
+
+/// 本のシリーズでの巻（1冊の本は1つのシリーズにのみ属する）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesVolume {
+    book_id: i32,
+    series_id: i32,
+    volume: VolumeNumber,
+}
+
+impl SeriesVolume {
+    pub fn new(book_id: i32, series_id: i32, volume: VolumeNumber) -> Self {
+        Self {
+            book_id,
+            series_id,
+            volume,
+        }
+    }
+
+    pub fn book_id(&self) -> i32 {
+        self.book_id
+    }
+
+    pub fn series_id(&self) -> i32 {
+        self.series_id
+    }
+
+    pub fn volume(&self) -> VolumeNumber {
+        self.volume
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn volumes(values: &[f64]) -> Vec<VolumeNumber> {
+        values
+            .iter()
+            .map(|v| VolumeNumber::new(*v).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_key_follows_reading() {
+        let mut series = Series::new(
+            "進撃の巨人".to_string(),
+            Some("シンゲキノキョジン".to_string()),
+            SeriesStatus::Completed,
+            Some(34),
+        )
+        .unwrap();
+        assert_eq!(series.sort_key().as_str(), "しんげきのきょじん");
+
+        series
+            .update(None, Some(String::new()), None, None)
+            .unwrap();
+        assert_eq!(series.reading(), None);
+        assert_eq!(series.sort_key().as_str(), "進撃の巨人");
+    }
+
+    #[test]
+    fn test_invalid_series_fails() {
+        assert!(Series::new("  ".to_string(), None, SeriesStatus::Ongoing, None).is_err());
+        assert!(
+            Series::new(
+                "One Piece".to_string(),
+                None,
+                SeriesStatus::Ongoing,
+                Some(0)
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_missing_volumes() {
+        let ongoing =
+            Series::new("Frieren".to_string(), None, SeriesStatus::Ongoing, None).unwrap();
+        assert_eq!(
+            ongoing.missing_volumes(&volumes(&[1.0, 1.5, 4.0, 2.0])),
+            vec![3]
+        );
+        assert!(ongoing.missing_volumes(&[]).is_empty());
+
+        // 刊行済みの巻数が分かる場合は最後の巻まで
+        let completed =
+            Series::new("Dune".to_string(), None, SeriesStatus::Completed, Some(6)).unwrap();
+        assert_eq!(
+            completed.missing_volumes(&volumes(&[0.0, 2.0, 3.5])),
+            vec![1, 3, 4, 5, 6]
+        );
+    }
+}
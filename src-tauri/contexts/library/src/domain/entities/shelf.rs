@@ -0,0 +1,155 @@
+// Library Domain Layer - Shelf エンティティ
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::book::BookFilter;
+
+/// 本棚の名前の最大文字数
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Shelf エンティティ（ユーザーが作成する本棚）
+///
+/// 手動の本棚は本を任意の順に並べる。スマート本棚は保存した絞り込み条件に一致する本を並べ、
+/// 本を個別に追加することはできない。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shelf {
+    id: Option<i32>,
+    name: String,
+    /// 本棚一覧での表示順（0始まり。新しい本棚は末尾に追加される）
+    position: i32,
+    /// スマート本棚の絞り込み条件
+    smart_filter: Option<BookFilter>,
+}
+
+impl Shelf {
+    /// 新しい本棚を作成（バリデーション付き。`smart_filter` を指定するとスマート本棚になる）
+    pub fn new(name: String, smart_filter: Option<BookFilter>) -> Result<Self, DomainError> {
+        let name = Self::validate_name(name)?;
+        if let Some(filter) = &smart_filter {
+            Self::validate_smart_filter(filter)?;
+        }
+
+        Ok(Self {
+            id: None,
+            name,
+            position: 0,
+            smart_filter,
+        })
+    }
+
+    /// 既存の本棚を再構築（DB から取得した場合など）
+    pub fn reconstruct(
+        id: i32,
+        name: String,
+        position: i32,
+        smart_filter: Option<BookFilter>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            name,
+            position,
+            smart_filter,
+        }
+    }
+
+    /// 名前を変更
+    pub fn rename(&mut self, name: String) -> Result<(), DomainError> {
+        self.name = Self::validate_name(name)?;
+        Ok(())
+    }
+
+    /// スマート本棚の絞り込み条件を変更（手動の本棚は変更できない）
+    pub fn change_smart_filter(&mut self, filter: BookFilter) -> Result<(), DomainError> {
+        if self.smart_filter.is_none() {
+            return Err(DomainError::InvalidState(format!(
+                "Shelf '{}' is not a smart shelf",
+                self.name
+            )));
+        }
+        Self::validate_smart_filter(&filter)?;
+        self.smart_filter = Some(filter);
+        Ok(())
+    }
+
+    /// ビジネスルール: 名前は必須で100文字以内
+    fn validate_name(name: String) -> Result<String, DomainError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Shelf name cannot be empty".to_string(),
+            ));
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Shelf name must be {} characters or less",
+                MAX_NAME_LENGTH
+            )));
+        }
+        Ok(name.to_string())
+    }
+
+    /// ビジネスルール: スマート本棚の条件は本棚を参照できず、出版年の範囲は正しい順
+    fn validate_smart_filter(filter: &BookFilter) -> Result<(), DomainError> {
+        if filter.shelf.is_some() {
+            return Err(DomainError::ValidationError(
+                "Smart shelf filter cannot refer to a shelf".to_string(),
+            ));
+        }
+        if let (Some(from), Some(to)) = (filter.published_year_from, filter.published_year_to)
+            && from > to
+        {
+            return Err(DomainError::ValidationError(
+                "Published year range start must not be after its end".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    pub fn smart_filter(&self) -> Option<&BookFilter> {
+        self.smart_filter.as_ref()
+    }
+
+    pub fn is_smart(&self) -> bool {
+        self.smart_filter.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::book::ShelfFilter;
+
+    #[test]
+    fn test_smart_filter_rules() {
+        let mut manual = Shelf::new(" 積読 ".to_string(), None).unwrap();
+        assert_eq!(manual.name(), "積読");
+        assert!(!manual.is_smart());
+        assert!(manual.change_smart_filter(BookFilter::default()).is_err());
+
+        let nested = BookFilter {
+            shelf: Some(ShelfFilter::Books(1)),
+            ..Default::default()
+        };
+        assert!(Shelf::new("Nested".to_string(), Some(nested)).is_err());
+
+        let reversed = BookFilter {
+            published_year_from: Some(2000),
+            published_year_to: Some(1990),
+            ..Default::default()
+        };
+        assert!(Shelf::new("Reversed".to_string(), Some(reversed)).is_err());
+        assert!(Shelf::new("  ".to_string(), None).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+// Library Domain Layer - Tag エンティティ
+
+use crate::domain::value_objects::tag_name::TagName;
+
+/// Tag エンティティ（本に付ける階層付きのラベル。1冊に複数付けられる）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tag {
+    id: Option<i32>,
+    name: TagName,
+}
+
+impl Tag {
+    /// 新しいタグを作成
+    pub fn new(name: TagName) -> Self {
+        Self { id: None, name }
+    }
+
+    /// 既存のタグを再構築（DB から取得した場合など）
+    pub fn reconstruct(id: i32, name: TagName) -> Self {
+        Self { id: Some(id), name }
+    }
+
+    /// 名前を変更
+    pub fn rename(&mut self, name: TagName) {
+        self.name = name;
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i32> {
+        self.id
+    }
+
+    pub fn name(&self) -> &TagName {
+        &self.name
+    }
+}
@@ -14,6 +14,10 @@ pub enum DomainError {
     #[error("Invalid state: {0}")]
     InvalidState(String),
 
+    /// 読み込んだ後に他で変更された（楽観的排他制御）
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("I/O error: {0}")]
     IoError(String),
 }
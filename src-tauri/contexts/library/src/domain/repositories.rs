@@ -1,3 +1,20 @@
 // Library Domain - Repositories
 
+pub mod author;
 pub mod book;
+pub mod book_change;
+pub mod book_file;
+pub mod borrower;
+pub mod calibre;
+pub mod copy;
+pub mod cover;
+pub mod ebook;
+pub mod highlight;
+pub mod loan;
+pub mod location;
+pub mod note;
+pub mod read_through;
+pub mod review;
+pub mod series;
+pub mod shelf;
+pub mod tag;
@@ -0,0 +1,19 @@
+// Library Domain Layer - Author リポジトリインターフェース
+
+use crate::domain::entities::author::Author;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Author リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait AuthorRepository: Send + Sync {
+    /// IDで著者を検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Author>, DomainError>;
+
+    /// 名前（完全一致）で著者を検索
+    async fn find_by_name(&self, name: &str) -> Result<Option<Author>, DomainError>;
+
+    /// 著者を保存（新規作成または更新）
+    async fn save(&self, author: Author) -> Result<Author, DomainError>;
+}
@@ -1,22 +1,237 @@
 // Library Domain Layer - Book リポジトリインターフェース
 
-use crate::domain::entities::book::Book;
+use crate::domain::entities::{book::Book, book_change::ChangeRecord};
 use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{
+    book_change_source::BookChangeSource,
+    class_number::{ClassPrefix, ClassificationScheme},
+    cover_hash::CoverHash,
+    external_source::ExternalSource,
+    isbn::Isbn,
+    search_query::SearchQuery,
+    tag_name::TagName,
+};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// 本の絞り込み条件
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BookFilter {
+    /// 著者名（部分一致）
+    pub author: Option<String>,
+    /// タイトル（部分一致）
+    pub title_contains: Option<String>,
+    /// 出版年の下限（この年を含む）
+    pub published_year_from: Option<i32>,
+    /// 出版年の上限（この年を含む）
+    pub published_year_to: Option<i32>,
+    /// 作成日時の下限（この日時を含む）
+    pub created_at_from: Option<DateTime<Utc>>,
+    /// 作成日時の上限（この日時を含む）
+    pub created_at_to: Option<DateTime<Utc>>,
+    /// 更新日時の下限（この日時を含む）
+    pub updated_at_from: Option<DateTime<Utc>>,
+    /// 更新日時の上限（この日時を含む）
+    pub updated_at_to: Option<DateTime<Utc>>,
+    /// 日本十進分類法（NDC）の分類（下位の分類を含む）
+    pub ndc: Option<ClassPrefix>,
+    /// デューイ十進分類法（DDC）の分類（下位の分類を含む）
+    pub ddc: Option<ClassPrefix>,
+    /// タグ（子孫のタグが付いた本も含む）
+    pub tag: Option<TagName>,
+    /// 本棚
+    pub shelf: Option<ShelfFilter>,
+}
+
+/// 本棚による絞り込み
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShelfFilter {
+    /// 手動の本棚に並べた本（本棚のID）
+    Books(i32),
+    /// スマート本棚の条件に一致する本
+    Matching(Box<BookFilter>),
+}
+
+/// 本の並び替えキー
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BookSortKey {
+    Title,
+    Author,
+    PublishedYear,
+    CreatedAt,
+    UpdatedAt,
+    #[default]
+    Id,
+}
+
+/// 並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// 本の一覧取得条件（絞り込み・並び替え・ページング）
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookQuerySpec {
+    pub filter: BookFilter,
+    pub sort_key: BookSortKey,
+    pub sort_order: SortOrder,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+/// 一覧取得の結果（1ページ分の本と条件に一致する総件数）
+#[derive(Debug, Clone)]
+pub struct BookPage {
+    pub books: Vec<Book>,
+    pub total_count: u64,
+}
+
+/// 分類ごとの本の数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassCount {
+    /// 分類記号（集計した桁数までの先頭部分）
+    pub code: String,
+    pub book_count: u64,
+}
+
+/// 全文検索の結果（一致箇所を強調した抜粋と関連度付き）
+#[derive(Debug, Clone)]
+pub struct BookSearchHit {
+    pub book: Book,
+    /// 一致箇所を強調したタイトル
+    pub title_highlight: String,
+    /// 一致箇所を強調した著者名
+    pub author_highlight: Option<String>,
+    /// 一致箇所周辺の説明文の抜粋
+    pub description_snippet: Option<String>,
+    /// 関連度（大きいほど関連が高い。3文字未満の語だけで検索した場合は0）
+    pub score: f64,
+}
 
 /// Book リポジトリのインターフェース
 /// インフラ層がこのtraitを実装する
+///
+/// ゴミ箱にある本は `find_trashed` などゴミ箱用のメソッド以外では取得しない
 #[async_trait]
 pub trait BookRepository: Send + Sync {
     /// IDで本を検索
     async fn find_by_id(&self, id: i32) -> Result<Option<Book>, DomainError>;
 
-    /// すべての本を取得
+    /// ISBNで本を検索
+    async fn find_by_isbn(&self, isbn: &Isbn) -> Result<Option<Book>, DomainError>;
+
+    /// タイトルが一致する本を取得（英字の大文字・小文字は区別しない）
+    async fn find_by_title(&self, title: &str) -> Result<Vec<Book>, DomainError>;
+
+    /// 取り込み元のサービスでのIDで本を検索
+    async fn find_by_external_id(
+        &self,
+        source: ExternalSource,
+        external_id: &str,
+    ) -> Result<Option<Book>, DomainError>;
+
+    /// 著者として関わっている本を取得（役割は問わない）
+    async fn find_by_author(&self, author_id: i32) -> Result<Vec<Book>, DomainError>;
+
+    /// すべての本をタイトルの読み順に取得
     async fn find_all(&self) -> Result<Vec<Book>, DomainError>;
 
+    /// 条件に一致する本をすべてタイトルの読み順に取得
+    async fn find_matching(&self, filter: &BookFilter) -> Result<Vec<Book>, DomainError>;
+
+    /// 条件に一致する本を1ページ分取得
+    async fn find_page(&self, spec: &BookQuerySpec) -> Result<BookPage, DomainError>;
+
+    /// タイトル・著者・説明を全文検索し、関連度の高い順に取得
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: u64,
+    ) -> Result<Vec<BookSearchHit>, DomainError>;
+
+    /// 分類記号の先頭 `digits` 桁ごとに本の数を数え、分類記号の順に取得
+    ///
+    /// `digits` が None の場合は分類記号ごとに数える。`within` を指定した場合はその分類に含まれる本のみ数える。
+    async fn count_by_class(
+        &self,
+        scheme: ClassificationScheme,
+        within: Option<&ClassPrefix>,
+        digits: Option<usize>,
+    ) -> Result<Vec<ClassCount>, DomainError>;
+
+    /// ゴミ箱にある本をゴミ箱に移した日時の新しい順に取得
+    async fn find_trashed(&self) -> Result<Vec<Book>, DomainError>;
+
+    /// ゴミ箱にある本をIDで検索
+    async fn find_trashed_by_id(&self, id: i32) -> Result<Option<Book>, DomainError>;
+
+    /// ゴミ箱にある本をISBNで検索
+    async fn find_trashed_by_isbn(&self, isbn: &Isbn) -> Result<Option<Book>, DomainError>;
+
+    /// 表紙画像を使っている本の数を取得（ゴミ箱にある本を含む）
+    async fn count_by_cover(&self, cover: &CoverHash) -> Result<u64, DomainError>;
+
     /// 本を保存（新規作成または更新）
+    ///
+    /// 変更履歴には記録しない。表紙など書誌情報以外の変更に使う。
     async fn save(&self, book: Book) -> Result<Book, DomainError>;
 
-    /// 本を削除
+    /// 本を保存し、同じトランザクションで変更履歴に記録する（書誌情報が変わらない編集・復元は記録しない）
+    async fn save_with_history(
+        &self,
+        book: Book,
+        change: ChangeRecord,
+    ) -> Result<Book, DomainError>;
+
+    /// 複数の新しい本を1つのトランザクションで保存し、それぞれ `source` による作成として変更履歴に記録する
+    /// （いずれかが失敗した場合はすべて取り消す）
+    ///
+    /// 著者の関連が未設定の本は、表示用の著者名から著者を取得または作成して関連付ける
+    async fn save_all(
+        &self,
+        books: Vec<Book>,
+        source: BookChangeSource,
+    ) -> Result<Vec<Book>, DomainError>;
+
+    /// 取り込み元のサービスでのIDを本に記録する（記録済みの場合は対応する本を置き換える）
+    async fn link_external_id(
+        &self,
+        book_id: i32,
+        source: ExternalSource,
+        external_id: &str,
+    ) -> Result<(), DomainError>;
+
+    /// 割り当て済みの引用キーをすべて取得（本のID → キー。ゴミ箱にある本を含む）
+    async fn find_citation_keys(&self) -> Result<HashMap<i32, String>, DomainError>;
+
+    /// 本に引用キーを記録する（版番号・更新日時は変えない。いずれかが失敗した場合はすべて取り消す）
+    async fn save_citation_keys(&self, keys: &[(i32, String)]) -> Result<(), DomainError>;
+
+    /// 複数の本を1冊にまとめる（いずれかが失敗した場合はすべて取り消す）
+    ///
+    /// まとめる本の読書記録・メモ・ハイライト・取り込み元のID・タグ・本棚・ファイルをまとめ先の本に付け替え、
+    /// レビューとシリーズはまとめ先の本にない場合のみ引き継ぐ。本棚ではまとめ先の本を末尾に加える。
+    /// `merged` はゴミ箱に移したまとめる本と変更履歴の組で、まとめる本・まとめ先の本の順に保存して
+    /// それぞれの変更履歴に記録する。
+    async fn merge(
+        &self,
+        primary: Book,
+        merged: Vec<(Book, ChangeRecord)>,
+        change: ChangeRecord,
+    ) -> Result<Book, DomainError>;
+
+    /// 本を完全に削除
     async fn delete(&self, id: i32) -> Result<(), DomainError>;
+
+    /// ゴミ箱にある本を完全に削除し、削除した件数を返す（`before` 指定時はその日時以前にゴミ箱に移した本のみ）
+    async fn delete_trashed(&self, before: Option<DateTime<Utc>>) -> Result<u64, DomainError>;
+
+    /// 保存済みの並び替え用のキーを現在の正規化の規則で作り直し、更新した件数を返す（ゴミ箱にある本を含む）
+    ///
+    /// 版番号・更新日時は変えない。
+    async fn refresh_sort_keys(&self) -> Result<u64, DomainError>;
 }
@@ -0,0 +1,23 @@
+// Library Domain Layer - BookChange リポジトリインターフェース
+
+use crate::domain::entities::book_change::BookChange;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// BookChange リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+///
+/// 変更履歴は追記のみで、本を完全に削除すると一緒に削除される。
+/// 記録は本の保存と同じトランザクションで `BookRepository::save_with_history` などが行う。
+#[async_trait]
+pub trait BookChangeRepository: Send + Sync {
+    /// 本の変更履歴を履歴番号の古い順にすべて取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<BookChange>, DomainError>;
+
+    /// 本の指定した履歴番号の変更を取得
+    async fn find_revision(
+        &self,
+        book_id: i32,
+        revision: i32,
+    ) -> Result<Option<BookChange>, DomainError>;
+}
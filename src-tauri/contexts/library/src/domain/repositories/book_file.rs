@@ -0,0 +1,26 @@
+// Library Domain Layer - BookFile リポジトリインターフェース
+
+use crate::domain::entities::book_file::BookFile;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+use std::path::Path;
+
+/// BookFile リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait BookFileRepository: Send + Sync {
+    /// IDで紐付けを検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<BookFile>, DomainError>;
+
+    /// 本に紐付けたファイルを紐付けた順にすべて取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<BookFile>, DomainError>;
+
+    /// パスで紐付けを検索
+    async fn find_by_path(&self, path: &Path) -> Result<Option<BookFile>, DomainError>;
+
+    /// 紐付けを保存（新規作成または更新）
+    async fn save(&self, file: BookFile) -> Result<BookFile, DomainError>;
+
+    /// 紐付けを削除（ファイルは削除しない）
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+}
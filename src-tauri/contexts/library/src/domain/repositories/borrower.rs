@@ -0,0 +1,22 @@
+// Library Domain Layer - Borrower リポジトリインターフェース
+
+use crate::domain::entities::borrower::Borrower;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Borrower リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait BorrowerRepository: Send + Sync {
+    /// IDで連絡先を検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Borrower>, DomainError>;
+
+    /// すべての連絡先を名前順に取得
+    async fn find_all(&self) -> Result<Vec<Borrower>, DomainError>;
+
+    /// 連絡先を保存（新規作成または更新）
+    async fn save(&self, borrower: Borrower) -> Result<Borrower, DomainError>;
+
+    /// 連絡先を削除（貸出の履歴も削除される）
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+}
@@ -0,0 +1,35 @@
+// Library Domain Layer - Calibre ライブラリ読み込みインターフェース
+
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+use std::path::Path;
+
+/// Calibre のライブラリに登録された1冊分のメタデータ
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibreBook {
+    /// Calibre での本のID
+    pub calibre_id: i32,
+    pub title: String,
+    /// 著者名（Calibre での表示順）
+    pub authors: Vec<String>,
+    /// シリーズ名と巻数（巻数は小数の場合がある）
+    pub series: Option<(String, f64)>,
+    pub tags: Vec<String>,
+    /// 識別子（isbn）、なければ本の ISBN 欄の値（未検証）
+    pub isbn: Option<String>,
+    pub publisher: Option<String>,
+    /// 紹介文（HTML）
+    pub comments: Option<String>,
+    /// 出版日の年（Calibre で未設定の場合はなし）
+    pub published_year: Option<i32>,
+}
+
+/// Calibre のライブラリ（metadata.db）の読み込みインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait CalibreLibraryRepository: Send + Sync {
+    /// ライブラリのすべての本を Calibre のID順に読み込む（ライブラリには書き込まない）
+    ///
+    /// `path` はライブラリのディレクトリ、または metadata.db のパス
+    async fn read_books(&self, path: &Path) -> Result<Vec<CalibreBook>, DomainError>;
+}
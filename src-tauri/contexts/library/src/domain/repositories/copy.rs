@@ -0,0 +1,32 @@
+// Library Domain Layer - Copy リポジトリインターフェース
+
+use crate::domain::entities::copy::Copy;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// Copy リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait CopyRepository: Send + Sync {
+    /// IDで1冊を検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Copy>, DomainError>;
+
+    /// 本の所有しているすべての冊を登録順に取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Copy>, DomainError>;
+
+    /// 指定した場所に置いてある冊を本のタイトルの読み順に取得（ゴミ箱にある本は含めない）
+    async fn find_in_locations(&self, location_ids: &[i32]) -> Result<Vec<Copy>, DomainError>;
+
+    /// 場所が未設定の冊を本のタイトルの読み順に取得（ゴミ箱にある本は含めない）
+    async fn find_unplaced(&self) -> Result<Vec<Copy>, DomainError>;
+
+    /// 場所ごとに置いてある冊数を数える（ゴミ箱にある本は含めない）
+    async fn count_by_location(&self) -> Result<HashMap<i32, u64>, DomainError>;
+
+    /// 1冊を保存（新規作成または更新）
+    async fn save(&self, copy: Copy) -> Result<Copy, DomainError>;
+
+    /// 1冊を削除
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+}
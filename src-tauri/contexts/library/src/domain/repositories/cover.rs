@@ -0,0 +1,35 @@
+// Library Domain Layer - 表紙画像の保存インターフェース
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::cover_hash::CoverHash;
+use async_trait::async_trait;
+use std::path::Path;
+
+/// 表紙画像の大きさ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverSize {
+    /// 元の画像（大きすぎる場合は縮小済み）
+    Full,
+    /// 一覧表示用の固定サイズのサムネイル
+    Thumbnail,
+}
+
+/// 表紙画像の保存先のインターフェース
+/// インフラ層がこのtraitを実装する
+///
+/// 画像は内容のハッシュで管理し、同じ画像は一度だけ保存する。
+#[async_trait]
+pub trait CoverImageRepository: Send + Sync {
+    /// 画像を正規化（向きの補正・メタデータの除去）して保存し、サムネイルを生成する
+    async fn save(&self, bytes: Vec<u8>) -> Result<CoverHash, DomainError>;
+
+    /// ローカルのファイルから画像を読み込んで保存する
+    async fn save_file(&self, path: &Path) -> Result<CoverHash, DomainError>;
+
+    /// 保存済みの画像を読み込む（存在しない場合は None）
+    async fn read(&self, hash: &CoverHash, size: CoverSize)
+    -> Result<Option<Vec<u8>>, DomainError>;
+
+    /// 画像とサムネイルを削除する（存在しない場合は何もしない）
+    async fn delete(&self, hash: &CoverHash) -> Result<(), DomainError>;
+}
@@ -0,0 +1,52 @@
+// Library Domain Layer - 電子書籍ファイルの読み込み・保存インターフェース
+
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{author_role::AuthorRole, ebook_format::EbookFormat};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+/// 電子書籍ファイルに埋め込まれた書誌情報
+///
+/// EPUB は OPF（パッケージ文書）、PDF は文書情報辞書と XMP から読み込む（値は未検証）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EbookMetadata {
+    pub title: Option<String>,
+    /// 著者・翻訳者などの名前と役割（ファイルでの記載順）
+    pub creators: Vec<(String, AuthorRole)>,
+    /// 言語（BCP 47 の言語タグ）
+    pub language: Option<String>,
+    /// 識別子（ISBN・URN・UUID など、ファイルに記載されたまま）
+    pub identifiers: Vec<String>,
+    pub publisher: Option<String>,
+    /// 紹介文（HTML を含む場合がある）
+    pub description: Option<String>,
+    /// 出版日の年
+    pub published_year: Option<i32>,
+    /// 表紙画像（EPUB は表紙として指定された画像、PDF は1ページ目の JPEG 画像）
+    pub cover: Option<Vec<u8>>,
+}
+
+/// 電子書籍ファイルの書誌情報の読み込みインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait EbookMetadataReader: Send + Sync {
+    /// ファイルの書誌情報を読み込む（ファイルには書き込まない）
+    async fn read_metadata(
+        &self,
+        path: &Path,
+        format: EbookFormat,
+    ) -> Result<EbookMetadata, DomainError>;
+}
+
+/// 電子書籍ファイルのコピーの保存先のインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait EbookFileStore: Send + Sync {
+    /// ファイルをアプリが管理するディレクトリにコピーし、コピー先のパスを返す
+    ///
+    /// 同じ内容のファイルは同じパスになる
+    async fn store_copy(&self, path: &Path, format: EbookFormat) -> Result<PathBuf, DomainError>;
+
+    /// 管理しているコピーを削除する（存在しない場合は何もしない）
+    async fn delete_copy(&self, path: &Path) -> Result<(), DomainError>;
+}
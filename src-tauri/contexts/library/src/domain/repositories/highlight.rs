@@ -0,0 +1,30 @@
+// Library Domain Layer - Highlight リポジトリインターフェース
+
+use crate::domain::entities::highlight::Highlight;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Highlight リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait HighlightRepository: Send + Sync {
+    /// IDでハイライトを検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Highlight>, DomainError>;
+
+    /// 本のハイライトを本文中の位置順にすべて取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Highlight>, DomainError>;
+
+    /// 同じ抜き書き（本・種類・開始位置・本文が一致）がすでに保存されていれば取得
+    ///
+    /// 作成日時は端末の設定によって変わるため比較しない
+    async fn find_same_clipping(
+        &self,
+        highlight: &Highlight,
+    ) -> Result<Option<Highlight>, DomainError>;
+
+    /// ハイライトを保存（新規作成または更新）
+    async fn save(&self, highlight: Highlight) -> Result<Highlight, DomainError>;
+
+    /// ハイライトを削除
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+}
@@ -0,0 +1,28 @@
+// Library Domain Layer - Loan リポジトリインターフェース
+
+use crate::domain::entities::loan::Loan;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Loan リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait LoanRepository: Send + Sync {
+    /// IDで貸出を検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Loan>, DomainError>;
+
+    /// 1冊の貸出中の貸出を取得
+    async fn find_active_by_copy(&self, copy_id: i32) -> Result<Option<Loan>, DomainError>;
+
+    /// 1冊の貸出の履歴を新しい順に取得
+    async fn find_by_copy(&self, copy_id: i32) -> Result<Vec<Loan>, DomainError>;
+
+    /// 相手ごとの貸出の履歴を新しい順に取得
+    async fn find_by_borrower(&self, borrower_id: i32) -> Result<Vec<Loan>, DomainError>;
+
+    /// 貸出中のすべての貸出を返却期限の近い順に取得（期限なしは最後）
+    async fn find_active(&self) -> Result<Vec<Loan>, DomainError>;
+
+    /// 貸出を保存（新規作成または更新）
+    async fn save(&self, loan: Loan) -> Result<Loan, DomainError>;
+}
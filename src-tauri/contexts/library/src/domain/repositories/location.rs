@@ -0,0 +1,25 @@
+// Library Domain Layer - Location リポジトリインターフェース
+
+use crate::domain::entities::location::Location;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Location リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait LocationRepository: Send + Sync {
+    /// IDで場所を検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Location>, DomainError>;
+
+    /// すべての場所を名前の順に取得
+    async fn find_all(&self) -> Result<Vec<Location>, DomainError>;
+
+    /// 1つ下の階層の場所を名前の順に取得（`parent_id` が None の場合は部屋）
+    async fn find_children(&self, parent_id: Option<i32>) -> Result<Vec<Location>, DomainError>;
+
+    /// 場所を保存（新規作成または更新）
+    async fn save(&self, location: Location) -> Result<Location, DomainError>;
+
+    /// 場所を削除（下位の場所も削除し、置いてあった本の場所は未設定に戻す）
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+}
@@ -0,0 +1,22 @@
+// Library Domain Layer - Note リポジトリインターフェース
+
+use crate::domain::entities::note::Note;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Note リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait NoteRepository: Send + Sync {
+    /// IDでメモを検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Note>, DomainError>;
+
+    /// 本のメモを作成日時の古い順にすべて取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Note>, DomainError>;
+
+    /// メモを保存（新規作成または更新）
+    async fn save(&self, note: Note) -> Result<Note, DomainError>;
+
+    /// メモを削除
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+}
@@ -0,0 +1,26 @@
+// Library Domain Layer - ReadThrough リポジトリインターフェース
+
+use crate::domain::entities::read_through::ReadThrough;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// ReadThrough リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait ReadThroughRepository: Send + Sync {
+    /// IDで通読を取得（読書記録を含む）
+    async fn find_by_id(&self, id: i32) -> Result<Option<ReadThrough>, DomainError>;
+
+    /// 本の通読を古い順にすべて取得（読書記録を含む）
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<ReadThrough>, DomainError>;
+
+    /// 本の最新の通読を取得
+    async fn find_latest_by_book(&self, book_id: i32) -> Result<Option<ReadThrough>, DomainError>;
+
+    /// 読書記録を含む通読を取得
+    async fn find_by_session_id(&self, session_id: i32)
+    -> Result<Option<ReadThrough>, DomainError>;
+
+    /// 通読を読書記録とともに保存（新規作成または更新）
+    async fn save(&self, read_through: ReadThrough) -> Result<ReadThrough, DomainError>;
+}
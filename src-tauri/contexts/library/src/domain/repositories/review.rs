@@ -0,0 +1,22 @@
+// Library Domain Layer - Review リポジトリインターフェース
+
+use crate::domain::entities::review::Review;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Review リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait ReviewRepository: Send + Sync {
+    /// IDでレビューを検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Review>, DomainError>;
+
+    /// 本のレビューを取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Option<Review>, DomainError>;
+
+    /// レビューを保存（新規作成または更新）
+    async fn save(&self, review: Review) -> Result<Review, DomainError>;
+
+    /// レビューを削除
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+}
@@ -0,0 +1,37 @@
+// Library Domain Layer - Series リポジトリインターフェース
+
+use crate::domain::entities::series::{Series, SeriesVolume};
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Series リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait SeriesRepository: Send + Sync {
+    /// IDでシリーズを検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Series>, DomainError>;
+
+    /// 名前（完全一致）でシリーズを検索
+    async fn find_by_name(&self, name: &str) -> Result<Option<Series>, DomainError>;
+
+    /// すべてのシリーズを読みの順に取得
+    async fn find_all(&self) -> Result<Vec<Series>, DomainError>;
+
+    /// シリーズを保存（新規作成または更新）
+    async fn save(&self, series: Series) -> Result<Series, DomainError>;
+
+    /// シリーズを削除（本のシリーズでの巻も削除する）
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+
+    /// シリーズに属する本の巻を巻数の順に取得（ゴミ箱にある本の巻は含めない）
+    async fn find_volumes(&self, series_id: i32) -> Result<Vec<SeriesVolume>, DomainError>;
+
+    /// 本のシリーズでの巻を取得
+    async fn find_volume_by_book(&self, book_id: i32) -> Result<Option<SeriesVolume>, DomainError>;
+
+    /// 本のシリーズでの巻を保存（本が別のシリーズに属していた場合は置き換える）
+    async fn save_volume(&self, volume: SeriesVolume) -> Result<(), DomainError>;
+
+    /// 本をシリーズから外す
+    async fn remove_volume(&self, book_id: i32) -> Result<(), DomainError>;
+}
@@ -0,0 +1,43 @@
+// Library Domain Layer - Shelf リポジトリインターフェース
+
+use crate::domain::entities::shelf::Shelf;
+use crate::domain::errors::DomainError;
+use async_trait::async_trait;
+
+/// Shelf リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait ShelfRepository: Send + Sync {
+    /// IDで本棚を検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Shelf>, DomainError>;
+
+    /// 名前（完全一致）で本棚を検索
+    async fn find_by_name(&self, name: &str) -> Result<Option<Shelf>, DomainError>;
+
+    /// すべての本棚を表示順に取得
+    async fn find_all(&self) -> Result<Vec<Shelf>, DomainError>;
+
+    /// 本を並べている手動の本棚を表示順に取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Shelf>, DomainError>;
+
+    /// 本棚を保存（新規作成の場合は一覧の末尾に追加する）
+    async fn save(&self, shelf: Shelf) -> Result<Shelf, DomainError>;
+
+    /// 本棚を削除（並べた本の関連も削除する）
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+
+    /// 本棚の表示順を指定したIDの順にそろえる（すべての本棚のIDを指定する）
+    async fn reorder(&self, shelf_ids: &[i32]) -> Result<(), DomainError>;
+
+    /// 手動の本棚に並べた本のIDを並び順に取得
+    async fn find_book_ids(&self, shelf_id: i32) -> Result<Vec<i32>, DomainError>;
+
+    /// 手動の本棚の末尾に本を追加する（並べている場合は何もしない）
+    async fn add_book(&self, shelf_id: i32, book_id: i32) -> Result<(), DomainError>;
+
+    /// 手動の本棚から本を外す
+    async fn remove_book(&self, shelf_id: i32, book_id: i32) -> Result<(), DomainError>;
+
+    /// 手動の本棚の並び順を指定したIDの順にそろえる（並べているすべての本のIDを指定する）
+    async fn reorder_books(&self, shelf_id: i32, book_ids: &[i32]) -> Result<(), DomainError>;
+}
@@ -0,0 +1,38 @@
+// Library Domain Layer - Tag リポジトリインターフェース
+
+use crate::domain::entities::tag::Tag;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::tag_name::TagName;
+use async_trait::async_trait;
+
+/// Tag リポジトリのインターフェース
+/// インフラ層がこのtraitを実装する
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// IDでタグを検索
+    async fn find_by_id(&self, id: i32) -> Result<Option<Tag>, DomainError>;
+
+    /// 名前（完全一致）でタグを検索
+    async fn find_by_name(&self, name: &TagName) -> Result<Option<Tag>, DomainError>;
+
+    /// すべてのタグを名前の順に取得
+    async fn find_all(&self) -> Result<Vec<Tag>, DomainError>;
+
+    /// 本に付いているタグを名前の順に取得
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Tag>, DomainError>;
+
+    /// タグを保存（新規作成または更新）
+    async fn save(&self, tag: Tag) -> Result<Tag, DomainError>;
+
+    /// 複数のタグを1つのトランザクションで保存（名前の変更で子孫のタグも移す場合など）
+    async fn save_all(&self, tags: Vec<Tag>) -> Result<Vec<Tag>, DomainError>;
+
+    /// タグを削除（子孫のタグと本への関連付けも削除する）
+    async fn delete(&self, id: i32) -> Result<(), DomainError>;
+
+    /// 本にタグを付ける（付いている場合は何もしない）
+    async fn add_to_book(&self, book_id: i32, tag_id: i32) -> Result<(), DomainError>;
+
+    /// 本からタグを外す
+    async fn remove_from_book(&self, book_id: i32, tag_id: i32) -> Result<(), DomainError>;
+}
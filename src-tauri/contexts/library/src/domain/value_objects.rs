@@ -0,0 +1,23 @@
+// Library Domain - Value Objects
+
+pub mod author_role;
+pub mod book_change_action;
+pub mod book_change_source;
+pub mod class_number;
+pub mod copy_condition;
+pub mod copy_format;
+pub mod cover_hash;
+pub mod ebook_format;
+pub mod external_source;
+pub mod highlight_kind;
+pub mod isbn;
+pub mod location_kind;
+pub mod price;
+pub mod rating;
+pub mod reading_progress;
+pub mod reading_status;
+pub mod search_query;
+pub mod series_status;
+pub mod sort_key;
+pub mod tag_name;
+pub mod volume_number;
@@ -0,0 +1,60 @@
+// Library Domain Layer - AuthorRole Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 本に対する人物の関わり方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuthorRole {
+    /// 著者
+    Author,
+    /// 翻訳者
+    Translator,
+    /// 編者
+    Editor,
+    /// 挿絵・イラスト
+    Illustrator,
+}
+
+impl AuthorRole {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Author => "author",
+            Self::Translator => "translator",
+            Self::Editor => "editor",
+            Self::Illustrator => "illustrator",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "author" => Ok(Self::Author),
+            "translator" => Ok(Self::Translator),
+            "editor" => Ok(Self::Editor),
+            "illustrator" => Ok(Self::Illustrator),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown author role: {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for role in [
+            AuthorRole::Author,
+            AuthorRole::Translator,
+            AuthorRole::Editor,
+            AuthorRole::Illustrator,
+        ] {
+            assert_eq!(AuthorRole::parse(role.as_str()).unwrap(), role);
+        }
+        assert!(AuthorRole::parse("narrator").is_err());
+    }
+}
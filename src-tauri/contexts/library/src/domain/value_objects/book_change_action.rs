@@ -0,0 +1,75 @@
+// Library Domain Layer - BookChangeAction Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 変更履歴に記録する本への操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BookChangeAction {
+    /// 作成
+    Create,
+    /// 書誌情報・著者の編集
+    Update,
+    /// ゴミ箱に移す
+    Delete,
+    /// ゴミ箱から元に戻す
+    Restore,
+    /// 指定した履歴番号の状態への復元
+    Revert,
+    /// 直前の変更の取り消し（取り消した変更は以降の取り消しの対象にしない）
+    Undo,
+    /// 他の本をまとめた、または他の本にまとめてゴミ箱に移した（まとめ先の本のまとめは取り消せない）
+    Merge,
+}
+
+impl BookChangeAction {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+            Self::Restore => "restore",
+            Self::Revert => "revert",
+            Self::Undo => "undo",
+            Self::Merge => "merge",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "create" => Ok(Self::Create),
+            "update" => Ok(Self::Update),
+            "delete" => Ok(Self::Delete),
+            "restore" => Ok(Self::Restore),
+            "revert" => Ok(Self::Revert),
+            "undo" => Ok(Self::Undo),
+            "merge" => Ok(Self::Merge),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown book change action: {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for action in [
+            BookChangeAction::Create,
+            BookChangeAction::Update,
+            BookChangeAction::Delete,
+            BookChangeAction::Restore,
+            BookChangeAction::Revert,
+            BookChangeAction::Undo,
+            BookChangeAction::Merge,
+        ] {
+            assert_eq!(BookChangeAction::parse(action.as_str()).unwrap(), action);
+        }
+        assert!(BookChangeAction::parse("import").is_err());
+    }
+}
@@ -0,0 +1,60 @@
+// Library Domain Layer - BookChangeSource Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 変更履歴に記録する変更のきっかけ（誰・何が本を変更したか）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BookChangeSource {
+    /// ユーザーによる作成・編集・ゴミ箱への移動・復元
+    User,
+    /// CSV・Calibre・電子書籍ファイル・Kindle のハイライトなどの取り込み
+    Import,
+    /// 重複した本のまとめ
+    Merge,
+    /// 変更履歴からの取り消し・以前の版への復元
+    History,
+}
+
+impl BookChangeSource {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Import => "import",
+            Self::Merge => "merge",
+            Self::History => "history",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "user" => Ok(Self::User),
+            "import" => Ok(Self::Import),
+            "merge" => Ok(Self::Merge),
+            "history" => Ok(Self::History),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown book change source: {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for source in [
+            BookChangeSource::User,
+            BookChangeSource::Import,
+            BookChangeSource::Merge,
+            BookChangeSource::History,
+        ] {
+            assert_eq!(BookChangeSource::parse(source.as_str()).unwrap(), source);
+        }
+        assert!(BookChangeSource::parse("system").is_err());
+    }
+}
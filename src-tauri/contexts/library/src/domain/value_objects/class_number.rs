@@ -0,0 +1,275 @@
+// Library Domain Layer - ClassNumber Value Object
+
+mod ddc;
+mod ndc;
+
+use crate::domain::errors::DomainError;
+use std::fmt;
+
+/// 分類記号の最大文字数（小数点を含む）
+const MAX_CLASS_NUMBER_LENGTH: usize = 20;
+/// 分類表に含める階層の深さ（類・綱・目）
+pub const CLASS_TABLE_DEPTH: usize = 3;
+
+/// 分類法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClassificationScheme {
+    /// 日本十進分類法（NDC）
+    Ndc,
+    /// デューイ十進分類法（DDC）
+    Ddc,
+}
+
+impl ClassificationScheme {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ndc => "NDC",
+            Self::Ddc => "DDC",
+        }
+    }
+
+    /// 分類表（類・綱・目の分類記号と名称、分類記号の順）
+    fn entries(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Ndc => ndc::ENTRIES,
+            Self::Ddc => ddc::ENTRIES,
+        }
+    }
+
+    /// 分類表での名称（類は1桁、綱は2桁、目は3桁の分類記号で引く）
+    pub fn class_name(self, code: &str) -> Option<&'static str> {
+        let entries = self.entries();
+        entries
+            .binary_search_by(|(entry, _)| (*entry).cmp(code))
+            .ok()
+            .map(|index| entries[index].1)
+    }
+
+    /// 分類表で1つ下の階層にある分類（`parent` が None の場合は類）
+    pub fn subclasses(self, parent: Option<&str>) -> Vec<(&'static str, &'static str)> {
+        let parent = parent.unwrap_or("");
+        self.entries()
+            .iter()
+            .filter(|(code, _)| code.len() == parent.len() + 1 && code.starts_with(parent))
+            .copied()
+            .collect()
+    }
+
+    /// 入力を正規化（全角の数字・小数点は半角にし、DDCの区切り記号 `'` `/` は除く）
+    fn normalize(self, input: &str) -> String {
+        input
+            .trim()
+            .chars()
+            .filter(|c| !(self == Self::Ddc && matches!(c, '\'' | '/' | '′')))
+            .map(|c| match c {
+                '０'..='９' => char::from_digit(c as u32 - '０' as u32, 10).unwrap_or(c),
+                '．' => '.',
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+/// 本の分類記号のValue Object（3桁の数字と、必要に応じて小数点以下の数字。例: NDC 913.6、DDC 823.914）
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassNumber {
+    scheme: ClassificationScheme,
+    value: String,
+}
+
+impl ClassNumber {
+    /// 入力を解析し、分類記号の形式を検証して作成
+    pub fn parse(scheme: ClassificationScheme, input: &str) -> Result<Self, DomainError> {
+        let value = scheme.normalize(input);
+
+        // ビジネスルール: 3桁の数字で始まり、小数点以下は数字のみで0で終わらない
+        let (main, decimals) = match value.split_once('.') {
+            Some((main, decimals)) => (main, Some(decimals)),
+            None => (value.as_str(), None),
+        };
+        let valid_main =
+            main.len() == CLASS_TABLE_DEPTH && main.chars().all(|c| c.is_ascii_digit());
+        let valid_decimals = decimals.is_none_or(|decimals| {
+            !decimals.is_empty()
+                && decimals.chars().all(|c| c.is_ascii_digit())
+                && !decimals.ends_with('0')
+        });
+        if !valid_main || !valid_decimals || value.len() > MAX_CLASS_NUMBER_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid {} class number: {} (expected three digits with optional decimals, e.g. {})",
+                scheme.as_str(),
+                input.trim(),
+                match scheme {
+                    ClassificationScheme::Ndc => "913.6",
+                    ClassificationScheme::Ddc => "823.914",
+                }
+            )));
+        }
+
+        Ok(Self { scheme, value })
+    }
+
+    pub fn scheme(&self) -> ClassificationScheme {
+        self.scheme
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// 分類表で最も詳しい階層の名称（目・綱・類の順に探す）
+    pub fn class_name(&self) -> Option<&'static str> {
+        (1..=CLASS_TABLE_DEPTH)
+            .rev()
+            .find_map(|depth| self.scheme.class_name(&self.value[..depth]))
+    }
+}
+
+impl fmt::Display for ClassNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// 分類による絞り込み・集計の範囲（類は1桁、綱は2桁、目以下は分類記号で指定し、その下位の分類を含む）
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassPrefix {
+    scheme: ClassificationScheme,
+    value: String,
+}
+
+impl ClassPrefix {
+    /// 入力を解析して作成（1〜2桁の数字、または分類記号）
+    pub fn parse(scheme: ClassificationScheme, input: &str) -> Result<Self, DomainError> {
+        let value = scheme.normalize(input);
+        if (1..CLASS_TABLE_DEPTH).contains(&value.len())
+            && value.chars().all(|c| c.is_ascii_digit())
+        {
+            return Ok(Self { scheme, value });
+        }
+
+        let number = ClassNumber::parse(scheme, input)?;
+        Ok(Self {
+            scheme,
+            value: number.value,
+        })
+    }
+
+    pub fn scheme(&self) -> ClassificationScheme {
+        self.scheme
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// 分類表での階層（1: 類、2: 綱、3: 目とその下位）
+    pub fn depth(&self) -> usize {
+        self.value.len().min(CLASS_TABLE_DEPTH)
+    }
+
+    /// 分類記号がこの範囲に含まれるか
+    pub fn contains(&self, number: &ClassNumber) -> bool {
+        number.scheme == self.scheme && number.value.starts_with(&self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_class_numbers() {
+        let ndc = ClassNumber::parse(ClassificationScheme::Ndc, " ９１３．６ ").unwrap();
+        assert_eq!(ndc.as_str(), "913.6");
+        assert_eq!(ndc.class_name(), Some("小説．物語"));
+
+        let ddc = ClassNumber::parse(ClassificationScheme::Ddc, "823/.914").unwrap();
+        assert_eq!(ddc.as_str(), "823.914");
+        assert_eq!(ddc.class_name(), Some("English fiction"));
+        assert_eq!(ddc.to_string(), "823.914");
+    }
+
+    #[test]
+    fn test_invalid_class_numbers_fail() {
+        for input in [
+            "",
+            "91",
+            "9136",
+            "913.",
+            "913.60",
+            "91a",
+            "913.6.1",
+            "913.12345678901234567",
+        ] {
+            assert!(
+                ClassNumber::parse(ClassificationScheme::Ndc, input).is_err(),
+                "{}",
+                input
+            );
+        }
+        // 区切り記号を除くのはDDCのみ
+        assert!(ClassNumber::parse(ClassificationScheme::Ndc, "823/.914").is_err());
+    }
+
+    #[test]
+    fn test_class_name_falls_back_to_broader_class() {
+        // 使われていない目は綱の名称を使う
+        let ndc = ClassNumber::parse(ClassificationScheme::Ndc, "001").unwrap();
+        assert_eq!(ndc.class_name(), Some("総記"));
+        let ddc = ClassNumber::parse(ClassificationScheme::Ddc, "007").unwrap();
+        assert_eq!(
+            ddc.class_name(),
+            Some("Computer science, knowledge & systems")
+        );
+    }
+
+    #[test]
+    fn test_class_tables() {
+        for scheme in [ClassificationScheme::Ndc, ClassificationScheme::Ddc] {
+            let entries = scheme.entries();
+            // 二分探索のため分類記号の順に並び、上位の分類がすべて含まれていること
+            assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            for (code, _) in entries {
+                assert!((1..=CLASS_TABLE_DEPTH).contains(&code.len()));
+                assert!(code.len() == 1 || scheme.class_name(&code[..code.len() - 1]).is_some());
+            }
+            assert_eq!(scheme.subclasses(None).len(), 10);
+        }
+
+        let divisions = ClassificationScheme::Ndc.subclasses(Some("9"));
+        assert_eq!(divisions.len(), 10);
+        assert_eq!(divisions[1], ("91", "日本文学"));
+        assert_eq!(
+            ClassificationScheme::Ddc.class_name("52"),
+            Some("Astronomy")
+        );
+        assert_eq!(ClassificationScheme::Ddc.class_name("04"), None);
+    }
+
+    #[test]
+    fn test_class_prefix() {
+        let literature = ClassPrefix::parse(ClassificationScheme::Ndc, "9").unwrap();
+        let japanese = ClassPrefix::parse(ClassificationScheme::Ndc, "91").unwrap();
+        let novels = ClassPrefix::parse(ClassificationScheme::Ndc, "913").unwrap();
+        assert_eq!(
+            (literature.depth(), japanese.depth(), novels.depth()),
+            (1, 2, 3)
+        );
+
+        let number = ClassNumber::parse(ClassificationScheme::Ndc, "913.6").unwrap();
+        assert!(literature.contains(&number));
+        assert!(novels.contains(&number));
+        assert!(
+            !ClassPrefix::parse(ClassificationScheme::Ndc, "914")
+                .unwrap()
+                .contains(&number)
+        );
+        assert!(
+            !ClassPrefix::parse(ClassificationScheme::Ddc, "9")
+                .unwrap()
+                .contains(&number)
+        );
+        assert!(ClassPrefix::parse(ClassificationScheme::Ndc, "9x").is_err());
+    }
+}
@@ -0,0 +1,1166 @@
+// Library Domain Layer - デューイ十進分類法（DDC）の分類表
+
+/// デューイ十進分類法（第23版）の類・綱・目の分類記号と名称（分類記号の順。割り当てのない記号は含まない）
+pub(super) const ENTRIES: &[(&str, &str)] = &[
+    ("0", "Computer science, information & general works"),
+    ("00", "Computer science, knowledge & systems"),
+    ("000", "Computer science, information & general works"),
+    ("001", "Knowledge"),
+    ("002", "The book"),
+    ("003", "Systems"),
+    ("004", "Data processing & computer science"),
+    ("005", "Computer programming, programs & data"),
+    ("006", "Special computer methods"),
+    ("01", "Bibliographies"),
+    ("010", "Bibliography"),
+    ("011", "Bibliographies"),
+    ("012", "Bibliographies of individuals"),
+    ("014", "Bibliographies of anonymous & pseudonymous works"),
+    ("015", "Bibliographies of works from specific places"),
+    ("016", "Bibliographies of works on specific subjects"),
+    ("017", "General subject catalogs"),
+    ("018", "Catalogs arranged by author, date, etc."),
+    ("019", "Dictionary catalogs"),
+    ("02", "Library & information sciences"),
+    ("020", "Library & information sciences"),
+    ("021", "Library relationships"),
+    ("022", "Administration of physical plant"),
+    ("023", "Personnel management"),
+    ("025", "Library operations"),
+    ("026", "Libraries for specific subjects"),
+    ("027", "General libraries"),
+    ("028", "Reading & use of other information media"),
+    ("03", "Encyclopedias & books of facts"),
+    ("030", "General encyclopedic works"),
+    ("031", "Encyclopedias in American English"),
+    ("032", "Encyclopedias in English"),
+    ("033", "Encyclopedias in other Germanic languages"),
+    ("034", "Encyclopedias in French, Occitan & Catalan"),
+    (
+        "035",
+        "Encyclopedias in Italian, Romanian & related languages",
+    ),
+    ("036", "Encyclopedias in Spanish, Portuguese, Galician"),
+    ("037", "Encyclopedias in Slavic languages"),
+    ("038", "Encyclopedias in Scandinavian languages"),
+    ("039", "Encyclopedias in other languages"),
+    ("05", "Magazines, journals & serials"),
+    ("050", "General serial publications"),
+    ("051", "Serials in American English"),
+    ("052", "Serials in English"),
+    ("053", "Serials in other Germanic languages"),
+    ("054", "Serials in French, Occitan & Catalan"),
+    ("055", "Serials in Italian, Romanian & related languages"),
+    ("056", "Serials in Spanish, Portuguese, Galician"),
+    ("057", "Serials in Slavic languages"),
+    ("058", "Serials in Scandinavian languages"),
+    ("059", "Serials in other languages"),
+    ("06", "Associations, organizations & museums"),
+    ("060", "General organizations & museum science"),
+    ("061", "Organizations in North America"),
+    ("062", "Organizations in British Isles; in England"),
+    ("063", "Organizations in central Europe; in Germany"),
+    ("064", "Organizations in France & Monaco"),
+    ("065", "Organizations in Italy & adjacent islands"),
+    (
+        "066",
+        "Organizations in Iberian Peninsula & adjacent islands",
+    ),
+    ("067", "Organizations in eastern Europe; in Russia"),
+    ("068", "Organizations in other geographic areas"),
+    ("069", "Museum science"),
+    ("07", "News media, journalism & publishing"),
+    ("070", "News media, journalism & publishing"),
+    ("071", "Newspapers in North America"),
+    ("072", "Newspapers in British Isles; in England"),
+    ("073", "Newspapers in central Europe; in Germany"),
+    ("074", "Newspapers in France & Monaco"),
+    ("075", "Newspapers in Italy & adjacent islands"),
+    ("076", "Newspapers in Iberian Peninsula & adjacent islands"),
+    ("077", "Newspapers in eastern Europe; in Russia"),
+    ("078", "Newspapers in Scandinavia"),
+    ("079", "Newspapers in other geographic areas"),
+    ("08", "General collections"),
+    ("080", "General collections"),
+    ("081", "Collections in American English"),
+    ("082", "Collections in English"),
+    ("083", "Collections in other Germanic languages"),
+    ("084", "Collections in French, Occitan & Catalan"),
+    (
+        "085",
+        "Collections in Italian, Romanian & related languages",
+    ),
+    ("086", "Collections in Spanish, Portuguese, Galician"),
+    ("087", "Collections in Slavic languages"),
+    ("088", "Collections in Scandinavian languages"),
+    ("089", "Collections in other languages"),
+    ("09", "Manuscripts & rare books"),
+    ("090", "Manuscripts & rare books"),
+    ("091", "Manuscripts"),
+    ("092", "Block books"),
+    ("093", "Incunabula"),
+    ("094", "Printed books"),
+    ("095", "Books notable for bindings"),
+    ("096", "Books notable for illustrations"),
+    ("097", "Books notable for ownership or origin"),
+    ("098", "Prohibited works, forgeries & hoaxes"),
+    ("099", "Books notable for format"),
+    ("1", "Philosophy & psychology"),
+    ("10", "Philosophy"),
+    ("100", "Philosophy & psychology"),
+    ("101", "Theory of philosophy"),
+    ("102", "Miscellany"),
+    ("103", "Dictionaries & encyclopedias"),
+    ("105", "Serial publications"),
+    ("106", "Organizations & management"),
+    ("107", "Education, research & related topics"),
+    ("108", "Groups of people"),
+    ("109", "History & collected biography"),
+    ("11", "Metaphysics"),
+    ("110", "Metaphysics"),
+    ("111", "Ontology"),
+    ("113", "Cosmology"),
+    ("114", "Space"),
+    ("115", "Time"),
+    ("116", "Change"),
+    ("117", "Structure"),
+    ("118", "Force & energy"),
+    ("119", "Number & quantity"),
+    ("12", "Epistemology"),
+    ("120", "Epistemology, causation & humankind"),
+    ("121", "Epistemology"),
+    ("122", "Causation"),
+    ("123", "Determinism & indeterminism"),
+    ("124", "Teleology"),
+    ("126", "The self"),
+    ("127", "The unconscious & the subconscious"),
+    ("128", "Humankind"),
+    ("129", "Origin & destiny of individual souls"),
+    ("13", "Parapsychology & occultism"),
+    ("130", "Parapsychology & occultism"),
+    ("131", "Parapsychological & occult methods"),
+    ("133", "Specific topics in parapsychology & occultism"),
+    ("135", "Dreams & mysteries"),
+    ("137", "Divinatory graphology"),
+    ("138", "Physiognomy"),
+    ("139", "Phrenology"),
+    ("14", "Philosophical schools of thought"),
+    ("140", "Specific philosophical schools"),
+    ("141", "Idealism & related systems"),
+    ("142", "Critical philosophy"),
+    ("143", "Bergsonism & intuitionism"),
+    ("144", "Humanism & related systems"),
+    ("145", "Sensationalism"),
+    ("146", "Naturalism & related systems"),
+    ("147", "Pantheism & related systems"),
+    (
+        "148",
+        "Dogmatism, eclecticism, liberalism, syncretism & traditionalism",
+    ),
+    ("149", "Other philosophical systems"),
+    ("15", "Psychology"),
+    ("150", "Psychology"),
+    (
+        "152",
+        "Sensory perception, movement, emotions & physiological drives",
+    ),
+    ("153", "Conscious mental processes & intelligence"),
+    ("154", "Subconscious & altered states & processes"),
+    ("155", "Differential & developmental psychology"),
+    ("156", "Comparative psychology"),
+    ("158", "Applied psychology"),
+    ("16", "Philosophical logic"),
+    ("160", "Philosophical logic"),
+    ("161", "Induction"),
+    ("162", "Deduction"),
+    ("165", "Fallacies & sources of error"),
+    ("166", "Syllogisms"),
+    ("167", "Hypotheses"),
+    ("168", "Argument & persuasion"),
+    ("169", "Analogy"),
+    ("17", "Ethics"),
+    ("170", "Ethics"),
+    ("171", "Ethical systems"),
+    ("172", "Political ethics"),
+    ("173", "Ethics of family relationships"),
+    ("174", "Occupational ethics"),
+    (
+        "175",
+        "Ethics of recreation, leisure, public performances, communication",
+    ),
+    ("176", "Ethics of sex & reproduction"),
+    ("177", "Ethics of social relations"),
+    ("178", "Ethics of consumption"),
+    ("179", "Other ethical norms"),
+    ("18", "Ancient, medieval & eastern philosophy"),
+    ("180", "Ancient, medieval & eastern philosophy"),
+    ("181", "Eastern philosophy"),
+    ("182", "Pre-Socratic Greek philosophies"),
+    ("183", "Sophistic, Socratic & related Greek philosophies"),
+    ("184", "Platonic philosophy"),
+    ("185", "Aristotelian philosophy"),
+    ("186", "Skeptic & Neoplatonic philosophies"),
+    ("187", "Epicurean philosophy"),
+    ("188", "Stoic philosophy"),
+    ("189", "Medieval western philosophy"),
+    ("19", "Modern western philosophy"),
+    ("190", "Modern western & other noneastern philosophy"),
+    ("191", "Philosophy of United States & Canada"),
+    ("192", "Philosophy of British Isles"),
+    ("193", "Philosophy of Germany & Austria"),
+    ("194", "Philosophy of France"),
+    ("195", "Philosophy of Italy"),
+    ("196", "Philosophy of Spain & Portugal"),
+    ("197", "Philosophy of Russia"),
+    ("198", "Philosophy of Scandinavia & Finland"),
+    ("199", "Philosophy in other geographic areas"),
+    ("2", "Religion"),
+    ("20", "Religion"),
+    ("200", "Religion"),
+    ("201", "Religious mythology & social theology"),
+    ("202", "Doctrines"),
+    ("203", "Public worship & other practices"),
+    ("204", "Religious experience, life & practice"),
+    ("205", "Religious ethics"),
+    ("206", "Leaders & organization"),
+    ("207", "Missions & religious education"),
+    ("208", "Sources"),
+    ("209", "Sects & reform movements"),
+    ("21", "Philosophy & theory of religion"),
+    ("210", "Philosophy & theory of religion"),
+    ("211", "Concepts of God"),
+    ("212", "Existence, knowability & attributes of God"),
+    ("213", "Creation"),
+    ("214", "Theodicy"),
+    ("215", "Science & religion"),
+    ("218", "Humankind"),
+    ("22", "The Bible"),
+    ("220", "Bible"),
+    ("221", "Old Testament (Tanakh)"),
+    ("222", "Historical books of Old Testament"),
+    ("223", "Poetic books of Old Testament"),
+    ("224", "Prophetic books of Old Testament"),
+    ("225", "New Testament"),
+    ("226", "Gospels & Acts"),
+    ("227", "Epistles"),
+    ("228", "Revelation (Apocalypse)"),
+    ("229", "Apocrypha & pseudepigrapha"),
+    ("23", "Christianity"),
+    ("230", "Christianity & Christian theology"),
+    ("231", "God"),
+    ("232", "Jesus Christ & his family"),
+    ("233", "Humankind"),
+    ("234", "Salvation & grace"),
+    ("235", "Spiritual beings"),
+    ("236", "Eschatology"),
+    (
+        "238",
+        "Creeds, confessions of faith, covenants & catechisms",
+    ),
+    ("239", "Apologetics & polemics"),
+    ("24", "Christian practice & observance"),
+    ("240", "Christian moral & devotional theology"),
+    ("241", "Christian ethics"),
+    ("242", "Devotional literature"),
+    ("243", "Evangelistic writings for individuals & families"),
+    ("246", "Use of art in Christianity"),
+    ("247", "Church furnishings & related articles"),
+    ("248", "Christian experience, practice & life"),
+    ("249", "Christian observances in family life"),
+    ("25", "Christian pastoral practice & religious orders"),
+    ("250", "Local Christian church & Christian religious orders"),
+    ("251", "Preaching"),
+    ("252", "Texts of sermons"),
+    ("253", "Pastoral office & work"),
+    ("254", "Parish administration"),
+    ("255", "Religious congregations & orders"),
+    ("259", "Pastoral care of specific kinds of persons"),
+    ("26", "Christian organization, social work & worship"),
+    ("260", "Christian social & ecclesiastical theology"),
+    (
+        "261",
+        "Social theology & interreligious relations & attitudes",
+    ),
+    ("262", "Ecclesiology"),
+    ("263", "Days, times & places of religious observance"),
+    ("264", "Public worship"),
+    ("265", "Sacraments, other rites & acts"),
+    ("266", "Missions"),
+    ("267", "Associations for religious work"),
+    ("268", "Religious education"),
+    ("269", "Spiritual renewal"),
+    ("27", "History of Christianity"),
+    ("270", "History of Christianity"),
+    ("271", "Religious congregations & orders in church history"),
+    ("272", "Persecutions in church history"),
+    (
+        "273",
+        "Doctrinal controversies & heresies in general church history",
+    ),
+    ("274", "History of Christianity in Europe"),
+    ("275", "History of Christianity in Asia"),
+    ("276", "History of Christianity in Africa"),
+    ("277", "History of Christianity in North America"),
+    ("278", "History of Christianity in South America"),
+    ("279", "History of Christianity in other areas"),
+    ("28", "Christian denominations"),
+    ("280", "Christian denominations"),
+    ("281", "Early church & Eastern churches"),
+    ("282", "Roman Catholic Church"),
+    ("283", "Anglican churches"),
+    (
+        "284",
+        "Protestant denominations of Continental origin & related bodies",
+    ),
+    (
+        "285",
+        "Presbyterian churches, Reformed churches centered in America, Congregational churches",
+    ),
+    ("286", "Baptist, Restoration movement, Adventist churches"),
+    ("287", "Methodist churches; churches related to Methodism"),
+    ("289", "Other denominations & sects"),
+    ("29", "Other religions"),
+    ("290", "Other religions"),
+    ("292", "Greek & Roman religion"),
+    ("293", "Germanic religion"),
+    ("294", "Religions of Indic origin"),
+    ("295", "Zoroastrianism"),
+    ("296", "Judaism"),
+    ("297", "Islam, Babism & Bahai Faith"),
+    ("299", "Religions not provided for elsewhere"),
+    ("3", "Social sciences"),
+    ("30", "Social sciences, sociology & anthropology"),
+    ("300", "Social sciences"),
+    ("301", "Sociology & anthropology"),
+    ("302", "Social interaction"),
+    ("303", "Social processes"),
+    ("304", "Factors affecting social behavior"),
+    ("305", "Groups of people"),
+    ("306", "Culture & institutions"),
+    ("307", "Communities"),
+    ("31", "Statistics"),
+    ("310", "Collections of general statistics"),
+    ("314", "General statistics of Europe"),
+    ("315", "General statistics of Asia"),
+    ("316", "General statistics of Africa"),
+    ("317", "General statistics of North America"),
+    ("318", "General statistics of South America"),
+    (
+        "319",
+        "General statistics of Australasia, Pacific Ocean islands, Atlantic Ocean islands, Arctic islands, Antarctica",
+    ),
+    ("32", "Political science"),
+    ("320", "Political science"),
+    ("321", "Systems of governments & states"),
+    ("322", "Relation of state to organized groups"),
+    ("323", "Civil & political rights"),
+    ("324", "The political process"),
+    ("325", "International migration & colonization"),
+    ("326", "Slavery & emancipation"),
+    ("327", "International relations"),
+    ("328", "The legislative process"),
+    ("33", "Economics"),
+    ("330", "Economics"),
+    ("331", "Labor economics"),
+    ("332", "Financial economics"),
+    ("333", "Economics of land & energy"),
+    ("334", "Cooperatives"),
+    ("335", "Socialism & related systems"),
+    ("336", "Public finance"),
+    ("337", "International economics"),
+    ("338", "Production"),
+    ("339", "Macroeconomics & related topics"),
+    ("34", "Law"),
+    ("340", "Law"),
+    ("341", "Law of nations"),
+    ("342", "Constitutional & administrative law"),
+    (
+        "343",
+        "Military, defense, public property, public finance, tax, commerce (trade), industrial law",
+    ),
+    ("344", "Labor, social service, education, cultural law"),
+    ("345", "Criminal law"),
+    ("346", "Private law"),
+    ("347", "Procedure & courts"),
+    ("348", "Laws, regulations & cases"),
+    (
+        "349",
+        "Law of specific jurisdictions, areas & socioeconomic regions",
+    ),
+    ("35", "Public administration & military science"),
+    ("350", "Public administration & military science"),
+    ("351", "Public administration"),
+    ("352", "General considerations of public administration"),
+    ("353", "Specific fields of public administration"),
+    ("354", "Public administration of economy & environment"),
+    ("355", "Military science"),
+    ("356", "Foot forces & warfare"),
+    ("357", "Mounted forces & warfare"),
+    (
+        "358",
+        "Air & other specialized forces & warfare; engineering & related services",
+    ),
+    ("359", "Sea forces & warfare"),
+    ("36", "Social problems & social services"),
+    ("360", "Social problems & services; associations"),
+    ("361", "Social problems & social welfare in general"),
+    ("362", "Social welfare problems & services"),
+    ("363", "Other social problems & services"),
+    ("364", "Criminology"),
+    ("365", "Penal & related institutions"),
+    ("366", "Secret associations & societies"),
+    ("367", "General clubs"),
+    ("368", "Insurance"),
+    ("369", "Miscellaneous kinds of associations"),
+    ("37", "Education"),
+    ("370", "Education"),
+    ("371", "Schools & their activities; special education"),
+    ("372", "Primary education (Elementary education)"),
+    ("373", "Secondary education"),
+    ("374", "Adult education"),
+    ("375", "Curricula"),
+    ("378", "Higher education (Tertiary education)"),
+    ("379", "Public policy issues in education"),
+    ("38", "Commerce, communications & transportation"),
+    ("380", "Commerce, communications & transportation"),
+    ("381", "Commerce (Trade)"),
+    ("382", "International commerce (Foreign trade)"),
+    ("383", "Postal communication"),
+    ("384", "Communications"),
+    ("385", "Railroad transportation"),
+    ("386", "Inland waterway & ferry transportation"),
+    ("387", "Water, air & space transportation"),
+    ("388", "Transportation"),
+    ("389", "Metrology & standardization"),
+    ("39", "Customs, etiquette & folklore"),
+    ("390", "Customs, etiquette & folklore"),
+    ("391", "Costume & personal appearance"),
+    ("392", "Customs of life cycle & domestic life"),
+    ("393", "Death customs"),
+    ("394", "General customs"),
+    ("395", "Etiquette (Manners)"),
+    ("398", "Folklore"),
+    ("399", "Customs of war & diplomacy"),
+    ("4", "Language"),
+    ("40", "Language"),
+    ("400", "Language"),
+    ("401", "Philosophy & theory; international languages"),
+    ("402", "Miscellany"),
+    ("403", "Dictionaries, encyclopedias, concordances"),
+    ("404", "Special topics of language"),
+    ("405", "Serial publications"),
+    ("406", "Organizations & management"),
+    ("407", "Education, research & related topics"),
+    ("408", "Groups of people"),
+    ("409", "Geographic treatment & persons"),
+    ("41", "Linguistics"),
+    ("410", "Linguistics"),
+    ("411", "Writing systems of standard forms of languages"),
+    ("412", "Etymology of standard forms of languages"),
+    ("413", "Dictionaries of standard forms of languages"),
+    (
+        "414",
+        "Phonology & phonetics of standard forms of languages",
+    ),
+    ("415", "Grammar of standard forms of languages"),
+    ("417", "Dialectology & historical linguistics"),
+    ("418", "Standard usage (Prescriptive linguistics)"),
+    ("419", "Sign languages"),
+    ("42", "English & Old English languages"),
+    ("420", "English & Old English (Anglo-Saxon)"),
+    ("421", "English writing system & phonology"),
+    ("422", "English etymology"),
+    ("423", "English dictionaries"),
+    ("425", "English grammar"),
+    ("427", "English language variations"),
+    ("428", "Standard English usage (Prescriptive linguistics)"),
+    ("429", "Old English (Anglo-Saxon)"),
+    ("43", "German & related languages"),
+    ("430", "German & related languages"),
+    ("431", "German writing system & phonology"),
+    ("432", "German etymology"),
+    ("433", "German dictionaries"),
+    ("435", "German grammar"),
+    ("437", "German language variations"),
+    ("438", "Standard German usage (Prescriptive linguistics)"),
+    ("439", "Other Germanic languages"),
+    ("44", "French & related languages"),
+    ("440", "French & related Romance languages"),
+    ("441", "French writing system & phonology"),
+    ("442", "French etymology"),
+    ("443", "French dictionaries"),
+    ("445", "French grammar"),
+    ("447", "French language variations"),
+    ("448", "Standard French usage (Prescriptive linguistics)"),
+    ("449", "Occitan, Catalan, Franco-Provençal"),
+    ("45", "Italian, Romanian & related languages"),
+    (
+        "450",
+        "Italian, Dalmatian, Romanian, Rhaetian, Sardinian, Corsican",
+    ),
+    ("451", "Italian writing system & phonology"),
+    ("452", "Italian etymology"),
+    ("453", "Italian dictionaries"),
+    ("455", "Italian grammar"),
+    ("457", "Italian language variations"),
+    ("458", "Standard Italian usage (Prescriptive linguistics)"),
+    ("459", "Romanian, Rhaetian, Sardinian, Corsican"),
+    ("46", "Spanish, Portuguese, Galician"),
+    ("460", "Spanish, Portuguese, Galician"),
+    ("461", "Spanish writing system & phonology"),
+    ("462", "Spanish etymology"),
+    ("463", "Spanish dictionaries"),
+    ("465", "Spanish grammar"),
+    ("467", "Spanish language variations"),
+    ("468", "Standard Spanish usage (Prescriptive linguistics)"),
+    ("469", "Portuguese"),
+    ("47", "Latin & Italic languages"),
+    ("470", "Latin & related Italic languages"),
+    ("471", "Classical Latin writing & phonology"),
+    ("472", "Classical Latin etymology"),
+    ("473", "Classical Latin dictionaries"),
+    ("475", "Classical Latin grammar"),
+    ("477", "Old, postclassical & Vulgar Latin"),
+    ("478", "Classical Latin usage (Prescriptive linguistics)"),
+    ("479", "Other Italic languages"),
+    ("48", "Classical & modern Greek languages"),
+    ("480", "Classical Greek & related Hellenic languages"),
+    ("481", "Classical Greek writing & phonology"),
+    ("482", "Classical Greek etymology"),
+    ("483", "Classical Greek dictionaries"),
+    ("485", "Classical Greek grammar"),
+    ("487", "Preclassical & postclassical Greek"),
+    ("488", "Classical Greek usage (Prescriptive linguistics)"),
+    ("489", "Other Hellenic languages"),
+    ("49", "Other languages"),
+    ("490", "Other languages"),
+    ("491", "East Indo-European & Celtic languages"),
+    ("492", "Afro-Asiatic languages"),
+    ("493", "Non-Semitic Afro-Asiatic languages"),
+    (
+        "494",
+        "Altaic, Uralic, Hyperborean & Dravidian languages; miscellaneous languages of south Asia",
+    ),
+    ("495", "Languages of East & Southeast Asia"),
+    ("496", "African languages"),
+    ("497", "North American native languages"),
+    ("498", "South American native languages"),
+    ("499", "Austronesian & other languages"),
+    ("5", "Science"),
+    ("50", "Science"),
+    ("500", "Science"),
+    ("501", "Philosophy & theory"),
+    ("502", "Miscellany"),
+    ("503", "Dictionaries, encyclopedias, concordances"),
+    ("505", "Serial publications"),
+    ("506", "Organizations & management"),
+    ("507", "Education, research & related topics"),
+    ("508", "Natural history"),
+    ("509", "History, geographic treatment, biography"),
+    ("51", "Mathematics"),
+    ("510", "Mathematics"),
+    ("511", "General principles of mathematics"),
+    ("512", "Algebra"),
+    ("513", "Arithmetic"),
+    ("514", "Topology"),
+    ("515", "Analysis"),
+    ("516", "Geometry"),
+    ("518", "Numerical analysis"),
+    ("519", "Probabilities & applied mathematics"),
+    ("52", "Astronomy"),
+    ("520", "Astronomy & allied sciences"),
+    ("521", "Celestial mechanics"),
+    (
+        "522",
+        "Techniques, procedures, apparatus, equipment, materials",
+    ),
+    ("523", "Specific celestial bodies & phenomena"),
+    ("525", "Earth (Astronomical geography)"),
+    ("526", "Mathematical geography"),
+    ("527", "Celestial navigation"),
+    ("528", "Ephemerides"),
+    ("529", "Chronology"),
+    ("53", "Physics"),
+    ("530", "Physics"),
+    ("531", "Classical mechanics"),
+    ("532", "Fluid mechanics"),
+    ("533", "Pneumatics (Gas mechanics)"),
+    ("534", "Sound & related vibrations"),
+    ("535", "Light & related radiation"),
+    ("536", "Heat"),
+    ("537", "Electricity & electronics"),
+    ("538", "Magnetism"),
+    ("539", "Modern physics"),
+    ("54", "Chemistry"),
+    ("540", "Chemistry & allied sciences"),
+    ("541", "Physical chemistry"),
+    (
+        "542",
+        "Techniques, procedures, apparatus, equipment, materials",
+    ),
+    ("543", "Analytical chemistry"),
+    ("546", "Inorganic chemistry"),
+    ("547", "Organic chemistry"),
+    ("548", "Crystallography"),
+    ("549", "Mineralogy"),
+    ("55", "Earth sciences & geology"),
+    ("550", "Earth sciences"),
+    ("551", "Geology, hydrology, meteorology"),
+    ("552", "Petrology"),
+    ("553", "Economic geology"),
+    ("554", "Earth sciences of Europe"),
+    ("555", "Earth sciences of Asia"),
+    ("556", "Earth sciences of Africa"),
+    ("557", "Earth sciences of North America"),
+    ("558", "Earth sciences of South America"),
+    (
+        "559",
+        "Earth sciences of Australasia, Pacific Ocean islands, Atlantic Ocean islands, Arctic islands, Antarctica, extraterrestrial worlds",
+    ),
+    ("56", "Fossils & prehistoric life"),
+    ("560", "Paleontology"),
+    ("561", "Paleobotany; fossil microorganisms"),
+    ("562", "Fossil invertebrates"),
+    ("563", "Fossil marine & seashore invertebrates"),
+    ("564", "Fossil mollusks"),
+    ("565", "Fossil arthropods"),
+    ("566", "Fossil chordates"),
+    ("567", "Fossil cold-blooded vertebrates"),
+    ("568", "Fossil birds"),
+    ("569", "Fossil mammals"),
+    ("57", "Biology"),
+    ("570", "Biology"),
+    ("571", "Physiology & related subjects"),
+    ("572", "Biochemistry"),
+    ("573", "Specific physiological systems in animals"),
+    ("575", "Specific parts of & physiological systems in plants"),
+    ("576", "Genetics & evolution"),
+    ("577", "Ecology"),
+    ("578", "Natural history of organisms"),
+    ("579", "Microorganisms, fungi, algae"),
+    ("58", "Plants (Botany)"),
+    ("580", "Plants (Botany)"),
+    ("581", "Specific topics in natural history of plants"),
+    (
+        "582",
+        "Plants noted for specific vegetative characteristics & flowers",
+    ),
+    ("583", "Magnoliopsida (Dicotyledons)"),
+    ("584", "Liliopsida (Monocotyledons)"),
+    ("585", "Pinophyta (Gymnosperms)"),
+    ("586", "Seedless plants"),
+    ("587", "Pteridophyta (Vascular seedless plants)"),
+    ("588", "Bryophyta"),
+    ("59", "Animals (Zoology)"),
+    ("590", "Animals (Zoology)"),
+    ("591", "Specific topics in natural history of animals"),
+    ("592", "Invertebrates"),
+    ("593", "Marine & seashore invertebrates"),
+    ("594", "Mollusca & Molluscoidea"),
+    ("595", "Arthropoda"),
+    ("596", "Chordata"),
+    ("597", "Cold-blooded vertebrates"),
+    ("598", "Aves (Birds)"),
+    ("599", "Mammalia (Mammals)"),
+    ("6", "Technology"),
+    ("60", "Technology"),
+    ("600", "Technology"),
+    ("601", "Philosophy & theory"),
+    ("602", "Miscellany"),
+    ("603", "Dictionaries, encyclopedias, concordances"),
+    (
+        "604",
+        "Technical drawing, hazardous materials technology; groups of people",
+    ),
+    ("605", "Serial publications"),
+    ("606", "Organizations"),
+    ("607", "Education, research, related topics"),
+    ("608", "Patents"),
+    ("609", "History, geographic treatment, biography"),
+    ("61", "Medicine & health"),
+    ("610", "Medicine & health"),
+    ("611", "Human anatomy, cytology, histology"),
+    ("612", "Human physiology"),
+    ("613", "Personal health & safety"),
+    (
+        "614",
+        "Forensic medicine; incidence of injuries, wounds, disease; public preventive medicine",
+    ),
+    ("615", "Pharmacology & therapeutics"),
+    ("616", "Diseases"),
+    (
+        "617",
+        "Surgery, regional medicine, dentistry, ophthalmology, otology, audiology",
+    ),
+    ("618", "Gynecology, obstetrics, pediatrics, geriatrics"),
+    ("62", "Engineering"),
+    ("620", "Engineering & allied operations"),
+    ("621", "Applied physics"),
+    ("622", "Mining & related operations"),
+    ("623", "Military & nautical engineering"),
+    ("624", "Civil engineering"),
+    ("625", "Engineering of railroads & roads"),
+    ("627", "Hydraulic engineering"),
+    ("628", "Sanitary engineering"),
+    ("629", "Other branches of engineering"),
+    ("63", "Agriculture"),
+    ("630", "Agriculture & related technologies"),
+    (
+        "631",
+        "Specific techniques; apparatus, equipment, materials",
+    ),
+    ("632", "Plant injuries, diseases, pests"),
+    ("633", "Field & plantation crops"),
+    ("634", "Orchards, fruits, forestry"),
+    ("635", "Garden crops (Horticulture)"),
+    ("636", "Animal husbandry"),
+    ("637", "Processing dairy & related products"),
+    ("638", "Insect culture"),
+    (
+        "639",
+        "Hunting, fishing, conservation, related technologies",
+    ),
+    ("64", "Home & family management"),
+    ("640", "Home & family management"),
+    ("641", "Food & drink"),
+    ("642", "Meals & table service"),
+    ("643", "Housing & household equipment"),
+    ("644", "Household utilities"),
+    ("645", "Household furnishings"),
+    (
+        "646",
+        "Sewing, clothing, management of personal & family life",
+    ),
+    (
+        "647",
+        "Management of public households (Institutional housekeeping)",
+    ),
+    ("648", "Housekeeping"),
+    (
+        "649",
+        "Child rearing; home care of people with disabilities & illnesses",
+    ),
+    ("65", "Management & public relations"),
+    ("650", "Management & auxiliary services"),
+    ("651", "Office services"),
+    ("652", "Processes of written communication"),
+    ("653", "Shorthand"),
+    ("657", "Accounting"),
+    ("658", "General management"),
+    ("659", "Advertising & public relations"),
+    ("66", "Chemical engineering"),
+    ("660", "Chemical engineering & related technologies"),
+    ("661", "Technology of industrial chemicals"),
+    ("662", "Technology of explosives, fuels, related products"),
+    ("663", "Beverage technology"),
+    ("664", "Food technology"),
+    ("665", "Technology of industrial oils, fats, waxes, gases"),
+    ("666", "Ceramic & allied technologies"),
+    ("667", "Cleaning, color, coating, related technologies"),
+    ("668", "Technology of other organic products"),
+    ("669", "Metallurgy"),
+    ("67", "Manufacturing"),
+    ("670", "Manufacturing"),
+    ("671", "Metalworking processes & primary metal products"),
+    ("672", "Iron, steel, other iron alloys"),
+    ("673", "Nonferrous metals"),
+    ("674", "Lumber processing, wood products, cork"),
+    ("675", "Leather & fur processing"),
+    ("676", "Pulp & paper technology"),
+    ("677", "Textiles"),
+    ("678", "Elastomers & elastomer products"),
+    ("679", "Other products of specific kinds of materials"),
+    ("68", "Manufacture for specific uses"),
+    ("680", "Manufacture of products for specific uses"),
+    ("681", "Precision instruments & other devices"),
+    ("682", "Small forge work (Blacksmithing)"),
+    ("683", "Hardware & household appliances"),
+    ("684", "Furnishings & home workshops"),
+    ("685", "Leather & fur goods, & related products"),
+    ("686", "Printing & related activities"),
+    ("687", "Clothing & accessories"),
+    ("688", "Other final products & packaging"),
+    ("69", "Construction of buildings"),
+    ("690", "Construction of buildings"),
+    ("691", "Building materials"),
+    ("692", "Auxiliary construction practices"),
+    (
+        "693",
+        "Construction in specific types of materials & for specific purposes",
+    ),
+    ("694", "Wood construction"),
+    ("695", "Roof covering"),
+    ("696", "Utilities"),
+    ("697", "Heating, ventilating, air-conditioning engineering"),
+    ("698", "Detail finishing"),
+    ("7", "Arts & recreation"),
+    ("70", "Arts"),
+    ("700", "Arts"),
+    ("701", "Philosophy & theory of fine & decorative arts"),
+    ("702", "Miscellany of fine & decorative arts"),
+    (
+        "703",
+        "Dictionaries, encyclopedias, concordances of fine & decorative arts",
+    ),
+    ("704", "Special topics in fine & decorative arts"),
+    ("705", "Serial publications of fine & decorative arts"),
+    (
+        "706",
+        "Organizations & management of fine & decorative arts",
+    ),
+    (
+        "707",
+        "Education, research, related topics of fine & decorative arts",
+    ),
+    (
+        "708",
+        "Galleries, museums, private collections of fine & decorative arts",
+    ),
+    ("709", "History, geographic treatment, biography"),
+    ("71", "Area planning & landscape architecture"),
+    ("710", "Area planning & landscape architecture"),
+    ("711", "Area planning (Civic art)"),
+    ("712", "Landscape architecture (Landscape design)"),
+    ("713", "Landscape architecture of trafficways"),
+    ("714", "Water features in landscape architecture"),
+    ("715", "Woody plants in landscape architecture"),
+    ("716", "Herbaceous plants in landscape architecture"),
+    ("717", "Structures in landscape architecture"),
+    ("718", "Landscape design of cemeteries"),
+    ("719", "Natural landscapes"),
+    ("72", "Architecture"),
+    ("720", "Architecture"),
+    ("721", "Architectural materials & structural elements"),
+    ("722", "Architecture from earliest times to ca. 300"),
+    ("723", "Architecture from ca. 300 to 1399"),
+    ("724", "Architecture from 1400"),
+    ("725", "Public structures"),
+    ("726", "Buildings for religious & related purposes"),
+    ("727", "Buildings for educational & research purposes"),
+    ("728", "Residential & related buildings"),
+    ("729", "Design & decoration of structures & accessories"),
+    ("73", "Sculpture, ceramics & metalwork"),
+    ("730", "Sculpture, ceramics & metalwork"),
+    ("731", "Processes, forms, subjects of sculpture"),
+    (
+        "732",
+        "Sculpture from earliest times to ca. 500, sculpture of nonliterate peoples",
+    ),
+    ("733", "Greek, Etruscan, Roman sculpture"),
+    ("734", "Sculpture from ca. 500 to 1399"),
+    ("735", "Sculpture from 1400"),
+    ("736", "Carving & carvings"),
+    ("737", "Numismatics & sigillography"),
+    ("738", "Ceramic arts"),
+    ("739", "Art metalwork"),
+    ("74", "Graphic arts & decorative arts"),
+    ("740", "Graphic arts & decorative arts"),
+    ("741", "Drawing & drawings"),
+    ("742", "Perspective in drawing"),
+    ("743", "Drawing & drawings by subject"),
+    ("745", "Decorative arts"),
+    ("746", "Textile arts"),
+    ("747", "Interior decoration"),
+    ("748", "Glass"),
+    ("749", "Furniture & accessories"),
+    ("75", "Painting"),
+    ("750", "Painting & paintings"),
+    (
+        "751",
+        "Techniques, procedures, apparatus, equipment, materials, forms",
+    ),
+    ("752", "Color"),
+    ("753", "Symbolism, allegory, mythology, legend"),
+    ("754", "Genre paintings"),
+    ("755", "Religion"),
+    ("757", "Human figures"),
+    (
+        "758",
+        "Nature, architectural subjects & cityscapes, other specific subjects",
+    ),
+    ("759", "History, geographic treatment, biography"),
+    ("76", "Printmaking & prints"),
+    ("760", "Printmaking & prints"),
+    ("761", "Relief processes (Block printing)"),
+    ("763", "Lithographic processes (Planographic processes)"),
+    ("764", "Chromolithography & serigraphy"),
+    ("765", "Metal engraving"),
+    ("766", "Mezzotinting, aquatinting, & related processes"),
+    ("767", "Etching & drypoint"),
+    ("769", "Prints"),
+    ("77", "Photography, computer art, film, video"),
+    ("770", "Photography, computer art, film, video"),
+    (
+        "771",
+        "Techniques, procedures, apparatus, equipment, materials",
+    ),
+    ("772", "Metallic salt processes"),
+    ("773", "Pigment processes of printing"),
+    ("774", "Holography"),
+    ("775", "Digital photography"),
+    ("776", "Computer art (Digital art)"),
+    ("777", "Cinematography & videography"),
+    ("778", "Specific fields & special kinds of photography"),
+    ("779", "Photographs"),
+    ("78", "Music"),
+    ("780", "Music"),
+    ("781", "General principles & musical forms"),
+    ("782", "Vocal music"),
+    ("783", "Music for single voices"),
+    ("784", "Instruments & instrumental ensembles & their music"),
+    ("785", "Ensembles with only one instrument per part"),
+    (
+        "786",
+        "Keyboard, mechanical, electrophonic, percussion instruments",
+    ),
+    ("787", "Stringed instruments (Chordophones)"),
+    ("788", "Wind instruments (Aerophones)"),
+    ("79", "Sports, games & entertainment"),
+    ("790", "Recreational & performing arts"),
+    ("791", "Public performances"),
+    ("792", "Stage presentations"),
+    ("793", "Indoor games & amusements"),
+    ("794", "Indoor games of skill"),
+    ("795", "Games of chance"),
+    ("796", "Athletic & outdoor sports & games"),
+    ("797", "Aquatic & air sports"),
+    ("798", "Equestrian sports & animal racing"),
+    ("799", "Fishing, hunting, shooting"),
+    ("8", "Literature"),
+    ("80", "Literature, rhetoric & criticism"),
+    ("800", "Literature"),
+    ("801", "Philosophy & theory"),
+    ("802", "Miscellany"),
+    ("803", "Dictionaries, encyclopedias, concordances"),
+    ("805", "Serial publications"),
+    ("806", "Organizations & management"),
+    ("807", "Education, research, related topics"),
+    (
+        "808",
+        "Rhetoric & collections of literary texts from more than two literatures",
+    ),
+    (
+        "809",
+        "History, description, critical appraisal of more than two literatures",
+    ),
+    ("81", "American literature in English"),
+    ("810", "American literature in English"),
+    ("811", "American poetry in English"),
+    ("812", "American drama in English"),
+    ("813", "American fiction in English"),
+    ("814", "American essays in English"),
+    ("815", "American speeches in English"),
+    ("816", "American letters in English"),
+    ("817", "American humor & satire in English"),
+    ("818", "American miscellaneous writings in English"),
+    ("82", "English & Old English literatures"),
+    ("820", "English & Old English literatures"),
+    ("821", "English poetry"),
+    ("822", "English drama"),
+    ("823", "English fiction"),
+    ("824", "English essays"),
+    ("825", "English speeches"),
+    ("826", "English letters"),
+    ("827", "English humor & satire"),
+    ("828", "English miscellaneous writings"),
+    ("829", "Old English (Anglo-Saxon) literature"),
+    ("83", "German & related literatures"),
+    ("830", "German & related literatures"),
+    ("831", "German poetry"),
+    ("832", "German drama"),
+    ("833", "German fiction"),
+    ("834", "German essays"),
+    ("835", "German speeches"),
+    ("836", "German letters"),
+    ("837", "German humor & satire"),
+    ("838", "German miscellaneous writings"),
+    ("839", "Other Germanic literatures"),
+    ("84", "French & related literatures"),
+    ("840", "French & related literatures"),
+    ("841", "French poetry"),
+    ("842", "French drama"),
+    ("843", "French fiction"),
+    ("844", "French essays"),
+    ("845", "French speeches"),
+    ("846", "French letters"),
+    ("847", "French humor & satire"),
+    ("848", "French miscellaneous writings"),
+    ("849", "Occitan, Catalan, Franco-Provençal literatures"),
+    ("85", "Italian, Romanian & related literatures"),
+    ("850", "Italian, Romanian & related literatures"),
+    ("851", "Italian poetry"),
+    ("852", "Italian drama"),
+    ("853", "Italian fiction"),
+    ("854", "Italian essays"),
+    ("855", "Italian speeches"),
+    ("856", "Italian letters"),
+    ("857", "Italian humor & satire"),
+    ("858", "Italian miscellaneous writings"),
+    ("859", "Romanian & related literatures"),
+    ("86", "Spanish, Portuguese, Galician literatures"),
+    ("860", "Spanish, Portuguese, Galician literatures"),
+    ("861", "Spanish poetry"),
+    ("862", "Spanish drama"),
+    ("863", "Spanish fiction"),
+    ("864", "Spanish essays"),
+    ("865", "Spanish speeches"),
+    ("866", "Spanish letters"),
+    ("867", "Spanish humor & satire"),
+    ("868", "Spanish miscellaneous writings"),
+    ("869", "Portuguese & Galician literatures"),
+    ("87", "Latin & Italic literatures"),
+    ("870", "Latin & Italic literatures"),
+    ("871", "Latin poetry"),
+    ("872", "Latin dramatic poetry & drama"),
+    ("873", "Latin epic poetry & fiction"),
+    ("874", "Latin lyric poetry"),
+    ("875", "Latin speeches"),
+    ("876", "Latin letters"),
+    ("877", "Latin humor & satire"),
+    ("878", "Latin miscellaneous writings"),
+    ("879", "Literatures of other Italic languages"),
+    ("88", "Classical & modern Greek literatures"),
+    ("880", "Classical Greek & related literatures"),
+    ("881", "Classical Greek poetry"),
+    ("882", "Classical Greek dramatic poetry & drama"),
+    ("883", "Classical Greek epic poetry & fiction"),
+    ("884", "Classical Greek lyric poetry"),
+    ("885", "Classical Greek speeches"),
+    ("886", "Classical Greek letters"),
+    ("887", "Classical Greek humor & satire"),
+    ("888", "Classical Greek miscellaneous writings"),
+    ("889", "Modern Greek literature"),
+    ("89", "Other literatures"),
+    ("890", "Other literatures"),
+    ("891", "East Indo-European & Celtic literatures"),
+    ("892", "Afro-Asiatic literatures"),
+    ("893", "Non-Semitic Afro-Asiatic literatures"),
+    ("894", "Altaic, Uralic, Hyperborean & Dravidian literatures"),
+    ("895", "Literatures of East & Southeast Asia"),
+    ("896", "African literatures"),
+    ("897", "North American native literatures"),
+    ("898", "South American native literatures"),
+    ("899", "Austronesian & other literatures"),
+    ("9", "History & geography"),
+    ("90", "History"),
+    ("900", "History & geography"),
+    ("901", "Philosophy & theory of history"),
+    ("902", "Miscellany of history"),
+    (
+        "903",
+        "Dictionaries, encyclopedias, concordances of history",
+    ),
+    ("904", "Collected accounts of events"),
+    ("905", "Serial publications of history"),
+    ("906", "Organizations & management of history"),
+    ("907", "Education, research & related topics of history"),
+    ("908", "History with respect to groups of people"),
+    ("909", "World history"),
+    ("91", "Geography & travel"),
+    ("910", "Geography & travel"),
+    ("911", "Historical geography"),
+    (
+        "912",
+        "Graphic representations of surface of earth & of extraterrestrial worlds",
+    ),
+    ("913", "Geography of & travel in ancient world"),
+    ("914", "Geography of & travel in Europe"),
+    ("915", "Geography of & travel in Asia"),
+    ("916", "Geography of & travel in Africa"),
+    ("917", "Geography of & travel in North America"),
+    ("918", "Geography of & travel in South America"),
+    (
+        "919",
+        "Geography of & travel in Australasia, Pacific Ocean islands, Atlantic Ocean islands, Arctic islands, Antarctica & on extraterrestrial worlds",
+    ),
+    ("92", "Biography & genealogy"),
+    ("920", "Biography, genealogy, insignia"),
+    ("929", "Genealogy, names, insignia"),
+    ("93", "History of ancient world (to ca. 499)"),
+    ("930", "History of ancient world to ca. 499"),
+    ("931", "China to 420"),
+    ("932", "Egypt to 640"),
+    ("933", "Palestine to 70"),
+    ("934", "South Asia to 647"),
+    ("935", "Mesopotamia & Iranian Plateau to 637"),
+    ("936", "Europe north & west of Italy to ca. 499"),
+    (
+        "937",
+        "Italian Peninsula to 476 & adjacent territories to 476",
+    ),
+    ("938", "Greece to 323"),
+    ("939", "Other parts of ancient world"),
+    ("94", "History of Europe"),
+    ("940", "History of Europe"),
+    ("941", "British Isles"),
+    ("942", "England & Wales"),
+    ("943", "Germany & neighboring central European countries"),
+    ("944", "France & Monaco"),
+    ("945", "Italy, San Marino, Vatican City, Malta"),
+    ("946", "Spain, Andorra, Gibraltar, Portugal"),
+    ("947", "Russia & neighboring east European countries"),
+    ("948", "Scandinavia & Finland"),
+    ("949", "Other parts of Europe"),
+    ("95", "History of Asia"),
+    ("950", "History of Asia"),
+    ("951", "China & adjacent areas"),
+    ("952", "Japan"),
+    ("953", "Arabian Peninsula & adjacent areas"),
+    ("954", "India & neighboring south Asian countries"),
+    ("955", "Iran"),
+    ("956", "Middle East (Near East)"),
+    ("957", "Siberia (Asiatic Russia)"),
+    ("958", "Central Asia"),
+    ("959", "Southeast Asia"),
+    ("96", "History of Africa"),
+    ("960", "History of Africa"),
+    ("961", "Tunisia & Libya"),
+    ("962", "Egypt, Sudan, South Sudan"),
+    ("963", "Ethiopia & Eritrea"),
+    (
+        "964",
+        "Morocco, Ceuta, Melilla, Western Sahara, Canary Islands",
+    ),
+    ("965", "Algeria"),
+    ("966", "West Africa & offshore islands"),
+    ("967", "Central Africa & offshore islands"),
+    (
+        "968",
+        "Republic of South Africa & neighboring southern African countries",
+    ),
+    ("969", "South Indian Ocean islands"),
+    ("97", "History of North America"),
+    ("970", "History of North America"),
+    ("971", "Canada"),
+    ("972", "Mexico, Central America, West Indies, Bermuda"),
+    ("973", "United States"),
+    ("974", "Northeastern United States"),
+    ("975", "Southeastern United States"),
+    ("976", "South central United States"),
+    ("977", "North central United States"),
+    ("978", "Western United States"),
+    ("979", "Great Basin & Pacific Slope region of United States"),
+    ("98", "History of South America"),
+    ("980", "History of South America"),
+    ("981", "Brazil"),
+    ("982", "Argentina"),
+    ("983", "Chile"),
+    ("984", "Bolivia"),
+    ("985", "Peru"),
+    ("986", "Colombia & Ecuador"),
+    ("987", "Venezuela"),
+    ("988", "Guiana"),
+    ("989", "Paraguay & Uruguay"),
+    ("99", "History of other areas"),
+    (
+        "990",
+        "History of Australasia, Pacific Ocean islands, Atlantic Ocean islands, Arctic islands, Antarctica, extraterrestrial worlds",
+    ),
+    ("993", "New Zealand"),
+    ("994", "Australia"),
+    ("995", "New Guinea & neighboring countries of Melanesia"),
+    ("996", "Polynesia & other Pacific Ocean islands"),
+    ("997", "Atlantic Ocean islands"),
+    ("998", "Arctic islands & Antarctica"),
+    ("999", "Extraterrestrial worlds"),
+];
@@ -0,0 +1,1050 @@
+// Library Domain Layer - 日本十進分類法（NDC）の分類表
+
+/// 日本十進分類法（新訂10版）の類・綱・目の分類記号と名称（分類記号の順。使われていない記号は含まない）
+pub(super) const ENTRIES: &[(&str, &str)] = &[
+    ("0", "総記"),
+    ("00", "総記"),
+    ("000", "総記"),
+    ("002", "知識．学問．学術"),
+    ("007", "情報学．情報科学"),
+    ("01", "図書館．図書館情報学"),
+    ("010", "図書館．図書館情報学"),
+    ("011", "図書館政策．図書館行財政"),
+    ("012", "図書館建築．図書館設備"),
+    ("013", "図書館経営・管理"),
+    ("014", "情報資源の収集・組織化・保存"),
+    ("015", "図書館サービス．図書館活動"),
+    ("016", "各種の図書館"),
+    ("017", "学校図書館"),
+    ("018", "専門図書館"),
+    ("019", "読書．読書法"),
+    ("02", "図書．書誌学"),
+    ("020", "図書．書誌学"),
+    ("021", "著作．編集"),
+    ("022", "写本．刊本．造本"),
+    ("023", "出版"),
+    ("024", "図書の販売"),
+    ("025", "一般書誌．全国書誌"),
+    ("026", "稀覯本に関する書誌"),
+    ("027", "特種目録"),
+    ("028", "選定図書目録．参考図書目録"),
+    ("029", "蔵書目録．総合目録"),
+    ("03", "百科事典．用語索引"),
+    ("030", "百科事典"),
+    ("031", "日本語"),
+    ("032", "中国語"),
+    ("033", "英語"),
+    ("034", "ドイツ語"),
+    ("035", "フランス語"),
+    ("036", "スペイン語"),
+    ("037", "イタリア語"),
+    ("038", "ロシア語"),
+    ("039", "用語索引"),
+    ("04", "一般論文集．一般講演集．雑著"),
+    ("040", "一般論文集．一般講演集"),
+    ("041", "日本語"),
+    ("042", "中国語"),
+    ("043", "英語"),
+    ("044", "ドイツ語"),
+    ("045", "フランス語"),
+    ("046", "スペイン語"),
+    ("047", "イタリア語"),
+    ("048", "ロシア語"),
+    ("049", "雑著"),
+    ("05", "逐次刊行物．一般年鑑"),
+    ("050", "逐次刊行物"),
+    ("051", "日本語"),
+    ("052", "中国語"),
+    ("053", "英語"),
+    ("054", "ドイツ語"),
+    ("055", "フランス語"),
+    ("056", "スペイン語"),
+    ("057", "イタリア語"),
+    ("058", "ロシア語"),
+    ("059", "一般年鑑"),
+    ("06", "団体．博物館"),
+    ("060", "団体"),
+    ("061", "学術・研究機関"),
+    ("063", "文化交流機関"),
+    ("065", "親睦団体．その他の団体"),
+    ("069", "博物館"),
+    ("07", "ジャーナリズム．新聞"),
+    ("070", "ジャーナリズム．新聞"),
+    ("071", "日本"),
+    ("072", "アジア"),
+    ("073", "ヨーロッパ"),
+    ("074", "アフリカ"),
+    ("075", "北アメリカ"),
+    ("076", "南アメリカ"),
+    ("077", "オセアニア．両極地方"),
+    ("08", "叢書．全集．選集"),
+    ("080", "叢書．全集．選集"),
+    ("081", "日本語"),
+    ("082", "中国語"),
+    ("083", "英語"),
+    ("084", "ドイツ語"),
+    ("085", "フランス語"),
+    ("086", "スペイン語"),
+    ("087", "イタリア語"),
+    ("088", "ロシア語"),
+    ("089", "その他の諸言語"),
+    ("09", "貴重書．郷土資料．その他の特別コレクション"),
+    ("090", "貴重書．郷土資料．その他の特別コレクション"),
+    ("1", "哲学"),
+    ("10", "哲学"),
+    ("100", "哲学"),
+    ("101", "哲学理論"),
+    ("102", "哲学史"),
+    ("103", "参考図書［レファレンスブック］"),
+    ("104", "論文集．評論集．講演集"),
+    ("105", "逐次刊行物"),
+    ("106", "団体"),
+    ("107", "研究法．指導法．哲学教育"),
+    ("108", "叢書．全集．選集"),
+    ("11", "哲学各論"),
+    ("110", "哲学各論"),
+    ("111", "形而上学．存在論"),
+    ("112", "自然哲学．宇宙論"),
+    ("113", "人生観．世界観"),
+    ("114", "人間学"),
+    ("115", "認識論"),
+    ("116", "論理学．弁証法．方法論"),
+    ("117", "価値哲学"),
+    ("118", "文化哲学．技術哲学"),
+    ("12", "東洋思想"),
+    ("120", "東洋思想"),
+    ("121", "日本思想"),
+    ("122", "中国思想．中国哲学"),
+    ("123", "経書"),
+    ("124", "先秦思想．諸子百家"),
+    ("125", "中世思想．近代思想"),
+    ("126", "インド哲学．バラモン教"),
+    ("129", "その他のアジア・アラブ哲学"),
+    ("13", "西洋哲学"),
+    ("130", "西洋哲学"),
+    ("131", "古代哲学"),
+    ("132", "中世哲学"),
+    ("133", "近代哲学"),
+    ("134", "ドイツ・オーストリア哲学"),
+    ("135", "フランス・オランダ哲学"),
+    ("136", "スペイン・ポルトガル哲学"),
+    ("137", "イタリア哲学"),
+    ("138", "ロシア哲学"),
+    ("139", "その他の哲学"),
+    ("14", "心理学"),
+    ("140", "心理学"),
+    ("141", "普通心理学．心理各論"),
+    ("143", "発達心理学"),
+    ("145", "異常心理学"),
+    ("146", "臨床心理学．精神分析学"),
+    ("147", "超心理学．心霊研究"),
+    ("148", "相法．易占"),
+    ("15", "倫理学．道徳"),
+    ("150", "倫理学．道徳"),
+    ("151", "倫理各論"),
+    ("152", "家庭倫理．性倫理"),
+    ("153", "職業倫理"),
+    ("154", "社会倫理［社会道徳］"),
+    ("155", "国体論．詔勅"),
+    ("156", "武士道"),
+    ("157", "報徳教．石門心学"),
+    ("158", "その他の特定主題"),
+    ("159", "人生訓．教訓"),
+    ("16", "宗教"),
+    ("160", "宗教"),
+    ("161", "宗教学．宗教思想"),
+    ("162", "宗教史・事情"),
+    ("163", "原始宗教．宗教民族学"),
+    ("164", "神話．神話学"),
+    ("165", "比較宗教"),
+    ("166", "道教"),
+    ("167", "イスラム"),
+    ("168", "ヒンズー教．ジャイナ教"),
+    ("169", "その他の宗教．新興宗教"),
+    ("17", "神道"),
+    ("170", "神道"),
+    ("171", "神道思想．神道説"),
+    ("172", "神祇・神道史"),
+    ("173", "神典"),
+    ("174", "信仰録．説教集"),
+    ("175", "神社．神職"),
+    ("176", "祭祀"),
+    ("177", "布教．伝道"),
+    ("178", "各教派．教派神道"),
+    ("18", "仏教"),
+    ("180", "仏教"),
+    ("181", "仏教教理．仏教哲学"),
+    ("182", "仏教史"),
+    ("183", "経典"),
+    ("184", "法話・説教集"),
+    ("185", "寺院．僧職"),
+    ("186", "仏会"),
+    ("187", "布教．伝道"),
+    ("188", "各宗"),
+    ("19", "キリスト教．ユダヤ教"),
+    ("190", "キリスト教"),
+    ("191", "教義．キリスト教神学"),
+    ("192", "キリスト教史．迫害史"),
+    ("193", "聖書"),
+    ("194", "信仰録．説教集"),
+    ("195", "教会．聖職"),
+    ("196", "典礼．祭式．礼拝"),
+    ("197", "布教．伝道"),
+    ("198", "各教派．教会史"),
+    ("199", "ユダヤ教"),
+    ("2", "歴史"),
+    ("20", "歴史．世界史．文化史"),
+    ("200", "歴史"),
+    ("201", "歴史学"),
+    ("202", "歴史補助学"),
+    ("203", "参考図書［レファレンスブック］"),
+    ("204", "論文集．評論集．講演集"),
+    ("205", "逐次刊行物"),
+    ("206", "団体"),
+    ("207", "研究法．指導法．歴史教育"),
+    ("208", "叢書．全集．選集"),
+    ("209", "世界史．文化史"),
+    ("21", "日本史"),
+    ("210", "日本史"),
+    ("211", "北海道地方"),
+    ("212", "東北地方"),
+    ("213", "関東地方"),
+    ("214", "北陸地方"),
+    ("215", "中部地方"),
+    ("216", "近畿地方"),
+    ("217", "中国地方"),
+    ("218", "四国地方"),
+    ("219", "九州地方"),
+    ("22", "アジア史．東洋史"),
+    ("220", "アジア史．東洋史"),
+    ("221", "朝鮮"),
+    ("222", "中国"),
+    ("223", "東南アジア"),
+    ("224", "インドネシア"),
+    ("225", "インド"),
+    ("227", "西南アジア．中東［中近東］"),
+    ("228", "アラブ諸国"),
+    ("229", "アジア・ロシア"),
+    ("23", "ヨーロッパ史．西洋史"),
+    ("230", "ヨーロッパ史．西洋史"),
+    ("231", "古代ギリシア"),
+    ("232", "古代ローマ"),
+    ("233", "イギリス．英国"),
+    ("234", "ドイツ．中欧"),
+    ("235", "フランス"),
+    ("236", "スペイン［イスパニア］"),
+    ("237", "イタリア"),
+    ("238", "ロシア"),
+    ("239", "バルカン諸国"),
+    ("24", "アフリカ史"),
+    ("240", "アフリカ史"),
+    ("241", "北アフリカ"),
+    ("242", "エジプト"),
+    ("243", "マグレブ諸国"),
+    ("244", "西アフリカ"),
+    ("245", "東アフリカ"),
+    ("248", "南アフリカ"),
+    ("249", "インド洋のアフリカ諸島"),
+    ("25", "北アメリカ史"),
+    ("250", "北アメリカ史"),
+    ("251", "カナダ"),
+    ("253", "アメリカ合衆国"),
+    ("255", "ラテンアメリカ［中南米］"),
+    ("256", "メキシコ"),
+    ("257", "中央アメリカ［中米諸国］"),
+    ("259", "西インド諸島"),
+    ("26", "南アメリカ史"),
+    ("260", "南アメリカ史"),
+    ("261", "北部諸国［カリブ沿海諸国］"),
+    ("262", "ブラジル"),
+    ("263", "パラグアイ"),
+    ("264", "ウルグアイ"),
+    ("265", "アルゼンチン"),
+    ("266", "チリ"),
+    ("267", "ボリビア"),
+    ("268", "ペルー"),
+    ("27", "オセアニア史．両極地方史"),
+    ("270", "オセアニア史．両極地方史"),
+    ("271", "オーストラリア"),
+    ("272", "ニュージーランド"),
+    ("273", "メラネシア"),
+    ("274", "ミクロネシア"),
+    ("275", "ポリネシア"),
+    ("276", "ハワイ"),
+    ("277", "両極地方"),
+    ("278", "北極．北極地方"),
+    ("279", "南極．南極地方"),
+    ("28", "伝記"),
+    ("280", "伝記"),
+    ("281", "日本"),
+    ("282", "アジア"),
+    ("283", "ヨーロッパ"),
+    ("284", "アフリカ"),
+    ("285", "北アメリカ"),
+    ("286", "南アメリカ"),
+    ("287", "オセアニア．両極地方"),
+    ("288", "系譜．家史．皇室"),
+    ("289", "個人伝記"),
+    ("29", "地理．地誌．紀行"),
+    ("290", "地理．地誌．紀行"),
+    ("291", "日本"),
+    ("292", "アジア"),
+    ("293", "ヨーロッパ"),
+    ("294", "アフリカ"),
+    ("295", "北アメリカ"),
+    ("296", "南アメリカ"),
+    ("297", "オセアニア．両極地方"),
+    ("299", "海洋"),
+    ("3", "社会科学"),
+    ("30", "社会科学"),
+    ("300", "社会科学"),
+    ("301", "理論．方法論"),
+    ("302", "政治・経済・社会・文化事情"),
+    ("303", "参考図書［レファレンスブック］"),
+    ("304", "論文集．評論集．講演集"),
+    ("305", "逐次刊行物"),
+    ("306", "団体"),
+    ("307", "研究法．指導法．社会科学教育"),
+    ("308", "叢書．全集．選集"),
+    ("309", "社会思想"),
+    ("31", "政治"),
+    ("310", "政治"),
+    ("311", "政治学．政治思想"),
+    ("312", "政治史・事情"),
+    ("313", "国家の形態．政治体制"),
+    ("314", "議会"),
+    ("315", "政党．政治結社"),
+    ("316", "国家と個人・宗教・民族"),
+    ("317", "行政"),
+    ("318", "地方自治．地方行政"),
+    ("319", "外交．国際問題"),
+    ("32", "法律"),
+    ("320", "法律"),
+    ("321", "法学"),
+    ("322", "法制史"),
+    ("323", "憲法"),
+    ("324", "民法．民事法"),
+    ("325", "商法．商事法"),
+    ("326", "刑法．刑事法"),
+    ("327", "司法．訴訟手続法"),
+    ("328", "諸法"),
+    ("329", "国際法"),
+    ("33", "経済"),
+    ("330", "経済"),
+    ("331", "経済学．経済思想"),
+    ("332", "経済史・事情．経済体制"),
+    ("333", "経済政策．国際経済"),
+    ("334", "人口．土地．資源"),
+    ("335", "企業．経営"),
+    ("336", "経営管理"),
+    ("337", "貨幣．通貨"),
+    ("338", "金融．銀行．信託"),
+    ("339", "保険"),
+    ("34", "財政"),
+    ("340", "財政"),
+    ("341", "財政学．財政思想"),
+    ("342", "財政史・事情"),
+    ("343", "財政政策．財務行政"),
+    ("344", "予算．決算"),
+    ("345", "租税"),
+    ("347", "公債．国債"),
+    ("348", "専売．国有財産"),
+    ("349", "地方財政"),
+    ("35", "統計"),
+    ("350", "統計"),
+    ("351", "日本"),
+    ("352", "アジア"),
+    ("353", "ヨーロッパ"),
+    ("354", "アフリカ"),
+    ("355", "北アメリカ"),
+    ("356", "南アメリカ"),
+    ("357", "オセアニア．両極地方"),
+    ("358", "人口統計．国勢調査"),
+    ("36", "社会"),
+    ("360", "社会"),
+    ("361", "社会学"),
+    ("362", "社会史．社会体制"),
+    ("364", "社会保障"),
+    ("365", "生活・消費者問題"),
+    ("366", "労働経済．労働問題"),
+    ("367", "家族問題．男性・女性問題．老人問題"),
+    ("368", "社会病理"),
+    ("369", "社会福祉"),
+    ("37", "教育"),
+    ("370", "教育"),
+    ("371", "教育学．教育思想"),
+    ("372", "教育史・事情"),
+    ("373", "教育政策．教育制度．教育行財政"),
+    ("374", "学校経営・管理．学校保健"),
+    ("375", "教育課程．学習指導．教科別教育"),
+    ("376", "幼児・初等・中等教育"),
+    ("377", "大学．高等・専門教育．学術行政"),
+    ("378", "障害児教育［特別支援教育］"),
+    ("379", "社会教育"),
+    ("38", "風俗習慣．民俗学．民族学"),
+    ("380", "風俗習慣．民俗学．民族学"),
+    ("382", "風俗史．民俗誌．民族誌"),
+    ("383", "衣食住の習俗"),
+    ("384", "社会・家庭生活の習俗"),
+    ("385", "通過儀礼．冠婚葬祭"),
+    ("386", "年中行事．祭礼"),
+    ("387", "民間信仰．迷信［俗信］"),
+    ("388", "伝説．民話［昔話］"),
+    ("389", "民族学．文化人類学"),
+    ("39", "国防．軍事"),
+    ("390", "国防．軍事"),
+    ("391", "戦争．戦略．戦術"),
+    ("392", "国防史・事情．軍事史・事情"),
+    ("393", "国防政策・行政・法令"),
+    ("394", "軍事医学．兵食"),
+    ("395", "軍事施設．軍需品"),
+    ("396", "陸軍"),
+    ("397", "海軍"),
+    ("398", "空軍"),
+    ("399", "古代兵法．軍学"),
+    ("4", "自然科学"),
+    ("40", "自然科学"),
+    ("400", "自然科学"),
+    ("401", "科学理論．科学哲学"),
+    ("402", "科学史・事情"),
+    ("403", "参考図書［レファレンスブック］"),
+    ("404", "論文集．評論集．講演集"),
+    ("405", "逐次刊行物"),
+    ("406", "団体"),
+    ("407", "研究法．指導法．科学教育"),
+    ("408", "叢書．全集．選集"),
+    ("409", "科学技術政策．科学技術行政"),
+    ("41", "数学"),
+    ("410", "数学"),
+    ("411", "代数学"),
+    ("412", "数論［整数論］"),
+    ("413", "解析学"),
+    ("414", "幾何学"),
+    ("415", "位相数学"),
+    ("417", "確率論．数理統計学"),
+    ("418", "計算法"),
+    ("419", "和算．中国算法"),
+    ("42", "物理学"),
+    ("420", "物理学"),
+    ("421", "理論物理学"),
+    ("423", "力学"),
+    ("424", "振動学．音響学"),
+    ("425", "光学"),
+    ("426", "熱学"),
+    ("427", "電磁気学"),
+    ("428", "物性物理学"),
+    ("429", "原子物理学"),
+    ("43", "化学"),
+    ("430", "化学"),
+    ("431", "物理化学．理論化学"),
+    ("432", "実験化学［化学実験法］"),
+    ("433", "分析化学［化学分析］"),
+    ("434", "合成化学［化学合成］"),
+    ("435", "無機化学"),
+    ("436", "金属元素とその化合物"),
+    ("437", "有機化学"),
+    ("438", "環式化合物の化学"),
+    ("439", "天然物質の化学"),
+    ("44", "天文学．宇宙科学"),
+    ("440", "天文学．宇宙科学"),
+    ("441", "理論天文学．数理天文学"),
+    ("442", "実地天文学．天体観測法"),
+    ("443", "恒星．恒星天文学"),
+    ("444", "太陽．太陽物理学"),
+    ("445", "惑星．衛星"),
+    ("446", "月"),
+    ("447", "彗星．流星"),
+    ("448", "地球．天文地理学"),
+    ("449", "時法．暦学"),
+    ("45", "地球科学．地学"),
+    ("450", "地球科学．地学"),
+    ("451", "気象学"),
+    ("452", "海洋学"),
+    ("453", "地震学"),
+    ("454", "地形学"),
+    ("455", "地質学"),
+    ("456", "地史学．層位学"),
+    ("457", "古生物学．化石"),
+    ("458", "岩石学"),
+    ("459", "鉱物学"),
+    ("46", "生物科学．一般生物学"),
+    ("460", "生物科学．一般生物学"),
+    ("461", "理論生物学．生命論"),
+    ("462", "生物地理．生物誌"),
+    ("463", "細胞学"),
+    ("464", "生化学"),
+    ("465", "微生物学"),
+    ("467", "遺伝学"),
+    ("468", "生態学"),
+    ("469", "人類学"),
+    ("47", "植物学"),
+    ("470", "植物学"),
+    ("471", "一般植物学"),
+    ("472", "植物地理．植物誌"),
+    ("473", "葉状植物"),
+    ("474", "藻類．菌類"),
+    ("475", "コケ植物［蘚苔類］"),
+    ("476", "シダ植物"),
+    ("477", "種子植物"),
+    ("478", "裸子植物"),
+    ("479", "被子植物"),
+    ("48", "動物学"),
+    ("480", "動物学"),
+    ("481", "一般動物学"),
+    ("482", "動物地理．動物誌"),
+    ("483", "無脊椎動物"),
+    ("484", "軟体動物．貝類学"),
+    ("485", "節足動物"),
+    ("486", "昆虫類"),
+    ("487", "脊椎動物"),
+    ("488", "鳥類"),
+    ("489", "哺乳類"),
+    ("49", "医学．薬学"),
+    ("490", "医学"),
+    ("491", "基礎医学"),
+    ("492", "臨床医学．診断・治療"),
+    ("493", "内科学"),
+    ("494", "外科学"),
+    ("495", "婦人科学．産科学"),
+    ("496", "眼科学．耳鼻咽喉科学"),
+    ("497", "歯科学"),
+    ("498", "衛生学．公衆衛生．予防医学"),
+    ("499", "薬学"),
+    ("5", "技術"),
+    ("50", "技術．工学"),
+    ("500", "技術．工学"),
+    ("501", "工業基礎学"),
+    ("502", "技術史．工学史"),
+    ("503", "参考図書［レファレンスブック］"),
+    ("504", "論文集．評論集．講演集"),
+    ("505", "逐次刊行物"),
+    ("506", "団体"),
+    ("507", "研究法．指導法．技術教育"),
+    ("508", "叢書．全集．選集"),
+    ("509", "工業．工業経済"),
+    ("51", "建設工学．土木工学"),
+    ("510", "建設工学．土木工学"),
+    ("511", "土木力学．建設材料"),
+    ("512", "測量"),
+    ("513", "土木設計・施工法"),
+    ("514", "道路工学"),
+    ("515", "橋梁工学"),
+    ("516", "鉄道工学"),
+    ("517", "河海工学．河川工学"),
+    ("518", "衛生工学．都市工学"),
+    ("519", "環境工学．公害"),
+    ("52", "建築学"),
+    ("520", "建築学"),
+    ("521", "日本の建築"),
+    ("522", "東洋の建築．アジアの建築"),
+    ("523", "西洋の建築．その他の様式の建築"),
+    ("524", "建築構造"),
+    ("525", "建築計画・施工"),
+    ("526", "各種の建築"),
+    ("527", "住宅建築"),
+    ("528", "建築設備．設備工学"),
+    ("529", "建築意匠・装飾"),
+    ("53", "機械工学．原子力工学"),
+    ("530", "機械工学"),
+    ("531", "機械力学・材料・設計"),
+    ("532", "機械工作．工作機械"),
+    ("533", "熱機関．熱工学"),
+    ("534", "流体機械．流体工学"),
+    ("535", "精密機器．光学機器"),
+    ("536", "運輸工学．車両．運搬機械"),
+    ("537", "自動車工学"),
+    ("538", "航空工学．宇宙工学"),
+    ("539", "原子力工学"),
+    ("54", "電気工学"),
+    ("540", "電気工学"),
+    ("541", "電気回路・計測・材料"),
+    ("542", "電気機器"),
+    ("543", "発電"),
+    ("544", "送電．変電．配電"),
+    ("545", "電灯．照明．電熱"),
+    ("546", "電気鉄道"),
+    ("547", "通信工学．電気通信"),
+    ("548", "情報工学"),
+    ("549", "電子工学"),
+    ("55", "海洋工学．船舶工学．兵器．軍事工学"),
+    ("550", "海洋工学．船舶工学"),
+    ("551", "理論造船学"),
+    ("552", "船体構造・材料・施工"),
+    ("553", "船体艤装．船舶設備"),
+    ("554", "舶用機関［造機］"),
+    ("555", "船舶修理．保守"),
+    ("556", "各種の船舶・艦艇"),
+    ("557", "航海．航海学"),
+    ("558", "海洋開発"),
+    ("559", "兵器．軍事工学"),
+    ("56", "金属工学．鉱山工学"),
+    ("560", "金属工学．鉱山工学"),
+    ("561", "採鉱．選鉱"),
+    ("562", "各種の金属鉱床・採掘"),
+    ("563", "冶金．合金"),
+    ("564", "鉄鋼"),
+    ("565", "非鉄金属"),
+    ("566", "金属加工．製造冶金"),
+    ("567", "石炭"),
+    ("568", "石油"),
+    ("569", "非金属鉱物．土石採取業"),
+    ("57", "化学工業"),
+    ("570", "化学工業"),
+    ("571", "化学工学．化学機器"),
+    ("572", "電気化学工業"),
+    ("573", "セラミックス．窯業．珪酸塩化学工業"),
+    ("574", "化学薬品"),
+    ("575", "燃料．爆発物"),
+    ("576", "油脂類"),
+    ("577", "染料"),
+    ("578", "高分子化学工業"),
+    ("579", "その他の化学工業"),
+    ("58", "製造工業"),
+    ("580", "製造工業"),
+    ("581", "金属製品"),
+    ("582", "事務機器．家庭機器．楽器"),
+    ("583", "木工業．木製品"),
+    ("584", "皮革工業．皮革製品"),
+    ("585", "パルプ・製紙工業"),
+    ("586", "繊維工学"),
+    ("587", "染色加工．染色業"),
+    ("588", "食品工業"),
+    ("589", "その他の雑工業"),
+    ("59", "家政学．生活科学"),
+    ("590", "家政学．生活科学"),
+    ("591", "家庭経済・経営"),
+    ("592", "家庭理工学"),
+    ("593", "衣服．裁縫"),
+    ("594", "手芸"),
+    ("595", "理容．美容"),
+    ("596", "食品．料理"),
+    ("597", "住居．家具調度"),
+    ("598", "家庭衛生"),
+    ("599", "育児"),
+    ("6", "産業"),
+    ("60", "産業"),
+    ("600", "産業"),
+    ("601", "産業政策・行政．総合開発"),
+    ("602", "産業史・事情．物産誌"),
+    ("603", "参考図書［レファレンスブック］"),
+    ("604", "論文集．評論集．講演集"),
+    ("605", "逐次刊行物"),
+    ("606", "団体"),
+    ("607", "研究法．指導法．産業教育"),
+    ("608", "叢書．全集．選集"),
+    ("609", "度量衡．計量法"),
+    ("61", "農業"),
+    ("610", "農業"),
+    ("611", "農業経済・行政・経営"),
+    ("612", "農業史・事情"),
+    ("613", "農業基礎学"),
+    ("614", "農業工学"),
+    ("615", "作物栽培．作物学"),
+    ("616", "食用作物"),
+    ("617", "工芸作物"),
+    ("618", "繊維作物"),
+    ("619", "農産物製造・加工"),
+    ("62", "園芸．造園"),
+    ("620", "園芸"),
+    ("621", "園芸経済・行政・経営"),
+    ("622", "園芸史・事情"),
+    ("623", "園芸植物学．病虫害"),
+    ("624", "温室．温床．園芸用具"),
+    ("625", "果樹園芸"),
+    ("626", "蔬菜園芸"),
+    ("627", "花卉園芸［草花］"),
+    ("628", "園芸利用"),
+    ("629", "造園"),
+    ("63", "蚕糸業"),
+    ("630", "蚕糸業"),
+    ("631", "蚕糸経済・行政・経営"),
+    ("632", "蚕糸業史・事情"),
+    ("633", "蚕学．蚕業基礎学"),
+    ("634", "蚕種"),
+    ("635", "飼育法"),
+    ("636", "くわ．栽桑"),
+    ("637", "蚕室．蚕具"),
+    ("638", "まゆ"),
+    ("639", "製糸．生糸．蚕糸利用"),
+    ("64", "畜産業．獣医学"),
+    ("640", "畜産業"),
+    ("641", "畜産経済・行政・経営"),
+    ("642", "畜産史・事情"),
+    ("643", "家畜の繁殖．家畜飼料"),
+    ("644", "家畜の管理．畜舎．用具"),
+    ("645", "家畜．畜産動物．愛玩動物"),
+    ("646", "家禽"),
+    ("647", "みつばち．昆虫"),
+    ("648", "畜産製造．畜産物"),
+    ("649", "獣医学"),
+    ("65", "林業．狩猟"),
+    ("650", "林業"),
+    ("651", "林業経済・行政・経営"),
+    ("652", "森林史．林業史・事情"),
+    ("653", "森林立地．造林"),
+    ("654", "森林保護"),
+    ("655", "森林施業"),
+    ("656", "森林工学"),
+    ("657", "森林利用．林産物．木材学"),
+    ("658", "林産製造"),
+    ("659", "狩猟"),
+    ("66", "水産業"),
+    ("660", "水産業"),
+    ("661", "水産経済・行政・経営"),
+    ("662", "水産業および漁業史・事情"),
+    ("663", "水産基礎学"),
+    ("664", "漁労．漁業各論"),
+    ("665", "漁船．漁具"),
+    ("666", "水産増殖．養殖業"),
+    ("667", "水産製造．水産食品"),
+    ("668", "水産物利用．水産利用工業"),
+    ("669", "製塩．塩業"),
+    ("67", "商業"),
+    ("670", "商業"),
+    ("671", "商業政策・行政"),
+    ("672", "商業史・事情"),
+    ("673", "商業経営．商店"),
+    ("674", "広告．宣伝"),
+    ("675", "マーケティング"),
+    ("676", "取引所"),
+    ("678", "貿易"),
+    ("68", "運輸．交通．観光事業"),
+    ("680", "運輸．交通"),
+    ("681", "交通政策・行政・経営"),
+    ("682", "交通史・事情"),
+    ("683", "海運"),
+    ("684", "内陸水運．運河交通"),
+    ("685", "陸運．道路運輸"),
+    ("686", "鉄道運輸"),
+    ("687", "航空運輸"),
+    ("688", "倉庫業"),
+    ("689", "観光事業"),
+    ("69", "通信事業"),
+    ("690", "通信事業"),
+    ("691", "通信政策・行政・法令"),
+    ("692", "通信事業史・事情"),
+    ("693", "郵便．郵政事業"),
+    ("694", "電気通信事業"),
+    ("699", "放送事業"),
+    ("7", "芸術"),
+    ("70", "芸術．美術"),
+    ("700", "芸術．美術"),
+    ("701", "芸術理論．美学"),
+    ("702", "芸術史．美術史"),
+    ("703", "参考図書［レファレンスブック］"),
+    ("704", "論文集．評論集．講演集"),
+    ("705", "逐次刊行物"),
+    ("706", "団体"),
+    ("707", "研究法．指導法．芸術教育"),
+    ("708", "叢書．全集．選集"),
+    ("709", "芸術政策．文化財"),
+    ("71", "彫刻．オブジェ"),
+    ("710", "彫刻"),
+    ("711", "彫塑材料・技法"),
+    ("712", "彫刻史．各国の彫刻"),
+    ("713", "木彫"),
+    ("714", "石彫"),
+    ("715", "金属彫刻．鋳造"),
+    ("717", "粘土彫刻．塑造"),
+    ("718", "仏像"),
+    ("719", "オブジェ"),
+    ("72", "絵画．書道"),
+    ("720", "絵画"),
+    ("721", "日本画"),
+    ("722", "東洋画"),
+    ("723", "洋画"),
+    ("724", "絵画材料・技法"),
+    ("725", "素描．描画"),
+    ("726", "漫画．挿絵．児童画"),
+    ("727", "グラフィックデザイン．図案"),
+    ("728", "書．書道"),
+    ("73", "版画．印章．篆刻．印譜"),
+    ("730", "版画"),
+    ("731", "版画材料・技法"),
+    ("732", "版画史．各国の版画"),
+    ("733", "木版画"),
+    ("734", "石版画［リトグラフ］"),
+    ("735", "銅版画．鋼版画"),
+    ("736", "リノリウム版画．ゴム版画"),
+    ("737", "写真版画．孔版画"),
+    ("739", "印章．篆刻．印譜"),
+    ("74", "写真．印刷"),
+    ("740", "写真"),
+    ("742", "写真器械・材料"),
+    ("743", "撮影技術"),
+    ("744", "現像．印画"),
+    ("745", "複写技術"),
+    ("746", "特殊写真"),
+    ("747", "写真の応用"),
+    ("748", "写真集"),
+    ("749", "印刷"),
+    ("75", "工芸"),
+    ("750", "工芸"),
+    ("751", "陶磁工芸"),
+    ("752", "漆工芸"),
+    ("753", "染織工芸"),
+    ("754", "木竹工芸"),
+    ("755", "宝石・牙角・皮革工芸"),
+    ("756", "金工芸"),
+    ("757", "デザイン．装飾美術"),
+    ("758", "美術家具"),
+    ("759", "人形．玩具"),
+    ("76", "音楽．舞踊．バレエ"),
+    ("760", "音楽"),
+    ("761", "音楽の一般理論．音楽学"),
+    ("762", "音楽史．各国の音楽"),
+    ("763", "楽器．器楽"),
+    ("764", "器楽合奏"),
+    ("765", "宗教音楽．聖楽"),
+    ("766", "劇音楽"),
+    ("767", "声楽"),
+    ("768", "邦楽"),
+    ("769", "舞踊．バレエ"),
+    ("77", "演劇．映画．大衆芸能"),
+    ("770", "演劇"),
+    ("771", "劇場．演出．演技"),
+    ("772", "演劇史．各国の演劇"),
+    ("773", "能楽．狂言"),
+    ("774", "歌舞伎"),
+    ("775", "各種の演劇"),
+    ("777", "人形劇"),
+    ("778", "映画"),
+    ("779", "大衆演芸"),
+    ("78", "スポーツ．体育"),
+    ("780", "スポーツ．体育"),
+    ("781", "体操．遊戯"),
+    ("782", "陸上競技"),
+    ("783", "球技"),
+    ("784", "冬季競技"),
+    ("785", "水上競技"),
+    ("786", "戸外レクリエーション"),
+    ("787", "釣魚．遊猟"),
+    ("788", "相撲．拳闘．競馬"),
+    ("789", "武術"),
+    ("79", "諸芸．娯楽"),
+    ("790", "諸芸．娯楽"),
+    ("791", "茶道"),
+    ("792", "香道"),
+    ("793", "花道［華道］"),
+    ("794", "ビリヤード"),
+    ("795", "囲碁"),
+    ("796", "将棋"),
+    ("797", "射倖ゲーム"),
+    ("798", "その他の室内娯楽"),
+    ("799", "ダンス"),
+    ("8", "言語"),
+    ("80", "言語"),
+    ("800", "言語"),
+    ("801", "言語学"),
+    ("802", "言語史・事情．言語政策"),
+    ("803", "参考図書［レファレンスブック］"),
+    ("804", "論文集．評論集．講演集"),
+    ("805", "逐次刊行物"),
+    ("806", "団体"),
+    ("807", "研究法．指導法．言語教育"),
+    ("808", "叢書．全集．選集"),
+    ("809", "言語生活"),
+    ("81", "日本語"),
+    ("810", "日本語"),
+    ("811", "音声．音韻．文字"),
+    ("812", "語源．意味［語義］"),
+    ("813", "辞典"),
+    ("814", "語彙"),
+    ("815", "文法．語法"),
+    ("816", "文章．文体．作文"),
+    ("817", "読本．解釈．会話"),
+    ("818", "方言．訛語"),
+    ("82", "中国語．その他の東洋の諸言語"),
+    ("820", "中国語"),
+    ("821", "音声．音韻．文字"),
+    ("822", "語源．意味［語義］"),
+    ("823", "辞典"),
+    ("824", "語彙"),
+    ("825", "文法．語法"),
+    ("826", "文章．文体．作文"),
+    ("827", "読本．解釈．会話"),
+    ("828", "方言．訛語"),
+    ("829", "その他の東洋の諸言語"),
+    ("83", "英語"),
+    ("830", "英語"),
+    ("831", "音声．音韻．文字"),
+    ("832", "語源．意味［語義］"),
+    ("833", "辞典"),
+    ("834", "語彙"),
+    ("835", "文法．語法"),
+    ("836", "文章．文体．作文"),
+    ("837", "読本．解釈．会話"),
+    ("838", "方言．訛語"),
+    ("84", "ドイツ語．その他のゲルマン諸語"),
+    ("840", "ドイツ語"),
+    ("841", "音声．音韻．文字"),
+    ("842", "語源．意味［語義］"),
+    ("843", "辞典"),
+    ("844", "語彙"),
+    ("845", "文法．語法"),
+    ("846", "文章．文体．作文"),
+    ("847", "読本．解釈．会話"),
+    ("848", "方言．訛語"),
+    ("849", "その他のゲルマン諸語"),
+    ("85", "フランス語．プロバンス語"),
+    ("850", "フランス語"),
+    ("851", "音声．音韻．文字"),
+    ("852", "語源．意味［語義］"),
+    ("853", "辞典"),
+    ("854", "語彙"),
+    ("855", "文法．語法"),
+    ("856", "文章．文体．作文"),
+    ("857", "読本．解釈．会話"),
+    ("858", "方言．訛語"),
+    ("859", "プロバンス語"),
+    ("86", "スペイン語．ポルトガル語"),
+    ("860", "スペイン語"),
+    ("861", "音声．音韻．文字"),
+    ("862", "語源．意味［語義］"),
+    ("863", "辞典"),
+    ("864", "語彙"),
+    ("865", "文法．語法"),
+    ("866", "文章．文体．作文"),
+    ("867", "読本．解釈．会話"),
+    ("868", "方言．訛語"),
+    ("869", "ポルトガル語"),
+    ("87", "イタリア語．その他のロマンス諸語"),
+    ("870", "イタリア語"),
+    ("871", "音声．音韻．文字"),
+    ("872", "語源．意味［語義］"),
+    ("873", "辞典"),
+    ("874", "語彙"),
+    ("875", "文法．語法"),
+    ("876", "文章．文体．作文"),
+    ("877", "読本．解釈．会話"),
+    ("878", "方言．訛語"),
+    ("879", "その他のロマンス諸語"),
+    ("88", "ロシア語．その他のスラブ諸語"),
+    ("880", "ロシア語"),
+    ("881", "音声．音韻．文字"),
+    ("882", "語源．意味［語義］"),
+    ("883", "辞典"),
+    ("884", "語彙"),
+    ("885", "文法．語法"),
+    ("886", "文章．文体．作文"),
+    ("887", "読本．解釈．会話"),
+    ("888", "方言．訛語"),
+    ("889", "その他のスラブ諸語"),
+    ("89", "その他の諸言語"),
+    ("890", "その他の諸言語"),
+    ("891", "ギリシア語"),
+    ("892", "ラテン語"),
+    ("893", "その他のヨーロッパの諸言語"),
+    ("894", "アフリカの諸言語"),
+    ("895", "アメリカの諸言語"),
+    ("897", "オーストラリアの諸言語"),
+    ("899", "国際語［人工語］"),
+    ("9", "文学"),
+    ("90", "文学"),
+    ("900", "文学"),
+    ("901", "文学理論・作法"),
+    ("902", "文学史．文学思想史"),
+    ("903", "参考図書［レファレンスブック］"),
+    ("904", "論文集．評論集．講演集"),
+    ("905", "逐次刊行物"),
+    ("906", "団体"),
+    ("907", "研究法．指導法．文学教育"),
+    ("908", "叢書．全集．選集"),
+    ("909", "児童文学研究"),
+    ("91", "日本文学"),
+    ("910", "日本文学"),
+    ("911", "詩歌"),
+    ("912", "戯曲"),
+    ("913", "小説．物語"),
+    ("914", "評論．エッセイ．随筆"),
+    ("915", "日記．書簡．紀行"),
+    ("916", "記録．手記．ルポルタージュ"),
+    ("917", "箴言．アフォリズム．寸言"),
+    ("918", "作品集"),
+    ("919", "漢詩文．日本漢文学"),
+    ("92", "中国文学．その他の東洋文学"),
+    ("920", "中国文学"),
+    ("921", "詩歌．韻文．詩文"),
+    ("922", "戯曲"),
+    ("923", "小説．物語"),
+    ("924", "評論．エッセイ．随筆"),
+    ("925", "日記．書簡．紀行"),
+    ("926", "記録．手記．ルポルタージュ"),
+    ("927", "箴言．アフォリズム．寸言"),
+    ("928", "作品集"),
+    ("929", "その他の東洋文学"),
+    ("93", "英米文学"),
+    ("930", "英米文学"),
+    ("931", "詩"),
+    ("932", "戯曲"),
+    ("933", "小説．物語"),
+    ("934", "評論．エッセイ．随筆"),
+    ("935", "日記．書簡．紀行"),
+    ("936", "記録．手記．ルポルタージュ"),
+    ("937", "箴言．アフォリズム．寸言"),
+    ("938", "作品集"),
+    ("94", "ドイツ文学．その他のゲルマン文学"),
+    ("940", "ドイツ文学"),
+    ("941", "詩"),
+    ("942", "戯曲"),
+    ("943", "小説．物語"),
+    ("944", "評論．エッセイ．随筆"),
+    ("945", "日記．書簡．紀行"),
+    ("946", "記録．手記．ルポルタージュ"),
+    ("947", "箴言．アフォリズム．寸言"),
+    ("948", "作品集"),
+    ("949", "その他のゲルマン文学"),
+    ("95", "フランス文学．プロバンス文学"),
+    ("950", "フランス文学"),
+    ("951", "詩"),
+    ("952", "戯曲"),
+    ("953", "小説．物語"),
+    ("954", "評論．エッセイ．随筆"),
+    ("955", "日記．書簡．紀行"),
+    ("956", "記録．手記．ルポルタージュ"),
+    ("957", "箴言．アフォリズム．寸言"),
+    ("958", "作品集"),
+    ("959", "プロバンス文学"),
+    ("96", "スペイン文学．ポルトガル文学"),
+    ("960", "スペイン文学"),
+    ("961", "詩"),
+    ("962", "戯曲"),
+    ("963", "小説．物語"),
+    ("964", "評論．エッセイ．随筆"),
+    ("965", "日記．書簡．紀行"),
+    ("966", "記録．手記．ルポルタージュ"),
+    ("967", "箴言．アフォリズム．寸言"),
+    ("968", "作品集"),
+    ("969", "ポルトガル文学"),
+    ("97", "イタリア文学．その他のロマンス文学"),
+    ("970", "イタリア文学"),
+    ("971", "詩"),
+    ("972", "戯曲"),
+    ("973", "小説．物語"),
+    ("974", "評論．エッセイ．随筆"),
+    ("975", "日記．書簡．紀行"),
+    ("976", "記録．手記．ルポルタージュ"),
+    ("977", "箴言．アフォリズム．寸言"),
+    ("978", "作品集"),
+    ("979", "その他のロマンス文学"),
+    ("98", "ロシア・ソビエト文学．その他のスラブ文学"),
+    ("980", "ロシア・ソビエト文学"),
+    ("981", "詩"),
+    ("982", "戯曲"),
+    ("983", "小説．物語"),
+    ("984", "評論．エッセイ．随筆"),
+    ("985", "日記．書簡．紀行"),
+    ("986", "記録．手記．ルポルタージュ"),
+    ("987", "箴言．アフォリズム．寸言"),
+    ("988", "作品集"),
+    ("989", "その他のスラブ文学"),
+    ("99", "その他の諸言語文学"),
+    ("990", "その他の諸言語文学"),
+    ("991", "ギリシア文学"),
+    ("992", "ラテン文学"),
+    ("993", "その他のヨーロッパ文学"),
+    ("994", "アフリカ文学"),
+    ("995", "アメリカ先住民語の文学"),
+    ("997", "オーストラリア先住民語の文学"),
+    ("999", "国際語［人工語］による文学"),
+];
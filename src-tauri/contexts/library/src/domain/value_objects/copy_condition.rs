@@ -0,0 +1,50 @@
+// Library Domain Layer - CopyCondition Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 所有している本の状態（古書店の状態の区分に合わせる）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CopyCondition {
+    /// 新品
+    New,
+    /// 新品同様
+    LikeNew,
+    /// 非常に良い
+    VeryGood,
+    /// 良い
+    Good,
+    /// 可（読むのに支障はない傷み・書き込みがある）
+    Fair,
+    /// 悪い
+    Poor,
+}
+
+impl CopyCondition {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::New => "new",
+            Self::LikeNew => "like_new",
+            Self::VeryGood => "very_good",
+            Self::Good => "good",
+            Self::Fair => "fair",
+            Self::Poor => "poor",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "new" => Ok(Self::New),
+            "like_new" => Ok(Self::LikeNew),
+            "very_good" => Ok(Self::VeryGood),
+            "good" => Ok(Self::Good),
+            "fair" => Ok(Self::Fair),
+            "poor" => Ok(Self::Poor),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown copy condition: {}",
+                other
+            ))),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// Library Domain Layer - CopyFormat Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 所有している本の版型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CopyFormat {
+    /// ハードカバー（上製本）
+    Hardcover,
+    /// ペーパーバック（文庫・新書などの並製本を含む）
+    Paperback,
+    /// 電子書籍
+    Ebook,
+    /// オーディオブック
+    Audiobook,
+}
+
+impl CopyFormat {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hardcover => "hardcover",
+            Self::Paperback => "paperback",
+            Self::Ebook => "ebook",
+            Self::Audiobook => "audiobook",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "hardcover" => Ok(Self::Hardcover),
+            "paperback" => Ok(Self::Paperback),
+            "ebook" => Ok(Self::Ebook),
+            "audiobook" => Ok(Self::Audiobook),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown copy format: {}",
+                other
+            ))),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// Library Domain Layer - CoverHash Value Object
+
+use crate::domain::errors::DomainError;
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// SHA-256 の16進表記の長さ
+const HASH_LENGTH: usize = 64;
+
+/// 表紙画像の内容のハッシュのValue Object（SHA-256、小文字の16進表記）
+///
+/// 画像ファイルはこのハッシュを名前として保存するため、同じ画像は1つのファイルを共有する。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoverHash {
+    hex: String,
+}
+
+impl CoverHash {
+    /// 画像の内容からハッシュを計算
+    pub fn of(bytes: &[u8]) -> Self {
+        let digest = Sha256::digest(bytes);
+        Self {
+            hex: digest.iter().map(|b| format!("{:02x}", b)).collect(),
+        }
+    }
+
+    /// 16進表記を解析（大文字は小文字に正規化する）
+    pub fn parse(input: &str) -> Result<Self, DomainError> {
+        // ビジネスルール: 64桁の16進数であること（ファイル名に使うため他の文字は不可）
+        if input.len() != HASH_LENGTH || !input.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid cover hash: {}",
+                input
+            )));
+        }
+        Ok(Self {
+            hex: input.to_ascii_lowercase(),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.hex
+    }
+}
+
+impl fmt::Display for CoverHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_of_bytes() {
+        let hash = CoverHash::of(b"abc");
+        assert_eq!(
+            hash.as_str(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            CoverHash::parse(&hash.as_str().to_uppercase()).unwrap(),
+            hash
+        );
+    }
+
+    #[test]
+    fn test_invalid_hash_fails() {
+        assert!(CoverHash::parse("abc").is_err());
+        assert!(CoverHash::parse(&"../".repeat(22)[..64]).is_err());
+    }
+}
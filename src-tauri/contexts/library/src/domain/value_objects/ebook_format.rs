@@ -0,0 +1,67 @@
+// Library Domain Layer - EbookFormat Value Object
+
+use crate::domain::errors::DomainError;
+use std::path::Path;
+
+/// 電子書籍ファイルの形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EbookFormat {
+    Epub,
+    Pdf,
+}
+
+impl EbookFormat {
+    /// 永続化用の文字列表現（ファイルの拡張子と同じ）
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Epub => "epub",
+            Self::Pdf => "pdf",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "epub" => Ok(Self::Epub),
+            "pdf" => Ok(Self::Pdf),
+            other => Err(DomainError::ValidationError(format!(
+                "Unsupported e-book format: {}",
+                other
+            ))),
+        }
+    }
+
+    /// ファイルの拡張子から形式を判定（大文字・小文字は区別しない）
+    pub fn from_path(path: &Path) -> Result<Self, DomainError> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Self::parse(&extension).map_err(|_| {
+            DomainError::ValidationError(format!(
+                "Unsupported e-book file (EPUB or PDF only): {}",
+                path.display()
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            EbookFormat::from_path(Path::new("/books/Dune.EPUB")).unwrap(),
+            EbookFormat::Epub
+        );
+        assert_eq!(
+            EbookFormat::from_path(Path::new("paper.pdf")).unwrap(),
+            EbookFormat::Pdf
+        );
+        assert!(EbookFormat::from_path(Path::new("notes.txt")).is_err());
+        assert!(EbookFormat::from_path(Path::new("README")).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+// Library Domain Layer - ExternalSource Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 本を取り込んだ元のサービス（再取り込み時に同じ本を特定するためにIDを記録する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExternalSource {
+    /// Calibre のライブラリ（metadata.db）
+    Calibre,
+}
+
+impl ExternalSource {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Calibre => "calibre",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "calibre" => Ok(Self::Calibre),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown external source: {}",
+                other
+            ))),
+        }
+    }
+}
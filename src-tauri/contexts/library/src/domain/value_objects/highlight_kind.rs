@@ -0,0 +1,34 @@
+// Library Domain Layer - HighlightKind Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 電子書籍から取り込んだ抜き書きの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HighlightKind {
+    /// 本文のハイライト
+    Highlight,
+    /// 端末で書き込んだメモ
+    Note,
+}
+
+impl HighlightKind {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Highlight => "highlight",
+            Self::Note => "note",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "highlight" => Ok(Self::Highlight),
+            "note" => Ok(Self::Note),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown highlight kind: {}",
+                other
+            ))),
+        }
+    }
+}
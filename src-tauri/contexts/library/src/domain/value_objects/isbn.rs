@@ -0,0 +1,275 @@
+// Library Domain Layer - ISBN Value Object
+
+use crate::domain::errors::DomainError;
+use std::fmt;
+
+/// 登録グループ番号の桁数の範囲表（GS1接頭辞, 下限, 上限, 桁数）
+///
+/// GS1接頭辞に続く7桁の値で範囲を判定する（ISBN国際機関の RangeMessage に準拠）。
+/// 桁数が0の範囲は未割り当て。
+const REGISTRATION_GROUP_RANGES: &[(&str, u32, u32, usize)] = &[
+    ("978", 0, 5_999_999, 1),
+    ("978", 6_000_000, 6_499_999, 3),
+    ("978", 6_500_000, 6_599_999, 2),
+    ("978", 6_600_000, 6_999_999, 0),
+    ("978", 7_000_000, 7_999_999, 1),
+    ("978", 8_000_000, 9_499_999, 2),
+    ("978", 9_500_000, 9_899_999, 3),
+    ("978", 9_900_000, 9_989_999, 4),
+    ("978", 9_990_000, 9_999_999, 5),
+    ("979", 0, 999_999, 0),
+    ("979", 1_000_000, 1_399_999, 2),
+    ("979", 1_400_000, 7_999_999, 0),
+    ("979", 8_000_000, 8_999_999, 1),
+    ("979", 9_000_000, 9_999_999, 0),
+];
+
+/// ISBNのValue Object
+///
+/// ISBN-10 / ISBN-13 のどちらの入力も受け付け、内部ではISBN-13（ハイフンなし）に正規化して保持する。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Isbn {
+    isbn13: String,
+}
+
+impl Isbn {
+    /// ハイフン・空白付きの入力を解析し、チェックディジットを検証して作成
+    pub fn parse(input: &str) -> Result<Self, DomainError> {
+        let digits = Self::normalize(input);
+        // 桁数は文字単位ではなくバイト単位で数えるため、先に数字と X 以外を弾く
+        if !digits.chars().all(|c| c.is_ascii_digit() || c == 'X') {
+            return Err(DomainError::ValidationError(format!(
+                "ISBN must contain only digits and X: {}",
+                input.trim()
+            )));
+        }
+
+        match digits.len() {
+            10 => {
+                if !Self::is_valid_isbn10(&digits) {
+                    return Err(DomainError::ValidationError(format!(
+                        "Invalid ISBN-10 check digit: {}",
+                        input.trim()
+                    )));
+                }
+                Ok(Self {
+                    isbn13: Self::isbn10_to_isbn13(&digits),
+                })
+            }
+            13 => {
+                if !(digits.starts_with("978") || digits.starts_with("979")) {
+                    return Err(DomainError::ValidationError(format!(
+                        "ISBN-13 must start with 978 or 979: {}",
+                        input.trim()
+                    )));
+                }
+                if !Self::is_valid_isbn13(&digits) {
+                    return Err(DomainError::ValidationError(format!(
+                        "Invalid ISBN-13 check digit: {}",
+                        input.trim()
+                    )));
+                }
+                Ok(Self { isbn13: digits })
+            }
+            _ => Err(DomainError::ValidationError(format!(
+                "ISBN must have 10 or 13 digits: {}",
+                input.trim()
+            ))),
+        }
+    }
+
+    /// ISBN-13（ハイフンなし）
+    pub fn as_isbn13(&self) -> &str {
+        &self.isbn13
+    }
+
+    /// ISBN-10（ハイフンなし）。979で始まるISBNはISBN-10を持たないため None
+    pub fn to_isbn10(&self) -> Option<String> {
+        let body = self.isbn13.strip_prefix("978")?;
+        let body = &body[..9];
+        Some(format!("{}{}", body, Self::isbn10_check_digit(body)))
+    }
+
+    /// GS1接頭辞（978 または 979）
+    pub fn gs1_prefix(&self) -> &str {
+        &self.isbn13[..3]
+    }
+
+    /// 登録グループ番号（国・言語圏。例: 日本は "4"）。未割り当ての範囲では None
+    pub fn registration_group(&self) -> Option<&str> {
+        let prefix = self.gs1_prefix();
+        let value: u32 = self.isbn13[3..10].parse().ok()?;
+
+        REGISTRATION_GROUP_RANGES
+            .iter()
+            .find(|(gs1, start, end, _)| *gs1 == prefix && (*start..=*end).contains(&value))
+            .and_then(|(_, _, _, length)| (*length > 0).then(|| &self.isbn13[3..3 + length]))
+    }
+
+    /// 入力から数字と X 以外を取り除く（全角数字・`ISBN` 接頭辞にも対応）
+    fn normalize(input: &str) -> String {
+        let trimmed = input.trim();
+        let without_label = match trimmed.get(..4) {
+            Some(label) if label.eq_ignore_ascii_case("ISBN") => {
+                let rest = &trimmed[4..];
+                // "ISBN-13: ..." のような表記はラベル部分を読み飛ばす
+                rest.split_once(':').map_or(rest, |(_, value)| value)
+            }
+            _ => trimmed,
+        };
+
+        without_label
+            .chars()
+            .filter_map(|c| match c {
+                '0'..='9' => Some(c),
+                'x' | 'X' => Some('X'),
+                // 全角数字
+                '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32),
+                '-' | '‐' | '－' | 'ー' | ' ' | '\u{3000}' => None,
+                // その他の文字は形式チェックで弾かれるように残す
+                _ => Some(c),
+            })
+            .collect()
+    }
+
+    fn digit_values(digits: &str) -> Option<Vec<u32>> {
+        digits.chars().map(|c| c.to_digit(10)).collect()
+    }
+
+    fn is_valid_isbn10(digits: &str) -> bool {
+        let (body, check) = digits.split_at(9);
+        Self::digit_values(body).is_some()
+            && (check == "X" || check.chars().all(|c| c.is_ascii_digit()))
+            && Self::isbn10_check_digit(body).to_string() == check
+    }
+
+    fn is_valid_isbn13(digits: &str) -> bool {
+        let Some(values) = Self::digit_values(digits) else {
+            return false;
+        };
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, d)| if i % 2 == 0 { *d } else { d * 3 })
+            .sum::<u32>()
+            % 10
+            == 0
+    }
+
+    /// ISBN-10 のチェックディジット（先頭9桁から計算）
+    fn isbn10_check_digit(body: &str) -> char {
+        let sum: u32 = body
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .zip((2..=10).rev())
+            .map(|(d, weight)| d * weight)
+            .sum();
+        match (11 - sum % 11) % 11 {
+            10 => 'X',
+            check => char::from_digit(check, 10).unwrap_or('0'),
+        }
+    }
+
+    /// ISBN-13 のチェックディジット（先頭12桁から計算）
+    fn isbn13_check_digit(body: &str) -> char {
+        let sum: u32 = body
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(i, d)| if i % 2 == 0 { d } else { d * 3 })
+            .sum();
+        char::from_digit((10 - sum % 10) % 10, 10).unwrap_or('0')
+    }
+
+    fn isbn10_to_isbn13(isbn10: &str) -> String {
+        let body = format!("978{}", &isbn10[..9]);
+        let check = Self::isbn13_check_digit(&body);
+        format!("{}{}", body, check)
+    }
+}
+
+impl fmt::Display for Isbn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.isbn13)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_isbn13() {
+        let isbn = Isbn::parse("978-4-06-519981-7").unwrap();
+        assert_eq!(isbn.as_isbn13(), "9784065199817");
+        assert_eq!(isbn.to_isbn10().as_deref(), Some("4065199816"));
+    }
+
+    #[test]
+    fn test_parse_isbn10_with_x_check_digit() {
+        let isbn = Isbn::parse("0-8044-2957-x").unwrap();
+        assert_eq!(isbn.as_isbn13(), "9780804429573");
+        assert_eq!(isbn.to_isbn10().as_deref(), Some("080442957X"));
+    }
+
+    #[test]
+    fn test_parse_labeled_and_full_width_input() {
+        let expected = Isbn::parse("9784065199817").unwrap();
+        assert_eq!(Isbn::parse("ISBN-13: 978-4-06-519981-7").unwrap(), expected);
+        assert_eq!(Isbn::parse("ISBN 4-06-519981-6").unwrap(), expected);
+        assert_eq!(
+            Isbn::parse("９７８－４－０６－５１９９８１－７").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_invalid_check_digit_fails() {
+        assert!(Isbn::parse("978-4-06-519981-8").is_err());
+        assert!(Isbn::parse("4-06-519981-7").is_err());
+    }
+
+    #[test]
+    fn test_invalid_format_fails() {
+        assert!(Isbn::parse("").is_err());
+        assert!(Isbn::parse("12345").is_err());
+        assert!(Isbn::parse("977-4-06-519981-7").is_err());
+        assert!(Isbn::parse("40651998X6").is_err());
+        assert!(Isbn::parse("978406519981A").is_err());
+    }
+
+    #[test]
+    fn test_multibyte_character_fails_without_panic() {
+        // 10バイトになる入力でも文字の境界で分割しない
+        assert!(Isbn::parse("1234567あ").is_err());
+        assert!(Isbn::parse("978406519あ").is_err());
+        assert!(Isbn::parse("ISBN:４０６５１９９８１あ").is_err());
+    }
+
+    #[test]
+    fn test_979_has_no_isbn10() {
+        let isbn = Isbn::parse("979-10-90636-07-1").unwrap();
+        assert_eq!(isbn.gs1_prefix(), "979");
+        assert_eq!(isbn.to_isbn10(), None);
+        assert_eq!(isbn.registration_group(), Some("10"));
+    }
+
+    #[test]
+    fn test_registration_group() {
+        // 日本
+        assert_eq!(
+            Isbn::parse("9784065199817").unwrap().registration_group(),
+            Some("4")
+        );
+        // 英語圏
+        assert_eq!(
+            Isbn::parse("9781593278281").unwrap().registration_group(),
+            Some("1")
+        );
+        // 韓国（2桁）
+        assert_eq!(
+            Isbn::parse("9788937460449").unwrap().registration_group(),
+            Some("89")
+        );
+    }
+}
@@ -0,0 +1,47 @@
+// Library Domain Layer - LocationKind Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 場所の階層（部屋 > 本棚 > 棚）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocationKind {
+    /// 部屋（最上位）
+    Room,
+    /// 本棚（部屋に置く）
+    Bookcase,
+    /// 棚（本棚の段）
+    Shelf,
+}
+
+impl LocationKind {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Room => "room",
+            Self::Bookcase => "bookcase",
+            Self::Shelf => "shelf",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "room" => Ok(Self::Room),
+            "bookcase" => Ok(Self::Bookcase),
+            "shelf" => Ok(Self::Shelf),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown location kind: {}",
+                other
+            ))),
+        }
+    }
+
+    /// 1つ上の階層（部屋はなし）
+    pub fn parent_kind(self) -> Option<Self> {
+        match self {
+            Self::Room => None,
+            Self::Bookcase => Some(Self::Room),
+            Self::Shelf => Some(Self::Bookcase),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Library Domain Layer - Price Value Object
+
+use crate::domain::errors::DomainError;
+use std::fmt;
+
+/// 補助単位を持たない通貨（ISO 4217 で小数点以下の桁数が0）
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &[
+    "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "UYI", "VND",
+    "VUV", "XAF", "XOF", "XPF",
+];
+/// 補助単位が1000分の1の通貨（ISO 4217 で小数点以下の桁数が3）
+const THREE_DECIMAL_CURRENCIES: &[&str] = &["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"];
+/// 金額の整数部分の最大桁数
+const MAX_INTEGER_DIGITS: usize = 12;
+
+/// 価格のValue Object（通貨の最小単位の整数で持つ。例: 12.99 USD は 1299、1980 JPY は 1980）
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Price {
+    minor_units: i64,
+    currency: String,
+}
+
+impl Price {
+    /// 金額（`1980`・`12.99` などの10進表記。`,` の桁区切りは除く）と通貨コードから作成
+    pub fn parse(amount: &str, currency: &str) -> Result<Self, DomainError> {
+        let currency = Self::validate_currency(currency)?;
+        let decimals = Self::decimals_of(&currency);
+
+        // ビジネスルール: 0以上で、小数点以下は通貨の補助単位の桁数まで
+        let amount = amount.trim().replace(',', "");
+        let (integer, fraction) = match amount.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (amount.as_str(), None),
+        };
+        let valid_integer = !integer.is_empty()
+            && integer.len() <= MAX_INTEGER_DIGITS
+            && integer.chars().all(|c| c.is_ascii_digit());
+        let valid_fraction = fraction.is_none_or(|fraction| {
+            !fraction.is_empty()
+                && fraction.len() <= decimals as usize
+                && fraction.chars().all(|c| c.is_ascii_digit())
+        });
+        if !valid_integer || !valid_fraction {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid price: {} {} (expected a non-negative amount with up to {} decimal places)",
+                amount, currency, decimals
+            )));
+        }
+
+        // 桁数は検証済みのため i64 に収まる
+        let minor_units = format!(
+            "{}{:0<width$}",
+            integer,
+            fraction.unwrap_or(""),
+            width = decimals as usize
+        )
+        .parse::<i64>()
+        .map_err(|e| DomainError::ValidationError(format!("Invalid price: {}", e)))?;
+        Ok(Self {
+            minor_units,
+            currency,
+        })
+    }
+
+    /// 最小単位の金額と通貨コードから作成（DB から取得した場合など）
+    pub fn from_minor_units(minor_units: i64, currency: &str) -> Result<Self, DomainError> {
+        if minor_units < 0 {
+            return Err(DomainError::ValidationError(format!(
+                "Price must not be negative: {}",
+                minor_units
+            )));
+        }
+        Ok(Self {
+            minor_units,
+            currency: Self::validate_currency(currency)?,
+        })
+    }
+
+    /// ビジネスルール: 通貨コードは3文字の英字（大文字にそろえる）
+    fn validate_currency(currency: &str) -> Result<String, DomainError> {
+        let currency = currency.trim().to_ascii_uppercase();
+        if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid currency code: {} (expected an ISO 4217 code such as JPY or USD)",
+                currency
+            )));
+        }
+        Ok(currency)
+    }
+
+    /// 通貨の小数点以下の桁数
+    fn decimals_of(currency: &str) -> u32 {
+        if ZERO_DECIMAL_CURRENCIES.contains(&currency) {
+            0
+        } else if THREE_DECIMAL_CURRENCIES.contains(&currency) {
+            3
+        } else {
+            2
+        }
+    }
+
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// 金額の10進表記（通貨の小数点以下の桁数にそろえる。例: `12.90`・`1980`）
+    pub fn amount(&self) -> String {
+        let decimals = Self::decimals_of(&self.currency);
+        if decimals == 0 {
+            return self.minor_units.to_string();
+        }
+        let scale = 10_i64.pow(decimals);
+        format!(
+            "{}.{:0width$}",
+            self.minor_units / scale,
+            self.minor_units % scale,
+            width = decimals as usize
+        )
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount(), self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_prices() {
+        let yen = Price::parse("1,980", "jpy").unwrap();
+        assert_eq!((yen.minor_units(), yen.currency()), (1980, "JPY"));
+        assert_eq!(yen.to_string(), "1980 JPY");
+
+        let dollars = Price::parse(" 12.9 ", "USD").unwrap();
+        assert_eq!(dollars.minor_units(), 1290);
+        assert_eq!(dollars.amount(), "12.90");
+
+        let dinars = Price::parse("4.5", "KWD").unwrap();
+        assert_eq!(dinars.amount(), "4.500");
+        assert_eq!(
+            Price::from_minor_units(dinars.minor_units(), "KWD").unwrap(),
+            dinars
+        );
+    }
+
+    #[test]
+    fn test_invalid_prices_fail() {
+        for (amount, currency) in [
+            ("-1", "USD"),
+            ("12.999", "USD"),
+            ("19.8", "JPY"),
+            ("12.", "USD"),
+            (".5", "USD"),
+            ("1e3", "USD"),
+            ("1234567890123", "JPY"),
+            ("10", "YEN1"),
+            ("10", "¥"),
+        ] {
+            assert!(
+                Price::parse(amount, currency).is_err(),
+                "{} {}",
+                amount,
+                currency
+            );
+        }
+        assert!(Price::from_minor_units(-100, "USD").is_err());
+    }
+}
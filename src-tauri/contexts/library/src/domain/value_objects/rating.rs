@@ -0,0 +1,52 @@
+// Library Domain Layer - Rating Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 星の最小値
+const MIN_STARS: f64 = 0.5;
+/// 星の最大値
+const MAX_STARS: f64 = 5.0;
+
+/// 星による評価のValue Object（0.5〜5.0、0.5刻み）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rating {
+    stars: f64,
+}
+
+impl Rating {
+    /// 星の数から作成（バリデーション付き）
+    pub fn new(stars: f64) -> Result<Self, DomainError> {
+        // ビジネスルール: 0.5〜5.0の範囲で、0.5刻み
+        if !(MIN_STARS..=MAX_STARS).contains(&stars) || (stars * 2.0).fract() != 0.0 {
+            return Err(DomainError::ValidationError(format!(
+                "Rating must be between {} and {} in steps of 0.5",
+                MIN_STARS, MAX_STARS
+            )));
+        }
+        Ok(Self { stars })
+    }
+
+    pub fn stars(self) -> f64 {
+        self.stars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_ratings() {
+        assert_eq!(Rating::new(0.5).unwrap().stars(), 0.5);
+        assert_eq!(Rating::new(3.5).unwrap().stars(), 3.5);
+        assert_eq!(Rating::new(5.0).unwrap().stars(), 5.0);
+    }
+
+    #[test]
+    fn test_invalid_ratings_fail() {
+        assert!(Rating::new(0.0).is_err());
+        assert!(Rating::new(5.5).is_err());
+        assert!(Rating::new(3.3).is_err());
+        assert!(Rating::new(f64::NAN).is_err());
+    }
+}
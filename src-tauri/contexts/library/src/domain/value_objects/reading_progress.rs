@@ -0,0 +1,108 @@
+// Library Domain Layer - ReadingProgress Value Object
+
+use crate::domain::errors::DomainError;
+use std::cmp::Ordering;
+
+/// 読書の進捗（どこまで読んだか）
+///
+/// ページ番号またはパーセントのどちらかで表す。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReadingProgress {
+    /// 読み終えたページ
+    Page(i32),
+    /// 読み終えた割合（0〜100）
+    Percent(f64),
+}
+
+impl ReadingProgress {
+    /// ページ番号またはパーセントから作成（どちらか一方のみ指定可）
+    pub fn new(page: Option<i32>, percent: Option<f64>) -> Result<Self, DomainError> {
+        match (page, percent) {
+            (Some(page), None) => Self::page(page),
+            (None, Some(percent)) => Self::percent(percent),
+            _ => Err(DomainError::ValidationError(
+                "Specify either page or percent for reading progress".to_string(),
+            )),
+        }
+    }
+
+    /// ページ番号の進捗を作成
+    pub fn page(page: i32) -> Result<Self, DomainError> {
+        // ビジネスルール: ページは1以上
+        if page < 1 {
+            return Err(DomainError::ValidationError(
+                "Page must be 1 or greater".to_string(),
+            ));
+        }
+        Ok(Self::Page(page))
+    }
+
+    /// パーセントの進捗を作成
+    pub fn percent(percent: f64) -> Result<Self, DomainError> {
+        // ビジネスルール: パーセントは0より大きく100以下
+        if !(percent > 0.0 && percent <= 100.0) {
+            return Err(DomainError::ValidationError(
+                "Percent must be greater than 0 and at most 100".to_string(),
+            ));
+        }
+        Ok(Self::Percent(percent))
+    }
+
+    pub fn as_page(&self) -> Option<i32> {
+        match self {
+            Self::Page(page) => Some(*page),
+            Self::Percent(_) => None,
+        }
+    }
+
+    pub fn as_percent(&self) -> Option<f64> {
+        match self {
+            Self::Page(_) => None,
+            Self::Percent(percent) => Some(*percent),
+        }
+    }
+
+    /// 同じ単位の進捗と比較（単位が異なる場合は比較できない）
+    pub fn compare(&self, other: &Self) -> Result<Ordering, DomainError> {
+        match (self, other) {
+            (Self::Page(a), Self::Page(b)) => Ok(a.cmp(b)),
+            (Self::Percent(a), Self::Percent(b)) => Ok(a.total_cmp(b)),
+            _ => Err(DomainError::ValidationError(
+                "Reading progress must use the same unit (page or percent) within a read-through"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_exactly_one_unit() {
+        assert!(ReadingProgress::new(Some(10), None).is_ok());
+        assert!(ReadingProgress::new(None, Some(12.5)).is_ok());
+        assert!(ReadingProgress::new(None, None).is_err());
+        assert!(ReadingProgress::new(Some(10), Some(12.5)).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_fails() {
+        assert!(ReadingProgress::page(0).is_err());
+        assert!(ReadingProgress::percent(0.0).is_err());
+        assert!(ReadingProgress::percent(100.5).is_err());
+        assert!(ReadingProgress::percent(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_compare_mixed_units_fails() {
+        let page = ReadingProgress::Page(10);
+        let percent = ReadingProgress::Percent(10.0);
+        assert!(page.compare(&percent).is_err());
+        assert_eq!(
+            page.compare(&ReadingProgress::Page(5)).unwrap(),
+            Ordering::Greater
+        );
+    }
+}
@@ -0,0 +1,47 @@
+// Library Domain Layer - ReadingStatus Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 読書の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadingStatus {
+    /// 読みたい
+    WantToRead,
+    /// 読書中
+    Reading,
+    /// 読了
+    Finished,
+    /// 中断（読むのをやめた）
+    Abandoned,
+}
+
+impl ReadingStatus {
+    /// 読み終えた・やめたなど、この読書が終わった状態か
+    pub fn is_closed(self) -> bool {
+        matches!(self, Self::Finished | Self::Abandoned)
+    }
+
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::WantToRead => "want_to_read",
+            Self::Reading => "reading",
+            Self::Finished => "finished",
+            Self::Abandoned => "abandoned",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "want_to_read" => Ok(Self::WantToRead),
+            "reading" => Ok(Self::Reading),
+            "finished" => Ok(Self::Finished),
+            "abandoned" => Ok(Self::Abandoned),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown reading status: {}",
+                other
+            ))),
+        }
+    }
+}
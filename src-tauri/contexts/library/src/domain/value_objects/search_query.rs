@@ -0,0 +1,115 @@
+// Library Domain Layer - SearchQuery Value Object
+
+use crate::domain::errors::DomainError;
+
+/// 検索語の最大数
+const MAX_TERMS: usize = 32;
+
+/// 検索語（単語またはフレーズ）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTerm {
+    text: String,
+}
+
+impl SearchTerm {
+    /// 検索語の文字列（フレーズの場合は空白を含む）
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// 全文検索の検索条件のValue Object
+///
+/// 空白区切りの語はすべて含むもの（AND）として扱い、各語は語の途中にも一致する（部分一致）。
+/// `"..."` で囲むと空白を含めて連続した文字列として検索する。
+/// すべての語が部分一致のため、前方一致の `*` は付けても無視する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<SearchTerm>,
+}
+
+impl SearchQuery {
+    /// 利用者の入力を解析して検索条件を作成
+    pub fn parse(input: &str) -> Result<Self, DomainError> {
+        let mut terms = Vec::new();
+        let mut chars = input.chars().peekable();
+
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+                continue;
+            }
+
+            let text = if c == '"' {
+                // フレーズ（閉じ引用符がない場合は末尾までをフレーズとみなす）
+                chars.next();
+                let text: String = chars.by_ref().take_while(|&c| c != '"').collect();
+                chars.next_if_eq(&'*');
+                text.split_whitespace().collect::<Vec<_>>().join(" ")
+            } else {
+                let mut text = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '"' {
+                        break;
+                    }
+                    text.push(c);
+                    chars.next();
+                }
+                text.trim_end_matches('*').to_string()
+            };
+
+            if !text.is_empty() {
+                terms.push(SearchTerm { text });
+            }
+        }
+
+        if terms.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Search query cannot be empty".to_string(),
+            ));
+        }
+        if terms.len() > MAX_TERMS {
+            return Err(DomainError::ValidationError(format!(
+                "Search query must have {} terms or fewer",
+                MAX_TERMS
+            )));
+        }
+
+        Ok(Self { terms })
+    }
+
+    pub fn terms(&self) -> &[SearchTerm] {
+        &self.terms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_words() {
+        let query = SearchQuery::parse("  rust   programming ").unwrap();
+        let texts: Vec<_> = query.terms().iter().map(SearchTerm::text).collect();
+        assert_eq!(texts, ["rust", "programming"]);
+    }
+
+    #[test]
+    fn test_parse_phrase_and_ignore_prefix_marker() {
+        let query = SearchQuery::parse(r#""the  rust book" prog* "sea orm"*"#).unwrap();
+        let texts: Vec<_> = query.terms().iter().map(SearchTerm::text).collect();
+        assert_eq!(texts, ["the rust book", "prog", "sea orm"]);
+    }
+
+    #[test]
+    fn test_parse_unterminated_phrase() {
+        let query = SearchQuery::parse(r#"dune "frank herbert"#).unwrap();
+        assert_eq!(query.terms()[1].text(), "frank herbert");
+    }
+
+    #[test]
+    fn test_empty_query_fails() {
+        assert!(SearchQuery::parse("").is_err());
+        assert!(SearchQuery::parse(r#"  "" * "#).is_err());
+    }
+}
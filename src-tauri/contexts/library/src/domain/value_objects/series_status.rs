@@ -0,0 +1,35 @@
+// Library Domain Layer - SeriesStatus Value Object
+
+use crate::domain::errors::DomainError;
+
+/// シリーズの刊行状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SeriesStatus {
+    /// 刊行中
+    #[default]
+    Ongoing,
+    /// 完結
+    Completed,
+}
+
+impl SeriesStatus {
+    /// 永続化用の文字列表現
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ongoing => "ongoing",
+            Self::Completed => "completed",
+        }
+    }
+
+    /// 文字列表現から変換
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "ongoing" => Ok(Self::Ongoing),
+            "completed" => Ok(Self::Completed),
+            other => Err(DomainError::ValidationError(format!(
+                "Unknown series status: {}",
+                other
+            ))),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// Library Domain Layer - SortKey Value Object
+
+/// 並び替え用のキーのValue Object
+///
+/// 読み・名前を正規化したもの。全角英数字は半角に、半角カタカナ・カタカナはひらがなに、英字は小文字にそろえ、
+/// 連続する空白は1つにまとめる（「ワンピース」と「ﾜﾝﾋﾟｰｽ」と「わんぴーす」、「ＯＮＥ」と「one」が同じ位置に並ぶ）。
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortKey(String);
+
+impl SortKey {
+    /// 読み・名前から作成
+    pub fn new(text: &str) -> Self {
+        let normalized: String = fold_halfwidth_kana(text)
+            .chars()
+            .map(normalize_char)
+            .collect();
+        let normalized = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+        Self(normalized.to_lowercase())
+    }
+
+    /// タイトルの読み・タイトルから作成（英語の先頭の冠詞 The / A / An は除く）
+    pub fn for_title(text: &str) -> Self {
+        let key = Self::new(text);
+        let stripped = ENGLISH_ARTICLES
+            .iter()
+            .find_map(|article| key.0.strip_prefix(article))
+            .filter(|rest| !rest.is_empty());
+        match stripped {
+            Some(rest) => Self(rest.to_string()),
+            None => key,
+        }
+    }
+
+    /// 保存済みのキーから再構築
+    pub fn reconstruct(key: String) -> Self {
+        Self(key)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// タイトルの並び替えで無視する英語の冠詞（正規化後の小文字、後ろの空白を含む）
+const ENGLISH_ARTICLES: [&str; 3] = ["the ", "a ", "an "];
+
+/// 半角カタカナ（U+FF61〜U+FF9F）に対応する全角文字
+const HALFWIDTH_KANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+
+/// 半角カタカナを全角にする（濁点・半濁点は直前の文字と合成する。「ｶﾞ」→「ガ」、「ﾎﾟ」→「ポ」）
+fn fold_halfwidth_kana(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        let full = match c {
+            '\u{FF61}'..='\u{FF9F}' => HALFWIDTH_KANA.chars().nth(c as usize - 0xFF61),
+            _ => None,
+        };
+        let Some(full) = full else {
+            folded.push(c);
+            continue;
+        };
+
+        let composed = match (folded.chars().last(), full) {
+            (Some('ウ'), '゛') => Some('ヴ'),
+            (Some(prev), '゛') if is_unvoiced(prev) => char::from_u32(prev as u32 + 1),
+            (Some(prev @ ('ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ')), '゜') => {
+                char::from_u32(prev as u32 + 2)
+            }
+            _ => None,
+        };
+        match composed {
+            Some(composed) => {
+                folded.pop();
+                folded.push(composed);
+            }
+            None => folded.push(full),
+        }
+    }
+    folded
+}
+
+/// 濁点を付けられる清音のカタカナか
+fn is_unvoiced(c: char) -> bool {
+    "カキクケコサシスセソタチツテトハヒフヘホ".contains(c)
+}
+
+/// 1文字を正規化（全角英数字・記号 → 半角、全角空白 → 半角空白、カタカナ → ひらがな）
+fn normalize_char(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        // ァ〜ヶ のうち、ひらがなに対応する文字（ヷ〜ヺ などは変換しない）
+        '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizes_kana_width_and_case() {
+        assert_eq!(SortKey::new("ワンピース").as_str(), "わんぴーす");
+        assert_eq!(SortKey::new("  ＯＮＥ　Piece ").as_str(), "one piece");
+        assert_eq!(
+            SortKey::new("ヴィンランド・サガ").as_str(),
+            "ゔぃんらんど・さが"
+        );
+        assert!(SortKey::new("あ") < SortKey::new("か"));
+        assert_eq!(SortKey::new("ﾜﾝﾋﾟｰｽ").as_str(), "わんぴーす");
+        assert_eq!(SortKey::new("ｶﾞﾝﾀﾞﾑ ｳﾞｧｲｽ").as_str(), "がんだむ ゔぁいす");
+    }
+
+    #[test]
+    fn test_title_strips_leading_articles() {
+        assert_eq!(SortKey::for_title("The Hobbit").as_str(), "hobbit");
+        assert_eq!(
+            SortKey::for_title("Ａ　Tale of Two Cities").as_str(),
+            "tale of two cities"
+        );
+        assert_eq!(SortKey::for_title("An Echo").as_str(), "echo");
+        assert_eq!(SortKey::for_title("Theory").as_str(), "theory");
+        assert_eq!(SortKey::for_title("The").as_str(), "the");
+        assert_eq!(SortKey::for_title("ワンピース").as_str(), "わんぴーす");
+    }
+}
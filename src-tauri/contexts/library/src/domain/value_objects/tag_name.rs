@@ -0,0 +1,127 @@
+// Library Domain Layer - TagName Value Object
+
+use crate::domain::errors::DomainError;
+
+/// タグ名全体の最大文字数
+const MAX_LENGTH: usize = 200;
+/// 階層の区切り
+const SEPARATOR: char = '/';
+
+/// タグ名のValue Object
+///
+/// `親/子` 形式で階層を表す。各階層の前後の空白は除き、空の階層は認めない。
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TagName(String);
+
+impl TagName {
+    /// 入力から作成（バリデーション・正規化付き）
+    pub fn new(name: &str) -> Result<Self, DomainError> {
+        let segments: Vec<&str> = name.split(SEPARATOR).map(str::trim).collect();
+        if segments.iter().all(|s| s.is_empty()) {
+            return Err(DomainError::ValidationError(
+                "Tag name cannot be empty".to_string(),
+            ));
+        }
+        if segments.iter().any(|s| s.is_empty()) {
+            return Err(DomainError::ValidationError(format!(
+                "Tag name must not contain an empty level: {}",
+                name.trim()
+            )));
+        }
+
+        let normalized = segments.join("/");
+        if normalized.chars().count() > MAX_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Tag name must be {} characters or less",
+                MAX_LENGTH
+            )));
+        }
+        Ok(Self(normalized))
+    }
+
+    /// 保存済みの名前から再構築
+    pub fn reconstruct(name: String) -> Self {
+        Self(name)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 最後の階層の名前
+    pub fn label(&self) -> &str {
+        self.0.rsplit(SEPARATOR).next().unwrap_or(&self.0)
+    }
+
+    /// 親のタグ名（最上位の場合はなし）
+    pub fn parent(&self) -> Option<TagName> {
+        self.0
+            .rsplit_once(SEPARATOR)
+            .map(|(parent, _)| Self(parent.to_string()))
+    }
+
+    /// 祖先のタグ名（上の階層から順に）
+    pub fn ancestors(&self) -> Vec<TagName> {
+        self.0
+            .match_indices(SEPARATOR)
+            .map(|(index, _)| Self(self.0[..index].to_string()))
+            .collect()
+    }
+
+    /// 階層の深さ（最上位は0）
+    pub fn depth(&self) -> usize {
+        self.0.matches(SEPARATOR).count()
+    }
+
+    /// このタグ自身か、その子孫か
+    pub fn is_within(&self, other: &TagName) -> bool {
+        self.0 == other.0
+            || self
+                .0
+                .strip_prefix(&other.0)
+                .is_some_and(|rest| rest.starts_with(SEPARATOR))
+    }
+
+    /// 祖先 `from` の部分を `to` に置き換えた名前（`from` の子孫でない場合はなし）
+    pub fn moved(&self, from: &TagName, to: &TagName) -> Option<TagName> {
+        self.is_within(from)
+            .then(|| Self(format!("{}{}", to.0, &self.0[from.0.len()..])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizes_levels() {
+        let name = TagName::new(" 小説 / SF /宇宙 ").unwrap();
+        assert_eq!(name.as_str(), "小説/SF/宇宙");
+        assert_eq!(name.label(), "宇宙");
+        assert_eq!(name.depth(), 2);
+        assert_eq!(name.parent().unwrap().as_str(), "小説/SF");
+        assert_eq!(
+            name.ancestors()
+                .iter()
+                .map(TagName::as_str)
+                .collect::<Vec<_>>(),
+            vec!["小説", "小説/SF"]
+        );
+
+        assert!(TagName::new(" / ").is_err());
+        assert!(TagName::new("小説//SF").is_err());
+    }
+
+    #[test]
+    fn test_is_within_and_moved() {
+        let fiction = TagName::new("fiction").unwrap();
+        let sf = TagName::new("fiction/sf").unwrap();
+        assert!(sf.is_within(&fiction));
+        assert!(fiction.is_within(&fiction));
+        assert!(!TagName::new("fictional").unwrap().is_within(&fiction));
+
+        let novels = TagName::new("novels").unwrap();
+        assert_eq!(sf.moved(&fiction, &novels).unwrap().as_str(), "novels/sf");
+        assert_eq!(novels.moved(&fiction, &novels), None);
+    }
+}
@@ -0,0 +1,69 @@
+// Library Domain Layer - VolumeNumber Value Object
+
+use crate::domain::errors::DomainError;
+use std::cmp::Ordering;
+
+/// 巻数の最大値
+const MAX_VOLUME: f64 = 9999.0;
+/// 小数の巻数の刻み（1.5巻・2.25巻などを表せるよう0.01刻み）
+const VOLUME_SCALE: f64 = 100.0;
+
+/// シリーズでの巻数のValue Object（0〜9999、小数は2桁まで。0巻・1.5巻などを含む）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VolumeNumber {
+    value: f64,
+}
+
+impl VolumeNumber {
+    /// 巻数から作成（バリデーション付き）
+    pub fn new(value: f64) -> Result<Self, DomainError> {
+        // ビジネスルール: 0〜9999の範囲で、小数は2桁まで
+        let scaled = value * VOLUME_SCALE;
+        if !(0.0..=MAX_VOLUME).contains(&value) || (scaled - scaled.round()).abs() > 1e-6 {
+            return Err(DomainError::ValidationError(format!(
+                "Volume must be between 0 and {} with at most two decimal places",
+                MAX_VOLUME
+            )));
+        }
+        Ok(Self {
+            value: scaled.round() / VOLUME_SCALE,
+        })
+    }
+
+    pub fn value(self) -> f64 {
+        self.value
+    }
+
+    /// 整数の巻数（1.5巻などの小数の巻はなし）
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn whole(self) -> Option<i32> {
+        // 範囲は検証済みのため i32 に収まる
+        (self.value.fract() == 0.0).then_some(self.value as i32)
+    }
+
+    /// 巻数の順で比較
+    pub fn compare(self, other: Self) -> Ordering {
+        self.value.total_cmp(&other.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_volumes() {
+        assert_eq!(VolumeNumber::new(1.0).unwrap().whole(), Some(1));
+        assert_eq!(VolumeNumber::new(0.0).unwrap().whole(), Some(0));
+        assert_eq!(VolumeNumber::new(1.5).unwrap().whole(), None);
+        assert_eq!(VolumeNumber::new(1.1).unwrap().value(), 1.1);
+    }
+
+    #[test]
+    fn test_invalid_volumes_fail() {
+        assert!(VolumeNumber::new(-1.0).is_err());
+        assert!(VolumeNumber::new(10000.0).is_err());
+        assert!(VolumeNumber::new(1.001).is_err());
+        assert!(VolumeNumber::new(f64::NAN).is_err());
+    }
+}
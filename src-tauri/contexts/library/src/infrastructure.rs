@@ -1,3 +1,6 @@
 // Library Infrastructure Layer
 
+pub mod database;
 pub mod repositories;
+mod search_markup;
+mod xml;
@@ -0,0 +1,35 @@
+// Library Infrastructure Layer - 差し替え可能なデータベース接続
+
+use sea_orm::DatabaseConnection;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// 実行中に差し替え可能なデータベース接続のハンドル
+///
+/// データベースディレクトリの変更時に、再起動せずに接続先を切り替えるために使用する。
+/// クローンしたハンドルはすべて同じ接続を共有する。
+#[derive(Clone)]
+pub struct DatabaseHandle {
+    inner: Arc<RwLock<DatabaseConnection>>,
+}
+
+impl DatabaseHandle {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(db)),
+        }
+    }
+
+    /// 現在の接続を取得（コネクションプールの参照をクローンするため低コスト）
+    pub fn connection(&self) -> DatabaseConnection {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// 接続を差し替え、以前の接続を返す
+    pub fn replace(&self, db: DatabaseConnection) -> DatabaseConnection {
+        let mut guard = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        std::mem::replace(&mut *guard, db)
+    }
+}
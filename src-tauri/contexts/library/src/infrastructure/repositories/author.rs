@@ -0,0 +1,80 @@
+// Library Infrastructure Layer - Author リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{entities::author::Author, repositories::author::AuthorRepository};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::author;
+use sea_orm::{ActiveModelTrait, NotSet, Set, entity::prelude::*};
+
+/// AuthorRepository の SeaORM実装
+pub struct AuthorRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl AuthorRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(author: &Author) -> author::ActiveModel {
+        author::ActiveModel {
+            id: author.id().map_or(NotSet, Set),
+            name: Set(author.name().to_string()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: author::Model) -> Author {
+        Author::reconstruct(model.id, model.name)
+    }
+}
+
+#[async_trait]
+impl AuthorRepository for AuthorRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Author>, DomainError> {
+        let db = self.db.connection();
+        let author = author::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(author.map(Self::db_to_domain))
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Author>, DomainError> {
+        let db = self.db.connection();
+        let author = author::Entity::find()
+            .filter(author::Column::Name.eq(name))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(author.map(Self::db_to_domain))
+    }
+
+    async fn save(&self, mut author: Author) -> Result<Author, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&author);
+
+        let result = if author.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        // 保存後のIDをドメインモデルにセット
+        author.set_id(result.id);
+
+        Ok(Self::db_to_domain(result))
+    }
+}
@@ -1,21 +1,76 @@
 // Library Infrastructure Layer - Book リポジトリ実装
 
 use crate::domain::errors::DomainError;
-use crate::domain::{entities::book::Book, repositories::book::BookRepository};
+use crate::domain::{
+    entities::{
+        author::{Author, BookAuthor},
+        book::Book,
+        book_change::ChangeRecord,
+    },
+    repositories::book::{
+        BookFilter, BookPage, BookQuerySpec, BookRepository, BookSearchHit, BookSortKey,
+        ClassCount, ShelfFilter, SortOrder,
+    },
+    value_objects::{
+        author_role::AuthorRole,
+        book_change_source::BookChangeSource,
+        class_number::{ClassNumber, ClassPrefix, ClassificationScheme},
+        cover_hash::CoverHash,
+        external_source::ExternalSource,
+        isbn::Isbn,
+        search_query::{SearchQuery, SearchTerm},
+        sort_key::SortKey,
+        tag_name::TagName,
+    },
+};
+use crate::infrastructure::database::DatabaseHandle;
+use crate::infrastructure::repositories::{
+    book_change::BookChangeRepositoryImpl, tag::TagRepositoryImpl,
+};
+use crate::infrastructure::search_markup;
 use async_trait::async_trait;
-use entity::book;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, NotSet, Set, entity::prelude::*};
+use chrono::{DateTime, Utc};
+use entity::{
+    author, book, book_author, book_external_id, book_file, book_highlight, book_note, book_review,
+    book_series, book_tag, copy, read_through, shelf_book, tag,
+};
+use sea_orm::{
+    ActiveModelTrait, Condition, ConnectionTrait, DbBackend, FromQueryResult, NotSet, Order,
+    QueryOrder, QueryResult, QuerySelect, Select, Set, Statement, TransactionTrait,
+    entity::prelude::*,
+    sea_query::{Alias, Func, OnConflict, Query, SimpleExpr},
+};
+use std::collections::HashMap;
+
+/// 全文検索の索引で検索できる語の最小文字数（これより短い語は LIKE で部分一致を探す）
+const TRIGRAM_CHARS: usize = 3;
+
+/// 説明文の抜粋の最大文字数
+const SNIPPET_MAX_CHARS: usize = 64;
+
+/// 著者の一括読み込みで1回のクエリに含める本の数（SQLiteのパラメータ数の上限対策）
+const AUTHOR_LOAD_CHUNK_SIZE: usize = 500;
 
 /// BookRepository の SeaORM実装
 pub struct BookRepositoryImpl {
-    db: DatabaseConnection,
+    db: DatabaseHandle,
 }
 
 impl BookRepositoryImpl {
-    pub fn new(db: DatabaseConnection) -> Self {
+    pub fn new(db: DatabaseHandle) -> Self {
         Self { db }
     }
 
+    /// ゴミ箱にない本の検索（ゴミ箱用以外の取得はすべてこれを起点にする）
+    fn active_books() -> Select<book::Entity> {
+        book::Entity::find().filter(book::Column::DeletedAt.is_null())
+    }
+
+    /// ゴミ箱にある本の検索
+    fn trashed_books() -> Select<book::Entity> {
+        book::Entity::find().filter(book::Column::DeletedAt.is_not_null())
+    }
+
     /// ドメインモデルをDBモデルに変換
     fn domain_to_active_model(book: &Book) -> book::ActiveModel {
         if let Some(id) = book.id() {
@@ -26,87 +81,1070 @@ impl BookRepositoryImpl {
                 author: Set(book.author().map(String::from)),
                 description: Set(book.description().map(String::from)),
                 published_year: Set(book.published_year()),
+                publisher: Set(book.publisher().map(String::from)),
+                isbn: Set(book.isbn().map(|isbn| isbn.as_isbn13().to_string())),
+                cover_hash: Set(book.cover().map(|cover| cover.as_str().to_string())),
+                deleted_at: Set(book.deleted_at()),
+                title_reading: Set(book.title_reading().map(String::from)),
+                author_reading: Set(book.author_reading().map(String::from)),
+                title_sort_key: Set(book.title_sort_key().as_str().to_string()),
+                author_sort_key: Set(book.author_sort_key().map(|key| key.as_str().to_string())),
+                ndc: Set(book.ndc().map(|ndc| ndc.as_str().to_string())),
+                ddc: Set(book.ddc().map(|ddc| ddc.as_str().to_string())),
+                version: Set(book.version() + 1),
+                citation_key: NotSet,
+                created_at: NotSet,
+                updated_at: Set(Utc::now()),
             }
         } else {
             // 新しい本（作成）
+            let now = Utc::now();
             book::ActiveModel {
                 id: NotSet,
                 title: Set(book.title().to_string()),
                 author: Set(book.author().map(String::from)),
                 description: Set(book.description().map(String::from)),
                 published_year: Set(book.published_year()),
+                publisher: Set(book.publisher().map(String::from)),
+                isbn: Set(book.isbn().map(|isbn| isbn.as_isbn13().to_string())),
+                cover_hash: Set(book.cover().map(|cover| cover.as_str().to_string())),
+                deleted_at: Set(book.deleted_at()),
+                title_reading: Set(book.title_reading().map(String::from)),
+                author_reading: Set(book.author_reading().map(String::from)),
+                title_sort_key: Set(book.title_sort_key().as_str().to_string()),
+                author_sort_key: Set(book.author_sort_key().map(|key| key.as_str().to_string())),
+                ndc: Set(book.ndc().map(|ndc| ndc.as_str().to_string())),
+                ddc: Set(book.ddc().map(|ddc| ddc.as_str().to_string())),
+                version: Set(1),
+                citation_key: NotSet,
+                created_at: Set(now),
+                updated_at: Set(now),
+            }
+        }
+    }
+
+    /// 絞り込み条件をクエリに適用
+    fn apply_filter(query: Select<book::Entity>, filter: &BookFilter) -> Select<book::Entity> {
+        query.filter(Self::filter_condition(filter))
+    }
+
+    /// 絞り込み条件をSQLの条件に変換（スマート本棚の条件は入れ子にする）
+    fn filter_condition(filter: &BookFilter) -> Condition {
+        Condition::all()
+            .add_option(
+                filter
+                    .author
+                    .as_deref()
+                    .map(|author| book::Column::Author.contains(author)),
+            )
+            .add_option(
+                filter
+                    .title_contains
+                    .as_deref()
+                    .map(|title| book::Column::Title.contains(title)),
+            )
+            .add_option(
+                filter
+                    .published_year_from
+                    .map(|year| book::Column::PublishedYear.gte(year)),
+            )
+            .add_option(
+                filter
+                    .published_year_to
+                    .map(|year| book::Column::PublishedYear.lte(year)),
+            )
+            .add_option(
+                filter
+                    .created_at_from
+                    .map(|at| book::Column::CreatedAt.gte(at)),
+            )
+            .add_option(
+                filter
+                    .created_at_to
+                    .map(|at| book::Column::CreatedAt.lte(at)),
+            )
+            .add_option(
+                filter
+                    .updated_at_from
+                    .map(|at| book::Column::UpdatedAt.gte(at)),
+            )
+            .add_option(
+                filter
+                    .updated_at_to
+                    .map(|at| book::Column::UpdatedAt.lte(at)),
+            )
+            .add_option(
+                filter
+                    .ndc
+                    .as_ref()
+                    .map(|ndc| book::Column::Ndc.starts_with(ndc.as_str())),
+            )
+            .add_option(
+                filter
+                    .ddc
+                    .as_ref()
+                    .map(|ddc| book::Column::Ddc.starts_with(ddc.as_str())),
+            )
+            .add_option(filter.tag.as_ref().map(Self::tag_condition))
+            .add_option(filter.shelf.as_ref().map(|shelf| {
+                match shelf {
+                    ShelfFilter::Books(shelf_id) => Condition::all().add(
+                        book::Column::Id.in_subquery(
+                            Query::select()
+                                .column(shelf_book::Column::BookId)
+                                .from(shelf_book::Entity)
+                                .and_where(shelf_book::Column::ShelfId.eq(*shelf_id))
+                                .to_owned(),
+                        ),
+                    ),
+                    ShelfFilter::Matching(filter) => Self::filter_condition(filter),
+                }
+            }))
+    }
+
+    /// タグ（子孫のタグを含む）が付いた本の条件
+    fn tag_condition(name: &TagName) -> Condition {
+        let tag_ids = Query::select()
+            .column(tag::Column::Id)
+            .from(tag::Entity)
+            .cond_where(TagRepositoryImpl::subtree_condition(name))
+            .to_owned();
+
+        Condition::all().add(
+            book::Column::Id.in_subquery(
+                Query::select()
+                    .column(book_tag::Column::BookId)
+                    .from(book_tag::Entity)
+                    .and_where(book_tag::Column::TagId.in_subquery(tag_ids))
+                    .to_owned(),
+            ),
+        )
+    }
+
+    /// 並び替え条件をクエリに適用（同順位はIDで安定化）
+    fn apply_sort(
+        query: Select<book::Entity>,
+        sort_key: BookSortKey,
+        sort_order: SortOrder,
+    ) -> Select<book::Entity> {
+        let order = match sort_order {
+            SortOrder::Asc => Order::Asc,
+            SortOrder::Desc => Order::Desc,
+        };
+        let column = match sort_key {
+            BookSortKey::Title => book::Column::TitleSortKey,
+            BookSortKey::Author => book::Column::AuthorSortKey,
+            BookSortKey::PublishedYear => book::Column::PublishedYear,
+            BookSortKey::CreatedAt => book::Column::CreatedAt,
+            BookSortKey::UpdatedAt => book::Column::UpdatedAt,
+            BookSortKey::Id => return query.order_by(book::Column::Id, order),
+        };
+
+        query
+            .order_by(column, order.clone())
+            .order_by(book::Column::Id, order)
+    }
+
+    /// 検索条件のうち索引で検索できる語を FTS5 の MATCH 式に変換（各語は引用符で囲みエスケープする）
+    ///
+    /// 索引は3文字ずつの部分一致のため、各語は語の途中にも一致し、フレーズは連続した文字列に一致する。
+    fn to_match_expression(query: &SearchQuery) -> Option<String> {
+        let terms: Vec<String> = query
+            .terms()
+            .iter()
+            .filter(|term| term.text().chars().count() >= TRIGRAM_CHARS)
+            .map(|term| format!("\"{}\"", term.text().replace('"', "\"\"")))
+            .collect();
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+
+    /// 索引で検索できない短い語の LIKE パターン（`%`・`_` はエスケープする）
+    fn short_term_patterns(query: &SearchQuery) -> Vec<String> {
+        query
+            .terms()
+            .iter()
+            .map(|term| term.text())
+            .filter(|text| text.chars().count() < TRIGRAM_CHARS)
+            .map(|text| {
+                let escaped = text
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+                format!("%{}%", escaped)
+            })
+            .collect()
+    }
+
+    /// 全文検索の結果行をドメインの検索結果に変換（一致箇所の強調はHTMLエスケープしてから行う）
+    fn search_row_to_hit(row: &QueryResult, terms: &[SearchTerm]) -> Result<BookSearchHit, DbErr> {
+        let rank: f64 = row.try_get("", "rank")?;
+        let book = Self::db_to_domain(book::Model::from_query_result(row, "")?);
+        Ok(BookSearchHit {
+            title_highlight: search_markup::highlight(book.title(), terms),
+            author_highlight: book
+                .author()
+                .map(|author| search_markup::highlight(author, terms)),
+            description_snippet: book
+                .description()
+                .map(|description| search_markup::snippet(description, terms, SNIPPET_MAX_CHARS)),
+            score: -rank,
+            book,
+        })
+    }
+
+    /// 本の著者一覧を一括で読み込んでセット
+    async fn attach_authors<'a, C, I>(db: &C, books: I) -> Result<(), DomainError>
+    where
+        C: ConnectionTrait,
+        I: IntoIterator<Item = &'a mut Book>,
+    {
+        let mut books: Vec<&mut Book> = books.into_iter().collect();
+        let ids: Vec<i32> = books.iter().filter_map(|book| book.id()).collect();
+
+        let mut authors_by_book: HashMap<i32, Vec<BookAuthor>> = HashMap::new();
+        for chunk in ids.chunks(AUTHOR_LOAD_CHUNK_SIZE) {
+            let rows = book_author::Entity::find()
+                .filter(book_author::Column::BookId.is_in(chunk.iter().copied()))
+                .find_also_related(author::Entity)
+                .order_by_asc(book_author::Column::BookId)
+                .order_by_asc(book_author::Column::Position)
+                .all(db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+            for (link, author) in rows {
+                let Some(author) = author else {
+                    continue;
+                };
+                authors_by_book
+                    .entry(link.book_id)
+                    .or_default()
+                    .push(BookAuthor::new(
+                        Author::reconstruct(author.id, author.name),
+                        AuthorRole::parse(&link.role)?,
+                    ));
+            }
+        }
+
+        for book in &mut books {
+            if let Some(authors) = book.id().and_then(|id| authors_by_book.remove(&id)) {
+                book.load_authors(authors);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 本と著者の関連を保存（呼び出し側のトランザクション内で実行する）
+    async fn save_with<C: ConnectionTrait>(db: &C, mut book: Book) -> Result<Book, DomainError> {
+        let active_model = Self::domain_to_active_model(&book);
+
+        let result = if let Some(id) = book.id() {
+            // 更新（読み込んだ時点の版番号から変わっていない場合のみ）
+            let updated = book::Entity::update_many()
+                .set(active_model)
+                .filter(book::Column::Id.eq(id))
+                .filter(book::Column::Version.eq(book.version()))
+                .exec(db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+            let model = book::Entity::find_by_id(id)
+                .one(db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+                .ok_or_else(|| DomainError::NotFound(format!("Book with id {} not found", id)))?;
+            if updated.rows_affected == 0 {
+                return Err(DomainError::Conflict(format!(
+                    "Book {} has been modified since version {} (current version is {})",
+                    id,
+                    book.version(),
+                    model.version
+                )));
             }
+            model
+        } else {
+            // 新規作成
+            active_model
+                .insert(db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        // 保存後のIDをドメインモデルにセット
+        book.set_id(result.id);
+
+        // 著者の関連を置き換える（並び順は一覧内の位置）
+        book_author::Entity::delete_many()
+            .filter(book_author::Column::BookId.eq(result.id))
+            .exec(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let links = book
+            .authors()
+            .iter()
+            .enumerate()
+            .filter_map(|(position, book_author)| {
+                Some(book_author::ActiveModel {
+                    book_id: Set(result.id),
+                    author_id: Set(book_author.author().id()?),
+                    role: Set(book_author.role().as_str().to_string()),
+                    position: Set(i32::try_from(position).ok()?),
+                })
+            })
+            .collect::<Vec<_>>();
+        if !links.is_empty() {
+            book_author::Entity::insert_many(links)
+                .exec(db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
         }
+
+        let mut saved = Self::db_to_domain(result);
+        saved.load_authors(book.authors().to_vec());
+        Ok(saved)
+    }
+
+    /// 表示用の著者名から著者を取得または作成し、役割を著者として関連付ける
+    async fn link_primary_author<C: ConnectionTrait>(
+        db: &C,
+        book: &mut Book,
+    ) -> Result<(), DomainError> {
+        let Some(name) = book.author() else {
+            return Ok(());
+        };
+
+        let existing = author::Entity::find()
+            .filter(author::Column::Name.eq(name))
+            .one(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+        let model = match existing {
+            Some(model) => model,
+            None => author::ActiveModel {
+                id: NotSet,
+                name: Set(Author::new(name.to_string())?.name().to_string()),
+            }
+            .insert(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?,
+        };
+
+        book.set_authors(vec![BookAuthor::new(
+            Author::reconstruct(model.id, model.name),
+            AuthorRole::Author,
+        )])
     }
 
     /// DBモデルをドメインモデルに変換
     fn db_to_domain(model: book::Model) -> Book {
-        Book::reconstruct(
+        let mut book = Book::reconstruct(
             model.id,
             model.title,
             model.author,
             model.description,
             model.published_year,
-        )
+            // 保存時に検証済みのため、読み込み時に解析できない値は無視する
+            model.isbn.and_then(|isbn| Isbn::parse(&isbn).ok()),
+        );
+        book.set_cover(
+            model
+                .cover_hash
+                .and_then(|hash| CoverHash::parse(&hash).ok()),
+        );
+        book.load_deleted_at(model.deleted_at);
+        book.load_version(model.version);
+        book.load_timestamps(model.created_at, model.updated_at);
+        book.load_readings(model.title_reading, model.author_reading);
+        book.load_publisher(model.publisher);
+        book.load_classification(
+            model
+                .ndc
+                .and_then(|ndc| ClassNumber::parse(ClassificationScheme::Ndc, &ndc).ok()),
+            model
+                .ddc
+                .and_then(|ddc| ClassNumber::parse(ClassificationScheme::Ddc, &ddc).ok()),
+        );
+        book
+    }
+
+    /// 分類法の分類記号を保存する列
+    fn class_column(scheme: ClassificationScheme) -> book::Column {
+        match scheme {
+            ClassificationScheme::Ndc => book::Column::Ndc,
+            ClassificationScheme::Ddc => book::Column::Ddc,
+        }
     }
 }
 
 #[async_trait]
 impl BookRepository for BookRepositoryImpl {
     async fn find_by_id(&self, id: i32) -> Result<Option<Book>, DomainError> {
-        let book = book::Entity::find_by_id(id)
-            .one(&self.db)
+        let db = self.db.connection();
+        let book = Self::active_books()
+            .filter(book::Column::Id.eq(id))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut book = book.map(Self::db_to_domain);
+        Self::attach_authors(&db, book.as_mut()).await?;
+        Ok(book)
+    }
+
+    async fn find_by_isbn(&self, isbn: &Isbn) -> Result<Option<Book>, DomainError> {
+        let db = self.db.connection();
+        let book = Self::active_books()
+            .filter(book::Column::Isbn.eq(isbn.as_isbn13()))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut book = book.map(Self::db_to_domain);
+        Self::attach_authors(&db, book.as_mut()).await?;
+        Ok(book)
+    }
+
+    async fn find_by_title(&self, title: &str) -> Result<Vec<Book>, DomainError> {
+        let db = self.db.connection();
+        // SQLite の LOWER は ASCII のみ変換するため、比較する値も ASCII のみ小文字にする
+        let books = Self::active_books()
+            .filter(
+                Expr::expr(Func::lower(Expr::col(book::Column::Title)))
+                    .eq(title.trim().to_ascii_lowercase()),
+            )
+            .order_by_asc(book::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut books: Vec<Book> = books.into_iter().map(Self::db_to_domain).collect();
+        Self::attach_authors(&db, &mut books).await?;
+        Ok(books)
+    }
+
+    async fn find_by_external_id(
+        &self,
+        source: ExternalSource,
+        external_id: &str,
+    ) -> Result<Option<Book>, DomainError> {
+        let db = self.db.connection();
+        let book = Self::active_books()
+            .filter(
+                book::Column::Id.in_subquery(
+                    Query::select()
+                        .column(book_external_id::Column::BookId)
+                        .from(book_external_id::Entity)
+                        .and_where(book_external_id::Column::Source.eq(source.as_str()))
+                        .and_where(book_external_id::Column::ExternalId.eq(external_id))
+                        .to_owned(),
+                ),
+            )
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut book = book.map(Self::db_to_domain);
+        Self::attach_authors(&db, book.as_mut()).await?;
+        Ok(book)
+    }
+
+    async fn find_by_author(&self, author_id: i32) -> Result<Vec<Book>, DomainError> {
+        let db = self.db.connection();
+        let books = Self::active_books()
+            .filter(
+                book::Column::Id.in_subquery(
+                    Query::select()
+                        .column(book_author::Column::BookId)
+                        .from(book_author::Entity)
+                        .and_where(book_author::Column::AuthorId.eq(author_id))
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(book::Column::PublishedYear)
+            .order_by_asc(book::Column::Id)
+            .all(&db)
             .await
             .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
 
-        Ok(book.map(Self::db_to_domain))
+        let mut books: Vec<Book> = books.into_iter().map(Self::db_to_domain).collect();
+        Self::attach_authors(&db, &mut books).await?;
+        Ok(books)
     }
 
     async fn find_all(&self) -> Result<Vec<Book>, DomainError> {
-        let books = book::Entity::find()
-            .all(&self.db)
+        let db = self.db.connection();
+        let books = Self::active_books()
+            .order_by_asc(book::Column::TitleSortKey)
+            .order_by_asc(book::Column::Id)
+            .all(&db)
             .await
             .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
 
-        Ok(books.into_iter().map(Self::db_to_domain).collect())
+        let mut books: Vec<Book> = books.into_iter().map(Self::db_to_domain).collect();
+        Self::attach_authors(&db, &mut books).await?;
+        Ok(books)
     }
 
-    async fn save(&self, mut book: Book) -> Result<Book, DomainError> {
-        let active_model = Self::domain_to_active_model(&book);
+    async fn find_matching(&self, filter: &BookFilter) -> Result<Vec<Book>, DomainError> {
+        let db = self.db.connection();
+        let books = Self::apply_filter(Self::active_books(), filter)
+            .order_by_asc(book::Column::TitleSortKey)
+            .order_by_asc(book::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
 
-        let result = if book.id().is_some() {
-            // 更新
-            active_model
-                .update(&self.db)
-                .await
-                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
-        } else {
-            // 新規作成
-            active_model
-                .insert(&self.db)
+        let mut books: Vec<Book> = books.into_iter().map(Self::db_to_domain).collect();
+        Self::attach_authors(&db, &mut books).await?;
+        Ok(books)
+    }
+
+    async fn find_page(&self, spec: &BookQuerySpec) -> Result<BookPage, DomainError> {
+        let db = self.db.connection();
+        let query = Self::apply_filter(Self::active_books(), &spec.filter);
+
+        let total_count = query
+            .clone()
+            .count(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let books = Self::apply_sort(query, spec.sort_key, spec.sort_order)
+            .offset(spec.offset)
+            .limit(spec.limit)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut books: Vec<Book> = books.into_iter().map(Self::db_to_domain).collect();
+        Self::attach_authors(&db, &mut books).await?;
+        Ok(BookPage { books, total_count })
+    }
+
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: u64,
+    ) -> Result<Vec<BookSearchHit>, DomainError> {
+        let db = self.db.connection();
+
+        let mut conditions = vec!["books.deleted_at IS NULL"];
+        let mut values: Vec<sea_orm::Value> = Vec::new();
+        // bm25 の重み: タイトル > 著者 > 説明（値が小さいほど関連が高い）。索引を使わない場合は関連度なし
+        let (source, rank) = match Self::to_match_expression(query) {
+            Some(expression) => {
+                conditions.push("books_fts MATCH ?");
+                values.push(expression.into());
+                (
+                    "books_fts JOIN books ON books.id = books_fts.rowid",
+                    "bm25(books_fts, 10.0, 5.0, 1.0)",
+                )
+            }
+            None => ("books", "0.0"),
+        };
+        for pattern in Self::short_term_patterns(query) {
+            conditions.push(
+                r"(books.title LIKE ? ESCAPE '\' OR books.author LIKE ? ESCAPE '\' OR books.description LIKE ? ESCAPE '\')",
+            );
+            values.extend(std::iter::repeat_n(sea_orm::Value::from(pattern), 3));
+        }
+        values.push(limit.into());
+
+        let statement = Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            format!(
+                "SELECT books.*, {} AS rank FROM {} WHERE {} ORDER BY rank, books.id LIMIT ?",
+                rank,
+                source,
+                conditions.join(" AND ")
+            ),
+            values,
+        );
+
+        let rows = db
+            .query_all(statement)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut hits = rows
+            .iter()
+            .map(|row| Self::search_row_to_hit(row, query.terms()))
+            .collect::<Result<Vec<_>, DbErr>>()
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+        Self::attach_authors(&db, hits.iter_mut().map(|hit| &mut hit.book)).await?;
+        Ok(hits)
+    }
+
+    async fn count_by_class(
+        &self,
+        scheme: ClassificationScheme,
+        within: Option<&ClassPrefix>,
+        digits: Option<usize>,
+    ) -> Result<Vec<ClassCount>, DomainError> {
+        let db = self.db.connection();
+        let column = Self::class_column(scheme);
+        let code: SimpleExpr = match digits {
+            Some(digits) => Func::cust(Alias::new("substr"))
+                .arg(Expr::col(column))
+                .arg(1)
+                .arg(digits as i64)
+                .into(),
+            None => Expr::col(column).into(),
+        };
+
+        let rows: Vec<(String, i64)> = Self::active_books()
+            .select_only()
+            .column_as(code, "code")
+            .column_as(book::Column::Id.count(), "book_count")
+            .filter(column.is_not_null())
+            .filter(
+                Condition::all()
+                    .add_option(within.map(|prefix| column.starts_with(prefix.as_str()))),
+            )
+            .group_by(Expr::col(Alias::new("code")))
+            .order_by_asc(Expr::col(Alias::new("code")))
+            .into_tuple()
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(code, book_count)| ClassCount {
+                code,
+                book_count: book_count as u64,
+            })
+            .collect())
+    }
+
+    async fn find_trashed(&self) -> Result<Vec<Book>, DomainError> {
+        let db = self.db.connection();
+        let books = Self::trashed_books()
+            .order_by_desc(book::Column::DeletedAt)
+            .order_by_desc(book::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut books: Vec<Book> = books.into_iter().map(Self::db_to_domain).collect();
+        Self::attach_authors(&db, &mut books).await?;
+        Ok(books)
+    }
+
+    async fn find_trashed_by_id(&self, id: i32) -> Result<Option<Book>, DomainError> {
+        let db = self.db.connection();
+        let book = Self::trashed_books()
+            .filter(book::Column::Id.eq(id))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut book = book.map(Self::db_to_domain);
+        Self::attach_authors(&db, book.as_mut()).await?;
+        Ok(book)
+    }
+
+    async fn find_trashed_by_isbn(&self, isbn: &Isbn) -> Result<Option<Book>, DomainError> {
+        let db = self.db.connection();
+        let book = Self::trashed_books()
+            .filter(book::Column::Isbn.eq(isbn.as_isbn13()))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut book = book.map(Self::db_to_domain);
+        Self::attach_authors(&db, book.as_mut()).await?;
+        Ok(book)
+    }
+
+    async fn count_by_cover(&self, cover: &CoverHash) -> Result<u64, DomainError> {
+        let db = self.db.connection();
+        book::Entity::find()
+            .filter(book::Column::CoverHash.eq(cover.as_str()))
+            .count(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))
+    }
+
+    async fn save(&self, book: Book) -> Result<Book, DomainError> {
+        let db = self.db.connection();
+
+        // 本と著者の関連をまとめて保存する
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let saved = Self::save_with(&txn, book).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(saved)
+    }
+
+    async fn save_with_history(
+        &self,
+        book: Book,
+        change: ChangeRecord,
+    ) -> Result<Book, DomainError> {
+        let db = self.db.connection();
+
+        // 本と著者の関連・変更履歴をまとめて保存する
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let saved = Self::save_with(&txn, book).await?;
+        BookChangeRepositoryImpl::record_with(&txn, &saved, change).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(saved)
+    }
+
+    async fn save_all(
+        &self,
+        books: Vec<Book>,
+        source: BookChangeSource,
+    ) -> Result<Vec<Book>, DomainError> {
+        let db = self.db.connection();
+
+        // 途中で失敗した場合はすべて取り消す（トランザクションは破棄時にロールバックされる）
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut saved = Vec::with_capacity(books.len());
+        for mut book in books {
+            if book.authors().is_empty() {
+                Self::link_primary_author(&txn, &mut book).await?;
+            }
+            let book = Self::save_with(&txn, book).await?;
+            BookChangeRepositoryImpl::record_with(&txn, &book, ChangeRecord::created(source))
+                .await?;
+            saved.push(book);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(saved)
+    }
+
+    async fn find_citation_keys(&self) -> Result<HashMap<i32, String>, DomainError> {
+        let db = self.db.connection();
+        let rows: Vec<(i32, String)> = book::Entity::find()
+            .select_only()
+            .column(book::Column::Id)
+            .column(book::Column::CitationKey)
+            .filter(book::Column::CitationKey.is_not_null())
+            .into_tuple()
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn save_citation_keys(&self, keys: &[(i32, String)]) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        for (id, key) in keys {
+            book::Entity::update_many()
+                .col_expr(book::Column::CitationKey, Expr::value(key.clone()))
+                .filter(book::Column::Id.eq(*id))
+                .exec(&txn)
                 .await
-                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))
+    }
+
+    async fn link_external_id(
+        &self,
+        book_id: i32,
+        source: ExternalSource,
+        external_id: &str,
+    ) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let link = book_external_id::ActiveModel {
+            id: NotSet,
+            book_id: Set(book_id),
+            source: Set(source.as_str().to_string()),
+            external_id: Set(external_id.to_string()),
         };
 
-        // 保存後のIDをドメインモデルにセット
-        book.set_id(result.id);
+        book_external_id::Entity::insert(link)
+            .on_conflict(
+                OnConflict::columns([
+                    book_external_id::Column::Source,
+                    book_external_id::Column::ExternalId,
+                ])
+                .update_column(book_external_id::Column::BookId)
+                .to_owned(),
+            )
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
 
-        Ok(Self::db_to_domain(result))
+        Ok(())
+    }
+
+    async fn merge(
+        &self,
+        primary: Book,
+        merged: Vec<(Book, ChangeRecord)>,
+        change: ChangeRecord,
+    ) -> Result<Book, DomainError> {
+        let primary_id = primary
+            .id()
+            .ok_or_else(|| DomainError::InvalidState("Primary book must be saved".to_string()))?;
+        let other_ids = merged
+            .iter()
+            .map(|(book, _)| {
+                book.id().ok_or_else(|| {
+                    DomainError::InvalidState("Books to merge must be saved".to_string())
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let db = self.db.connection();
+        let db_error = |e: DbErr| DomainError::InvalidState(format!("Database error: {}", e));
+
+        // 途中で失敗した場合はすべて取り消す（トランザクションは破棄時にロールバックされる）
+        let txn = db.begin().await.map_err(db_error)?;
+
+        // 複数持てる記録はそのまま付け替える（読書セッションは読書記録に付いて移る）
+        let others = other_ids.iter().copied();
+        read_through::Entity::update_many()
+            .col_expr(read_through::Column::BookId, Expr::value(primary_id))
+            .filter(read_through::Column::BookId.is_in(others.clone()))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        book_note::Entity::update_many()
+            .col_expr(book_note::Column::BookId, Expr::value(primary_id))
+            .filter(book_note::Column::BookId.is_in(others.clone()))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        book_highlight::Entity::update_many()
+            .col_expr(book_highlight::Column::BookId, Expr::value(primary_id))
+            .filter(book_highlight::Column::BookId.is_in(others.clone()))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        book_external_id::Entity::update_many()
+            .col_expr(book_external_id::Column::BookId, Expr::value(primary_id))
+            .filter(book_external_id::Column::BookId.is_in(others.clone()))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        book_file::Entity::update_many()
+            .col_expr(book_file::Column::BookId, Expr::value(primary_id))
+            .filter(book_file::Column::BookId.is_in(others.clone()))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        copy::Entity::update_many()
+            .col_expr(copy::Column::BookId, Expr::value(primary_id))
+            .filter(copy::Column::BookId.is_in(others.clone()))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        // レビュー・シリーズは1冊に1つのため、まとめ先にない場合のみまとめる本の順に最初のものを引き継ぐ
+        let has_review = book_review::Entity::find()
+            .filter(book_review::Column::BookId.eq(primary_id))
+            .one(&txn)
+            .await
+            .map_err(db_error)?
+            .is_some();
+        if !has_review {
+            let reviews = book_review::Entity::find()
+                .filter(book_review::Column::BookId.is_in(others.clone()))
+                .all(&txn)
+                .await
+                .map_err(db_error)?;
+            let first = other_ids
+                .iter()
+                .find_map(|id| reviews.iter().find(|review| review.book_id == *id));
+            if let Some(review) = first {
+                let mut active_model: book_review::ActiveModel = review.clone().into();
+                active_model.book_id = Set(primary_id);
+                active_model.update(&txn).await.map_err(db_error)?;
+            }
+        }
+        let has_series = book_series::Entity::find()
+            .filter(book_series::Column::BookId.eq(primary_id))
+            .one(&txn)
+            .await
+            .map_err(db_error)?
+            .is_some();
+        if !has_series {
+            let volumes = book_series::Entity::find()
+                .filter(book_series::Column::BookId.is_in(others.clone()))
+                .all(&txn)
+                .await
+                .map_err(db_error)?;
+            let first = other_ids
+                .iter()
+                .find_map(|id| volumes.iter().find(|volume| volume.book_id == *id));
+            if let Some(volume) = first {
+                let mut active_model: book_series::ActiveModel = volume.clone().into();
+                active_model.book_id = Set(primary_id);
+                active_model.update(&txn).await.map_err(db_error)?;
+            }
+        }
+
+        // タグ・本棚はまとめ先にないものだけ追加する（本棚では末尾に並べる）
+        let tag_links = book_tag::Entity::find()
+            .filter(book_tag::Column::BookId.is_in(others.clone()))
+            .all(&txn)
+            .await
+            .map_err(db_error)?;
+        for link in tag_links {
+            book_tag::Entity::insert(book_tag::ActiveModel {
+                book_id: Set(primary_id),
+                tag_id: Set(link.tag_id),
+            })
+            .on_conflict(
+                OnConflict::columns([book_tag::Column::BookId, book_tag::Column::TagId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        }
+        let shelf_entries = shelf_book::Entity::find()
+            .filter(shelf_book::Column::BookId.is_in(others.clone()))
+            .all(&txn)
+            .await
+            .map_err(db_error)?;
+        for id in &other_ids {
+            for entry in shelf_entries.iter().filter(|entry| entry.book_id == *id) {
+                let max: Option<Option<i32>> = shelf_book::Entity::find()
+                    .filter(shelf_book::Column::ShelfId.eq(entry.shelf_id))
+                    .select_only()
+                    .column_as(shelf_book::Column::Position.max(), "max_position")
+                    .into_tuple()
+                    .one(&txn)
+                    .await
+                    .map_err(db_error)?;
+                let position = max.flatten().map_or(0, |position| position + 1);
+
+                shelf_book::Entity::insert(shelf_book::ActiveModel {
+                    shelf_id: Set(entry.shelf_id),
+                    book_id: Set(primary_id),
+                    position: Set(position),
+                })
+                .on_conflict(
+                    OnConflict::columns([shelf_book::Column::ShelfId, shelf_book::Column::BookId])
+                        .do_nothing()
+                        .to_owned(),
+                )
+                .do_nothing()
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+            }
+        }
+
+        // ISBNは一意のため、まとめる本をゴミ箱に移して保存してからまとめ先を保存する
+        // （まとめる本に残ったタグ・本棚は、ゴミ箱から元に戻したときのためにそのまま残す）
+        for (book, change) in merged {
+            let saved = Self::save_with(&txn, book).await?;
+            BookChangeRepositoryImpl::record_with(&txn, &saved, change).await?;
+        }
+
+        let saved = Self::save_with(&txn, primary).await?;
+        BookChangeRepositoryImpl::record_with(&txn, &saved, change).await?;
+
+        txn.commit().await.map_err(db_error)?;
+
+        Ok(saved)
     }
 
     async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
         let book = book::Entity::find_by_id(id)
-            .one(&self.db)
+            .one(&db)
             .await
             .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
             .ok_or_else(|| DomainError::NotFound(format!("Book with id {} not found", id)))?;
 
         let active_model: book::ActiveModel = book.into();
         active_model
-            .delete(&self.db)
+            .delete(&db)
             .await
             .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
 
         Ok(())
     }
+
+    async fn delete_trashed(&self, before: Option<DateTime<Utc>>) -> Result<u64, DomainError> {
+        let db = self.db.connection();
+        let result = book::Entity::delete_many()
+            .filter(book::Column::DeletedAt.is_not_null())
+            .filter(
+                Condition::all()
+                    .add_option(before.map(|before| book::Column::DeletedAt.lte(before))),
+            )
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn refresh_sort_keys(&self) -> Result<u64, DomainError> {
+        let db = self.db.connection();
+        let models = book::Entity::find()
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut updated = 0;
+        for model in models {
+            let (id, stored_title, stored_author) = (
+                model.id,
+                model.title_sort_key.clone(),
+                model.author_sort_key.clone(),
+            );
+            let book = Self::db_to_domain(model);
+            let title_key = book.title_sort_key().as_str();
+            let author_key = book.author_sort_key().map(SortKey::as_str);
+            if title_key == stored_title && author_key == stored_author.as_deref() {
+                continue;
+            }
+
+            book::Entity::update_many()
+                .col_expr(book::Column::TitleSortKey, Expr::value(title_key))
+                .col_expr(book::Column::AuthorSortKey, Expr::value(author_key))
+                .filter(book::Column::Id.eq(id))
+                .exec(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+            updated += 1;
+        }
+
+        Ok(updated)
+    }
 }
@@ -0,0 +1,220 @@
+// Library Infrastructure Layer - BookChange リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::{
+        book::Book,
+        book_change::{BookChange, BookSnapshot, ChangeRecord},
+    },
+    repositories::book_change::BookChangeRepository,
+    value_objects::{
+        author_role::AuthorRole, book_change_action::BookChangeAction,
+        book_change_source::BookChangeSource,
+    },
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::book_change;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, NotSet, QueryOrder, Set, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+
+/// 本の書誌情報の保存形式（JSON）
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotRecord {
+    title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title_reading: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    author_reading: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    published_year: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    publisher: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    isbn: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ndc: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ddc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<AuthorRecord>,
+}
+
+/// 著者一覧の1人分の保存形式（JSON）
+#[derive(Debug, Serialize, Deserialize)]
+struct AuthorRecord {
+    name: String,
+    role: String,
+}
+
+impl From<&BookSnapshot> for SnapshotRecord {
+    fn from(snapshot: &BookSnapshot) -> Self {
+        Self {
+            title: snapshot.title.clone(),
+            author: snapshot.author.clone(),
+            title_reading: snapshot.title_reading.clone(),
+            author_reading: snapshot.author_reading.clone(),
+            description: snapshot.description.clone(),
+            published_year: snapshot.published_year,
+            publisher: snapshot.publisher.clone(),
+            isbn: snapshot.isbn.clone(),
+            ndc: snapshot.ndc.clone(),
+            ddc: snapshot.ddc.clone(),
+            authors: snapshot
+                .authors
+                .iter()
+                .map(|(name, role)| AuthorRecord {
+                    name: name.clone(),
+                    role: role.as_str().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<SnapshotRecord> for BookSnapshot {
+    type Error = DomainError;
+
+    fn try_from(record: SnapshotRecord) -> Result<Self, Self::Error> {
+        let authors = record
+            .authors
+            .into_iter()
+            .map(|a| Ok((a.name, AuthorRole::parse(&a.role)?)))
+            .collect::<Result<_, DomainError>>()?;
+        Ok(Self {
+            title: record.title,
+            author: record.author,
+            title_reading: record.title_reading,
+            author_reading: record.author_reading,
+            description: record.description,
+            published_year: record.published_year,
+            publisher: record.publisher,
+            isbn: record.isbn,
+            ndc: record.ndc,
+            ddc: record.ddc,
+            authors,
+        })
+    }
+}
+
+/// BookChangeRepository の SeaORM実装
+pub struct BookChangeRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl BookChangeRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: book_change::Model) -> Result<BookChange, DomainError> {
+        let decode = |json: &str| {
+            serde_json::from_str::<SnapshotRecord>(json)
+                .map_err(|e| {
+                    DomainError::InvalidState(format!(
+                        "Invalid snapshot in book change {}: {}",
+                        model.id, e
+                    ))
+                })
+                .and_then(BookSnapshot::try_from)
+        };
+        let before = model.before_state.as_deref().map(decode).transpose()?;
+        let after = decode(&model.after_state)?;
+
+        Ok(BookChange::reconstruct(
+            model.id,
+            model.book_id,
+            model.revision,
+            BookChangeAction::parse(&model.action)?,
+            BookChangeSource::parse(&model.source)?,
+            before,
+            after,
+            model.changed_at,
+        ))
+    }
+
+    fn snapshot_json(snapshot: &BookSnapshot) -> Result<String, DomainError> {
+        serde_json::to_string(&SnapshotRecord::from(snapshot))
+            .map_err(|e| DomainError::InvalidState(format!("Failed to encode snapshot: {}", e)))
+    }
+
+    /// 保存した本の変更を記録する（呼び出し側のトランザクション内で本の保存と一緒に実行する）
+    pub(crate) async fn record_with<C: ConnectionTrait>(
+        db: &C,
+        book: &Book,
+        change: ChangeRecord,
+    ) -> Result<(), DomainError> {
+        let after = BookSnapshot::of(book);
+        if !change.is_recorded(&after) {
+            return Ok(());
+        }
+
+        let book_id = book.id().ok_or_else(|| {
+            DomainError::InvalidState("Book must be saved before recording a change".to_string())
+        })?;
+        let latest = book_change::Entity::find()
+            .filter(book_change::Column::BookId.eq(book_id))
+            .order_by_desc(book_change::Column::Revision)
+            .one(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+        let change = change.into_change(
+            book_id,
+            latest.map_or(1, |latest| latest.revision + 1),
+            after,
+        );
+
+        let active_model = book_change::ActiveModel {
+            id: NotSet,
+            book_id: Set(change.book_id()),
+            revision: Set(change.revision()),
+            action: Set(change.action().as_str().to_string()),
+            source: Set(change.source().as_str().to_string()),
+            before_state: Set(change.before().map(Self::snapshot_json).transpose()?),
+            after_state: Set(Self::snapshot_json(change.after())?),
+            changed_at: Set(change.changed_at()),
+        };
+        active_model
+            .insert(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BookChangeRepository for BookChangeRepositoryImpl {
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<BookChange>, DomainError> {
+        let db = self.db.connection();
+        let changes = book_change::Entity::find()
+            .filter(book_change::Column::BookId.eq(book_id))
+            .order_by_asc(book_change::Column::Revision)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        changes.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn find_revision(
+        &self,
+        book_id: i32,
+        revision: i32,
+    ) -> Result<Option<BookChange>, DomainError> {
+        let db = self.db.connection();
+        let change = book_change::Entity::find()
+            .filter(book_change::Column::BookId.eq(book_id))
+            .filter(book_change::Column::Revision.eq(revision))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        change.map(Self::db_to_domain).transpose()
+    }
+}
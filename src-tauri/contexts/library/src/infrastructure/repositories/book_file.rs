@@ -0,0 +1,119 @@
+// Library Infrastructure Layer - BookFile リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::book_file::BookFile, repositories::book_file::BookFileRepository,
+    value_objects::ebook_format::EbookFormat,
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::book_file;
+use sea_orm::{ActiveModelTrait, NotSet, QueryOrder, Set, entity::prelude::*};
+use std::path::{Path, PathBuf};
+
+/// BookFileRepository の SeaORM実装
+pub struct BookFileRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl BookFileRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(file: &BookFile) -> book_file::ActiveModel {
+        book_file::ActiveModel {
+            id: file.id().map_or(NotSet, Set),
+            book_id: Set(file.book_id()),
+            path: Set(file.path().to_string_lossy().into_owned()),
+            format: Set(file.format().as_str().to_string()),
+            managed: Set(file.managed()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: book_file::Model) -> Result<BookFile, DomainError> {
+        Ok(BookFile::reconstruct(
+            model.id,
+            model.book_id,
+            PathBuf::from(model.path),
+            EbookFormat::parse(&model.format)?,
+            model.managed,
+        ))
+    }
+}
+
+#[async_trait]
+impl BookFileRepository for BookFileRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<BookFile>, DomainError> {
+        let db = self.db.connection();
+        let file = book_file::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        file.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<BookFile>, DomainError> {
+        let db = self.db.connection();
+        let files = book_file::Entity::find()
+            .filter(book_file::Column::BookId.eq(book_id))
+            .order_by_asc(book_file::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        files.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn find_by_path(&self, path: &Path) -> Result<Option<BookFile>, DomainError> {
+        let db = self.db.connection();
+        let file = book_file::Entity::find()
+            .filter(book_file::Column::Path.eq(path.to_string_lossy().into_owned()))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        file.map(Self::db_to_domain).transpose()
+    }
+
+    async fn save(&self, file: BookFile) -> Result<BookFile, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&file);
+
+        let result = if file.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Self::db_to_domain(result)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = book_file::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Book file with id {} not found",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+}
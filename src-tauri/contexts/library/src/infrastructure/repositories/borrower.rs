@@ -0,0 +1,96 @@
+// Library Infrastructure Layer - Borrower リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{entities::borrower::Borrower, repositories::borrower::BorrowerRepository};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::borrower;
+use sea_orm::{ActiveModelTrait, NotSet, QueryOrder, Set, entity::prelude::*};
+
+/// BorrowerRepository の SeaORM実装
+pub struct BorrowerRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl BorrowerRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(borrower: &Borrower) -> borrower::ActiveModel {
+        borrower::ActiveModel {
+            id: borrower.id().map_or(NotSet, Set),
+            name: Set(borrower.name().to_string()),
+            email: Set(borrower.email().map(str::to_string)),
+            phone: Set(borrower.phone().map(str::to_string)),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: borrower::Model) -> Borrower {
+        Borrower::reconstruct(model.id, model.name, model.email, model.phone)
+    }
+}
+
+#[async_trait]
+impl BorrowerRepository for BorrowerRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Borrower>, DomainError> {
+        let db = self.db.connection();
+        let borrower = borrower::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(borrower.map(Self::db_to_domain))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Borrower>, DomainError> {
+        let db = self.db.connection();
+        let borrowers = borrower::Entity::find()
+            .order_by_asc(borrower::Column::Name)
+            .order_by_asc(borrower::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(borrowers.into_iter().map(Self::db_to_domain).collect())
+    }
+
+    async fn save(&self, borrower: Borrower) -> Result<Borrower, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&borrower);
+
+        let result = if borrower.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Ok(Self::db_to_domain(result))
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = borrower::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Borrower with id {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+// Library Infrastructure Layer - Calibre ライブラリ読み込み実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::calibre::{CalibreBook, CalibreLibraryRepository};
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, DbErr, QueryResult, SqlxSqliteConnector,
+    Statement,
+    sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Calibre のライブラリディレクトリ内のデータベースファイル名
+const METADATA_DB_FILE_NAME: &str = "metadata.db";
+
+/// CalibreLibraryRepository の SeaORM実装（metadata.db を読み取り専用で開く）
+#[derive(Default)]
+pub struct CalibreLibraryRepositoryImpl;
+
+impl CalibreLibraryRepositoryImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// ライブラリのディレクトリが指定された場合は metadata.db のパスにする
+    fn metadata_db_path(path: &Path) -> Result<PathBuf, DomainError> {
+        let path = if path.is_dir() {
+            path.join(METADATA_DB_FILE_NAME)
+        } else {
+            path.to_path_buf()
+        };
+        if !path.is_file() {
+            return Err(DomainError::NotFound(format!(
+                "Calibre library not found at {}",
+                path.display()
+            )));
+        }
+        Ok(path)
+    }
+
+    async fn connect(path: &Path) -> Result<DatabaseConnection, DomainError> {
+        let options = SqliteConnectOptions::new().filename(path).read_only(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .map_err(|e| DomainError::IoError(format!("Failed to open Calibre library: {}", e)))?;
+        Ok(SqlxSqliteConnector::from_sqlx_sqlite_pool(pool))
+    }
+
+    async fn query_all(
+        db: &DatabaseConnection,
+        sql: &str,
+    ) -> Result<Vec<QueryResult>, DomainError> {
+        db.query_all(Statement::from_string(DbBackend::Sqlite, sql))
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Calibre library error: {}", e)))
+    }
+
+    /// 出版日の年（Calibre は未設定の出版日を 0101-01-01 として保存する）
+    fn published_year(pubdate: Option<&str>) -> Option<i32> {
+        pubdate
+            .and_then(|date| date.get(..4))
+            .and_then(|year| year.parse::<i32>().ok())
+            .filter(|year| *year >= 1000)
+    }
+
+    async fn read_with(db: &DatabaseConnection) -> Result<Vec<CalibreBook>, DomainError> {
+        let to_error =
+            |e: DbErr| DomainError::InvalidState(format!("Calibre library error: {}", e));
+
+        let mut books = BTreeMap::new();
+        for row in Self::query_all(db, "SELECT id, title, pubdate, isbn FROM books").await? {
+            let calibre_id: i32 = row.try_get("", "id").map_err(to_error)?;
+            let pubdate: Option<String> = row.try_get("", "pubdate").map_err(to_error)?;
+            let isbn: Option<String> = row.try_get("", "isbn").map_err(to_error)?;
+            books.insert(
+                calibre_id,
+                CalibreBook {
+                    calibre_id,
+                    title: row.try_get("", "title").map_err(to_error)?,
+                    authors: Vec::new(),
+                    series: None,
+                    tags: Vec::new(),
+                    isbn: isbn.filter(|isbn| !isbn.trim().is_empty()),
+                    publisher: None,
+                    comments: None,
+                    published_year: Self::published_year(pubdate.as_deref()),
+                },
+            );
+        }
+
+        // 関連する項目を (本のID, 値) の組で読み込んで各本に割り当てる
+        let rows = Self::query_all(
+            db,
+            "SELECT l.book, a.name FROM books_authors_link l \
+             JOIN authors a ON a.id = l.author ORDER BY l.book, l.id",
+        )
+        .await?;
+        for (book, name) in Self::pairs::<String>(&rows, "name")? {
+            if let Some(calibre_book) = books.get_mut(&book) {
+                calibre_book.authors.push(name);
+            }
+        }
+
+        let rows = Self::query_all(
+            db,
+            "SELECT l.book, t.name FROM books_tags_link l \
+             JOIN tags t ON t.id = l.tag ORDER BY l.book, t.name",
+        )
+        .await?;
+        for (book, name) in Self::pairs::<String>(&rows, "name")? {
+            if let Some(calibre_book) = books.get_mut(&book) {
+                calibre_book.tags.push(name);
+            }
+        }
+
+        let rows = Self::query_all(
+            db,
+            "SELECT l.book, s.name, b.series_index FROM books_series_link l \
+             JOIN series s ON s.id = l.series JOIN books b ON b.id = l.book",
+        )
+        .await?;
+        for row in &rows {
+            let book: i32 = row.try_get("", "book").map_err(to_error)?;
+            let name: String = row.try_get("", "name").map_err(to_error)?;
+            let index: f64 = row.try_get("", "series_index").map_err(to_error)?;
+            if let Some(calibre_book) = books.get_mut(&book) {
+                calibre_book.series = Some((name, index));
+            }
+        }
+
+        let rows = Self::query_all(
+            db,
+            "SELECT l.book, p.name FROM books_publishers_link l \
+             JOIN publishers p ON p.id = l.publisher",
+        )
+        .await?;
+        for (book, name) in Self::pairs::<String>(&rows, "name")? {
+            if let Some(calibre_book) = books.get_mut(&book) {
+                calibre_book.publisher = Some(name);
+            }
+        }
+
+        // 識別子の isbn は本の ISBN 欄より優先する
+        let rows =
+            Self::query_all(db, "SELECT book, val FROM identifiers WHERE type = 'isbn'").await?;
+        for (book, isbn) in Self::pairs::<String>(&rows, "val")? {
+            if let Some(calibre_book) = books.get_mut(&book)
+                && !isbn.trim().is_empty()
+            {
+                calibre_book.isbn = Some(isbn);
+            }
+        }
+
+        let rows = Self::query_all(db, "SELECT book, text FROM comments").await?;
+        for (book, text) in Self::pairs::<String>(&rows, "text")? {
+            if let Some(calibre_book) = books.get_mut(&book)
+                && !text.trim().is_empty()
+            {
+                calibre_book.comments = Some(text);
+            }
+        }
+
+        Ok(books.into_values().collect())
+    }
+
+    /// `book` 列と指定した列の組を取得する
+    fn pairs<T>(rows: &[QueryResult], column: &str) -> Result<Vec<(i32, T)>, DomainError>
+    where
+        T: sea_orm::TryGetable,
+    {
+        rows.iter()
+            .map(|row| Ok((row.try_get("", "book")?, row.try_get("", column)?)))
+            .collect::<Result<Vec<_>, DbErr>>()
+            .map_err(|e| DomainError::InvalidState(format!("Calibre library error: {}", e)))
+    }
+}
+
+#[async_trait]
+impl CalibreLibraryRepository for CalibreLibraryRepositoryImpl {
+    async fn read_books(&self, path: &Path) -> Result<Vec<CalibreBook>, DomainError> {
+        let path = Self::metadata_db_path(path)?;
+        let db = Self::connect(&path).await?;
+
+        let books = Self::read_with(&db).await;
+        // 読み込みの成否にかかわらず接続を閉じる（ファイルのロックを残さない）
+        let _ = db.close().await;
+        books
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_read_books_from_metadata_db() {
+        let library_dir = tempfile::tempdir().unwrap();
+        let metadata_db = library_dir.path().join(METADATA_DB_FILE_NAME);
+        let calibre = Database::connect(format!("sqlite://{}?mode=rwc", metadata_db.display()))
+            .await
+            .unwrap();
+        calibre
+            .execute_unprepared(
+                "CREATE TABLE books (id INTEGER PRIMARY KEY, title TEXT NOT NULL, pubdate TIMESTAMP, series_index REAL NOT NULL DEFAULT 1.0, isbn TEXT DEFAULT '');
+                 CREATE TABLE authors (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+                 CREATE TABLE books_authors_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, author INTEGER NOT NULL);
+                 CREATE TABLE series (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+                 CREATE TABLE books_series_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, series INTEGER NOT NULL);
+                 CREATE TABLE tags (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+                 CREATE TABLE books_tags_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, tag INTEGER NOT NULL);
+                 CREATE TABLE publishers (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+                 CREATE TABLE books_publishers_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, publisher INTEGER NOT NULL);
+                 CREATE TABLE identifiers (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, type TEXT NOT NULL, val TEXT NOT NULL);
+                 CREATE TABLE comments (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, text TEXT NOT NULL);
+                 INSERT INTO books VALUES
+                     (1, 'Good Omens', '1990-05-01 00:00:00+00:00', 1.0, '9780060853976'),
+                     (2, 'Dune Messiah', '0101-01-01 00:00:00+00:00', 2.5, ''),
+                     (3, 'Untitled', NULL, 1.0, '');
+                 INSERT INTO authors VALUES (1, 'Terry Pratchett'), (2, 'Neil Gaiman'), (3, 'Frank Herbert');
+                 INSERT INTO books_authors_link VALUES (1, 1, 2), (2, 1, 1), (3, 2, 3);
+                 INSERT INTO series VALUES (1, 'Dune Chronicles');
+                 INSERT INTO books_series_link VALUES (1, 2, 1);
+                 INSERT INTO tags VALUES (1, 'Humor'), (2, 'Fantasy');
+                 INSERT INTO books_tags_link VALUES (1, 1, 1), (2, 1, 2);
+                 INSERT INTO publishers VALUES (1, 'Workman');
+                 INSERT INTO books_publishers_link VALUES (1, 1, 1);
+                 INSERT INTO identifiers VALUES (1, 2, 'isbn', '9780441172696'), (2, 2, 'goodreads', '106'), (3, 3, 'isbn', ' ');
+                 INSERT INTO comments VALUES (1, 1, '<p>The world ends on a Saturday.</p>'), (2, 2, '  ');",
+            )
+            .await
+            .unwrap();
+        calibre.close().await.unwrap();
+
+        let books = CalibreLibraryRepositoryImpl::new()
+            .read_books(library_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(
+            books,
+            vec![
+                CalibreBook {
+                    calibre_id: 1,
+                    title: "Good Omens".to_string(),
+                    // 著者は Calibre での表示順（リンクの登録順）
+                    authors: vec!["Neil Gaiman".to_string(), "Terry Pratchett".to_string()],
+                    series: None,
+                    tags: vec!["Fantasy".to_string(), "Humor".to_string()],
+                    isbn: Some("9780060853976".to_string()),
+                    publisher: Some("Workman".to_string()),
+                    comments: Some("<p>The world ends on a Saturday.</p>".to_string()),
+                    published_year: Some(1990),
+                },
+                CalibreBook {
+                    calibre_id: 2,
+                    title: "Dune Messiah".to_string(),
+                    authors: vec!["Frank Herbert".to_string()],
+                    // 巻数は小数のまま読む
+                    series: Some(("Dune Chronicles".to_string(), 2.5)),
+                    tags: Vec::new(),
+                    // 識別子の isbn を使い、他の種類の識別子は使わない
+                    isbn: Some("9780441172696".to_string()),
+                    publisher: None,
+                    // 空白だけの紹介文はなし
+                    comments: None,
+                    // 未設定の出版日（0101-01-01）は出版年なし
+                    published_year: None,
+                },
+                CalibreBook {
+                    calibre_id: 3,
+                    title: "Untitled".to_string(),
+                    authors: Vec::new(),
+                    series: None,
+                    tags: Vec::new(),
+                    // 空白だけの識別子・ISBN欄はなし
+                    isbn: None,
+                    publisher: None,
+                    comments: None,
+                    published_year: None,
+                },
+            ]
+        );
+
+        // metadata.db のパスを直接指定しても読める
+        let books = CalibreLibraryRepositoryImpl::new()
+            .read_books(&metadata_db)
+            .await
+            .unwrap();
+        assert_eq!(books.len(), 3);
+
+        let result = CalibreLibraryRepositoryImpl::new()
+            .read_books(&library_dir.path().join("missing"))
+            .await;
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+    }
+}
@@ -0,0 +1,176 @@
+// Library Infrastructure Layer - Copy リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::copy::Copy,
+    repositories::copy::CopyRepository,
+    value_objects::{copy_condition::CopyCondition, copy_format::CopyFormat, price::Price},
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::{book, copy};
+use sea_orm::{
+    ActiveModelTrait, JoinType, NotSet, QueryOrder, QuerySelect, Set, entity::prelude::*,
+};
+use std::collections::HashMap;
+
+/// CopyRepository の SeaORM実装
+pub struct CopyRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl CopyRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(copy: &Copy) -> copy::ActiveModel {
+        copy::ActiveModel {
+            id: copy.id().map_or(NotSet, Set),
+            book_id: Set(copy.book_id()),
+            format: Set(copy.format().as_str().to_string()),
+            condition: Set(copy.condition().map(|c| c.as_str().to_string())),
+            acquired_on: Set(copy.acquired_on()),
+            price_amount: Set(copy.price().map(Price::minor_units)),
+            price_currency: Set(copy.price().map(|p| p.currency().to_string())),
+            location_id: Set(copy.location_id()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: copy::Model) -> Result<Copy, DomainError> {
+        let price = match (model.price_amount, model.price_currency) {
+            (Some(amount), Some(currency)) => Some(Price::from_minor_units(amount, &currency)?),
+            _ => None,
+        };
+
+        Ok(Copy::reconstruct(
+            model.id,
+            model.book_id,
+            CopyFormat::parse(&model.format)?,
+            model
+                .condition
+                .as_deref()
+                .map(CopyCondition::parse)
+                .transpose()?,
+            model.acquired_on,
+            price,
+            model.location_id,
+        ))
+    }
+
+    /// ゴミ箱にない本の冊（本のタイトルの読み順）
+    fn active_copies() -> Select<copy::Entity> {
+        copy::Entity::find()
+            .join(JoinType::InnerJoin, copy::Relation::Book.def())
+            .filter(book::Column::DeletedAt.is_null())
+            .order_by_asc(book::Column::TitleSortKey)
+            .order_by_asc(copy::Column::Id)
+    }
+}
+
+#[async_trait]
+impl CopyRepository for CopyRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Copy>, DomainError> {
+        let db = self.db.connection();
+        let copy = copy::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        copy.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Copy>, DomainError> {
+        let db = self.db.connection();
+        let copies = copy::Entity::find()
+            .filter(copy::Column::BookId.eq(book_id))
+            .order_by_asc(copy::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        copies.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn find_in_locations(&self, location_ids: &[i32]) -> Result<Vec<Copy>, DomainError> {
+        let db = self.db.connection();
+        let copies = Self::active_copies()
+            .filter(copy::Column::LocationId.is_in(location_ids.iter().copied()))
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        copies.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn find_unplaced(&self) -> Result<Vec<Copy>, DomainError> {
+        let db = self.db.connection();
+        let copies = Self::active_copies()
+            .filter(copy::Column::LocationId.is_null())
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        copies.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn count_by_location(&self) -> Result<HashMap<i32, u64>, DomainError> {
+        let db = self.db.connection();
+        let counts: Vec<(i32, i64)> = copy::Entity::find()
+            .select_only()
+            .column(copy::Column::LocationId)
+            .column_as(copy::Column::Id.count(), "copy_count")
+            .join(JoinType::InnerJoin, copy::Relation::Book.def())
+            .filter(book::Column::DeletedAt.is_null())
+            .filter(copy::Column::LocationId.is_not_null())
+            .group_by(copy::Column::LocationId)
+            .into_tuple()
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(counts
+            .into_iter()
+            .map(|(location_id, count)| (location_id, count as u64))
+            .collect())
+    }
+
+    async fn save(&self, copy: Copy) -> Result<Copy, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&copy);
+
+        let result = if copy.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Self::db_to_domain(result)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = copy::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Copy with id {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+// Library Infrastructure Layer - 表紙画像の保存実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::cover::{CoverImageRepository, CoverSize};
+use crate::domain::value_objects::cover_hash::CoverHash;
+use async_trait::async_trait;
+use image::{
+    DynamicImage, ImageDecoder, ImageReader, RgbImage, codecs::jpeg::JpegEncoder,
+    imageops::FilterType,
+};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// 受け付ける画像ファイルの最大サイズ（バイト）
+const MAX_IMAGE_BYTES: usize = 20 * 1024 * 1024;
+/// 保存する画像の長辺の最大ピクセル数（これより大きい画像は縮小する）
+const MAX_FULL_DIMENSION: u32 = 2400;
+/// サムネイルの大きさ（文庫本に近い 2:3 の縦長）
+const THUMBNAIL_WIDTH: u32 = 240;
+const THUMBNAIL_HEIGHT: u32 = 360;
+/// JPEG の画質
+const FULL_QUALITY: u8 = 90;
+const THUMBNAIL_QUALITY: u8 = 85;
+
+/// 画像・サムネイルの保存先ディレクトリ名
+const FULL_DIR_NAME: &str = "full";
+const THUMBNAIL_DIR_NAME: &str = "thumbnails";
+
+/// 正規化済みの画像とサムネイル（どちらも JPEG）
+struct ProcessedCover {
+    hash: CoverHash,
+    full: Vec<u8>,
+    thumbnail: Vec<u8>,
+}
+
+/// CoverImageRepository のファイルシステム実装
+///
+/// `<root>/full/<ハッシュの先頭2文字>/<ハッシュ>.jpg` に画像を、
+/// `<root>/thumbnails/...` に同じ名前でサムネイルを保存する。
+pub struct CoverImageRepositoryImpl {
+    root: PathBuf,
+}
+
+impl CoverImageRepositoryImpl {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// 画像の保存先のパス
+    fn path_for(&self, hash: &CoverHash, size: CoverSize) -> PathBuf {
+        let dir = match size {
+            CoverSize::Full => FULL_DIR_NAME,
+            CoverSize::Thumbnail => THUMBNAIL_DIR_NAME,
+        };
+        self.root
+            .join(dir)
+            .join(&hash.as_str()[..2])
+            .join(format!("{}.jpg", hash))
+    }
+
+    /// 画像を解析し、向きを補正して JPEG に再エンコードする
+    ///
+    /// 再エンコードにより EXIF などのメタデータは取り除かれる。
+    /// ハッシュは正規化後の画像から計算するため、メタデータだけが異なる画像は同じものとして扱う。
+    fn process(bytes: &[u8]) -> Result<ProcessedCover, DomainError> {
+        let invalid = |e: image::ImageError| {
+            DomainError::ValidationError(format!("Unsupported cover image: {}", e))
+        };
+
+        let reader = ImageReader::new(Cursor::new(bytes))
+            .with_guessed_format()
+            .map_err(|e| DomainError::IoError(format!("Failed to read cover image: {}", e)))?;
+        let mut decoder = reader.into_decoder().map_err(invalid)?;
+        let orientation = decoder.orientation().map_err(invalid)?;
+        let mut image = DynamicImage::from_decoder(decoder).map_err(invalid)?;
+        image.apply_orientation(orientation);
+
+        if image.width().max(image.height()) > MAX_FULL_DIMENSION {
+            image = image.resize(MAX_FULL_DIMENSION, MAX_FULL_DIMENSION, FilterType::Lanczos3);
+        }
+        let image = DynamicImage::ImageRgb8(Self::flatten(&image));
+
+        let full = Self::encode(&image, FULL_QUALITY)?;
+        let thumbnail = Self::encode(
+            &image.resize_to_fill(THUMBNAIL_WIDTH, THUMBNAIL_HEIGHT, FilterType::Lanczos3),
+            THUMBNAIL_QUALITY,
+        )?;
+
+        Ok(ProcessedCover {
+            hash: CoverHash::of(&full),
+            full,
+            thumbnail,
+        })
+    }
+
+    /// 透過部分を白で塗りつぶす（JPEG は透過を扱えないため）
+    fn flatten(image: &DynamicImage) -> RgbImage {
+        let rgba = image.to_rgba8();
+        RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+            let [r, g, b, a] = rgba.get_pixel(x, y).0;
+            let blend = |c: u8| {
+                let value = (u16::from(c) * u16::from(a) + 255 * (255 - u16::from(a))) / 255;
+                u8::try_from(value).unwrap_or(u8::MAX)
+            };
+            image::Rgb([blend(r), blend(g), blend(b)])
+        })
+    }
+
+    fn encode(image: &DynamicImage, quality: u8) -> Result<Vec<u8>, DomainError> {
+        let mut bytes = Vec::new();
+        image
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, quality))
+            .map_err(|e| DomainError::IoError(format!("Failed to encode cover image: {}", e)))?;
+        Ok(bytes)
+    }
+
+    /// 一時ファイルに書き込んでから置き換える（書き込み途中のファイルを読ませないため）
+    async fn write_atomically(path: &Path, bytes: &[u8]) -> Result<(), DomainError> {
+        let io_error =
+            |e: std::io::Error| DomainError::IoError(format!("Failed to save cover image: {}", e));
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).await.map_err(io_error)?;
+        }
+        let temp_path = path.with_extension("jpg.tmp");
+        fs::write(&temp_path, bytes).await.map_err(io_error)?;
+        fs::rename(&temp_path, path).await.map_err(io_error)
+    }
+}
+
+#[async_trait]
+impl CoverImageRepository for CoverImageRepositoryImpl {
+    async fn save(&self, bytes: Vec<u8>) -> Result<CoverHash, DomainError> {
+        // ビジネスルール: 大きすぎる画像は受け付けない
+        if bytes.len() > MAX_IMAGE_BYTES {
+            return Err(DomainError::ValidationError(format!(
+                "Cover image must be {} MB or less",
+                MAX_IMAGE_BYTES / 1024 / 1024
+            )));
+        }
+
+        // 画像の変換は時間がかかるため、非同期処理のスレッドを塞がないようにする
+        let processed = tokio::task::spawn_blocking(move || Self::process(&bytes))
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Cover processing failed: {}", e)))??;
+
+        let full_path = self.path_for(&processed.hash, CoverSize::Full);
+        let thumbnail_path = self.path_for(&processed.hash, CoverSize::Thumbnail);
+        // 同じ画像が保存済みの場合は書き込まない
+        if !(full_path.is_file() && thumbnail_path.is_file()) {
+            Self::write_atomically(&thumbnail_path, &processed.thumbnail).await?;
+            Self::write_atomically(&full_path, &processed.full).await?;
+        }
+
+        Ok(processed.hash)
+    }
+
+    async fn save_file(&self, path: &Path) -> Result<CoverHash, DomainError> {
+        let metadata = fs::metadata(path).await.map_err(|_| {
+            DomainError::NotFound(format!("Cover image not found at {}", path.display()))
+        })?;
+        if !metadata.is_file() {
+            return Err(DomainError::NotFound(format!(
+                "Cover image not found at {}",
+                path.display()
+            )));
+        }
+        if usize::try_from(metadata.len()).map_or(true, |len| len > MAX_IMAGE_BYTES) {
+            return Err(DomainError::ValidationError(format!(
+                "Cover image must be {} MB or less",
+                MAX_IMAGE_BYTES / 1024 / 1024
+            )));
+        }
+
+        let bytes = fs::read(path)
+            .await
+            .map_err(|e| DomainError::IoError(format!("Failed to read cover image: {}", e)))?;
+        self.save(bytes).await
+    }
+
+    async fn read(
+        &self,
+        hash: &CoverHash,
+        size: CoverSize,
+    ) -> Result<Option<Vec<u8>>, DomainError> {
+        match fs::read(self.path_for(hash, size)).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(DomainError::IoError(format!(
+                "Failed to read cover image: {}",
+                e
+            ))),
+        }
+    }
+
+    async fn delete(&self, hash: &CoverHash) -> Result<(), DomainError> {
+        for size in [CoverSize::Full, CoverSize::Thumbnail] {
+            match fs::remove_file(self.path_for(hash, size)).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    return Err(DomainError::IoError(format!(
+                        "Failed to delete cover image: {}",
+                        e
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgba, RgbaImage};
+
+    /// 向き（Orientation）だけを持つ EXIF の APP1 セグメント
+    fn exif_segment(orientation: u8) -> Vec<u8> {
+        let mut payload = b"Exif\0\0MM\0*\0\0\0\x08".to_vec();
+        payload.extend_from_slice(&[0, 1]); // エントリ数
+        payload.extend_from_slice(&[0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, orientation, 0, 0]);
+        payload.extend_from_slice(&[0, 0, 0, 0]); // 次の IFD なし
+
+        let length = u16::try_from(payload.len() + 2).unwrap().to_be_bytes();
+        let mut segment = vec![0xFF, 0xE1, length[0], length[1]];
+        segment.extend(payload);
+        segment
+    }
+
+    #[tokio::test]
+    async fn test_save_normalizes_orientation_and_strips_exif() {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = CoverImageRepositoryImpl::new(dir.path().to_path_buf());
+
+        // 横長の JPEG に「90度回転して表示」の EXIF を埋め込む
+        let mut jpeg = Vec::new();
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(40, 20, Rgba([200, 30, 30, 255])))
+            .to_rgb8()
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+        let jpeg = [&jpeg[..2], &exif_segment(6), &jpeg[2..]].concat();
+
+        let hash = repository.save(jpeg.clone()).await.unwrap();
+        let full = repository
+            .read(&hash, CoverSize::Full)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hash, CoverHash::of(&full));
+        assert!(!full.windows(4).any(|w| w == b"Exif"));
+
+        let image = image::load_from_memory(&full).unwrap();
+        assert_eq!((image.width(), image.height()), (20, 40));
+        let thumbnail = image::load_from_memory(
+            &repository
+                .read(&hash, CoverSize::Thumbnail)
+                .await
+                .unwrap()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            (thumbnail.width(), thumbnail.height()),
+            (THUMBNAIL_WIDTH, THUMBNAIL_HEIGHT)
+        );
+
+        // 同じ画像は同じハッシュになる
+        assert_eq!(repository.save(jpeg).await.unwrap(), hash);
+
+        repository.delete(&hash).await.unwrap();
+        assert!(
+            repository
+                .read(&hash, CoverSize::Full)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(matches!(
+            repository.save(b"not an image".to_vec()).await,
+            Err(DomainError::ValidationError(_))
+        ));
+    }
+}
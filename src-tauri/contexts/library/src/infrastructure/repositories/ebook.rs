@@ -0,0 +1,558 @@
+// Library Infrastructure Layer - 電子書籍ファイルの書誌情報の読み込み実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ebook::{EbookMetadata, EbookMetadataReader};
+use crate::domain::value_objects::{author_role::AuthorRole, ebook_format::EbookFormat};
+use crate::infrastructure::xml::XmlElement;
+use async_trait::async_trait;
+use lopdf::{Document, decode_text_string};
+use std::fs::File;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+
+/// EPUB のパッケージ文書の場所を記載したファイル
+const EPUB_CONTAINER_PATH: &str = "META-INF/container.xml";
+/// 読み込む XML（パッケージ文書など）の最大サイズ（バイト）
+const MAX_XML_BYTES: u64 = 4 * 1024 * 1024;
+/// 読み込む表紙画像の最大サイズ（バイト）
+const MAX_COVER_BYTES: u64 = 20 * 1024 * 1024;
+
+/// EbookMetadataReader の実装（EPUB は zip 内の OPF、PDF は文書情報辞書と XMP を読む）
+#[derive(Default)]
+pub struct EbookMetadataReaderImpl;
+
+impl EbookMetadataReaderImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn read_epub(path: &Path) -> Result<EbookMetadata, DomainError> {
+        let invalid =
+            |e: zip::result::ZipError| DomainError::ValidationError(format!("Invalid EPUB: {}", e));
+        let mut archive = ZipArchive::new(Self::open(path)?).map_err(invalid)?;
+
+        // container.xml に記載されたパッケージ文書（OPF）を読む
+        let container = Self::read_xml(&mut archive, EPUB_CONTAINER_PATH)?;
+        let opf_path = container
+            .descendants("rootfile")
+            .into_iter()
+            .find_map(|rootfile| rootfile.attribute("full-path"))
+            .ok_or_else(|| {
+                DomainError::ValidationError("Invalid EPUB: package document not found".to_string())
+            })?
+            .to_string();
+        let package = Self::read_xml(&mut archive, &opf_path)?;
+        let metadata = package.element("metadata").unwrap_or(&package);
+
+        let texts = |name| -> Vec<String> {
+            metadata
+                .descendants(name)
+                .into_iter()
+                .map(XmlElement::text)
+                .filter(|text| !text.is_empty())
+                .collect()
+        };
+        let first = |name| texts(name).into_iter().next();
+
+        // EPUB 3 は <meta refines="#id" property="role"> で、EPUB 2 は opf:role 属性で役割を指定する
+        let metas = metadata.descendants("meta");
+        let refined_role = |id: &str| {
+            metas.iter().find_map(|meta| {
+                (meta.attribute("property") == Some("role")
+                    && meta.attribute("refines") == Some(&format!("#{}", id)))
+                .then(|| meta.text())
+            })
+        };
+        let mut creators = Vec::new();
+        for (name, default_role) in [("creator", Some(AuthorRole::Author)), ("contributor", None)] {
+            for element in metadata.descendants(name) {
+                let text = element.text();
+                if text.is_empty() {
+                    continue;
+                }
+                let code = element
+                    .attribute("role")
+                    .map(String::from)
+                    .or_else(|| element.attribute("id").and_then(refined_role));
+                // 役割の指定がない著者は著者として扱い、対応しない役割（出版者など）は読み飛ばす
+                let role = match code {
+                    Some(code) => Self::relator_role(&code),
+                    None => default_role,
+                };
+                if let Some(role) = role {
+                    creators.push((text, role));
+                }
+            }
+        }
+
+        let cover = Self::epub_cover_path(&package, &opf_path)
+            .map(|cover_path| Self::read_entry(&mut archive, &cover_path, MAX_COVER_BYTES))
+            .transpose()
+            .unwrap_or_default();
+
+        Ok(EbookMetadata {
+            title: first("title"),
+            creators,
+            language: first("language"),
+            identifiers: texts("identifier"),
+            publisher: first("publisher"),
+            description: first("description"),
+            published_year: first("date").as_deref().and_then(Self::year_of),
+            cover,
+        })
+    }
+
+    /// 表紙画像の zip 内のパス
+    ///
+    /// EPUB 3 の `cover-image` 指定、EPUB 2 の `<meta name="cover">` 指定、
+    /// ID に cover を含む画像の順に探す
+    fn epub_cover_path(package: &XmlElement, opf_path: &str) -> Option<String> {
+        let items: Vec<&XmlElement> = package
+            .element("manifest")?
+            .elements()
+            .filter(|e| e.name() == "item")
+            .collect();
+        let is_image = |item: &&&XmlElement| {
+            item.attribute("media-type")
+                .is_some_and(|t| t.starts_with("image/"))
+        };
+        let cover_id = package.descendants("meta").into_iter().find_map(|meta| {
+            (meta.attribute("name") == Some("cover"))
+                .then(|| meta.attribute("content"))
+                .flatten()
+        });
+
+        let item = items
+            .iter()
+            .find(|item| {
+                item.attribute("properties")
+                    .is_some_and(|p| p.split_whitespace().any(|p| p == "cover-image"))
+            })
+            .or_else(|| {
+                cover_id.and_then(|id| {
+                    items
+                        .iter()
+                        .filter(is_image)
+                        .find(|item| item.attribute("id") == Some(id))
+                })
+            })
+            .or_else(|| {
+                items.iter().filter(is_image).find(|item| {
+                    item.attribute("id")
+                        .is_some_and(|id| id.to_lowercase().contains("cover"))
+                })
+            })?;
+
+        Some(Self::resolve_href(opf_path, item.attribute("href")?))
+    }
+
+    fn read_pdf(path: &Path) -> Result<EbookMetadata, DomainError> {
+        let document = Document::load_from(Self::open(path)?)
+            .map_err(|e| DomainError::ValidationError(format!("Invalid PDF: {}", e)))?;
+
+        // XMP の値を優先し、ない項目は文書情報辞書の値を使う
+        let mut metadata = Self::pdf_xmp(&document).unwrap_or_default();
+        let info = document
+            .trailer
+            .get(b"Info")
+            .and_then(|info| document.dereference(info))
+            .and_then(|(_, info)| info.as_dict());
+        if let Ok(info) = info {
+            let text = |key: &[u8]| {
+                info.get(key)
+                    .and_then(|value| document.dereference(value))
+                    .and_then(|(_, value)| decode_text_string(value))
+                    .ok()
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+            metadata.title = metadata.title.or_else(|| text(b"Title"));
+            metadata.description = metadata.description.or_else(|| text(b"Subject"));
+            if metadata.creators.is_empty()
+                && let Some(author) = text(b"Author")
+            {
+                metadata.creators = author
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| (name.to_string(), AuthorRole::Author))
+                    .collect();
+            }
+        }
+        metadata.cover = Self::pdf_cover(&document);
+
+        Ok(metadata)
+    }
+
+    /// カタログの Metadata ストリーム（XMP）の Dublin Core・PRISM の値
+    fn pdf_xmp(document: &Document) -> Option<EbookMetadata> {
+        let stream = document
+            .catalog()
+            .ok()?
+            .get(b"Metadata")
+            .and_then(|metadata| document.dereference(metadata))
+            .and_then(|(_, metadata)| metadata.as_stream())
+            .ok()?;
+        let content = stream
+            .decompressed_content()
+            .unwrap_or_else(|_| stream.content.clone());
+        let xmp = XmlElement::parse(&String::from_utf8_lossy(&content)).ok()?;
+
+        // 値は rdf:Alt / rdf:Seq / rdf:Bag の rdf:li、または要素のテキストに入る
+        let values = |name| -> Vec<String> {
+            xmp.descendants(name)
+                .into_iter()
+                .flat_map(|element| {
+                    let items = element.descendants("li");
+                    if items.is_empty() {
+                        vec![element.text()]
+                    } else {
+                        items.into_iter().map(XmlElement::text).collect()
+                    }
+                })
+                .filter(|value| !value.is_empty())
+                .collect()
+        };
+        let first = |name| values(name).into_iter().next();
+
+        let mut identifiers = values("identifier");
+        identifiers.extend(values("isbn"));
+        Some(EbookMetadata {
+            title: first("title"),
+            creators: values("creator")
+                .into_iter()
+                .map(|name| (name, AuthorRole::Author))
+                .collect(),
+            language: first("language"),
+            identifiers,
+            publisher: first("publisher"),
+            description: first("description"),
+            published_year: first("publicationDate")
+                .or_else(|| first("date"))
+                .as_deref()
+                .and_then(Self::year_of),
+            cover: None,
+        })
+    }
+
+    /// 1ページ目の JPEG 画像のうち最も大きいもの（ページの描画はしない）
+    fn pdf_cover(document: &Document) -> Option<Vec<u8>> {
+        let page_id = document.page_iter().next()?;
+        document
+            .get_page_images(page_id)
+            .ok()?
+            .into_iter()
+            .filter(|image| {
+                image
+                    .filters
+                    .as_ref()
+                    .is_some_and(|filters| filters == &["DCTDecode"])
+            })
+            .max_by_key(|image| image.width.saturating_mul(image.height))
+            .map(|image| image.content.to_vec())
+    }
+
+    fn open(path: &Path) -> Result<File, DomainError> {
+        File::open(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => {
+                DomainError::NotFound(format!("E-book file not found at {}", path.display()))
+            }
+            _ => DomainError::IoError(format!("Failed to open e-book file: {}", e)),
+        })
+    }
+
+    fn read_entry<R: Read + Seek>(
+        archive: &mut ZipArchive<R>,
+        name: &str,
+        max_bytes: u64,
+    ) -> Result<Vec<u8>, DomainError> {
+        let mut entry = archive.by_name(name).map_err(|_| {
+            DomainError::ValidationError(format!("Invalid EPUB: {} not found", name))
+        })?;
+        if entry.size() > max_bytes {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid EPUB: {} is too large",
+                name
+            )));
+        }
+        let mut bytes = Vec::new();
+        entry
+            .read_to_end(&mut bytes)
+            .map_err(|e| DomainError::IoError(format!("Failed to read EPUB: {}", e)))?;
+        Ok(bytes)
+    }
+
+    fn read_xml<R: Read + Seek>(
+        archive: &mut ZipArchive<R>,
+        name: &str,
+    ) -> Result<XmlElement, DomainError> {
+        let bytes = Self::read_entry(archive, name, MAX_XML_BYTES)?;
+        XmlElement::parse(&String::from_utf8_lossy(&bytes))
+            .map_err(|e| DomainError::ValidationError(format!("Invalid EPUB: {}: {}", name, e)))
+    }
+
+    /// パッケージ文書からの相対パス（URL エンコードされている）を zip 内のパスにする
+    fn resolve_href(opf_path: &str, href: &str) -> String {
+        let href = href.split(['#', '?']).next().unwrap_or_default();
+        let mut segments: Vec<String> = opf_path
+            .rsplit_once('/')
+            .map(|(dir, _)| dir.split('/').map(String::from).collect())
+            .unwrap_or_default();
+        for segment in href.split('/') {
+            match segment {
+                "" | "." => {}
+                ".." => {
+                    segments.pop();
+                }
+                segment => segments.push(Self::percent_decode(segment)),
+            }
+        }
+        segments.join("/")
+    }
+
+    fn percent_decode(value: &str) -> String {
+        let bytes = value.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            match (bytes[i], hex) {
+                (b'%', Some(byte)) => {
+                    decoded.push(byte);
+                    i += 3;
+                }
+                (byte, _) => {
+                    decoded.push(byte);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+
+    /// MARC の役割コードを著者の役割に変換（対応しない役割は None）
+    fn relator_role(code: &str) -> Option<AuthorRole> {
+        match code.trim() {
+            "aut" => Some(AuthorRole::Author),
+            "trl" => Some(AuthorRole::Translator),
+            "edt" => Some(AuthorRole::Editor),
+            "ill" => Some(AuthorRole::Illustrator),
+            _ => None,
+        }
+    }
+
+    /// 日付（`2019`、`2019-05-01`、`2019-05-01T00:00:00Z` など）の年
+    fn year_of(date: &str) -> Option<i32> {
+        date.trim().get(..4)?.parse().ok()
+    }
+}
+
+#[async_trait]
+impl EbookMetadataReader for EbookMetadataReaderImpl {
+    async fn read_metadata(
+        &self,
+        path: &Path,
+        format: EbookFormat,
+    ) -> Result<EbookMetadata, DomainError> {
+        let path = PathBuf::from(path);
+        // 大きなファイルの解析で非同期処理のスレッドを塞がないようにする
+        tokio::task::spawn_blocking(move || match format {
+            EbookFormat::Epub => Self::read_epub(&path),
+            EbookFormat::Pdf => Self::read_pdf(&path),
+        })
+        .await
+        .map_err(|e| DomainError::InvalidState(format!("E-book reading failed: {}", e)))?
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use lopdf::{Dictionary, Object, Stream, StringFormat, dictionary};
+    use std::io::{Cursor, Write};
+    use zip::write::{SimpleFileOptions, ZipWriter};
+
+    /// テスト用の EPUB（EPUB 3 のパッケージ文書と表紙画像）を作成
+    pub(crate) fn epub(opf_metadata: &str, cover: &[u8]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        let opf = format!(
+            r#"<?xml version="1.0"?><package xmlns="http://www.idpf.org/2007/opf" xmlns:dc="http://purl.org/dc/elements/1.1/" version="3.0"><metadata>{}</metadata><manifest><item id="c" href="../Images/front%20cover.jpg" media-type="image/jpeg" properties="cover-image"/></manifest></package>"#,
+            opf_metadata
+        );
+        let files: [(&str, &[u8]); 4] = [
+            ("mimetype", b"application/epub+zip"),
+            (
+                EPUB_CONTAINER_PATH,
+                br#"<?xml version="1.0"?><container xmlns="urn:oasis:names:tc:opendocument:xmlns:container"><rootfiles><rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles></container>"#,
+            ),
+            ("OEBPS/content.opf", opf.as_bytes()),
+            ("Images/front cover.jpg", cover),
+        ];
+        for (name, content) in files {
+            writer.start_file(name, options).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    /// テスト用の PDF（文書情報辞書・XMP・1ページ目の JPEG 画像）を作成
+    pub(crate) fn pdf(info: Dictionary, xmp: Option<&str>, jpeg: &[u8]) -> Vec<u8> {
+        let mut document = Document::with_version("1.5");
+        let pages_id = document.new_object_id();
+        let image_id = document.add_object(Stream::new(
+            dictionary! {
+                "Type" => "XObject",
+                "Subtype" => "Image",
+                "Width" => 2,
+                "Height" => 3,
+                "ColorSpace" => "DeviceRGB",
+                "BitsPerComponent" => 8,
+                "Filter" => "DCTDecode",
+            },
+            jpeg.to_vec(),
+        ));
+        let page_id = document.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 200.into(), 300.into()],
+            "Resources" => dictionary! { "XObject" => dictionary! { "Im0" => image_id } },
+        });
+        document.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+            }),
+        );
+        let mut catalog = dictionary! { "Type" => "Catalog", "Pages" => pages_id };
+        if let Some(xmp) = xmp {
+            let metadata_id = document.add_object(Stream::new(
+                dictionary! { "Type" => "Metadata", "Subtype" => "XML" },
+                xmp.as_bytes().to_vec(),
+            ));
+            catalog.set("Metadata", metadata_id);
+        }
+        let catalog_id = document.add_object(catalog);
+        let info_id = document.add_object(info);
+        document.trailer.set("Root", catalog_id);
+        document.trailer.set("Info", info_id);
+
+        let mut bytes = Vec::new();
+        document.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// UTF-16BE（BOM 付き）の PDF 文字列
+    pub(crate) fn pdf_text(text: &str) -> Object {
+        let mut bytes = vec![0xFE, 0xFF];
+        bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes));
+        Object::String(bytes, StringFormat::Hexadecimal)
+    }
+
+    async fn read(bytes: &[u8], format: EbookFormat) -> EbookMetadata {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(format!("book.{}", format.as_str()));
+        std::fs::write(&path, bytes).unwrap();
+        EbookMetadataReaderImpl::new()
+            .read_metadata(&path, format)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_read_epub_package_metadata() {
+        let metadata = read(
+            &epub(
+                r##"<dc:title>羅生門</dc:title>
+                <dc:creator id="a1">芥川 龍之介</dc:creator>
+                <meta refines="#a1" property="role" scheme="marc:relators">aut</meta>
+                <dc:creator id="t1">Jay Rubin</dc:creator>
+                <meta refines="#t1" property="role" scheme="marc:relators">trl</meta>
+                <dc:contributor opf:role="bkp">calibre</dc:contributor>
+                <dc:language>ja</dc:language>
+                <dc:identifier id="uid">urn:uuid:0b4e5d3a</dc:identifier>
+                <dc:identifier>urn:isbn:9784101025018</dc:identifier>
+                <dc:publisher>新潮社</dc:publisher>
+                <dc:description>&lt;p&gt;短編集&lt;/p&gt;</dc:description>
+                <dc:date>1968-07-19</dc:date>"##,
+                b"cover-bytes",
+            ),
+            EbookFormat::Epub,
+        )
+        .await;
+
+        assert_eq!(
+            metadata,
+            EbookMetadata {
+                title: Some("羅生門".to_string()),
+                creators: vec![
+                    ("芥川 龍之介".to_string(), AuthorRole::Author),
+                    ("Jay Rubin".to_string(), AuthorRole::Translator),
+                ],
+                language: Some("ja".to_string()),
+                identifiers: vec![
+                    "urn:uuid:0b4e5d3a".to_string(),
+                    "urn:isbn:9784101025018".to_string()
+                ],
+                publisher: Some("新潮社".to_string()),
+                description: Some("<p>短編集</p>".to_string()),
+                published_year: Some(1968),
+                cover: Some(b"cover-bytes".to_vec()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_pdf_info_and_xmp() {
+        let xmp = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+            <x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+              <rdf:Description xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:prism="http://prismstandard.org/namespaces/basic/2.0/">
+                <dc:title><rdf:Alt><rdf:li xml:lang="x-default">Cosmos</rdf:li></rdf:Alt></dc:title>
+                <dc:creator><rdf:Seq><rdf:li>Carl Sagan</rdf:li><rdf:li>Ann Druyan</rdf:li></rdf:Seq></dc:creator>
+                <dc:language><rdf:Bag><rdf:li>en</rdf:li></rdf:Bag></dc:language>
+                <dc:publisher><rdf:Bag><rdf:li>Random House</rdf:li></rdf:Bag></dc:publisher>
+                <prism:isbn>978-0-394-50294-6</prism:isbn>
+                <prism:publicationDate>1980-10-12</prism:publicationDate>
+              </rdf:Description>
+            </rdf:RDF></x:xmpmeta>
+            <?xpacket end="w"?>"#;
+        let info = dictionary! {
+            "Title" => pdf_text("Old title"),
+            "Author" => pdf_text("Someone Else"),
+            "Subject" => pdf_text("宇宙の本"),
+        };
+        let metadata = read(&pdf(info, Some(xmp), b"jpeg-bytes"), EbookFormat::Pdf).await;
+
+        assert_eq!(metadata.title.as_deref(), Some("Cosmos"));
+        assert_eq!(
+            metadata.creators,
+            vec![
+                ("Carl Sagan".to_string(), AuthorRole::Author),
+                ("Ann Druyan".to_string(), AuthorRole::Author),
+            ]
+        );
+        assert_eq!(metadata.language.as_deref(), Some("en"));
+        assert_eq!(metadata.publisher.as_deref(), Some("Random House"));
+        assert_eq!(metadata.identifiers, vec!["978-0-394-50294-6".to_string()]);
+        assert_eq!(metadata.published_year, Some(1980));
+        // XMP にない項目は文書情報辞書から読む
+        assert_eq!(metadata.description.as_deref(), Some("宇宙の本"));
+        assert_eq!(metadata.cover.as_deref(), Some(&b"jpeg-bytes"[..]));
+
+        // XMP がない場合は文書情報辞書の値
+        let info = dictionary! {
+            "Title" => Object::string_literal("Pale Blue Dot"),
+            "Author" => Object::string_literal("Carl Sagan; Ann Druyan"),
+        };
+        let metadata = read(&pdf(info, None, b"jpeg"), EbookFormat::Pdf).await;
+        assert_eq!(metadata.title.as_deref(), Some("Pale Blue Dot"));
+        assert_eq!(metadata.creators.len(), 2);
+    }
+}
@@ -0,0 +1,72 @@
+// Library Infrastructure Layer - 電子書籍ファイルのコピーの保存実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ebook::EbookFileStore;
+use crate::domain::value_objects::ebook_format::EbookFormat;
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// EbookFileStore のファイルシステム実装
+///
+/// `<root>/<内容のハッシュ>.<拡張子>` にコピーする（同じ内容のファイルは1つだけ保存する）
+pub struct EbookFileStoreImpl {
+    root: PathBuf,
+}
+
+impl EbookFileStoreImpl {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+}
+
+#[async_trait]
+impl EbookFileStore for EbookFileStoreImpl {
+    async fn store_copy(&self, path: &Path, format: EbookFormat) -> Result<PathBuf, DomainError> {
+        let io_error =
+            |e: std::io::Error| DomainError::IoError(format!("Failed to copy e-book file: {}", e));
+
+        let bytes = fs::read(path).await.map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => {
+                DomainError::NotFound(format!("E-book file not found at {}", path.display()))
+            }
+            _ => io_error(e),
+        })?;
+        let hash: String = Sha256::digest(&bytes)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        let target = self.root.join(format!("{}.{}", hash, format.as_str()));
+        if fs::try_exists(&target).await.map_err(io_error)? {
+            return Ok(target);
+        }
+
+        // 一時ファイルに書き込んでから置き換える（書き込み途中のファイルを残さないため）
+        fs::create_dir_all(&self.root).await.map_err(io_error)?;
+        let temp_path = target.with_extension(format!("{}.tmp", format.as_str()));
+        fs::write(&temp_path, &bytes).await.map_err(io_error)?;
+        fs::rename(&temp_path, &target).await.map_err(io_error)?;
+
+        Ok(target)
+    }
+
+    async fn delete_copy(&self, path: &Path) -> Result<(), DomainError> {
+        // 管理ディレクトリの外のファイルは削除しない
+        if path.parent() != Some(self.root.as_path()) {
+            return Err(DomainError::InvalidState(format!(
+                "{} is not a managed e-book file",
+                path.display()
+            )));
+        }
+
+        match fs::remove_file(path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(DomainError::IoError(format!(
+                "Failed to delete e-book file: {}",
+                e
+            ))),
+        }
+    }
+}
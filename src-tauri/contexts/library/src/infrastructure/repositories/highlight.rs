@@ -0,0 +1,138 @@
+// Library Infrastructure Layer - Highlight リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::highlight::{Highlight, HighlightLocation},
+    repositories::highlight::HighlightRepository,
+    value_objects::highlight_kind::HighlightKind,
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::book_highlight;
+use sea_orm::{ActiveModelTrait, NotSet, QueryOrder, Set, entity::prelude::*};
+
+/// HighlightRepository の SeaORM実装
+pub struct HighlightRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl HighlightRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(highlight: &Highlight) -> book_highlight::ActiveModel {
+        let location = highlight.location();
+        book_highlight::ActiveModel {
+            id: highlight.id().map_or(NotSet, Set),
+            book_id: Set(highlight.book_id()),
+            kind: Set(highlight.kind().as_str().to_string()),
+            content: Set(highlight.content().to_string()),
+            page: Set(location.page()),
+            location_start: Set(location.start()),
+            location_end: Set(location.end()),
+            clipped_at: Set(highlight.clipped_at()),
+            created_at: Set(highlight.created_at()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: book_highlight::Model) -> Result<Highlight, DomainError> {
+        Ok(Highlight::reconstruct(
+            model.id,
+            model.book_id,
+            HighlightKind::parse(&model.kind)?,
+            model.content,
+            HighlightLocation::new(model.page, model.location_start, model.location_end)?,
+            model.clipped_at,
+            model.created_at,
+        ))
+    }
+}
+
+#[async_trait]
+impl HighlightRepository for HighlightRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Highlight>, DomainError> {
+        let db = self.db.connection();
+        let highlight = book_highlight::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        highlight.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Highlight>, DomainError> {
+        let db = self.db.connection();
+        let highlights = book_highlight::Entity::find()
+            .filter(book_highlight::Column::BookId.eq(book_id))
+            .order_by_asc(book_highlight::Column::LocationStart)
+            .order_by_asc(book_highlight::Column::Page)
+            .order_by_asc(book_highlight::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        highlights.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn find_same_clipping(
+        &self,
+        highlight: &Highlight,
+    ) -> Result<Option<Highlight>, DomainError> {
+        let db = self.db.connection();
+        let location_start = match highlight.location().start() {
+            Some(start) => book_highlight::Column::LocationStart.eq(start),
+            None => book_highlight::Column::LocationStart.is_null(),
+        };
+        let existing = book_highlight::Entity::find()
+            .filter(book_highlight::Column::BookId.eq(highlight.book_id()))
+            .filter(book_highlight::Column::Kind.eq(highlight.kind().as_str()))
+            .filter(location_start)
+            .filter(book_highlight::Column::Content.eq(highlight.content()))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        existing.map(Self::db_to_domain).transpose()
+    }
+
+    async fn save(&self, highlight: Highlight) -> Result<Highlight, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&highlight);
+
+        let result = if highlight.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Self::db_to_domain(result)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = book_highlight::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Highlight with id {} not found",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+// Library Infrastructure Layer - Loan リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{entities::loan::Loan, repositories::loan::LoanRepository};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::loan;
+use sea_orm::{ActiveModelTrait, NotSet, Order, QueryOrder, Set, entity::prelude::*};
+
+/// LoanRepository の SeaORM実装
+pub struct LoanRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl LoanRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(loan: &Loan) -> loan::ActiveModel {
+        loan::ActiveModel {
+            id: loan.id().map_or(NotSet, Set),
+            copy_id: Set(loan.copy_id()),
+            borrower_id: Set(loan.borrower_id()),
+            lent_on: Set(loan.lent_on()),
+            due_on: Set(loan.due_on()),
+            returned_on: Set(loan.returned_on()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: loan::Model) -> Loan {
+        Loan::reconstruct(
+            model.id,
+            model.copy_id,
+            model.borrower_id,
+            model.lent_on,
+            model.due_on,
+            model.returned_on,
+        )
+    }
+
+    /// 条件に合う貸出を新しい順に取得
+    async fn find_history(&self, condition: sea_orm::Condition) -> Result<Vec<Loan>, DomainError> {
+        let db = self.db.connection();
+        let loans = loan::Entity::find()
+            .filter(condition)
+            .order_by_desc(loan::Column::LentOn)
+            .order_by_desc(loan::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(loans.into_iter().map(Self::db_to_domain).collect())
+    }
+}
+
+#[async_trait]
+impl LoanRepository for LoanRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Loan>, DomainError> {
+        let db = self.db.connection();
+        let loan = loan::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(loan.map(Self::db_to_domain))
+    }
+
+    async fn find_active_by_copy(&self, copy_id: i32) -> Result<Option<Loan>, DomainError> {
+        let db = self.db.connection();
+        let loan = loan::Entity::find()
+            .filter(loan::Column::CopyId.eq(copy_id))
+            .filter(loan::Column::ReturnedOn.is_null())
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(loan.map(Self::db_to_domain))
+    }
+
+    async fn find_by_copy(&self, copy_id: i32) -> Result<Vec<Loan>, DomainError> {
+        self.find_history(sea_orm::Condition::all().add(loan::Column::CopyId.eq(copy_id)))
+            .await
+    }
+
+    async fn find_by_borrower(&self, borrower_id: i32) -> Result<Vec<Loan>, DomainError> {
+        self.find_history(sea_orm::Condition::all().add(loan::Column::BorrowerId.eq(borrower_id)))
+            .await
+    }
+
+    async fn find_active(&self) -> Result<Vec<Loan>, DomainError> {
+        let db = self.db.connection();
+        let loans = loan::Entity::find()
+            .filter(loan::Column::ReturnedOn.is_null())
+            // SQLite は NULL を先に並べるため、期限なしを最後に回す
+            .order_by(Expr::col(loan::Column::DueOn).is_null(), Order::Asc)
+            .order_by_asc(loan::Column::DueOn)
+            .order_by_asc(loan::Column::LentOn)
+            .order_by_asc(loan::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(loans.into_iter().map(Self::db_to_domain).collect())
+    }
+
+    async fn save(&self, loan: Loan) -> Result<Loan, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&loan);
+
+        let result = if loan.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Ok(Self::db_to_domain(result))
+    }
+}
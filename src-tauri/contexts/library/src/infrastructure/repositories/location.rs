@@ -0,0 +1,121 @@
+// Library Infrastructure Layer - Location リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::location::Location, repositories::location::LocationRepository,
+    value_objects::location_kind::LocationKind,
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::location;
+use sea_orm::{ActiveModelTrait, NotSet, QueryOrder, Set, entity::prelude::*};
+
+/// LocationRepository の SeaORM実装
+pub struct LocationRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl LocationRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(location: &Location) -> location::ActiveModel {
+        location::ActiveModel {
+            id: location.id().map_or(NotSet, Set),
+            name: Set(location.name().to_string()),
+            kind: Set(location.kind().as_str().to_string()),
+            parent_id: Set(location.parent_id()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: location::Model) -> Result<Location, DomainError> {
+        Ok(Location::reconstruct(
+            model.id,
+            model.name,
+            LocationKind::parse(&model.kind)?,
+            model.parent_id,
+        ))
+    }
+}
+
+#[async_trait]
+impl LocationRepository for LocationRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Location>, DomainError> {
+        let db = self.db.connection();
+        let location = location::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        location.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Location>, DomainError> {
+        let db = self.db.connection();
+        let locations = location::Entity::find()
+            .order_by_asc(location::Column::Name)
+            .order_by_asc(location::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        locations.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn find_children(&self, parent_id: Option<i32>) -> Result<Vec<Location>, DomainError> {
+        let db = self.db.connection();
+        let parent_condition = match parent_id {
+            Some(parent_id) => location::Column::ParentId.eq(parent_id),
+            None => location::Column::ParentId.is_null(),
+        };
+        let locations = location::Entity::find()
+            .filter(parent_condition)
+            .order_by_asc(location::Column::Name)
+            .order_by_asc(location::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        locations.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn save(&self, location: Location) -> Result<Location, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&location);
+
+        let result = if location.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Self::db_to_domain(result)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = location::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Location with id {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+// Library Infrastructure Layer - Note リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{entities::note::Note, repositories::note::NoteRepository};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::book_note;
+use sea_orm::{ActiveModelTrait, NotSet, QueryOrder, Set, entity::prelude::*};
+
+/// NoteRepository の SeaORM実装
+pub struct NoteRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl NoteRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(note: &Note) -> book_note::ActiveModel {
+        book_note::ActiveModel {
+            id: note.id().map_or(NotSet, Set),
+            book_id: Set(note.book_id()),
+            body: Set(note.body().to_string()),
+            created_at: Set(note.created_at()),
+            updated_at: Set(note.updated_at()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: book_note::Model) -> Note {
+        Note::reconstruct(
+            model.id,
+            model.book_id,
+            model.body,
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
+
+#[async_trait]
+impl NoteRepository for NoteRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Note>, DomainError> {
+        let db = self.db.connection();
+        let note = book_note::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(note.map(Self::db_to_domain))
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Note>, DomainError> {
+        let db = self.db.connection();
+        let notes = book_note::Entity::find()
+            .filter(book_note::Column::BookId.eq(book_id))
+            .order_by_asc(book_note::Column::CreatedAt)
+            .order_by_asc(book_note::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(notes.into_iter().map(Self::db_to_domain).collect())
+    }
+
+    async fn save(&self, note: Note) -> Result<Note, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&note);
+
+        let result = if note.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Ok(Self::db_to_domain(result))
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = book_note::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Note with id {} not found",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+// Library Infrastructure Layer - ReadThrough リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::read_through::{ReadThrough, ReadingSession},
+    repositories::read_through::ReadThroughRepository,
+    value_objects::{
+        rating::Rating, reading_progress::ReadingProgress, reading_status::ReadingStatus,
+    },
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::{read_through, reading_session};
+use sea_orm::{
+    ActiveModelTrait, ConnectionTrait, NotSet, QueryOrder, Set, TransactionTrait,
+    entity::prelude::*,
+};
+use std::collections::HashMap;
+
+/// ReadThroughRepository の SeaORM実装
+pub struct ReadThroughRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl ReadThroughRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(read_through: &ReadThrough) -> read_through::ActiveModel {
+        read_through::ActiveModel {
+            id: read_through.id().map_or(NotSet, Set),
+            book_id: Set(read_through.book_id()),
+            status: Set(read_through.status().as_str().to_string()),
+            started_on: Set(read_through.started_on()),
+            finished_on: Set(read_through.finished_on()),
+            rating: Set(read_through.rating().map(Rating::stars)),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn session_to_domain(model: reading_session::Model) -> Result<ReadingSession, DomainError> {
+        Ok(ReadingSession::reconstruct(
+            model.id,
+            model.read_on,
+            ReadingProgress::new(model.page, model.percent)?,
+            model.duration_minutes,
+        ))
+    }
+
+    /// 通読のDBモデルを読書記録とともにドメインモデルに変換
+    async fn load_with_sessions<C: ConnectionTrait>(
+        db: &C,
+        models: Vec<read_through::Model>,
+    ) -> Result<Vec<ReadThrough>, DomainError> {
+        let ids: Vec<i32> = models.iter().map(|m| m.id).collect();
+        let sessions = reading_session::Entity::find()
+            .filter(reading_session::Column::ReadThroughId.is_in(ids))
+            .order_by_asc(reading_session::Column::ReadOn)
+            .order_by_asc(reading_session::Column::Id)
+            .all(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut sessions_by_read_through: HashMap<i32, Vec<ReadingSession>> = HashMap::new();
+        for session in sessions {
+            sessions_by_read_through
+                .entry(session.read_through_id)
+                .or_default()
+                .push(Self::session_to_domain(session)?);
+        }
+
+        models
+            .into_iter()
+            .map(|model| {
+                Ok(ReadThrough::reconstruct(
+                    model.id,
+                    model.book_id,
+                    ReadingStatus::parse(&model.status)?,
+                    model.started_on,
+                    model.finished_on,
+                    model.rating.map(Rating::new).transpose()?,
+                    sessions_by_read_through
+                        .remove(&model.id)
+                        .unwrap_or_default(),
+                ))
+            })
+            .collect()
+    }
+
+    /// IDで通読を取得（トランザクション内でも使用）
+    async fn find_by_id_with<C: ConnectionTrait>(
+        db: &C,
+        id: i32,
+    ) -> Result<Option<ReadThrough>, DomainError> {
+        let model = read_through::Entity::find_by_id(id)
+            .one(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(Self::load_with_sessions(db, model.into_iter().collect())
+            .await?
+            .pop())
+    }
+}
+
+#[async_trait]
+impl ReadThroughRepository for ReadThroughRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<ReadThrough>, DomainError> {
+        let db = self.db.connection();
+        Self::find_by_id_with(&db, id).await
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<ReadThrough>, DomainError> {
+        let db = self.db.connection();
+        let models = read_through::Entity::find()
+            .filter(read_through::Column::BookId.eq(book_id))
+            .order_by_asc(read_through::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Self::load_with_sessions(&db, models).await
+    }
+
+    async fn find_latest_by_book(&self, book_id: i32) -> Result<Option<ReadThrough>, DomainError> {
+        let db = self.db.connection();
+        let model = read_through::Entity::find()
+            .filter(read_through::Column::BookId.eq(book_id))
+            .order_by_desc(read_through::Column::Id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(Self::load_with_sessions(&db, model.into_iter().collect())
+            .await?
+            .pop())
+    }
+
+    async fn find_by_session_id(
+        &self,
+        session_id: i32,
+    ) -> Result<Option<ReadThrough>, DomainError> {
+        let db = self.db.connection();
+        let Some(session) = reading_session::Entity::find_by_id(session_id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        else {
+            return Ok(None);
+        };
+
+        Self::find_by_id_with(&db, session.read_through_id).await
+    }
+
+    async fn save(&self, read_through: ReadThrough) -> Result<ReadThrough, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&read_through);
+
+        // 通読と読書記録をまとめて保存する
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let result = if read_through.id().is_some() {
+            // 更新
+            active_model
+                .update(&txn)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&txn)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        // 削除された読書記録を反映し、新しい読書記録を追加する（記録済みの内容は変わらない）
+        let kept_ids: Vec<i32> = read_through
+            .sessions()
+            .iter()
+            .filter_map(ReadingSession::id)
+            .collect();
+        reading_session::Entity::delete_many()
+            .filter(reading_session::Column::ReadThroughId.eq(result.id))
+            .filter(reading_session::Column::Id.is_not_in(kept_ids))
+            .exec(&txn)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        for session in read_through.sessions().iter().filter(|s| s.id().is_none()) {
+            reading_session::ActiveModel {
+                id: NotSet,
+                read_through_id: Set(result.id),
+                read_on: Set(session.read_on()),
+                page: Set(session.progress().as_page()),
+                percent: Set(session.progress().as_percent()),
+                duration_minutes: Set(session.duration_minutes()),
+            }
+            .insert(&txn)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+        }
+
+        let saved = Self::find_by_id_with(&txn, result.id).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        saved.ok_or_else(|| {
+            DomainError::InvalidState(format!("Read-through with id {} not found", result.id))
+        })
+    }
+}
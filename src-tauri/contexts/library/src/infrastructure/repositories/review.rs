@@ -0,0 +1,107 @@
+// Library Infrastructure Layer - Review リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::review::Review, repositories::review::ReviewRepository, value_objects::rating::Rating,
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::book_review;
+use sea_orm::{ActiveModelTrait, NotSet, Set, entity::prelude::*};
+
+/// ReviewRepository の SeaORM実装
+pub struct ReviewRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl ReviewRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(review: &Review) -> book_review::ActiveModel {
+        book_review::ActiveModel {
+            id: review.id().map_or(NotSet, Set),
+            book_id: Set(review.book_id()),
+            rating: Set(review.rating().map(Rating::stars)),
+            body: Set(review.body().map(String::from)),
+            created_at: Set(review.created_at()),
+            updated_at: Set(review.updated_at()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: book_review::Model) -> Result<Review, DomainError> {
+        Ok(Review::reconstruct(
+            model.id,
+            model.book_id,
+            model.rating.map(Rating::new).transpose()?,
+            model.body,
+            model.created_at,
+            model.updated_at,
+        ))
+    }
+}
+
+#[async_trait]
+impl ReviewRepository for ReviewRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Review>, DomainError> {
+        let db = self.db.connection();
+        let review = book_review::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        review.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Option<Review>, DomainError> {
+        let db = self.db.connection();
+        let review = book_review::Entity::find()
+            .filter(book_review::Column::BookId.eq(book_id))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        review.map(Self::db_to_domain).transpose()
+    }
+
+    async fn save(&self, review: Review) -> Result<Review, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&review);
+
+        let result = if review.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Self::db_to_domain(result)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = book_review::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Review with id {} not found",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+// Library Infrastructure Layer - Series リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::series::{Series, SeriesVolume},
+    repositories::series::SeriesRepository,
+    value_objects::{series_status::SeriesStatus, sort_key::SortKey, volume_number::VolumeNumber},
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::{book, book_series, series};
+use sea_orm::{
+    ActiveModelTrait, NotSet, QueryOrder, Set,
+    entity::prelude::*,
+    sea_query::{OnConflict, Query},
+};
+
+/// SeriesRepository の SeaORM実装
+pub struct SeriesRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl SeriesRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// ドメインモデルをDBモデルに変換
+    fn domain_to_active_model(series: &Series) -> series::ActiveModel {
+        series::ActiveModel {
+            id: series.id().map_or(NotSet, Set),
+            name: Set(series.name().to_string()),
+            reading: Set(series.reading().map(String::from)),
+            sort_key: Set(series.sort_key().as_str().to_string()),
+            status: Set(series.status().as_str().to_string()),
+            volume_count: Set(series.volume_count()),
+        }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: series::Model) -> Result<Series, DomainError> {
+        Ok(Series::reconstruct(
+            model.id,
+            model.name,
+            model.reading,
+            SortKey::reconstruct(model.sort_key),
+            SeriesStatus::parse(&model.status)?,
+            model.volume_count,
+        ))
+    }
+
+    fn volume_to_domain(model: book_series::Model) -> Result<SeriesVolume, DomainError> {
+        Ok(SeriesVolume::new(
+            model.book_id,
+            model.series_id,
+            VolumeNumber::new(model.volume)?,
+        ))
+    }
+}
+
+#[async_trait]
+impl SeriesRepository for SeriesRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Series>, DomainError> {
+        let db = self.db.connection();
+        let series = series::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        series.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Series>, DomainError> {
+        let db = self.db.connection();
+        let series = series::Entity::find()
+            .filter(series::Column::Name.eq(name))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        series.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Series>, DomainError> {
+        let db = self.db.connection();
+        let series = series::Entity::find()
+            .order_by_asc(series::Column::SortKey)
+            .order_by_asc(series::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        series.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn save(&self, series: Series) -> Result<Series, DomainError> {
+        let db = self.db.connection();
+        let active_model = Self::domain_to_active_model(&series);
+
+        let result = if series.id().is_some() {
+            // 更新
+            active_model
+                .update(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(&db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Self::db_to_domain(result)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = series::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Series with id {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn find_volumes(&self, series_id: i32) -> Result<Vec<SeriesVolume>, DomainError> {
+        let db = self.db.connection();
+        // ゴミ箱にある本の巻は含めない
+        let volumes = book_series::Entity::find()
+            .filter(book_series::Column::SeriesId.eq(series_id))
+            .filter(
+                book_series::Column::BookId.in_subquery(
+                    Query::select()
+                        .column(book::Column::Id)
+                        .from(book::Entity)
+                        .and_where(book::Column::DeletedAt.is_null())
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(book_series::Column::Volume)
+            .order_by_asc(book_series::Column::BookId)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        volumes.into_iter().map(Self::volume_to_domain).collect()
+    }
+
+    async fn find_volume_by_book(&self, book_id: i32) -> Result<Option<SeriesVolume>, DomainError> {
+        let db = self.db.connection();
+        let volume = book_series::Entity::find()
+            .filter(book_series::Column::BookId.eq(book_id))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        volume.map(Self::volume_to_domain).transpose()
+    }
+
+    async fn save_volume(&self, volume: SeriesVolume) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let active_model = book_series::ActiveModel {
+            id: NotSet,
+            book_id: Set(volume.book_id()),
+            series_id: Set(volume.series_id()),
+            volume: Set(volume.volume().value()),
+        };
+
+        book_series::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(book_series::Column::BookId)
+                    .update_columns([book_series::Column::SeriesId, book_series::Column::Volume])
+                    .to_owned(),
+            )
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn remove_volume(&self, book_id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        book_series::Entity::delete_many()
+            .filter(book_series::Column::BookId.eq(book_id))
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+}
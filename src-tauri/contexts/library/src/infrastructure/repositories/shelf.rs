@@ -0,0 +1,341 @@
+// Library Infrastructure Layer - Shelf リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::shelf::Shelf,
+    repositories::{book::BookFilter, shelf::ShelfRepository},
+    value_objects::{
+        class_number::{ClassPrefix, ClassificationScheme},
+        tag_name::TagName,
+    },
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::{shelf, shelf_book};
+use sea_orm::{
+    ActiveModelTrait, ConnectionTrait, NotSet, QueryOrder, QuerySelect, Set, TransactionTrait,
+    entity::prelude::*,
+    sea_query::{Expr, OnConflict, Query},
+};
+use serde::{Deserialize, Serialize};
+
+/// スマート本棚の絞り込み条件の保存形式（JSON）
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SmartFilterRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title_contains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    published_year_from: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    published_year_to: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ndc: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ddc: Option<String>,
+}
+
+impl From<&BookFilter> for SmartFilterRecord {
+    fn from(filter: &BookFilter) -> Self {
+        Self {
+            author: filter.author.clone(),
+            title_contains: filter.title_contains.clone(),
+            published_year_from: filter.published_year_from,
+            published_year_to: filter.published_year_to,
+            tag: filter.tag.as_ref().map(|tag| tag.as_str().to_string()),
+            ndc: filter.ndc.as_ref().map(|ndc| ndc.as_str().to_string()),
+            ddc: filter.ddc.as_ref().map(|ddc| ddc.as_str().to_string()),
+        }
+    }
+}
+
+impl From<SmartFilterRecord> for BookFilter {
+    fn from(record: SmartFilterRecord) -> Self {
+        Self {
+            author: record.author,
+            title_contains: record.title_contains,
+            published_year_from: record.published_year_from,
+            published_year_to: record.published_year_to,
+            tag: record.tag.map(TagName::reconstruct),
+            // 保存時に検証済みのため、読み込み時に解析できない値は無視する
+            ndc: record
+                .ndc
+                .and_then(|ndc| ClassPrefix::parse(ClassificationScheme::Ndc, &ndc).ok()),
+            ddc: record
+                .ddc
+                .and_then(|ddc| ClassPrefix::parse(ClassificationScheme::Ddc, &ddc).ok()),
+            // スマート本棚は作成日時・更新日時の条件を持たない
+            ..Default::default()
+        }
+    }
+}
+
+/// ShelfRepository の SeaORM実装
+pub struct ShelfRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl ShelfRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: shelf::Model) -> Result<Shelf, DomainError> {
+        let smart_filter = model
+            .smart_filter
+            .map(|json| {
+                serde_json::from_str::<SmartFilterRecord>(&json).map_err(|e| {
+                    DomainError::InvalidState(format!(
+                        "Invalid smart filter for shelf {}: {}",
+                        model.id, e
+                    ))
+                })
+            })
+            .transpose()?
+            .map(BookFilter::from);
+
+        Ok(Shelf::reconstruct(
+            model.id,
+            model.name,
+            model.position,
+            smart_filter,
+        ))
+    }
+
+    fn smart_filter_json(shelf: &Shelf) -> Result<Option<String>, DomainError> {
+        shelf
+            .smart_filter()
+            .map(|filter| {
+                serde_json::to_string(&SmartFilterRecord::from(filter)).map_err(|e| {
+                    DomainError::InvalidState(format!("Failed to encode smart filter: {}", e))
+                })
+            })
+            .transpose()
+    }
+
+    /// 次に追加する位置（現在の最大値 + 1）
+    async fn next_position<C, E>(
+        db: &C,
+        query: Select<E>,
+        column: E::Column,
+    ) -> Result<i32, DomainError>
+    where
+        C: ConnectionTrait,
+        E: EntityTrait,
+    {
+        let max: Option<Option<i32>> = query
+            .select_only()
+            .column_as(column.max(), "max_position")
+            .into_tuple()
+            .one(db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(max.flatten().map_or(0, |position| position + 1))
+    }
+}
+
+#[async_trait]
+impl ShelfRepository for ShelfRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Shelf>, DomainError> {
+        let db = self.db.connection();
+        let shelf = shelf::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        shelf.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Shelf>, DomainError> {
+        let db = self.db.connection();
+        let shelf = shelf::Entity::find()
+            .filter(shelf::Column::Name.eq(name))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        shelf.map(Self::db_to_domain).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Shelf>, DomainError> {
+        let db = self.db.connection();
+        let shelves = shelf::Entity::find()
+            .order_by_asc(shelf::Column::Position)
+            .order_by_asc(shelf::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        shelves.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Shelf>, DomainError> {
+        let db = self.db.connection();
+        let shelves = shelf::Entity::find()
+            .filter(
+                shelf::Column::Id.in_subquery(
+                    Query::select()
+                        .column(shelf_book::Column::ShelfId)
+                        .from(shelf_book::Entity)
+                        .and_where(shelf_book::Column::BookId.eq(book_id))
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(shelf::Column::Position)
+            .order_by_asc(shelf::Column::Id)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        shelves.into_iter().map(Self::db_to_domain).collect()
+    }
+
+    async fn save(&self, shelf: Shelf) -> Result<Shelf, DomainError> {
+        let db = self.db.connection();
+        let smart_filter = Self::smart_filter_json(&shelf)?;
+
+        let result = if let Some(id) = shelf.id() {
+            // 更新（表示順は reorder でのみ変更する）
+            shelf::ActiveModel {
+                id: Set(id),
+                name: Set(shelf.name().to_string()),
+                position: NotSet,
+                smart_filter: Set(smart_filter),
+            }
+            .update(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成（一覧の末尾に追加）
+            let position =
+                Self::next_position(&db, shelf::Entity::find(), shelf::Column::Position).await?;
+            shelf::ActiveModel {
+                id: NotSet,
+                name: Set(shelf.name().to_string()),
+                position: Set(position),
+                smart_filter: Set(smart_filter),
+            }
+            .insert(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Self::db_to_domain(result)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let result = shelf::Entity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Shelf with id {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn reorder(&self, shelf_ids: &[i32]) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        for (position, id) in (0..).zip(shelf_ids) {
+            shelf::Entity::update_many()
+                .col_expr(shelf::Column::Position, Expr::value(position))
+                .filter(shelf::Column::Id.eq(*id))
+                .exec(&txn)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))
+    }
+
+    async fn find_book_ids(&self, shelf_id: i32) -> Result<Vec<i32>, DomainError> {
+        let db = self.db.connection();
+        let entries = shelf_book::Entity::find()
+            .filter(shelf_book::Column::ShelfId.eq(shelf_id))
+            .order_by_asc(shelf_book::Column::Position)
+            .order_by_asc(shelf_book::Column::BookId)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(entries.into_iter().map(|entry| entry.book_id).collect())
+    }
+
+    async fn add_book(&self, shelf_id: i32, book_id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let position = Self::next_position(
+            &db,
+            shelf_book::Entity::find().filter(shelf_book::Column::ShelfId.eq(shelf_id)),
+            shelf_book::Column::Position,
+        )
+        .await?;
+
+        shelf_book::Entity::insert(shelf_book::ActiveModel {
+            shelf_id: Set(shelf_id),
+            book_id: Set(book_id),
+            position: Set(position),
+        })
+        .on_conflict(
+            OnConflict::columns([shelf_book::Column::ShelfId, shelf_book::Column::BookId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .do_nothing()
+        .exec(&db)
+        .await
+        .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn remove_book(&self, shelf_id: i32, book_id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        shelf_book::Entity::delete_many()
+            .filter(shelf_book::Column::ShelfId.eq(shelf_id))
+            .filter(shelf_book::Column::BookId.eq(book_id))
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn reorder_books(&self, shelf_id: i32, book_ids: &[i32]) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        for (position, book_id) in (0..).zip(book_ids) {
+            shelf_book::Entity::update_many()
+                .col_expr(shelf_book::Column::Position, Expr::value(position))
+                .filter(shelf_book::Column::ShelfId.eq(shelf_id))
+                .filter(shelf_book::Column::BookId.eq(*book_id))
+                .exec(&txn)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))
+    }
+}
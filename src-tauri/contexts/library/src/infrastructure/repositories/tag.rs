@@ -0,0 +1,199 @@
+// Library Infrastructure Layer - Tag リポジトリ実装
+
+use crate::domain::errors::DomainError;
+use crate::domain::{
+    entities::tag::Tag, repositories::tag::TagRepository, value_objects::tag_name::TagName,
+};
+use crate::infrastructure::database::DatabaseHandle;
+use async_trait::async_trait;
+use entity::{book_tag, tag};
+use sea_orm::{
+    ActiveModelTrait, Condition, ConnectionTrait, NotSet, QueryOrder, Set, TransactionTrait,
+    entity::prelude::*,
+    sea_query::{LikeExpr, OnConflict, Query},
+};
+
+/// TagRepository の SeaORM実装
+pub struct TagRepositoryImpl {
+    db: DatabaseHandle,
+}
+
+impl TagRepositoryImpl {
+    pub fn new(db: DatabaseHandle) -> Self {
+        Self { db }
+    }
+
+    /// タグとその子孫のタグの条件
+    pub(crate) fn subtree_condition(name: &TagName) -> Condition {
+        // `%` `_` を含むタグ名でも前方一致になるようエスケープする
+        let escaped = name
+            .as_str()
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+
+        Condition::any()
+            .add(tag::Column::Name.eq(name.as_str()))
+            .add(tag::Column::Name.like(LikeExpr::new(format!("{}/%", escaped)).escape('\\')))
+    }
+
+    /// DBモデルをドメインモデルに変換
+    fn db_to_domain(model: tag::Model) -> Tag {
+        Tag::reconstruct(model.id, TagName::reconstruct(model.name))
+    }
+
+    async fn save_with<C: ConnectionTrait>(db: &C, tag: Tag) -> Result<Tag, DomainError> {
+        let active_model = tag::ActiveModel {
+            id: tag.id().map_or(NotSet, Set),
+            name: Set(tag.name().as_str().to_string()),
+        };
+
+        let result = if tag.id().is_some() {
+            // 更新
+            active_model
+                .update(db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        } else {
+            // 新規作成
+            active_model
+                .insert(db)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+        };
+
+        Ok(Self::db_to_domain(result))
+    }
+}
+
+#[async_trait]
+impl TagRepository for TagRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Tag>, DomainError> {
+        let db = self.db.connection();
+        let tag = tag::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(tag.map(Self::db_to_domain))
+    }
+
+    async fn find_by_name(&self, name: &TagName) -> Result<Option<Tag>, DomainError> {
+        let db = self.db.connection();
+        let tag = tag::Entity::find()
+            .filter(tag::Column::Name.eq(name.as_str()))
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(tag.map(Self::db_to_domain))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Tag>, DomainError> {
+        let db = self.db.connection();
+        let tags = tag::Entity::find()
+            .order_by_asc(tag::Column::Name)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(tags.into_iter().map(Self::db_to_domain).collect())
+    }
+
+    async fn find_by_book(&self, book_id: i32) -> Result<Vec<Tag>, DomainError> {
+        let db = self.db.connection();
+        let tags = tag::Entity::find()
+            .filter(
+                tag::Column::Id.in_subquery(
+                    Query::select()
+                        .column(book_tag::Column::TagId)
+                        .from(book_tag::Entity)
+                        .and_where(book_tag::Column::BookId.eq(book_id))
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(tag::Column::Name)
+            .all(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(tags.into_iter().map(Self::db_to_domain).collect())
+    }
+
+    async fn save(&self, tag: Tag) -> Result<Tag, DomainError> {
+        let db = self.db.connection();
+        Self::save_with(&db, tag).await
+    }
+
+    async fn save_all(&self, tags: Vec<Tag>) -> Result<Vec<Tag>, DomainError> {
+        let db = self.db.connection();
+
+        // 途中で失敗した場合はすべて取り消す（トランザクションは破棄時にロールバックされる）
+        let txn = db
+            .begin()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        let mut saved = Vec::with_capacity(tags.len());
+        for tag in tags {
+            saved.push(Self::save_with(&txn, tag).await?);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(saved)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let tag = tag::Entity::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::NotFound(format!("Tag with id {} not found", id)))?;
+
+        // 本への関連付けは外部キーの CASCADE で削除される
+        tag::Entity::delete_many()
+            .filter(Self::subtree_condition(&TagName::reconstruct(tag.name)))
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn add_to_book(&self, book_id: i32, tag_id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        let active_model = book_tag::ActiveModel {
+            book_id: Set(book_id),
+            tag_id: Set(tag_id),
+        };
+
+        book_tag::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([book_tag::Column::BookId, book_tag::Column::TagId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn remove_from_book(&self, book_id: i32, tag_id: i32) -> Result<(), DomainError> {
+        let db = self.db.connection();
+        book_tag::Entity::delete_many()
+            .filter(book_tag::Column::BookId.eq(book_id))
+            .filter(book_tag::Column::TagId.eq(tag_id))
+            .exec(&db)
+            .await
+            .map_err(|e| DomainError::InvalidState(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+// Library Infrastructure Layer - 全文検索の結果の強調表示
+
+use crate::domain::value_objects::search_query::SearchTerm;
+use quick_xml::escape::escape;
+use unicode_normalization::char::decompose_canonical;
+
+/// 一致箇所を囲むマーカー（本文はHTMLエスケープしてから囲む）
+const MARK_START: &str = "<mark>";
+const MARK_END: &str = "</mark>";
+
+/// 抜粋で一致箇所の前に残す文字数
+const SNIPPET_LEAD_CHARS: usize = 16;
+
+/// 索引と同じく英字の大文字・小文字と発音区別符号を区別しないための文字の正規化
+/// （文字数が変わらないよう1文字ずつ変換する。かなの濁点などは索引と同じく区別する）
+fn fold(c: char) -> char {
+    let mut base = None;
+    decompose_canonical(c, |d| {
+        base.get_or_insert(d);
+    });
+    let c = base.filter(char::is_ascii_alphabetic).unwrap_or(c);
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// 検索語のいずれかに一致する文字の位置
+fn matched_chars(chars: &[char], terms: &[SearchTerm]) -> Vec<bool> {
+    let folded: Vec<char> = chars.iter().copied().map(fold).collect();
+    let mut matched = vec![false; chars.len()];
+
+    for term in terms {
+        let needle: Vec<char> = term.text().chars().map(fold).collect();
+        if needle.is_empty() || needle.len() > folded.len() {
+            continue;
+        }
+        for start in 0..=folded.len() - needle.len() {
+            if folded[start..start + needle.len()] == needle[..] {
+                matched[start..start + needle.len()].fill(true);
+            }
+        }
+    }
+
+    matched
+}
+
+/// 文字列をHTMLエスケープし、一致箇所をマーカーで囲む
+fn mark(chars: &[char], matched: &[bool]) -> String {
+    let mut marked = String::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let in_match = matched[start];
+        let end = (start..chars.len())
+            .find(|&i| matched[i] != in_match)
+            .unwrap_or(chars.len());
+        let text: String = chars[start..end].iter().collect();
+        if in_match {
+            marked.push_str(MARK_START);
+            marked.push_str(&escape(&text));
+            marked.push_str(MARK_END);
+        } else {
+            marked.push_str(&escape(&text));
+        }
+        start = end;
+    }
+    marked
+}
+
+/// 本文をHTMLエスケープし、検索語に一致する箇所（英字の大文字・小文字は区別しない）を `<mark>` で囲む
+pub(crate) fn highlight(text: &str, terms: &[SearchTerm]) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let matched = matched_chars(&chars, terms);
+    mark(&chars, &matched)
+}
+
+/// 最初の一致箇所の周辺を最大 `max_chars` 文字抜き出して強調する（一致がない場合は先頭から。切り詰めた側には `…` を付ける）
+pub(crate) fn snippet(text: &str, terms: &[SearchTerm], max_chars: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let matched = matched_chars(&chars, terms);
+
+    let first = matched.iter().position(|&m| m).unwrap_or(0);
+    let end = (first.saturating_sub(SNIPPET_LEAD_CHARS) + max_chars).min(chars.len());
+    let start = end.saturating_sub(max_chars);
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    snippet.push_str(&mark(&chars[start..end], &matched[start..end]));
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::search_query::SearchQuery;
+
+    fn terms(query: &str) -> Vec<SearchTerm> {
+        SearchQuery::parse(query).unwrap().terms().to_vec()
+    }
+
+    #[test]
+    fn test_highlight_escapes_text() {
+        assert_eq!(
+            highlight("<b>Rust</b> & rust", &terms("rust")),
+            "&lt;b&gt;<mark>Rust</mark>&lt;/b&gt; &amp; <mark>rust</mark>"
+        );
+        assert_eq!(
+            highlight("村上春樹", &terms("村上")),
+            "<mark>村上</mark>春樹"
+        );
+        assert_eq!(highlight("Dune", &terms("zero")), "Dune");
+    }
+
+    #[test]
+    fn test_highlight_ignores_diacritics() {
+        assert_eq!(
+            highlight("Café Société", &terms("cafe SOCIÉTÉ")),
+            "<mark>Café</mark> <mark>Société</mark>"
+        );
+        // かなの濁点は区別する
+        assert_eq!(highlight("かがみ", &terms("か")), "<mark>か</mark>がみ");
+    }
+
+    #[test]
+    fn test_overlapping_matches_are_merged() {
+        assert_eq!(
+            highlight("Frank Herbert", &terms(r#""frank h" herbert"#)),
+            "<mark>Frank Herbert</mark>"
+        );
+    }
+
+    #[test]
+    fn test_snippet_around_first_match() {
+        let text = format!("{}砂の惑星{}", "あ".repeat(40), "い".repeat(40));
+        assert_eq!(
+            snippet(&text, &terms("砂の惑星"), 24),
+            format!(
+                "…{}<mark>砂の惑星</mark>{}…",
+                "あ".repeat(16),
+                "い".repeat(4)
+            )
+        );
+        assert_eq!(snippet("短い説明", &terms("dune"), 24), "短い説明");
+    }
+}
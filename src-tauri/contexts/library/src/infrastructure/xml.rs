@@ -0,0 +1,201 @@
+// Library Infrastructure Layer - XML の読み込み（電子書籍のメタデータ用）
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, escape};
+
+/// XML の要素（名前空間の接頭辞を除いた名前で扱う）
+#[derive(Debug, Clone, Default)]
+pub(crate) struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlNode>,
+}
+
+#[derive(Debug, Clone)]
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+impl XmlElement {
+    /// XML 文書を解析してルート要素を返す
+    pub(crate) fn parse(xml: &str) -> Result<Self, String> {
+        let mut reader = Reader::from_str(xml.trim_start_matches('\u{feff}'));
+        // 親要素の一覧（先頭はルートを受け取るための仮の要素）
+        let mut stack = vec![Self::default()];
+
+        loop {
+            match reader.read_event().map_err(|e| e.to_string())? {
+                Event::Start(start) => stack.push(Self::from_start(&start)?),
+                Event::Empty(start) => {
+                    let element = Self::from_start(&start)?;
+                    Self::last(&mut stack)
+                        .children
+                        .push(XmlNode::Element(element));
+                }
+                Event::End(_) => {
+                    if stack.len() < 2 {
+                        return Err("Unexpected closing tag".to_string());
+                    }
+                    let element = stack.pop().unwrap_or_default();
+                    Self::last(&mut stack)
+                        .children
+                        .push(XmlNode::Element(element));
+                }
+                Event::Text(text) => {
+                    let text = text.decode().map_err(|e| e.to_string())?;
+                    Self::last(&mut stack).push_text(&text);
+                }
+                Event::CData(data) => {
+                    let text = String::from_utf8_lossy(&data.into_inner()).into_owned();
+                    Self::last(&mut stack).push_text(&text);
+                }
+                Event::GeneralRef(reference) => {
+                    let name = reference.decode().map_err(|e| e.to_string())?;
+                    let resolved = match reference.resolve_char_ref().map_err(|e| e.to_string())? {
+                        Some(c) => c.to_string(),
+                        None => escape::resolve_predefined_entity(&name)
+                            .map_or_else(|| format!("&{};", name), String::from),
+                    };
+                    Self::last(&mut stack).push_text(&resolved);
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        if stack.len() != 1 {
+            return Err("Unclosed element".to_string());
+        }
+        stack
+            .pop()
+            .and_then(|document| document.elements().next().cloned())
+            .ok_or_else(|| "Empty XML document".to_string())
+    }
+
+    fn from_start(start: &BytesStart<'_>) -> Result<Self, String> {
+        let name = String::from_utf8_lossy(start.local_name().as_ref()).into_owned();
+        let attributes = start
+            .attributes()
+            .map(|attribute| {
+                let attribute = attribute.map_err(|e| e.to_string())?;
+                let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+                let value = attribute.unescape_value().map_err(|e| e.to_string())?;
+                Ok((key, value.into_owned()))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Self {
+            name,
+            attributes,
+            children: Vec::new(),
+        })
+    }
+
+    fn last(stack: &mut [Self]) -> &mut Self {
+        stack
+            .last_mut()
+            .expect("XML element stack must not be empty")
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some(XmlNode::Text(last)) = self.children.last_mut() {
+            last.push_str(text);
+        } else {
+            self.children.push(XmlNode::Text(text.to_string()));
+        }
+    }
+
+    /// 名前空間の接頭辞を除いた要素名
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 属性の値（名前空間の接頭辞を除いた名前で検索）
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 子要素
+    pub(crate) fn elements(&self) -> impl Iterator<Item = &Self> {
+        self.children.iter().filter_map(|node| match node {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    /// 名前が一致する最初の子要素
+    pub(crate) fn element(&self, name: &str) -> Option<&Self> {
+        self.elements().find(|element| element.name == name)
+    }
+
+    /// 名前が一致する子孫の要素（文書内の順）
+    pub(crate) fn descendants<'a>(&'a self, name: &'a str) -> Vec<&'a Self> {
+        let mut found = Vec::new();
+        for element in self.elements() {
+            if element.name == name {
+                found.push(element);
+            }
+            found.extend(element.descendants(name));
+        }
+        found
+    }
+
+    /// 子孫を含むすべてのテキスト（前後の空白は除く）
+    pub(crate) fn text(&self) -> String {
+        fn collect(element: &XmlElement, text: &mut String) {
+            for node in &element.children {
+                match node {
+                    XmlNode::Element(child) => collect(child, text),
+                    XmlNode::Text(value) => text.push_str(value),
+                }
+            }
+        }
+
+        let mut text = String::new();
+        collect(self, &mut text);
+        text.trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_namespaced_document() {
+        let root = XmlElement::parse(
+            r#"<?xml version="1.0"?>
+            <package xmlns:dc="http://purl.org/dc/elements/1.1/">
+              <metadata>
+                <dc:title>Pride &amp; Prejudice</dc:title>
+                <dc:creator opf:role="aut">Jane&#32;Austen</dc:creator>
+                <meta name="cover" content="img"/>
+                <dc:description><![CDATA[<p>A novel</p>]]></dc:description>
+              </metadata>
+            </package>"#,
+        )
+        .unwrap();
+
+        assert_eq!(root.name(), "package");
+        let metadata = root.element("metadata").unwrap();
+        assert_eq!(
+            metadata.element("title").unwrap().text(),
+            "Pride & Prejudice"
+        );
+        let creator = &root.descendants("creator")[0];
+        assert_eq!(creator.text(), "Jane Austen");
+        assert_eq!(creator.attribute("role"), Some("aut"));
+        assert_eq!(
+            metadata.element("meta").unwrap().attribute("content"),
+            Some("img")
+        );
+        assert_eq!(
+            metadata.element("description").unwrap().text(),
+            "<p>A novel</p>"
+        );
+        assert!(XmlElement::parse("<a><b></a>").is_err());
+    }
+}
@@ -6,8 +6,20 @@ pub(crate) mod infrastructure;
 pub(crate) mod presentation;
 
 // Public API - Presentation層のみ公開
-pub use presentation::graphql::{BookMutation, BookQuery};
-pub use presentation::integration::build_book_service;
+pub use presentation::graphql::{BookMutation, BookQuery, LibraryMutation, LibraryQuery};
+pub use presentation::integration::{
+    build_book_service, build_catalog_service, build_copy_service, build_cover_service,
+    build_duplicate_service, build_ebook_service, build_highlight_service, build_loan_service,
+    build_reading_service, build_review_service, build_series_service, build_shelf_service,
+    build_tag_service,
+};
 
 // Type exports for type annotations (opaque to external users)
-pub use application::services::book::BookService;
+pub use application::dto::cover::COVER_PROTOCOL;
+pub use application::services::{
+    book::BookService, catalog::CatalogService, copy::CopyService, cover::CoverService,
+    duplicate::DuplicateService, ebook::EbookService, highlight::HighlightService,
+    loan::LoanService, reading::ReadingService, review::ReviewService, series::SeriesService,
+    shelf::ShelfService, tag::TagService,
+};
+pub use infrastructure::database::DatabaseHandle;
@@ -1,12 +1,55 @@
 // Presentation Layer - Library GraphQL Module
 
 mod error_ext;
+mod fields;
 pub mod mutations;
 pub mod queries;
 
+use async_graphql::MergedObject;
+
 // Re-export for convenience
-pub use mutations::book::BookMutation;
-pub use queries::book::BookQuery;
+pub use mutations::{
+    book::BookMutation, catalog::CatalogMutation, copy::CopyMutation, cover::CoverMutation,
+    duplicate::DuplicateMutation, ebook::EbookMutation, highlight::HighlightMutation,
+    loan::LoanMutation, reading::ReadingMutation, review::ReviewMutation, series::SeriesMutation,
+    shelf::ShelfMutation, tag::TagMutation,
+};
+pub use queries::{
+    book::BookQuery, catalog::CatalogQuery, copy::CopyQuery, duplicate::DuplicateQuery,
+    ebook::EbookQuery, loan::LoanQuery, series::SeriesQuery, shelf::ShelfQuery, tag::TagQuery,
+};
 
 // Re-export error conversion function for internal use
 pub(crate) use error_ext::to_graphql_error;
+
+/// Libraryコンテキストのクエリ（各クエリを統合）
+#[derive(MergedObject, Default)]
+pub struct LibraryQuery(
+    BookQuery,
+    CatalogQuery,
+    SeriesQuery,
+    TagQuery,
+    ShelfQuery,
+    EbookQuery,
+    DuplicateQuery,
+    CopyQuery,
+    LoanQuery,
+);
+
+/// Libraryコンテキストのミューテーション（各ミューテーションを統合）
+#[derive(MergedObject, Default)]
+pub struct LibraryMutation(
+    BookMutation,
+    ReadingMutation,
+    ReviewMutation,
+    HighlightMutation,
+    CatalogMutation,
+    SeriesMutation,
+    TagMutation,
+    ShelfMutation,
+    CoverMutation,
+    EbookMutation,
+    DuplicateMutation,
+    CopyMutation,
+    LoanMutation,
+);
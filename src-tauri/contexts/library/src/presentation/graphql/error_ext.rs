@@ -20,6 +20,9 @@ pub fn to_graphql_error(e: ApplicationError) -> Error {
             DomainError::InvalidState(msg) => Error::new(msg).extend_with(|_, ext| {
                 ext.set("code", "INVALID_STATE");
             }),
+            DomainError::Conflict(msg) => Error::new(msg).extend_with(|_, ext| {
+                ext.set("code", "CONFLICT");
+            }),
             DomainError::IoError(msg) => Error::new(msg).extend_with(|_, ext| {
                 ext.set("code", "IO_ERROR");
             }),
@@ -0,0 +1,7 @@
+// Presentation Layer - Library DTO の関連フィールド
+
+pub mod book;
+pub mod copy;
+pub mod loan;
+pub mod series;
+pub mod shelf;
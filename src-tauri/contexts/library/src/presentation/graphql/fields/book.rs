@@ -0,0 +1,146 @@
+// Presentation Layer - BookDto の関連フィールド（読書状態・評価・メモ・ハイライト・所有している冊など）
+
+use crate::application::{
+    dto::{
+        book::BookDto,
+        copy::CopyDto,
+        ebook::BookFileDto,
+        highlight::HighlightDto,
+        reading::{BookReadingStatus, ReadThroughDto},
+        review::{NoteDto, ReviewDto},
+        series::BookSeriesDto,
+        shelf::ShelfDto,
+        tag::TagDto,
+    },
+    services::{
+        copy::CopyService, ebook::EbookService, highlight::HighlightService,
+        reading::ReadingService, review::ReviewService, series::SeriesService, shelf::ShelfService,
+        tag::TagService,
+    },
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[ComplexObject]
+impl BookDto {
+    /// 現在の読書状態（最新の通読の状態。未登録の場合はなし）
+    async fn reading_status(&self, ctx: &Context<'_>) -> Result<Option<BookReadingStatus>> {
+        let reading_service = ctx
+            .data::<Arc<ReadingService>>()
+            .map_err(|_| Error::new("ReadingService not found"))?;
+
+        reading_service
+            .get_reading_status(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 通読の履歴（古い順。再読ごとに1件）
+    async fn read_throughs(&self, ctx: &Context<'_>) -> Result<Vec<ReadThroughDto>> {
+        let reading_service = ctx
+            .data::<Arc<ReadingService>>()
+            .map_err(|_| Error::new("ReadingService not found"))?;
+
+        reading_service
+            .get_read_throughs(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 評価・感想
+    async fn review(&self, ctx: &Context<'_>) -> Result<Option<ReviewDto>> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .get_review(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 非公開のメモ（古い順）
+    async fn notes(&self, ctx: &Context<'_>) -> Result<Vec<NoteDto>> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .get_notes(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 電子書籍から取り込んだハイライト・メモ（本文中の位置順）
+    async fn highlights(&self, ctx: &Context<'_>) -> Result<Vec<HighlightDto>> {
+        let highlight_service = ctx
+            .data::<Arc<HighlightService>>()
+            .map_err(|_| Error::new("HighlightService not found"))?;
+
+        highlight_service
+            .get_highlights(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 属するシリーズと巻数（シリーズに属していない場合はなし）
+    async fn series(&self, ctx: &Context<'_>) -> Result<Option<BookSeriesDto>> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .get_book_series(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 付いているタグ（名前の順）
+    async fn tags(&self, ctx: &Context<'_>) -> Result<Vec<TagDto>> {
+        let tag_service = ctx
+            .data::<Arc<TagService>>()
+            .map_err(|_| Error::new("TagService not found"))?;
+
+        tag_service
+            .get_book_tags(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 並べている手動の本棚（表示順。スマート本棚は含まない）
+    async fn shelves(&self, ctx: &Context<'_>) -> Result<Vec<ShelfDto>> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .get_book_shelves(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 紐付けた電子書籍ファイル（紐付けた順）
+    async fn files(&self, ctx: &Context<'_>) -> Result<Vec<BookFileDto>> {
+        let ebook_service = ctx
+            .data::<Arc<EbookService>>()
+            .map_err(|_| Error::new("EbookService not found"))?;
+
+        ebook_service
+            .get_book_files(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 所有している冊（登録順。版型・状態・置いてある場所など）
+    async fn copies(&self, ctx: &Context<'_>) -> Result<Vec<CopyDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .get_book_copies(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
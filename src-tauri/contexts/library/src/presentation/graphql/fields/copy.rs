@@ -0,0 +1,106 @@
+// Presentation Layer - CopyDto・LocationDto の関連フィールド（本・場所の経路・貸出・下位の場所・冊数）
+
+use crate::application::{
+    dto::{
+        book::BookDto,
+        copy::{CopyDto, LocationDto},
+        loan::LoanDto,
+    },
+    services::{book::BookService, copy::CopyService, loan::LoanService},
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[ComplexObject]
+impl CopyDto {
+    /// 本（ゴミ箱にある場合はなし）
+    async fn book(&self, ctx: &Context<'_>) -> Result<Option<BookDto>> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .get_book(self.book_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 置いてある場所の部屋からの経路（例: 書斎 > 壁の本棚 > 上段。場所が未設定の場合は空）
+    async fn location_path(&self, ctx: &Context<'_>) -> Result<Vec<LocationDto>> {
+        let Some(location_id) = self.location_id else {
+            return Ok(Vec::new());
+        };
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .get_location_path(location_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出中の貸出（貸し出していない場合はなし）
+    async fn current_loan(&self, ctx: &Context<'_>) -> Result<Option<LoanDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .get_current_loan(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出の履歴（新しい順）
+    async fn loans(&self, ctx: &Context<'_>) -> Result<Vec<LoanDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .get_copy_loans(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[ComplexObject]
+impl LocationDto {
+    /// 1つ下の階層の場所（名前の順）
+    async fn children(&self, ctx: &Context<'_>) -> Result<Vec<LocationDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .list_locations(Some(self.id))
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 部屋からこの場所までの経路（部屋が先頭で、この場所を含む）
+    async fn path(&self, ctx: &Context<'_>) -> Result<Vec<LocationDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .get_location_path(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// この場所（下位の場所を含む）に置いてある冊数（ゴミ箱にある本は含めない）
+    async fn copy_count(&self, ctx: &Context<'_>) -> Result<u64> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .count_copies_in(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
@@ -0,0 +1,54 @@
+// Presentation Layer - LoanDto・BorrowerDto の関連フィールド（貸した冊・貸出相手・貸出の履歴）
+
+use crate::application::{
+    dto::{
+        copy::CopyDto,
+        loan::{BorrowerDto, LoanDto},
+    },
+    services::{copy::CopyService, loan::LoanService},
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[ComplexObject]
+impl LoanDto {
+    /// 貸した冊
+    async fn copy(&self, ctx: &Context<'_>) -> Result<Option<CopyDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .get_copy(self.copy_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出相手
+    async fn borrower(&self, ctx: &Context<'_>) -> Result<Option<BorrowerDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .get_borrower(self.borrower_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[ComplexObject]
+impl BorrowerDto {
+    /// この相手への貸出の履歴（新しい順）
+    async fn loans(&self, ctx: &Context<'_>) -> Result<Vec<LoanDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .get_borrower_loans(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
@@ -0,0 +1,36 @@
+// Presentation Layer - SeriesDto の関連フィールド（巻の一覧・欠けている巻）
+
+use crate::application::{
+    dto::series::{SeriesDto, SeriesVolumeDto},
+    services::series::SeriesService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[ComplexObject]
+impl SeriesDto {
+    /// シリーズの本（巻数の順。1.5巻などの小数の巻を含む）
+    async fn volumes(&self, ctx: &Context<'_>) -> Result<Vec<SeriesVolumeDto>> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .get_volumes(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 所有していない巻（1巻から、刊行済みの巻数と所有している最後の巻の大きい方まで。整数の巻のみ）
+    async fn missing_volumes(&self, ctx: &Context<'_>) -> Result<Vec<i32>> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .get_missing_volumes(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
@@ -0,0 +1,24 @@
+// Presentation Layer - ShelfDto の関連フィールド（並べた本）
+
+use crate::application::{
+    dto::{book::BookDto, shelf::ShelfDto},
+    services::shelf::ShelfService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[ComplexObject]
+impl ShelfDto {
+    /// 本棚の本（手動の本棚は並べた順、スマート本棚は条件に一致する本をタイトルの読み順）
+    async fn books(&self, ctx: &Context<'_>) -> Result<Vec<BookDto>> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .get_shelf_books(self.id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
@@ -1,3 +1,15 @@
 // Presentation Layer - Library Mutations
 
 pub mod book;
+pub mod catalog;
+pub mod copy;
+pub mod cover;
+pub mod duplicate;
+pub mod ebook;
+pub mod highlight;
+pub mod loan;
+pub mod reading;
+pub mod review;
+pub mod series;
+pub mod shelf;
+pub mod tag;
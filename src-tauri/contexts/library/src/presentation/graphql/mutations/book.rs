@@ -1,6 +1,9 @@
 // Presentation Layer - Library Book GraphQL Mutation
 
-use crate::application::{dto::book::BookDto, services::book::BookService};
+use crate::application::{
+    dto::{author::BookAuthorInput, book::BookDto},
+    services::book::BookService,
+};
 use crate::presentation::graphql::to_graphql_error;
 use async_graphql::*;
 use std::sync::Arc;
@@ -18,18 +21,20 @@ impl BookMutation {
         author: Option<String>,
         description: Option<String>,
         published_year: Option<i32>,
+        isbn: Option<String>,
     ) -> Result<BookDto> {
         let book_service = ctx
             .data::<Arc<BookService>>()
             .map_err(|_| Error::new("BookService not found"))?;
 
         book_service
-            .create_book(title, author, description, published_year)
+            .create_book(title, author, description, published_year, isbn)
             .await
             .map_err(to_graphql_error)
     }
 
-    /// 本を更新
+    /// 本を更新（`publisher` は空文字列で削除。`expectedVersion` には編集を始めた時点の版を指定し、他で変更されていた場合は CONFLICT エラーになる）
+    #[allow(clippy::too_many_arguments)]
     async fn update_book(
         &self,
         ctx: &Context<'_>,
@@ -38,18 +43,85 @@ impl BookMutation {
         author: Option<String>,
         description: Option<String>,
         published_year: Option<i32>,
+        isbn: Option<String>,
+        publisher: Option<String>,
+        expected_version: i32,
     ) -> Result<BookDto> {
         let book_service = ctx
             .data::<Arc<BookService>>()
             .map_err(|_| Error::new("BookService not found"))?;
 
         book_service
-            .update_book(id, title, author, description, published_year)
+            .update_book(
+                id,
+                title,
+                author,
+                description,
+                published_year,
+                isbn,
+                publisher,
+                expected_version,
+            )
             .await
             .map_err(to_graphql_error)
     }
 
-    /// 本を削除
+    /// 本のタイトル・著者の読みを更新（省略した項目は変更なし、空文字列で削除。`expectedVersion` は `updateBook` と同じ）
+    async fn update_book_readings(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        title_reading: Option<String>,
+        author_reading: Option<String>,
+        expected_version: i32,
+    ) -> Result<BookDto> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .update_book_readings(id, title_reading, author_reading, expected_version)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本の分類記号を更新（日本十進分類法・デューイ十進分類法。省略した項目は変更なし、空文字列で削除。`expectedVersion` は `updateBook` と同じ）
+    async fn update_book_classification(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        ndc: Option<String>,
+        ddc: Option<String>,
+        expected_version: i32,
+    ) -> Result<BookDto> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .update_book_classification(id, ndc, ddc, expected_version)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本の著者一覧（役割・表示順）を置き換える
+    async fn set_book_authors(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        authors: Vec<BookAuthorInput>,
+    ) -> Result<BookDto> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .set_book_authors(book_id, authors)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本をゴミ箱に移す（`restoreBook` で元に戻せる。保存日数を過ぎると自動で完全に削除する）
     async fn delete_book(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
         let book_service = ctx
             .data::<Arc<BookService>>()
@@ -62,4 +134,54 @@ impl BookMutation {
 
         Ok(true)
     }
+
+    /// ゴミ箱にある本を元に戻す
+    async fn restore_book(&self, ctx: &Context<'_>, id: i32) -> Result<BookDto> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .restore_book(id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本の最後の変更を取り消す（ゴミ箱への移動・復元、編集、まとめてゴミ箱に移した本を元に戻す。続けて呼ぶと古い変更へ順に取り消す。作成とまとめ先の本のまとめは取り消せない）
+    async fn undo_last_change(&self, ctx: &Context<'_>, book_id: i32) -> Result<BookDto> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .undo_last_change(book_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本の書誌情報を履歴の指定した版の状態に戻す（通常の編集と同じバリデーションを行う）
+    async fn revert_to_version(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        version: i32,
+    ) -> Result<BookDto> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .revert_to_revision(book_id, version)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// ゴミ箱を空にする（ゴミ箱にある本を完全に削除し、削除した件数を返す）
+    async fn empty_trash(&self, ctx: &Context<'_>) -> Result<u64> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service.empty_trash().await.map_err(to_graphql_error)
+    }
 }
@@ -0,0 +1,515 @@
+// Presentation Layer - Library Catalog GraphQL Mutation
+
+use crate::application::{
+    dto::import::{
+        BookImportReportDto, CalibreImportReportDto, CsvColumnMappingInput,
+        ExternalImportReportDto, ExternalLibrarySource,
+    },
+    services::catalog::CatalogService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct CatalogMutation;
+
+#[Object]
+impl CatalogMutation {
+    /// CSVから本を一括登録する
+    ///
+    /// 列はヘッダー名で判定し（`exportBooksCsv` と同じ列名のほか、`Year`・`著者` なども可）、
+    /// `columnMapping` で任意のヘッダー名を対応付けられる。`id` 列は無視して新しい本として登録する。
+    /// エラーのある行が1件でもあれば何も登録しない（`allowPartial` で正常な行のみ登録）
+    async fn import_books_csv(
+        &self,
+        ctx: &Context<'_>,
+        content: String,
+        #[graphql(default)] column_mapping: Vec<CsvColumnMappingInput>,
+        #[graphql(default)] allow_partial: bool,
+    ) -> Result<BookImportReportDto> {
+        let catalog_service = ctx
+            .data::<Arc<CatalogService>>()
+            .map_err(|_| Error::new("CatalogService not found"))?;
+
+        catalog_service
+            .import_books_csv(content, column_mapping, allow_partial)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// Goodreads・StoryGraph の書き出しCSVから本・読書状態・評価・感想を取り込む
+    ///
+    /// 登録済みの本（ISBN、またはタイトルと著者が一致）には不足している情報だけを追加する。
+    /// `dryRun` の場合は何も保存せず、行う予定の登録・更新・読み飛ばしを返す
+    async fn import_external_library(
+        &self,
+        ctx: &Context<'_>,
+        source: ExternalLibrarySource,
+        content: String,
+        #[graphql(default)] dry_run: bool,
+    ) -> Result<ExternalImportReportDto> {
+        let catalog_service = ctx
+            .data::<Arc<CatalogService>>()
+            .map_err(|_| Error::new("CatalogService not found"))?;
+
+        catalog_service
+            .import_external_library(source, content, dry_run)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// Calibre のライブラリ（ディレクトリまたは metadata.db のパス）から本を取り込む
+    ///
+    /// Calibre での本のIDを記録し、再取り込み時は同じ本を Calibre の内容で更新する。
+    /// シリーズは同じ名前のシリーズに巻として登録し、タグは同じ名前のタグとして付け、出版社は本の出版社として保存する
+    async fn import_calibre_library(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> Result<CalibreImportReportDto> {
+        let catalog_service = ctx
+            .data::<Arc<CatalogService>>()
+            .map_err(|_| Error::new("CatalogService not found"))?;
+
+        catalog_service
+            .import_calibre_library(path)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{
+        build_book_service, build_catalog_service, build_reading_service, build_review_service,
+        build_series_service, build_tag_service,
+    };
+    use async_graphql::{EmptySubscription, Request, Schema, Variables};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::{ConnectionTrait, Database};
+
+    type TestSchema = Schema<LibraryQuery, LibraryMutation, EmptySubscription>;
+
+    const IMPORT: &str = r#"mutation Import($content: String!, $allowPartial: Boolean!) {
+        importBooksCsv(content: $content, allowPartial: $allowPartial, columnMapping: [{ header: "書籍", field: TITLE }]) {
+            importedCount committed errors { line message } books { id title authors { name } }
+        }
+    }"#;
+
+    const CONTENT: &str = "書籍,Author,Year,ISBN\n\
+        Dune,Frank Herbert,1965,\n\
+        Children of Dune,Frank Herbert,1976,\n\
+        ,Nobody,2000,\n\
+        Neuromancer,William Gibson,nineteen,\n";
+
+    async fn setup_schema() -> TestSchema {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+
+        Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_reading_service(handle.clone()))
+        .data(build_review_service(handle.clone()))
+        .data(build_series_service(handle.clone()))
+        .data(build_tag_service(handle.clone()))
+        .data(build_catalog_service(handle))
+        .finish()
+    }
+
+    async fn import(schema: &TestSchema, allow_partial: bool) -> serde_json::Value {
+        let request = Request::new(IMPORT).variables(Variables::from_json(
+            serde_json::json!({ "content": CONTENT, "allowPartial": allow_partial }),
+        ));
+        let response = schema.execute(request).await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        response.data.into_json().unwrap()["importBooksCsv"].clone()
+    }
+
+    async fn total_count(schema: &TestSchema) -> serde_json::Value {
+        schema
+            .execute("query { books { totalCount } }")
+            .await
+            .data
+            .into_json()
+            .unwrap()["books"]["totalCount"]
+            .clone()
+    }
+
+    #[tokio::test]
+    async fn test_import_with_errors_leaves_library_untouched() {
+        let schema = setup_schema().await;
+
+        let report = import(&schema, false).await;
+        assert_eq!(report["committed"], false);
+        assert_eq!(report["importedCount"], 0);
+        let errors = report["errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0]["line"], 4);
+        assert_eq!(errors[1]["line"], 5);
+        assert_eq!(total_count(&schema).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_partial_import_and_export() {
+        let schema = setup_schema().await;
+
+        let report = import(&schema, true).await;
+        assert_eq!(report["committed"], true);
+        assert_eq!(report["importedCount"], 2);
+        assert_eq!(report["errors"].as_array().unwrap().len(), 2);
+        // 同じ著者は1人として関連付ける
+        assert_eq!(report["books"][1]["authors"][0]["name"], "Frank Herbert");
+        assert_eq!(total_count(&schema).await, 2);
+
+        let data = schema
+            .execute("query { author(id: 1) { books { title } } exportBooksCsv }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["author"]["books"].as_array().unwrap().len(), 2);
+        let history = schema
+            .execute("query { bookHistory(id: 1) { action } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            history["bookHistory"],
+            serde_json::json!([{ "action": "CREATE" }])
+        );
+        assert_eq!(
+            data["exportBooksCsv"],
+            "id,title,author,description,published_year,isbn\n\
+             2,Children of Dune,Frank Herbert,,1976,\n\
+             1,Dune,Frank Herbert,,1965,\n"
+        );
+    }
+
+    const GOODREADS: &str = "Book Id,Title,Author,Additional Authors,ISBN,ISBN13,My Rating,Year Published,Original Publication Year,Date Read,Date Added,Bookshelves,Exclusive Shelf,My Review,Private Notes\n\
+        1,Dune,Frank Herbert,,\"=\"\"0441172717\"\"\",\"=\"\"9780441172719\"\"\",5,1990,1965,2024/03/04,2024/01/02,\"sci-fi, favorites\",read,Loved it.<br/>Again.,p. 42\n\
+        2,Neuromancer,William Gibson,,\"=\"\"\"\"\",\"=\"\"\"\"\",0,,1984,,2024/02/01,,to-read,,\n\
+        3,Dune,Frank Herbert,,\"=\"\"0441172717\"\"\",\"=\"\"9780441172719\"\"\",4,,,,,,read,,\n";
+
+    const IMPORT_EXTERNAL: &str = r#"mutation Import($content: String!, $dryRun: Boolean!) {
+        importExternalLibrary(source: GOODREADS, content: $content, dryRun: $dryRun) {
+            dryRun createdCount updatedCount skippedCount failedCount
+            items { line action bookId isbn readingStatus rating shelves changes message }
+        }
+    }"#;
+
+    async fn import_external(schema: &TestSchema, dry_run: bool) -> serde_json::Value {
+        let request = Request::new(IMPORT_EXTERNAL).variables(Variables::from_json(
+            serde_json::json!({ "content": GOODREADS, "dryRun": dry_run }),
+        ));
+        let response = schema.execute(request).await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        response.data.into_json().unwrap()["importExternalLibrary"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_goodreads_dry_run_and_import() {
+        let schema = setup_schema().await;
+        // ISBNのない登録済みの本（タイトルと著者で対応付ける）
+        schema
+            .execute(r#"mutation { createBook(title: "Neuromancer", author: "Gibson, William") { id } }"#)
+            .await;
+
+        // 試行では何も保存しない
+        let report = import_external(&schema, true).await;
+        assert_eq!(report["dryRun"], true);
+        assert_eq!(report["createdCount"], 1);
+        assert_eq!(report["updatedCount"], 1);
+        assert_eq!(report["skippedCount"], 1);
+        let items = report["items"].as_array().unwrap();
+        assert_eq!(items[0]["action"], "CREATE");
+        assert_eq!(items[0]["isbn"], "9780441172719");
+        assert_eq!(items[0]["readingStatus"], "FINISHED");
+        assert_eq!(items[0]["rating"], 5.0);
+        assert_eq!(
+            items[0]["shelves"],
+            serde_json::json!(["sci-fi", "favorites"])
+        );
+        assert_eq!(
+            items[0]["changes"],
+            serde_json::json!([
+                "Create book",
+                "Add read-through: finished (on 2024-03-04)",
+                "Add review (5 stars)",
+                "Add private note",
+                "Add tags sci-fi, favorites"
+            ])
+        );
+        assert_eq!(items[1]["action"], "UPDATE");
+        assert_eq!(items[1]["bookId"], 1);
+        assert_eq!(items[2]["action"], "SKIP");
+        assert_eq!(items[2]["message"], "Duplicate of line 2");
+        assert_eq!(total_count(&schema).await, 1);
+
+        let report = import_external(&schema, false).await;
+        assert_eq!(report["createdCount"], 1);
+        assert_eq!(report["failedCount"], 0);
+        assert_eq!(report["items"][0]["bookId"], 2);
+
+        let data = schema
+            .execute(
+                "query { dune: book(id: 2) { publishedYear readingStatus review { rating body } notes { body } } neuromancer: book(id: 1) { publishedYear readingStatus } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["dune"]["publishedYear"], 1965);
+        assert_eq!(data["dune"]["readingStatus"], "FINISHED");
+        assert_eq!(data["dune"]["review"]["rating"], 5.0);
+        assert_eq!(data["dune"]["review"]["body"], "Loved it.\nAgain.");
+        assert_eq!(data["dune"]["notes"][0]["body"], "p. 42");
+        assert_eq!(data["neuromancer"]["publishedYear"], 1984);
+        assert_eq!(data["neuromancer"]["readingStatus"], "WANT_TO_READ");
+
+        // 取り込みで作成・更新した本は変更履歴に記録する
+        let data = schema
+            .execute(
+                "query { dune: bookHistory(id: 2) { action source } neuromancer: bookHistory(id: 1) { action source changes { field after } } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["dune"],
+            serde_json::json!([{ "action": "CREATE", "source": "IMPORT" }])
+        );
+        assert_eq!(data["neuromancer"][0]["action"], "UPDATE");
+        assert_eq!(data["neuromancer"][0]["source"], "IMPORT");
+        assert_eq!(data["neuromancer"][1]["source"], "USER");
+        assert_eq!(
+            data["neuromancer"][0]["changes"],
+            serde_json::json!([{ "field": "PUBLISHED_YEAR", "after": "1984" }])
+        );
+
+        // 再取り込みでは追加する情報がない
+        let report = import_external(&schema, false).await;
+        assert_eq!(report["createdCount"], 0);
+        assert_eq!(report["updatedCount"], 0);
+        assert_eq!(report["skippedCount"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_imports_report_isbn_of_trashed_book() {
+        let schema = setup_schema().await;
+        // ゴミ箱にある本もISBNを使っている
+        for mutation in [
+            r#"mutation { createBook(title: "Dune", isbn: "9780441172719") { id } }"#,
+            "mutation { deleteBook(id: 1) }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        // 取り込み全体を失敗させず、行のエラーとして報告する
+        let request = Request::new(IMPORT).variables(Variables::from_json(serde_json::json!({
+            "content": "書籍,ISBN\nDune,9780441172719\nNeuromancer,\n",
+            "allowPartial": true
+        })));
+        let response = schema.execute(request).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let report = &response.data.into_json().unwrap()["importBooksCsv"];
+        assert_eq!(report["importedCount"], 1);
+        assert_eq!(
+            report["errors"],
+            serde_json::json!([{
+                "line": 2,
+                "message": "Domain error: Validation error: A book with ISBN 9780441172719 is in the trash; restore it or empty the trash"
+            }])
+        );
+
+        let report = import_external(&schema, false).await;
+        assert_eq!(report["failedCount"], 1);
+        assert_eq!(report["items"][0]["action"], "FAIL");
+        assert_eq!(
+            report["items"][0]["message"],
+            "Domain error: Validation error: A book with ISBN 9780441172719 is in the trash; restore it or empty the trash"
+        );
+        assert_eq!(report["items"][1]["action"], "UPDATE");
+    }
+
+    /// Calibre の metadata.db と同じ構成のテーブル（取り込みで読む列のみ）
+    const CALIBRE_SCHEMA: &str = "
+        CREATE TABLE books (id INTEGER PRIMARY KEY, title TEXT NOT NULL, pubdate TIMESTAMP, series_index REAL NOT NULL DEFAULT 1.0, isbn TEXT DEFAULT '');
+        CREATE TABLE authors (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE books_authors_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, author INTEGER NOT NULL);
+        CREATE TABLE series (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE books_series_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, series INTEGER NOT NULL);
+        CREATE TABLE tags (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE books_tags_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, tag INTEGER NOT NULL);
+        CREATE TABLE publishers (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE books_publishers_link (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, publisher INTEGER NOT NULL);
+        CREATE TABLE identifiers (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, type TEXT NOT NULL, val TEXT NOT NULL);
+        CREATE TABLE comments (id INTEGER PRIMARY KEY, book INTEGER NOT NULL, text TEXT NOT NULL);
+        INSERT INTO books VALUES
+            (1, 'Dune', '1965-08-01 00:00:00+00:00', 1.0, ''),
+            (2, 'Dune Messiah', '0101-01-01 00:00:00+00:00', 2.0, ''),
+            (3, 'Neuromancer', '1984-07-01 00:00:00+00:00', 1.0, 'not-an-isbn');
+        INSERT INTO authors VALUES (1, 'Frank Herbert'), (2, 'William Gibson');
+        INSERT INTO books_authors_link VALUES (1, 1, 1), (2, 2, 1), (3, 3, 2);
+        INSERT INTO series VALUES (1, 'Dune Chronicles');
+        INSERT INTO books_series_link VALUES (1, 1, 1), (2, 2, 1);
+        INSERT INTO tags VALUES (1, 'Science Fiction'), (2, 'Classic');
+        INSERT INTO books_tags_link VALUES (1, 1, 1), (2, 1, 2);
+        INSERT INTO publishers VALUES (1, 'Chilton Books');
+        INSERT INTO books_publishers_link VALUES (1, 1, 1);
+        INSERT INTO identifiers VALUES (1, 1, 'isbn', '9780441172719'), (2, 1, 'goodreads', '234225');
+        INSERT INTO comments VALUES (1, 1, '<p>Set on the desert planet <b>Arrakis</b>.</p>');
+    ";
+
+    const IMPORT_CALIBRE: &str = r#"mutation Import($path: String!) {
+        importCalibreLibrary(path: $path) {
+            createdCount updatedCount unchangedCount failedCount
+            items { calibreId action bookId changes series seriesIndex tags publisher message }
+        }
+    }"#;
+
+    async fn import_calibre(schema: &TestSchema, path: &str) -> serde_json::Value {
+        let request = Request::new(IMPORT_CALIBRE)
+            .variables(Variables::from_json(serde_json::json!({ "path": path })));
+        let response = schema.execute(request).await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        response.data.into_json().unwrap()["importCalibreLibrary"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_calibre_import_and_reimport() {
+        let schema = setup_schema().await;
+        let library_dir = tempfile::tempdir().unwrap();
+        let metadata_db = library_dir.path().join("metadata.db");
+        let calibre = Database::connect(format!("sqlite://{}?mode=rwc", metadata_db.display()))
+            .await
+            .unwrap();
+        calibre.execute_unprepared(CALIBRE_SCHEMA).await.unwrap();
+
+        // ISBNのない登録済みの本（タイトルと著者で対応付ける）
+        schema
+            .execute(
+                r#"mutation { createBook(title: "Neuromancer", author: "William Gibson") { id } }"#,
+            )
+            .await;
+
+        let path = library_dir.path().to_str().unwrap();
+        let report = import_calibre(&schema, path).await;
+        assert_eq!(report["createdCount"], 2);
+        assert_eq!(report["updatedCount"], 1);
+        assert_eq!(report["failedCount"], 0);
+        let items = report["items"].as_array().unwrap();
+        assert_eq!(items[0]["action"], "CREATE");
+        assert_eq!(items[0]["series"], "Dune Chronicles");
+        assert_eq!(items[0]["seriesIndex"], 1.0);
+        assert_eq!(
+            items[0]["tags"],
+            serde_json::json!(["Classic", "Science Fiction"])
+        );
+        assert_eq!(items[0]["publisher"], "Chilton Books");
+        assert!(items[0]["message"].is_null());
+        assert_eq!(
+            items[0]["changes"],
+            serde_json::json!([
+                "Create book",
+                "Set series Dune Chronicles #1",
+                "Add tags Classic, Science Fiction"
+            ])
+        );
+        assert_eq!(items[2]["action"], "UPDATE");
+        assert_eq!(items[2]["bookId"], 1);
+        assert_eq!(
+            items[2]["changes"],
+            serde_json::json!(["Link Calibre book 3", "Set published year 1984"])
+        );
+        assert_eq!(items[2]["message"], "Ignored invalid ISBN not-an-isbn");
+
+        let dune_id = items[0]["bookId"].as_i64().unwrap();
+        let data = schema
+            .execute(format!(
+                "query {{ book(id: {}) {{ isbn description publishedYear publisher authors {{ name }} tags {{ name }} }} }}",
+                dune_id
+            ))
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["book"]["isbn"], "9780441172719");
+        assert_eq!(
+            data["book"]["description"],
+            "Set on the desert planet Arrakis."
+        );
+        assert_eq!(data["book"]["publishedYear"], 1965);
+        assert_eq!(data["book"]["publisher"], "Chilton Books");
+        assert_eq!(data["book"]["authors"][0]["name"], "Frank Herbert");
+        assert_eq!(
+            data["book"]["tags"],
+            serde_json::json!([{ "name": "Classic" }, { "name": "Science Fiction" }])
+        );
+
+        // シリーズの巻として登録する
+        let data = schema
+            .execute("query { allSeries { name volumes { volume book { title } } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["allSeries"][0]["name"], "Dune Chronicles");
+        assert_eq!(
+            data["allSeries"][0]["volumes"][1],
+            serde_json::json!({ "volume": 2.0, "book": { "title": "Dune Messiah" } })
+        );
+
+        // 再取り込みでは Calibre のIDで同じ本を更新する
+        calibre
+            .execute_unprepared(
+                "UPDATE books SET title = 'Dune (40th Anniversary)' WHERE id = 1;
+                 UPDATE publishers SET name = 'Ace Books' WHERE id = 1;",
+            )
+            .await
+            .unwrap();
+        let report = import_calibre(&schema, metadata_db.to_str().unwrap()).await;
+        assert_eq!(report["createdCount"], 0);
+        assert_eq!(report["updatedCount"], 1);
+        assert_eq!(report["unchangedCount"], 2);
+        assert_eq!(report["items"][0]["bookId"], dune_id);
+        assert_eq!(
+            report["items"][0]["changes"],
+            serde_json::json!([
+                "Set title Dune (40th Anniversary)",
+                "Set publisher Ace Books"
+            ])
+        );
+        assert_eq!(total_count(&schema).await, 3);
+
+        // 存在しないライブラリ
+        let response = schema
+            .execute(Request::new(IMPORT_CALIBRE).variables(Variables::from_json(
+                serde_json::json!({ "path": library_dir.path().join("missing").to_str() }),
+            )))
+            .await;
+        assert!(!response.errors.is_empty());
+    }
+}
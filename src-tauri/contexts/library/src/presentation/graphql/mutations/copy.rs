@@ -0,0 +1,373 @@
+// Presentation Layer - Library Copy GraphQL Mutation
+
+use crate::application::{
+    dto::copy::{AddCopyInput, BookLocationKind, CopyDto, LocationDto, UpdateCopyInput},
+    services::copy::CopyService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct CopyMutation;
+
+#[Object]
+impl CopyMutation {
+    /// 場所を作成（部屋は `parentId` なし、本棚は部屋に、棚は本棚に作る）
+    async fn create_location(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        kind: BookLocationKind,
+        parent_id: Option<i32>,
+    ) -> Result<LocationDto> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .create_location(name, kind, parent_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 場所の名前を変更・別の場所に移す（本棚を別の部屋に、棚を別の本棚に移す場合など）
+    async fn update_location(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        name: Option<String>,
+        parent_id: MaybeUndefined<i32>,
+    ) -> Result<LocationDto> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .update_location(id, name, parent_id.into())
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 場所を削除（下位の場所や置いてある本がある場合は削除できない）
+    async fn delete_location(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .delete_location(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+
+    /// 所有している本の1冊を追加（同じ本を複数冊持つ場合は冊ごとに追加する）
+    async fn add_copy(&self, ctx: &Context<'_>, input: AddCopyInput) -> Result<CopyDto> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service.add_copy(input).await.map_err(to_graphql_error)
+    }
+
+    /// 1冊の情報を更新（省略した項目は変更せず、`null` を指定した項目は未設定に戻す）
+    async fn update_copy(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        input: UpdateCopyInput,
+    ) -> Result<CopyDto> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .update_copy(id, input)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 1冊を削除（本そのものは削除しない）
+    async fn delete_copy(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .delete_copy(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{build_book_service, build_copy_service};
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    type TestSchema = Schema<LibraryQuery, LibraryMutation, EmptySubscription>;
+
+    /// 書斎 > 壁の本棚 > 上段 と 居間 の場所、3冊の本を用意する
+    async fn setup_schema() -> TestSchema {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_copy_service(handle))
+        .finish();
+
+        for mutation in [
+            r#"mutation { createBook(title: "ノルウェイの森", author: "村上春樹") { id } }"#,
+            r#"mutation { createBook(title: "Dune", author: "Frank Herbert") { id } }"#,
+            r#"mutation { createBook(title: "Neuromancer", author: "William Gibson") { id } }"#,
+            r#"mutation { createLocation(name: "書斎", kind: ROOM) { id } }"#,
+            r#"mutation { createLocation(name: "壁の本棚", kind: BOOKCASE, parentId: 1) { id } }"#,
+            r#"mutation { createLocation(name: "上段", kind: SHELF, parentId: 2) { id } }"#,
+            r#"mutation { createLocation(name: "居間", kind: ROOM) { id } }"#,
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+        schema
+    }
+
+    fn error_code(response: &async_graphql::Response) -> String {
+        response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"))
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_copies_and_where_is() {
+        let schema = setup_schema().await;
+
+        for mutation in [
+            r#"mutation { addCopy(input: { bookId: 1, format: PAPERBACK, condition: GOOD, acquiredOn: "2024-05-01", price: { amount: "1,980", currency: "jpy" }, locationId: 3 }) { id } }"#,
+            "mutation { addCopy(input: { bookId: 1, format: HARDCOVER, locationId: 4 }) { id } }",
+            "mutation { addCopy(input: { bookId: 2, format: EBOOK }) { id } }",
+            "mutation { addCopy(input: { bookId: 3, format: PAPERBACK, locationId: 2 }) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        let data = schema
+            .execute(
+                "query { book(id: 1) { copies {
+                    format condition acquiredOn price { amount currency } locationPath { name kind }
+                } } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let copies = &data["book"]["copies"];
+        assert_eq!(copies.as_array().unwrap().len(), 2);
+        assert_eq!(copies[0]["format"], "PAPERBACK");
+        assert_eq!(copies[0]["condition"], "GOOD");
+        assert_eq!(copies[0]["acquiredOn"], "2024-05-01");
+        assert_eq!(
+            copies[0]["price"],
+            serde_json::json!({ "amount": "1980", "currency": "JPY" })
+        );
+        assert_eq!(
+            copies[0]["locationPath"],
+            serde_json::json!([
+                { "name": "書斎", "kind": "ROOM" },
+                { "name": "壁の本棚", "kind": "BOOKCASE" },
+                { "name": "上段", "kind": "SHELF" }
+            ])
+        );
+        assert_eq!(copies[1]["locationPath"][0]["name"], "居間");
+
+        // 本がどこにあるかを探す
+        let data = schema
+            .execute(
+                r#"query { whereIs(query: "gibson") { book { title } locationPath { name } } }"#,
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["whereIs"],
+            serde_json::json!([{
+                "book": { "title": "Neuromancer" },
+                "locationPath": [{ "name": "書斎" }, { "name": "壁の本棚" }]
+            }])
+        );
+
+        // 省略した項目は変更せず、null の項目は未設定に戻す
+        let data = schema
+            .execute(
+                r#"mutation { updateCopy(id: 1, input: { format: HARDCOVER, condition: null, locationId: null }) {
+                    format condition price { amount } locationId locationPath { name }
+                } }"#,
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["updateCopy"],
+            serde_json::json!({
+                "format": "HARDCOVER",
+                "condition": null,
+                "price": { "amount": "1980" },
+                "locationId": null,
+                "locationPath": []
+            })
+        );
+
+        // 不正な価格・存在しない本や場所は不可
+        let response = schema
+            .execute(r#"mutation { addCopy(input: { bookId: 2, format: PAPERBACK, price: { amount: "12.999", currency: "USD" } }) { id } }"#)
+            .await;
+        assert_eq!(error_code(&response), r#""VALIDATION_ERROR""#);
+        for mutation in [
+            "mutation { addCopy(input: { bookId: 99, format: PAPERBACK }) { id } }",
+            "mutation { addCopy(input: { bookId: 2, format: PAPERBACK, locationId: 99 }) { id } }",
+            "mutation { updateCopy(id: 99, input: {}) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert_eq!(error_code(&response), r#""NOT_FOUND""#);
+        }
+
+        // 1冊を削除しても本は残る
+        schema.execute("mutation { deleteCopy(id: 2) }").await;
+        let data = schema
+            .execute("query { book(id: 1) { title copies { id } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["book"]["copies"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_location_hierarchy_and_inventory() {
+        let schema = setup_schema().await;
+
+        // 階層を飛ばす・同じ場所に同じ名前は不可
+        for mutation in [
+            r#"mutation { createLocation(name: "下段", kind: SHELF, parentId: 1) { id } }"#,
+            r#"mutation { createLocation(name: "本棚", kind: BOOKCASE) { id } }"#,
+            r#"mutation { createLocation(name: "上段", kind: SHELF, parentId: 2) { id } }"#,
+            "mutation { updateLocation(id: 2, parentId: null) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert_eq!(error_code(&response), r#""VALIDATION_ERROR""#);
+        }
+
+        for mutation in [
+            "mutation { addCopy(input: { bookId: 1, format: PAPERBACK, locationId: 3 }) { id } }",
+            "mutation { addCopy(input: { bookId: 3, format: HARDCOVER, locationId: 2 }) { id } }",
+            "mutation { addCopy(input: { bookId: 2, format: PAPERBACK, locationId: 4 }) { id } }",
+            "mutation { addCopy(input: { bookId: 2, format: AUDIOBOOK }) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        // 下位の場所を含めて数え、本のタイトルの読み順に並べる
+        let data = schema
+            .execute(
+                "query {
+                    locations { name copyCount children { name copyCount children { name } } }
+                    room: locationInventory(locationId: 1) { bookId locationId }
+                    unplaced: locationInventory { bookId format }
+                }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["locations"],
+            serde_json::json!([
+                { "name": "居間", "copyCount": 1, "children": [] },
+                { "name": "書斎", "copyCount": 2, "children": [
+                    { "name": "壁の本棚", "copyCount": 2, "children": [{ "name": "上段" }] }
+                ] }
+            ])
+        );
+        assert_eq!(
+            data["room"],
+            serde_json::json!([
+                { "bookId": 3, "locationId": 2 },
+                { "bookId": 1, "locationId": 3 }
+            ])
+        );
+        assert_eq!(
+            data["unplaced"],
+            serde_json::json!([{ "bookId": 2, "format": "AUDIOBOOK" }])
+        );
+
+        // 本棚を別の部屋に移すと、置いてある本も一緒に移る
+        let response = schema
+            .execute(r#"mutation { updateLocation(id: 2, name: "窓際の本棚", parentId: 4) { name parentId } }"#)
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = schema
+            .execute(
+                "query {
+                    location(id: 3) { path { name } }
+                    study: location(id: 1) { copyCount }
+                    living: location(id: 4) { copyCount }
+                }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["location"]["path"],
+            serde_json::json!([{ "name": "居間" }, { "name": "窓際の本棚" }, { "name": "上段" }])
+        );
+        assert_eq!(data["study"]["copyCount"], 0);
+        assert_eq!(data["living"]["copyCount"], 3);
+
+        // ゴミ箱にある本は数えない
+        schema.execute("mutation { deleteBook(id: 3) }").await;
+        let data = schema
+            .execute("query { location(id: 2) { copyCount } locationInventory(locationId: 2) { bookId } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["location"]["copyCount"], 1);
+        assert_eq!(
+            data["locationInventory"],
+            serde_json::json!([{ "bookId": 1 }])
+        );
+
+        // 下位の場所や本がある場所は削除できない
+        let response = schema.execute("mutation { deleteLocation(id: 2) }").await;
+        assert_eq!(error_code(&response), r#""INVALID_STATE""#);
+        let response = schema.execute("mutation { deleteLocation(id: 1) }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = schema
+            .execute("query { locations { name } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["locations"], serde_json::json!([{ "name": "居間" }]));
+    }
+}
@@ -0,0 +1,168 @@
+// Presentation Layer - Library Cover GraphQL Mutation
+
+use crate::application::{dto::book::BookDto, services::cover::CoverService};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct CoverMutation;
+
+#[Object]
+impl CoverMutation {
+    /// Base64 でエンコードされた画像を本の表紙にする（JPEG・PNG・WebP・GIF、20MBまで）
+    async fn set_book_cover(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        image_base64: String,
+    ) -> Result<BookDto> {
+        let cover_service = ctx
+            .data::<Arc<CoverService>>()
+            .map_err(|_| Error::new("CoverService not found"))?;
+
+        cover_service
+            .set_book_cover(book_id, &image_base64)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// ローカルのファイルの画像を本の表紙にする（画像はアプリのデータディレクトリにコピーする）
+    async fn set_book_cover_from_file(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        path: String,
+    ) -> Result<BookDto> {
+        let cover_service = ctx
+            .data::<Arc<CoverService>>()
+            .map_err(|_| Error::new("CoverService not found"))?;
+
+        cover_service
+            .set_book_cover_from_file(book_id, &PathBuf::from(path))
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本の表紙画像を外す
+    async fn remove_book_cover(&self, ctx: &Context<'_>, book_id: i32) -> Result<BookDto> {
+        let cover_service = ctx
+            .data::<Arc<CoverService>>()
+            .map_err(|_| Error::new("CoverService not found"))?;
+
+        cover_service
+            .remove_book_cover(book_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{build_book_service, build_cover_service};
+    use async_graphql::{EmptySubscription, Schema};
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+    use std::io::Cursor;
+
+    fn png(color: [u8; 4]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(30, 45, Rgba(color)))
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[tokio::test]
+    async fn test_set_replace_and_remove_book_cover() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let covers_dir = tempfile::tempdir().unwrap();
+        let cover_service = build_cover_service(handle.clone(), covers_dir.path().to_path_buf());
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle))
+        .data(cover_service.clone())
+        .finish();
+
+        let response = schema
+            .execute(r#"mutation { createBook(title: "Dune") { id coverUrl } }"#)
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert!(response.data.into_json().unwrap()["createBook"]["coverUrl"].is_null());
+
+        // アップロードした画像は URL からカスタムプロトコル経由で読み込める
+        let mutation = format!(
+            r#"mutation {{ setBookCover(bookId: 1, imageBase64: "data:image/png;base64,{}") {{ coverUrl thumbnailUrl }} }}"#,
+            STANDARD.encode(png([10, 20, 30, 255]))
+        );
+        let response = schema.execute(mutation).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let path = |url: &serde_json::Value| {
+            let url = url.as_str().unwrap();
+            url[url.find("localhost").unwrap() + "localhost".len()..].to_string()
+        };
+        let cover_path = path(&data["setBookCover"]["coverUrl"]);
+        let thumbnail_path = path(&data["setBookCover"]["thumbnailUrl"]);
+        assert!(cover_path.starts_with("/covers/"));
+        let image = cover_service
+            .read_cover_resource(&cover_path)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(image::guess_format(&image).unwrap(), ImageFormat::Jpeg);
+        assert!(
+            cover_service
+                .read_cover_resource(&thumbnail_path)
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // ファイルから置き換えると、使われなくなった画像は削除される
+        let file = covers_dir.path().join("new-cover.png");
+        std::fs::write(&file, png([200, 100, 0, 128])).unwrap();
+        let mutation = format!(
+            r#"mutation {{ setBookCoverFromFile(bookId: 1, path: {:?}) {{ coverUrl }} }}"#,
+            file.to_str().unwrap()
+        );
+        let response = schema.execute(mutation).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_ne!(path(&data["setBookCoverFromFile"]["coverUrl"]), cover_path);
+        assert!(
+            cover_service
+                .read_cover_resource(&cover_path)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let response = schema
+            .execute("mutation { removeBookCover(bookId: 1) { coverUrl thumbnailUrl } }")
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "removeBookCover": { "coverUrl": null, "thumbnailUrl": null } })
+        );
+
+        // 画像でないデータは受け付けない
+        let response = schema
+            .execute(
+                r#"mutation { setBookCover(bookId: 1, imageBase64: "bm90IGFuIGltYWdl") { id } }"#,
+            )
+            .await;
+        assert_eq!(response.errors.len(), 1);
+    }
+}
@@ -0,0 +1,258 @@
+// Presentation Layer - Library Duplicate GraphQL Mutation
+
+use crate::application::{
+    dto::{book::BookDto, duplicate::MergeFieldChoiceInput},
+    services::duplicate::DuplicateService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct DuplicateMutation;
+
+#[Object]
+impl DuplicateMutation {
+    /// 重複した本を1冊にまとめる（まとめる本の読書記録・メモ・タグなどは付け替え、本はゴミ箱に移す）
+    ///
+    /// `fieldChoices` で項目ごとに値を採る本を指定する。指定がない項目はまとめ先の値を採り、
+    /// 空の場合はまとめる本の値を `otherIds` の順に採る。
+    async fn merge_books(
+        &self,
+        ctx: &Context<'_>,
+        primary_id: i32,
+        other_ids: Vec<i32>,
+        #[graphql(default)] field_choices: Vec<MergeFieldChoiceInput>,
+    ) -> Result<BookDto> {
+        let duplicate_service = ctx
+            .data::<Arc<DuplicateService>>()
+            .map_err(|_| Error::new("DuplicateService not found"))?;
+
+        duplicate_service
+            .merge_books(primary_id, other_ids, field_choices)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{
+        build_book_service, build_duplicate_service, build_reading_service, build_review_service,
+        build_shelf_service, build_tag_service,
+    };
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_find_and_merge_duplicates() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let covers_dir = tempfile::tempdir().unwrap();
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_reading_service(handle.clone()))
+        .data(build_review_service(handle.clone()))
+        .data(build_tag_service(handle.clone()))
+        .data(build_shelf_service(handle.clone()))
+        .data(build_duplicate_service(
+            handle,
+            covers_dir.path().to_path_buf(),
+        ))
+        .finish();
+
+        let execute = |query: &'static str| {
+            let schema = schema.clone();
+            async move {
+                let response = schema.execute(query).await;
+                assert!(response.errors.is_empty(), "{:?}", response.errors);
+                response.data.into_json().unwrap()
+            }
+        };
+
+        for mutation in [
+            r#"mutation { createBook(title: "ワンピース 1", author: "尾田 栄一郎") { id } }"#,
+            r#"mutation { createBook(title: "ﾜﾝﾋﾟｰｽ　１", author: "尾田栄一郎", description: "海賊の物語", isbn: "9784088725093") { id } }"#,
+            r#"mutation { createBook(title: "Dune", author: "Frank Herbert") { id } }"#,
+            r#"mutation { createBook(title: "DUNE", author: "Someone Else") { id } }"#,
+            r#"mutation { createBook(title: "わんぴーす1") { id } }"#,
+        ] {
+            execute(mutation).await;
+        }
+
+        // 幅・カタカナの違いを無視したタイトルで1つのグループになり、著者が異なる Dune は含めない
+        let data = execute("query { duplicateBooks { books { id } confidence reasons } }").await;
+        let groups = data["duplicateBooks"].as_array().unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(
+            groups[0]["books"],
+            serde_json::json!([{ "id": 1 }, { "id": 2 }, { "id": 5 }])
+        );
+        assert!(groups[0]["confidence"].as_f64().unwrap() >= 0.5);
+        assert_eq!(
+            groups[0]["reasons"],
+            serde_json::json!(["SAME_TITLE", "SIMILAR_AUTHORS"])
+        );
+        let data = execute("query { duplicateBooks(minConfidence: 0.0) { books { id } } }").await;
+        assert_eq!(data["duplicateBooks"].as_array().unwrap().len(), 2);
+
+        // まとめる本に付いた記録・タグ・本棚はまとめ先に付け替える
+        execute(r#"mutation { createNote(bookId: 2, body: "メモ") { id } }"#).await;
+        execute(r#"mutation { createReview(bookId: 2, rating: 4.5) { id } }"#).await;
+        execute(r#"mutation { tagBook(bookId: 1, name: "漫画") { id } }"#).await;
+        execute(r#"mutation { tagBook(bookId: 2, name: "漫画") { id } }"#).await;
+        execute(r#"mutation { tagBook(bookId: 5, name: "日本語") { id } }"#).await;
+        execute(r#"mutation { createShelf(name: "Favorites") { id } }"#).await;
+        execute("mutation { addBookToShelf(shelfId: 1, bookId: 2) { id } }").await;
+        execute("mutation { addBookToShelf(shelfId: 1, bookId: 3) { id } }").await;
+
+        let data = execute(
+            r#"mutation { mergeBooks(primaryId: 1, otherIds: [2, 5], fieldChoices: [{ field: TITLE, bookId: 2 }]) {
+                id title author description isbn notes { body } review { rating } tags { name } shelves { name }
+            } }"#,
+        )
+        .await;
+        let merged = &data["mergeBooks"];
+        assert_eq!(merged["id"], 1);
+        assert_eq!(merged["title"], "ﾜﾝﾋﾟｰｽ　１");
+        assert_eq!(merged["author"], "尾田 栄一郎");
+        assert_eq!(merged["description"], "海賊の物語");
+        assert_eq!(merged["isbn"], "9784088725093");
+        assert_eq!(merged["notes"], serde_json::json!([{ "body": "メモ" }]));
+        assert_eq!(merged["review"]["rating"], 4.5);
+        assert_eq!(merged["tags"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            merged["shelves"],
+            serde_json::json!([{ "name": "Favorites" }])
+        );
+
+        // まとめ先は本棚の末尾に加え、位置が重ならない
+        let data = execute("query { shelf(id: 1) { books { id } } }").await;
+        assert_eq!(
+            data["shelf"]["books"],
+            serde_json::json!([{ "id": 3 }, { "id": 1 }])
+        );
+
+        // まとめる本はゴミ箱に移し、まとめ先に引き継いだISBNは外す
+        let data = execute(
+            "query { book(id: 2) { id } trashedBooks { id isbn } duplicateBooks { books { id } } }",
+        )
+        .await;
+        assert!(data["book"].is_null());
+        assert_eq!(
+            data["trashedBooks"],
+            serde_json::json!([{ "id": 5, "isbn": null }, { "id": 2, "isbn": null }])
+        );
+        assert_eq!(data["duplicateBooks"], serde_json::json!([]));
+        let data = execute("query { bookHistory(id: 2) { action source } }").await;
+        assert_eq!(
+            data["bookHistory"][0],
+            serde_json::json!({ "action": "MERGE", "source": "MERGE" })
+        );
+
+        // まとめた本は取り消しでゴミ箱から元に戻せる（付け替えた記録はまとめ先に残る）
+        let data = execute("mutation { undoLastChange(bookId: 5) { id tags { name } } }").await;
+        assert_eq!(
+            data["undoLastChange"],
+            serde_json::json!({ "id": 5, "tags": [{ "name": "日本語" }] })
+        );
+        execute("mutation { deleteBook(id: 5) }").await;
+
+        // まとめ先の変更履歴にまとめとして記録し、まとめ先のまとめは取り消せない
+        let data = execute("query { bookHistory(id: 1) { action changes { field } } }").await;
+        assert_eq!(data["bookHistory"][0]["action"], "MERGE");
+        assert_eq!(
+            data["bookHistory"][0]["changes"],
+            serde_json::json!([{ "field": "TITLE" }, { "field": "DESCRIPTION" }, { "field": "ISBN" }])
+        );
+        let response = schema
+            .execute("mutation { undoLastChange(bookId: 1) { id } }")
+            .await;
+        assert_eq!(response.errors.len(), 1);
+
+        // まとめ先を含む指定・まとめる本にない本からの値の指定は不可
+        for mutation in [
+            "mutation { mergeBooks(primaryId: 1, otherIds: [1, 3]) { id } }",
+            "mutation { mergeBooks(primaryId: 1, otherIds: [3], fieldChoices: [{ field: TITLE, bookId: 4 }]) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert_eq!(response.errors.len(), 1);
+        }
+
+        // 分類記号はまとめ先（なければまとめる本の順に最初に見つかったもの）か、指定した本の値を採る
+        execute(
+            r#"mutation { updateBookClassification(id: 3, ndc: "933", ddc: "813.54", expectedVersion: 1) { id } }"#,
+        )
+        .await;
+        execute(r#"mutation { updateBookClassification(id: 4, ndc: "913.6", expectedVersion: 1) { id } }"#).await;
+        let data =
+            execute("mutation { mergeBooks(primaryId: 4, otherIds: [3]) { ndc ddc } }").await;
+        assert_eq!(
+            data["mergeBooks"],
+            serde_json::json!({ "ndc": "913.6", "ddc": null })
+        );
+        execute(r#"mutation { createBook(title: "Dune Messiah") { id } }"#).await;
+        execute(
+            r#"mutation { updateBookClassification(id: 6, ndc: "933", ddc: "813.54", expectedVersion: 1) { id } }"#,
+        )
+        .await;
+        let data = execute(
+            "mutation { mergeBooks(primaryId: 4, otherIds: [6], fieldChoices: [{ field: CLASSIFICATION, bookId: 6 }]) { ndc ddc } }",
+        )
+        .await;
+        assert_eq!(
+            data["mergeBooks"],
+            serde_json::json!({ "ndc": "933", "ddc": "813.54" })
+        );
+        execute(r#"mutation { createBook(title: "Dune (新訳版)") { id } }"#).await;
+        let data =
+            execute("mutation { mergeBooks(primaryId: 7, otherIds: [4]) { ndc ddc } }").await;
+        assert_eq!(
+            data["mergeBooks"],
+            serde_json::json!({ "ndc": "933", "ddc": "813.54" })
+        );
+
+        // 読みはまとめ先（なければまとめる本の順に最初に見つかったもの）か、指定した本の値を採り、並び替え用のキーを読みから作る
+        for mutation in [
+            r#"mutation { createBook(title: "吾輩は猫である", author: "夏目漱石") { id } }"#,
+            r#"mutation { createBook(title: "吾輩ハ猫デアル", author: "夏目漱石") { id } }"#,
+            r#"mutation { createBook(title: "吾輩は猫である（新装版）", author: "夏目漱石") { id } }"#,
+            r#"mutation { updateBookReadings(id: 9, titleReading: "わがはいはねこである", authorReading: "なつめそうせき", expectedVersion: 1) { id } }"#,
+            r#"mutation { updateBookReadings(id: 10, titleReading: "わがはいはねこである しんそうばん", expectedVersion: 1) { id } }"#,
+        ] {
+            execute(mutation).await;
+        }
+        let data = execute(
+            "mutation { mergeBooks(primaryId: 8, otherIds: [9]) { titleReading authorReading titleSortKey authorSortKey } }",
+        )
+        .await;
+        assert_eq!(
+            data["mergeBooks"],
+            serde_json::json!({
+                "titleReading": "わがはいはねこである",
+                "authorReading": "なつめそうせき",
+                "titleSortKey": "わがはいはねこである",
+                "authorSortKey": "なつめそうせき"
+            })
+        );
+        let data = execute(
+            "mutation { mergeBooks(primaryId: 8, otherIds: [10], fieldChoices: [{ field: READINGS, bookId: 10 }]) { titleReading authorReading } }",
+        )
+        .await;
+        assert_eq!(
+            data["mergeBooks"],
+            serde_json::json!({
+                "titleReading": "わがはいはねこである しんそうばん",
+                "authorReading": null
+            })
+        );
+    }
+}
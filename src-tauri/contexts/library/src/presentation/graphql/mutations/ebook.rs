@@ -0,0 +1,233 @@
+// Presentation Layer - Library E-book GraphQL Mutation
+
+use crate::application::{
+    dto::ebook::{BookFileDto, EbookImportResultDto},
+    services::ebook::EbookService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct EbookMutation;
+
+#[Object]
+impl EbookMutation {
+    /// EPUB・PDF ファイルの書誌情報から本を登録し、ファイルを紐付ける
+    ///
+    /// ISBNが一致する本が登録済みの場合はその本に紐付ける。
+    /// `copyFile` の場合はアプリのデータディレクトリにコピーしたファイルを紐付ける。
+    async fn import_ebook(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+        #[graphql(default)] copy_file: bool,
+    ) -> Result<EbookImportResultDto> {
+        let ebook_service = ctx
+            .data::<Arc<EbookService>>()
+            .map_err(|_| Error::new("EbookService not found"))?;
+
+        ebook_service
+            .import_ebook(path, copy_file)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 登録済みの本に EPUB・PDF ファイルを紐付ける
+    async fn attach_book_file(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        path: String,
+        #[graphql(default)] copy_file: bool,
+    ) -> Result<BookFileDto> {
+        let ebook_service = ctx
+            .data::<Arc<EbookService>>()
+            .map_err(|_| Error::new("EbookService not found"))?;
+
+        ebook_service
+            .attach_book_file(book_id, path, copy_file)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// ファイルの紐付けを外す（コピーしたファイルは削除する）
+    async fn remove_book_file(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let ebook_service = ctx
+            .data::<Arc<EbookService>>()
+            .map_err(|_| Error::new("EbookService not found"))?;
+
+        ebook_service
+            .remove_book_file(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::infrastructure::repositories::ebook::tests::{epub, pdf, pdf_text};
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{build_book_service, build_ebook_service};
+    use async_graphql::{EmptySubscription, Schema};
+    use image::{DynamicImage, ImageFormat, RgbImage};
+    use lopdf::dictionary;
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+    use std::io::Cursor;
+
+    #[tokio::test]
+    async fn test_import_epub_and_pdf_files() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let data_dir = tempfile::tempdir().unwrap();
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_ebook_service(
+            handle,
+            data_dir.path().join("covers"),
+            data_dir.path().join("books"),
+        ))
+        .finish();
+
+        let mut jpeg = Vec::new();
+        DynamicImage::ImageRgb8(RgbImage::new(20, 30))
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+        let epub_path = data_dir.path().join("Rashomon.epub");
+        std::fs::write(
+            &epub_path,
+            epub(
+                r##"<dc:title>羅生門</dc:title>
+                <dc:creator>芥川 龍之介</dc:creator>
+                <dc:creator opf:role="trl">Jay Rubin</dc:creator>
+                <dc:identifier>urn:isbn:978-4-10-102501-8</dc:identifier>
+                <dc:description>&lt;p&gt;短編集&lt;/p&gt;</dc:description>
+                <dc:publisher>新潮社</dc:publisher>
+                <dc:date>1968</dc:date>"##,
+                &jpeg,
+            ),
+        )
+        .unwrap();
+        let epub_path = epub_path.to_str().unwrap();
+
+        // 書誌情報の読み込みは本を登録しない
+        let response = schema
+            .execute(format!(
+                r#"{{ ebookMetadata(path: {:?}) {{ format title author isbn description publishedYear hasCover creators {{ name role }} }} books {{ totalCount }} }}"#,
+                epub_path
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({
+                "ebookMetadata": {
+                    "format": "EPUB",
+                    "title": "羅生門",
+                    "author": "芥川 龍之介",
+                    "isbn": "9784101025018",
+                    "description": "短編集",
+                    "publishedYear": 1968,
+                    "hasCover": true,
+                    "creators": [
+                        { "name": "芥川 龍之介", "role": "AUTHOR" },
+                        { "name": "Jay Rubin", "role": "TRANSLATOR" }
+                    ]
+                },
+                "books": { "totalCount": 0 }
+            })
+        );
+
+        // 取り込むと本を登録し、表紙とファイルを紐付ける
+        let import = |path: &str, copy: bool| {
+            format!(
+                r#"mutation {{ importEbook(path: {:?}, copyFile: {}) {{ created messages book {{ id title publisher authors {{ name role }} thumbnailUrl files {{ path format managed }} }} file {{ id path managed }} }} }}"#,
+                path, copy
+            )
+        };
+        let response = schema.execute(import(epub_path, false)).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let result = &data["importEbook"];
+        assert_eq!(result["created"], true);
+        assert_eq!(result["book"]["title"], "羅生門");
+        assert_eq!(result["book"]["publisher"], "新潮社");
+        assert_eq!(result["book"]["authors"][1]["role"], "TRANSLATOR");
+        assert!(result["book"]["thumbnailUrl"].is_string());
+        assert_eq!(
+            result["book"]["files"],
+            serde_json::json!([{ "path": epub_path, "format": "EPUB", "managed": false }])
+        );
+
+        // 同じファイルは再び紐付けられない
+        let response = schema.execute(import(epub_path, false)).await;
+        assert_eq!(response.errors.len(), 1);
+
+        // ISBNが一致する本には新しく登録せずに紐付け、コピーは管理ディレクトリに置く
+        let pdf_path = data_dir.path().join("rashomon.pdf");
+        std::fs::write(
+            &pdf_path,
+            pdf(
+                dictionary! {
+                    "Title" => pdf_text("Rashōmon"),
+                    "Author" => pdf_text("Ryūnosuke Akutagawa"),
+                },
+                Some(
+                    r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"><rdf:Description><dc:identifier>9784101025018</dc:identifier></rdf:Description></rdf:RDF></x:xmpmeta>"#,
+                ),
+                &jpeg,
+            ),
+        )
+        .unwrap();
+        let response = schema
+            .execute(import(pdf_path.to_str().unwrap(), true))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let result = &data["importEbook"];
+        assert_eq!(result["created"], false);
+        assert_eq!(result["book"]["title"], "羅生門");
+        assert_eq!(result["file"]["managed"], true);
+        let copy = result["file"]["path"].as_str().unwrap().to_string();
+        assert!(copy.starts_with(data_dir.path().join("books").to_str().unwrap()));
+        assert!(std::path::Path::new(&copy).is_file());
+
+        // 紐付けを外すとコピーは削除され、元のファイルは残る
+        let file_id = result["file"]["id"].as_i64().unwrap();
+        let response = schema
+            .execute(format!("mutation {{ removeBookFile(id: {}) }}", file_id))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert!(!std::path::Path::new(&copy).exists());
+        assert!(pdf_path.is_file());
+
+        // 取り込みに失敗した場合はコピーを残さない
+        let response = schema.execute("mutation { deleteBook(id: 1) }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let response = schema
+            .execute(import(pdf_path.to_str().unwrap(), true))
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(
+            std::fs::read_dir(data_dir.path().join("books"))
+                .unwrap()
+                .count(),
+            0
+        );
+
+        // 形式に対応しないファイルは受け付けない
+        let response = schema
+            .execute(r#"mutation { attachBookFile(bookId: 1, path: "/tmp/notes.txt") { id } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+    }
+}
@@ -0,0 +1,164 @@
+// Presentation Layer - Library Highlight GraphQL Mutation
+
+use crate::application::{
+    dto::highlight::KindleImportReportDto, services::highlight::HighlightService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct HighlightMutation;
+
+#[Object]
+impl HighlightMutation {
+    /// Kindle の「My Clippings.txt」の内容からハイライト・メモを取り込む
+    ///
+    /// 本はタイトルと著者で対応付け、見つからなければ作成する。取り込み済みの項目は重複として読み飛ばす
+    async fn import_kindle_clippings(
+        &self,
+        ctx: &Context<'_>,
+        content: String,
+    ) -> Result<KindleImportReportDto> {
+        let highlight_service = ctx
+            .data::<Arc<HighlightService>>()
+            .map_err(|_| Error::new("HighlightService not found"))?;
+
+        highlight_service
+            .import_kindle_clippings(content)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// ハイライトを削除
+    async fn delete_highlight(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let highlight_service = ctx
+            .data::<Arc<HighlightService>>()
+            .map_err(|_| Error::new("HighlightService not found"))?;
+
+        highlight_service
+            .delete_highlight(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{BookQuery, LibraryMutation};
+    use crate::presentation::integration::{build_book_service, build_highlight_service};
+    use async_graphql::{EmptySubscription, Request, Schema, Variables};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    type TestSchema = Schema<BookQuery, LibraryMutation, EmptySubscription>;
+
+    const CLIPPINGS: &str = "\u{feff}Dune (Herbert, Frank)\r\n\
+        - Your Highlight on page 12 | Location 180-181 | Added on Sunday, October 18, 2026 10:12:33 PM\r\n\
+        \r\n\
+        I must not fear.\r\n\
+        ==========\r\n\
+        \u{feff}吾輩は猫である (夏目 漱石)\r\n\
+        - 位置No. 10-11のハイライト |作成日: 2026年10月18日日曜日 22:12:33\r\n\
+        \r\n\
+        吾輩は猫である。名前はまだ無い。\r\n\
+        ==========\r\n\
+        \u{feff}Dune (Herbert, Frank)\r\n\
+        - Your Bookmark on Location 200 | Added on Sunday, October 18, 2026 10:20:00 PM\r\n\
+        \r\n\
+        \r\n\
+        ==========\r\n\
+        broken entry\r\n\
+        ==========\r\n";
+
+    const IMPORT: &str = r#"mutation Import($content: String!) {
+        importKindleClippings(content: $content) {
+            importedCount duplicateCount skippedCount failedCount
+            entries { index status title bookId bookCreated message }
+        }
+    }"#;
+
+    async fn setup_schema() -> TestSchema {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let book_service = build_book_service(handle.clone());
+        // 著者名の表記が異なる既存の本
+        book_service
+            .create_book(
+                "Dune".to_string(),
+                Some("Frank Herbert".to_string()),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        Schema::build(BookQuery, LibraryMutation::default(), EmptySubscription)
+            .data(book_service)
+            .data(build_highlight_service(handle))
+            .finish()
+    }
+
+    async fn import(schema: &TestSchema) -> serde_json::Value {
+        let request = Request::new(IMPORT).variables(Variables::from_json(
+            serde_json::json!({ "content": CLIPPINGS }),
+        ));
+        let response = schema.execute(request).await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        response.data.into_json().unwrap()["importKindleClippings"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_import_kindle_clippings_is_idempotent() {
+        let schema = setup_schema().await;
+
+        let report = import(&schema).await;
+        assert_eq!(report["importedCount"], 2);
+        assert_eq!(report["skippedCount"], 1);
+        assert_eq!(report["failedCount"], 1);
+        let entries = report["entries"].as_array().unwrap();
+        // 既存の本に対応付ける
+        assert_eq!(entries[0]["status"], "IMPORTED");
+        assert_eq!(entries[0]["bookId"], 1);
+        assert_eq!(entries[0]["bookCreated"], false);
+        // 見つからない本は作成する
+        assert_eq!(entries[1]["bookId"], 2);
+        assert_eq!(entries[1]["bookCreated"], true);
+        assert_eq!(entries[2]["status"], "SKIPPED");
+        assert_eq!(entries[3]["status"], "FAILED");
+        assert_eq!(entries[3]["title"], "broken entry");
+
+        // 再取り込みしても重複しない
+        let report = import(&schema).await;
+        assert_eq!(report["importedCount"], 0);
+        assert_eq!(report["duplicateCount"], 2);
+        assert_eq!(report["entries"][1]["bookId"], 2);
+        assert_eq!(report["entries"][1]["bookCreated"], false);
+
+        let data = schema
+            .execute(
+                "query { books(first: 10) { totalCount } book(id: 2) { title author highlights { kind content locationStart locationEnd clippedAt } } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["books"]["totalCount"], 2);
+        assert_eq!(data["book"]["author"], "夏目 漱石");
+        let highlights = data["book"]["highlights"].as_array().unwrap();
+        assert_eq!(highlights.len(), 1);
+        assert_eq!(highlights[0]["kind"], "HIGHLIGHT");
+        assert_eq!(highlights[0]["locationStart"], 10);
+        assert_eq!(highlights[0]["locationEnd"], 11);
+        assert_eq!(highlights[0]["clippedAt"], "2026-10-18T22:12:33");
+    }
+}
@@ -0,0 +1,370 @@
+// Presentation Layer - Library Loan GraphQL Mutation
+
+use crate::application::{
+    dto::loan::{BorrowerDto, LoanDto},
+    services::loan::LoanService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct LoanMutation;
+
+#[Object]
+impl LoanMutation {
+    /// 貸出相手を登録
+    async fn create_borrower(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        email: Option<String>,
+        phone: Option<String>,
+    ) -> Result<BorrowerDto> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .create_borrower(name, email, phone)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出相手の連絡先を更新（省略した項目は変更せず、`null` を指定した項目は削除する）
+    async fn update_borrower(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        name: Option<String>,
+        email: MaybeUndefined<String>,
+        phone: MaybeUndefined<String>,
+    ) -> Result<BorrowerDto> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .update_borrower(id, name, email.into(), phone.into())
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出相手を削除（貸出の履歴も削除する。貸出中の本がある場合は削除できない）
+    async fn delete_borrower(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .delete_borrower(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+
+    /// 所有している本の1冊を貸し出す（貸出中の冊は不可。`lentOn` 省略時は今日）
+    async fn lend_copy(
+        &self,
+        ctx: &Context<'_>,
+        copy_id: i32,
+        borrower_id: i32,
+        due_on: Option<NaiveDate>,
+        lent_on: Option<NaiveDate>,
+    ) -> Result<LoanDto> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .lend_copy(copy_id, borrower_id, due_on, lent_on)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出中の1冊の返却を記録（`returnedOn` 省略時は今日）
+    async fn return_copy(
+        &self,
+        ctx: &Context<'_>,
+        copy_id: i32,
+        returned_on: Option<NaiveDate>,
+    ) -> Result<LoanDto> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .return_copy(copy_id, returned_on)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出中の返却期限を変更（`dueOn` 省略時は期限なしにする）
+    async fn set_loan_due_date(
+        &self,
+        ctx: &Context<'_>,
+        loan_id: i32,
+        due_on: Option<NaiveDate>,
+    ) -> Result<LoanDto> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .set_loan_due_date(loan_id, due_on)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{
+        build_book_service, build_copy_service, build_loan_service,
+    };
+    use async_graphql::{EmptySubscription, Schema};
+    use chrono::Days;
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    type TestSchema = Schema<LibraryQuery, LibraryMutation, EmptySubscription>;
+
+    /// 2冊の本とその冊、2人の貸出相手を用意する
+    async fn setup_schema() -> TestSchema {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_copy_service(handle.clone()))
+        .data(build_loan_service(handle))
+        .finish();
+
+        for mutation in [
+            r#"mutation { createBook(title: "ノルウェイの森", author: "村上春樹") { id } }"#,
+            r#"mutation { createBook(title: "Dune", author: "Frank Herbert") { id } }"#,
+            "mutation { addCopy(input: { bookId: 1, format: PAPERBACK }) { id } }",
+            "mutation { addCopy(input: { bookId: 2, format: HARDCOVER }) { id } }",
+            "mutation { addCopy(input: { bookId: 2, format: PAPERBACK }) { id } }",
+            r#"mutation { createBorrower(name: "山田太郎", email: "taro@example.com") { id } }"#,
+            r#"mutation { createBorrower(name: "佐藤花子", phone: "090-1234-5678") { id } }"#,
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+        schema
+    }
+
+    fn error_code(response: &async_graphql::Response) -> String {
+        response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"))
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_lend_and_return_copy() {
+        let schema = setup_schema().await;
+
+        let data = schema
+            .execute(
+                r#"mutation { lendCopy(copyId: 1, borrowerId: 1, lentOn: "2026-01-10", dueOn: "2026-01-31") {
+                    lentOn dueOn returnedOn borrower { name } copy { book { title } }
+                } }"#,
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["lendCopy"],
+            serde_json::json!({
+                "lentOn": "2026-01-10",
+                "dueOn": "2026-01-31",
+                "returnedOn": null,
+                "borrower": { "name": "山田太郎" },
+                "copy": { "book": { "title": "ノルウェイの森" } }
+            })
+        );
+
+        // 貸出中の冊は返却されるまで貸し出せない
+        let response = schema
+            .execute("mutation { lendCopy(copyId: 1, borrowerId: 2) { id } }")
+            .await;
+        assert_eq!(error_code(&response), r#""INVALID_STATE""#);
+
+        // 返却期限は貸出日以降・存在しない冊や相手は不可
+        let response = schema
+            .execute(r#"mutation { lendCopy(copyId: 2, borrowerId: 2, lentOn: "2026-02-10", dueOn: "2026-02-01") { id } }"#)
+            .await;
+        assert_eq!(error_code(&response), r#""VALIDATION_ERROR""#);
+        for mutation in [
+            "mutation { lendCopy(copyId: 99, borrowerId: 1) { id } }",
+            "mutation { lendCopy(copyId: 2, borrowerId: 99) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert_eq!(error_code(&response), r#""NOT_FOUND""#);
+        }
+
+        // 返却後は別の相手に貸し出せ、履歴は新しい順に残る
+        let response = schema
+            .execute(
+                r#"mutation { returnCopy(copyId: 1, returnedOn: "2026-02-03") { returnedOn } }"#,
+            )
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let response = schema
+            .execute(r#"mutation { returnCopy(copyId: 1) { id } }"#)
+            .await;
+        assert_eq!(error_code(&response), r#""INVALID_STATE""#);
+        let response = schema
+            .execute(
+                r#"mutation { lendCopy(copyId: 1, borrowerId: 2, lentOn: "2026-03-01") { id } }"#,
+            )
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        let data = schema
+            .execute(
+                "query {
+                    copy(id: 1) { currentLoan { borrower { name } } loans { lentOn returnedOn } }
+                    borrower(id: 1) { loans { copyId returnedOn } }
+                }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["copy"],
+            serde_json::json!({
+                "currentLoan": { "borrower": { "name": "佐藤花子" } },
+                "loans": [
+                    { "lentOn": "2026-03-01", "returnedOn": null },
+                    { "lentOn": "2026-01-10", "returnedOn": "2026-02-03" }
+                ]
+            })
+        );
+        assert_eq!(
+            data["borrower"]["loans"],
+            serde_json::json!([{ "copyId": 1, "returnedOn": "2026-02-03" }])
+        );
+
+        // 貸出中の本がある相手は削除できない
+        let response = schema.execute("mutation { deleteBorrower(id: 2) }").await;
+        assert_eq!(error_code(&response), r#""INVALID_STATE""#);
+        let response = schema.execute("mutation { deleteBorrower(id: 1) }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        // 連絡先の更新（null で削除）と不正なメールアドレス
+        let data = schema
+            .execute(r#"mutation { updateBorrower(id: 2, email: "hanako@example.com", phone: null) { name email phone } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["updateBorrower"],
+            serde_json::json!({ "name": "佐藤花子", "email": "hanako@example.com", "phone": null })
+        );
+        let response = schema
+            .execute(r#"mutation { updateBorrower(id: 2, email: "hanako") { id } }"#)
+            .await;
+        assert_eq!(error_code(&response), r#""VALIDATION_ERROR""#);
+    }
+
+    #[tokio::test]
+    async fn test_overdue_loans_and_reminders() {
+        let schema = setup_schema().await;
+        let today = chrono::Local::now().date_naive();
+        let in_days = |days| today.checked_add_days(Days::new(days)).unwrap();
+
+        for mutation in [
+            // 期限切れ
+            r#"mutation { lendCopy(copyId: 1, borrowerId: 1, lentOn: "2000-01-01", dueOn: "2000-01-15") { id } }"#.to_string(),
+            // 期限が近い
+            format!(
+                r#"mutation {{ lendCopy(copyId: 2, borrowerId: 2, lentOn: "2000-01-01", dueOn: "{}") {{ id }} }}"#,
+                in_days(2)
+            ),
+            // 期限まで余裕がある
+            format!(
+                r#"mutation {{ lendCopy(copyId: 3, borrowerId: 2, lentOn: "2000-01-01", dueOn: "{}") {{ id }} }}"#,
+                in_days(10)
+            ),
+        ] {
+            let response = schema.execute(mutation.as_str()).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        let data = schema
+            .execute(
+                "query {
+                    activeLoans { copyId }
+                    overdueLoans { copyId borrower { name email } }
+                    loanReminders { kind daysUntilDue loan { copyId } }
+                    later: loanReminders(withinDays: 10) { loan { copyId } }
+                }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["activeLoans"],
+            serde_json::json!([{ "copyId": 1 }, { "copyId": 2 }, { "copyId": 3 }])
+        );
+        assert_eq!(
+            data["overdueLoans"],
+            serde_json::json!([{
+                "copyId": 1,
+                "borrower": { "name": "山田太郎", "email": "taro@example.com" }
+            }])
+        );
+        let reminders = data["loanReminders"].as_array().unwrap();
+        assert_eq!(reminders.len(), 2);
+        assert_eq!(reminders[0]["kind"], "OVERDUE");
+        assert!(reminders[0]["daysUntilDue"].as_i64().unwrap() < 0);
+        assert_eq!(
+            reminders[1],
+            serde_json::json!({ "kind": "DUE_SOON", "daysUntilDue": 2, "loan": { "copyId": 2 } })
+        );
+        assert_eq!(data["later"].as_array().unwrap().len(), 3);
+
+        // 返却期限を延ばす・返却すると延滞ではなくなる
+        for mutation in [
+            format!(
+                r#"mutation {{ setLoanDueDate(loanId: 1, dueOn: "{}") {{ id }} }}"#,
+                in_days(7)
+            ),
+            "mutation { returnCopy(copyId: 2) { id } }".to_string(),
+        ] {
+            let response = schema.execute(mutation.as_str()).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+        let data = schema
+            .execute("query { overdueLoans { id } loanReminders { loan { copyId } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["overdueLoans"], serde_json::json!([]));
+        assert_eq!(data["loanReminders"], serde_json::json!([]));
+
+        // 返却済みの貸出の期限は変更できない
+        let response = schema
+            .execute("mutation { setLoanDueDate(loanId: 2) { id } }")
+            .await;
+        assert_eq!(error_code(&response), r#""INVALID_STATE""#);
+    }
+}
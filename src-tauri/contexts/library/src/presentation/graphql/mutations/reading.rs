@@ -0,0 +1,207 @@
+// Presentation Layer - Library Reading GraphQL Mutation
+
+use crate::application::{
+    dto::reading::{BookReadingStatus, LogReadingSessionInput, ReadThroughDto},
+    services::reading::ReadingService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ReadingMutation;
+
+#[Object]
+impl ReadingMutation {
+    /// 読書状態を変更（読了・中断後に変更すると再読として記録）
+    ///
+    /// `date` は読書中なら開始日、読了・中断なら終了日（省略時は今日）
+    async fn change_reading_status(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        status: BookReadingStatus,
+        date: Option<NaiveDate>,
+    ) -> Result<ReadThroughDto> {
+        let reading_service = ctx
+            .data::<Arc<ReadingService>>()
+            .map_err(|_| Error::new("ReadingService not found"))?;
+
+        reading_service
+            .change_reading_status(book_id, status, date)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 読書記録を追加
+    async fn log_reading_session(
+        &self,
+        ctx: &Context<'_>,
+        input: LogReadingSessionInput,
+    ) -> Result<ReadThroughDto> {
+        let reading_service = ctx
+            .data::<Arc<ReadingService>>()
+            .map_err(|_| Error::new("ReadingService not found"))?;
+
+        reading_service
+            .log_reading_session(input)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 通読の評価を設定（0.5〜5.0、0.5刻み。`null` で削除）
+    async fn rate_read_through(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        rating: Option<f64>,
+    ) -> Result<ReadThroughDto> {
+        let reading_service = ctx
+            .data::<Arc<ReadingService>>()
+            .map_err(|_| Error::new("ReadingService not found"))?;
+
+        reading_service
+            .rate_read_through(id, rating)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 読書記録を削除
+    async fn delete_reading_session(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let reading_service = ctx
+            .data::<Arc<ReadingService>>()
+            .map_err(|_| Error::new("ReadingService not found"))?;
+
+        reading_service
+            .delete_reading_session(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{BookQuery, LibraryMutation};
+    use crate::presentation::integration::{build_book_service, build_reading_service};
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    type TestSchema = Schema<BookQuery, LibraryMutation, EmptySubscription>;
+
+    async fn setup_schema() -> TestSchema {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let book_service = build_book_service(handle.clone());
+        book_service
+            .create_book("Dune".to_string(), None, None, None, None)
+            .await
+            .unwrap();
+
+        Schema::build(BookQuery, LibraryMutation::default(), EmptySubscription)
+            .data(book_service)
+            .data(build_reading_service(handle))
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn test_reading_lifecycle_and_reread() {
+        let schema = setup_schema().await;
+
+        let response = schema
+            .execute(
+                r#"mutation { logReadingSession(input: { bookId: 1, readOn: "2026-10-01", page: 50, durationMinutes: 40 }) { status startedOn currentPage } }"#,
+            )
+            .await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["logReadingSession"]["status"], "READING");
+        assert_eq!(data["logReadingSession"]["startedOn"], "2026-10-01");
+        assert_eq!(data["logReadingSession"]["currentPage"], 50);
+
+        // 進捗は減らせない
+        let response = schema
+            .execute(
+                r#"mutation { logReadingSession(input: { bookId: 1, readOn: "2026-10-02", page: 30 }) { id } }"#,
+            )
+            .await;
+        let code = response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"));
+        assert_eq!(code.unwrap().to_string(), r#""VALIDATION_ERROR""#);
+
+        // 開始日より前に読了にはできない
+        let response = schema
+            .execute(
+                r#"mutation { changeReadingStatus(bookId: 1, status: FINISHED, date: "2026-09-30") { id } }"#,
+            )
+            .await;
+        assert!(!response.errors.is_empty());
+
+        let response = schema
+            .execute(
+                r#"mutation { changeReadingStatus(bookId: 1, status: FINISHED, date: "2026-10-05") { status finishedOn } }"#,
+            )
+            .await;
+        assert!(response.errors.is_empty());
+
+        // 読了後の記録は再読として新しい通読になる
+        let response = schema
+            .execute(
+                r#"mutation { logReadingSession(input: { bookId: 1, readOn: "2026-10-10", percent: 10 }) { id } }"#,
+            )
+            .await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+
+        let data = schema
+            .execute(
+                "query { book(id: 1) { readingStatus readThroughs { status finishedOn sessions { id page percent } } } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["book"]["readingStatus"], "READING");
+        let read_throughs = data["book"]["readThroughs"].as_array().unwrap();
+        assert_eq!(read_throughs.len(), 2);
+        assert_eq!(read_throughs[0]["status"], "FINISHED");
+        assert_eq!(read_throughs[0]["finishedOn"], "2026-10-05");
+        assert_eq!(read_throughs[1]["sessions"][0]["percent"], 10.0);
+
+        // 読書記録の削除
+        let session_id = read_throughs[1]["sessions"][0]["id"].as_i64().unwrap();
+        let response = schema
+            .execute(&format!(
+                "mutation {{ deleteReadingSession(id: {}) }}",
+                session_id
+            ))
+            .await;
+        assert!(response.errors.is_empty());
+        let data = schema
+            .execute("query { book(id: 1) { readThroughs { sessions { id } } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert!(
+            data["book"]["readThroughs"][1]["sessions"]
+                .as_array()
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,252 @@
+// Presentation Layer - Library Review GraphQL Mutation
+
+use crate::application::{
+    dto::review::{NoteDto, ReviewDto},
+    services::review::ReviewService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ReviewMutation;
+
+#[Object]
+impl ReviewMutation {
+    /// 本の評価・感想を作成（1冊につき1件。評価は0.5〜5.0、0.5刻み）
+    async fn create_review(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        rating: Option<f64>,
+        body: Option<String>,
+    ) -> Result<ReviewDto> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .create_review(book_id, rating, body)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 評価・感想を更新（`rating: null` で評価を削除、`body: ""` で感想を削除）
+    async fn update_review(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        rating: MaybeUndefined<f64>,
+        body: Option<String>,
+    ) -> Result<ReviewDto> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .update_review(id, rating.into(), body)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 評価・感想を削除
+    async fn delete_review(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .delete_review(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+
+    /// 本にメモを追加
+    async fn create_note(&self, ctx: &Context<'_>, book_id: i32, body: String) -> Result<NoteDto> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .create_note(book_id, body)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// メモを編集
+    async fn update_note(&self, ctx: &Context<'_>, id: i32, body: String) -> Result<NoteDto> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .update_note(id, body)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// メモを削除
+    async fn delete_note(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let review_service = ctx
+            .data::<Arc<ReviewService>>()
+            .map_err(|_| Error::new("ReviewService not found"))?;
+
+        review_service
+            .delete_note(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{BookQuery, LibraryMutation};
+    use crate::presentation::integration::{
+        build_book_service, build_reading_service, build_review_service,
+    };
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    type TestSchema = Schema<BookQuery, LibraryMutation, EmptySubscription>;
+
+    async fn setup_schema() -> TestSchema {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let book_service = build_book_service(handle.clone());
+        book_service
+            .create_book("Dune".to_string(), None, None, None, None)
+            .await
+            .unwrap();
+
+        Schema::build(BookQuery, LibraryMutation::default(), EmptySubscription)
+            .data(book_service)
+            .data(build_reading_service(handle.clone()))
+            .data(build_review_service(handle))
+            .finish()
+    }
+
+    fn error_code(response: &async_graphql::Response) -> String {
+        response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"))
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_review_crud() {
+        let schema = setup_schema().await;
+
+        // 0.5刻みでない評価は不可
+        let response = schema
+            .execute("mutation { createReview(bookId: 1, rating: 4.2) { id } }")
+            .await;
+        assert_eq!(error_code(&response), r#""VALIDATION_ERROR""#);
+
+        let response = schema
+            .execute(r#"mutation { createReview(bookId: 1, rating: 4.5, body: "Spice!") { id rating body } }"#)
+            .await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        let review_id = data["createReview"]["id"].as_i64().unwrap();
+        assert_eq!(data["createReview"]["rating"], 4.5);
+
+        // 1冊につき1件
+        let response = schema
+            .execute("mutation { createReview(bookId: 1, rating: 3.0) { id } }")
+            .await;
+        assert_eq!(error_code(&response), r#""INVALID_STATE""#);
+
+        // 評価のみ削除
+        let query = format!(
+            "mutation {{ updateReview(id: {}, rating: null) {{ rating body }} }}",
+            review_id
+        );
+        let data = schema.execute(&query).await.data.into_json().unwrap();
+        assert!(data["updateReview"]["rating"].is_null());
+        assert_eq!(data["updateReview"]["body"], "Spice!");
+
+        let query = format!("mutation {{ deleteReview(id: {}) }}", review_id);
+        assert!(schema.execute(&query).await.errors.is_empty());
+        let data = schema
+            .execute("query { book(id: 1) { review { id } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert!(data["book"]["review"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_notes_crud() {
+        let schema = setup_schema().await;
+
+        for body in ["first", "second"] {
+            let query = format!(
+                r#"mutation {{ createNote(bookId: 1, body: "{}") {{ id }} }}"#,
+                body
+            );
+            assert!(schema.execute(&query).await.errors.is_empty());
+        }
+
+        let response = schema
+            .execute(r#"mutation { createNote(bookId: 1, body: "  ") { id } }"#)
+            .await;
+        assert_eq!(error_code(&response), r#""VALIDATION_ERROR""#);
+
+        let data = schema
+            .execute("query { book(id: 1) { notes { id body createdAt updatedAt } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let notes = data["book"]["notes"].as_array().unwrap();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0]["body"], "first");
+        let first_id = notes[0]["id"].as_i64().unwrap();
+
+        let query = format!(
+            r#"mutation {{ updateNote(id: {}, body: "edited") {{ body }} }}"#,
+            first_id
+        );
+        let data = schema.execute(&query).await.data.into_json().unwrap();
+        assert_eq!(data["updateNote"]["body"], "edited");
+
+        let query = format!("mutation {{ deleteNote(id: {}) }}", first_id);
+        assert!(schema.execute(&query).await.errors.is_empty());
+        let response = schema.execute(&query).await;
+        assert_eq!(error_code(&response), r#""NOT_FOUND""#);
+    }
+
+    #[tokio::test]
+    async fn test_rate_read_through() {
+        let schema = setup_schema().await;
+
+        let data = schema
+            .execute("mutation { changeReadingStatus(bookId: 1, status: READING) { id } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let read_through_id = data["changeReadingStatus"]["id"].as_i64().unwrap();
+
+        let query = format!(
+            "mutation {{ rateReadThrough(id: {}, rating: 3.5) {{ rating }} }}",
+            read_through_id
+        );
+        let data = schema.execute(&query).await.data.into_json().unwrap();
+        assert_eq!(data["rateReadThrough"]["rating"], 3.5);
+    }
+}
@@ -0,0 +1,197 @@
+// Presentation Layer - Library Series GraphQL Mutation
+
+use crate::application::{
+    dto::series::{BookSeriesDto, SeriesDto, SeriesPublicationStatus},
+    services::series::SeriesService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct SeriesMutation;
+
+#[Object]
+impl SeriesMutation {
+    /// シリーズを作成（読みはかなで指定し、一覧の並び順に使う）
+    async fn create_series(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        reading: Option<String>,
+        #[graphql(default_with = "SeriesPublicationStatus::Ongoing")]
+        status: SeriesPublicationStatus,
+        volume_count: Option<i32>,
+    ) -> Result<SeriesDto> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .create_series(name, reading, status, volume_count)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// シリーズを更新（`reading: ""` で読みを削除、`volumeCount: null` で巻数を削除）
+    async fn update_series(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        name: Option<String>,
+        reading: Option<String>,
+        status: Option<SeriesPublicationStatus>,
+        volume_count: MaybeUndefined<i32>,
+    ) -> Result<SeriesDto> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .update_series(id, name, reading, status, volume_count.into())
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// シリーズを削除（本は削除せず、シリーズから外す）
+    async fn delete_series(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .delete_series(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+
+    /// 本をシリーズの巻として登録（巻数は 1・1.5・2 など。別のシリーズに属していた場合は移す）
+    async fn set_book_series(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        series_id: i32,
+        volume: f64,
+    ) -> Result<BookSeriesDto> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .set_book_series(book_id, series_id, volume)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本をシリーズから外す
+    async fn remove_book_from_series(&self, ctx: &Context<'_>, book_id: i32) -> Result<bool> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .remove_book_from_series(book_id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{build_book_service, build_series_service};
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_series_volume_order_and_missing_volumes() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_series_service(handle))
+        .finish();
+
+        for mutation in [
+            r#"mutation { createSeries(name: "葬送のフリーレン", reading: "ソウソウノフリーレン") { id } }"#,
+            r#"mutation { createSeries(name: "ダンジョン飯", reading: "だんじょんめし", status: COMPLETED, volumeCount: 14) { id } }"#,
+            r#"mutation { createBook(title: "葬送のフリーレン 4") { id } }"#,
+            r#"mutation { createBook(title: "葬送のフリーレン 1") { id } }"#,
+            r#"mutation { createBook(title: "葬送のフリーレン 公式ファンブック") { id } }"#,
+            "mutation { setBookSeries(bookId: 1, seriesId: 1, volume: 4) { volume } }",
+            "mutation { setBookSeries(bookId: 2, seriesId: 1, volume: 1) { volume } }",
+            "mutation { setBookSeries(bookId: 3, seriesId: 1, volume: 1.5) { volume } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        let data = schema
+            .execute(
+                "query {
+                    allSeries { name sortKey }
+                    series(id: 1) { volumes { volume book { id } } missingVolumes }
+                    book(id: 3) { series { series { name } volume } }
+                }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        // 読みの順（カタカナ・ひらがなの違いは問わない）
+        assert_eq!(data["allSeries"][0]["name"], "葬送のフリーレン");
+        assert_eq!(data["allSeries"][0]["sortKey"], "そうそうのふりーれん");
+        assert_eq!(
+            data["series"]["volumes"],
+            serde_json::json!([
+                { "volume": 1.0, "book": { "id": 2 } },
+                { "volume": 1.5, "book": { "id": 3 } },
+                { "volume": 4.0, "book": { "id": 1 } }
+            ])
+        );
+        assert_eq!(data["series"]["missingVolumes"], serde_json::json!([2, 3]));
+        assert_eq!(data["book"]["series"]["series"]["name"], "葬送のフリーレン");
+        assert_eq!(data["book"]["series"]["volume"], 1.5);
+
+        // 完結したシリーズは刊行済みの巻数まで
+        let data = schema
+            .execute("query { series(id: 2) { missingVolumes } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["series"]["missingVolumes"].as_array().unwrap().len(),
+            14
+        );
+
+        // 同じ名前・不正な巻数は不可
+        for mutation in [
+            r#"mutation { createSeries(name: "ダンジョン飯") { id } }"#,
+            "mutation { setBookSeries(bookId: 1, seriesId: 1, volume: -1) { volume } }",
+        ] {
+            assert!(!schema.execute(mutation).await.errors.is_empty());
+        }
+
+        // シリーズを削除しても本は残る
+        schema.execute("mutation { deleteSeries(id: 1) }").await;
+        let data = schema
+            .execute("query { book(id: 3) { title series { volume } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["book"]["series"], serde_json::Value::Null);
+    }
+}
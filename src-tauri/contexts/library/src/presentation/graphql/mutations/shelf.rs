@@ -0,0 +1,251 @@
+// Presentation Layer - Library Shelf GraphQL Mutation
+
+use crate::application::{
+    dto::shelf::{ShelfDto, SmartShelfFilterInput},
+    services::shelf::ShelfService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ShelfMutation;
+
+#[Object]
+impl ShelfMutation {
+    /// 本棚を作成（`smartFilter` を指定すると条件に一致する本を並べるスマート本棚になる）
+    async fn create_shelf(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        smart_filter: Option<SmartShelfFilterInput>,
+    ) -> Result<ShelfDto> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .create_shelf(name, smart_filter)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本棚を更新（`smartFilter` はスマート本棚のみ変更できる）
+    async fn update_shelf(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        name: Option<String>,
+        smart_filter: Option<SmartShelfFilterInput>,
+    ) -> Result<ShelfDto> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .update_shelf(id, name, smart_filter)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本棚を削除（本は削除しない）
+    async fn delete_shelf(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .delete_shelf(id)
+            .await
+            .map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+
+    /// 本棚の表示順を並べ替える（すべての本棚のIDを新しい順に指定する）
+    async fn reorder_shelves(&self, ctx: &Context<'_>, ids: Vec<i32>) -> Result<Vec<ShelfDto>> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .reorder_shelves(ids)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 手動の本棚の末尾に本を追加する（並べている場合は何もしない）
+    async fn add_book_to_shelf(
+        &self,
+        ctx: &Context<'_>,
+        shelf_id: i32,
+        book_id: i32,
+    ) -> Result<ShelfDto> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .add_book_to_shelf(shelf_id, book_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 手動の本棚から本を外す
+    async fn remove_book_from_shelf(
+        &self,
+        ctx: &Context<'_>,
+        shelf_id: i32,
+        book_id: i32,
+    ) -> Result<ShelfDto> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .remove_book_from_shelf(shelf_id, book_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 手動の本棚の本を並べ替える（並べているすべての本のIDを新しい順に指定する）
+    async fn reorder_shelf_books(
+        &self,
+        ctx: &Context<'_>,
+        shelf_id: i32,
+        book_ids: Vec<i32>,
+    ) -> Result<ShelfDto> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service
+            .reorder_shelf_books(shelf_id, book_ids)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{
+        build_book_service, build_shelf_service, build_tag_service,
+    };
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_manual_and_smart_shelves() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_tag_service(handle.clone()))
+        .data(build_shelf_service(handle))
+        .finish();
+
+        for mutation in [
+            r#"mutation { createBook(title: "Dune", publishedYear: 1965) { id } }"#,
+            r#"mutation { createBook(title: "Emma", publishedYear: 1815) { id } }"#,
+            r#"mutation { createBook(title: "Hyperion", publishedYear: 1989) { id } }"#,
+            r#"mutation { tagBook(bookId: 1, name: "SF/Classic") { name } }"#,
+            r#"mutation { tagBook(bookId: 3, name: "SF") { name } }"#,
+            r#"mutation { createShelf(name: "To read") { id } }"#,
+            r#"mutation { createShelf(name: "Modern SF", smartFilter: { tag: "SF", publishedYearFrom: 1980 }) { id } }"#,
+            "mutation { addBookToShelf(shelfId: 1, bookId: 3) { id } }",
+            "mutation { addBookToShelf(shelfId: 1, bookId: 2) { id } }",
+            "mutation { addBookToShelf(shelfId: 1, bookId: 1) { id } }",
+            "mutation { reorderShelfBooks(shelfId: 1, bookIds: [1, 3, 2]) { id } }",
+            "mutation { reorderShelves(ids: [2, 1]) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        let data = schema
+            .execute(
+                "{ shelves { name position smart smartFilter { tag publishedYearFrom } books { title } } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["shelves"],
+            serde_json::json!([
+                {
+                    "name": "Modern SF",
+                    "position": 0,
+                    "smart": true,
+                    "smartFilter": { "tag": "SF", "publishedYearFrom": 1980 },
+                    "books": [{ "title": "Hyperion" }]
+                },
+                {
+                    "name": "To read",
+                    "position": 1,
+                    "smart": false,
+                    "smartFilter": null,
+                    "books": [{ "title": "Dune" }, { "title": "Hyperion" }, { "title": "Emma" }]
+                }
+            ])
+        );
+
+        // books の絞り込みは本棚の条件と他の条件を組み合わせられる
+        let data = schema
+            .execute(
+                r#"{
+                    manual: books(shelf: 1, filter: { publishedYearTo: 1970 }) { totalCount }
+                    smart: books(shelf: 2, sort: { field: TITLE }) { edges { node { title shelves { name } } } }
+                }"#,
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["manual"]["totalCount"], 2);
+        assert_eq!(
+            data["smart"]["edges"],
+            serde_json::json!([{ "node": { "title": "Hyperion", "shelves": [{ "name": "To read" }] } }])
+        );
+
+        // スマート本棚には本を並べられず、並べ替えはすべての本を指定する
+        for mutation in [
+            "mutation { addBookToShelf(shelfId: 2, bookId: 1) { id } }",
+            "mutation { reorderShelfBooks(shelfId: 1, bookIds: [1, 3]) { id } }",
+            r#"mutation { updateShelf(id: 1, smartFilter: { tag: "SF" }) { id } }"#,
+            r#"mutation { createShelf(name: "To read") { id } }"#,
+            "{ books(shelf: 99) { totalCount } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert_eq!(response.errors.len(), 1, "{}", mutation);
+        }
+
+        // ゴミ箱にある本は指定せずに並べ替え、復元すると末尾に並ぶ
+        for mutation in [
+            "mutation { deleteBook(id: 3) }",
+            "mutation { reorderShelfBooks(shelfId: 1, bookIds: [2, 1]) { id } }",
+            "mutation { restoreBook(id: 3) { id } }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+        let data = schema
+            .execute("{ shelf(id: 1) { books { title } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["shelf"]["books"],
+            serde_json::json!([{ "title": "Emma" }, { "title": "Dune" }, { "title": "Hyperion" }])
+        );
+    }
+}
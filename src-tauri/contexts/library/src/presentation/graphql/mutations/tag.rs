@@ -0,0 +1,187 @@
+// Presentation Layer - Library Tag GraphQL Mutation
+
+use crate::application::{dto::tag::TagDto, services::tag::TagService};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct TagMutation;
+
+#[Object]
+impl TagMutation {
+    /// タグを作成（`親/子` 形式で階層を指定し、祖先のタグがなければ作成する）
+    async fn create_tag(&self, ctx: &Context<'_>, name: String) -> Result<TagDto> {
+        let tag_service = ctx
+            .data::<Arc<TagService>>()
+            .map_err(|_| Error::new("TagService not found"))?;
+
+        tag_service.create_tag(name).await.map_err(to_graphql_error)
+    }
+
+    /// タグの名前を変更（子孫のタグも新しい名前の下に移す）
+    async fn rename_tag(&self, ctx: &Context<'_>, id: i32, name: String) -> Result<TagDto> {
+        let tag_service = ctx
+            .data::<Arc<TagService>>()
+            .map_err(|_| Error::new("TagService not found"))?;
+
+        tag_service
+            .rename_tag(id, name)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// タグを削除（子孫のタグも削除し、本からも外す）
+    async fn delete_tag(&self, ctx: &Context<'_>, id: i32) -> Result<bool> {
+        let tag_service = ctx
+            .data::<Arc<TagService>>()
+            .map_err(|_| Error::new("TagService not found"))?;
+
+        tag_service.delete_tag(id).await.map_err(to_graphql_error)?;
+
+        Ok(true)
+    }
+
+    /// 本にタグを付ける（タグがなければ作成する）。付けた後の本のタグを返す
+    async fn tag_book(&self, ctx: &Context<'_>, book_id: i32, name: String) -> Result<Vec<TagDto>> {
+        let tag_service = ctx
+            .data::<Arc<TagService>>()
+            .map_err(|_| Error::new("TagService not found"))?;
+
+        tag_service
+            .tag_book(book_id, name)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本からタグを外す。外した後の本のタグを返す
+    async fn untag_book(
+        &self,
+        ctx: &Context<'_>,
+        book_id: i32,
+        tag_id: i32,
+    ) -> Result<Vec<TagDto>> {
+        let tag_service = ctx
+            .data::<Arc<TagService>>()
+            .map_err(|_| Error::new("TagService not found"))?;
+
+        tag_service
+            .untag_book(book_id, tag_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{build_book_service, build_tag_service};
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_tag_hierarchy_filter_rename_and_delete() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_tag_service(handle))
+        .finish();
+
+        for mutation in [
+            r#"mutation { createBook(title: "Dune") { id } }"#,
+            r#"mutation { createBook(title: "Emma") { id } }"#,
+            r#"mutation { createBook(title: "Cosmos") { id } }"#,
+            r#"mutation { tagBook(bookId: 1, name: " Fiction / SF ") { name } }"#,
+            r#"mutation { tagBook(bookId: 2, name: "Fiction") { name } }"#,
+            r#"mutation { tagBook(bookId: 3, name: "Fiction SF") { name } }"#,
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        // 祖先のタグも作成され、親の直後に子が並ぶ
+        let data = schema
+            .execute("{ tags { id name label parent depth } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let names: Vec<&str> = data["tags"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Fiction", "Fiction/SF", "Fiction SF"]);
+        assert_eq!(data["tags"][1]["label"], "SF");
+        assert_eq!(data["tags"][1]["parent"], "Fiction");
+        assert_eq!(data["tags"][1]["depth"], 1);
+
+        // 親のタグで絞り込むと子孫のタグが付いた本も含む
+        let titles = |data: serde_json::Value| -> Vec<String> {
+            data["books"]["edges"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|e| e["node"]["title"].as_str().unwrap().to_string())
+                .collect()
+        };
+        let data = schema
+            .execute(r#"{ books(tag: "Fiction") { edges { node { title tags { name } } } } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(titles(data.clone()), vec!["Dune", "Emma"]);
+        assert_eq!(
+            data["books"]["edges"][0]["node"]["tags"][0]["name"],
+            "Fiction/SF"
+        );
+
+        // 名前の変更で子孫のタグも移り、既存の名前とは衝突できない
+        let response = schema
+            .execute(r#"mutation { renameTag(id: 1, name: "Fiction SF") { name } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        let response = schema
+            .execute(r#"mutation { renameTag(id: 1, name: "Fiction/Novels") { name } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        let response = schema
+            .execute(r#"mutation { renameTag(id: 1, name: "Books/Novels") { name } }"#)
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = schema
+            .execute(r#"{ books(tag: "Books") { edges { node { title } } } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(titles(data), vec!["Dune", "Emma"]);
+
+        // 削除すると子孫のタグも削除され、本からも外れる
+        let response = schema.execute("mutation { deleteTag(id: 1) }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = schema
+            .execute("{ tags { name } book(id: 1) { tags { name } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data,
+            serde_json::json!({
+                "tags": [{ "name": "Books" }, { "name": "Fiction SF" }],
+                "book": { "tags": [] }
+            })
+        );
+    }
+}
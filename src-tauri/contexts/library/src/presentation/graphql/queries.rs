@@ -1,3 +1,11 @@
 // Presentation Layer - Library Queries
 
 pub mod book;
+pub mod catalog;
+pub mod copy;
+pub mod duplicate;
+pub mod ebook;
+pub mod loan;
+pub mod series;
+pub mod shelf;
+pub mod tag;
@@ -1,22 +1,130 @@
 // Presentation Layer - Library Book GraphQL Query
 
-use crate::application::{dto::book::BookDto, services::book::BookService};
+use crate::application::{
+    dto::{
+        author::AuthorDto,
+        book::{BookDto, BookFilterInput, BookSearchResultDto, BookSortInput},
+        classification::{BookClassDto, ClassificationSystem},
+        history::BookChangeDto,
+    },
+    services::book::BookService,
+};
 use crate::presentation::graphql::to_graphql_error;
+use async_graphql::connection::{
+    self, Connection, ConnectionNameType, Edge, EdgeNameType, EmptyFields,
+};
 use async_graphql::*;
 use std::sync::Arc;
 
+/// `first` / `limit` 省略時の取得件数
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// 本一覧のコネクションに付与する追加フィールド
+#[derive(SimpleObject)]
+pub struct BookConnectionFields {
+    /// 条件に一致する本の総件数
+    total_count: u64,
+}
+
+/// コネクション型のGraphQL名（BookConnection）
+pub struct BookConnectionName;
+
+impl ConnectionNameType for BookConnectionName {
+    fn type_name<T: OutputType>() -> String {
+        "BookConnection".to_string()
+    }
+}
+
+/// エッジ型のGraphQL名（BookEdge）
+pub struct BookEdgeName;
+
+impl EdgeNameType for BookEdgeName {
+    fn type_name<T: OutputType>() -> String {
+        "BookEdge".to_string()
+    }
+}
+
+/// 本一覧のRelay形式コネクション（カーソルは先頭からの位置）
+pub type BookConnection =
+    Connection<usize, BookDto, BookConnectionFields, EmptyFields, BookConnectionName, BookEdgeName>;
+
 #[derive(Default)]
 pub struct BookQuery;
 
 #[Object]
 impl BookQuery {
-    /// すべての本を取得
-    async fn books(&self, ctx: &Context<'_>) -> Result<Vec<BookDto>> {
+    /// 本の一覧を取得（絞り込み・並び替え・ページング対応）
+    ///
+    /// `tag` はタグ名（子孫のタグが付いた本も含む）、`shelf` は本棚のID（スマート本棚は条件に一致する本）で絞り込む。
+    #[allow(clippy::too_many_arguments)]
+    async fn books(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        filter: Option<BookFilterInput>,
+        tag: Option<String>,
+        shelf: Option<i32>,
+        sort: Option<BookSortInput>,
+    ) -> Result<BookConnection> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        connection::query(
+            after,
+            None,
+            first,
+            None,
+            |after: Option<usize>, _before: Option<usize>, first, _last| async move {
+                let offset = after.map_or(0, |cursor| cursor + 1);
+                let page = book_service
+                    .get_books_page(
+                        filter.unwrap_or_default(),
+                        tag,
+                        shelf,
+                        sort.unwrap_or_default(),
+                        offset,
+                        first.unwrap_or(DEFAULT_PAGE_SIZE),
+                    )
+                    .await
+                    .map_err(to_graphql_error)?;
+
+                let mut connection = BookConnection::with_additional_fields(
+                    offset > 0,
+                    page.has_next_page,
+                    BookConnectionFields {
+                        total_count: page.total_count,
+                    },
+                );
+                connection.edges.extend(
+                    page.books
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, book)| Edge::new(offset + index, book)),
+                );
+
+                Ok::<_, Error>(connection)
+            },
+        )
+        .await
+    }
+
+    /// タイトル・著者・説明を全文検索（語の途中にも一致する。`"..."` で空白を含む連続した文字列として検索）
+    async fn search_books(
+        &self,
+        ctx: &Context<'_>,
+        query: String,
+        limit: Option<usize>,
+    ) -> Result<Vec<BookSearchResultDto>> {
         let book_service = ctx
             .data::<Arc<BookService>>()
             .map_err(|_| Error::new("BookService not found"))?;
 
-        book_service.get_all_books().await.map_err(to_graphql_error)
+        book_service
+            .search_books(query, limit.unwrap_or(DEFAULT_PAGE_SIZE))
+            .await
+            .map_err(to_graphql_error)
     }
 
     /// IDで本を取得
@@ -27,4 +135,881 @@ impl BookQuery {
 
         book_service.get_book(id).await.map_err(to_graphql_error)
     }
+
+    /// ISBN（ISBN-10 / ISBN-13、ハイフン有無は問わない）で本を取得
+    async fn book_by_isbn(&self, ctx: &Context<'_>, isbn: String) -> Result<Option<BookDto>> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .get_book_by_isbn(isbn)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// ゴミ箱にある本をゴミ箱に移した日時の新しい順に取得
+    async fn trashed_books(&self, ctx: &Context<'_>) -> Result<Vec<BookDto>> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .get_trashed_books()
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本の変更履歴を新しい順に取得（項目ごとの変更前後の値付き）
+    async fn book_history(&self, ctx: &Context<'_>, id: i32) -> Result<Vec<BookChangeDto>> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .get_book_history(id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 分類ごとの本の数を分類記号の順に取得（分類から本を探す）
+    ///
+    /// `parent` を省略すると類ごと、類（1桁）・綱（2桁）を指定するとその下の階層ごとに、本のない分類も含めて返す。
+    /// 目（3桁）以下を指定すると、本に付けた分類記号ごとに返す。各分類の本は `books` の `filter.ndc` / `filter.ddc` で取得できる。
+    async fn book_classes(
+        &self,
+        ctx: &Context<'_>,
+        system: ClassificationSystem,
+        parent: Option<String>,
+    ) -> Result<Vec<BookClassDto>> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service
+            .get_book_classes(system, parent)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// IDで著者を取得（その人物が関わっている本の一覧付き）
+    async fn author(&self, ctx: &Context<'_>, id: i32) -> Result<Option<AuthorDto>> {
+        let book_service = ctx
+            .data::<Arc<BookService>>()
+            .map_err(|_| Error::new("BookService not found"))?;
+
+        book_service.get_author(id).await.map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::mutations::book::BookMutation;
+    use crate::presentation::integration::build_book_service;
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    type TestSchema = Schema<BookQuery, BookMutation, EmptySubscription>;
+
+    async fn setup_schema() -> TestSchema {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let service = build_book_service(DatabaseHandle::new(db));
+
+        for (title, author, year) in [
+            ("Dune", "Frank Herbert", 1965),
+            ("Children of Dune", "Frank Herbert", 1976),
+            ("Neuromancer", "William Gibson", 1984),
+            ("Foundation", "Isaac Asimov", 1951),
+            ("Dune Messiah", "Frank Herbert", 1969),
+        ] {
+            service
+                .create_book(
+                    title.to_string(),
+                    Some(author.to_string()),
+                    None,
+                    Some(year),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        Schema::build(BookQuery, BookMutation, EmptySubscription)
+            .data(service)
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn test_books_pagination() {
+        let schema = setup_schema().await;
+
+        let query = r#"
+            query {
+                books(first: 2) {
+                    totalCount
+                    pageInfo { hasNextPage hasPreviousPage endCursor }
+                    nodes { title }
+                }
+            }
+        "#;
+        let response = schema.execute(query).await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["books"]["totalCount"], 5);
+        assert_eq!(data["books"]["nodes"].as_array().unwrap().len(), 2);
+        assert_eq!(data["books"]["pageInfo"]["hasNextPage"], true);
+        assert_eq!(data["books"]["pageInfo"]["hasPreviousPage"], false);
+
+        // 2ページ目以降を取得
+        let end_cursor = data["books"]["pageInfo"]["endCursor"].as_str().unwrap();
+        let query = format!(
+            r#"
+            query {{
+                books(first: 10, after: "{}") {{
+                    pageInfo {{ hasNextPage hasPreviousPage }}
+                    nodes {{ title }}
+                }}
+            }}
+            "#,
+            end_cursor
+        );
+        let response = schema.execute(&query).await;
+        assert!(response.errors.is_empty());
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["books"]["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(data["books"]["pageInfo"]["hasNextPage"], false);
+        assert_eq!(data["books"]["pageInfo"]["hasPreviousPage"], true);
+    }
+
+    #[tokio::test]
+    async fn test_books_filter_and_sort() {
+        let schema = setup_schema().await;
+
+        let query = r#"
+            query {
+                books(
+                    filter: { author: "Herbert", titleContains: "Dune", publishedYearTo: 1970 }
+                    sort: { field: PUBLISHED_YEAR, direction: DESC }
+                ) {
+                    totalCount
+                    nodes { title publishedYear }
+                }
+            }
+        "#;
+        let response = schema.execute(query).await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["books"]["totalCount"], 2);
+        assert_eq!(data["books"]["nodes"][0]["title"], "Dune Messiah");
+        assert_eq!(data["books"]["nodes"][1]["title"], "Dune");
+    }
+
+    #[tokio::test]
+    async fn test_books_sort_and_filter_by_timestamps() {
+        let schema = setup_schema().await;
+
+        let data = schema
+            .execute(r#"mutation { updateBook(id: 4, title: "Foundation (Revised)", expectedVersion: 1) { createdAt updatedAt } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let created_at = data["updateBook"]["createdAt"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let updated_at = data["updateBook"]["updatedAt"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        assert_ne!(created_at, updated_at);
+
+        // 最近編集した本が先頭になる
+        let data = schema
+            .execute("query { books(first: 2, sort: { field: UPDATED_AT, direction: DESC }) { nodes { id } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["books"]["nodes"][0]["id"], 4);
+        let data = schema
+            .execute("query { books(first: 1, sort: { field: CREATED_AT, direction: DESC }) { nodes { id } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["books"]["nodes"][0]["id"], 5);
+
+        let query = format!(
+            r#"query {{ books(filter: {{ updatedAtFrom: "{}" }}) {{ totalCount nodes {{ id }} }} }}"#,
+            updated_at
+        );
+        let data = schema.execute(query).await.data.into_json().unwrap();
+        assert_eq!(data["books"]["totalCount"], 1);
+        assert_eq!(data["books"]["nodes"][0]["id"], 4);
+
+        let query = format!(
+            r#"query {{ books(filter: {{ createdAtFrom: "{}", createdAtTo: "{}" }}) {{ totalCount }} }}"#,
+            updated_at, created_at
+        );
+        let response = schema.execute(query).await;
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_books_sort_by_reading() {
+        let schema = setup_schema().await;
+
+        for title in ["吾輩は猫である", "坊っちゃん", "The Left Hand of Darkness"] {
+            let query = format!(r#"mutation {{ createBook(title: "{}") {{ id }} }}"#, title);
+            let response = schema.execute(query).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+        for (id, reading) in [(6, "ワガハイハネコデアル"), (7, "ぼっちゃん")] {
+            let query = format!(
+                r#"mutation {{ updateBookReadings(id: {}, titleReading: "{}", expectedVersion: 1) {{ titleSortKey }} }}"#,
+                id, reading
+            );
+            let response = schema.execute(query).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        // 漢字のタイトルは読みの順、英語のタイトルは先頭の冠詞を除いた順に並ぶ
+        let data = schema
+            .execute("query { books(sort: { field: TITLE }) { nodes { title titleSortKey } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let titles: Vec<&str> = data["books"]["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|node| node["title"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Children of Dune",
+                "Dune",
+                "Dune Messiah",
+                "Foundation",
+                "The Left Hand of Darkness",
+                "Neuromancer",
+                "坊っちゃん",
+                "吾輩は猫である",
+            ]
+        );
+        assert_eq!(
+            data["books"]["nodes"][7]["titleSortKey"],
+            "わがはいはねこである"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_books() {
+        let schema = setup_schema().await;
+
+        // 語の途中にも一致する
+        let response = schema
+            .execute(
+                r#"query { searchBooks(query: "romancer") { book { title } titleHighlight score } }"#,
+            )
+            .await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["searchBooks"].as_array().unwrap().len(), 1);
+        assert_eq!(data["searchBooks"][0]["book"]["title"], "Neuromancer");
+        assert_eq!(
+            data["searchBooks"][0]["titleHighlight"],
+            "Neu<mark>romancer</mark>"
+        );
+        assert!(data["searchBooks"][0]["score"].as_f64().unwrap() > 0.0);
+
+        // フレーズ（タイトルの一致が著者の一致より上位）
+        let response = schema
+            .execute(r#"query { searchBooks(query: "\"frank herbert\" dune", limit: 2) { book { title } authorHighlight } }"#)
+            .await;
+        assert!(response.errors.is_empty());
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["searchBooks"].as_array().unwrap().len(), 2);
+        assert_eq!(data["searchBooks"][0]["book"]["title"], "Dune");
+        assert_eq!(
+            data["searchBooks"][0]["authorHighlight"],
+            "<mark>Frank Herbert</mark>"
+        );
+
+        // フレーズは語順どおりに連続している場合だけ一致する
+        let data = schema
+            .execute(r#"query { searchBooks(query: "\"herbert frank\"") { book { id } } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["searchBooks"], serde_json::json!([]));
+
+        // 更新が索引に反映される
+        schema
+            .execute(
+                r#"mutation { updateBook(id: 3, title: "Count Zero", expectedVersion: 1) { id } }"#,
+            )
+            .await;
+        let response = schema
+            .execute(r#"query { searchBooks(query: "neuromancer") { book { id } } }"#)
+            .await;
+        let data = response.data.into_json().unwrap();
+        assert!(data["searchBooks"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_books_in_japanese() {
+        let schema = setup_schema().await;
+        schema
+            .execute(
+                r#"mutation { createBook(title: "ノルウェイの森", author: "村上春樹", description: "<b>恋愛</b>小説 & 青春") { id } }"#,
+            )
+            .await;
+
+        // 空白で区切らない語の途中にも一致し、2文字以下の語も検索できる
+        for (query, title, author) in [
+            ("村上", "ノルウェイの森", "<mark>村上</mark>春樹"),
+            ("ウェイの", "ノル<mark>ウェイの</mark>森", "村上春樹"),
+            (
+                "森 春樹",
+                "ノルウェイの<mark>森</mark>",
+                "村上<mark>春樹</mark>",
+            ),
+        ] {
+            let response = schema
+                .execute(format!(
+                    r#"query {{ searchBooks(query: "{}") {{ titleHighlight authorHighlight }} }}"#,
+                    query
+                ))
+                .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            let data = response.data.into_json().unwrap();
+            assert_eq!(
+                data["searchBooks"],
+                serde_json::json!([{ "titleHighlight": title, "authorHighlight": author }]),
+                "{}",
+                query
+            );
+        }
+
+        // 強調表示は本文をHTMLエスケープしてから一致箇所を囲む
+        let data = schema
+            .execute(r#"query { searchBooks(query: "恋愛") { descriptionSnippet } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["searchBooks"][0]["descriptionSnippet"],
+            "&lt;b&gt;<mark>恋愛</mark>&lt;/b&gt;小説 &amp; 青春"
+        );
+
+        // 発音区別符号を無視して一致した箇所も強調する
+        schema
+            .execute(r#"mutation { createBook(title: "Café Société") { id } }"#)
+            .await;
+        let data = schema
+            .execute(r#"query { searchBooks(query: "cafe societe") { titleHighlight } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["searchBooks"],
+            serde_json::json!([{ "titleHighlight": "<mark>Café</mark> <mark>Société</mark>" }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_book_detects_conflict() {
+        let schema = setup_schema().await;
+
+        let data = schema
+            .execute("query { book(id: 1) { version } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["book"]["version"], 1);
+
+        let data = schema
+            .execute(r#"mutation { updateBook(id: 1, title: "Dune I", expectedVersion: 1) { version } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["updateBook"]["version"], 2);
+
+        // 古い版を元にした編集は反映しない
+        let response = schema
+            .execute(
+                r#"mutation { updateBook(id: 1, title: "Dune II", expectedVersion: 1) { id } }"#,
+            )
+            .await;
+        let code = response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"));
+        assert_eq!(code.unwrap().to_string(), r#""CONFLICT""#);
+
+        // 版を指定しない編集は受け付けない
+        let response = schema
+            .execute(r#"mutation { updateBook(id: 1, title: "Dune III") { id } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+
+        let data = schema
+            .execute("query { book(id: 1) { title version } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["book"]["title"], "Dune I");
+        assert_eq!(data["book"]["version"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_browse_books_by_class() {
+        let schema = setup_schema().await;
+
+        for (id, ndc, ddc) in [
+            (1, "933.7", "813.54"),
+            (2, "933.7", ""),
+            (3, "９３３", "813/.54"),
+            (5, "913.6", ""),
+        ] {
+            let query = format!(
+                r#"mutation {{ updateBookClassification(id: {}, ndc: "{}", ddc: "{}", expectedVersion: 1) {{ id }} }}"#,
+                id, ndc, ddc
+            );
+            let response = schema.execute(query).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        let response = schema
+            .execute(r#"mutation { updateBookClassification(id: 4, ndc: "93", expectedVersion: 1) { id } }"#)
+            .await;
+        let code = response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"));
+        assert_eq!(code.unwrap().to_string(), r#""VALIDATION_ERROR""#);
+
+        let data = schema
+            .execute("query { book(id: 3) { ndc ndcName ddc ddcName } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["book"]["ndc"], "933");
+        assert_eq!(data["book"]["ndcName"], "小説．物語");
+        assert_eq!(data["book"]["ddc"], "813.54");
+        assert_eq!(data["book"]["ddcName"], "American fiction in English");
+
+        // 類ごと（本のない類も含む）
+        let data = schema
+            .execute("query { bookClasses(system: NDC) { code name bookCount } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let classes = data["bookClasses"].as_array().unwrap();
+        assert_eq!(classes.len(), 10);
+        assert_eq!(classes[9]["name"], "文学");
+        assert_eq!(classes[9]["bookCount"], 4);
+        assert_eq!(classes[0]["bookCount"], 0);
+
+        let data = schema
+            .execute(r#"query { bookClasses(system: NDC, parent: "93") { code bookCount } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let counts: Vec<(&str, u64)> = data["bookClasses"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|class| class["bookCount"].as_u64().unwrap() > 0)
+            .map(|class| {
+                (
+                    class["code"].as_str().unwrap(),
+                    class["bookCount"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(counts, vec![("933", 3)]);
+
+        // 目の下は本に付けた分類記号ごと
+        let data = schema
+            .execute(r#"query { bookClasses(system: NDC, parent: "933") { code name bookCount } }"#)
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["bookClasses"],
+            serde_json::json!([
+                { "code": "933", "name": "小説．物語", "bookCount": 1 },
+                { "code": "933.7", "name": "小説．物語", "bookCount": 2 },
+            ])
+        );
+
+        let data = schema
+            .execute(
+                r#"query { books(filter: { ddc: "81" }, sort: { field: TITLE }) { totalCount nodes { title } } }"#,
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["books"]["totalCount"], 2);
+        assert_eq!(data["books"]["nodes"][0]["title"], "Dune");
+        assert_eq!(data["books"]["nodes"][1]["title"], "Neuromancer");
+
+        let response = schema
+            .execute(r#"query { books(filter: { ndc: "9a" }) { totalCount } }"#)
+            .await;
+        assert!(!response.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_book_by_isbn() {
+        let schema = setup_schema().await;
+
+        let response = schema
+            .execute(r#"mutation { updateBook(id: 1, isbn: "0-441-17271-7", expectedVersion: 1) { isbn isbn10 } }"#)
+            .await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["updateBook"]["isbn"], "9780441172719");
+        assert_eq!(data["updateBook"]["isbn10"], "0441172717");
+
+        // ISBN-10 / ISBN-13 のどちらでも検索できる
+        for isbn in ["978-0-441-17271-9", "0441172717"] {
+            let query = format!(r#"query {{ bookByIsbn(isbn: "{}") {{ title }} }}"#, isbn);
+            let data = schema.execute(&query).await.data.into_json().unwrap();
+            assert_eq!(data["bookByIsbn"]["title"], "Dune");
+        }
+
+        // 同じISBNは別の本に登録できない
+        let response = schema
+            .execute(
+                r#"mutation { createBook(title: "Dune (copy)", isbn: "9780441172719") { id } }"#,
+            )
+            .await;
+        let code = response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"));
+        assert_eq!(code.unwrap().to_string(), r#""VALIDATION_ERROR""#);
+    }
+
+    #[tokio::test]
+    async fn test_author_books() {
+        let schema = setup_schema().await;
+
+        // 作成時の著者名から著者が登録される
+        let data = schema
+            .execute("query { book(id: 1) { authors { id name role } } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let herbert_id = data["book"]["authors"][0]["id"].as_i64().unwrap();
+        assert_eq!(data["book"]["authors"][0]["name"], "Frank Herbert");
+        assert_eq!(data["book"]["authors"][0]["role"], "AUTHOR");
+
+        // 共著・翻訳者を設定
+        let mutation = format!(
+            r#"
+            mutation {{
+                setBookAuthors(bookId: 3, authors: [
+                    {{ name: "William Gibson" }}
+                    {{ authorId: {} }}
+                    {{ name: "黒丸 尚", role: TRANSLATOR }}
+                ]) {{
+                    author
+                    authors {{ name role }}
+                }}
+            }}
+            "#,
+            herbert_id
+        );
+        let response = schema.execute(&mutation).await;
+        assert!(
+            response.errors.is_empty(),
+            "GraphQL errors: {:?}",
+            response.errors
+        );
+        let data = response.data.into_json().unwrap();
+        assert_eq!(
+            data["setBookAuthors"]["author"],
+            "William Gibson, Frank Herbert"
+        );
+        assert_eq!(data["setBookAuthors"]["authors"][2]["role"], "TRANSLATOR");
+
+        // 著者の本の一覧（出版年順）
+        let query = format!(
+            "query {{ author(id: {}) {{ name books {{ title }} }} }}",
+            herbert_id
+        );
+        let data = schema.execute(&query).await.data.into_json().unwrap();
+        let titles: Vec<&str> = data["author"]["books"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["title"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            titles,
+            ["Dune", "Dune Messiah", "Children of Dune", "Neuromancer"]
+        );
+
+        // ゴミ箱に移した本は著者の本の一覧に含めない
+        schema.execute("mutation { deleteBook(id: 3) }").await;
+        let data = schema.execute(&query).await.data.into_json().unwrap();
+        assert_eq!(data["author"]["books"].as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_trash_restore_and_empty() {
+        let schema = setup_schema().await;
+
+        let response = schema.execute("mutation { deleteBook(id: 1) }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        // ゴミ箱にある本は一覧・検索・IDでの取得の対象外
+        let data = schema
+            .execute(
+                r#"query {
+                    books { totalCount }
+                    searchBooks(query: "Dune") { book { id } }
+                    book(id: 1) { id }
+                    trashedBooks { id deletedAt }
+                }"#,
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["books"]["totalCount"], 4);
+        assert_eq!(data["searchBooks"].as_array().unwrap().len(), 2);
+        assert!(data["book"].is_null());
+        assert_eq!(data["trashedBooks"][0]["id"], 1);
+        assert!(data["trashedBooks"][0]["deletedAt"].is_string());
+
+        // ゴミ箱にある本は再度削除できない
+        let response = schema.execute("mutation { deleteBook(id: 1) }").await;
+        assert_eq!(response.errors.len(), 1);
+
+        let data = schema
+            .execute("mutation { restoreBook(id: 1) { title deletedAt } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["restoreBook"]["title"], "Dune");
+        assert!(data["restoreBook"]["deletedAt"].is_null());
+        let response = schema
+            .execute("mutation { restoreBook(id: 1) { id } }")
+            .await;
+        let code = response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"));
+        assert_eq!(code.unwrap().to_string(), r#""NOT_FOUND""#);
+
+        // ゴミ箱を空にすると完全に削除される
+        schema.execute("mutation { deleteBook(id: 2) }").await;
+        schema.execute("mutation { deleteBook(id: 3) }").await;
+        let data = schema
+            .execute("mutation { emptyTrash }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["emptyTrash"], 2);
+        let data = schema
+            .execute("query { books { totalCount } trashedBooks { id } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["books"]["totalCount"], 3);
+        assert_eq!(data["trashedBooks"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_book_history_undo_and_revert() {
+        let schema = setup_schema().await;
+
+        for mutation in [
+            r#"mutation { updateBook(id: 1, title: "Dune (Revised)", publishedYear: 1966, publisher: "Ace Books", expectedVersion: 1) { id } }"#,
+            r#"mutation { updateBook(id: 1, description: "Desert planet", expectedVersion: 2) { id } }"#,
+            "mutation { deleteBook(id: 1) }",
+        ] {
+            let response = schema.execute(mutation).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        let data = schema
+            .execute(
+                "query { bookHistory(id: 1) { version action source changes { field before after } } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        let history = data["bookHistory"].as_array().unwrap();
+        assert_eq!(history.len(), 4);
+        assert_eq!(history[0]["action"], "DELETE");
+        assert_eq!(history[0]["source"], "USER");
+        assert_eq!(history[0]["changes"], serde_json::json!([]));
+        assert_eq!(history[2]["version"], 2);
+        assert_eq!(
+            history[2]["changes"],
+            serde_json::json!([
+                { "field": "TITLE", "before": "Dune", "after": "Dune (Revised)" },
+                { "field": "PUBLISHED_YEAR", "before": "1965", "after": "1966" },
+                { "field": "PUBLISHER", "before": null, "after": "Ace Books" },
+            ])
+        );
+        assert_eq!(history[3]["action"], "CREATE");
+
+        // ゴミ箱への移動を取り消すと元に戻る
+        let data = schema
+            .execute("mutation { undoLastChange(bookId: 1) { deletedAt } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert!(data["undoLastChange"]["deletedAt"].is_null());
+
+        // 説明を付ける前の版に戻し、その復元を取り消す
+        let data = schema
+            .execute(
+                "mutation { revertToVersion(bookId: 1, version: 2) { title publishedYear description } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["revertToVersion"]["title"], "Dune (Revised)");
+        assert_eq!(data["revertToVersion"]["publishedYear"], 1966);
+        assert!(data["revertToVersion"]["description"].is_null());
+        let data = schema
+            .execute("mutation { undoLastChange(bookId: 1) { description } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["undoLastChange"]["description"], "Desert planet");
+
+        // 作成時の状態に戻す（作成後に設定した項目は空になる）
+        let data = schema
+            .execute(
+                "mutation { revertToVersion(bookId: 1, version: 1) { title publishedYear publisher description } }",
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["revertToVersion"]["title"], "Dune");
+        assert_eq!(data["revertToVersion"]["publishedYear"], 1965);
+        assert!(data["revertToVersion"]["publisher"].is_null());
+        assert!(data["revertToVersion"]["description"].is_null());
+        let data = schema
+            .execute("query { bookHistory(id: 1) { version action source } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["bookHistory"][0]["version"], 8);
+        assert_eq!(data["bookHistory"][0]["action"], "REVERT");
+        assert_eq!(data["bookHistory"][0]["source"], "HISTORY");
+
+        // 取り消しを続けると、取り消し済みの変更を飛ばして古い変更へ順に取り消していく
+        let mut undone = Vec::new();
+        for _ in 0..3 {
+            let response = schema
+                .execute(
+                    "mutation { undoLastChange(bookId: 1) { title publishedYear description } }",
+                )
+                .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            undone.push(response.data.into_json().unwrap()["undoLastChange"].clone());
+        }
+        assert_eq!(
+            undone,
+            vec![
+                serde_json::json!({ "title": "Dune (Revised)", "publishedYear": 1966, "description": "Desert planet" }),
+                serde_json::json!({ "title": "Dune (Revised)", "publishedYear": 1966, "description": null }),
+                serde_json::json!({ "title": "Dune", "publishedYear": 1965, "description": null }),
+            ]
+        );
+        let data = schema
+            .execute("query { bookHistory(id: 1) { version action } }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(data["bookHistory"][0]["version"], 11);
+        assert_eq!(data["bookHistory"][0]["action"], "UNDO");
+        let response = schema
+            .execute("mutation { undoLastChange(bookId: 1) { id } }")
+            .await;
+        assert_eq!(response.errors.len(), 1);
+
+        // 作成は取り消せず、存在しない版は指定できない
+        let response = schema
+            .execute("mutation { undoLastChange(bookId: 2) { id } }")
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        let response = schema
+            .execute("mutation { revertToVersion(bookId: 1, version: 99) { id } }")
+            .await;
+        let code = response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"));
+        assert_eq!(code.unwrap().to_string(), r#""NOT_FOUND""#);
+    }
+
+    #[tokio::test]
+    async fn test_books_rejects_oversized_page() {
+        let schema = setup_schema().await;
+
+        let response = schema
+            .execute("query { books(first: 1000) { totalCount } }")
+            .await;
+        assert!(!response.errors.is_empty());
+
+        let error = &response.errors[0];
+        let code = error.extensions.as_ref().and_then(|ext| ext.get("code"));
+        assert_eq!(code.unwrap().to_string(), r#""VALIDATION_ERROR""#);
+    }
 }
@@ -0,0 +1,152 @@
+// Presentation Layer - Library Catalog GraphQL Query
+
+use crate::application::{
+    dto::{book::BookFilterInput, citation::CitationFormat},
+    services::catalog::CatalogService,
+};
+use crate::domain::repositories::book::BookFilter;
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct CatalogQuery;
+
+#[Object]
+impl CatalogQuery {
+    /// すべての本をCSVで書き出す
+    ///
+    /// 列は `id,title,author,description,published_year,isbn`（1行目はヘッダー、ISBNはISBN-13）
+    async fn export_books_csv(&self, ctx: &Context<'_>) -> Result<String> {
+        let catalog_service = ctx
+            .data::<Arc<CatalogService>>()
+            .map_err(|_| Error::new("CatalogService not found"))?;
+
+        catalog_service
+            .export_books_csv()
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 本を BibTeX・RIS・CSL-JSON で書き出す
+    ///
+    /// `ids` で1冊または複数の本を指定する。指定しない場合は `filter` に一致する本（未指定ならすべての本）を書き出す。
+    /// 引用キーは `著者の姓 + 出版年 + タイトルの最初の語`（例: `herbert1965dune`）で、初めて書き出すときに割り当てて保存する。
+    /// 一度割り当てたキーは書き出す範囲や他の本の変更・削除によらず変わらない
+    async fn export_citations(
+        &self,
+        ctx: &Context<'_>,
+        format: CitationFormat,
+        ids: Option<Vec<i32>>,
+        filter: Option<BookFilterInput>,
+    ) -> Result<String> {
+        let catalog_service = ctx
+            .data::<Arc<CatalogService>>()
+            .map_err(|_| Error::new("CatalogService not found"))?;
+
+        let filter = BookFilter::try_from(filter.unwrap_or_default())
+            .map_err(|e| to_graphql_error(e.into()))?;
+
+        catalog_service
+            .export_citations(format, ids, filter)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::DatabaseHandle;
+    use crate::presentation::graphql::{LibraryMutation, LibraryQuery};
+    use crate::presentation::integration::{build_book_service, build_catalog_service};
+    use async_graphql::{EmptySubscription, Schema};
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_export_citations() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let handle = DatabaseHandle::new(db);
+        let schema = Schema::build(
+            LibraryQuery::default(),
+            LibraryMutation::default(),
+            EmptySubscription,
+        )
+        .data(build_book_service(handle.clone()))
+        .data(build_catalog_service(handle))
+        .finish();
+
+        for mutation in [
+            r#"mutation { createBook(title: "Dune", author: "Frank Herbert", publishedYear: 1965) { id } }"#,
+            r#"mutation { createBook(title: "Dune Messiah", author: "Frank Herbert", publishedYear: 1965) { id } }"#,
+            r#"mutation { createBook(title: "Neuromancer", author: "William Gibson", publishedYear: 1984) { id } }"#,
+        ] {
+            schema.execute(mutation).await;
+        }
+
+        // 1冊だけ書き出してもライブラリ全体で割り当てたキーになる
+        let data = schema
+            .execute(
+                r#"query {
+                    one: exportCitations(format: BIBTEX, ids: [2])
+                    filtered: exportCitations(format: RIS, filter: { author: "Gibson" })
+                    all: exportCitations(format: CSL_JSON)
+                }"#,
+            )
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert_eq!(
+            data["one"],
+            "@book{herbert1965dunea,\n  author = {Frank Herbert},\n  title = {{Dune Messiah}},\n  year = {1965},\n}\n"
+        );
+        assert!(
+            data["filtered"]
+                .as_str()
+                .unwrap()
+                .contains("ID  - gibson1984neuromancer\n")
+        );
+        let all: serde_json::Value = serde_json::from_str(data["all"].as_str().unwrap()).unwrap();
+        assert_eq!(all.as_array().unwrap().len(), 3);
+        assert_eq!(all[0]["id"], "herbert1965dune");
+
+        let response = schema
+            .execute("query { exportCitations(format: RIS, ids: [99]) }")
+            .await;
+        assert!(!response.errors.is_empty());
+        // 同じキーになる1冊目をゴミ箱に移しても2冊目のキーは変わらない
+        let response = schema.execute("mutation { deleteBook(id: 1) }").await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = schema
+            .execute("query { exportCitations(format: BIBTEX, ids: [2]) }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert!(
+            data["exportCitations"]
+                .as_str()
+                .unwrap()
+                .starts_with("@book{herbert1965dunea,\n")
+        );
+
+        // 後から追加した本には割り当て済みのキーと重ならないキーを割り当てる
+        schema
+            .execute(r#"mutation { createBook(title: "Dune", author: "Frank Herbert", publishedYear: 1965) { id } }"#)
+            .await;
+        let data = schema
+            .execute("query { exportCitations(format: BIBTEX, ids: [4]) }")
+            .await
+            .data
+            .into_json()
+            .unwrap();
+        assert!(
+            data["exportCitations"]
+                .as_str()
+                .unwrap()
+                .starts_with("@book{herbert1965duneb,\n")
+        );
+    }
+}
@@ -0,0 +1,88 @@
+// Presentation Layer - Library Copy GraphQL Query
+
+use crate::application::{
+    dto::copy::{CopyDto, LocationDto},
+    services::copy::CopyService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+/// `limit` 省略時に検索する本の数
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+#[derive(Default)]
+pub struct CopyQuery;
+
+#[Object]
+impl CopyQuery {
+    /// 1つ下の階層の場所を名前の順に取得（`parentId` 省略時は部屋）
+    async fn locations(
+        &self,
+        ctx: &Context<'_>,
+        parent_id: Option<i32>,
+    ) -> Result<Vec<LocationDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .list_locations(parent_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// IDで場所を取得
+    async fn location(&self, ctx: &Context<'_>, id: i32) -> Result<Option<LocationDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .get_location(id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// IDで所有している本の1冊を取得
+    async fn copy(&self, ctx: &Context<'_>, id: i32) -> Result<Option<CopyDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service.get_copy(id).await.map_err(to_graphql_error)
+    }
+
+    /// 本がどこにあるかを探す（タイトル・著者などで全文検索し、一致した本の冊を関連度の高い本の順に返す）
+    async fn where_is(
+        &self,
+        ctx: &Context<'_>,
+        query: String,
+        limit: Option<usize>,
+    ) -> Result<Vec<CopyDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .where_is(query, limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 場所（下位の場所を含む）に置いてある冊を本のタイトルの読み順に取得（`locationId` 省略時は場所が未設定の冊）
+    async fn location_inventory(
+        &self,
+        ctx: &Context<'_>,
+        location_id: Option<i32>,
+    ) -> Result<Vec<CopyDto>> {
+        let copy_service = ctx
+            .data::<Arc<CopyService>>()
+            .map_err(|_| Error::new("CopyService not found"))?;
+
+        copy_service
+            .get_location_inventory(location_id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
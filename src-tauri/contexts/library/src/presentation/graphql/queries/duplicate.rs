@@ -0,0 +1,33 @@
+// Presentation Layer - Library Duplicate GraphQL Query
+
+use crate::application::{
+    dto::duplicate::DuplicateGroupDto, services::duplicate::DuplicateService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct DuplicateQuery;
+
+#[Object]
+impl DuplicateQuery {
+    /// 重複している可能性がある本のグループを確からしさの高い順に取得
+    ///
+    /// ISBN・タイトル（全角・半角、カタカナ・ひらがなの違いは無視する）・著者で判定する。
+    /// `minConfidence`（0.0〜1.0、既定は0.5）未満の組は含めない。
+    async fn duplicate_books(
+        &self,
+        ctx: &Context<'_>,
+        min_confidence: Option<f64>,
+    ) -> Result<Vec<DuplicateGroupDto>> {
+        let duplicate_service = ctx
+            .data::<Arc<DuplicateService>>()
+            .map_err(|_| Error::new("DuplicateService not found"))?;
+
+        duplicate_service
+            .find_duplicates(min_confidence)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
@@ -0,0 +1,24 @@
+// Presentation Layer - Library E-book GraphQL Query
+
+use crate::application::{dto::ebook::EbookMetadataDto, services::ebook::EbookService};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct EbookQuery;
+
+#[Object]
+impl EbookQuery {
+    /// EPUB・PDF ファイルの書誌情報を読み込む（本の登録フォームの初期値。本は登録しない）
+    async fn ebook_metadata(&self, ctx: &Context<'_>, path: String) -> Result<EbookMetadataDto> {
+        let ebook_service = ctx
+            .data::<Arc<EbookService>>()
+            .map_err(|_| Error::new("EbookService not found"))?;
+
+        ebook_service
+            .read_ebook_metadata(path)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
@@ -0,0 +1,76 @@
+// Presentation Layer - Library Loan GraphQL Query
+
+use crate::application::{
+    dto::loan::{BorrowerDto, LoanDto, LoanReminderDto},
+    services::loan::LoanService,
+};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+/// `withinDays` 省略時にリマインダーに含める返却期限までの日数
+const DEFAULT_REMINDER_DAYS: u32 = 3;
+
+#[derive(Default)]
+pub struct LoanQuery;
+
+#[Object]
+impl LoanQuery {
+    /// すべての貸出相手を名前順に取得
+    async fn borrowers(&self, ctx: &Context<'_>) -> Result<Vec<BorrowerDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .list_borrowers()
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// IDで貸出相手を取得
+    async fn borrower(&self, ctx: &Context<'_>, id: i32) -> Result<Option<BorrowerDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .get_borrower(id)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    /// 貸出中のすべての貸出を返却期限の近い順に取得（期限なしは最後）
+    async fn active_loans(&self, ctx: &Context<'_>) -> Result<Vec<LoanDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service.active_loans().await.map_err(to_graphql_error)
+    }
+
+    /// 返却期限を過ぎている貸出を期限の古い順に取得
+    async fn overdue_loans(&self, ctx: &Context<'_>) -> Result<Vec<LoanDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service.overdue_loans().await.map_err(to_graphql_error)
+    }
+
+    /// 返却を促すリマインダー（期限切れと、`withinDays` 日以内に期限が来る貸出。デスクトップアプリから定期的に取得する）
+    async fn loan_reminders(
+        &self,
+        ctx: &Context<'_>,
+        within_days: Option<u32>,
+    ) -> Result<Vec<LoanReminderDto>> {
+        let loan_service = ctx
+            .data::<Arc<LoanService>>()
+            .map_err(|_| Error::new("LoanService not found"))?;
+
+        loan_service
+            .loan_reminders(within_days.unwrap_or(DEFAULT_REMINDER_DAYS))
+            .await
+            .map_err(to_graphql_error)
+    }
+}
@@ -0,0 +1,33 @@
+// Presentation Layer - Library Series GraphQL Query
+
+use crate::application::{dto::series::SeriesDto, services::series::SeriesService};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct SeriesQuery;
+
+#[Object]
+impl SeriesQuery {
+    /// すべてのシリーズを読みの順に取得
+    async fn all_series(&self, ctx: &Context<'_>) -> Result<Vec<SeriesDto>> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service.list_series().await.map_err(to_graphql_error)
+    }
+
+    /// IDでシリーズを取得
+    async fn series(&self, ctx: &Context<'_>, id: i32) -> Result<Option<SeriesDto>> {
+        let series_service = ctx
+            .data::<Arc<SeriesService>>()
+            .map_err(|_| Error::new("SeriesService not found"))?;
+
+        series_service
+            .get_series(id)
+            .await
+            .map_err(to_graphql_error)
+    }
+}
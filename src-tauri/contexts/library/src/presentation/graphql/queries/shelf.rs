@@ -0,0 +1,30 @@
+// Presentation Layer - Library Shelf GraphQL Query
+
+use crate::application::{dto::shelf::ShelfDto, services::shelf::ShelfService};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ShelfQuery;
+
+#[Object]
+impl ShelfQuery {
+    /// すべての本棚を表示順に取得
+    async fn shelves(&self, ctx: &Context<'_>) -> Result<Vec<ShelfDto>> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service.list_shelves().await.map_err(to_graphql_error)
+    }
+
+    /// IDで本棚を取得
+    async fn shelf(&self, ctx: &Context<'_>, id: i32) -> Result<Option<ShelfDto>> {
+        let shelf_service = ctx
+            .data::<Arc<ShelfService>>()
+            .map_err(|_| Error::new("ShelfService not found"))?;
+
+        shelf_service.get_shelf(id).await.map_err(to_graphql_error)
+    }
+}
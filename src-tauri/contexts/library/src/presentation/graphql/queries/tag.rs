@@ -0,0 +1,21 @@
+// Presentation Layer - Library Tag GraphQL Query
+
+use crate::application::{dto::tag::TagDto, services::tag::TagService};
+use crate::presentation::graphql::to_graphql_error;
+use async_graphql::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct TagQuery;
+
+#[Object]
+impl TagQuery {
+    /// すべてのタグを階層の順に取得（親のタグの直後に子のタグが並ぶ）
+    async fn tags(&self, ctx: &Context<'_>) -> Result<Vec<TagDto>> {
+        let tag_service = ctx
+            .data::<Arc<TagService>>()
+            .map_err(|_| Error::new("TagService not found"))?;
+
+        tag_service.list_tags().await.map_err(to_graphql_error)
+    }
+}
@@ -1,19 +1,240 @@
 // Presentation Layer - 統合ヘルパー
 // 他のクレートからの依存性注入を簡素化する
 
-use crate::application::services::book::BookService;
-use crate::infrastructure::repositories::book::BookRepositoryImpl;
-use sea_orm::DatabaseConnection;
+use crate::application::services::{
+    book::BookService, catalog::CatalogService, copy::CopyService, cover::CoverService,
+    duplicate::DuplicateService, ebook::EbookService, highlight::HighlightService,
+    loan::LoanService, reading::ReadingService, review::ReviewService, series::SeriesService,
+    shelf::ShelfService, tag::TagService,
+};
+use crate::infrastructure::database::DatabaseHandle;
+use crate::infrastructure::repositories::{
+    author::AuthorRepositoryImpl, book::BookRepositoryImpl, book_change::BookChangeRepositoryImpl,
+    book_file::BookFileRepositoryImpl, borrower::BorrowerRepositoryImpl,
+    calibre::CalibreLibraryRepositoryImpl, copy::CopyRepositoryImpl,
+    cover::CoverImageRepositoryImpl, ebook::EbookMetadataReaderImpl,
+    ebook_file::EbookFileStoreImpl, highlight::HighlightRepositoryImpl, loan::LoanRepositoryImpl,
+    location::LocationRepositoryImpl, note::NoteRepositoryImpl,
+    read_through::ReadThroughRepositoryImpl, review::ReviewRepositoryImpl,
+    series::SeriesRepositoryImpl, shelf::ShelfRepositoryImpl, tag::TagRepositoryImpl,
+};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// BookServiceを構築する統合関数
 ///
 /// # Arguments
-/// * `db` - データベース接続
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
 ///
 /// # Returns
 /// 依存性が注入されたBookServiceのArcポインタ
-pub fn build_book_service(db: DatabaseConnection) -> Arc<BookService> {
+pub fn build_book_service(db: DatabaseHandle) -> Arc<BookService> {
+    let book_repo = Arc::new(BookRepositoryImpl::new(db.clone()));
+    let author_repo = Arc::new(AuthorRepositoryImpl::new(db.clone()));
+    let shelf_repo = Arc::new(ShelfRepositoryImpl::new(db.clone()));
+    let history_repo = Arc::new(BookChangeRepositoryImpl::new(db));
+    Arc::new(BookService::new(
+        book_repo,
+        author_repo,
+        shelf_repo,
+        history_repo,
+    ))
+}
+
+/// CatalogServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたCatalogServiceのArcポインタ
+pub fn build_catalog_service(db: DatabaseHandle) -> Arc<CatalogService> {
+    let book_repo = Arc::new(BookRepositoryImpl::new(db.clone()));
+    let author_repo = Arc::new(AuthorRepositoryImpl::new(db.clone()));
+    let read_through_repo = Arc::new(ReadThroughRepositoryImpl::new(db.clone()));
+    let review_repo = Arc::new(ReviewRepositoryImpl::new(db.clone()));
+    let note_repo = Arc::new(NoteRepositoryImpl::new(db.clone()));
+    let series_repo = Arc::new(SeriesRepositoryImpl::new(db.clone()));
+    let tag_repo = Arc::new(TagRepositoryImpl::new(db));
+    let calibre_library_repo = Arc::new(CalibreLibraryRepositoryImpl::new());
+    Arc::new(CatalogService::new(
+        book_repo,
+        author_repo,
+        read_through_repo,
+        review_repo,
+        note_repo,
+        series_repo,
+        tag_repo,
+        calibre_library_repo,
+    ))
+}
+
+/// ReadingServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたReadingServiceのArcポインタ
+pub fn build_reading_service(db: DatabaseHandle) -> Arc<ReadingService> {
+    let read_through_repo = Arc::new(ReadThroughRepositoryImpl::new(db.clone()));
+    let book_repo = Arc::new(BookRepositoryImpl::new(db));
+    Arc::new(ReadingService::new(read_through_repo, book_repo))
+}
+
+/// ReviewServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたReviewServiceのArcポインタ
+pub fn build_review_service(db: DatabaseHandle) -> Arc<ReviewService> {
+    let review_repo = Arc::new(ReviewRepositoryImpl::new(db.clone()));
+    let note_repo = Arc::new(NoteRepositoryImpl::new(db.clone()));
+    let book_repo = Arc::new(BookRepositoryImpl::new(db));
+    Arc::new(ReviewService::new(review_repo, note_repo, book_repo))
+}
+
+/// HighlightServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたHighlightServiceのArcポインタ
+pub fn build_highlight_service(db: DatabaseHandle) -> Arc<HighlightService> {
+    let highlight_repo = Arc::new(HighlightRepositoryImpl::new(db.clone()));
+    let book_repo = Arc::new(BookRepositoryImpl::new(db.clone()));
+    let author_repo = Arc::new(AuthorRepositoryImpl::new(db));
+    Arc::new(HighlightService::new(
+        highlight_repo,
+        book_repo,
+        author_repo,
+    ))
+}
+
+/// SeriesServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたSeriesServiceのArcポインタ
+pub fn build_series_service(db: DatabaseHandle) -> Arc<SeriesService> {
+    let series_repo = Arc::new(SeriesRepositoryImpl::new(db.clone()));
+    let book_repo = Arc::new(BookRepositoryImpl::new(db));
+    Arc::new(SeriesService::new(series_repo, book_repo))
+}
+
+/// TagServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたTagServiceのArcポインタ
+pub fn build_tag_service(db: DatabaseHandle) -> Arc<TagService> {
+    let tag_repo = Arc::new(TagRepositoryImpl::new(db.clone()));
     let book_repo = Arc::new(BookRepositoryImpl::new(db));
-    Arc::new(BookService::new(book_repo))
+    Arc::new(TagService::new(tag_repo, book_repo))
+}
+
+/// ShelfServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたShelfServiceのArcポインタ
+pub fn build_shelf_service(db: DatabaseHandle) -> Arc<ShelfService> {
+    let shelf_repo = Arc::new(ShelfRepositoryImpl::new(db.clone()));
+    let book_repo = Arc::new(BookRepositoryImpl::new(db));
+    Arc::new(ShelfService::new(shelf_repo, book_repo))
+}
+
+/// CoverServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+/// * `covers_dir` - 表紙画像を保存するディレクトリ（アプリのローカルデータディレクトリ内）
+///
+/// # Returns
+/// 依存性が注入されたCoverServiceのArcポインタ
+pub fn build_cover_service(db: DatabaseHandle, covers_dir: PathBuf) -> Arc<CoverService> {
+    let cover_repo = Arc::new(CoverImageRepositoryImpl::new(covers_dir));
+    let book_repo = Arc::new(BookRepositoryImpl::new(db));
+    Arc::new(CoverService::new(cover_repo, book_repo))
+}
+
+/// DuplicateServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+/// * `covers_dir` - 表紙画像を保存するディレクトリ（統合で使われなくなった表紙画像の削除用）
+///
+/// # Returns
+/// 依存性が注入されたDuplicateServiceのArcポインタ
+pub fn build_duplicate_service(db: DatabaseHandle, covers_dir: PathBuf) -> Arc<DuplicateService> {
+    let book_repo = Arc::new(BookRepositoryImpl::new(db));
+    let cover_repo = Arc::new(CoverImageRepositoryImpl::new(covers_dir));
+    Arc::new(DuplicateService::new(book_repo, cover_repo))
+}
+
+/// EbookServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+/// * `covers_dir` - 表紙画像を保存するディレクトリ（ファイルの表紙画像の保存先）
+/// * `books_dir` - 電子書籍ファイルのコピーを保存するディレクトリ
+///
+/// # Returns
+/// 依存性が注入されたEbookServiceのArcポインタ
+pub fn build_ebook_service(
+    db: DatabaseHandle,
+    covers_dir: PathBuf,
+    books_dir: PathBuf,
+) -> Arc<EbookService> {
+    let book_repo = Arc::new(BookRepositoryImpl::new(db.clone()));
+    let author_repo = Arc::new(AuthorRepositoryImpl::new(db.clone()));
+    let book_file_repo = Arc::new(BookFileRepositoryImpl::new(db));
+    let cover_repo = Arc::new(CoverImageRepositoryImpl::new(covers_dir));
+    let metadata_reader = Arc::new(EbookMetadataReaderImpl::new());
+    let file_store = Arc::new(EbookFileStoreImpl::new(books_dir));
+    Arc::new(EbookService::new(
+        book_repo,
+        author_repo,
+        book_file_repo,
+        cover_repo,
+        metadata_reader,
+        file_store,
+    ))
+}
+
+/// CopyServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたCopyServiceのArcポインタ
+pub fn build_copy_service(db: DatabaseHandle) -> Arc<CopyService> {
+    let copy_repo = Arc::new(CopyRepositoryImpl::new(db.clone()));
+    let location_repo = Arc::new(LocationRepositoryImpl::new(db.clone()));
+    let book_repo = Arc::new(BookRepositoryImpl::new(db));
+    Arc::new(CopyService::new(copy_repo, location_repo, book_repo))
+}
+
+/// LoanServiceを構築する統合関数
+///
+/// # Arguments
+/// * `db` - データベース接続のハンドル（実行中の接続切り替えに対応）
+///
+/// # Returns
+/// 依存性が注入されたLoanServiceのArcポインタ
+pub fn build_loan_service(db: DatabaseHandle) -> Arc<LoanService> {
+    let loan_repo = Arc::new(LoanRepositoryImpl::new(db.clone()));
+    let borrower_repo = Arc::new(BorrowerRepositoryImpl::new(db.clone()));
+    let copy_repo = Arc::new(CopyRepositoryImpl::new(db));
+    Arc::new(LoanService::new(loan_repo, borrower_repo, copy_repo))
 }
@@ -3,7 +3,9 @@
 mod appearance;
 mod database;
 mod general;
+mod trash;
 
 pub use appearance::AppearanceSettingsDto;
 pub use database::DatabaseSettingsDto;
 pub use general::GeneralSettingsDto;
+pub use trash::TrashSettingsDto;
@@ -0,0 +1,19 @@
+// Settings Application Layer - Trash Settings DTO
+
+use crate::domain::entities::TrashSettings;
+use async_graphql::SimpleObject;
+
+/// ゴミ箱設定のDTO
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TrashSettingsDto {
+    /// ゴミ箱に移した本を自動で完全に削除するまでの日数（0 の場合は自動で削除しない）
+    pub retention_days: u32,
+}
+
+impl From<TrashSettings> for TrashSettingsDto {
+    fn from(settings: TrashSettings) -> Self {
+        Self {
+            retention_days: settings.retention_days,
+        }
+    }
+}
@@ -15,6 +15,12 @@ pub enum ApplicationError {
     #[error("Invalid database directory: {0}")]
     InvalidDatabaseDirectory(String),
 
+    #[error("Invalid trash retention period: {0}")]
+    InvalidTrashRetention(String),
+
+    #[error("Database relocation failed: {0}")]
+    DatabaseRelocationFailed(String),
+
     #[error("Domain error: {0}")]
     Domain(#[from] DomainError),
 }
@@ -1,5 +1,7 @@
 // Settings Application Layer - Services モジュール
 
+mod database_relocator;
 mod settings_service;
 
+pub use database_relocator::{DatabaseRelocator, PreparedDatabase};
 pub use settings_service::SettingsService;
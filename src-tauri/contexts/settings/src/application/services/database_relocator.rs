@@ -0,0 +1,23 @@
+// Settings Application Layer - Database Relocator
+
+use async_trait::async_trait;
+use std::path::Path;
+
+/// データベースディレクトリ変更時にデータベース本体を移設するためのインターフェース
+/// 実際の移設処理（ファイルのコピー、マイグレーション、接続の切り替え）はアプリ側で実装する
+///
+/// 設定の保存に失敗した場合に接続を元に戻さなくて済むよう、移設先の用意と接続の切り替えを分ける
+#[async_trait]
+pub trait DatabaseRelocator: Send + Sync {
+    /// `from` ディレクトリのデータベースを `to` ディレクトリへ移設する（接続はまだ切り替えない）
+    async fn prepare(&self, from: &Path, to: &Path) -> anyhow::Result<Box<dyn PreparedDatabase>>;
+}
+
+/// 移設先に用意したデータベース
+///
+/// `switch` せずに破棄した場合は移設先の接続を閉じ、現在の接続を使い続ける
+#[async_trait]
+pub trait PreparedDatabase: Send {
+    /// 接続を移設先のデータベースに切り替える
+    async fn switch(self: Box<Self>);
+}
@@ -2,11 +2,12 @@
 
 use crate::{
     application::{
-        dto::{AppearanceSettingsDto, DatabaseSettingsDto, GeneralSettingsDto},
+        dto::{AppearanceSettingsDto, DatabaseSettingsDto, GeneralSettingsDto, TrashSettingsDto},
         errors::ApplicationError,
+        services::DatabaseRelocator,
     },
     domain::{
-        entities::Settings,
+        entities::{MAX_TRASH_RETENTION_DAYS, Settings},
         repositories::SettingsRepository,
         value_objects::{Language, Theme},
     },
@@ -30,6 +31,8 @@ pub struct SettingsService {
     cache: Arc<RwLock<Option<CachedSettings>>>,
     // キャッシュの有効期限（秒）
     cache_ttl: Duration,
+    // データベースディレクトリ変更時の移設処理（未設定の場合は設定の保存のみ）
+    database_relocator: Option<Arc<dyn DatabaseRelocator>>,
 }
 
 impl SettingsService {
@@ -39,6 +42,7 @@ impl SettingsService {
             repository,
             cache: Arc::new(RwLock::new(None)),
             cache_ttl: Duration::from_secs(60), // デフォルト60秒
+            database_relocator: None,
         }
     }
 
@@ -48,9 +52,16 @@ impl SettingsService {
             repository,
             cache: Arc::new(RwLock::new(None)),
             cache_ttl: Duration::from_secs(ttl_seconds),
+            database_relocator: None,
         }
     }
 
+    /// データベースディレクトリ変更時の移設処理を設定
+    pub fn with_database_relocator(mut self, relocator: Arc<dyn DatabaseRelocator>) -> Self {
+        self.database_relocator = Some(relocator);
+        self
+    }
+
     /// キャッシュの有効性をチェック
     fn is_cache_valid(cached: &CachedSettings, ttl: Duration) -> bool {
         cached.cached_at.elapsed() < ttl
@@ -125,6 +136,12 @@ impl SettingsService {
         Ok(settings.database.into())
     }
 
+    /// ゴミ箱設定を取得
+    pub async fn get_trash_settings(&self) -> Result<TrashSettingsDto, ApplicationError> {
+        let settings = self.load_settings().await?;
+        Ok(settings.trash.into())
+    }
+
     /// 一般設定を更新
     pub async fn update_general_settings(
         &self,
@@ -161,6 +178,28 @@ impl SettingsService {
         Ok(settings.appearance.into())
     }
 
+    /// ゴミ箱設定を更新
+    pub async fn update_trash_settings(
+        &self,
+        retention_days: Option<u32>,
+    ) -> Result<TrashSettingsDto, ApplicationError> {
+        let mut settings = self.load_settings().await?;
+
+        // 保存日数を更新
+        if let Some(days) = retention_days {
+            if days > MAX_TRASH_RETENTION_DAYS {
+                return Err(ApplicationError::InvalidTrashRetention(format!(
+                    "Retention period must be {} days or less",
+                    MAX_TRASH_RETENTION_DAYS
+                )));
+            }
+            settings.trash.retention_days = days;
+        }
+
+        self.save_settings(&settings).await?;
+        Ok(settings.trash.into())
+    }
+
     /// データベースディレクトリのバリデーション（セキュリティ強化版）
     fn validate_database_directory(path_str: &str) -> Result<PathBuf, ApplicationError> {
         // 空文字列チェック
@@ -230,14 +269,34 @@ impl SettingsService {
         database_directory: Option<String>,
     ) -> Result<DatabaseSettingsDto, ApplicationError> {
         let mut settings = self.load_settings().await?;
+        let mut prepared = None;
 
         // データベースディレクトリを更新
         if let Some(dir_str) = database_directory {
             let validated_path = Self::validate_database_directory(&dir_str)?;
+
+            // ディレクトリが変わる場合は、保存前に移設先のデータベースを用意する
+            if validated_path != settings.database.database_directory
+                && let Some(relocator) = &self.database_relocator
+            {
+                prepared = Some(
+                    relocator
+                        .prepare(&settings.database.database_directory, &validated_path)
+                        .await
+                        .map_err(|e| {
+                            ApplicationError::DatabaseRelocationFailed(format!("{:#}", e))
+                        })?,
+                );
+            }
+
             settings.database.database_directory = validated_path;
         }
 
+        // 保存に失敗した場合は接続を切り替えない（用意した移設先の接続は破棄される）
         self.save_settings(&settings).await?;
+        if let Some(prepared) = prepared {
+            prepared.switch().await;
+        }
         Ok(settings.database.into())
     }
 
@@ -259,9 +318,55 @@ impl SettingsService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::services::PreparedDatabase;
     use crate::infrastructure::repositories::SettingsRepositoryImpl;
+    use async_trait::async_trait;
+    use std::path::Path;
+    use std::sync::Mutex;
     use tempfile::TempDir;
 
+    /// 呼び出しを記録するテスト用の DatabaseRelocator
+    #[derive(Default)]
+    struct RecordingRelocator {
+        calls: Mutex<Vec<(PathBuf, PathBuf)>>,
+        switched: Arc<Mutex<Vec<PathBuf>>>,
+        fail: bool,
+    }
+
+    /// 切り替えを記録するテスト用の PreparedDatabase
+    struct RecordingPreparedDatabase {
+        to: PathBuf,
+        switched: Arc<Mutex<Vec<PathBuf>>>,
+    }
+
+    #[async_trait]
+    impl DatabaseRelocator for RecordingRelocator {
+        async fn prepare(
+            &self,
+            from: &Path,
+            to: &Path,
+        ) -> anyhow::Result<Box<dyn PreparedDatabase>> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((from.to_path_buf(), to.to_path_buf()));
+            if self.fail {
+                anyhow::bail!("disk full");
+            }
+            Ok(Box::new(RecordingPreparedDatabase {
+                to: to.to_path_buf(),
+                switched: self.switched.clone(),
+            }))
+        }
+    }
+
+    #[async_trait]
+    impl PreparedDatabase for RecordingPreparedDatabase {
+        async fn switch(self: Box<Self>) {
+            self.switched.lock().unwrap().push(self.to);
+        }
+    }
+
     #[tokio::test]
     async fn test_get_default_settings() {
         let temp_dir = TempDir::new().unwrap();
@@ -317,6 +422,32 @@ mod tests {
         assert_eq!(updated.theme, "dark");
     }
 
+    #[tokio::test]
+    async fn test_update_trash_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let default_db_dir = temp_dir.path().join("databases");
+        let repository = Arc::new(SettingsRepositoryImpl::new(
+            temp_dir.path().to_path_buf(),
+            default_db_dir,
+        ));
+        let service = SettingsService::new(repository);
+
+        let trash = service.get_trash_settings().await.unwrap();
+        assert_eq!(trash.retention_days, 30);
+
+        let updated = service.update_trash_settings(Some(7)).await.unwrap();
+        assert_eq!(updated.retention_days, 7);
+
+        // 上限を超える日数は不可
+        let result = service.update_trash_settings(Some(3651)).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::InvalidTrashRetention(_))
+        ));
+        let trash = service.get_trash_settings().await.unwrap();
+        assert_eq!(trash.retention_days, 7);
+    }
+
     #[tokio::test]
     async fn test_reset_settings() {
         let temp_dir = TempDir::new().unwrap();
@@ -508,6 +639,92 @@ mod tests {
         assert_eq!(updated.database_directory, valid_path.to_str().unwrap());
     }
 
+    #[tokio::test]
+    async fn test_database_relocator_called_on_directory_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let default_db_dir = temp_dir.path().join("databases");
+        let repository = Arc::new(SettingsRepositoryImpl::new(
+            temp_dir.path().to_path_buf(),
+            default_db_dir.clone(),
+        ));
+        let relocator = Arc::new(RecordingRelocator::default());
+        let service = SettingsService::new(repository).with_database_relocator(relocator.clone());
+
+        let new_path = temp_dir.path().join("my_databases");
+        service
+            .update_database_settings(Some(new_path.to_str().unwrap().to_string()))
+            .await
+            .unwrap();
+
+        // 同じディレクトリへの更新では移設しない
+        service
+            .update_database_settings(Some(new_path.to_str().unwrap().to_string()))
+            .await
+            .unwrap();
+
+        let calls = relocator.calls.lock().unwrap();
+        assert_eq!(calls.as_slice(), &[(default_db_dir, new_path.clone())]);
+        let switched = relocator.switched.lock().unwrap();
+        assert_eq!(switched.as_slice(), &[new_path]);
+    }
+
+    #[tokio::test]
+    async fn test_database_not_switched_when_settings_save_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        // 設定ディレクトリの位置にファイルがあるため保存に失敗する
+        let config_dir = temp_dir.path().join("config");
+        std::fs::write(&config_dir, "").unwrap();
+        let repository = Arc::new(SettingsRepositoryImpl::new(
+            config_dir,
+            temp_dir.path().join("databases"),
+        ));
+        let relocator = Arc::new(RecordingRelocator::default());
+        let service = SettingsService::new(repository).with_database_relocator(relocator.clone());
+
+        let new_path = temp_dir.path().join("my_databases");
+        let result = service
+            .update_database_settings(Some(new_path.to_str().unwrap().to_string()))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(relocator.calls.lock().unwrap().len(), 1);
+        assert!(relocator.switched.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_database_relocation_failure_keeps_previous_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let default_db_dir = temp_dir.path().join("databases");
+        let repository = Arc::new(SettingsRepositoryImpl::new(
+            temp_dir.path().to_path_buf(),
+            default_db_dir.clone(),
+        ));
+        let relocator = Arc::new(RecordingRelocator {
+            fail: true,
+            ..Default::default()
+        });
+        let service = SettingsService::new(repository).with_database_relocator(relocator);
+
+        let new_path = temp_dir.path().join("my_databases");
+        let result = service
+            .update_database_settings(Some(new_path.to_str().unwrap().to_string()))
+            .await;
+
+        match result.unwrap_err() {
+            ApplicationError::DatabaseRelocationFailed(msg) => {
+                assert!(msg.contains("disk full"));
+            }
+            _ => panic!("Expected DatabaseRelocationFailed error"),
+        }
+
+        // 移設に失敗した場合は設定も変更されない
+        let database = service.get_database_settings().await.unwrap();
+        assert_eq!(
+            database.database_directory,
+            default_db_dir.to_str().unwrap()
+        );
+    }
+
     // ============================================
     // キャッシュTTLのテスト
     // ============================================
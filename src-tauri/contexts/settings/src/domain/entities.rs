@@ -4,8 +4,10 @@ mod appearance;
 mod database;
 mod general;
 mod settings;
+mod trash;
 
 pub use appearance::AppearanceSettings;
 pub use database::DatabaseSettings;
 pub use general::GeneralSettings;
 pub use settings::Settings;
+pub use trash::{MAX_TRASH_RETENTION_DAYS, TrashSettings};
@@ -1,6 +1,6 @@
 // Settings Domain Layer - App Settings Entity
 
-use super::{AppearanceSettings, DatabaseSettings, GeneralSettings};
+use super::{AppearanceSettings, DatabaseSettings, GeneralSettings, TrashSettings};
 use serde::{Deserialize, Serialize};
 
 /// アプリケーション全体の設定
@@ -9,6 +9,9 @@ pub struct Settings {
     pub general: GeneralSettings,
     pub appearance: AppearanceSettings,
     pub database: DatabaseSettings,
+    /// 追加前に保存された設定ファイルには存在しないため、省略時は既定値を使う
+    #[serde(default)]
+    pub trash: TrashSettings,
 }
 
 #[cfg(test)]
@@ -23,6 +26,7 @@ mod tests {
         assert_eq!(settings.general.language, Language::Japanese);
         assert_eq!(settings.appearance.theme, Theme::System);
         assert_eq!(settings.database.database_directory, PathBuf::new());
+        assert_eq!(settings.trash.retention_days, 30);
     }
 
     #[test]
@@ -48,5 +52,6 @@ mod tests {
             settings.database.database_directory,
             PathBuf::from("/custom/path")
         );
+        assert_eq!(settings.trash.retention_days, 30);
     }
 }
@@ -0,0 +1,24 @@
+// Settings Domain Layer - Trash Settings Entity
+
+use serde::{Deserialize, Serialize};
+
+/// ゴミ箱の本を保存する日数の既定値
+const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+/// ゴミ箱の本を保存する日数の上限（約10年）
+pub const MAX_TRASH_RETENTION_DAYS: u32 = 3650;
+
+/// ゴミ箱設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashSettings {
+    /// ゴミ箱に移した本を自動で完全に削除するまでの日数（0 の場合は自動で削除しない）
+    pub retention_days: u32,
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        Self {
+            retention_days: DEFAULT_TRASH_RETENTION_DAYS,
+        }
+    }
+}
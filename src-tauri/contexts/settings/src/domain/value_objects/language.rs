@@ -5,13 +5,25 @@ use strum::{AsRefStr, Display, EnumString};
 
 /// 言語のValue Object
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, AsRefStr, Display,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    EnumString,
+    AsRefStr,
+    Display,
+    Default,
 )]
 #[serde(rename_all = "lowercase")]
 #[strum(ascii_case_insensitive)]
 pub enum Language {
     #[serde(rename = "ja")]
     #[strum(serialize = "ja")]
+    #[default]
     Japanese,
     #[serde(rename = "en")]
     #[strum(serialize = "en")]
@@ -25,12 +37,6 @@ pub enum Language {
     // Korean,
 }
 
-impl Default for Language {
-    fn default() -> Self {
-        Self::Japanese
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
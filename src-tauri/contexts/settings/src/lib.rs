@@ -7,7 +7,9 @@ pub(crate) mod presentation;
 
 // Public API - Presentation層のみ公開
 pub use presentation::graphql::{mutations::SettingsMutation, queries::SettingsQuery};
-pub use presentation::integration::build_settings_service;
+pub use presentation::integration::{
+    build_settings_service, build_settings_service_with_relocator,
+};
 
 // Type exports for type annotations (opaque to external users)
-pub use application::services::SettingsService;
+pub use application::services::{DatabaseRelocator, PreparedDatabase, SettingsService};
@@ -15,6 +15,12 @@ pub fn to_graphql_error(e: ApplicationError) -> Error {
         ApplicationError::InvalidDatabaseDirectory(msg) => Error::new(msg).extend_with(|_, ext| {
             ext.set("code", "INVALID_DATABASE_DIRECTORY");
         }),
+        ApplicationError::InvalidTrashRetention(msg) => Error::new(msg).extend_with(|_, ext| {
+            ext.set("code", "INVALID_TRASH_RETENTION");
+        }),
+        ApplicationError::DatabaseRelocationFailed(msg) => Error::new(msg).extend_with(|_, ext| {
+            ext.set("code", "DATABASE_RELOCATION_FAILED");
+        }),
         ApplicationError::Domain(e) => {
             Error::new(format!("Domain error: {}", e)).extend_with(|_, ext| {
                 ext.set("code", "DOMAIN_ERROR");
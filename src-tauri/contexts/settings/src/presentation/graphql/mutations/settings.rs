@@ -2,7 +2,7 @@
 
 use crate::{
     application::{
-        dto::{AppearanceSettingsDto, DatabaseSettingsDto, GeneralSettingsDto},
+        dto::{AppearanceSettingsDto, DatabaseSettingsDto, GeneralSettingsDto, TrashSettingsDto},
         services::SettingsService,
     },
     presentation::graphql::to_graphql_error,
@@ -63,6 +63,22 @@ impl SettingsMutation {
             .map_err(to_graphql_error)
     }
 
+    /// ゴミ箱設定を更新（保存日数は0〜3650日。0 の場合は自動で削除しない）
+    async fn update_trash_settings(
+        &self,
+        ctx: &Context<'_>,
+        retention_days: Option<u32>,
+    ) -> Result<TrashSettingsDto> {
+        let settings_service = ctx
+            .data::<Arc<SettingsService>>()
+            .map_err(|_| Error::new("SettingsService not found"))?;
+
+        settings_service
+            .update_trash_settings(retention_days)
+            .await
+            .map_err(to_graphql_error)
+    }
+
     /// すべての設定をリセット
     async fn reset_settings(&self, ctx: &Context<'_>) -> Result<bool> {
         let settings_service = ctx
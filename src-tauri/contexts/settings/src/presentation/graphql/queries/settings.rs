@@ -2,7 +2,7 @@
 
 use crate::{
     application::{
-        dto::{AppearanceSettingsDto, DatabaseSettingsDto, GeneralSettingsDto},
+        dto::{AppearanceSettingsDto, DatabaseSettingsDto, GeneralSettingsDto, TrashSettingsDto},
         services::SettingsService,
     },
     presentation::graphql::to_graphql_error,
@@ -50,6 +50,18 @@ impl SettingsQuery {
             .await
             .map_err(to_graphql_error)
     }
+
+    /// ゴミ箱設定を取得
+    async fn trash_settings(&self, ctx: &Context<'_>) -> Result<TrashSettingsDto> {
+        let settings_service = ctx
+            .data::<Arc<SettingsService>>()
+            .map_err(|_| Error::new("SettingsService not found"))?;
+
+        settings_service
+            .get_trash_settings()
+            .await
+            .map_err(to_graphql_error)
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 // Presentation Layer - 統合ヘルパー
 // 他のクレートからの依存性注入を簡素化する
 
-use crate::application::services::SettingsService;
+use crate::application::services::{DatabaseRelocator, SettingsService};
 use crate::infrastructure::repositories::SettingsRepositoryImpl;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -21,3 +21,21 @@ pub fn build_settings_service(
     let settings_repo = Arc::new(SettingsRepositoryImpl::new(config_dir, default_db_dir));
     Arc::new(SettingsService::new(settings_repo))
 }
+
+/// データベースの移設処理を注入してSettingsServiceを構築する統合関数
+///
+/// # Arguments
+/// * `config_dir` - 設定ファイルを保存するディレクトリ
+/// * `default_db_dir` - データベースディレクトリのデフォルト値
+/// * `relocator` - データベースディレクトリ変更時の移設処理
+///
+/// # Returns
+/// 依存性が注入されたSettingsServiceのArcポインタ
+pub fn build_settings_service_with_relocator(
+    config_dir: PathBuf,
+    default_db_dir: PathBuf,
+    relocator: Arc<dyn DatabaseRelocator>,
+) -> Arc<SettingsService> {
+    let settings_repo = Arc::new(SettingsRepositoryImpl::new(config_dir, default_db_dir));
+    Arc::new(SettingsService::new(settings_repo).with_database_relocator(relocator))
+}
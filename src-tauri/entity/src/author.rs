@@ -0,0 +1,28 @@
+// Entity Layer - Author SeaORMモデル（DBスキーマ）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Author テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "authors")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::book_author::Entity")]
+    BookAuthor,
+}
+
+impl Related<super::book_author::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookAuthor.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -13,9 +13,134 @@ pub struct Model {
     pub author: Option<String>,
     pub description: Option<String>,
     pub published_year: Option<i32>,
+    /// 出版社
+    pub publisher: Option<String>,
+    /// ISBN-13（ハイフンなし）
+    #[sea_orm(unique)]
+    pub isbn: Option<String>,
+    /// 表紙画像の内容のハッシュ（SHA-256）
+    pub cover_hash: Option<String>,
+    /// ゴミ箱に移した日時（ゴミ箱にない本は None）
+    pub deleted_at: Option<DateTimeUtc>,
+    /// 楽観的排他制御用の版番号（保存するたびに1増える）
+    pub version: i32,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+    /// タイトルの読み（かな）
+    pub title_reading: Option<String>,
+    /// 著者の読み（かな）
+    pub author_reading: Option<String>,
+    /// タイトルの並び替え用のキー（読み、なければタイトルを正規化したもの）
+    pub title_sort_key: String,
+    /// 著者の並び替え用のキー（読み、なければ著者名を正規化したもの）
+    pub author_sort_key: Option<String>,
+    /// 日本十進分類法（NDC）の分類記号
+    pub ndc: Option<String>,
+    /// デューイ十進分類法（DDC）の分類記号
+    pub ddc: Option<String>,
+    /// 引用キー（初めて引用文献として書き出したときに割り当てる）
+    #[sea_orm(unique)]
+    pub citation_key: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(has_many = "super::book_author::Entity")]
+    BookAuthor,
+    #[sea_orm(has_many = "super::book_change::Entity")]
+    BookChange,
+    #[sea_orm(has_many = "super::read_through::Entity")]
+    ReadThrough,
+    #[sea_orm(has_one = "super::book_review::Entity")]
+    BookReview,
+    #[sea_orm(has_many = "super::book_note::Entity")]
+    BookNote,
+    #[sea_orm(has_many = "super::book_highlight::Entity")]
+    BookHighlight,
+    #[sea_orm(has_many = "super::book_external_id::Entity")]
+    BookExternalId,
+    #[sea_orm(has_many = "super::book_file::Entity")]
+    BookFile,
+    #[sea_orm(has_one = "super::book_series::Entity")]
+    BookSeries,
+    #[sea_orm(has_many = "super::book_tag::Entity")]
+    BookTag,
+    #[sea_orm(has_many = "super::shelf_book::Entity")]
+    ShelfBook,
+    #[sea_orm(has_many = "super::copy::Entity")]
+    Copy,
+}
+
+impl Related<super::book_author::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookAuthor.def()
+    }
+}
+
+impl Related<super::book_change::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookChange.def()
+    }
+}
+
+impl Related<super::read_through::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReadThrough.def()
+    }
+}
+
+impl Related<super::book_review::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookReview.def()
+    }
+}
+
+impl Related<super::book_note::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookNote.def()
+    }
+}
+
+impl Related<super::book_highlight::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookHighlight.def()
+    }
+}
+
+impl Related<super::book_external_id::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookExternalId.def()
+    }
+}
+
+impl Related<super::book_file::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookFile.def()
+    }
+}
+
+impl Related<super::book_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookSeries.def()
+    }
+}
+
+impl Related<super::book_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookTag.def()
+    }
+}
+
+impl Related<super::shelf_book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ShelfBook.def()
+    }
+}
+
+impl Related<super::copy::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Copy.def()
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
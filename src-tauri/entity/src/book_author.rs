@@ -0,0 +1,51 @@
+// Entity Layer - BookAuthor SeaORMモデル（本と著者の関連）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// book_authors テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "book_authors")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub book_id: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub author_id: i32,
+    /// 役割（author / translator / editor / illustrator）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub role: String,
+    /// 本の著者一覧での表示順（0始まり）
+    pub position: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+    #[sea_orm(
+        belongs_to = "super::author::Entity",
+        from = "Column::AuthorId",
+        to = "super::author::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Author,
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl Related<super::author::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Author.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
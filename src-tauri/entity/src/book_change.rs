@@ -0,0 +1,43 @@
+// Entity Layer - BookChange SeaORMモデル（本の変更履歴）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// book_changes テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "book_changes")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub book_id: i32,
+    /// 本ごとの履歴番号（1から連番）
+    pub revision: i32,
+    /// 操作（create / update / delete / restore / revert / undo / merge）
+    pub action: String,
+    /// 変更のきっかけ（user / import / merge / history）
+    pub source: String,
+    /// 変更前の書誌情報（JSON。作成時は None）
+    pub before_state: Option<String>,
+    /// 変更後の書誌情報（JSON）
+    pub after_state: String,
+    pub changed_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,35 @@
+// Entity Layer - BookExternalId SeaORMモデル（取り込み元のサービスでの本のID）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// book_external_ids テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "book_external_ids")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub book_id: i32,
+    /// 取り込み元（calibre など）
+    pub source: String,
+    pub external_id: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,39 @@
+// Entity Layer - BookFile SeaORMモデル（本の電子書籍ファイル）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// book_files テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "book_files")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub book_id: i32,
+    /// ファイルの絶対パス
+    #[sea_orm(unique)]
+    pub path: String,
+    /// ファイル形式（epub / pdf）
+    pub format: String,
+    /// アプリが管理するコピーか（削除時にファイルも削除する）
+    pub managed: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,41 @@
+// Entity Layer - BookHighlight SeaORMモデル（電子書籍から取り込んだハイライト・メモ）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// book_highlights テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "book_highlights")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub book_id: i32,
+    /// highlight / note
+    pub kind: String,
+    pub content: String,
+    pub page: Option<i32>,
+    pub location_start: Option<i32>,
+    pub location_end: Option<i32>,
+    /// 端末上で作成された日時（端末のローカル時刻）
+    pub clipped_at: Option<DateTime>,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,29 @@
+// Entity Layer - Borrower SeaORMモデル（本を貸す相手の連絡先）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// borrowers テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "borrowers")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::loan::Entity")]
+    Loan,
+}
+
+impl Related<super::loan::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Loan.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
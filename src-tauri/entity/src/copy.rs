@@ -0,0 +1,63 @@
+// Entity Layer - Copy SeaORMモデル（所有している本の1冊ごとの記録）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// copies テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "copies")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub book_id: i32,
+    /// 版型（hardcover / paperback / ebook / audiobook）
+    pub format: String,
+    /// 状態（new / like_new / very_good / good / fair / poor）
+    pub condition: Option<String>,
+    pub acquired_on: Option<Date>,
+    /// 価格（通貨の最小単位。例: 12.99 USD は 1299）
+    pub price_amount: Option<i64>,
+    /// 価格の通貨（ISO 4217 の通貨コード）
+    pub price_currency: Option<String>,
+    pub location_id: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+    #[sea_orm(
+        belongs_to = "super::location::Entity",
+        from = "Column::LocationId",
+        to = "super::location::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Location,
+    #[sea_orm(has_many = "super::loan::Entity")]
+    Loan,
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl Related<super::location::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Location.def()
+    }
+}
+
+impl Related<super::loan::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Loan.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
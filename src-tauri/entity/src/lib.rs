@@ -1,3 +1,23 @@
 //! Entity Layer - SeaORM Models
 
+pub mod author;
 pub mod book;
+pub mod book_author;
+pub mod book_change;
+pub mod book_external_id;
+pub mod book_file;
+pub mod book_highlight;
+pub mod book_note;
+pub mod book_review;
+pub mod book_series;
+pub mod book_tag;
+pub mod borrower;
+pub mod copy;
+pub mod loan;
+pub mod location;
+pub mod read_through;
+pub mod reading_session;
+pub mod series;
+pub mod shelf;
+pub mod shelf_book;
+pub mod tag;
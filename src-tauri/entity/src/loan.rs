@@ -0,0 +1,51 @@
+// Entity Layer - Loan SeaORMモデル（所有している本の1冊の貸出）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// loans テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "loans")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub copy_id: i32,
+    pub borrower_id: i32,
+    pub lent_on: Date,
+    /// 返却期限
+    pub due_on: Option<Date>,
+    /// 返却日（貸出中はなし）
+    pub returned_on: Option<Date>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::copy::Entity",
+        from = "Column::CopyId",
+        to = "super::copy::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Copy,
+    #[sea_orm(
+        belongs_to = "super::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "super::borrower::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Borrower,
+}
+
+impl Related<super::copy::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Copy.def()
+    }
+}
+
+impl Related<super::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
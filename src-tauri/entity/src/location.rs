@@ -0,0 +1,38 @@
+// Entity Layer - Location SeaORMモデル（部屋・本棚・棚の階層）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// locations テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "locations")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    /// 階層（room / bookcase / shelf）
+    pub kind: String,
+    /// 1つ上の階層の場所（部屋はなし）
+    pub parent_id: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_delete = "Cascade"
+    )]
+    Parent,
+    #[sea_orm(has_many = "super::copy::Entity")]
+    Copy,
+}
+
+impl Related<super::copy::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Copy.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
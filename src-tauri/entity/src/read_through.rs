@@ -0,0 +1,46 @@
+// Entity Layer - ReadThrough SeaORMモデル（通読）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// read_throughs テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "read_throughs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub book_id: i32,
+    /// 状態（want_to_read / reading / finished / abandoned）
+    pub status: String,
+    pub started_on: Option<Date>,
+    pub finished_on: Option<Date>,
+    /// この通読での評価（0.5〜5.0、0.5刻み）
+    pub rating: Option<f64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+    #[sea_orm(has_many = "super::reading_session::Entity")]
+    ReadingSession,
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl Related<super::reading_session::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReadingSession.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
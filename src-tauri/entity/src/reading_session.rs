@@ -0,0 +1,38 @@
+// Entity Layer - ReadingSession SeaORMモデル（読書記録）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// reading_sessions テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "reading_sessions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub read_through_id: i32,
+    pub read_on: Date,
+    /// 読み終えたページ（percent とどちらか一方）
+    pub page: Option<i32>,
+    /// 読み終えた割合（page とどちらか一方）
+    pub percent: Option<f64>,
+    pub duration_minutes: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::read_through::Entity",
+        from = "Column::ReadThroughId",
+        to = "super::read_through::Column::Id",
+        on_delete = "Cascade"
+    )]
+    ReadThrough,
+}
+
+impl Related<super::read_through::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReadThrough.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
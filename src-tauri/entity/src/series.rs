@@ -0,0 +1,36 @@
+// Entity Layer - Series SeaORMモデル（漫画・ライトノベルなどのシリーズ）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// series テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "series")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub name: String,
+    /// 読み（かな）
+    pub reading: Option<String>,
+    /// 並び替え用のキー（読み、なければ名前を正規化したもの）
+    pub sort_key: String,
+    /// 刊行状況（ongoing / completed）
+    pub status: String,
+    /// 刊行済みの巻数
+    pub volume_count: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::book_series::Entity")]
+    BookSeries,
+}
+
+impl Related<super::book_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookSeries.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,32 @@
+// Entity Layer - Shelf SeaORMモデル（ユーザーが作成する本棚）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// shelves テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "shelves")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub name: String,
+    /// 本棚一覧での表示順（0始まり）
+    pub position: i32,
+    /// スマート本棚の絞り込み条件（JSON。手動の本棚はなし）
+    pub smart_filter: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::shelf_book::Entity")]
+    ShelfBook,
+}
+
+impl Related<super::shelf_book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ShelfBook.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,48 @@
+// Entity Layer - ShelfBook SeaORMモデル（本棚に並べた本）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// shelf_books テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "shelf_books")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub shelf_id: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub book_id: i32,
+    /// 本棚での並び順（0始まり）
+    pub position: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::shelf::Entity",
+        from = "Column::ShelfId",
+        to = "super::shelf::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Shelf,
+    #[sea_orm(
+        belongs_to = "super::book::Entity",
+        from = "Column::BookId",
+        to = "super::book::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Book,
+}
+
+impl Related<super::shelf::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Shelf.def()
+    }
+}
+
+impl Related<super::book::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Book.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,29 @@
+// Entity Layer - Tag SeaORMモデル（階層付きのタグ）
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// tags テーブルのSeaORMモデル
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "tags")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    /// 親からのパス（`親/子` 形式）
+    #[sea_orm(unique)]
+    pub name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::book_tag::Entity")]
+    BookTag,
+}
+
+impl Related<super::book_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookTag.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,28 +1,128 @@
 // Application State - 依存性注入コンテナ
 
-use library::{BookService, build_book_service};
-use sea_orm::DatabaseConnection;
-use settings::{SettingsService, build_settings_service};
+use crate::database::{LibraryDatabaseRelocator, setup_database};
+use library::{
+    BookService, CatalogService, CopyService, CoverService, DatabaseHandle, DuplicateService,
+    EbookService, HighlightService, LoanService, ReadingService, ReviewService, SeriesService,
+    ShelfService, TagService, build_book_service, build_catalog_service, build_copy_service,
+    build_cover_service, build_duplicate_service, build_ebook_service, build_highlight_service,
+    build_loan_service, build_reading_service, build_review_service, build_series_service,
+    build_shelf_service, build_tag_service,
+};
+use settings::{SettingsService, build_settings_service, build_settings_service_with_relocator};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// 起動中にゴミ箱の保存日数を過ぎた本を削除する間隔
+const TRASH_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
 
 /// アプリケーション全体の状態を保持する構造体
 pub struct AppState {
     pub book_service: Arc<BookService>,
+    pub catalog_service: Arc<CatalogService>,
+    pub reading_service: Arc<ReadingService>,
+    pub review_service: Arc<ReviewService>,
+    pub highlight_service: Arc<HighlightService>,
+    pub series_service: Arc<SeriesService>,
+    pub tag_service: Arc<TagService>,
+    pub shelf_service: Arc<ShelfService>,
+    pub copy_service: Arc<CopyService>,
+    pub loan_service: Arc<LoanService>,
+    pub cover_service: Arc<CoverService>,
+    pub ebook_service: Arc<EbookService>,
+    pub duplicate_service: Arc<DuplicateService>,
     pub settings_service: Arc<SettingsService>,
 }
 
 impl AppState {
-    pub fn new(db: DatabaseConnection, config_dir: PathBuf, default_db_dir: PathBuf) -> Self {
+    /// 設定されたデータベースディレクトリでデータベースに接続し、各サービスを構築する
+    /// （起動時にゴミ箱の保存日数を過ぎた本を削除し、本の並び替え用のキーを作り直す）
+    ///
+    /// 表紙画像は `covers_dir` に、電子書籍ファイルのコピーは `books_dir` に保存する
+    /// （データベースディレクトリの変更では移動しない）
+    pub async fn initialize(
+        config_dir: PathBuf,
+        default_db_dir: PathBuf,
+        covers_dir: PathBuf,
+        books_dir: PathBuf,
+    ) -> anyhow::Result<Self> {
+        // 保存済みの設定からデータベースディレクトリを取得
+        let saved_settings = build_settings_service(config_dir.clone(), default_db_dir.clone());
+        let database_directory = saved_settings
+            .get_database_settings()
+            .await?
+            .database_directory;
+
+        // データベース接続を確立
+        let db = setup_database(&PathBuf::from(database_directory)).await?;
+        let db_handle = DatabaseHandle::new(db);
+
         // Library Context（統合ヘルパー関数）
-        let book_service = build_book_service(db);
+        let book_service = build_book_service(db_handle.clone());
+        let catalog_service = build_catalog_service(db_handle.clone());
+        let reading_service = build_reading_service(db_handle.clone());
+        let review_service = build_review_service(db_handle.clone());
+        let highlight_service = build_highlight_service(db_handle.clone());
+        let series_service = build_series_service(db_handle.clone());
+        let tag_service = build_tag_service(db_handle.clone());
+        let shelf_service = build_shelf_service(db_handle.clone());
+        let copy_service = build_copy_service(db_handle.clone());
+        let loan_service = build_loan_service(db_handle.clone());
+        let cover_service = build_cover_service(db_handle.clone(), covers_dir.clone());
+        let ebook_service = build_ebook_service(db_handle.clone(), covers_dir.clone(), books_dir);
+        let duplicate_service = build_duplicate_service(db_handle.clone(), covers_dir);
+
+        // ゴミ箱に移してから保存日数を過ぎた本を完全に削除する
+        let retention_days = saved_settings.get_trash_settings().await?.retention_days;
+        book_service.purge_expired_trash(retention_days).await?;
+
+        // 並び替え用のキーを現在の正規化の規則で作り直す（移行直後の本や規則の変更に追従する）
+        book_service.refresh_sort_keys().await?;
 
         // Settings Context（統合ヘルパー関数）
-        let settings_service = build_settings_service(config_dir, default_db_dir);
+        // データベースディレクトリの変更時は接続を切り替える
+        let settings_service = build_settings_service_with_relocator(
+            config_dir,
+            default_db_dir,
+            Arc::new(LibraryDatabaseRelocator::new(db_handle)),
+        );
 
-        Self {
+        Ok(Self {
             book_service,
+            catalog_service,
+            reading_service,
+            review_service,
+            highlight_service,
+            series_service,
+            tag_service,
+            shelf_service,
+            copy_service,
+            loan_service,
+            cover_service,
+            ebook_service,
+            duplicate_service,
             settings_service,
+        })
+    }
+
+    /// 起動中も定期的にゴミ箱の保存日数を過ぎた本を削除するタスク
+    ///
+    /// 保存日数は毎回設定から読み直す（起動時の削除は `initialize` で行う）。
+    pub fn trash_purge_task(&self) -> impl Future<Output = ()> + Send + 'static {
+        let book_service = self.book_service.clone();
+        let settings_service = self.settings_service.clone();
+        async move {
+            let mut interval = tokio::time::interval(TRASH_PURGE_INTERVAL);
+            // 最初の tick はすぐに完了するため読み飛ばす
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                // 失敗した場合は次の間隔で再試行する
+                if let Ok(trash) = settings_service.get_trash_settings().await {
+                    let _ = book_service.purge_expired_trash(trash.retention_days).await;
+                }
+            }
         }
     }
 }
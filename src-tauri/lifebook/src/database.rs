@@ -1,14 +1,117 @@
+use async_trait::async_trait;
+use library::DatabaseHandle;
 use migration::Migrator;
-use sea_orm::{Database, DatabaseConnection, DbErr};
+use sea_orm::sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, RuntimeErr, SqlxSqliteConnector,
+    Statement,
+};
 use sea_orm_migration::MigratorTrait;
+use settings::{DatabaseRelocator, PreparedDatabase};
+use std::path::{Path, PathBuf};
 
-pub async fn setup_database() -> Result<DatabaseConnection, DbErr> {
-    // SQLiteデータベースを使用（アプリのデータディレクトリに保存）
-    let database_url = "sqlite://lifebook.db?mode=rwc";
-    let db = Database::connect(database_url).await?;
+/// データベースファイル名
+pub const DATABASE_FILE_NAME: &str = "lifebook.db";
+
+/// データベースディレクトリ内のデータベースファイルのパスを取得
+pub fn database_file_path(database_directory: &Path) -> PathBuf {
+    database_directory.join(DATABASE_FILE_NAME)
+}
+
+pub async fn setup_database(database_directory: &Path) -> Result<DatabaseConnection, DbErr> {
+    // 設定されたデータベースディレクトリが存在しない場合は作成
+    std::fs::create_dir_all(database_directory).map_err(|e| {
+        DbErr::Custom(format!(
+            "Failed to create database directory {}: {}",
+            database_directory.display(),
+            e
+        ))
+    })?;
+
+    // SQLiteデータベースを使用（設定されたデータベースディレクトリに保存）
+    let db = connect(&database_file_path(database_directory)).await?;
 
     // マイグレーションを実行
     Migrator::up(&db, None).await?;
 
     Ok(db)
 }
+
+/// データベースファイルに接続する（存在しない場合は作成）
+///
+/// パスをURLに埋め込まないため、`?` や `#` を含むパスや UTF-8 でないパスも扱える
+async fn connect(database_file: &Path) -> Result<DatabaseConnection, DbErr> {
+    let options = SqliteConnectOptions::new()
+        .filename(database_file)
+        .create_if_missing(true);
+    // SeaORM の SQLite 接続の既定と同じく接続は1本にする
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| DbErr::Conn(RuntimeErr::SqlxError(e)))?;
+    Ok(SqlxSqliteConnector::from_sqlx_sqlite_pool(pool))
+}
+
+/// データベースディレクトリ変更時に、データベースを移設して接続を切り替える
+pub struct LibraryDatabaseRelocator {
+    handle: DatabaseHandle,
+}
+
+impl LibraryDatabaseRelocator {
+    pub fn new(handle: DatabaseHandle) -> Self {
+        Self { handle }
+    }
+}
+
+#[async_trait]
+impl DatabaseRelocator for LibraryDatabaseRelocator {
+    async fn prepare(&self, from: &Path, to: &Path) -> anyhow::Result<Box<dyn PreparedDatabase>> {
+        let source = database_file_path(from);
+        let target = database_file_path(to);
+        if source == target {
+            return Ok(Box::new(PreparedLibraryDatabase { handle: None }));
+        }
+
+        tokio::fs::create_dir_all(to).await?;
+
+        // 移設先にデータベースが既にある場合はそれを開く（上書きしない）
+        // 移設元のファイルはバックアップとして残す
+        if !target.exists() {
+            // 稼働中の接続から VACUUM INTO で整合性のあるコピーを作成
+            let target_name = target.to_str().ok_or_else(|| {
+                anyhow::anyhow!("Database path is not valid UTF-8: {}", target.display())
+            })?;
+            self.handle
+                .connection()
+                .execute(Statement::from_sql_and_values(
+                    DatabaseBackend::Sqlite,
+                    "VACUUM INTO ?",
+                    [target_name.into()],
+                ))
+                .await?;
+        }
+
+        // 移設先で接続し、マイグレーションを実行しておく（切り替えは設定の保存後）
+        let db = setup_database(to).await?;
+        Ok(Box::new(PreparedLibraryDatabase {
+            handle: Some((self.handle.clone(), db)),
+        }))
+    }
+}
+
+/// 移設先で接続済みのデータベース（移設先が同じ場合は切り替えない）
+struct PreparedLibraryDatabase {
+    handle: Option<(DatabaseHandle, DatabaseConnection)>,
+}
+
+#[async_trait]
+impl PreparedDatabase for PreparedLibraryDatabase {
+    async fn switch(self: Box<Self>) {
+        if let Some((handle, db)) = self.handle {
+            // 切り替え後は古い接続を使わないため、閉じる際のエラーは無視する
+            let previous = handle.replace(db);
+            let _ = previous.close().await;
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::app_state::AppState;
 use async_graphql::*;
-use library::{BookMutation, BookQuery};
+use library::{LibraryMutation, LibraryQuery};
 use settings::{SettingsMutation, SettingsQuery};
 
 /// クエリのルート
@@ -12,8 +12,8 @@ pub struct QueryRoot;
 #[Object]
 impl QueryRoot {
     /// Libraryコンテキストへのアクセス
-    async fn library(&self) -> BookQuery {
-        BookQuery
+    async fn library(&self) -> LibraryQuery {
+        LibraryQuery::default()
     }
 
     /// Settingsコンテキストへのアクセス
@@ -29,8 +29,8 @@ pub struct MutationRoot;
 #[Object]
 impl MutationRoot {
     /// Libraryコンテキストのミューテーション
-    async fn library(&self) -> BookMutation {
-        BookMutation
+    async fn library(&self) -> LibraryMutation {
+        LibraryMutation::default()
     }
 
     /// Settingsコンテキストのミューテーション
@@ -45,6 +45,18 @@ pub type AppSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
 pub fn build_schema(app_state: AppState) -> AppSchema {
     Schema::build(QueryRoot, MutationRoot, EmptySubscription)
         .data(app_state.book_service)
+        .data(app_state.catalog_service)
+        .data(app_state.reading_service)
+        .data(app_state.review_service)
+        .data(app_state.highlight_service)
+        .data(app_state.series_service)
+        .data(app_state.tag_service)
+        .data(app_state.shelf_service)
+        .data(app_state.copy_service)
+        .data(app_state.loan_service)
+        .data(app_state.cover_service)
+        .data(app_state.ebook_service)
+        .data(app_state.duplicate_service)
         .data(app_state.settings_service)
         .finish()
 }
@@ -5,18 +5,34 @@ pub mod database;
 pub mod graphql_schema;
 
 use app_state::AppState;
-use database::setup_database;
 use graphql_schema::build_schema;
+use library::{COVER_PROTOCOL, CoverService};
+use std::sync::Arc;
 use tauri::Manager;
+use tauri::http::{Response, StatusCode, header};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        // 表紙画像を webview に配信する（`coverUrl` / `thumbnailUrl` のURL）
+        .register_asynchronous_uri_scheme_protocol(COVER_PROTOCOL, |ctx, request, responder| {
+            let Some(cover_service) = ctx.app_handle().try_state::<Arc<CoverService>>() else {
+                responder.respond(cover_response(StatusCode::SERVICE_UNAVAILABLE, Vec::new()));
+                return;
+            };
+            let cover_service = cover_service.inner().clone();
+            let path = request.uri().path().to_string();
+            tauri::async_runtime::spawn(async move {
+                let response = match cover_service.read_cover_resource(&path).await {
+                    Ok(Some(bytes)) => cover_response(StatusCode::OK, bytes),
+                    Ok(None) => cover_response(StatusCode::NOT_FOUND, Vec::new()),
+                    Err(_) => cover_response(StatusCode::INTERNAL_SERVER_ERROR, Vec::new()),
+                };
+                responder.respond(response);
+            });
+        })
         .setup(|app| {
-            // データベース接続を確立
-            let db = tauri::async_runtime::block_on(setup_database())?;
-
             // 設定ディレクトリを取得
             let config_dir = app
                 .path()
@@ -29,9 +45,22 @@ pub fn run() {
                 .app_local_data_dir()
                 .map_err(|e| format!("Failed to get local data directory: {}", e))?;
             let default_db_dir = local_data_dir.join("databases");
+            let covers_dir = local_data_dir.join("covers");
+            let books_dir = local_data_dir.join("books");
+
+            // アプリケーションステートを初期化（設定されたディレクトリのデータベースに接続）
+            let app_state = tauri::async_runtime::block_on(AppState::initialize(
+                config_dir,
+                default_db_dir,
+                covers_dir,
+                books_dir,
+            ))?;
 
-            // アプリケーションステートを初期化
-            let app_state = AppState::new(db, config_dir, default_db_dir);
+            // 起動中も定期的にゴミ箱の保存日数を過ぎた本を削除する
+            tauri::async_runtime::spawn(app_state.trash_purge_task());
+
+            // 表紙画像のプロトコルから使うサービスを管理
+            app.manage(app_state.cover_service.clone());
 
             // GraphQLスキーマを構築
             let schema = build_schema(app_state);
@@ -65,6 +94,23 @@ async fn graphql_request(
     serde_json::to_string(&response).map_err(|e| format!("Failed to serialize response: {}", e))
 }
 
+/// 表紙画像のプロトコルのレスポンスを作成
+///
+/// 画像のURLは内容のハッシュを含み、同じURLの内容は変わらないため長期間キャッシュさせる
+fn cover_response(status: StatusCode, body: Vec<u8>) -> Response<Vec<u8>> {
+    let builder = Response::builder().status(status);
+    let builder = if status == StatusCode::OK {
+        builder
+            .header(header::CONTENT_TYPE, "image/jpeg")
+            .header(header::CACHE_CONTROL, "public, max-age=31536000, immutable")
+    } else {
+        builder
+    };
+    builder
+        .body(body)
+        .expect("cover response headers must be valid")
+}
+
 /// データベース接続状態を確認するTauriコマンド
 #[tauri::command]
 fn get_db_status() -> String {
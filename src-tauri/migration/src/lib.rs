@@ -1,12 +1,56 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20250108_000001_create_book_table;
+mod m20261018_000001_create_book_search_index;
+mod m20261018_000002_add_isbn_to_books;
+mod m20261018_000003_create_authors;
+mod m20261018_000004_create_reading_tracking;
+mod m20261018_000005_create_reviews_and_notes;
+mod m20261018_000006_create_highlights;
+mod m20261018_000007_create_book_external_ids;
+mod m20261018_000008_create_series;
+mod m20261018_000009_create_tags_and_shelves;
+mod m20261018_000010_add_cover_to_books;
+mod m20261018_000011_create_book_files;
+mod m20261018_000012_add_deleted_at_to_books;
+mod m20261018_000013_create_book_changes;
+mod m20261018_000014_add_version_to_books;
+mod m20261018_000015_add_timestamps_to_books;
+mod m20261018_000016_add_readings_to_books;
+mod m20261018_000017_add_classification_to_books;
+mod m20261018_000018_create_copies_and_locations;
+mod m20261018_000019_create_loans;
+mod m20261018_000020_add_citation_key_to_books;
+mod m20261018_000021_add_publisher_to_books;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20250108_000001_create_book_table::Migration)]
+        vec![
+            Box::new(m20250108_000001_create_book_table::Migration),
+            Box::new(m20261018_000001_create_book_search_index::Migration),
+            Box::new(m20261018_000002_add_isbn_to_books::Migration),
+            Box::new(m20261018_000003_create_authors::Migration),
+            Box::new(m20261018_000004_create_reading_tracking::Migration),
+            Box::new(m20261018_000005_create_reviews_and_notes::Migration),
+            Box::new(m20261018_000006_create_highlights::Migration),
+            Box::new(m20261018_000007_create_book_external_ids::Migration),
+            Box::new(m20261018_000008_create_series::Migration),
+            Box::new(m20261018_000009_create_tags_and_shelves::Migration),
+            Box::new(m20261018_000010_add_cover_to_books::Migration),
+            Box::new(m20261018_000011_create_book_files::Migration),
+            Box::new(m20261018_000012_add_deleted_at_to_books::Migration),
+            Box::new(m20261018_000013_create_book_changes::Migration),
+            Box::new(m20261018_000014_add_version_to_books::Migration),
+            Box::new(m20261018_000015_add_timestamps_to_books::Migration),
+            Box::new(m20261018_000016_add_readings_to_books::Migration),
+            Box::new(m20261018_000017_add_classification_to_books::Migration),
+            Box::new(m20261018_000018_create_copies_and_locations::Migration),
+            Box::new(m20261018_000019_create_loans::Migration),
+            Box::new(m20261018_000020_add_citation_key_to_books::Migration),
+            Box::new(m20261018_000021_add_publisher_to_books::Migration),
+        ]
     }
 }
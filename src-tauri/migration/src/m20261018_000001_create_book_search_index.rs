@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // books を外部コンテンツとする FTS5 仮想テーブル
+        // 空白で区切らない日本語も部分一致で検索できるよう、3文字ずつの索引（trigram）にする
+        db.execute_unprepared(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS books_fts USING fts5(
+                title,
+                author,
+                description,
+                content = 'books',
+                content_rowid = 'id',
+                tokenize = 'trigram remove_diacritics 1'
+            );
+            "#,
+        )
+        .await?;
+
+        // books の変更に追従して索引を更新するトリガー
+        db.execute_unprepared(
+            r#"
+            CREATE TRIGGER IF NOT EXISTS books_fts_after_insert AFTER INSERT ON books BEGIN
+                INSERT INTO books_fts (rowid, title, author, description)
+                VALUES (new.id, new.title, new.author, new.description);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS books_fts_after_delete AFTER DELETE ON books BEGIN
+                INSERT INTO books_fts (books_fts, rowid, title, author, description)
+                VALUES ('delete', old.id, old.title, old.author, old.description);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS books_fts_after_update AFTER UPDATE ON books BEGIN
+                INSERT INTO books_fts (books_fts, rowid, title, author, description)
+                VALUES ('delete', old.id, old.title, old.author, old.description);
+                INSERT INTO books_fts (rowid, title, author, description)
+                VALUES (new.id, new.title, new.author, new.description);
+            END;
+            "#,
+        )
+        .await?;
+
+        // 既存の本を索引に取り込む
+        db.execute_unprepared("INSERT INTO books_fts (books_fts) VALUES ('rebuild');")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                DROP TRIGGER IF EXISTS books_fts_after_update;
+                DROP TRIGGER IF EXISTS books_fts_after_delete;
+                DROP TRIGGER IF EXISTS books_fts_after_insert;
+                DROP TABLE IF EXISTS books_fts;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .add_column(ColumnDef::new(Book::Isbn).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同じISBNの本を重複登録しない（NULLは重複可）
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_books_isbn")
+                    .table(Book::Table)
+                    .col(Book::Isbn)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_books_isbn")
+                    .table(Book::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .drop_column(Book::Isbn)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "isbn")]
+    Isbn,
+}
@@ -0,0 +1,147 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Author::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Author::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Author::Name).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同名の著者は1人として扱う
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_authors_name")
+                    .table(Author::Table)
+                    .col(Author::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookAuthor::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(BookAuthor::BookId).integer().not_null())
+                    .col(ColumnDef::new(BookAuthor::AuthorId).integer().not_null())
+                    .col(ColumnDef::new(BookAuthor::Role).string().not_null())
+                    .col(
+                        ColumnDef::new(BookAuthor::Position)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(BookAuthor::BookId)
+                            .col(BookAuthor::AuthorId)
+                            .col(BookAuthor::Role),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_authors_book_id")
+                            .from(BookAuthor::Table, BookAuthor::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_authors_author_id")
+                            .from(BookAuthor::Table, BookAuthor::AuthorId)
+                            .to(Author::Table, Author::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 著者から本を引くためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_authors_author_id")
+                    .table(BookAuthor::Table)
+                    .col(BookAuthor::AuthorId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 既存の著者名（自由記述）を著者テーブルへ移行
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            INSERT OR IGNORE INTO authors (name)
+            SELECT DISTINCT trim(author) FROM books
+            WHERE author IS NOT NULL AND trim(author) <> '';
+
+            INSERT INTO book_authors (book_id, author_id, role, position)
+            SELECT books.id, authors.id, 'author', 0
+            FROM books
+            JOIN authors ON authors.name = trim(books.author);
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookAuthor::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Author::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Author {
+    #[sea_orm(iden = "authors")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "name")]
+    Name,
+}
+
+#[derive(DeriveIden)]
+enum BookAuthor {
+    #[sea_orm(iden = "book_authors")]
+    Table,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "author_id")]
+    AuthorId,
+    #[sea_orm(iden = "role")]
+    Role,
+    #[sea_orm(iden = "position")]
+    Position,
+}
@@ -0,0 +1,140 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReadThrough::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReadThrough::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ReadThrough::BookId).integer().not_null())
+                    .col(ColumnDef::new(ReadThrough::Status).string().not_null())
+                    .col(ColumnDef::new(ReadThrough::StartedOn).date())
+                    .col(ColumnDef::new(ReadThrough::FinishedOn).date())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_read_throughs_book_id")
+                            .from(ReadThrough::Table, ReadThrough::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_read_throughs_book_id")
+                    .table(ReadThrough::Table)
+                    .col(ReadThrough::BookId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReadingSession::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReadingSession::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ReadingSession::ReadThroughId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ReadingSession::ReadOn).date().not_null())
+                    .col(ColumnDef::new(ReadingSession::Page).integer())
+                    .col(ColumnDef::new(ReadingSession::Percent).double())
+                    .col(ColumnDef::new(ReadingSession::DurationMinutes).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reading_sessions_read_through_id")
+                            .from(ReadingSession::Table, ReadingSession::ReadThroughId)
+                            .to(ReadThrough::Table, ReadThrough::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reading_sessions_read_through_id")
+                    .table(ReadingSession::Table)
+                    .col(ReadingSession::ReadThroughId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReadingSession::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ReadThrough::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ReadThrough {
+    #[sea_orm(iden = "read_throughs")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "status")]
+    Status,
+    #[sea_orm(iden = "started_on")]
+    StartedOn,
+    #[sea_orm(iden = "finished_on")]
+    FinishedOn,
+}
+
+#[derive(DeriveIden)]
+enum ReadingSession {
+    #[sea_orm(iden = "reading_sessions")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "read_through_id")]
+    ReadThroughId,
+    #[sea_orm(iden = "read_on")]
+    ReadOn,
+    #[sea_orm(iden = "page")]
+    Page,
+    #[sea_orm(iden = "percent")]
+    Percent,
+    #[sea_orm(iden = "duration_minutes")]
+    DurationMinutes,
+}
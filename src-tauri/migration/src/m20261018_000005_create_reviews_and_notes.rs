@@ -0,0 +1,174 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookReview::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookReview::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BookReview::BookId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(BookReview::Rating).double())
+                    .col(ColumnDef::new(BookReview::Body).text())
+                    .col(
+                        ColumnDef::new(BookReview::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BookReview::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_reviews_book_id")
+                            .from(BookReview::Table, BookReview::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookNote::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookNote::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BookNote::BookId).integer().not_null())
+                    .col(ColumnDef::new(BookNote::Body).text().not_null())
+                    .col(
+                        ColumnDef::new(BookNote::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BookNote::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_notes_book_id")
+                            .from(BookNote::Table, BookNote::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_notes_book_id")
+                    .table(BookNote::Table)
+                    .col(BookNote::BookId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 通読ごとの評価
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ReadThrough::Table)
+                    .add_column(ColumnDef::new(ReadThrough::Rating).double())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ReadThrough::Table)
+                    .drop_column(ReadThrough::Rating)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BookNote::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BookReview::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ReadThrough {
+    #[sea_orm(iden = "read_throughs")]
+    Table,
+    #[sea_orm(iden = "rating")]
+    Rating,
+}
+
+#[derive(DeriveIden)]
+enum BookReview {
+    #[sea_orm(iden = "book_reviews")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "rating")]
+    Rating,
+    #[sea_orm(iden = "body")]
+    Body,
+    #[sea_orm(iden = "created_at")]
+    CreatedAt,
+    #[sea_orm(iden = "updated_at")]
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BookNote {
+    #[sea_orm(iden = "book_notes")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "body")]
+    Body,
+    #[sea_orm(iden = "created_at")]
+    CreatedAt,
+    #[sea_orm(iden = "updated_at")]
+    UpdatedAt,
+}
@@ -0,0 +1,94 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookHighlight::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookHighlight::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BookHighlight::BookId).integer().not_null())
+                    .col(ColumnDef::new(BookHighlight::Kind).string().not_null())
+                    .col(ColumnDef::new(BookHighlight::Content).text().not_null())
+                    .col(ColumnDef::new(BookHighlight::Page).integer())
+                    .col(ColumnDef::new(BookHighlight::LocationStart).integer())
+                    .col(ColumnDef::new(BookHighlight::LocationEnd).integer())
+                    .col(ColumnDef::new(BookHighlight::ClippedAt).date_time())
+                    .col(
+                        ColumnDef::new(BookHighlight::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_highlights_book_id")
+                            .from(BookHighlight::Table, BookHighlight::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 再取り込み時の重複判定（本・種類・位置）に使う
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_highlights_book_id_location")
+                    .table(BookHighlight::Table)
+                    .col(BookHighlight::BookId)
+                    .col(BookHighlight::LocationStart)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookHighlight::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum BookHighlight {
+    #[sea_orm(iden = "book_highlights")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "kind")]
+    Kind,
+    #[sea_orm(iden = "content")]
+    Content,
+    #[sea_orm(iden = "page")]
+    Page,
+    #[sea_orm(iden = "location_start")]
+    LocationStart,
+    #[sea_orm(iden = "location_end")]
+    LocationEnd,
+    #[sea_orm(iden = "clipped_at")]
+    ClippedAt,
+    #[sea_orm(iden = "created_at")]
+    CreatedAt,
+}
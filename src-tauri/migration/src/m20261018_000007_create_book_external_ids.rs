@@ -0,0 +1,80 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookExternalId::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookExternalId::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BookExternalId::BookId).integer().not_null())
+                    .col(ColumnDef::new(BookExternalId::Source).string().not_null())
+                    .col(
+                        ColumnDef::new(BookExternalId::ExternalId)
+                            .string()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_external_ids_book_id")
+                            .from(BookExternalId::Table, BookExternalId::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 取り込み元のIDは1冊の本にのみ対応する
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_external_ids_source_external_id")
+                    .table(BookExternalId::Table)
+                    .col(BookExternalId::Source)
+                    .col(BookExternalId::ExternalId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookExternalId::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum BookExternalId {
+    #[sea_orm(iden = "book_external_ids")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "source")]
+    Source,
+    #[sea_orm(iden = "external_id")]
+    ExternalId,
+}
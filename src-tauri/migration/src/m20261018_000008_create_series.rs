@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Series::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Series::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Series::Name)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(Series::Reading).string())
+                    .col(ColumnDef::new(Series::SortKey).string().not_null())
+                    .col(ColumnDef::new(Series::Status).string().not_null())
+                    .col(ColumnDef::new(Series::VolumeCount).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_series_sort_key")
+                    .table(Series::Table)
+                    .col(Series::SortKey)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 本は1つのシリーズにのみ属する
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookSeries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookSeries::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BookSeries::BookId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(BookSeries::SeriesId).integer().not_null())
+                    .col(ColumnDef::new(BookSeries::Volume).double().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_series_book_id")
+                            .from(BookSeries::Table, BookSeries::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_series_series_id")
+                            .from(BookSeries::Table, BookSeries::SeriesId)
+                            .to(Series::Table, Series::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_series_series_id_volume")
+                    .table(BookSeries::Table)
+                    .col(BookSeries::SeriesId)
+                    .col(BookSeries::Volume)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookSeries::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Series::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Series {
+    #[sea_orm(iden = "series")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "name")]
+    Name,
+    #[sea_orm(iden = "reading")]
+    Reading,
+    #[sea_orm(iden = "sort_key")]
+    SortKey,
+    #[sea_orm(iden = "status")]
+    Status,
+    #[sea_orm(iden = "volume_count")]
+    VolumeCount,
+}
+
+#[derive(DeriveIden)]
+enum BookSeries {
+    #[sea_orm(iden = "book_series")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "series_id")]
+    SeriesId,
+    #[sea_orm(iden = "volume")]
+    Volume,
+}
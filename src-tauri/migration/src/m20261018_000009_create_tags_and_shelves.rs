@@ -0,0 +1,193 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タグ名は `親/子` 形式の階層のパス
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tag::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Tag::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Tag::Name).string().not_null().unique_key())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookTag::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(BookTag::BookId).integer().not_null())
+                    .col(ColumnDef::new(BookTag::TagId).integer().not_null())
+                    .primary_key(Index::create().col(BookTag::BookId).col(BookTag::TagId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_tags_book_id")
+                            .from(BookTag::Table, BookTag::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_tags_tag_id")
+                            .from(BookTag::Table, BookTag::TagId)
+                            .to(Tag::Table, Tag::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_tags_tag_id")
+                    .table(BookTag::Table)
+                    .col(BookTag::TagId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // スマート本棚は絞り込み条件（JSON）を持ち、本を手動で並べない
+        manager
+            .create_table(
+                Table::create()
+                    .table(Shelf::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Shelf::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Shelf::Name).string().not_null().unique_key())
+                    .col(ColumnDef::new(Shelf::Position).integer().not_null())
+                    .col(ColumnDef::new(Shelf::SmartFilter).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ShelfBook::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ShelfBook::ShelfId).integer().not_null())
+                    .col(ColumnDef::new(ShelfBook::BookId).integer().not_null())
+                    .col(ColumnDef::new(ShelfBook::Position).integer().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(ShelfBook::ShelfId)
+                            .col(ShelfBook::BookId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_shelf_books_shelf_id")
+                            .from(ShelfBook::Table, ShelfBook::ShelfId)
+                            .to(Shelf::Table, Shelf::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_shelf_books_book_id")
+                            .from(ShelfBook::Table, ShelfBook::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_shelf_books_book_id")
+                    .table(ShelfBook::Table)
+                    .col(ShelfBook::BookId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ShelfBook::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Shelf::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(BookTag::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Tag::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Tag {
+    #[sea_orm(iden = "tags")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "name")]
+    Name,
+}
+
+#[derive(DeriveIden)]
+enum BookTag {
+    #[sea_orm(iden = "book_tags")]
+    Table,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "tag_id")]
+    TagId,
+}
+
+#[derive(DeriveIden)]
+enum Shelf {
+    #[sea_orm(iden = "shelves")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "name")]
+    Name,
+    #[sea_orm(iden = "position")]
+    Position,
+    #[sea_orm(iden = "smart_filter")]
+    SmartFilter,
+}
+
+#[derive(DeriveIden)]
+enum ShelfBook {
+    #[sea_orm(iden = "shelf_books")]
+    Table,
+    #[sea_orm(iden = "shelf_id")]
+    ShelfId,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "position")]
+    Position,
+}
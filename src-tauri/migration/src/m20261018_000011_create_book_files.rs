@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 本の電子書籍ファイル（元の場所、またはアプリが管理するコピーのパス）
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookFile::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookFile::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BookFile::BookId).integer().not_null())
+                    .col(
+                        ColumnDef::new(BookFile::Path)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(BookFile::Format).string().not_null())
+                    .col(
+                        ColumnDef::new(BookFile::Managed)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_files_book_id")
+                            .from(BookFile::Table, BookFile::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_files_book_id")
+                    .table(BookFile::Table)
+                    .col(BookFile::BookId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookFile::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum BookFile {
+    #[sea_orm(iden = "book_files")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "path")]
+    Path,
+    #[sea_orm(iden = "format")]
+    Format,
+    #[sea_orm(iden = "managed")]
+    Managed,
+}
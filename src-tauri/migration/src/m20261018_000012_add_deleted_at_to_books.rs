@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ゴミ箱に移した日時（未設定の本のみ通常の一覧・検索の対象にする）
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .add_column(ColumnDef::new(Book::DeletedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        // ゴミ箱の一覧・保存期間を過ぎた本の削除用
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_books_deleted_at")
+                    .table(Book::Table)
+                    .col(Book::DeletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_books_deleted_at")
+                    .table(Book::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .drop_column(Book::DeletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "deleted_at")]
+    DeletedAt,
+}
@@ -0,0 +1,92 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 本の変更履歴（変更のきっかけと変更前後の書誌情報をJSONで保持し、本ごとに1から履歴番号を振る。同時編集検出用の books.version とは別の番号）
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookChange::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookChange::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BookChange::BookId).integer().not_null())
+                    .col(ColumnDef::new(BookChange::Revision).integer().not_null())
+                    .col(ColumnDef::new(BookChange::Action).string().not_null())
+                    .col(ColumnDef::new(BookChange::Source).string().not_null())
+                    .col(ColumnDef::new(BookChange::BeforeState).text().null())
+                    .col(ColumnDef::new(BookChange::AfterState).text().not_null())
+                    .col(
+                        ColumnDef::new(BookChange::ChangedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_changes_book_id")
+                            .from(BookChange::Table, BookChange::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_changes_book_id_revision")
+                    .table(BookChange::Table)
+                    .col(BookChange::BookId)
+                    .col(BookChange::Revision)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookChange::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum BookChange {
+    #[sea_orm(iden = "book_changes")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "revision")]
+    Revision,
+    #[sea_orm(iden = "action")]
+    Action,
+    #[sea_orm(iden = "source")]
+    Source,
+    #[sea_orm(iden = "before_state")]
+    BeforeState,
+    #[sea_orm(iden = "after_state")]
+    AfterState,
+    #[sea_orm(iden = "changed_at")]
+    ChangedAt,
+}
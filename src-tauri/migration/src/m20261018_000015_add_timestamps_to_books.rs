@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 作成日時・更新日時（SQLite では現在日時を既定値にして列を追加できないため、
+        // 固定値で追加してから既存の行を埋める）
+        for column in [Book::CreatedAt, Book::UpdatedAt] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Book::Table)
+                        .add_column(
+                            ColumnDef::new(column)
+                                .timestamp_with_time_zone()
+                                .not_null()
+                                .default("1970-01-01T00:00:00+00:00"),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // 既存の本は変更履歴の最初と最後の日時を使い、履歴がない本は移行時の日時とする
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            UPDATE books SET
+                created_at = coalesce(
+                    (SELECT min(changed_at) FROM book_changes WHERE book_changes.book_id = books.id),
+                    strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+                ),
+                updated_at = coalesce(
+                    (SELECT max(changed_at) FROM book_changes WHERE book_changes.book_id = books.id),
+                    strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+                );
+            "#,
+        )
+        .await?;
+
+        // 最近追加・更新した本の一覧用
+        for (name, column) in [
+            ("idx_books_created_at", Book::CreatedAt),
+            ("idx_books_updated_at", Book::UpdatedAt),
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(name)
+                        .table(Book::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for name in ["idx_books_created_at", "idx_books_updated_at"] {
+            manager
+                .drop_index(Index::drop().name(name).table(Book::Table).to_owned())
+                .await?;
+        }
+
+        for column in [Book::CreatedAt, Book::UpdatedAt] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Book::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "created_at")]
+    CreatedAt,
+    #[sea_orm(iden = "updated_at")]
+    UpdatedAt,
+}
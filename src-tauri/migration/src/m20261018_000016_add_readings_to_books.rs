@@ -0,0 +1,109 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タイトル・著者の読み（かな）
+        for column in [Book::TitleReading, Book::AuthorReading] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Book::Table)
+                        .add_column(ColumnDef::new(column).string())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // 並び替え用のキー（読み、なければタイトル・著者名を正規化したもの）
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .add_column(
+                        ColumnDef::new(Book::TitleSortKey)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .add_column(ColumnDef::new(Book::AuthorSortKey).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 既存の本は仮のキー（小文字にしたタイトル・著者名）で埋める
+        // （正規化したキーへの作り直しはアプリの起動時に行う）
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "UPDATE books SET title_sort_key = lower(title), author_sort_key = lower(author);",
+        )
+        .await?;
+
+        for (name, column) in [
+            ("idx_books_title_sort_key", Book::TitleSortKey),
+            ("idx_books_author_sort_key", Book::AuthorSortKey),
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(name)
+                        .table(Book::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for name in ["idx_books_title_sort_key", "idx_books_author_sort_key"] {
+            manager
+                .drop_index(Index::drop().name(name).table(Book::Table).to_owned())
+                .await?;
+        }
+
+        for column in [
+            Book::TitleReading,
+            Book::AuthorReading,
+            Book::TitleSortKey,
+            Book::AuthorSortKey,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Book::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "title_reading")]
+    TitleReading,
+    #[sea_orm(iden = "author_reading")]
+    AuthorReading,
+    #[sea_orm(iden = "title_sort_key")]
+    TitleSortKey,
+    #[sea_orm(iden = "author_sort_key")]
+    AuthorSortKey,
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 日本十進分類法（NDC）・デューイ十進分類法（DDC）の分類記号
+        for column in [Book::Ndc, Book::Ddc] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Book::Table)
+                        .add_column(ColumnDef::new(column).string())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // 分類ごとの絞り込み・集計用（分類記号の前方一致）
+        for (name, column) in [("idx_books_ndc", Book::Ndc), ("idx_books_ddc", Book::Ddc)] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(name)
+                        .table(Book::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for name in ["idx_books_ndc", "idx_books_ddc"] {
+            manager
+                .drop_index(Index::drop().name(name).table(Book::Table).to_owned())
+                .await?;
+        }
+
+        for column in [Book::Ndc, Book::Ddc] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Book::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "ndc")]
+    Ndc,
+    #[sea_orm(iden = "ddc")]
+    Ddc,
+}
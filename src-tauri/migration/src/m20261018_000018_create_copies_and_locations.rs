@@ -0,0 +1,157 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 部屋・本棚・棚の階層（部屋は最上位）
+        manager
+            .create_table(
+                Table::create()
+                    .table(Location::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Location::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Location::Name).string().not_null())
+                    .col(ColumnDef::new(Location::Kind).string().not_null())
+                    .col(ColumnDef::new(Location::ParentId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_locations_parent_id")
+                            .from(Location::Table, Location::ParentId)
+                            .to(Location::Table, Location::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_locations_parent_id")
+                    .table(Location::Table)
+                    .col(Location::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 本の所有している1冊ごとの記録（場所を削除した場合は未設定に戻す）
+        manager
+            .create_table(
+                Table::create()
+                    .table(Copy::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Copy::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Copy::BookId).integer().not_null())
+                    .col(ColumnDef::new(Copy::Format).string().not_null())
+                    .col(ColumnDef::new(Copy::Condition).string())
+                    .col(ColumnDef::new(Copy::AcquiredOn).date())
+                    .col(ColumnDef::new(Copy::PriceAmount).big_integer())
+                    .col(ColumnDef::new(Copy::PriceCurrency).string())
+                    .col(ColumnDef::new(Copy::LocationId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_copies_book_id")
+                            .from(Copy::Table, Copy::BookId)
+                            .to(Book::Table, Book::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_copies_location_id")
+                            .from(Copy::Table, Copy::LocationId)
+                            .to(Location::Table, Location::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_copies_book_id")
+                    .table(Copy::Table)
+                    .col(Copy::BookId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_copies_location_id")
+                    .table(Copy::Table)
+                    .col(Copy::LocationId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Copy::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Location::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Location {
+    #[sea_orm(iden = "locations")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "name")]
+    Name,
+    #[sea_orm(iden = "kind")]
+    Kind,
+    #[sea_orm(iden = "parent_id")]
+    ParentId,
+}
+
+#[derive(DeriveIden)]
+enum Copy {
+    #[sea_orm(iden = "copies")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "book_id")]
+    BookId,
+    #[sea_orm(iden = "format")]
+    Format,
+    #[sea_orm(iden = "condition")]
+    Condition,
+    #[sea_orm(iden = "acquired_on")]
+    AcquiredOn,
+    #[sea_orm(iden = "price_amount")]
+    PriceAmount,
+    #[sea_orm(iden = "price_currency")]
+    PriceCurrency,
+    #[sea_orm(iden = "location_id")]
+    LocationId,
+}
@@ -0,0 +1,153 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Borrower::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Borrower::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Borrower::Name).string().not_null())
+                    .col(ColumnDef::new(Borrower::Email).string())
+                    .col(ColumnDef::new(Borrower::Phone).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 返却済みの貸出も履歴として残す
+        manager
+            .create_table(
+                Table::create()
+                    .table(Loan::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Loan::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Loan::CopyId).integer().not_null())
+                    .col(ColumnDef::new(Loan::BorrowerId).integer().not_null())
+                    .col(ColumnDef::new(Loan::LentOn).date().not_null())
+                    .col(ColumnDef::new(Loan::DueOn).date())
+                    .col(ColumnDef::new(Loan::ReturnedOn).date())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_loans_copy_id")
+                            .from(Loan::Table, Loan::CopyId)
+                            .to(Copy::Table, Copy::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_loans_borrower_id")
+                            .from(Loan::Table, Loan::BorrowerId)
+                            .to(Borrower::Table, Borrower::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loans_copy_id")
+                    .table(Loan::Table)
+                    .col(Loan::CopyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loans_borrower_id")
+                    .table(Loan::Table)
+                    .col(Loan::BorrowerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loans_due_on")
+                    .table(Loan::Table)
+                    .col(Loan::DueOn)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 1冊を同時に貸し出せるのは1件のみ（返却していない貸出に限る部分インデックス）
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_loans_active_copy_id ON loans (copy_id) WHERE returned_on IS NULL;",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Loan::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Borrower::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Copy {
+    #[sea_orm(iden = "copies")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Borrower {
+    #[sea_orm(iden = "borrowers")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "name")]
+    Name,
+    #[sea_orm(iden = "email")]
+    Email,
+    #[sea_orm(iden = "phone")]
+    Phone,
+}
+
+#[derive(DeriveIden)]
+enum Loan {
+    #[sea_orm(iden = "loans")]
+    Table,
+    #[sea_orm(iden = "id")]
+    Id,
+    #[sea_orm(iden = "copy_id")]
+    CopyId,
+    #[sea_orm(iden = "borrower_id")]
+    BorrowerId,
+    #[sea_orm(iden = "lent_on")]
+    LentOn,
+    #[sea_orm(iden = "due_on")]
+    DueOn,
+    #[sea_orm(iden = "returned_on")]
+    ReturnedOn,
+}
@@ -0,0 +1,59 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 引用キー（初めて引用文献として書き出したときに割り当て、以後は変えない）
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .add_column(ColumnDef::new(Book::CitationKey).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite は列の追加で UNIQUE 制約を付けられないため、一意インデックスで重複を防ぐ
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_books_citation_key")
+                    .table(Book::Table)
+                    .col(Book::CitationKey)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_books_citation_key")
+                    .table(Book::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Book::Table)
+                    .drop_column(Book::CitationKey)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Book {
+    #[sea_orm(iden = "books")]
+    Table,
+    #[sea_orm(iden = "citation_key")]
+    CitationKey,
+}
@@ -7,7 +7,7 @@ export type DeleteBookMutationVariables = Types.Exact<{
 }>;
 
 
-export type DeleteBookMutation = { __typename?: 'MutationRoot', library: { __typename?: 'BookMutation', deleteBook: boolean } };
+export type DeleteBookMutation = { __typename?: 'MutationRoot', library: { __typename?: 'LibraryMutation', deleteBook: boolean } };
 
 
 export const DeleteBookDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteBook"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"id"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"library"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteBook"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"id"},"value":{"kind":"Variable","name":{"kind":"Name","value":"id"}}}]}]}}]}}]} as unknown as DocumentNode<DeleteBookMutation, DeleteBookMutationVariables>;
\ No newline at end of file
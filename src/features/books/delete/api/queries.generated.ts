@@ -7,7 +7,7 @@ export type CheckBookExistsQueryVariables = Types.Exact<{
 }>;
 
 
-export type CheckBookExistsQuery = { __typename?: 'QueryRoot', library: { __typename?: 'BookQuery', book?: { __typename?: 'BookDto', id: number } | null } };
+export type CheckBookExistsQuery = { __typename?: 'QueryRoot', library: { __typename?: 'LibraryQuery', book?: { __typename?: 'BookDto', id: number } | null } };
 
 
 export const CheckBookExistsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"CheckBookExists"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"id"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"library"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"book"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"id"},"value":{"kind":"Variable","name":{"kind":"Name","value":"id"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]}}]} as unknown as DocumentNode<CheckBookExistsQuery, CheckBookExistsQueryVariables>;
\ No newline at end of file
@@ -2,12 +2,28 @@ import { apolloClient } from "~/lib/apollo-client"
 import type { BookCardFragment } from "../../shared/fragments/book.generated"
 import { GetAllBooksDocument } from "./queries.generated"
 
+/** 1回のリクエストで取得する件数（サーバー側の上限） */
+const PAGE_SIZE = 100
+
 /**
  * すべての書籍を取得
+ * サーバーはページング形式で返すため、最後のページまで順に取得する
  */
 export async function getAllBooks(): Promise<BookCardFragment[]> {
-  const result = await apolloClient.query({
-    query: GetAllBooksDocument,
-  })
-  return (result.data?.library.books as BookCardFragment[]) || []
+  const books: BookCardFragment[] = []
+  let after: string | null | undefined = null
+
+  do {
+    const result = await apolloClient.query({
+      query: GetAllBooksDocument,
+      variables: { first: PAGE_SIZE, after },
+    })
+    const connection = result.data?.library.books
+    if (!connection) break
+
+    books.push(...(connection.nodes as BookCardFragment[]))
+    after = connection.pageInfo.hasNextPage ? connection.pageInfo.endCursor : null
+  } while (after)
+
+  return books
 }
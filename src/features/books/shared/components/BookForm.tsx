@@ -13,6 +13,8 @@ interface BookFormProps {
   submitLabel: string
   submittingLabel: string
   cancelTo: string
+  /** 編集を始めた時点の本の版数（更新フォームのみ。同時編集の検出に使う） */
+  expectedVersion?: number
 }
 
 export function BookForm({
@@ -22,6 +24,7 @@ export function BookForm({
   submitLabel,
   submittingLabel,
   cancelTo,
+  expectedVersion,
 }: BookFormProps) {
   const values: Partial<BookFormValues> = initialValues ?? {}
 
@@ -32,6 +35,10 @@ export function BookForm({
       </DialogHeader>
 
       <Form method="post" className="space-y-4">
+        {expectedVersion !== undefined && (
+          <input type="hidden" name="expectedVersion" defaultValue={expectedVersion} />
+        )}
+
         <div className="space-y-2">
           <Label htmlFor="title">
             タイトル <span className="text-destructive">*</span>
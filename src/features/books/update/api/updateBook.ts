@@ -1,8 +1,11 @@
+import { CombinedGraphQLErrors } from "@apollo/client"
 import { apolloClient } from "~/lib/apollo-client"
 import { UpdateBookDocument } from "./mutations.generated"
 
 /**
  * 書籍を更新
+ *
+ * expectedVersion には編集を始めた時点の本の版数を渡す（その後に他の画面で更新されていれば失敗する）
  */
 export async function updateBook(input: {
   id: number
@@ -10,6 +13,7 @@ export async function updateBook(input: {
   author?: string
   description?: string
   publishedYear?: number
+  expectedVersion: number
 }) {
   try {
     const result = await apolloClient.mutate({
@@ -19,6 +23,17 @@ export async function updateBook(input: {
 
     // Apollo Clientのエラーチェック
     if (result.error) {
+      // 編集中に他の画面で更新された場合は、上書きせずに編集し直してもらう
+      if (
+        CombinedGraphQLErrors.is(result.error) &&
+        result.error.errors.some((error) => error.extensions?.code === "CONFLICT")
+      ) {
+        return {
+          error: new Error(
+            "この本は他の画面で更新されました。最新の内容を表示しているので、もう一度編集してください",
+          ),
+        }
+      }
       return {
         error: result.error,
       }
@@ -38,9 +38,17 @@ export async function clientAction({ request, params }: Route.ActionArgs) {
 
   const formData = await request.formData()
   const parsed = parseBookFormData(formData)
+  const expectedVersion = parseInt((formData.get("expectedVersion") as string | null) ?? "", 10)
+  if (Number.isNaN(expectedVersion)) {
+    return {
+      success: false,
+      error: "Book version is required",
+    }
+  }
   const bookData = {
     id: parseInt(params.id, 10),
     ...parsed,
+    expectedVersion,
   }
 
   const result = await updateBook(bookData)
@@ -74,12 +82,15 @@ export default function UpdateBook({ loaderData, actionData }: Route.ComponentPr
         </Alert>
       )}
 
+      {/* 他の画面で更新されて読み込み直した場合は、最新の内容でフォームを作り直す */}
       <BookForm
+        key={book.version}
         title="書籍編集"
         isSubmitting={isSubmitting}
         submitLabel="更新"
         submittingLabel="更新中..."
         cancelTo={`/books/${book.id}`}
+        expectedVersion={book.version}
         initialValues={{
           title: book.title,
           author: book.author || undefined,
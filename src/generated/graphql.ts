@@ -13,6 +13,42 @@ export type Scalars = {
   Boolean: { input: boolean; output: boolean; }
   Int: { input: number; output: number; }
   Float: { input: number; output: number; }
+  /**
+   * Implement the DateTime<Utc> scalar
+   *
+   * The input/output is a string in RFC3339 format.
+   */
+  DateTime: { input: any; output: any; }
+  /**
+   * ISO 8601 calendar date without timezone.
+   * Format: %Y-%m-%d
+   *
+   * # Examples
+   *
+   * * `1994-11-13`
+   * * `2000-02-24`
+   */
+  NaiveDate: { input: any; output: any; }
+  /**
+   * ISO 8601 combined date and time without timezone.
+   *
+   * # Examples
+   *
+   * * `2015-07-01T08:59:60.123`,
+   */
+  NaiveDateTime: { input: any; output: any; }
+};
+
+/** 所有している本の1冊の追加 - GraphQL入力用 */
+export type AddCopyInput = {
+  acquiredOn?: InputMaybe<Scalars['NaiveDate']['input']>;
+  bookId: Scalars['Int']['input'];
+  condition?: InputMaybe<BookCopyCondition>;
+  format: BookCopyFormat;
+  /** 置いてある場所（部屋・本棚・棚のいずれか） */
+  locationId?: InputMaybe<Scalars['Int']['input']>;
+  /** 入手時の価格 */
+  price?: InputMaybe<PriceInput>;
 };
 
 /** 表示設定のDTO */
@@ -21,89 +57,1620 @@ export type AppearanceSettingsDto = {
   theme: Scalars['String']['output'];
 };
 
-/** Book DTO - GraphQLレスポンス用 */
+/** 著者 DTO - GraphQLレスポンス用 */
+export type AuthorDto = {
+  __typename?: 'AuthorDto';
+  /** この人物が関わっている本（出版年順） */
+  books: Array<BookDto>;
+  id: Scalars['Int']['output'];
+  name: Scalars['String']['output'];
+};
+
+/** 本の著者（役割付き） DTO - GraphQLレスポンス用 */
+export type BookAuthorDto = {
+  __typename?: 'BookAuthorDto';
+  /** 著者ID */
+  id: Scalars['Int']['output'];
+  name: Scalars['String']['output'];
+  role: BookAuthorRole;
+};
+
+/**
+ * 本の著者の指定 - GraphQL入力用
+ *
+ * 既存の著者は `authorId`、新しい著者は `name` で指定する（同名の著者がいれば再利用する）。
+ */
+export type BookAuthorInput = {
+  authorId?: InputMaybe<Scalars['Int']['input']>;
+  name?: InputMaybe<Scalars['String']['input']>;
+  role?: BookAuthorRole;
+};
+
+/** 本に対する人物の役割 */
+export enum BookAuthorRole {
+  Author = 'AUTHOR',
+  Editor = 'EDITOR',
+  Illustrator = 'ILLUSTRATOR',
+  Translator = 'TRANSLATOR'
+}
+
+/** 本の変更履歴 DTO - GraphQLレスポンス用 */
+export type BookChangeDto = {
+  __typename?: 'BookChangeDto';
+  action: BookChangeKind;
+  bookId: Scalars['Int']['output'];
+  changedAt: Scalars['DateTime']['output'];
+  /** 変更した項目（ゴミ箱への移動・復元では空） */
+  changes: Array<FieldChangeDto>;
+  /** 変更のきっかけ（ユーザーの操作・取り込み・まとめ・変更履歴からの復元） */
+  source: BookChangeOrigin;
+  /** 本ごとの版番号（1から連番。`revertToVersion` で指定する。同時編集検出用の `Book.version` とは別の番号） */
+  version: Scalars['Int']['output'];
+};
+
+/** 変更履歴に記録する操作 */
+export enum BookChangeKind {
+  Create = 'CREATE',
+  /** ゴミ箱に移す */
+  Delete = 'DELETE',
+  /** 他の本をまとめた、または他の本にまとめてゴミ箱に移した */
+  Merge = 'MERGE',
+  /** ゴミ箱から元に戻す */
+  Restore = 'RESTORE',
+  /** 指定した履歴番号の状態への復元 */
+  Revert = 'REVERT',
+  /** 直前の変更の取り消し */
+  Undo = 'UNDO',
+  Update = 'UPDATE'
+}
+
+/** 変更のきっかけ */
+export enum BookChangeOrigin {
+  /** 変更履歴からの取り消し・以前の版への復元 */
+  History = 'HISTORY',
+  /** CSV・Calibre・電子書籍ファイル・Kindle のハイライトなどの取り込み */
+  Import = 'IMPORT',
+  /** 重複した本のまとめ */
+  Merge = 'MERGE',
+  /** ユーザーによる作成・編集・ゴミ箱への移動・復元 */
+  User = 'USER'
+}
+
+/** 分類ごとの本の数 DTO - GraphQLレスポンス用 */
+export type BookClassDto = {
+  __typename?: 'BookClassDto';
+  /** この分類に含まれる本の数（下位の分類を含む） */
+  bookCount: Scalars['Int']['output'];
+  /** 分類記号（類は1桁、綱は2桁、目以下は分類記号） */
+  code: Scalars['String']['output'];
+  /** 分類表での名称（分類表にない分類記号の場合はなし） */
+  name?: Maybe<Scalars['String']['output']>;
+};
+
+export type BookConnection = {
+  __typename?: 'BookConnection';
+  /** A list of edges. */
+  edges: Array<BookEdge>;
+  /** A list of nodes. */
+  nodes: Array<BookDto>;
+  /** Information to aid in pagination. */
+  pageInfo: PageInfo;
+  /** 条件に一致する本の総件数 */
+  totalCount: Scalars['Int']['output'];
+};
+
+/** 所有している本の状態 */
+export enum BookCopyCondition {
+  /** 可 */
+  Fair = 'FAIR',
+  /** 良い */
+  Good = 'GOOD',
+  /** 新品同様 */
+  LikeNew = 'LIKE_NEW',
+  /** 新品 */
+  New = 'NEW',
+  /** 悪い */
+  Poor = 'POOR',
+  /** 非常に良い */
+  VeryGood = 'VERY_GOOD'
+}
+
+/** 所有している本の版型 */
+export enum BookCopyFormat {
+  Audiobook = 'AUDIOBOOK',
+  Ebook = 'EBOOK',
+  Hardcover = 'HARDCOVER',
+  Paperback = 'PAPERBACK'
+}
+
+/** CSVの列（取り込み時の列の対応付け用） */
+export enum BookCsvField {
+  Author = 'AUTHOR',
+  Description = 'DESCRIPTION',
+  /** 取り込み時は無視する */
+  Id = 'ID',
+  Isbn = 'ISBN',
+  PublishedYear = 'PUBLISHED_YEAR',
+  Title = 'TITLE'
+}
+
+/**
+ * Book DTO - GraphQLレスポンス用
+ *
+ * 読書状態などの関連データは presentation 層の `ComplexObject` で解決する。
+ */
 export type BookDto = {
   __typename?: 'BookDto';
   author?: Maybe<Scalars['String']['output']>;
+  /** 著者の読み（かな） */
+  authorReading?: Maybe<Scalars['String']['output']>;
+  /** 著者の並び替え用のキー（読み、なければ著者名を正規化したもの） */
+  authorSortKey?: Maybe<Scalars['String']['output']>;
+  /** 著者・翻訳者などの一覧（表示順） */
+  authors: Array<BookAuthorDto>;
+  /** 所有している冊（登録順。版型・状態・置いてある場所など） */
+  copies: Array<CopyDto>;
+  /** 表紙画像のURL（カスタムプロトコル経由、表紙がない場合はなし） */
+  coverUrl?: Maybe<Scalars['String']['output']>;
+  createdAt: Scalars['DateTime']['output'];
+  /** デューイ十進分類法（DDC）の分類記号 */
+  ddc?: Maybe<Scalars['String']['output']>;
+  /** DDCの分類表での名称（目・綱・類のうち最も詳しいもの） */
+  ddcName?: Maybe<Scalars['String']['output']>;
+  /** ゴミ箱に移した日時（ゴミ箱にない本はなし） */
+  deletedAt?: Maybe<Scalars['DateTime']['output']>;
   description?: Maybe<Scalars['String']['output']>;
+  /** 紐付けた電子書籍ファイル（紐付けた順） */
+  files: Array<BookFileDto>;
+  /** 電子書籍から取り込んだハイライト・メモ（本文中の位置順） */
+  highlights: Array<HighlightDto>;
   id: Scalars['Int']['output'];
+  /** ISBN-13（ハイフンなし） */
+  isbn?: Maybe<Scalars['String']['output']>;
+  /** ISBN-10（ハイフンなし、979で始まるISBNの場合はなし） */
+  isbn10?: Maybe<Scalars['String']['output']>;
+  /** 日本十進分類法（NDC）の分類記号 */
+  ndc?: Maybe<Scalars['String']['output']>;
+  /** NDCの分類表での名称（目・綱・類のうち最も詳しいもの） */
+  ndcName?: Maybe<Scalars['String']['output']>;
+  /** 非公開のメモ（古い順） */
+  notes: Array<NoteDto>;
   publishedYear?: Maybe<Scalars['Int']['output']>;
+  /** 出版社 */
+  publisher?: Maybe<Scalars['String']['output']>;
+  /** 通読の履歴（古い順。再読ごとに1件） */
+  readThroughs: Array<ReadThroughDto>;
+  /** 現在の読書状態（最新の通読の状態。未登録の場合はなし） */
+  readingStatus?: Maybe<BookReadingStatus>;
+  /** 評価・感想 */
+  review?: Maybe<ReviewDto>;
+  /** 属するシリーズと巻数（シリーズに属していない場合はなし） */
+  series?: Maybe<BookSeriesDto>;
+  /** 並べている手動の本棚（表示順。スマート本棚は含まない） */
+  shelves: Array<ShelfDto>;
+  /** 付いているタグ（名前の順） */
+  tags: Array<TagDto>;
+  /** 表紙画像のサムネイル（固定サイズ）のURL */
+  thumbnailUrl?: Maybe<Scalars['String']['output']>;
+  title: Scalars['String']['output'];
+  /** タイトルの読み（かな） */
+  titleReading?: Maybe<Scalars['String']['output']>;
+  /** タイトルの並び替え用のキー（読み、なければタイトルを正規化したもの。英語の先頭の冠詞は除く） */
+  titleSortKey: Scalars['String']['output'];
+  updatedAt: Scalars['DateTime']['output'];
+  /** 版番号（保存するたびに1増える。本を更新するミューテーションの `expectedVersion` に渡す。他で変更されていた場合は CONFLICT エラーになる） */
+  version: Scalars['Int']['output'];
+};
+
+/** An edge in a connection. */
+export type BookEdge = {
+  __typename?: 'BookEdge';
+  /** A cursor for use in pagination */
+  cursor: Scalars['String']['output'];
+  /** The item at the end of the edge */
+  node: BookDto;
+};
+
+/** 本に紐付けた電子書籍ファイル DTO - GraphQLレスポンス用 */
+export type BookFileDto = {
+  __typename?: 'BookFileDto';
+  bookId: Scalars['Int']['output'];
+  format: EbookFileFormat;
+  id: Scalars['Int']['output'];
+  /** アプリが管理するコピーか（紐付けを外すとファイルも削除する） */
+  managed: Scalars['Boolean']['output'];
+  /** ファイルの絶対パス（このパスでファイルを開く） */
+  path: Scalars['String']['output'];
+};
+
+/** 本一覧の絞り込み条件 - GraphQL入力用 */
+export type BookFilterInput = {
+  /** 著者名（部分一致） */
+  author?: InputMaybe<Scalars['String']['input']>;
+  /** 作成日時の下限（この日時を含む） */
+  createdAtFrom?: InputMaybe<Scalars['DateTime']['input']>;
+  /** 作成日時の上限（この日時を含む） */
+  createdAtTo?: InputMaybe<Scalars['DateTime']['input']>;
+  /** デューイ十進分類法（DDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む） */
+  ddc?: InputMaybe<Scalars['String']['input']>;
+  /** 日本十進分類法（NDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む） */
+  ndc?: InputMaybe<Scalars['String']['input']>;
+  /** 出版年の下限（この年を含む） */
+  publishedYearFrom?: InputMaybe<Scalars['Int']['input']>;
+  /** 出版年の上限（この年を含む） */
+  publishedYearTo?: InputMaybe<Scalars['Int']['input']>;
+  /** タイトル（部分一致） */
+  titleContains?: InputMaybe<Scalars['String']['input']>;
+  /** 更新日時の下限（この日時を含む） */
+  updatedAtFrom?: InputMaybe<Scalars['DateTime']['input']>;
+  /** 更新日時の上限（この日時を含む） */
+  updatedAtTo?: InputMaybe<Scalars['DateTime']['input']>;
+};
+
+/** 取り込んだ抜き書きの種類 */
+export enum BookHighlightKind {
+  Highlight = 'HIGHLIGHT',
+  Note = 'NOTE'
+}
+
+/** 変更履歴で差分を表示する本の項目 */
+export enum BookHistoryField {
+  /** 著者（翻訳者などは役割を添える） */
+  Authors = 'AUTHORS',
+  AuthorReading = 'AUTHOR_READING',
+  /** デューイ十進分類法（DDC）の分類記号 */
+  Ddc = 'DDC',
+  Description = 'DESCRIPTION',
+  Isbn = 'ISBN',
+  /** 日本十進分類法（NDC）の分類記号 */
+  Ndc = 'NDC',
+  PublishedYear = 'PUBLISHED_YEAR',
+  Publisher = 'PUBLISHER',
+  Title = 'TITLE',
+  TitleReading = 'TITLE_READING'
+}
+
+/** 一括取り込みの結果 DTO - GraphQLレスポンス用 */
+export type BookImportReportDto = {
+  __typename?: 'BookImportReportDto';
+  /** 登録した本 */
+  books: Array<BookDto>;
+  /** 登録を確定したか（エラーのある行があり、部分的な取り込みを許可していない場合は false） */
+  committed: Scalars['Boolean']['output'];
+  errors: Array<ImportRowErrorDto>;
+  /** 登録した本の件数 */
+  importedCount: Scalars['Int']['output'];
+};
+
+/** 場所の階層 */
+export enum BookLocationKind {
+  /** 本棚（部屋に置く） */
+  Bookcase = 'BOOKCASE',
+  /** 部屋（最上位） */
+  Room = 'ROOM',
+  /** 棚（本棚に置く） */
+  Shelf = 'SHELF'
+}
+
+/** 本をまとめる際に値を選べる項目 */
+export enum BookMergeField {
+  /** 著者（表示用の著者名と、翻訳者などを含む著者一覧） */
+  Authors = 'AUTHORS',
+  /** 分類記号（NDC・DDC） */
+  Classification = 'CLASSIFICATION',
+  Cover = 'COVER',
+  Description = 'DESCRIPTION',
+  Isbn = 'ISBN',
+  PublishedYear = 'PUBLISHED_YEAR',
+  Publisher = 'PUBLISHER',
+  /** タイトル・著者の読み（並び替えの位置も変わる） */
+  Readings = 'READINGS',
+  Title = 'TITLE'
+}
+
+/** 読書の状態 */
+export enum BookReadingStatus {
+  Abandoned = 'ABANDONED',
+  Finished = 'FINISHED',
+  Reading = 'READING',
+  WantToRead = 'WANT_TO_READ'
+}
+
+/**
+ * 全文検索結果 DTO - GraphQLレスポンス用
+ *
+ * 強調箇所は `<mark>` で囲まれる（本文はHTMLエスケープされない）
+ */
+export type BookSearchResultDto = {
+  __typename?: 'BookSearchResultDto';
+  /** 一致箇所を強調した著者名 */
+  authorHighlight?: Maybe<Scalars['String']['output']>;
+  book: BookDto;
+  /** 一致箇所周辺の説明文の抜粋 */
+  descriptionSnippet?: Maybe<Scalars['String']['output']>;
+  /** 関連度（大きいほど関連が高い。3文字未満の語だけで検索した場合は0） */
+  score: Scalars['Float']['output'];
+  /** 一致箇所を強調したタイトル */
+  titleHighlight: Scalars['String']['output'];
+};
+
+/** 本が属するシリーズと巻数 DTO - GraphQLレスポンス用 */
+export type BookSeriesDto = {
+  __typename?: 'BookSeriesDto';
+  series: SeriesDto;
+  /** 巻数（1.5 などの小数を含む） */
+  volume: Scalars['Float']['output'];
+};
+
+/** 本一覧の並び替え項目 */
+export enum BookSortField {
+  /** 著者の読み順（並び替え用のキー） */
+  Author = 'AUTHOR',
+  /** 作成日時（最近追加した本） */
+  CreatedAt = 'CREATED_AT',
+  Id = 'ID',
+  PublishedYear = 'PUBLISHED_YEAR',
+  /** タイトルの読み順（並び替え用のキー） */
+  Title = 'TITLE',
+  /** 更新日時（最近編集した本） */
+  UpdatedAt = 'UPDATED_AT'
+}
+
+/** 本一覧の並び替え条件 - GraphQL入力用 */
+export type BookSortInput = {
+  direction?: SortDirection;
+  field?: BookSortField;
+};
+
+/**
+ * 貸出相手 DTO - GraphQLレスポンス用
+ *
+ * 貸出の履歴は presentation 層の `ComplexObject` で解決する。
+ */
+export type BorrowerDto = {
+  __typename?: 'BorrowerDto';
+  email?: Maybe<Scalars['String']['output']>;
+  id: Scalars['Int']['output'];
+  /** この相手への貸出の履歴（新しい順） */
+  loans: Array<LoanDto>;
+  name: Scalars['String']['output'];
+  phone?: Maybe<Scalars['String']['output']>;
+};
+
+/** Calibre からの取り込み結果の1冊分 DTO - GraphQLレスポンス用 */
+export type CalibreImportItemDto = {
+  __typename?: 'CalibreImportItemDto';
+  /** 新規登録・更新・変更なし・失敗（変更なしは SKIP） */
+  action: ExternalImportAction;
+  author?: Maybe<Scalars['String']['output']>;
+  /** 対応付けた本のID */
+  bookId?: Maybe<Scalars['Int']['output']>;
+  /** Calibre での本のID */
+  calibreId: Scalars['Int']['output'];
+  /** 行った変更の説明 */
+  changes: Array<Scalars['String']['output']>;
+  /** 失敗の理由・無視した値の説明 */
+  message?: Maybe<Scalars['String']['output']>;
+  /** 出版社（本の出版社として保存する） */
+  publisher?: Maybe<Scalars['String']['output']>;
+  /** シリーズ名（同じ名前のシリーズに巻として登録する） */
+  series?: Maybe<Scalars['String']['output']>;
+  /** シリーズでの巻数 */
+  seriesIndex?: Maybe<Scalars['Float']['output']>;
+  /** タグ（同じ名前のタグとして本に付ける） */
+  tags: Array<Scalars['String']['output']>;
   title: Scalars['String']['output'];
 };
 
-export type BookMutation = {
-  __typename?: 'BookMutation';
+/** Calibre からの取り込み結果 DTO - GraphQLレスポンス用 */
+export type CalibreImportReportDto = {
+  __typename?: 'CalibreImportReportDto';
+  createdCount: Scalars['Int']['output'];
+  failedCount: Scalars['Int']['output'];
+  items: Array<CalibreImportItemDto>;
+  /** 登録済みで変更がなかった本の数 */
+  unchangedCount: Scalars['Int']['output'];
+  updatedCount: Scalars['Int']['output'];
+};
+
+/** 引用文献の書き出し形式 */
+export enum CitationFormat {
+  /** BibTeX（`@book` エントリ、LaTeX の特殊文字はエスケープ済み） */
+  Bibtex = 'BIBTEX',
+  /** CSL-JSON（Pandoc・Zotero などの引用処理系向け） */
+  CslJson = 'CSL_JSON',
+  /** RIS（EndNote・Zotero などで読み込める形式） */
+  Ris = 'RIS'
+}
+
+/** 分類法 */
+export enum ClassificationSystem {
+  /** デューイ十進分類法 */
+  Ddc = 'DDC',
+  /** 日本十進分類法 */
+  Ndc = 'NDC'
+}
+
+/** 取り込み結果の1項目 DTO - GraphQLレスポンス用 */
+export type ClippingImportEntryDto = {
+  __typename?: 'ClippingImportEntryDto';
+  author?: Maybe<Scalars['String']['output']>;
+  /** この項目のために本を新しく作成したか */
+  bookCreated: Scalars['Boolean']['output'];
+  /** 対応付けた本のID */
+  bookId?: Maybe<Scalars['Int']['output']>;
+  highlightId?: Maybe<Scalars['Int']['output']>;
+  /** ファイル内での順番（1始まり） */
+  index: Scalars['Int']['output'];
+  /** 読み飛ばし・失敗の理由 */
+  message?: Maybe<Scalars['String']['output']>;
+  status: ClippingImportStatus;
+  title?: Maybe<Scalars['String']['output']>;
+};
+
+/** 取り込み結果（項目ごと） */
+export enum ClippingImportStatus {
+  /** 取り込み済みのため読み飛ばした */
+  Duplicate = 'DUPLICATE',
+  /** 解析・保存に失敗した */
+  Failed = 'FAILED',
+  /** 新しく取り込んだ */
+  Imported = 'IMPORTED',
+  /** 取り込み対象外（ブックマーク・本文のない項目） */
+  Skipped = 'SKIPPED'
+}
+
+/**
+ * 所有している本の1冊 DTO - GraphQLレスポンス用
+ *
+ * 本・置いてある場所の経路は presentation 層の `ComplexObject` で解決する。
+ */
+export type CopyDto = {
+  __typename?: 'CopyDto';
+  acquiredOn?: Maybe<Scalars['NaiveDate']['output']>;
+  /** 本（ゴミ箱にある場合はなし） */
+  book?: Maybe<BookDto>;
+  bookId: Scalars['Int']['output'];
+  condition?: Maybe<BookCopyCondition>;
+  /** 貸出中の貸出（貸し出していない場合はなし） */
+  currentLoan?: Maybe<LoanDto>;
+  format: BookCopyFormat;
+  id: Scalars['Int']['output'];
+  /** 貸出の履歴（新しい順） */
+  loans: Array<LoanDto>;
+  /** 置いてある場所（部屋・本棚・棚のいずれか） */
+  locationId?: Maybe<Scalars['Int']['output']>;
+  /** 置いてある場所の部屋からの経路（例: 書斎 > 壁の本棚 > 上段。場所が未設定の場合は空） */
+  locationPath: Array<LocationDto>;
+  /** 入手時の価格 */
+  price?: Maybe<PriceDto>;
+};
+
+/** ヘッダー名と列の対応 - GraphQL入力用（自動判定より優先する） */
+export type CsvColumnMappingInput = {
+  field: BookCsvField;
+  /** CSVのヘッダー名（大文字・小文字は区別しない） */
+  header: Scalars['String']['input'];
+};
+
+/** データベース設定のDTO */
+export type DatabaseSettingsDto = {
+  __typename?: 'DatabaseSettingsDto';
+  databaseDirectory: Scalars['String']['output'];
+};
+
+/** 重複している可能性がある本のグループ DTO - GraphQLレスポンス用 */
+export type DuplicateGroupDto = {
+  __typename?: 'DuplicateGroupDto';
+  /** グループ内の本（ID順） */
+  books: Array<BookDto>;
+  /** 重複である確からしさ（0.0〜1.0。グループ内で最も弱い結び付きの値） */
+  confidence: Scalars['Float']['output'];
+  reasons: Array<DuplicateReason>;
+};
+
+/** 重複とみなした根拠 */
+export enum DuplicateReason {
+  /** ISBNが一致する */
+  SameIsbn = 'SAME_ISBN',
+  /** 出版年が一致する */
+  SamePublishedYear = 'SAME_PUBLISHED_YEAR',
+  /** タイトルが一致する（大文字・小文字、全角・半角、カタカナ・ひらがな、記号・空白の違いは無視する） */
+  SameTitle = 'SAME_TITLE',
+  /** 著者が一致する（表記ゆれを吸収して比較する） */
+  SimilarAuthors = 'SIMILAR_AUTHORS'
+}
+
+/** 電子書籍ファイルの著者（役割付き） DTO - GraphQLレスポンス用 */
+export type EbookCreatorDto = {
+  __typename?: 'EbookCreatorDto';
+  name: Scalars['String']['output'];
+  role: BookAuthorRole;
+};
+
+/** 電子書籍ファイルの形式 */
+export enum EbookFileFormat {
+  Epub = 'EPUB',
+  Pdf = 'PDF'
+}
+
+/** 電子書籍ファイルの取り込み結果 DTO - GraphQLレスポンス用 */
+export type EbookImportResultDto = {
+  __typename?: 'EbookImportResultDto';
+  /** 登録した本、またはISBNが一致した登録済みの本 */
+  book: BookDto;
+  /** 本を新規登録したか */
+  created: Scalars['Boolean']['output'];
+  file: BookFileDto;
+  /** 無視した値の説明 */
+  messages: Array<Scalars['String']['output']>;
+};
+
+/** 電子書籍ファイルの書誌情報 DTO - GraphQLレスポンス用（本の登録フォームの初期値） */
+export type EbookMetadataDto = {
+  __typename?: 'EbookMetadataDto';
+  /** 表示用の著者名（役割が著者の人物をカンマ区切りで連結） */
+  author?: Maybe<Scalars['String']['output']>;
+  /** 著者・翻訳者などの一覧（ファイルでの記載順） */
+  creators: Array<EbookCreatorDto>;
+  /** 紹介文（HTMLはテキストに変換） */
+  description?: Maybe<Scalars['String']['output']>;
+  format: EbookFileFormat;
+  /** 表紙画像が含まれているか */
+  hasCover: Scalars['Boolean']['output'];
+  /** ファイルに記載された識別子 */
+  identifiers: Array<Scalars['String']['output']>;
+  /** 識別子のうち最初の正しいISBN（ISBN-13、ハイフンなし） */
+  isbn?: Maybe<Scalars['String']['output']>;
+  /** 言語（LifeBookには言語がないため保存しない） */
+  language?: Maybe<Scalars['String']['output']>;
+  publishedYear?: Maybe<Scalars['Int']['output']>;
+  /** 出版社（新しく登録する本の出版社として保存する） */
+  publisher?: Maybe<Scalars['String']['output']>;
+  /** タイトル（ファイルにない場合はファイル名） */
+  title: Scalars['String']['output'];
+};
+
+/** 取り込み時の1冊ごとの処理 */
+export enum ExternalImportAction {
+  /** 本を新しく登録する */
+  Create = 'CREATE',
+  /** 解析・検証・保存に失敗した */
+  Fail = 'FAIL',
+  /** 登録済みで追加する情報がない・ファイル内で重複している */
+  Skip = 'SKIP',
+  /** 登録済みの本に不足している情報を追加する */
+  Update = 'UPDATE'
+}
+
+/** 取り込み結果の1冊分 DTO - GraphQLレスポンス用 */
+export type ExternalImportItemDto = {
+  __typename?: 'ExternalImportItemDto';
+  action: ExternalImportAction;
+  author?: Maybe<Scalars['String']['output']>;
+  /** 対応付けた本のID（新規登録の場合は登録後のID。試行時はなし） */
+  bookId?: Maybe<Scalars['Int']['output']>;
+  /** 行う変更の説明 */
+  changes: Array<Scalars['String']['output']>;
+  /** ISBN-13（ハイフンなし） */
+  isbn?: Maybe<Scalars['String']['output']>;
+  /** ファイル内の行番号（1始まり、ヘッダーを含む） */
+  line: Scalars['Int']['output'];
+  /** 読み飛ばし・失敗の理由 */
+  message?: Maybe<Scalars['String']['output']>;
+  /** 星の数（0.5刻みに丸めたもの） */
+  rating?: Maybe<Scalars['Float']['output']>;
+  readingStatus?: Maybe<BookReadingStatus>;
+  /** 取り込み元の棚・タグ（同じ名前のタグとして本に付ける） */
+  shelves: Array<Scalars['String']['output']>;
+  title?: Maybe<Scalars['String']['output']>;
+};
+
+/** 他サービスからの取り込み結果 DTO - GraphQLレスポンス用 */
+export type ExternalImportReportDto = {
+  __typename?: 'ExternalImportReportDto';
+  createdCount: Scalars['Int']['output'];
+  /** 試行のみで何も保存していないか */
+  dryRun: Scalars['Boolean']['output'];
+  failedCount: Scalars['Int']['output'];
+  items: Array<ExternalImportItemDto>;
+  skippedCount: Scalars['Int']['output'];
+  updatedCount: Scalars['Int']['output'];
+};
+
+/** 取り込み元のサービス */
+export enum ExternalLibrarySource {
+  /** Goodreads の goodreads_library_export.csv */
+  Goodreads = 'GOODREADS',
+  /** StoryGraph の書き出しCSV */
+  Storygraph = 'STORYGRAPH'
+}
+
+/** 1項目の変更前後の値 DTO - GraphQLレスポンス用 */
+export type FieldChangeDto = {
+  __typename?: 'FieldChangeDto';
+  after?: Maybe<Scalars['String']['output']>;
+  before?: Maybe<Scalars['String']['output']>;
+  field: BookHistoryField;
+};
+
+/** 一般設定のDTO */
+export type GeneralSettingsDto = {
+  __typename?: 'GeneralSettingsDto';
+  language: Scalars['String']['output'];
+};
+
+/** ハイライト DTO - GraphQLレスポンス用 */
+export type HighlightDto = {
+  __typename?: 'HighlightDto';
+  bookId: Scalars['Int']['output'];
+  /** 端末上で作成された日時（端末のローカル時刻） */
+  clippedAt?: Maybe<Scalars['NaiveDateTime']['output']>;
+  content: Scalars['String']['output'];
+  createdAt: Scalars['DateTime']['output'];
+  id: Scalars['Int']['output'];
+  kind: BookHighlightKind;
+  locationEnd?: Maybe<Scalars['Int']['output']>;
+  locationStart?: Maybe<Scalars['Int']['output']>;
+  page?: Maybe<Scalars['Int']['output']>;
+};
+
+/** 取り込めなかった行 DTO - GraphQLレスポンス用 */
+export type ImportRowErrorDto = {
+  __typename?: 'ImportRowErrorDto';
+  /** ファイル内の行番号（1始まり、ヘッダーを含む） */
+  line: Scalars['Int']['output'];
+  message: Scalars['String']['output'];
+};
+
+/** Kindle「My Clippings.txt」の取り込み結果 DTO - GraphQLレスポンス用 */
+export type KindleImportReportDto = {
+  __typename?: 'KindleImportReportDto';
+  duplicateCount: Scalars['Int']['output'];
+  entries: Array<ClippingImportEntryDto>;
+  failedCount: Scalars['Int']['output'];
+  importedCount: Scalars['Int']['output'];
+  skippedCount: Scalars['Int']['output'];
+};
+
+/** Libraryコンテキストのミューテーション（各ミューテーションを統合） */
+export type LibraryMutation = {
+  __typename?: 'LibraryMutation';
+  /** 手動の本棚の末尾に本を追加する（並べている場合は何もしない） */
+  addBookToShelf: ShelfDto;
+  /** 所有している本の1冊を追加（同じ本を複数冊持つ場合は冊ごとに追加する） */
+  addCopy: CopyDto;
+  /** 登録済みの本に EPUB・PDF ファイルを紐付ける */
+  attachBookFile: BookFileDto;
+  /**
+   * 読書状態を変更（読了・中断後に変更すると再読として記録）
+   *
+   * `date` は読書中なら開始日、読了・中断なら終了日（省略時は今日）
+   */
+  changeReadingStatus: ReadThroughDto;
   /** 新しい本を作成 */
   createBook: BookDto;
-  /** 本を削除 */
+  /** 貸出相手を登録 */
+  createBorrower: BorrowerDto;
+  /** 場所を作成（部屋は `parentId` なし、本棚は部屋に、棚は本棚に作る） */
+  createLocation: LocationDto;
+  /** 本にメモを追加 */
+  createNote: NoteDto;
+  /** 本の評価・感想を作成（1冊につき1件。評価は0.5〜5.0、0.5刻み） */
+  createReview: ReviewDto;
+  /** シリーズを作成（読みはかなで指定し、一覧の並び順に使う） */
+  createSeries: SeriesDto;
+  /** 本棚を作成（`smartFilter` を指定すると条件に一致する本を並べるスマート本棚になる） */
+  createShelf: ShelfDto;
+  /** タグを作成（`親/子` 形式で階層を指定し、祖先のタグがなければ作成する） */
+  createTag: TagDto;
+  /** 本をゴミ箱に移す（`restoreBook` で元に戻せる。保存日数を過ぎると自動で完全に削除する） */
   deleteBook: Scalars['Boolean']['output'];
-  /** 本を更新 */
+  /** 貸出相手を削除（貸出の履歴も削除する。貸出中の本がある場合は削除できない） */
+  deleteBorrower: Scalars['Boolean']['output'];
+  /** 1冊を削除（本そのものは削除しない） */
+  deleteCopy: Scalars['Boolean']['output'];
+  /** ハイライトを削除 */
+  deleteHighlight: Scalars['Boolean']['output'];
+  /** 場所を削除（下位の場所や置いてある本がある場合は削除できない） */
+  deleteLocation: Scalars['Boolean']['output'];
+  /** メモを削除 */
+  deleteNote: Scalars['Boolean']['output'];
+  /** 読書記録を削除 */
+  deleteReadingSession: Scalars['Boolean']['output'];
+  /** 評価・感想を削除 */
+  deleteReview: Scalars['Boolean']['output'];
+  /** シリーズを削除（本は削除せず、シリーズから外す） */
+  deleteSeries: Scalars['Boolean']['output'];
+  /** 本棚を削除（本は削除しない） */
+  deleteShelf: Scalars['Boolean']['output'];
+  /** タグを削除（子孫のタグも削除し、本からも外す） */
+  deleteTag: Scalars['Boolean']['output'];
+  /** ゴミ箱を空にする（ゴミ箱にある本を完全に削除し、削除した件数を返す） */
+  emptyTrash: Scalars['Int']['output'];
+  /**
+   * CSVから本を一括登録する
+   *
+   * 列はヘッダー名で判定し（`exportBooksCsv` と同じ列名のほか、`Year`・`著者` なども可）、
+   * `columnMapping` で任意のヘッダー名を対応付けられる。`id` 列は無視して新しい本として登録する。
+   * エラーのある行が1件でもあれば何も登録しない（`allowPartial` で正常な行のみ登録）
+   */
+  importBooksCsv: BookImportReportDto;
+  /**
+   * Calibre のライブラリ（ディレクトリまたは metadata.db のパス）から本を取り込む
+   *
+   * Calibre での本のIDを記録し、再取り込み時は同じ本を Calibre の内容で更新する。
+   * シリーズは同じ名前のシリーズに巻として登録し、タグは同じ名前のタグとして付け、出版社は本の出版社として保存する
+   */
+  importCalibreLibrary: CalibreImportReportDto;
+  /**
+   * EPUB・PDF ファイルの書誌情報から本を登録し、ファイルを紐付ける
+   *
+   * ISBNが一致する本が登録済みの場合はその本に紐付ける。
+   * `copyFile` の場合はアプリのデータディレクトリにコピーしたファイルを紐付ける。
+   */
+  importEbook: EbookImportResultDto;
+  /**
+   * Goodreads・StoryGraph の書き出しCSVから本・読書状態・評価・感想を取り込む
+   *
+   * 登録済みの本（ISBN、またはタイトルと著者が一致）には不足している情報だけを追加する。
+   * `dryRun` の場合は何も保存せず、行う予定の登録・更新・読み飛ばしを返す
+   */
+  importExternalLibrary: ExternalImportReportDto;
+  /**
+   * Kindle の「My Clippings.txt」の内容からハイライト・メモを取り込む
+   *
+   * 本はタイトルと著者で対応付け、見つからなければ作成する。取り込み済みの項目は重複として読み飛ばす
+   */
+  importKindleClippings: KindleImportReportDto;
+  /** 所有している本の1冊を貸し出す（貸出中の冊は不可。`lentOn` 省略時は今日） */
+  lendCopy: LoanDto;
+  /** 読書記録を追加 */
+  logReadingSession: ReadThroughDto;
+  /**
+   * 重複した本を1冊にまとめる（まとめる本の読書記録・メモ・タグなどは付け替え、本はゴミ箱に移す）
+   *
+   * `fieldChoices` で項目ごとに値を採る本を指定する。指定がない項目はまとめ先の値を採り、
+   * 空の場合はまとめる本の値を `otherIds` の順に採る。
+   */
+  mergeBooks: BookDto;
+  /** 通読の評価を設定（0.5〜5.0、0.5刻み。`null` で削除） */
+  rateReadThrough: ReadThroughDto;
+  /** 本の表紙画像を外す */
+  removeBookCover: BookDto;
+  /** ファイルの紐付けを外す（コピーしたファイルは削除する） */
+  removeBookFile: Scalars['Boolean']['output'];
+  /** 本をシリーズから外す */
+  removeBookFromSeries: Scalars['Boolean']['output'];
+  /** 手動の本棚から本を外す */
+  removeBookFromShelf: ShelfDto;
+  /** タグの名前を変更（子孫のタグも新しい名前の下に移す） */
+  renameTag: TagDto;
+  /** 手動の本棚の本を並べ替える（並べているすべての本のIDを新しい順に指定する） */
+  reorderShelfBooks: ShelfDto;
+  /** 本棚の表示順を並べ替える（すべての本棚のIDを新しい順に指定する） */
+  reorderShelves: Array<ShelfDto>;
+  /** ゴミ箱にある本を元に戻す */
+  restoreBook: BookDto;
+  /** 貸出中の1冊の返却を記録（`returnedOn` 省略時は今日） */
+  returnCopy: LoanDto;
+  /** 本の書誌情報を履歴の指定した版の状態に戻す（通常の編集と同じバリデーションを行う） */
+  revertToVersion: BookDto;
+  /** 本の著者一覧（役割・表示順）を置き換える */
+  setBookAuthors: BookDto;
+  /** Base64 でエンコードされた画像を本の表紙にする（JPEG・PNG・WebP・GIF、20MBまで） */
+  setBookCover: BookDto;
+  /** ローカルのファイルの画像を本の表紙にする（画像はアプリのデータディレクトリにコピーする） */
+  setBookCoverFromFile: BookDto;
+  /** 本をシリーズの巻として登録（巻数は 1・1.5・2 など。別のシリーズに属していた場合は移す） */
+  setBookSeries: BookSeriesDto;
+  /** 貸出中の返却期限を変更（`dueOn` 省略時は期限なしにする） */
+  setLoanDueDate: LoanDto;
+  /** 本にタグを付ける（タグがなければ作成する）。付けた後の本のタグを返す */
+  tagBook: Array<TagDto>;
+  /** 本の最後の変更を取り消す（ゴミ箱への移動・復元、編集、まとめてゴミ箱に移した本を元に戻す。続けて呼ぶと古い変更へ順に取り消す。作成とまとめ先の本のまとめは取り消せない） */
+  undoLastChange: BookDto;
+  /** 本からタグを外す。外した後の本のタグを返す */
+  untagBook: Array<TagDto>;
+  /** 本を更新（`publisher` は空文字列で削除。`expectedVersion` には編集を始めた時点の版を指定し、他で変更されていた場合は CONFLICT エラーになる） */
   updateBook: BookDto;
+  /** 本の分類記号を更新（日本十進分類法・デューイ十進分類法。省略した項目は変更なし、空文字列で削除。`expectedVersion` は `updateBook` と同じ） */
+  updateBookClassification: BookDto;
+  /** 本のタイトル・著者の読みを更新（省略した項目は変更なし、空文字列で削除。`expectedVersion` は `updateBook` と同じ） */
+  updateBookReadings: BookDto;
+  /** 貸出相手の連絡先を更新（省略した項目は変更せず、`null` を指定した項目は削除する） */
+  updateBorrower: BorrowerDto;
+  /** 1冊の情報を更新（省略した項目は変更せず、`null` を指定した項目は未設定に戻す） */
+  updateCopy: CopyDto;
+  /** 場所の名前を変更・別の場所に移す（本棚を別の部屋に、棚を別の本棚に移す場合など） */
+  updateLocation: LocationDto;
+  /** メモを編集 */
+  updateNote: NoteDto;
+  /** 評価・感想を更新（`rating: null` で評価を削除、`body: ""` で感想を削除） */
+  updateReview: ReviewDto;
+  /** シリーズを更新（`reading: ""` で読みを削除、`volumeCount: null` で巻数を削除） */
+  updateSeries: SeriesDto;
+  /** 本棚を更新（`smartFilter` はスマート本棚のみ変更できる） */
+  updateShelf: ShelfDto;
+};
+
+
+export type LibraryMutationAddBookToShelfArgs = {
+  bookId: Scalars['Int']['input'];
+  shelfId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationAddCopyArgs = {
+  input: AddCopyInput;
 };
 
 
-export type BookMutationCreateBookArgs = {
+export type LibraryMutationAttachBookFileArgs = {
+  bookId: Scalars['Int']['input'];
+  copyFile?: Scalars['Boolean']['input'];
+  path: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationChangeReadingStatusArgs = {
+  bookId: Scalars['Int']['input'];
+  date?: InputMaybe<Scalars['NaiveDate']['input']>;
+  status: BookReadingStatus;
+};
+
+
+export type LibraryMutationCreateBookArgs = {
   author?: InputMaybe<Scalars['String']['input']>;
   description?: InputMaybe<Scalars['String']['input']>;
+  isbn?: InputMaybe<Scalars['String']['input']>;
   publishedYear?: InputMaybe<Scalars['Int']['input']>;
   title: Scalars['String']['input'];
 };
 
 
-export type BookMutationDeleteBookArgs = {
+export type LibraryMutationCreateBorrowerArgs = {
+  email?: InputMaybe<Scalars['String']['input']>;
+  name: Scalars['String']['input'];
+  phone?: InputMaybe<Scalars['String']['input']>;
+};
+
+
+export type LibraryMutationCreateLocationArgs = {
+  kind: BookLocationKind;
+  name: Scalars['String']['input'];
+  parentId?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
+export type LibraryMutationCreateNoteArgs = {
+  body: Scalars['String']['input'];
+  bookId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationCreateReviewArgs = {
+  body?: InputMaybe<Scalars['String']['input']>;
+  bookId: Scalars['Int']['input'];
+  rating?: InputMaybe<Scalars['Float']['input']>;
+};
+
+
+export type LibraryMutationCreateSeriesArgs = {
+  name: Scalars['String']['input'];
+  reading?: InputMaybe<Scalars['String']['input']>;
+  status?: SeriesPublicationStatus;
+  volumeCount?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
+export type LibraryMutationCreateShelfArgs = {
+  name: Scalars['String']['input'];
+  smartFilter?: InputMaybe<SmartShelfFilterInput>;
+};
+
+
+export type LibraryMutationCreateTagArgs = {
+  name: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationDeleteBookArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteBorrowerArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteCopyArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteHighlightArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteLocationArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteNoteArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteReadingSessionArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteReviewArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteSeriesArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteShelfArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationDeleteTagArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationImportBooksCsvArgs = {
+  allowPartial?: Scalars['Boolean']['input'];
+  columnMapping?: Array<CsvColumnMappingInput>;
+  content: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationImportCalibreLibraryArgs = {
+  path: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationImportEbookArgs = {
+  copyFile?: Scalars['Boolean']['input'];
+  path: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationImportExternalLibraryArgs = {
+  content: Scalars['String']['input'];
+  dryRun?: Scalars['Boolean']['input'];
+  source: ExternalLibrarySource;
+};
+
+
+export type LibraryMutationImportKindleClippingsArgs = {
+  content: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationLendCopyArgs = {
+  borrowerId: Scalars['Int']['input'];
+  copyId: Scalars['Int']['input'];
+  dueOn?: InputMaybe<Scalars['NaiveDate']['input']>;
+  lentOn?: InputMaybe<Scalars['NaiveDate']['input']>;
+};
+
+
+export type LibraryMutationLogReadingSessionArgs = {
+  input: LogReadingSessionInput;
+};
+
+
+export type LibraryMutationMergeBooksArgs = {
+  fieldChoices?: Array<MergeFieldChoiceInput>;
+  otherIds: Array<Scalars['Int']['input']>;
+  primaryId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationRateReadThroughArgs = {
+  id: Scalars['Int']['input'];
+  rating?: InputMaybe<Scalars['Float']['input']>;
+};
+
+
+export type LibraryMutationRemoveBookCoverArgs = {
+  bookId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationRemoveBookFileArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationRemoveBookFromSeriesArgs = {
+  bookId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationRemoveBookFromShelfArgs = {
+  bookId: Scalars['Int']['input'];
+  shelfId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationRenameTagArgs = {
   id: Scalars['Int']['input'];
+  name: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationReorderShelfBooksArgs = {
+  bookIds: Array<Scalars['Int']['input']>;
+  shelfId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationReorderShelvesArgs = {
+  ids: Array<Scalars['Int']['input']>;
+};
+
+
+export type LibraryMutationRestoreBookArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationReturnCopyArgs = {
+  copyId: Scalars['Int']['input'];
+  returnedOn?: InputMaybe<Scalars['NaiveDate']['input']>;
+};
+
+
+export type LibraryMutationRevertToVersionArgs = {
+  bookId: Scalars['Int']['input'];
+  version: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationSetBookAuthorsArgs = {
+  authors: Array<BookAuthorInput>;
+  bookId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationSetBookCoverArgs = {
+  bookId: Scalars['Int']['input'];
+  imageBase64: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationSetBookCoverFromFileArgs = {
+  bookId: Scalars['Int']['input'];
+  path: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationSetBookSeriesArgs = {
+  bookId: Scalars['Int']['input'];
+  seriesId: Scalars['Int']['input'];
+  volume: Scalars['Float']['input'];
+};
+
+
+export type LibraryMutationSetLoanDueDateArgs = {
+  dueOn?: InputMaybe<Scalars['NaiveDate']['input']>;
+  loanId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationTagBookArgs = {
+  bookId: Scalars['Int']['input'];
+  name: Scalars['String']['input'];
+};
+
+
+export type LibraryMutationUndoLastChangeArgs = {
+  bookId: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationUntagBookArgs = {
+  bookId: Scalars['Int']['input'];
+  tagId: Scalars['Int']['input'];
 };
 
 
-export type BookMutationUpdateBookArgs = {
+export type LibraryMutationUpdateBookArgs = {
   author?: InputMaybe<Scalars['String']['input']>;
   description?: InputMaybe<Scalars['String']['input']>;
+  expectedVersion: Scalars['Int']['input'];
   id: Scalars['Int']['input'];
+  isbn?: InputMaybe<Scalars['String']['input']>;
   publishedYear?: InputMaybe<Scalars['Int']['input']>;
+  publisher?: InputMaybe<Scalars['String']['input']>;
   title?: InputMaybe<Scalars['String']['input']>;
 };
 
-export type BookQuery = {
-  __typename?: 'BookQuery';
+
+export type LibraryMutationUpdateBookClassificationArgs = {
+  ddc?: InputMaybe<Scalars['String']['input']>;
+  expectedVersion: Scalars['Int']['input'];
+  id: Scalars['Int']['input'];
+  ndc?: InputMaybe<Scalars['String']['input']>;
+};
+
+
+export type LibraryMutationUpdateBookReadingsArgs = {
+  authorReading?: InputMaybe<Scalars['String']['input']>;
+  expectedVersion: Scalars['Int']['input'];
+  id: Scalars['Int']['input'];
+  titleReading?: InputMaybe<Scalars['String']['input']>;
+};
+
+
+export type LibraryMutationUpdateBorrowerArgs = {
+  email?: InputMaybe<Scalars['String']['input']>;
+  id: Scalars['Int']['input'];
+  name?: InputMaybe<Scalars['String']['input']>;
+  phone?: InputMaybe<Scalars['String']['input']>;
+};
+
+
+export type LibraryMutationUpdateCopyArgs = {
+  id: Scalars['Int']['input'];
+  input: UpdateCopyInput;
+};
+
+
+export type LibraryMutationUpdateLocationArgs = {
+  id: Scalars['Int']['input'];
+  name?: InputMaybe<Scalars['String']['input']>;
+  parentId?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
+export type LibraryMutationUpdateNoteArgs = {
+  body: Scalars['String']['input'];
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryMutationUpdateReviewArgs = {
+  body?: InputMaybe<Scalars['String']['input']>;
+  id: Scalars['Int']['input'];
+  rating?: InputMaybe<Scalars['Float']['input']>;
+};
+
+
+export type LibraryMutationUpdateSeriesArgs = {
+  id: Scalars['Int']['input'];
+  name?: InputMaybe<Scalars['String']['input']>;
+  reading?: InputMaybe<Scalars['String']['input']>;
+  status?: InputMaybe<SeriesPublicationStatus>;
+  volumeCount?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
+export type LibraryMutationUpdateShelfArgs = {
+  id: Scalars['Int']['input'];
+  name?: InputMaybe<Scalars['String']['input']>;
+  smartFilter?: InputMaybe<SmartShelfFilterInput>;
+};
+
+/** Libraryコンテキストのクエリ（各クエリを統合） */
+export type LibraryQuery = {
+  __typename?: 'LibraryQuery';
+  /** 貸出中のすべての貸出を返却期限の近い順に取得（期限なしは最後） */
+  activeLoans: Array<LoanDto>;
+  /** すべてのシリーズを読みの順に取得 */
+  allSeries: Array<SeriesDto>;
+  /** IDで著者を取得（その人物が関わっている本の一覧付き） */
+  author?: Maybe<AuthorDto>;
   /** IDで本を取得 */
   book?: Maybe<BookDto>;
-  /** すべての本を取得 */
-  books: Array<BookDto>;
+  /** ISBN（ISBN-10 / ISBN-13、ハイフン有無は問わない）で本を取得 */
+  bookByIsbn?: Maybe<BookDto>;
+  /**
+   * 分類ごとの本の数を分類記号の順に取得（分類から本を探す）
+   *
+   * `parent` を省略すると類ごと、類（1桁）・綱（2桁）を指定するとその下の階層ごとに、本のない分類も含めて返す。
+   * 目（3桁）以下を指定すると、本に付けた分類記号ごとに返す。各分類の本は `books` の `filter.ndc` / `filter.ddc` で取得できる。
+   */
+  bookClasses: Array<BookClassDto>;
+  /** 本の変更履歴を新しい順に取得（項目ごとの変更前後の値付き） */
+  bookHistory: Array<BookChangeDto>;
+  /**
+   * 本の一覧を取得（絞り込み・並び替え・ページング対応）
+   *
+   * `tag` はタグ名（子孫のタグが付いた本も含む）、`shelf` は本棚のID（スマート本棚は条件に一致する本）で絞り込む。
+   */
+  books: BookConnection;
+  /** IDで貸出相手を取得 */
+  borrower?: Maybe<BorrowerDto>;
+  /** すべての貸出相手を名前順に取得 */
+  borrowers: Array<BorrowerDto>;
+  /** IDで所有している本の1冊を取得 */
+  copy?: Maybe<CopyDto>;
+  /**
+   * 重複している可能性がある本のグループを確からしさの高い順に取得
+   *
+   * ISBN・タイトル（全角・半角、カタカナ・ひらがなの違いは無視する）・著者で判定する。
+   * `minConfidence`（0.0〜1.0、既定は0.5）未満の組は含めない。
+   */
+  duplicateBooks: Array<DuplicateGroupDto>;
+  /** EPUB・PDF ファイルの書誌情報を読み込む（本の登録フォームの初期値。本は登録しない） */
+  ebookMetadata: EbookMetadataDto;
+  /**
+   * すべての本をCSVで書き出す
+   *
+   * 列は `id,title,author,description,published_year,isbn`（1行目はヘッダー、ISBNはISBN-13）
+   */
+  exportBooksCsv: Scalars['String']['output'];
+  /**
+   * 本を BibTeX・RIS・CSL-JSON で書き出す
+   *
+   * `ids` で1冊または複数の本を指定する。指定しない場合は `filter` に一致する本（未指定ならすべての本）を書き出す。
+   * 引用キーは `著者の姓 + 出版年 + タイトルの最初の語`（例: `herbert1965dune`）で、初めて書き出すときに割り当てて保存する。
+   * 一度割り当てたキーは書き出す範囲や他の本の変更・削除によらず変わらない
+   */
+  exportCitations: Scalars['String']['output'];
+  /** 返却を促すリマインダー（期限切れと、`withinDays` 日以内に期限が来る貸出。デスクトップアプリから定期的に取得する） */
+  loanReminders: Array<LoanReminderDto>;
+  /** IDで場所を取得 */
+  location?: Maybe<LocationDto>;
+  /** 場所（下位の場所を含む）に置いてある冊を本のタイトルの読み順に取得（`locationId` 省略時は場所が未設定の冊） */
+  locationInventory: Array<CopyDto>;
+  /** 1つ下の階層の場所を名前の順に取得（`parentId` 省略時は部屋） */
+  locations: Array<LocationDto>;
+  /** 返却期限を過ぎている貸出を期限の古い順に取得 */
+  overdueLoans: Array<LoanDto>;
+  /** タイトル・著者・説明を全文検索（語の途中にも一致する。`"..."` で空白を含む連続した文字列として検索） */
+  searchBooks: Array<BookSearchResultDto>;
+  /** IDでシリーズを取得 */
+  series?: Maybe<SeriesDto>;
+  /** IDで本棚を取得 */
+  shelf?: Maybe<ShelfDto>;
+  /** すべての本棚を表示順に取得 */
+  shelves: Array<ShelfDto>;
+  /** すべてのタグを階層の順に取得（親のタグの直後に子のタグが並ぶ） */
+  tags: Array<TagDto>;
+  /** ゴミ箱にある本をゴミ箱に移した日時の新しい順に取得 */
+  trashedBooks: Array<BookDto>;
+  /** 本がどこにあるかを探す（タイトル・著者などで全文検索し、一致した本の冊を関連度の高い本の順に返す） */
+  whereIs: Array<CopyDto>;
 };
 
 
-export type BookQueryBookArgs = {
+export type LibraryQueryAuthorArgs = {
   id: Scalars['Int']['input'];
 };
 
-/** データベース設定のDTO */
-export type DatabaseSettingsDto = {
-  __typename?: 'DatabaseSettingsDto';
-  databaseDirectory: Scalars['String']['output'];
+
+export type LibraryQueryBookArgs = {
+  id: Scalars['Int']['input'];
 };
 
-/** 一般設定のDTO */
-export type GeneralSettingsDto = {
-  __typename?: 'GeneralSettingsDto';
-  language: Scalars['String']['output'];
+
+export type LibraryQueryBookByIsbnArgs = {
+  isbn: Scalars['String']['input'];
+};
+
+
+export type LibraryQueryBookClassesArgs = {
+  parent?: InputMaybe<Scalars['String']['input']>;
+  system: ClassificationSystem;
+};
+
+
+export type LibraryQueryBookHistoryArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryQueryBooksArgs = {
+  after?: InputMaybe<Scalars['String']['input']>;
+  filter?: InputMaybe<BookFilterInput>;
+  first?: InputMaybe<Scalars['Int']['input']>;
+  shelf?: InputMaybe<Scalars['Int']['input']>;
+  sort?: InputMaybe<BookSortInput>;
+  tag?: InputMaybe<Scalars['String']['input']>;
+};
+
+
+export type LibraryQueryBorrowerArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryQueryCopyArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryQueryDuplicateBooksArgs = {
+  minConfidence?: InputMaybe<Scalars['Float']['input']>;
+};
+
+
+export type LibraryQueryEbookMetadataArgs = {
+  path: Scalars['String']['input'];
+};
+
+
+export type LibraryQueryExportCitationsArgs = {
+  filter?: InputMaybe<BookFilterInput>;
+  format: CitationFormat;
+  ids?: InputMaybe<Array<Scalars['Int']['input']>>;
+};
+
+
+export type LibraryQueryLoanRemindersArgs = {
+  withinDays?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
+export type LibraryQueryLocationArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryQueryLocationInventoryArgs = {
+  locationId?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
+export type LibraryQueryLocationsArgs = {
+  parentId?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
+export type LibraryQuerySearchBooksArgs = {
+  limit?: InputMaybe<Scalars['Int']['input']>;
+  query: Scalars['String']['input'];
+};
+
+
+export type LibraryQuerySeriesArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryQueryShelfArgs = {
+  id: Scalars['Int']['input'];
+};
+
+
+export type LibraryQueryWhereIsArgs = {
+  limit?: InputMaybe<Scalars['Int']['input']>;
+  query: Scalars['String']['input'];
+};
+
+/**
+ * 貸出 DTO - GraphQLレスポンス用
+ *
+ * 貸した冊・貸出相手は presentation 層の `ComplexObject` で解決する。
+ */
+export type LoanDto = {
+  __typename?: 'LoanDto';
+  /** 貸出相手 */
+  borrower?: Maybe<BorrowerDto>;
+  borrowerId: Scalars['Int']['output'];
+  /** 貸した冊 */
+  copy?: Maybe<CopyDto>;
+  copyId: Scalars['Int']['output'];
+  /** 返却期限 */
+  dueOn?: Maybe<Scalars['NaiveDate']['output']>;
+  id: Scalars['Int']['output'];
+  lentOn: Scalars['NaiveDate']['output'];
+  /** 返却日（貸出中はなし） */
+  returnedOn?: Maybe<Scalars['NaiveDate']['output']>;
+};
+
+/** 返却のリマインダー DTO - GraphQLレスポンス用 */
+export type LoanReminderDto = {
+  __typename?: 'LoanReminderDto';
+  /** 返却期限までの日数（当日は0、期限を過ぎている場合は負） */
+  daysUntilDue: Scalars['Int']['output'];
+  kind: LoanReminderKind;
+  loan: LoanDto;
+};
+
+/** 返却のリマインダーの種類 */
+export enum LoanReminderKind {
+  /** 返却期限が近い（当日を含む） */
+  DueSoon = 'DUE_SOON',
+  /** 返却期限を過ぎている */
+  Overdue = 'OVERDUE'
+}
+
+/**
+ * 場所 DTO - GraphQLレスポンス用
+ *
+ * 下位の場所・部屋からの経路・冊数は presentation 層の `ComplexObject` で解決する。
+ */
+export type LocationDto = {
+  __typename?: 'LocationDto';
+  /** 1つ下の階層の場所（名前の順） */
+  children: Array<LocationDto>;
+  /** この場所（下位の場所を含む）に置いてある冊数（ゴミ箱にある本は含めない） */
+  copyCount: Scalars['Int']['output'];
+  id: Scalars['Int']['output'];
+  kind: BookLocationKind;
+  name: Scalars['String']['output'];
+  /** 1つ上の階層の場所（部屋はなし） */
+  parentId?: Maybe<Scalars['Int']['output']>;
+  /** 部屋からこの場所までの経路（部屋が先頭で、この場所を含む） */
+  path: Array<LocationDto>;
+};
+
+/**
+ * 読書記録の追加 - GraphQL入力用
+ *
+ * 進捗は `page` と `percent` のどちらか一方を指定する。
+ */
+export type LogReadingSessionInput = {
+  bookId: Scalars['Int']['input'];
+  durationMinutes?: InputMaybe<Scalars['Int']['input']>;
+  /** 読み終えたページ */
+  page?: InputMaybe<Scalars['Int']['input']>;
+  /** 読み終えた割合（0〜100） */
+  percent?: InputMaybe<Scalars['Float']['input']>;
+  /** 読んだ日（省略時は今日） */
+  readOn?: InputMaybe<Scalars['NaiveDate']['input']>;
+};
+
+/** 項目の値をどの本から採るか - GraphQL入力用 */
+export type MergeFieldChoiceInput = {
+  /** 値を採る本のID（まとめ先またはまとめる本のいずれか） */
+  bookId: Scalars['Int']['input'];
+  field: BookMergeField;
 };
 
 export type MutationRoot = {
   __typename?: 'MutationRoot';
   /** Libraryコンテキストのミューテーション */
-  library: BookMutation;
+  library: LibraryMutation;
   /** Settingsコンテキストのミューテーション */
   settings: SettingsMutation;
 };
 
+/** メモ DTO - GraphQLレスポンス用 */
+export type NoteDto = {
+  __typename?: 'NoteDto';
+  body: Scalars['String']['output'];
+  bookId: Scalars['Int']['output'];
+  createdAt: Scalars['DateTime']['output'];
+  id: Scalars['Int']['output'];
+  updatedAt: Scalars['DateTime']['output'];
+};
+
+/** Information about pagination in a connection */
+export type PageInfo = {
+  __typename?: 'PageInfo';
+  /** When paginating forwards, the cursor to continue. */
+  endCursor?: Maybe<Scalars['String']['output']>;
+  /** When paginating forwards, are there more items? */
+  hasNextPage: Scalars['Boolean']['output'];
+  /** When paginating backwards, are there more items? */
+  hasPreviousPage: Scalars['Boolean']['output'];
+  /** When paginating backwards, the cursor to continue. */
+  startCursor?: Maybe<Scalars['String']['output']>;
+};
+
+/** 価格 DTO - GraphQLレスポンス用 */
+export type PriceDto = {
+  __typename?: 'PriceDto';
+  /** 金額の10進表記（通貨の小数点以下の桁数にそろえる。例: `12.90`・`1980`） */
+  amount: Scalars['String']['output'];
+  /** 通貨コード（ISO 4217） */
+  currency: Scalars['String']['output'];
+};
+
+/** 価格 - GraphQL入力用 */
+export type PriceInput = {
+  /** 金額の10進表記（例: `1980`・`12.99`。`,` の桁区切りは無視する） */
+  amount: Scalars['String']['input'];
+  /** 通貨コード（ISO 4217。例: `JPY`・`USD`） */
+  currency: Scalars['String']['input'];
+};
+
 export type QueryRoot = {
   __typename?: 'QueryRoot';
   /** Libraryコンテキストへのアクセス */
-  library: BookQuery;
+  library: LibraryQuery;
   /** Settingsコンテキストへのアクセス */
   settings: SettingsQuery;
 };
 
+/** 通読 DTO - GraphQLレスポンス用 */
+export type ReadThroughDto = {
+  __typename?: 'ReadThroughDto';
+  bookId: Scalars['Int']['output'];
+  /** 最新の進捗（ページ） */
+  currentPage?: Maybe<Scalars['Int']['output']>;
+  /** 最新の進捗（割合） */
+  currentPercent?: Maybe<Scalars['Float']['output']>;
+  finishedOn?: Maybe<Scalars['NaiveDate']['output']>;
+  id: Scalars['Int']['output'];
+  /** この通読での評価（0.5〜5.0、0.5刻み） */
+  rating?: Maybe<Scalars['Float']['output']>;
+  /** 読書記録（日付順） */
+  sessions: Array<ReadingSessionDto>;
+  startedOn?: Maybe<Scalars['NaiveDate']['output']>;
+  status: BookReadingStatus;
+};
+
+/** 読書記録 DTO - GraphQLレスポンス用 */
+export type ReadingSessionDto = {
+  __typename?: 'ReadingSessionDto';
+  durationMinutes?: Maybe<Scalars['Int']['output']>;
+  id: Scalars['Int']['output'];
+  /** 読み終えたページ */
+  page?: Maybe<Scalars['Int']['output']>;
+  /** 読み終えた割合（0〜100） */
+  percent?: Maybe<Scalars['Float']['output']>;
+  readOn: Scalars['NaiveDate']['output'];
+};
+
+/** 評価・感想 DTO - GraphQLレスポンス用 */
+export type ReviewDto = {
+  __typename?: 'ReviewDto';
+  body?: Maybe<Scalars['String']['output']>;
+  bookId: Scalars['Int']['output'];
+  createdAt: Scalars['DateTime']['output'];
+  id: Scalars['Int']['output'];
+  /** 星の数（0.5〜5.0、0.5刻み） */
+  rating?: Maybe<Scalars['Float']['output']>;
+  updatedAt: Scalars['DateTime']['output'];
+};
+
+/**
+ * シリーズ DTO - GraphQLレスポンス用
+ *
+ * 巻の一覧・欠けている巻は presentation 層の `ComplexObject` で解決する。
+ */
+export type SeriesDto = {
+  __typename?: 'SeriesDto';
+  id: Scalars['Int']['output'];
+  /** 所有していない巻（1巻から、刊行済みの巻数と所有している最後の巻の大きい方まで。整数の巻のみ） */
+  missingVolumes: Array<Scalars['Int']['output']>;
+  name: Scalars['String']['output'];
+  /** 読み（かな） */
+  reading?: Maybe<Scalars['String']['output']>;
+  /** 並び替え用のキー（読み、なければ名前を正規化したもの） */
+  sortKey: Scalars['String']['output'];
+  status: SeriesPublicationStatus;
+  /** 刊行済みの巻数 */
+  volumeCount?: Maybe<Scalars['Int']['output']>;
+  /** シリーズの本（巻数の順。1.5巻などの小数の巻を含む） */
+  volumes: Array<SeriesVolumeDto>;
+};
+
+/** シリーズの刊行状況 */
+export enum SeriesPublicationStatus {
+  /** 完結 */
+  Completed = 'COMPLETED',
+  /** 刊行中 */
+  Ongoing = 'ONGOING'
+}
+
+/** シリーズの1巻分 DTO - GraphQLレスポンス用 */
+export type SeriesVolumeDto = {
+  __typename?: 'SeriesVolumeDto';
+  book: BookDto;
+  /** 巻数（1.5 などの小数を含む） */
+  volume: Scalars['Float']['output'];
+};
+
 export type SettingsMutation = {
   __typename?: 'SettingsMutation';
   /** すべての設定をリセット */
@@ -114,6 +1681,8 @@ export type SettingsMutation = {
   updateDatabaseSettings: DatabaseSettingsDto;
   /** 一般設定を更新 */
   updateGeneralSettings: GeneralSettingsDto;
+  /** ゴミ箱設定を更新（保存日数は0〜3650日。0 の場合は自動で削除しない） */
+  updateTrashSettings: TrashSettingsDto;
 };
 
 
@@ -131,6 +1700,11 @@ export type SettingsMutationUpdateGeneralSettingsArgs = {
   language?: InputMaybe<Scalars['String']['input']>;
 };
 
+
+export type SettingsMutationUpdateTrashSettingsArgs = {
+  retentionDays?: InputMaybe<Scalars['Int']['input']>;
+};
+
 export type SettingsQuery = {
   __typename?: 'SettingsQuery';
   /** 表示設定を取得 */
@@ -139,4 +1713,104 @@ export type SettingsQuery = {
   databaseSettings: DatabaseSettingsDto;
   /** 一般設定を取得 */
   generalSettings: GeneralSettingsDto;
+  /** ゴミ箱設定を取得 */
+  trashSettings: TrashSettingsDto;
+};
+
+/**
+ * 本棚 DTO - GraphQLレスポンス用
+ *
+ * 並べた本は presentation 層の `ComplexObject` で解決する。
+ */
+export type ShelfDto = {
+  __typename?: 'ShelfDto';
+  /** 本棚の本（手動の本棚は並べた順、スマート本棚は条件に一致する本をタイトルの読み順） */
+  books: Array<BookDto>;
+  id: Scalars['Int']['output'];
+  name: Scalars['String']['output'];
+  /** 本棚一覧での表示順（0始まり） */
+  position: Scalars['Int']['output'];
+  /** スマート本棚か（条件に一致する本を並べ、本を個別に追加できない） */
+  smart: Scalars['Boolean']['output'];
+  /** スマート本棚の絞り込み条件 */
+  smartFilter?: Maybe<SmartShelfFilterDto>;
+};
+
+/** スマート本棚の絞り込み条件 DTO - GraphQLレスポンス用 */
+export type SmartShelfFilterDto = {
+  __typename?: 'SmartShelfFilterDto';
+  /** 著者名（部分一致） */
+  author?: Maybe<Scalars['String']['output']>;
+  /** デューイ十進分類法（DDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む） */
+  ddc?: Maybe<Scalars['String']['output']>;
+  /** 日本十進分類法（NDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む） */
+  ndc?: Maybe<Scalars['String']['output']>;
+  /** 出版年の下限（この年を含む） */
+  publishedYearFrom?: Maybe<Scalars['Int']['output']>;
+  /** 出版年の上限（この年を含む） */
+  publishedYearTo?: Maybe<Scalars['Int']['output']>;
+  /** タグ（子孫のタグが付いた本も含む） */
+  tag?: Maybe<Scalars['String']['output']>;
+  /** タイトル（部分一致） */
+  titleContains?: Maybe<Scalars['String']['output']>;
+};
+
+/** スマート本棚の絞り込み条件 - GraphQL入力用 */
+export type SmartShelfFilterInput = {
+  /** 著者名（部分一致） */
+  author?: InputMaybe<Scalars['String']['input']>;
+  /** デューイ十進分類法（DDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む） */
+  ddc?: InputMaybe<Scalars['String']['input']>;
+  /** 日本十進分類法（NDC）の分類（類は1桁、綱は2桁、目以下は分類記号。下位の分類を含む） */
+  ndc?: InputMaybe<Scalars['String']['input']>;
+  /** 出版年の下限（この年を含む） */
+  publishedYearFrom?: InputMaybe<Scalars['Int']['input']>;
+  /** 出版年の上限（この年を含む） */
+  publishedYearTo?: InputMaybe<Scalars['Int']['input']>;
+  /** タグ（子孫のタグが付いた本も含む） */
+  tag?: InputMaybe<Scalars['String']['input']>;
+  /** タイトル（部分一致） */
+  titleContains?: InputMaybe<Scalars['String']['input']>;
+};
+
+/** 並び順 */
+export enum SortDirection {
+  Asc = 'ASC',
+  Desc = 'DESC'
+}
+
+/** タグ DTO - GraphQLレスポンス用 */
+export type TagDto = {
+  __typename?: 'TagDto';
+  /** 階層の深さ（最上位は0） */
+  depth: Scalars['Int']['output'];
+  id: Scalars['Int']['output'];
+  /** 最後の階層の名前 */
+  label: Scalars['String']['output'];
+  /** 最上位からのパス（`親/子` 形式） */
+  name: Scalars['String']['output'];
+  /** 親のタグ名（最上位の場合はなし） */
+  parent?: Maybe<Scalars['String']['output']>;
+};
+
+/** ゴミ箱設定のDTO */
+export type TrashSettingsDto = {
+  __typename?: 'TrashSettingsDto';
+  /** ゴミ箱に移した本を自動で完全に削除するまでの日数（0 の場合は自動で削除しない） */
+  retentionDays: Scalars['Int']['output'];
+};
+
+/**
+ * 所有している本の1冊の更新 - GraphQL入力用
+ *
+ * 省略した項目は変更せず、`null` を指定した項目は未設定に戻す。
+ */
+export type UpdateCopyInput = {
+  acquiredOn?: InputMaybe<Scalars['NaiveDate']['input']>;
+  condition?: InputMaybe<BookCopyCondition>;
+  format?: InputMaybe<BookCopyFormat>;
+  /** 置いてある場所（部屋・本棚・棚のいずれか） */
+  locationId?: InputMaybe<Scalars['Int']['input']>;
+  /** 入手時の価格 */
+  price?: InputMaybe<PriceInput>;
 };